@@ -0,0 +1,120 @@
+use crate::functions::{json_file_structs::Task, storage_helpers::*, task_store::TaskList};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+// done tasks that were taken out of the done lists to keep them short, kept in archive.json next to chartodo.json
+// (or chartodo.sqlite3). they're kept the same way they are in chartodo.json, done_at and all, just w/o a todo list.
+// see Retention
+//
+// a TaskStore holds on to what it archived until it's saved, then adds it here, see TaskStore::save. archive-list
+// and archive-restore read the whole file, see TaskStore::load_archive
+
+pub const ARCHIVE_FILE_NAME: &str = "archive.json";
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct Archive {
+    pub regular: Vec<Task>,
+    pub deadline: Vec<Task>,
+    pub repeating: Vec<Task>,
+}
+
+impl Archive {
+    pub fn list(&self, list: TaskList) -> &Vec<Task> {
+        match list {
+            TaskList::Regular => &self.regular,
+            TaskList::Deadline => &self.deadline,
+            TaskList::Repeating => &self.repeating,
+        }
+    }
+
+    pub fn list_mut(&mut self, list: TaskList) -> &mut Vec<Task> {
+        match list {
+            TaskList::Regular => &mut self.regular,
+            TaskList::Deadline => &mut self.deadline,
+            TaskList::Repeating => &mut self.repeating,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regular.is_empty() && self.deadline.is_empty() && self.repeating.is_empty()
+    }
+}
+
+// no file means nothing has been archived yet
+pub fn read_archive(archive_path: &Path) -> anyhow::Result<Archive> {
+    if !archive_path.exists() {
+        return Ok(Archive::default());
+    }
+
+    Ok(try_read_json_from_file(archive_path)?.unwrap_or_default())
+}
+
+// the newly archived tasks go after the ones that are already there
+pub fn append_to_archive(archive_path: &Path, archived: &Archive) -> anyhow::Result<()> {
+    if archived.is_empty() {
+        return Ok(());
+    }
+
+    let mut archive = read_archive(archive_path)?;
+    for list in [TaskList::Regular, TaskList::Deadline, TaskList::Repeating] {
+        archive
+            .list_mut(list)
+            .extend(archived.list(list).iter().cloned());
+    }
+
+    try_write_json_to_file(archive_path, &archive)
+}
+
+// cargo test archive_unit_tests
+#[cfg(test)]
+mod archive_unit_tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn fresh_test_dir(test_name: &str) -> PathBuf {
+        let mut test_dir = std::env::temp_dir();
+        test_dir.push(format!(
+            "chartodo_archive_unit_tests_{}_{}",
+            test_name,
+            std::process::id()
+        ));
+
+        // in case a previous run didn't clean up after itself
+        if test_dir.exists() {
+            std::fs::remove_dir_all(&test_dir).unwrap();
+        }
+        std::fs::create_dir(&test_dir).unwrap();
+
+        test_dir
+    }
+
+    #[test]
+    fn archive_is_appended_to() {
+        let test_dir = fresh_test_dir("appended");
+        let archive_path = test_dir.join(ARCHIVE_FILE_NAME);
+        assert!(read_archive(&archive_path).unwrap().is_empty());
+
+        let regular = |task: &str| Archive {
+            regular: vec![Task::regular(task.to_string())],
+            ..Archive::default()
+        };
+        append_to_archive(&archive_path, &regular("first")).unwrap();
+        append_to_archive(&archive_path, &Archive::default()).unwrap();
+        append_to_archive(&archive_path, &regular("second")).unwrap();
+
+        let archive = read_archive(&archive_path).unwrap();
+        assert_eq!(
+            archive.regular,
+            vec![
+                Task::regular(String::from("first")),
+                Task::regular(String::from("second"))
+            ]
+        );
+        assert!(archive.deadline.is_empty() && archive.repeating.is_empty());
+
+        std::fs::write(&archive_path, "{ not json").unwrap();
+        assert!(read_archive(&archive_path).is_err());
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+}
@@ -0,0 +1,194 @@
+use crate::functions::{
+    errors::ChartodoError, json_file_structs::*, storage_helpers::open_task_store,
+    task_ids::PositionsIn,
+};
+use clap::ValueEnum;
+use clap_complete::{
+    engine::CompletionCandidate,
+    env::{Bash, Elvish, EnvCompleter, Fish, Powershell, Zsh},
+};
+
+// the completions are dynamic, i.e., the script that chartodo completions [shell] spits out calls chartodo again
+// every time tab is pressed. that's what lets the positions show their task, e.g., chartodo done <tab> shows
+// 1 -- buy-milk, 2 -- walk-dog, etc.
+
+// the env var the shell sets when it asks chartodo for completions. main hands it to clap_complete's CompleteEnv
+pub const COMPLETE_ENV: &str = "COMPLETE";
+
+// the shells clap_complete can write a script for. clap_complete's own Shell enum can grow, so this one is
+// chartodo's, which means every shell that can be passed in has a script
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CompletionShell {
+    Bash,
+    Elvish,
+    Fish,
+    Powershell,
+    Zsh,
+}
+
+impl CompletionShell {
+    fn completer(self) -> &'static dyn EnvCompleter {
+        match self {
+            CompletionShell::Bash => &Bash,
+            CompletionShell::Elvish => &Elvish,
+            CompletionShell::Fish => &Fish,
+            CompletionShell::Powershell => &Powershell,
+            CompletionShell::Zsh => &Zsh,
+        }
+    }
+}
+
+pub fn completions(shell: CompletionShell) -> Result<(), ChartodoError> {
+    let writer = &mut std::io::stdout();
+
+    // the script has to call this exact chartodo, in case it's not the one in PATH
+    let chartodo_path = std::env::current_exe()
+        .map(|path| path.to_string_lossy().to_string())
+        .unwrap_or_else(|_| "chartodo".to_string());
+
+    // e.g., stdout was closed early by whatever it was piped into
+    shell
+        .completer()
+        .write_registration(COMPLETE_ENV, "chartodo", "chartodo", &chartodo_path, writer)
+        .map_err(|e| {
+            ChartodoError::Storage(format!("couldn't write the completion script: {:#}", e))
+        })?;
+
+    Ok(())
+}
+
+// turns a list into tab candidates where the position is what gets completed and the task is shown next to it
+fn position_candidates(tasks: &[Task], describe: fn(&Task) -> String) -> Vec<CompletionCandidate> {
+    tasks
+        .iter()
+        .enumerate()
+        .map(|(index, task)| {
+            CompletionCandidate::new((index + 1).to_string()).help(Some(describe(task).into()))
+        })
+        .collect()
+}
+
+fn describe_regular(task: &Task) -> String {
+    task.task.clone()
+}
+
+fn describe_deadline(task: &Task) -> String {
+    match task.due() {
+        Some(due) => format!(
+            "{} (due: {} {})",
+            task.task,
+            due.format(DATE_FORMAT),
+            due.format(TIME_FORMAT)
+        ),
+        None => task.task.clone(),
+    }
+}
+
+fn describe_repeating(task: &Task) -> String {
+    match task.repeat() {
+        Some(repeat) => format!("{} (every {})", task.task, repeat.every()),
+        None => task.task.clone(),
+    }
+}
+
+// a file that can't be read has no positions to offer. what went wrong is shown once the command itself is run
+fn positions_in(list: PositionsIn, describe: fn(&Task) -> String) -> Vec<CompletionCandidate> {
+    match open_task_store() {
+        Ok(store) => position_candidates(store.tasks(list), describe),
+        Err(_) => vec![],
+    }
+}
+
+pub fn regular_todo_positions() -> Vec<CompletionCandidate> {
+    positions_in(PositionsIn::RegularTodo, describe_regular)
+}
+
+pub fn regular_done_positions() -> Vec<CompletionCandidate> {
+    positions_in(PositionsIn::RegularDone, describe_regular)
+}
+
+pub fn deadline_todo_positions() -> Vec<CompletionCandidate> {
+    positions_in(PositionsIn::DeadlineTodo, describe_deadline)
+}
+
+pub fn deadline_done_positions() -> Vec<CompletionCandidate> {
+    positions_in(PositionsIn::DeadlineDone, describe_deadline)
+}
+
+pub fn repeating_todo_positions() -> Vec<CompletionCandidate> {
+    positions_in(PositionsIn::RepeatingTodo, describe_repeating)
+}
+
+pub fn repeating_done_positions() -> Vec<CompletionCandidate> {
+    positions_in(PositionsIn::RepeatingDone, describe_repeating)
+}
+
+// cargo test completions_unit_tests
+#[cfg(test)]
+mod completions_unit_tests {
+    use super::*;
+
+    #[test]
+    fn position_candidates_show_task_next_to_position() {
+        let tasks: Tasks = serde_json::from_str(
+            r#"
+            {
+                "todo": [
+                    {
+                        "task": "buy-milk",
+                        "date": "2099-01-01",
+                        "time": "13:00",
+                        "repeat_number": null,
+                        "repeat_unit": null,
+                        "repeat_done": null,
+                        "repeat_original_date": null,
+                        "repeat_original_time": null
+                    },
+                    {
+                        "task": "walk-dog",
+                        "date": "2099-01-02",
+                        "time": "08:30",
+                        "repeat_number": null,
+                        "repeat_unit": null,
+                        "repeat_done": null,
+                        "repeat_original_date": null,
+                        "repeat_original_time": null
+                    }
+                ],
+                "done": []
+            }
+        "#,
+        )
+        .unwrap();
+
+        let candidates = position_candidates(&tasks.todo, describe_deadline);
+        let candidates: Vec<(String, String)> = candidates
+            .iter()
+            .map(|candidate| {
+                (
+                    candidate.get_value().to_string_lossy().to_string(),
+                    candidate.get_help().unwrap().to_string(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            candidates,
+            vec![
+                (
+                    "1".to_string(),
+                    "buy-milk (due: 2099-01-01 13:00)".to_string()
+                ),
+                (
+                    "2".to_string(),
+                    "walk-dog (due: 2099-01-02 08:30)".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn position_candidates_of_empty_list_is_empty() {
+        assert!(position_candidates(&[], describe_regular).is_empty());
+    }
+}
@@ -0,0 +1,426 @@
+use crate::functions::{
+    date_parsing::parse_time, errors::ChartodoError, json_file_structs::TIME_FORMAT,
+    storage_helpers::*,
+};
+use anyhow::Context;
+use chrono::NaiveTime;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{path::Path, sync::OnceLock};
+
+// settings for how chartodo behaves, kept in config.toml in the chartodo folder. the file doesn't have to exist, and
+// anything that's left out of it keeps its default, e.g., this keeps 50 done tasks per list, for at most 90 days,
+// deletes the rest instead of archiving them, and shows the lists w/ plain ascii borders:
+//
+//     [retention]
+//     max_done = 50
+//     max_done_days = 90
+//     overflow = "delete"
+//
+//     [display]
+//     table_style = "ascii"
+//
+// chartodo config get/set reads and changes it one setting at a time, see set_setting
+
+pub const CONFIG_FILE_NAME: &str = "config.toml";
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub retention: Retention,
+    pub defaults: Defaults,
+    pub display: Appearance,
+}
+
+// how long done tasks stay in the done lists. each of the three done lists is held to it on its own, every time the
+// lists are saved. see TaskStore::apply_retention
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Retention {
+    // how many tasks a done list keeps. the ones that were done first go first. 0 keeps all of them
+    pub max_done: usize,
+    // how many days a task stays in a done list after it was done. 0 keeps them forever
+    pub max_done_days: u32,
+    pub overflow: Overflow,
+}
+
+// 30 is how many the regular done list used to be wiped at
+impl Default for Retention {
+    fn default() -> Self {
+        Retention {
+            max_done: 30,
+            max_done_days: 0,
+            overflow: Overflow::Archive,
+        }
+    }
+}
+
+// what commands do when they aren't told otherwise
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Defaults {
+    // the time deadline-addonlydate gives a task whose date doesn't have one. any time the commands take works, e.g.,
+    // 17:00 or eod
+    #[serde(
+        serialize_with = "serialize_time",
+        deserialize_with = "deserialize_time"
+    )]
+    pub deadline_time: NaiveTime,
+    // giving every position in a list longer than this is refused, since there's a command for the whole list, e.g.,
+    // doneall instead of done 1-10
+    pub whole_list_threshold: usize,
+    // whether a new chartodo.json starts w/ the example tasks instead of empty, see chartodo init for starting w/ your
+    // own
+    pub starter_tasks: bool,
+}
+
+impl Default for Defaults {
+    fn default() -> Self {
+        Defaults {
+            deadline_time: NaiveTime::MIN,
+            whole_list_threshold: 5,
+            starter_tasks: false,
+        }
+    }
+}
+
+fn serialize_time<S: Serializer>(time: &NaiveTime, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&time.format(TIME_FORMAT).to_string())
+}
+
+fn deserialize_time<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NaiveTime, D::Error> {
+    let time = String::deserialize(deserializer)?;
+    parse_time(&time).ok_or_else(|| {
+        serde::de::Error::custom(format!(
+            "'{}' isn't a time. Try something like 13:58, noon, or eod",
+            time
+        ))
+    })
+}
+
+// how the lists are shown. w/ --format json, the due status is always due or missed, so that scripts don't have to
+// know the labels
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Appearance {
+    pub table_style: TableStyle,
+    // what's shown before the due date of a todo that's past it, and of one that isn't
+    pub missed_label: String,
+    pub due_label: String,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Appearance {
+            table_style: TableStyle::Rounded,
+            missed_label: String::from("MISSED"),
+            due_label: String::from("due"),
+        }
+    }
+}
+
+// the borders around the lists, see list
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TableStyle {
+    #[default]
+    Rounded,
+    Sharp,
+    Ascii,
+    Markdown,
+    // no borders at all
+    Plain,
+}
+
+// what happens to the done tasks that don't fit
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Overflow {
+    // moved to archive.json, see archive
+    #[default]
+    Archive,
+    Delete,
+}
+
+// no file is the same as an empty one. anything in it that isn't a setting is an error, so that a typo doesn't
+// quietly do nothing
+pub fn read_config(config_path: &Path) -> anyhow::Result<Config> {
+    if !config_path.exists() {
+        return Ok(Config::default());
+    }
+
+    let contents = std::fs::read_to_string(config_path)
+        .with_context(|| format!("couldn't open {}", config_path.display()))?;
+
+    // toml's errors end w/ a newline, and point at where in the file it went wrong
+    toml::from_str(&contents).map_err(|parse_error| {
+        anyhow::anyhow!(
+            "couldn't parse {}: {}",
+            config_path.display(),
+            parse_error.to_string().trim_end()
+        )
+    })
+}
+
+pub fn path_to_config_file() -> Result<std::path::PathBuf, ChartodoError> {
+    Ok(path_to_chartodo_dir()?.join(CONFIG_FILE_NAME))
+}
+
+// the cli's way to the config. it's only read once per command, the first time something needs it. one that can't be
+// read is a Storage error, so the command stops before anything is changed. same as a broken chartodo.json, it's
+// left where it is
+pub fn config() -> Result<&'static Config, ChartodoError> {
+    static CONFIG: OnceLock<Config> = OnceLock::new();
+
+    if let Some(config) = CONFIG.get() {
+        return Ok(config);
+    }
+    let config = read_config(&path_to_config_file()?).map_err(|config_error| {
+        ChartodoError::Storage(format!("{:#}. Nothing was changed.", config_error))
+    })?;
+
+    Ok(CONFIG.get_or_init(|| config))
+}
+
+// every setting, e.g., display.table_style, w/ its value in config. a setting is its section and its name, joined
+// w/ a .
+pub fn settings(config: &Config) -> Vec<(String, toml::Value)> {
+    let config = toml::Table::try_from(config).expect("a config can always be turned into toml");
+
+    config
+        .into_iter()
+        .flat_map(|(section, settings)| match settings {
+            toml::Value::Table(settings) => settings
+                .into_iter()
+                .map(|(name, value)| (format!("{}.{}", section, name), value))
+                .collect::<Vec<(String, toml::Value)>>(),
+            value => vec![(section, value)],
+        })
+        .collect()
+}
+
+pub fn get_setting(config: &Config, key: &str) -> Result<toml::Value, ChartodoError> {
+    settings(config)
+        .into_iter()
+        .find(|(setting, _)| setting == key)
+        .map(|(_, value)| value)
+        .ok_or_else(|| not_a_setting(key))
+}
+
+fn not_a_setting(key: &str) -> ChartodoError {
+    let setting_names: Vec<String> = settings(&Config::default())
+        .into_iter()
+        .map(|(setting, _)| setting)
+        .collect();
+
+    ChartodoError::InvalidSetting(format!(
+        "'{}' isn't a setting. Try one of: {}.",
+        key,
+        setting_names.join(", ")
+    ))
+}
+
+// changes one setting in config.toml and leaves the rest of it be, comments and all. the value is taken as whatever
+// kind of value the setting has, e.g., a number for retention.max_done, and the whole file has to still make sense
+// afterward
+pub fn set_setting(config_path: &Path, key: &str, value: &str) -> Result<Config, ChartodoError> {
+    let storage_error =
+        |error: anyhow::Error| ChartodoError::Storage(format!("{:#}. Nothing was changed.", error));
+    let invalid_value =
+        || ChartodoError::InvalidSetting(format!("'{}' isn't a proper value for {}.", value, key));
+
+    let mut new_value = match get_setting(&Config::default(), key)? {
+        toml::Value::Integer(_) => {
+            toml_edit::Value::from(value.parse::<i64>().map_err(|_| invalid_value())?)
+        }
+        toml::Value::Boolean(_) => {
+            toml_edit::Value::from(value.parse::<bool>().map_err(|_| invalid_value())?)
+        }
+        _ => toml_edit::Value::from(value),
+    };
+
+    // checked first, so that a file that's already broken isn't made worse
+    read_config(config_path).map_err(storage_error)?;
+    let mut config_file: toml_edit::DocumentMut = match config_path.exists() {
+        true => std::fs::read_to_string(config_path)
+            .with_context(|| format!("couldn't open {}", config_path.display()))
+            .map_err(storage_error)?
+            .parse()
+            .expect("config.toml was just read, so it's proper toml"),
+        false => toml_edit::DocumentMut::new(),
+    };
+
+    let (section, name) = key.split_once('.').ok_or_else(|| not_a_setting(key))?;
+    let settings = config_file
+        .entry(section)
+        .or_insert_with(toml_edit::table)
+        .as_table_like_mut()
+        .ok_or_else(|| not_a_setting(key))?;
+    // w/ the old value's spacing and trailing comment, e.g., max_done = 10 # keeps a week's worth
+    if let Some(old_value) = settings.get(name).and_then(|old_item| old_item.as_value()) {
+        *new_value.decor_mut() = old_value.decor().clone();
+    }
+    settings.insert(name, toml_edit::Item::Value(new_value));
+
+    let config_toml = config_file.to_string();
+    let config: Config = toml::from_str(&config_toml).map_err(|parse_error: toml::de::Error| {
+        ChartodoError::InvalidSetting(format!(
+            "'{}' isn't a proper value for {}: {}",
+            value,
+            key,
+            parse_error.message().trim_end()
+        ))
+    })?;
+
+    try_write_to_file(config_path, config_toml.as_bytes()).map_err(storage_error)?;
+
+    Ok(config)
+}
+
+// cargo test config_unit_tests
+#[cfg(test)]
+mod config_unit_tests {
+    use super::*;
+
+    #[test]
+    fn missing_settings_are_the_default() {
+        assert_eq!(toml::from_str::<Config>("").unwrap(), Config::default());
+        assert_eq!(
+            toml::from_str::<Config>("[retention]\nmax_done = 5\n").unwrap(),
+            Config {
+                retention: Retention {
+                    max_done: 5,
+                    ..Retention::default()
+                },
+                ..Config::default()
+            }
+        );
+    }
+
+    #[test]
+    fn overflow_is_read() {
+        let config: Config =
+            toml::from_str("[retention]\nmax_done_days = 7\noverflow = \"delete\"\n").unwrap();
+
+        assert_eq!(config.retention.max_done, 30);
+        assert_eq!(config.retention.max_done_days, 7);
+        assert_eq!(config.retention.overflow, Overflow::Delete);
+    }
+
+    #[test]
+    fn unknown_settings_are_errors() {
+        assert!(toml::from_str::<Config>("[retention]\nmax_dones = 5\n").is_err());
+        assert!(toml::from_str::<Config>("[retention]\noverflow = \"shred\"\n").is_err());
+        assert!(toml::from_str::<Config>("[retentoin]\n").is_err());
+        assert!(toml::from_str::<Config>("[retention]\nmax_done = -1\n").is_err());
+    }
+
+    #[test]
+    fn display_and_defaults_are_read() {
+        let config: Config = toml::from_str(
+            "[defaults]\ndeadline_time = \"eod\"\nstarter_tasks = true\n\n[display]\ntable_style = \"plain\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.defaults.deadline_time,
+            NaiveTime::from_hms_opt(23, 59, 0).unwrap()
+        );
+        assert!(config.defaults.starter_tasks);
+        assert_eq!(config.defaults.whole_list_threshold, 5);
+        assert_eq!(config.display.table_style, TableStyle::Plain);
+        assert_eq!(config.display.missed_label, "MISSED");
+        assert!(toml::from_str::<Config>("[defaults]\ndeadline_time = \"25:00\"\n").is_err());
+    }
+
+    #[test]
+    fn settings_are_gotten_by_name() {
+        let config = Config::default();
+
+        assert_eq!(
+            get_setting(&config, "display.table_style").unwrap(),
+            toml::Value::String(String::from("rounded"))
+        );
+        assert_eq!(
+            get_setting(&config, "retention.max_done").unwrap(),
+            toml::Value::Integer(30)
+        );
+        assert_eq!(
+            get_setting(&config, "display").unwrap_err().code(),
+            "invalid_setting"
+        );
+    }
+
+    #[test]
+    fn set_setting_only_changes_that_setting() {
+        let mut test_dir = std::env::temp_dir();
+        test_dir.push(format!(
+            "chartodo_config_unit_tests_set_{}",
+            std::process::id()
+        ));
+        if test_dir.exists() {
+            std::fs::remove_dir_all(&test_dir).unwrap();
+        }
+        std::fs::create_dir(&test_dir).unwrap();
+        let config_path = test_dir.join(CONFIG_FILE_NAME);
+
+        let config = set_setting(&config_path, "retention.max_done", "10").unwrap();
+        assert_eq!(config.retention.max_done, 10);
+        let config = set_setting(&config_path, "display.missed_label", "LATE").unwrap();
+        assert_eq!(config.retention.max_done, 10);
+        assert_eq!(config.display.missed_label, "LATE");
+        assert_eq!(read_config(&config_path).unwrap(), config);
+
+        // none of these are written
+        for (key, value) in [
+            ("retention.max_done", "lots"),
+            ("retention.max_done", "-1"),
+            ("defaults.starter_tasks", "no"),
+            ("display.table_style", "fancy"),
+            ("display.colour", "red"),
+        ] {
+            let setting_error = set_setting(&config_path, key, value).unwrap_err();
+            assert_eq!(setting_error.code(), "invalid_setting");
+        }
+        assert_eq!(read_config(&config_path).unwrap(), config);
+
+        // nor is anything in a file that's already broken
+        std::fs::write(&config_path, "[retention\n").unwrap();
+        let storage_error = set_setting(&config_path, "retention.max_done", "1").unwrap_err();
+        assert_eq!(storage_error.code(), "storage");
+        assert_eq!(
+            std::fs::read_to_string(&config_path).unwrap(),
+            "[retention\n"
+        );
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+
+    #[test]
+    fn set_setting_keeps_comments() {
+        let mut test_dir = std::env::temp_dir();
+        test_dir.push(format!(
+            "chartodo_config_unit_tests_comments_{}",
+            std::process::id()
+        ));
+        if test_dir.exists() {
+            std::fs::remove_dir_all(&test_dir).unwrap();
+        }
+        std::fs::create_dir(&test_dir).unwrap();
+        let config_path = test_dir.join(CONFIG_FILE_NAME);
+        std::fs::write(
+            &config_path,
+            "# how much to keep\n[retention]\nmax_done = 50 # a month or so\n\n# borders\n[display]\ntable_style = \"ascii\"\n",
+        )
+        .unwrap();
+
+        set_setting(&config_path, "retention.max_done", "10").unwrap();
+        set_setting(&config_path, "display.missed_label", "LATE").unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&config_path).unwrap(),
+            "# how much to keep\n[retention]\nmax_done = 10 # a month or so\n\n# borders\n[display]\ntable_style = \"ascii\"\nmissed_label = \"LATE\"\n"
+        );
+        assert!(!test_dir.join("config.toml.tmp").exists());
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+}
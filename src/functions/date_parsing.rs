@@ -0,0 +1,313 @@
+use crate::functions::json_file_structs::{RepeatUnit, DATE_FORMAT, TIME_FORMAT};
+use chrono::{Datelike, Days, Local, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Weekday};
+
+// every date and time a deadline or repeating command is given goes through here. besides 2099-01-01 and 13:58,
+// a date can be:
+// - today, tomorrow, or yesterday
+// - a weekday, e.g., fri, friday, or next friday, which is the soonest friday after today
+// - some time from now, e.g., +3d, +2w, in 3d, or in 3 days. the units are the same as for repeating tasks, plus
+//   min, h, d, w, mo, and y
+// - some time ago, e.g., -7d or 2 weeks ago, which is mostly for chartodo history
+// - a date w/ a time in it, e.g., 2099-01-01T08:00, eod (today at 23:59), now, +2h, or in 30min
+//
+// and a time can also be noon, midnight, or eod
+//
+// the ones w/ a space in them have to be in quotes on the command line, e.g., chartodo dl-aod meeting "next monday"
+
+// what a date turned out to be. some of them already say what time it is, see When::or_time
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum When {
+    Day(NaiveDate),
+    DayAndTime(NaiveDateTime),
+}
+
+impl When {
+    pub fn date(self) -> NaiveDate {
+        match self {
+            When::Day(date) => date,
+            When::DayAndTime(datetime) => datetime.date(),
+        }
+    }
+
+    // for commands that don't take a time, e.g., deadline-addonlydate. the time in the date is used if there is
+    // one. commands that do take a time always use that one instead, see date
+    pub fn or_time(self, time: NaiveTime) -> NaiveDateTime {
+        match self {
+            When::Day(date) => date.and_time(time),
+            When::DayAndTime(datetime) => datetime,
+        }
+    }
+}
+
+pub fn parse_date(date: &str) -> Option<When> {
+    parse_date_at(date, Local::now().naive_local())
+}
+
+// same as parse_date, but w/ what now is, so that it can be tested
+pub fn parse_date_at(date: &str, now: NaiveDateTime) -> Option<When> {
+    let date = date.trim().to_lowercase();
+    let today = now.date();
+    // seconds would never show up anywhere, so now is to the minute, same as every other due date
+    let now = today.and_hms_opt(now.hour(), now.minute(), 0)?;
+
+    if let Ok(date) = NaiveDate::parse_from_str(&date, DATE_FORMAT) {
+        return Some(When::Day(date));
+    }
+    // lowercase, so the T is a t
+    for format in ["%Y-%m-%dt%H:%M", "%Y-%m-%d %H:%M"] {
+        if let Ok(datetime) = NaiveDateTime::parse_from_str(&date, format) {
+            return Some(When::DayAndTime(datetime));
+        }
+    }
+
+    match date.as_str() {
+        "today" => return Some(When::Day(today)),
+        "tomorrow" => return Some(When::Day(today.succ_opt()?)),
+        "yesterday" => return Some(When::Day(today.pred_opt()?)),
+        "now" => return Some(When::DayAndTime(now)),
+        "eod" => return Some(When::DayAndTime(today.and_time(end_of_day()))),
+        _ => (),
+    }
+
+    let weekday = date.strip_prefix("next ").unwrap_or(&date);
+    if let Ok(weekday) = weekday.parse::<Weekday>() {
+        // never today, so fri on a friday is a week from now
+        let days_until =
+            (7 + weekday.num_days_from_monday() - today.weekday().num_days_from_monday()) % 7;
+        let days_until = match days_until {
+            0 => 7,
+            _ => days_until,
+        };
+        return Some(When::Day(
+            today.checked_add_days(Days::new(days_until.into()))?,
+        ));
+    }
+
+    if let Some(from_now) = date.strip_prefix('+').or_else(|| date.strip_prefix("in ")) {
+        let (interval, unit) = parse_offset(from_now.trim())?;
+        return match unit {
+            RepeatUnit::Minutes | RepeatUnit::Hours => {
+                Some(When::DayAndTime(unit.add_to(now, interval)?))
+            }
+            _ => Some(When::Day(
+                unit.add_to(today.and_time(NaiveTime::MIN), interval)?
+                    .date(),
+            )),
+        };
+    }
+
+    let ago = date
+        .strip_prefix('-')
+        .or_else(|| date.strip_suffix(" ago"))?
+        .trim();
+    let (interval, unit) = parse_offset(ago)?;
+    match unit {
+        RepeatUnit::Minutes | RepeatUnit::Hours => {
+            Some(When::DayAndTime(unit.subtract_from(now, interval)?))
+        }
+        _ => Some(When::Day(
+            unit.subtract_from(today.and_time(NaiveTime::MIN), interval)?
+                .date(),
+        )),
+    }
+}
+
+// e.g., 3d, 3 days, or 1 week
+fn parse_offset(offset: &str) -> Option<(u32, RepeatUnit)> {
+    let unit_starts_at = offset.find(|character: char| !character.is_ascii_digit())?;
+    let (interval, unit) = offset.split_at(unit_starts_at);
+    let interval = interval.parse::<u32>().ok()?;
+
+    let unit = match unit.trim() {
+        "min" | "mins" => RepeatUnit::Minutes,
+        "h" | "hr" | "hrs" => RepeatUnit::Hours,
+        "d" => RepeatUnit::Days,
+        "w" => RepeatUnit::Weeks,
+        "mo" => RepeatUnit::Months,
+        "y" | "yr" | "yrs" => RepeatUnit::Years,
+        unit => unit.parse().ok()?,
+    };
+
+    Some((interval, unit))
+}
+
+fn end_of_day() -> NaiveTime {
+    NaiveTime::from_hms_opt(23, 59, 0).unwrap()
+}
+
+pub fn parse_time(time: &str) -> Option<NaiveTime> {
+    let time = time.trim().to_lowercase();
+
+    match time.as_str() {
+        "noon" => NaiveTime::from_hms_opt(12, 0, 0),
+        "midnight" => Some(NaiveTime::MIN),
+        "eod" => Some(end_of_day()),
+        _ => NaiveTime::parse_from_str(&time, TIME_FORMAT).ok(),
+    }
+}
+
+// for --until on the repeating add commands, and history --to. a date w/o a time in it lasts to the end of the day, so
+// the task is still due on it
+pub fn parse_until(until: &str) -> Result<NaiveDateTime, String> {
+    parse_date(until)
+        .map(|when| when.or_time(end_of_day()))
+        .ok_or_else(|| {
+            format!(
+                "'{}' isn't a date. Try a year-month-day date, e.g., 2099-12-25, or 2099-12-25T08:00, +6w, or 'next friday'",
+                until
+            )
+        })
+}
+
+// for history --from. a date w/o a time in it starts at the start of the day
+pub fn parse_from(from: &str) -> Result<NaiveDateTime, String> {
+    parse_date(from)
+        .map(|when| when.or_time(NaiveTime::MIN))
+        .ok_or_else(|| {
+            format!(
+                "'{}' isn't a date. Try a year-month-day date, e.g., 2099-12-25, or 2099-12-25T08:00, yesterday, -7d, or '2 weeks ago'",
+                from
+            )
+        })
+}
+
+// cargo test date_parsing_unit_tests
+#[cfg(test)]
+mod date_parsing_unit_tests {
+    use super::*;
+
+    // a wednesday
+    fn now() -> NaiveDateTime {
+        NaiveDateTime::parse_from_str("2099-01-07 13:45:30", "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    fn day(date: &str) -> Option<When> {
+        Some(When::Day(
+            NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+        ))
+    }
+
+    fn day_and_time(datetime: &str) -> Option<When> {
+        Some(When::DayAndTime(
+            NaiveDateTime::parse_from_str(datetime, "%Y-%m-%d %H:%M").unwrap(),
+        ))
+    }
+
+    #[test]
+    fn plain_dates_are_correct() {
+        assert_eq!(parse_date_at("2099-12-25", now()), day("2099-12-25"));
+        assert_eq!(
+            parse_date_at("2099-12-25T08:00", now()),
+            day_and_time("2099-12-25 08:00")
+        );
+        assert_eq!(
+            parse_date_at("2099-12-25 08:00", now()),
+            day_and_time("2099-12-25 08:00")
+        );
+        assert_eq!(parse_date_at("2099-13-25", now()), None);
+        assert_eq!(parse_date_at("2099-12-25T25:00", now()), None);
+    }
+
+    #[test]
+    fn until_lasts_to_the_end_of_the_day() {
+        assert_eq!(
+            parse_until("2099-12-25"),
+            Ok(NaiveDateTime::parse_from_str("2099-12-25 23:59", "%Y-%m-%d %H:%M").unwrap())
+        );
+        assert_eq!(
+            parse_until("2099-12-25T08:00"),
+            Ok(NaiveDateTime::parse_from_str("2099-12-25 08:00", "%Y-%m-%d %H:%M").unwrap())
+        );
+        assert!(parse_until("someday").is_err());
+    }
+
+    #[test]
+    fn named_days_are_correct() {
+        assert_eq!(parse_date_at("today", now()), day("2099-01-07"));
+        assert_eq!(parse_date_at("Tomorrow", now()), day("2099-01-08"));
+        assert_eq!(parse_date_at("yesterday", now()), day("2099-01-06"));
+        assert_eq!(
+            parse_date_at("now", now()),
+            day_and_time("2099-01-07 13:45")
+        );
+        assert_eq!(
+            parse_date_at("eod", now()),
+            day_and_time("2099-01-07 23:59")
+        );
+    }
+
+    #[test]
+    fn weekdays_are_correct() {
+        assert_eq!(parse_date_at("fri", now()), day("2099-01-09"));
+        assert_eq!(parse_date_at("friday", now()), day("2099-01-09"));
+        assert_eq!(parse_date_at("next monday", now()), day("2099-01-12"));
+        // never today
+        assert_eq!(parse_date_at("wed", now()), day("2099-01-14"));
+        assert_eq!(parse_date_at("next someday", now()), None);
+    }
+
+    #[test]
+    fn from_now_is_correct() {
+        assert_eq!(parse_date_at("+3d", now()), day("2099-01-10"));
+        assert_eq!(parse_date_at("in 2w", now()), day("2099-01-21"));
+        assert_eq!(parse_date_at("in 1 month", now()), day("2099-02-07"));
+        assert_eq!(parse_date_at("+1y", now()), day("2100-01-07"));
+        assert_eq!(
+            parse_date_at("in 2h", now()),
+            day_and_time("2099-01-07 15:45")
+        );
+        assert_eq!(
+            parse_date_at("+30min", now()),
+            day_and_time("2099-01-07 14:15")
+        );
+        assert_eq!(parse_date_at("+3", now()), None);
+        assert_eq!(parse_date_at("+d", now()), None);
+        assert_eq!(parse_date_at("in 3 fortnights", now()), None);
+    }
+
+    #[test]
+    fn ago_is_correct() {
+        assert_eq!(parse_date_at("-7d", now()), day("2098-12-31"));
+        assert_eq!(parse_date_at("2 weeks ago", now()), day("2098-12-24"));
+        assert_eq!(
+            parse_date_at("3h ago", now()),
+            day_and_time("2099-01-07 10:45")
+        );
+        assert_eq!(parse_date_at("-d", now()), None);
+        assert_eq!(parse_date_at("ago", now()), None);
+    }
+
+    #[test]
+    fn from_starts_at_the_start_of_the_day() {
+        assert_eq!(
+            parse_from("2099-12-25"),
+            Ok(NaiveDateTime::parse_from_str("2099-12-25 00:00", "%Y-%m-%d %H:%M").unwrap())
+        );
+        assert!(parse_from("someday").is_err());
+    }
+
+    #[test]
+    fn times_are_correct() {
+        let time = |time: &str| NaiveTime::parse_from_str(time, "%H:%M").ok();
+
+        assert_eq!(parse_time("08:30"), time("08:30"));
+        assert_eq!(parse_time("noon"), time("12:00"));
+        assert_eq!(parse_time("Midnight"), time("00:00"));
+        assert_eq!(parse_time("eod"), time("23:59"));
+        assert_eq!(parse_time("24:00"), None);
+    }
+
+    #[test]
+    fn or_time_is_correct() {
+        let eight = NaiveTime::from_hms_opt(8, 0, 0).unwrap();
+
+        assert_eq!(
+            day("2099-01-01").unwrap().or_time(eight),
+            NaiveDateTime::parse_from_str("2099-01-01 08:00", "%Y-%m-%d %H:%M").unwrap()
+        );
+        assert_eq!(
+            day_and_time("2099-01-01 13:00").unwrap().or_time(eight),
+            NaiveDateTime::parse_from_str("2099-01-01 13:00", "%Y-%m-%d %H:%M").unwrap()
+        );
+    }
+}
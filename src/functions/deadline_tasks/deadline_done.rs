@@ -1,352 +1,131 @@
-use super::deadline_helpers::*;
-use crate::functions::general_helpers::{check_if_range_positioning, unwrap_range_positioning};
-use std::io::Write;
-
-pub fn deadline_tasks_rmdone(mut done_remove: Vec<String>) -> bool {
-    // housekeeping
-    deadline_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
+use crate::functions::errors::ChartodoError;
+use crate::functions::general_helpers::archive_done_tasks;
+use crate::functions::storage_helpers::{open_task_store, save_task_store};
+use crate::functions::task_ids::PositionsIn;
+use crate::functions::task_store::{PositionErrors, TaskList};
 
+pub fn deadline_tasks_rmdone(done_remove: Vec<String>) -> Result<(), ChartodoError> {
     // open file and parse
-    let mut deadline_tasks = open_deadline_tasks_and_return_tasks_struct();
-
-    // check if the done list is empty
-    if deadline_tasks.done.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The deadline done list is currently empty, so you can't remove any items."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // for the record, i hate that this is a separate iteration
-    // go thru list and check if an item is ranged. if yes, unwrap it and push to original list
-    for i in (0..done_remove.len()).rev() {
-        let (error_or_not, bound1, bound2) = check_if_range_positioning(
-            done_remove.get(i).unwrap().to_string(),
-            deadline_tasks.done.len(),
-        );
-
-        if !error_or_not {
-            let unwrapped_range = unwrap_range_positioning(bound1, bound2);
-            unwrapped_range
-                .iter()
-                .for_each(|number| done_remove.push(number.to_string()));
-            // this is not good
-        }
-    }
-
-    // filter for viable items
-    for i in (0..done_remove.len()).rev() {
-        if done_remove.get(i).unwrap().parse::<usize>().is_err()
-        || done_remove.get(i).unwrap().is_empty() // this will never trigger smh
-        || done_remove.get(i).unwrap().parse::<usize>().unwrap() == 0
-        || done_remove.get(i).unwrap().parse::<usize>().unwrap() > deadline_tasks.done.len()
-        {
-            done_remove.swap_remove(i);
-        }
-    }
-
-    if done_remove.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: None of the positions you provided were viable \
-            -- they were all either negative, zero, or exceeded the deadline done list's \
-            length."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // sort and dedup
-    let mut done_remove: Vec<usize> = done_remove
-        .iter()
-        .map(|x| x.parse::<usize>().unwrap())
-        .collect();
-    done_remove.sort();
-    done_remove.dedup();
-
-    // check if user wants to remove all of the items
-    if done_remove.len() >= deadline_tasks.done.len() && deadline_tasks.done.len() > 5 {
-        writeln!(
-            writer,
-            "WARNING: You might as well do deadline-cleardone since you want to \
-            remove all of the items."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // remove each item one by one
-    done_remove.iter().rev().for_each(|position| {
-        deadline_tasks.done.remove(position - 1);
-    });
+    let mut store = open_task_store()?;
+
+    // unwrap any ranges and check the positions
+    let done_remove = store.positions_from_args(
+        PositionsIn::DeadlineDone,
+        &done_remove,
+        &PositionErrors {
+            empty: "The deadline done list is currently empty, so you can't remove any items.",
+            none_viable: "None of the positions you provided were viable \
+                -- they were all either negative, zero, or exceeded the deadline done list's \
+                length.",
+            whole_list: Some(
+                "You might as well do deadline-cleardone since you want to \
+                remove all of the items.",
+            ),
+        },
+    )?;
+
+    // remove the items
+    store.remove(PositionsIn::DeadlineDone, &done_remove)?;
 
     // write changes to file
-    write_changes_to_new_deadline_tasks(deadline_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn deadline_tasks_not_done(mut not_done: Vec<String>) -> bool {
-    // housekeeping
-    deadline_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn deadline_tasks_not_done(not_done: Vec<String>) -> Result<(), ChartodoError> {
     // open file and parse
-    let mut deadline_tasks = open_deadline_tasks_and_return_tasks_struct();
-
-    // check if the done list is empty
-    if deadline_tasks.done.is_empty() {
-        writeln!(writer, "ERROR: The deadline done list is currently empty.")
-            .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // for the record, i hate that this is a separate iteration
-    // go thru list and check if an item is ranged. if yes, unwrap it and push to original list
-    for i in (0..not_done.len()).rev() {
-        let (error_or_not, bound1, bound2) = check_if_range_positioning(
-            not_done.get(i).unwrap().to_string(),
-            deadline_tasks.done.len(),
-        );
-
-        if !error_or_not {
-            let unwrapped_range = unwrap_range_positioning(bound1, bound2);
-            unwrapped_range
-                .iter()
-                .for_each(|number| not_done.push(number.to_string()));
-            // this is not good
-        }
-    }
-
-    // filter for viable items
-    for i in (0..not_done.len()).rev() {
-        if not_done.get(i).unwrap().parse::<usize>().is_err()
-        || not_done.get(i).unwrap().is_empty() // this will never trigger smh
-        || not_done.get(i).unwrap().parse::<usize>().unwrap() == 0
-        || not_done.get(i).unwrap().parse::<usize>().unwrap() > deadline_tasks.done.len()
-        {
-            not_done.swap_remove(i);
-        }
-    }
-
-    if not_done.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: None of the positions you provided were viable \
-            -- they were all either negative, zero, or exceeded the deadline done list's \
-            length."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // sort and dedup
-    let mut not_done: Vec<usize> = not_done
-        .iter()
-        .map(|x| x.parse::<usize>().unwrap())
-        .collect();
-    not_done.sort();
-    not_done.dedup();
-
-    // check if user wants to remove all done items to todo
-    if not_done.len() >= deadline_tasks.done.len() && deadline_tasks.done.len() > 5 {
-        writeln!(
-            writer,
-            "WARNING: You might as well do deadline-notdoneall \
-            since you want to reverse all deadline done items."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // reverse dones one by one
-    not_done.iter().rev().for_each(|position| {
-        deadline_tasks
-            .todo
-            .push(deadline_tasks.done.get(position - 1).unwrap().to_owned());
-        deadline_tasks.done.remove(position - 1);
-    });
+    let mut store = open_task_store()?;
+
+    // unwrap any ranges and check the positions
+    let not_done = store.positions_from_args(
+        PositionsIn::DeadlineDone,
+        &not_done,
+        &PositionErrors {
+            empty: "The deadline done list is currently empty.",
+            none_viable: "None of the positions you provided were viable \
+                -- they were all either negative, zero, or exceeded the deadline done list's \
+                length.",
+            whole_list: Some(
+                "You might as well do deadline-notdoneall \
+                since you want to reverse all deadline done items.",
+            ),
+        },
+    )?;
+
+    // reverse dones
+    store.uncomplete(TaskList::Deadline, &not_done)?;
 
     // write changes to file
-    write_changes_to_new_deadline_tasks(deadline_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn deadline_tasks_clear_done() -> bool {
-    // housekeeping
-    deadline_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
+// moves done items to the archive instead of removing them. Has chaining and range positioning
+pub fn deadline_tasks_archive_done(done_to_archive: Vec<String>) -> Result<(), ChartodoError> {
+    archive_done_tasks(TaskList::Deadline, done_to_archive, "deadline-cleardone")
+}
 
+// w/ archive, the done items are moved to the archive instead of being deleted
+pub fn deadline_tasks_clear_done(archive: bool) -> Result<(), ChartodoError> {
     // open file and parse
-    let mut deadline_tasks = open_deadline_tasks_and_return_tasks_struct();
+    let mut store = open_task_store()?;
+    let deadline_tasks = store.deadline();
 
     // check if the done list is empty
     if deadline_tasks.done.is_empty() {
-        writeln!(writer, "ERROR: The deadline done list is currently empty.")
-            .expect("writeln failed");
-
-        // error = true
-        return true;
+        return Err(ChartodoError::EmptyList(
+            "The deadline done list is currently empty.".to_string(),
+        ));
     }
 
     // clear done list
-    deadline_tasks.done.clear();
+    match archive {
+        true => store.archive_all(TaskList::Deadline),
+        false => store.clear(PositionsIn::DeadlineDone),
+    }
 
     // write changes to file
-    write_changes_to_new_deadline_tasks(deadline_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn deadline_tasks_notdoneall() -> bool {
-    // housekeeping
-    deadline_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn deadline_tasks_notdoneall() -> Result<(), ChartodoError> {
     // open file and parse
-    let mut deadline_tasks = open_deadline_tasks_and_return_tasks_struct();
+    let mut store = open_task_store()?;
+    let deadline_tasks = store.deadline();
 
     // check if the done list is empty
     if deadline_tasks.done.is_empty() {
-        writeln!(writer, "ERROR: The deadline done list is currently empty.")
-            .expect("writeln failed");
-
-        // error = true
-        return true;
+        return Err(ChartodoError::EmptyList(
+            "The deadline done list is currently empty.".to_string(),
+        ));
     }
 
     // reverse all done items
-    deadline_tasks
-        .done
-        .iter()
-        .for_each(|item| deadline_tasks.todo.push(item.clone()));
-    deadline_tasks.done.clear();
+    store.uncomplete_all(TaskList::Deadline);
 
     // write changes to file
-    write_changes_to_new_deadline_tasks(deadline_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-// cargo test deadline_done_unit_tests -- --test-threads=1
+// cargo test deadline_done_unit_tests
 #[cfg(test)]
 mod deadline_done_unit_tests {
+    use super::super::deadline_helpers::*;
     use super::*;
     use crate::functions::json_file_structs::*;
+    use crate::functions::storage_helpers::test_data_dir;
     use anyhow::Context;
-    use std::path::PathBuf;
-
-    // these are taken from deadline_helpers
-    fn path_to_deadline_tasks() -> PathBuf {
-        // get the data dir XDG spec and return it with path to deadline_tasks.json
-        let mut deadline_tasks_path = dirs::data_dir()
-            .context(
-                "linux: couldn't get $HOME/.local/share/
-                    windows: couldn't get C:/Users/your_user/AppData/Local/
-                    mac: couldn't get /Users/your_user/Library/Application Support/
-
-                    those directories should exist for your OS. please double check that they do.",
-            )
-            .expect("something went wrong with fetching the user's data dirs");
-        deadline_tasks_path.push("chartodo/deadline_tasks.json");
-
-        deadline_tasks_path
-    }
-
-    fn deadline_tasks_copy_path() -> PathBuf {
-        // get the path for deadline_tasks_copy.json, which will be used to hold the original contents
-        // of deadline_tasks.json while it's getting modified
-        let mut deadline_tasks_copy_path = dirs::data_dir()
-            .context(
-                "linux: couldn't get $HOME/.local/share/
-                    windows: couldn't get C:/Users/your_user/AppData/Local/
-                    mac: couldn't get /Users/your_user/Library/Application Support/
-
-                    those directories should exist for your OS. please double check that they do.",
-            )
-            .expect("something went wrong with fetching the user's data dirs");
-        deadline_tasks_copy_path.push("chartodo/deadline_tasks_copy.json");
-
-        deadline_tasks_copy_path
-    }
-
-    // these have been tested in other fns, these are just included here as a sanity check
-    #[test]
-    fn deadline_tasks_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/deadline_tasks.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/deadline_tasks.json";
-        let mac_path = "/Library/Application Support/chartodo/deadline_tasks.json";
-        let mut got_deadline_tasks_path: bool = false;
-        let deadline_path = path_to_deadline_tasks();
-        let deadline_path = deadline_path.to_str().unwrap();
-
-        if deadline_path.contains(linux_path)
-            | deadline_path.contains(windows_path)
-            | deadline_path.contains(mac_path)
-        {
-            got_deadline_tasks_path = true;
-        }
-
-        assert!(got_deadline_tasks_path);
-    }
-
-    #[test]
-    fn deadline_tasks_copy_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/deadline_tasks_copy.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/deadline_tasks_copy.json";
-        let mac_path = "/Library/Application Support/chartodo/deadline_tasks_copy.json";
-        let mut got_deadline_tasks_copy_path: bool = false;
-        let deadline_tasks_copy_path = deadline_tasks_copy_path();
-        let deadline_tasks_copy_path = deadline_tasks_copy_path.to_str().unwrap();
-
-        if deadline_tasks_copy_path.contains(linux_path)
-            | deadline_tasks_copy_path.contains(windows_path)
-            | deadline_tasks_copy_path.contains(mac_path)
-        {
-            got_deadline_tasks_copy_path = true;
-        }
-
-        assert!(got_deadline_tasks_copy_path);
-    }
-
-    #[test]
-    fn aaaa_deadline_tasks_clone_file() {
-        // name is aaaa so it's done first
-        // since we will be modifying the original file to run a test, the original data must be
-        // preserved first
-        std::fs::File::create(deadline_tasks_copy_path())
-            .context("failed to create deadline_tasks_copy.json")
-            .expect("failed to create a copy during unit test");
-
-        std::fs::copy(path_to_deadline_tasks(), deadline_tasks_copy_path())
-            .context("failed to copy deadline_tasks.json to deadline_tasks_copy.json")
-            .expect("failed to copy original file to copy file during unit test");
-    }
 
     #[test]
     fn deadline_tasks_rmdone_done_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -364,13 +143,15 @@ mod deadline_done_unit_tests {
 
         // perform actions
         let arguments = vec![String::from("don't matter")];
-        let error_should_be_true = deadline_tasks_rmdone(arguments);
+        let error_should_be_true = deadline_tasks_rmdone(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn deadline_tasks_rmdone_no_valid_args() {
+        let _data_dir = test_data_dir();
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -399,13 +180,15 @@ mod deadline_done_unit_tests {
 
         // check that there are no valid args
         let arguments = vec![String::from("-11"), String::from("0"), String::from("2")];
-        let error_should_be_true = deadline_tasks_rmdone(arguments);
+        let error_should_be_true = deadline_tasks_rmdone(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn deadline_tasks_rmdone_should_do_deadlinecleardone() {
+        let _data_dir = test_data_dir();
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -492,13 +275,15 @@ mod deadline_done_unit_tests {
             String::from("6"),
             String::from("1"),
         ];
-        let error_should_be_true = deadline_tasks_rmdone(arguments);
+        let error_should_be_true = deadline_tasks_rmdone(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn deadline_tasks_rmdone_should_do_deadlinecleardone_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -577,13 +362,15 @@ mod deadline_done_unit_tests {
 
         // check that there are no valid args
         let arguments = vec![String::from("1-6"), String::from("1")];
-        let error_should_be_true = deadline_tasks_rmdone(arguments);
+        let error_should_be_true = deadline_tasks_rmdone(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn deadline_tasks_rmdone_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -612,7 +399,7 @@ mod deadline_done_unit_tests {
 
         // perform actions
         let arguments = vec![String::from("1")];
-        let error_should_be_false = deadline_tasks_rmdone(arguments);
+        let error_should_be_false = deadline_tasks_rmdone(arguments).is_err();
         let read_test_file = open_deadline_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -635,6 +422,8 @@ mod deadline_done_unit_tests {
 
     #[test]
     fn deadline_tasks_rmdone_multiple_args_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -683,7 +472,7 @@ mod deadline_done_unit_tests {
 
         // perform actions
         let arguments = vec![String::from("1"), String::from("3")];
-        let error_should_be_false = deadline_tasks_rmdone(arguments);
+        let error_should_be_false = deadline_tasks_rmdone(arguments).is_err();
         let read_test_file = open_deadline_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -717,6 +506,8 @@ mod deadline_done_unit_tests {
 
     #[test]
     fn deadline_tasks_rmdone_multiple_args_is_correct_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -765,7 +556,7 @@ mod deadline_done_unit_tests {
 
         // perform actions
         let arguments = vec![String::from("2-3")];
-        let error_should_be_false = deadline_tasks_rmdone(arguments);
+        let error_should_be_false = deadline_tasks_rmdone(arguments).is_err();
         let read_test_file = open_deadline_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -799,6 +590,8 @@ mod deadline_done_unit_tests {
 
     #[test]
     fn deadline_tasks_notdone_done_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -816,13 +609,15 @@ mod deadline_done_unit_tests {
 
         // perform actions
         let arguments = vec![String::from("don't matter")];
-        let error_should_be_true = deadline_tasks_not_done(arguments);
+        let error_should_be_true = deadline_tasks_not_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn deadline_tasks_notdone_no_valid_args() {
+        let _data_dir = test_data_dir();
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -851,13 +646,15 @@ mod deadline_done_unit_tests {
 
         // check that there are no valid args
         let arguments = vec![String::from("-11"), String::from("0"), String::from("2")];
-        let error_should_be_true = deadline_tasks_not_done(arguments);
+        let error_should_be_true = deadline_tasks_not_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn deadline_tasks_notdone_should_do_deadlinenotdoneall() {
+        let _data_dir = test_data_dir();
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -944,13 +741,15 @@ mod deadline_done_unit_tests {
             String::from("6"),
             String::from("1"),
         ];
-        let error_should_be_true = deadline_tasks_not_done(arguments);
+        let error_should_be_true = deadline_tasks_not_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn deadline_tasks_notdone_should_do_deadlinenotdoneall_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1029,13 +828,15 @@ mod deadline_done_unit_tests {
 
         // check that there are no valid args
         let arguments = vec![String::from("1-6"), String::from("1")];
-        let error_should_be_true = deadline_tasks_not_done(arguments);
+        let error_should_be_true = deadline_tasks_not_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn deadline_tasks_notdone_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1064,7 +865,7 @@ mod deadline_done_unit_tests {
 
         // perform actions
         let arguments = vec![String::from("1")];
-        let error_should_be_false = deadline_tasks_not_done(arguments);
+        let error_should_be_false = deadline_tasks_not_done(arguments).is_err();
         let read_test_file = open_deadline_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -1098,6 +899,8 @@ mod deadline_done_unit_tests {
 
     #[test]
     fn deadline_tasks_notdone_multiple_args_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1146,7 +949,7 @@ mod deadline_done_unit_tests {
 
         // perform actions
         let arguments = vec![String::from("1"), String::from("3")];
-        let error_should_be_false = deadline_tasks_not_done(arguments);
+        let error_should_be_false = deadline_tasks_not_done(arguments).is_err();
         let read_test_file = open_deadline_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -1201,6 +1004,8 @@ mod deadline_done_unit_tests {
 
     #[test]
     fn deadline_tasks_notdone_multiple_args_is_correct_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1249,7 +1054,7 @@ mod deadline_done_unit_tests {
 
         // perform actions
         let arguments = vec![String::from("2-3")];
-        let error_should_be_false = deadline_tasks_not_done(arguments);
+        let error_should_be_false = deadline_tasks_not_done(arguments).is_err();
         let read_test_file = open_deadline_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -1304,6 +1109,8 @@ mod deadline_done_unit_tests {
 
     #[test]
     fn deadline_tasks_cleardone_done_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1320,13 +1127,15 @@ mod deadline_done_unit_tests {
         write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
 
         // perform actions
-        let error_should_be_true = deadline_tasks_clear_done();
+        let error_should_be_true = deadline_tasks_clear_done(false).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn deadline_tasks_cleardone_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1354,7 +1163,7 @@ mod deadline_done_unit_tests {
         write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
 
         // perform actions
-        let error_should_be_false = deadline_tasks_clear_done();
+        let error_should_be_false = deadline_tasks_clear_done(false).is_err();
         let read_test_file = open_deadline_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -1377,6 +1186,8 @@ mod deadline_done_unit_tests {
 
     #[test]
     fn deadline_tasks_notdoneall_done_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1393,13 +1204,15 @@ mod deadline_done_unit_tests {
         write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
 
         // perform actions
-        let error_should_be_true = deadline_tasks_notdoneall();
+        let error_should_be_true = deadline_tasks_notdoneall().is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn deadline_tasks_notdoneall_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1447,7 +1260,7 @@ mod deadline_done_unit_tests {
         write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
 
         // perform actions
-        let error_should_be_false = deadline_tasks_notdoneall();
+        let error_should_be_false = deadline_tasks_notdoneall().is_err();
         let read_test_file = open_deadline_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -1498,18 +1311,4 @@ mod deadline_done_unit_tests {
         assert!(!error_should_be_false);
         assert_eq!(read_test_file, deadline_tasks);
     }
-
-    #[test]
-    fn zzzz_rename_copy_to_original() {
-        // name is zzzz so it's done last
-        // now that tests are done, remove the modified original and rename copy to original
-
-        std::fs::remove_file(path_to_deadline_tasks())
-            .context("failed delete modified deadline_tasks.json after running tests")
-            .expect("failed to delete deadline_tasks.json after deadline_helpers unit tests");
-
-        std::fs::rename(deadline_tasks_copy_path(), path_to_deadline_tasks())
-            .context("failed to rename deadline_tasks_copy to deadline_tasks")
-            .expect("failed to rename deadline_tasks_copy to deadline_tasks after tests were done");
-    }
 }
@@ -0,0 +1,227 @@
+use crate::functions::{
+    json_file_structs::{Repeat, RepeatUnit, DATE_FORMAT, TIME_FORMAT},
+    recurrence::RepeatOn,
+};
+use std::fmt;
+
+// every way a command can refuse to do what it was asked. each one holds the message shown to the user, which is
+// printed to stderr as ERROR: [message] (or WARNING: for WholeListSpecified), and decides the exit code and the
+// code in the json result. nothing is ever changed when a command returns one of these
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChartodoError {
+    // the command, or the number of args it got, isn't right
+    UnknownCommand(String),
+    WrongNumberOfArguments(String),
+    // every position in a list was given, when there's a command that does exactly that, e.g., doneall
+    WholeListSpecified(String),
+    // the positions or ids don't point at a task
+    InvalidPosition(String),
+    InvalidId(String),
+    // one of the values isn't proper
+    InvalidDate(String),
+    InvalidTime(String),
+    InvalidTimeUnit(String),
+    InvalidInterval(String),
+    InvalidStartOrEnd(String),
+    InvalidPriority(String),
+    InvalidTag(String),
+    // config set was given a setting that doesn't exist, or a value that doesn't fit it. also CHARTODO_LOCK_TIMEOUT
+    // when it isn't a number of seconds
+    InvalidSetting(String),
+    // search --regex was given something that isn't a regex
+    InvalidPattern(String),
+    // there's nothing for the command to work on
+    EmptyList(String),
+    MissingTag(String),
+    NothingToUndo(String),
+    NothingToRedo(String),
+    // init was run where there are already tasks
+    AlreadyInitialized(String),
+    // a file in the chartodo folder, e.g., chartodo.json or config.toml, couldn't be read or written, or the folder
+    // itself couldn't be found or created
+    Storage(String),
+    // another chartodo held chartodo.lock for longer than the timeout, see file_lock
+    Locked(String),
+}
+
+impl ChartodoError {
+    fn message(&self) -> &str {
+        match self {
+            ChartodoError::UnknownCommand(message)
+            | ChartodoError::WrongNumberOfArguments(message)
+            | ChartodoError::WholeListSpecified(message)
+            | ChartodoError::InvalidPosition(message)
+            | ChartodoError::InvalidId(message)
+            | ChartodoError::InvalidDate(message)
+            | ChartodoError::InvalidTime(message)
+            | ChartodoError::InvalidTimeUnit(message)
+            | ChartodoError::InvalidInterval(message)
+            | ChartodoError::InvalidStartOrEnd(message)
+            | ChartodoError::InvalidPriority(message)
+            | ChartodoError::InvalidTag(message)
+            | ChartodoError::InvalidSetting(message)
+            | ChartodoError::InvalidPattern(message)
+            | ChartodoError::EmptyList(message)
+            | ChartodoError::MissingTag(message)
+            | ChartodoError::NothingToUndo(message)
+            | ChartodoError::NothingToRedo(message)
+            | ChartodoError::AlreadyInitialized(message)
+            | ChartodoError::Storage(message)
+            | ChartodoError::Locked(message) => message,
+        }
+    }
+
+    // the code in the json result, see print_json_result
+    pub fn code(&self) -> &'static str {
+        match self {
+            ChartodoError::UnknownCommand(_) => "unknown_command",
+            ChartodoError::WrongNumberOfArguments(_) => "wrong_number_of_arguments",
+            ChartodoError::WholeListSpecified(_) => "whole_list_specified",
+            ChartodoError::InvalidPosition(_) => "invalid_position",
+            ChartodoError::InvalidId(_) => "invalid_id",
+            ChartodoError::InvalidDate(_) => "invalid_date",
+            ChartodoError::InvalidTime(_) => "invalid_time",
+            ChartodoError::InvalidTimeUnit(_) => "invalid_time_unit",
+            ChartodoError::InvalidInterval(_) => "invalid_interval",
+            ChartodoError::InvalidStartOrEnd(_) => "invalid_start_or_end",
+            ChartodoError::InvalidPriority(_) => "invalid_priority",
+            ChartodoError::InvalidTag(_) => "invalid_tag",
+            ChartodoError::InvalidSetting(_) => "invalid_setting",
+            ChartodoError::InvalidPattern(_) => "invalid_pattern",
+            ChartodoError::EmptyList(_) => "empty_list",
+            ChartodoError::MissingTag(_) => "missing_tag",
+            ChartodoError::NothingToUndo(_) => "nothing_to_undo",
+            ChartodoError::NothingToRedo(_) => "nothing_to_redo",
+            ChartodoError::AlreadyInitialized(_) => "already_initialized",
+            ChartodoError::Storage(_) => "storage",
+            ChartodoError::Locked(_) => "locked",
+        }
+    }
+
+    // one exit code per class of error, so that scripts can tell them apart w/o reading the message. 1 is for
+    // chartodo.json being broken or too new, and 2 is the same code clap exits w/ when it rejects the args itself. 6
+    // is for another chartodo being in the way, which is worth trying again later, e.g., from a cron job
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            ChartodoError::Storage(_) => 1,
+            ChartodoError::UnknownCommand(_)
+            | ChartodoError::WrongNumberOfArguments(_)
+            | ChartodoError::WholeListSpecified(_) => 2,
+            ChartodoError::InvalidPosition(_) | ChartodoError::InvalidId(_) => 3,
+            ChartodoError::InvalidDate(_)
+            | ChartodoError::InvalidTime(_)
+            | ChartodoError::InvalidTimeUnit(_)
+            | ChartodoError::InvalidInterval(_)
+            | ChartodoError::InvalidStartOrEnd(_)
+            | ChartodoError::InvalidPriority(_)
+            | ChartodoError::InvalidTag(_)
+            | ChartodoError::InvalidSetting(_)
+            | ChartodoError::InvalidPattern(_) => 4,
+            ChartodoError::EmptyList(_)
+            | ChartodoError::MissingTag(_)
+            | ChartodoError::NothingToUndo(_)
+            | ChartodoError::NothingToRedo(_)
+            | ChartodoError::AlreadyInitialized(_) => 5,
+            ChartodoError::Locked(_) => 6,
+        }
+    }
+
+    // a whole list being specified isn't wrong as such, there's just a better command for it
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChartodoError::WholeListSpecified(_) => "WARNING",
+            _ => "ERROR",
+        }
+    }
+}
+
+// the only way to get here is w/ a huge interval, e.g., 4294967295 years, since chrono can't count that far
+pub fn interval_out_of_range(task: &str) -> ChartodoError {
+    ChartodoError::InvalidInterval(format!(
+        "The interval for '{}' is too big. Its due date would be too far in the future, or its start too far in \
+        the past, to keep track of. Try a smaller interval.",
+        task
+    ))
+}
+
+// a rule only works w/ one unit, e.g., mon,wed,fri w/ weeks, see recurrence
+pub fn check_rule_fits(
+    task: &str,
+    unit: RepeatUnit,
+    on: Option<RepeatOn>,
+) -> Result<(), ChartodoError> {
+    match on {
+        Some(on) if on.unit() != unit => Err(ChartodoError::InvalidTimeUnit(format!(
+            "'{}' can't repeat every so many {} on {}. That rule only works w/ {}.",
+            task,
+            unit,
+            on,
+            on.unit()
+        ))),
+        _ => Ok(()),
+    }
+}
+
+// a new series has to have at least one occurrence before it ends, see RepeatRules
+pub fn check_end_fits(task: &str, repeat: &Repeat) -> Result<(), ChartodoError> {
+    if repeat.max_occurrences == Some(0) {
+        return Err(ChartodoError::InvalidInterval(format!(
+            "'{}' can't repeat 0 times. Try 1 or more.",
+            task
+        )));
+    }
+
+    match repeat.until {
+        Some(until) if repeat.due > until => Err(ChartodoError::InvalidDate(format!(
+            "'{}' would first be due on {} {}, which is after it ends on {} {}. Try a later until.",
+            task,
+            repeat.due.format(DATE_FORMAT),
+            repeat.due.format(TIME_FORMAT),
+            until.format(DATE_FORMAT),
+            until.format(TIME_FORMAT)
+        ))),
+        _ => Ok(()),
+    }
+}
+
+impl fmt::Display for ChartodoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl std::error::Error for ChartodoError {}
+
+// cargo test errors_unit_tests
+#[cfg(test)]
+mod errors_unit_tests {
+    use super::*;
+
+    #[test]
+    fn exit_codes_are_by_class() {
+        let usage = ChartodoError::WrongNumberOfArguments(String::from("x"));
+        let position = ChartodoError::InvalidId(String::from("x"));
+        let value = ChartodoError::InvalidDate(String::from("x"));
+        let nothing = ChartodoError::EmptyList(String::from("x"));
+        let locked = ChartodoError::Locked(String::from("x"));
+
+        assert_eq!(usage.exit_code(), 2);
+        assert_eq!(position.exit_code(), 3);
+        assert_eq!(value.exit_code(), 4);
+        assert_eq!(nothing.exit_code(), 5);
+        assert_eq!(locked.exit_code(), 6);
+    }
+
+    #[test]
+    fn message_and_code_are_correct() {
+        let error = ChartodoError::InvalidPosition(String::from("The position was invalid."));
+
+        assert_eq!(error.to_string(), "The position was invalid.");
+        assert_eq!(error.code(), "invalid_position");
+        assert_eq!(error.label(), "ERROR");
+        assert_eq!(
+            ChartodoError::WholeListSpecified(String::from("x")).label(),
+            "WARNING"
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use crate::functions::errors::ChartodoError;
+use std::{
+    fs::{File, OpenOptions, TryLockError},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+// every command that reads or writes the lists holds chartodo.lock, in the chartodo folder, from before it opens
+// anything until it's done writing. two chartodos running at once, e.g., from two terminals and a cron job, used to
+// both read the same lists and then have the second write wipe out what the first one did. now the second one waits
+// for the first. the lock covers everything in the folder, i.e., chartodo.json (or chartodo.sqlite3), the undo
+// history, and history.jsonl
+//
+// the lock is advisory and the os lets go of it when the process ends, so a chartodo that gets killed never leaves
+// it stuck. chartodo.lock itself is never removed, since removing it while someone's waiting on it would let a third
+// chartodo lock a new file at the same time
+
+pub const LOCK_FILE_NAME: &str = "chartodo.lock";
+
+// how long to wait for another chartodo before giving up, in seconds, e.g., CHARTODO_LOCK_TIMEOUT=30 chartodo list
+pub const CHARTODO_LOCK_TIMEOUT_ENV: &str = "CHARTODO_LOCK_TIMEOUT";
+const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+
+// how often to check if the other chartodo is done
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(20);
+
+// the lock is held until this is dropped
+#[derive(Debug)]
+pub struct ChartodoLock {
+    _lock_file: File,
+}
+
+// an empty CHARTODO_LOCK_TIMEOUT is treated as not being set, same as CHARTODO_HOME
+pub fn lock_timeout() -> Result<Duration, ChartodoError> {
+    let timeout = std::env::var(CHARTODO_LOCK_TIMEOUT_ENV).unwrap_or_default();
+    if timeout.is_empty() {
+        return Ok(DEFAULT_LOCK_TIMEOUT);
+    }
+
+    match timeout.parse::<u64>() {
+        Ok(seconds) => Ok(Duration::from_secs(seconds)),
+        Err(_) => Err(ChartodoError::InvalidSetting(format!(
+            "{}='{}' isn't a number of seconds. Nothing was changed.",
+            CHARTODO_LOCK_TIMEOUT_ENV, timeout
+        ))),
+    }
+}
+
+// waits up to timeout for any other chartodo using the folder to finish. the folder has to exist already
+pub fn lock_chartodo_dir(
+    chartodo_dir: &Path,
+    timeout: Duration,
+) -> Result<ChartodoLock, ChartodoError> {
+    let lock_path = chartodo_dir.join(LOCK_FILE_NAME);
+    let lock_file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(&lock_path)
+        .map_err(|open_error| {
+            ChartodoError::Storage(format!(
+                "couldn't open {}: {}. Nothing was changed.",
+                lock_path.display(),
+                open_error
+            ))
+        })?;
+
+    let started = Instant::now();
+    loop {
+        match lock_file.try_lock() {
+            Ok(()) => {
+                return Ok(ChartodoLock {
+                    _lock_file: lock_file,
+                })
+            }
+            Err(TryLockError::WouldBlock) if started.elapsed() < timeout => {
+                std::thread::sleep(LOCK_RETRY_INTERVAL)
+            }
+            Err(TryLockError::WouldBlock) => {
+                return Err(ChartodoError::Locked(format!(
+                    "Another chartodo has been using {} for more than {} seconds, so this one gave up. Nothing \
+                    was changed. Try again once it's done, or set {} to wait longer.",
+                    chartodo_dir.display(),
+                    timeout.as_secs_f32(),
+                    CHARTODO_LOCK_TIMEOUT_ENV
+                )))
+            }
+            Err(TryLockError::Error(lock_error)) => {
+                return Err(ChartodoError::Storage(format!(
+                    "couldn't lock {}: {}. Nothing was changed.",
+                    lock_path.display(),
+                    lock_error
+                )))
+            }
+        }
+    }
+}
+
+// cargo test file_lock_unit_tests
+#[cfg(test)]
+mod file_lock_unit_tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn fresh_test_dir(test_name: &str) -> PathBuf {
+        let mut test_dir = std::env::temp_dir();
+        test_dir.push(format!(
+            "chartodo_file_lock_unit_tests_{}_{}",
+            test_name,
+            std::process::id()
+        ));
+
+        // in case a previous run didn't clean up after itself
+        if test_dir.exists() {
+            std::fs::remove_dir_all(&test_dir).unwrap();
+        }
+        std::fs::create_dir(&test_dir).unwrap();
+
+        test_dir
+    }
+
+    #[test]
+    fn held_lock_times_out() {
+        let test_dir = fresh_test_dir("times_out");
+
+        let lock = lock_chartodo_dir(&test_dir, Duration::ZERO).unwrap();
+        let error = lock_chartodo_dir(&test_dir, Duration::from_millis(50)).unwrap_err();
+        assert_eq!(error.code(), "locked");
+        assert_eq!(error.exit_code(), 6);
+
+        // once it's let go, the next one gets it right away
+        drop(lock);
+        assert!(lock_chartodo_dir(&test_dir, Duration::ZERO).is_ok());
+        assert!(test_dir.join(LOCK_FILE_NAME).exists());
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+
+    #[test]
+    fn waits_for_the_other_chartodo() {
+        let test_dir = fresh_test_dir("waits");
+
+        let lock = lock_chartodo_dir(&test_dir, Duration::ZERO).unwrap();
+        let holder = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(100));
+            drop(lock);
+        });
+
+        assert!(lock_chartodo_dir(&test_dir, Duration::from_secs(10)).is_ok());
+        holder.join().unwrap();
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+}
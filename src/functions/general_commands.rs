@@ -1,34 +1,46 @@
 use super::general_helpers::*;
+use crate::functions::errors::ChartodoError;
 use crate::functions::{
-    deadline_tasks::deadline_helpers::*, regular_tasks::regular_helpers::*,
-    repeating_tasks::repeating_helpers::*,
+    config::*,
+    history::{read_history, HistoryEvent},
+    migrations::upgrade_older_layouts_if_needed,
+    output::*,
+    search::SearchPattern,
+    storage_helpers::*,
+    task_store::{PositionErrors, TaskList},
 };
+use chrono::NaiveDateTime;
 use comfy_table::*;
 use modifiers::UTF8_ROUND_CORNERS;
-use presets::UTF8_FULL;
-use std::io::Write;
-
-pub fn list() {
-    // housekeeping
-    regular_tasks_create_dir_and_file_if_needed();
-    deadline_tasks_create_dir_and_file_if_needed();
-    repeating_tasks_create_dir_and_file_if_needed();
+use presets::{ASCII_FULL, ASCII_MARKDOWN, NOTHING, UTF8_FULL};
+use serde_json::json;
+use std::{io::Write, path::Path};
+
+// w/ a tag, only the tasks that have it are shown. w/ --format json, see print_json_result instead
+pub fn list(tag: Option<&str>) -> Result<(), ChartodoError> {
     let writer = &mut std::io::stdout();
     let mut table = Table::new();
+    refresh_repeating_tasks()?;
 
     // open file and parse
-    let regular_tasks = open_regular_tasks_and_return_tasks_struct();
-    let deadline_tasks = open_deadline_tasks_and_return_tasks_struct();
-    let repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
+    let store = open_task_store()?;
 
     // get strings to print
-    let (regular_todo, regular_done) = regular_tasks_list(regular_tasks);
-    let (deadline_todo, deadline_done) = deadline_tasks_list(deadline_tasks);
-    let (repeating_todo, repeating_done) = repeating_tasks_list(repeating_tasks);
-
+    let (regular_todo, regular_done) = regular_tasks_list(store.regular().clone(), tag);
+    let (deadline_todo, deadline_done) = deadline_tasks_list(store.deadline().clone(), tag);
+    let (repeating_todo, repeating_done) = repeating_tasks_list(store.repeating().clone(), tag);
+
+    // the borders can be changed in config.toml, see TableStyle
+    match config()?.display.table_style {
+        TableStyle::Rounded => table
+            .load_preset(UTF8_FULL)
+            .apply_modifier(UTF8_ROUND_CORNERS),
+        TableStyle::Sharp => table.load_preset(UTF8_FULL),
+        TableStyle::Ascii => table.load_preset(ASCII_FULL),
+        TableStyle::Markdown => table.load_preset(ASCII_MARKDOWN),
+        TableStyle::Plain => table.load_preset(NOTHING),
+    };
     table
-        .load_preset(UTF8_FULL)
-        .apply_modifier(UTF8_ROUND_CORNERS)
         .set_content_arrangement(ContentArrangement::Dynamic)
         .set_header(vec![
             Cell::new("CHARTODO").add_attribute(Attribute::Bold),
@@ -47,295 +59,414 @@ pub fn list() {
         ]);
 
     writeln!(writer, "{table}").expect("writeln failed");
+
+    Ok(())
+}
+
+// what's shown once a command is done. that's the list if the command went through. if it didn't, or the list
+// itself couldn't be shown, what went wrong goes to stderr and nothing else is shown. returns whichever it was
+pub fn show_result(result: Result<(), ChartodoError>) -> Result<(), ChartodoError> {
+    match output_format() {
+        OutputFormat::Text => {
+            let result = result.and_then(|()| list(None));
+            if let Err(error) = &result {
+                eprintln!("{}: {}", error.label(), error);
+            }
+
+            result
+        }
+        OutputFormat::Json => print_json_result(result, None),
+    }
 }
 
-pub fn clear_all_lists() -> bool {
-    // housekeeping
-    regular_tasks_create_dir_and_file_if_needed();
-    deadline_tasks_create_dir_and_file_if_needed();
-    repeating_tasks_create_dir_and_file_if_needed();
+// w/ --format json, every command ends w/ exactly one json object on stdout:
+// {"ok": true, "messages": [...], "lists": {"regular": ..., "deadline": ..., "repeating": ...}} if it went through, or
+// {"ok": false, "messages": [...], "error": {"code": "invalid_position", "message": "..."}} if it didn't. messages
+// are any notes the command had, e.g., Undid: .... if the lists can't be loaded, that's the error instead
+pub fn print_json_result(
+    result: Result<(), ChartodoError>,
+    tag: Option<&str>,
+) -> Result<(), ChartodoError> {
     let writer = &mut std::io::stdout();
+    let messages = take_held_messages();
+
+    let lists = result.and_then(|()| {
+        refresh_repeating_tasks()?;
+
+        // open file and parse
+        let store = open_task_store()?;
+
+        Ok(json!({
+            "regular": tasks_json(store.regular(), tag),
+            "deadline": tasks_json(store.deadline(), tag),
+            "repeating": tasks_json(store.repeating(), tag),
+        }))
+    });
+
+    let json_result = match &lists {
+        Ok(lists) => json!({
+            "ok": true,
+            "messages": messages,
+            "lists": lists,
+        }),
+        Err(error) => json!({
+            "ok": false,
+            "messages": messages,
+            "error": {
+                "code": error.code(),
+                "message": error.to_string(),
+            },
+        }),
+    };
+
+    writeln!(writer, "{}", json_result).expect("writeln failed");
+
+    lists.map(|_| ())
+}
 
-    // open file and parse
-    let mut regular_tasks = open_regular_tasks_and_return_tasks_struct();
-    let mut deadline_tasks = open_deadline_tasks_and_return_tasks_struct();
-    let mut repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
+// chartodo history --from -7d. everything in history.jsonl that happened between from and to, oldest first. w/ json,
+// it's {"ok": true, "messages": [...], "history": [...]}, w/ each event the same as its line in the file
+pub fn show_history(
+    from: Option<NaiveDateTime>,
+    to: Option<NaiveDateTime>,
+) -> Result<(), ChartodoError> {
+    let history_path = open_task_store()?.history_path();
+    let history = read_history(&history_path)
+        .map_err(|read_error| ChartodoError::Storage(format!("{:#}", read_error)))?;
+    let history: Vec<&HistoryEvent> = history
+        .iter()
+        .filter(|event| event.happened_between(from, to))
+        .collect();
 
-    // check if all lists are empty
-    if regular_tasks.todo.is_empty()
-        && regular_tasks.done.is_empty()
-        && deadline_tasks.todo.is_empty()
-        && deadline_tasks.done.is_empty()
-        && repeating_tasks.todo.is_empty()
-        && repeating_tasks.done.is_empty()
-    {
-        writeln!(writer, "ERROR: All of the lists are currently empty.").expect("writeln failed");
+    let writer = &mut std::io::stdout();
+    if output_format() == OutputFormat::Json {
+        let result = json!({
+            "ok": true,
+            "messages": take_held_messages(),
+            "history": history,
+        });
+        writeln!(writer, "{}", result).expect("writeln failed");
+        return Ok(());
+    }
 
-        // error = true
-        return true;
+    if history.is_empty() {
+        writeln!(writer, "Nothing has happened to any tasks in that time.")
+            .expect("writeln failed");
+    }
+    for event in history {
+        writeln!(writer, "{}", event).expect("writeln failed");
     }
 
-    // clear all lists
-    regular_tasks.todo.clear();
-    regular_tasks.done.clear();
-    deadline_tasks.todo.clear();
-    deadline_tasks.done.clear();
-    repeating_tasks.todo.clear();
-    repeating_tasks.done.clear();
-
-    // write changes to files
-    write_changes_to_new_regular_tasks(regular_tasks);
-    write_changes_to_new_deadline_tasks(deadline_tasks);
-    write_changes_to_new_repeating_tasks(repeating_tasks);
-
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn clear_regular_tasks() -> bool {
-    // housekeeping
-    regular_tasks_create_dir_and_file_if_needed();
+// chartodo config get display.table_style. w/o a setting, every one is shown, w/ the defaults filled in for the ones
+// that aren't in config.toml. w/ json, it's {"ok": true, "messages": [...], "config": {...}}, w/ only the one setting
+// in it if one was asked for
+pub fn show_setting(key: Option<&str>) -> Result<(), ChartodoError> {
+    let config = config()?;
     let writer = &mut std::io::stdout();
 
-    // open file and parse
-    let mut regular_tasks = open_regular_tasks_and_return_tasks_struct();
+    let Some(key) = key else {
+        match output_format() {
+            OutputFormat::Text => write!(
+                writer,
+                "{}",
+                toml::to_string(config).expect("a config can always be turned into toml")
+            )
+            .expect("write failed"),
+            OutputFormat::Json => writeln!(
+                writer,
+                "{}",
+                json!({
+                    "ok": true,
+                    "messages": take_held_messages(),
+                    "config": config,
+                })
+            )
+            .expect("writeln failed"),
+        }
+        return Ok(());
+    };
 
-    // check if all lists are empty
-    if regular_tasks.todo.is_empty() && regular_tasks.done.is_empty() {
-        writeln!(writer, "ERROR: The regular task lists are currently empty.")
-            .expect("writeln failed");
+    let value = get_setting(config, key)?;
+    show_one_setting(key, &value);
 
-        // error = true
-        return true;
-    }
+    Ok(())
+}
 
-    // clear all lists
-    regular_tasks.todo.clear();
-    regular_tasks.done.clear();
+// chartodo config set display.table_style ascii. only that setting is changed in config.toml
+pub fn change_setting(key: &str, value: &str) -> Result<(), ChartodoError> {
+    chartodo_dir_create_if_needed()?;
+    let config = set_setting(&path_to_config_file()?, key, value)?;
 
-    // write changes to file
-    write_changes_to_new_regular_tasks(regular_tasks);
+    show_one_setting(key, &get_setting(&config, key)?);
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn clear_deadline_tasks() -> bool {
-    // housekeeping
-    deadline_tasks_create_dir_and_file_if_needed();
+// e.g., display.table_style = "ascii", the same way it's written in config.toml
+fn show_one_setting(key: &str, value: &toml::Value) {
     let writer = &mut std::io::stdout();
+    let line = match output_format() {
+        OutputFormat::Text => format!("{} = {}", key, value),
+        OutputFormat::Json => json!({
+            "ok": true,
+            "messages": take_held_messages(),
+            "config": { key: value },
+        })
+        .to_string(),
+    };
+
+    writeln!(writer, "{}", line).expect("writeln failed");
+}
 
-    // open file and parse
-    let mut deadline_tasks = open_deadline_tasks_and_return_tasks_struct();
+// chartodo archive-list. everything in archive.json, by kind of task, in the order it was archived. w/ json, it's
+// {"ok": true, "messages": [...], "archive": {"regular": [...], "deadline": [...], "repeating": [...]}}
+pub fn show_archive() -> Result<(), ChartodoError> {
+    let mut store = open_task_store()?;
+    let archive = store.load_archive()?;
 
-    // check if all lists are empty
-    if deadline_tasks.todo.is_empty() && deadline_tasks.done.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The deadline task lists are currently empty."
-        )
-        .expect("writeln failed");
+    let writer = &mut std::io::stdout();
+    if output_format() == OutputFormat::Json {
+        let result = json!({
+            "ok": true,
+            "messages": take_held_messages(),
+            "archive": {
+                "regular": archived_tasks_json(&archive.regular),
+                "deadline": archived_tasks_json(&archive.deadline),
+                "repeating": archived_tasks_json(&archive.repeating),
+            },
+        });
+        writeln!(writer, "{}", result).expect("writeln failed");
+        return Ok(());
+    }
 
-        // error = true
-        return true;
+    if archive.is_empty() {
+        writeln!(writer, "Nothing has been archived yet.").expect("writeln failed");
+        return Ok(());
+    }
+    for (list, heading) in [
+        (TaskList::Regular, "REGULAR"),
+        (TaskList::Deadline, "DEADLINE"),
+        (TaskList::Repeating, "REPEATING"),
+    ] {
+        let archived = archive.list(list);
+        if archived.is_empty() {
+            continue;
+        }
+
+        writeln!(writer, "{}", heading).expect("writeln failed");
+        for (index, task) in archived.iter().enumerate() {
+            writeln!(writer, "{}", task_line(index + 1, task)).expect("writeln failed");
+        }
     }
 
-    // clear all lists
-    deadline_tasks.todo.clear();
-    deadline_tasks.done.clear();
+    Ok(())
+}
 
-    // write changes to file
-    write_changes_to_new_deadline_tasks(deadline_tasks);
+// chartodo search milk, or chartodo search --regex '^buy-'. every task whose text matches, in every list and the
+// archive, w/ the list it's in and its position there, so it can go straight into done, edit, archive-restore, etc.
+// w/ json, it's {"ok": true, "messages": [...], "matches": [...]}
+pub fn search_tasks(query: &str, regex: bool) -> Result<(), ChartodoError> {
+    let pattern = match regex {
+        true => SearchPattern::regex(query)?,
+        false => SearchPattern::text(query),
+    };
 
-    // error = false
-    false
-}
+    // the positions have to be the ones the list would show
+    refresh_repeating_tasks()?;
+    let matches = open_task_store()?.search(&pattern)?;
 
-pub fn clear_repeating_tasks() -> bool {
-    // housekeeping
-    repeating_tasks_create_dir_and_file_if_needed();
     let writer = &mut std::io::stdout();
+    if output_format() == OutputFormat::Json {
+        let result = json!({
+            "ok": true,
+            "messages": take_held_messages(),
+            "matches": search_matches_json(&matches),
+        });
+        writeln!(writer, "{}", result).expect("writeln failed");
+        return Ok(());
+    }
 
-    // open file and parse
-    let mut repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
-
-    // check if all lists are empty
-    if repeating_tasks.todo.is_empty() && repeating_tasks.done.is_empty() {
+    if matches.is_empty() {
+        writeln!(writer, "Nothing matched '{}'.", query).expect("writeln failed");
+    }
+    for found in matches {
         writeln!(
             writer,
-            "ERROR: The repeating task lists are currently empty."
+            "{} {}",
+            found.found_in,
+            task_line(found.position, &found.task)
         )
         .expect("writeln failed");
-
-        // error = true
-        return true;
     }
 
-    // clear all lists
-    repeating_tasks.todo.clear();
-    repeating_tasks.done.clear();
+    Ok(())
+}
+
+// chartodo archive-restore deadline 2 4-5. the positions are the ones archive-list shows. the tasks go back to their
+// done list, and count as done just now
+pub fn restore_archived(list: TaskList, positions: Vec<String>) -> Result<(), ChartodoError> {
+    // open file and parse
+    let mut store = open_task_store()?;
+
+    // unwrap any ranges and check the positions. the whole archive can be restored in one go
+    let positions = store.archived_positions_from_args(
+        list,
+        &positions,
+        &PositionErrors {
+            empty: &format!(
+                "The {} archive is currently empty, so you can't restore any items.",
+                list
+            ),
+            none_viable: &format!(
+                "None of the positions you provided were viable -- they were all either negative, zero, exceeded \
+                the {} archive's length, or were invalid range positioning.",
+                list
+            ),
+            whole_list: None,
+        },
+    )?;
+
+    store.restore(list, &positions)?;
 
     // write changes to file
-    write_changes_to_new_repeating_tasks(repeating_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-// cargo test general_commands_unit_tests -- --test-threads=1
-#[cfg(test)]
-mod general_commands_unit_tests {
-    use super::*;
-    use crate::functions::json_file_structs::*;
-    use anyhow::Context;
-    use std::path::PathBuf;
-
-    // these are taken from regular_helpers
-    fn path_to_regular_tasks() -> PathBuf {
-        // get the data dir XDG spec and return it with path to regular_tasks.json
-        let mut regular_tasks_path = dirs::data_dir()
-            .context(
-                "linux: couldn't get $HOME/.local/share/
-                    windows: couldn't get C:/Users/your_user/AppData/Local/
-                    mac: couldn't get /Users/your_user/Library/Application Support/
-
-                    those directories should exist for your OS. please double check that they do.",
-            )
-            .expect("something went wrong with fetching the user's data dirs");
-        regular_tasks_path.push("chartodo/regular_tasks.json");
+// chartodo init --template team.json. starts the lists in the chartodo folder, either empty or as a copy of the
+// template, which can be any chartodo.json. it won't touch lists that are already there, even broken ones, since
+// the user's tasks could still be in them
+pub fn init(template: Option<&Path>) -> Result<(), ChartodoError> {
+    chartodo_dir_create_if_needed()?;
+    upgrade_older_layouts_if_needed(&path_to_chartodo_dir()?)?;
+
+    // w/ sqlite, chartodo.json would be brought over the first time, so it counts too
+    let backend = storage_backend()?;
+    let chartodo_file_path = path_to_chartodo_file()?;
+    if let Some(tasks_path) = [chartodo_file_path.clone(), backend.path().to_path_buf()]
+        .into_iter()
+        .find(|tasks_path| tasks_path.exists())
+    {
+        // a file that can't be read isn't initialized, but it's not replaced either
+        if tasks_path == chartodo_file_path {
+            if let Err(LoadError::Broken(parse_error)) = load_chartodo_file(&tasks_path) {
+                return Err(broken_file_error(&tasks_path, parse_error));
+            }
+        }
 
-        regular_tasks_path
+        return Err(ChartodoError::AlreadyInitialized(format!(
+            "There are already tasks in {}. Nothing was changed.",
+            tasks_path.display()
+        )));
     }
 
-    fn regular_tasks_copy_path() -> PathBuf {
-        // get the path for regular_tasks_copy.json, which will be used to hold the original contents
-        // of regular_tasks.json while it's getting modified
-        let mut regular_tasks_copy_path = dirs::data_dir()
-            .context(
-                "linux: couldn't get $HOME/.local/share/
-                    windows: couldn't get C:/Users/your_user/AppData/Local/
-                    mac: couldn't get /Users/your_user/Library/Application Support/
-
-                    those directories should exist for your OS. please double check that they do.",
-            )
-            .expect("something went wrong with fetching the user's data dirs");
-        regular_tasks_copy_path.push("chartodo/regular_tasks_copy.json");
+    let mut chartodo_file = match template {
+        Some(template) => match load_chartodo_file(template) {
+            Ok(Some((chartodo_file, _))) => chartodo_file,
+            Ok(None) => new_chartodo_file(false),
+            Err(LoadError::TooNew(message)) => return Err(ChartodoError::Storage(message)),
+            Err(LoadError::Broken(parse_error)) => {
+                return Err(ChartodoError::Storage(format!(
+                    "{:#}. Nothing was changed.",
+                    parse_error.context("couldn't use the template")
+                )))
+            }
+        },
+        None => new_chartodo_file(false),
+    };
 
-        regular_tasks_copy_path
-    }
+    // write changes to file
+    write_chartodo_file(&mut chartodo_file)?;
 
-    // these are taken from deadline_helpers
-    fn path_to_deadline_tasks() -> PathBuf {
-        // get the data dir XDG spec and return it with path to deadline_tasks.json
-        let mut deadline_tasks_path = dirs::data_dir()
-            .context(
-                "linux: couldn't get $HOME/.local/share/
-                    windows: couldn't get C:/Users/your_user/AppData/Local/
-                    mac: couldn't get /Users/your_user/Library/Application Support/
+    Ok(())
+}
 
-                    those directories should exist for your OS. please double check that they do.",
-            )
-            .expect("something went wrong with fetching the user's data dirs");
-        deadline_tasks_path.push("chartodo/deadline_tasks.json");
+// w/ archive, the done tasks of every list are moved to the archive instead of being deleted
+pub fn clear_all_lists(archive: bool) -> Result<(), ChartodoError> {
+    // open file and parse
+    let mut store = open_task_store()?;
 
-        deadline_tasks_path
+    // check if all lists are empty
+    if [TaskList::Regular, TaskList::Deadline, TaskList::Repeating]
+        .iter()
+        .all(|list| store.list(*list).todo.is_empty() && store.list(*list).done.is_empty())
+    {
+        return Err(ChartodoError::EmptyList(
+            "All of the lists are currently empty.".to_string(),
+        ));
     }
 
-    fn deadline_tasks_copy_path() -> PathBuf {
-        // get the path for deadline_tasks_copy.json, which will be used to hold the original contents
-        // of deadline_tasks.json while it's getting modified
-        let mut deadline_tasks_copy_path = dirs::data_dir()
-            .context(
-                "linux: couldn't get $HOME/.local/share/
-                    windows: couldn't get C:/Users/your_user/AppData/Local/
-                    mac: couldn't get /Users/your_user/Library/Application Support/
-
-                    those directories should exist for your OS. please double check that they do.",
-            )
-            .expect("something went wrong with fetching the user's data dirs");
-        deadline_tasks_copy_path.push("chartodo/deadline_tasks_copy.json");
-
-        deadline_tasks_copy_path
+    // clear all lists
+    for list in [TaskList::Regular, TaskList::Deadline, TaskList::Repeating] {
+        if archive {
+            store.archive_all(list);
+        }
+        store.clear_both(list);
     }
 
-    // these are taken from repeating_helpers
-    fn path_to_repeating_tasks() -> PathBuf {
-        // get the data dir XDG spec and return it with path to repeating_tasks.json
-        let mut repeating_tasks_path = dirs::data_dir()
-            .context(
-                "linux: couldn't get $HOME/.local/share/
-                    windows: couldn't get C:/Users/your_user/AppData/Local/
-                    mac: couldn't get /Users/your_user/Library/Application Support/
+    // write changes to file
+    save_task_store(&mut store)?;
 
-                    those directories should exist for your OS. please double check that they do.",
-            )
-            .expect("something went wrong with fetching the user's data dirs");
-        repeating_tasks_path.push("chartodo/repeating_tasks.json");
+    Ok(())
+}
 
-        repeating_tasks_path
-    }
+pub fn clear_regular_tasks(archive: bool) -> Result<(), ChartodoError> {
+    clear_both_lists(TaskList::Regular, archive)
+}
 
-    fn repeating_tasks_copy_path() -> PathBuf {
-        // get the path for repeating_tasks_copy.json, which will be used to hold the original contents
-        // of repeating_tasks.json while it's getting modified
-        let mut repeating_tasks_copy_path = dirs::data_dir()
-            .context(
-                "linux: couldn't get $HOME/.local/share/
-                    windows: couldn't get C:/Users/your_user/AppData/Local/
-                    mac: couldn't get /Users/your_user/Library/Application Support/
+pub fn clear_deadline_tasks(archive: bool) -> Result<(), ChartodoError> {
+    clear_both_lists(TaskList::Deadline, archive)
+}
 
-                    those directories should exist for your OS. please double check that they do.",
-            )
-            .expect("something went wrong with fetching the user's data dirs");
-        repeating_tasks_copy_path.push("chartodo/repeating_tasks_copy.json");
+pub fn clear_repeating_tasks(archive: bool) -> Result<(), ChartodoError> {
+    clear_both_lists(TaskList::Repeating, archive)
+}
 
-        repeating_tasks_copy_path
-    }
+// clears the todo and done lists of one kind of task. w/ archive, the done tasks are moved to the archive instead of
+// being deleted. the todos are deleted either way
+fn clear_both_lists(list: TaskList, archive: bool) -> Result<(), ChartodoError> {
+    // open file and parse
+    let mut store = open_task_store()?;
+    let tasks = store.list(list);
 
-    #[test]
-    fn aaaa_regular_tasks_clone_file() {
-        // name is aaaa so it's done first
-        // since we will be modifying the original file to run a test, the original data must be
-        // preserved first
-        std::fs::File::create(regular_tasks_copy_path())
-            .context("failed to create regular_tasks_copy.json")
-            .expect("failed to create a copy during unit test");
-
-        std::fs::copy(path_to_regular_tasks(), regular_tasks_copy_path())
-            .context("failed to copy regular_tasks.json to regular_tasks_copy.json")
-            .expect("failed to copy original file to copy file during unit test");
+    // check if all lists are empty
+    if tasks.todo.is_empty() && tasks.done.is_empty() {
+        return Err(ChartodoError::EmptyList(format!(
+            "The {} task lists are currently empty.",
+            list
+        )));
     }
 
-    #[test]
-    fn aaaa_deadline_tasks_clone_file() {
-        // name is aaaa so it's done first
-        // since we will be modifying the original file to run a test, the original data must be
-        // preserved first
-        std::fs::File::create(deadline_tasks_copy_path())
-            .context("failed to create deadline_tasks_copy.json")
-            .expect("failed to create a copy during unit test");
-
-        std::fs::copy(path_to_deadline_tasks(), deadline_tasks_copy_path())
-            .context("failed to copy deadline_tasks.json to deadline_tasks_copy.json")
-            .expect("failed to copy original file to copy file during unit test");
+    // clear all lists
+    if archive {
+        store.archive_all(list);
     }
+    store.clear_both(list);
 
-    #[test]
-    fn aaaa_repeating_tasks_clone_file() {
-        // name is aaaa so it's done first
-        // since we will be modifying the original file to run a test, the original data must be
-        // preserved first
-        std::fs::File::create(repeating_tasks_copy_path())
-            .context("failed to create repeating_tasks_copy.json")
-            .expect("failed to create a copy during unit test");
-
-        std::fs::copy(path_to_repeating_tasks(), repeating_tasks_copy_path())
-            .context("failed to copy repeating_tasks.json to repeating_tasks_copy.json")
-            .expect("failed to copy original file to copy file during unit test");
-    }
+    // write changes to file
+    save_task_store(&mut store)?;
+
+    Ok(())
+}
+
+// cargo test general_commands_unit_tests
+#[cfg(test)]
+mod general_commands_unit_tests {
+    use super::*;
+    use crate::functions::{
+        deadline_tasks::deadline_helpers::*, json_file_structs::*,
+        regular_tasks::regular_helpers::*, repeating_tasks::repeating_helpers::*,
+    };
+    use anyhow::Context;
 
     #[test]
     fn clearall_all_lists_are_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -379,12 +510,14 @@ mod general_commands_unit_tests {
             .expect("changing str to tasks struct failed");
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
-        let error_should_be_true = clear_all_lists();
+        let error_should_be_true = clear_all_lists(false).is_err();
         assert!(error_should_be_true);
     }
 
     #[test]
     fn clearall_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -535,7 +668,7 @@ mod general_commands_unit_tests {
             )
             .expect("changing str to tasks struct failed");
 
-        let error_should_be_false = clear_all_lists();
+        let error_should_be_false = clear_all_lists(false).is_err();
         assert!(!error_should_be_false);
 
         let read_regular_tasks = open_regular_tasks_and_return_tasks_struct();
@@ -548,6 +681,8 @@ mod general_commands_unit_tests {
 
     #[test]
     fn clearregular_lists_are_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -563,12 +698,14 @@ mod general_commands_unit_tests {
             .expect("changing str to tasks struct failed");
         write_changes_to_new_regular_tasks(fresh_regular_tasks);
 
-        let error_should_be_true = clear_regular_tasks();
+        let error_should_be_true = clear_regular_tasks(false).is_err();
         assert!(error_should_be_true);
     }
 
     #[test]
     fn clearregular_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -620,7 +757,7 @@ mod general_commands_unit_tests {
             )
             .expect("changing str to tasks struct failed");
 
-        let error_should_be_false = clear_regular_tasks();
+        let error_should_be_false = clear_regular_tasks(false).is_err();
         assert!(!error_should_be_false);
 
         let read_regular_tasks = open_regular_tasks_and_return_tasks_struct();
@@ -629,6 +766,8 @@ mod general_commands_unit_tests {
 
     #[test]
     fn cleardeadline_lists_are_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -644,12 +783,14 @@ mod general_commands_unit_tests {
             .expect("changing str to tasks struct failed");
         write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
 
-        let error_should_be_true = clear_deadline_tasks();
+        let error_should_be_true = clear_deadline_tasks(false).is_err();
         assert!(error_should_be_true);
     }
 
     #[test]
     fn cleardeadline_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -687,7 +828,7 @@ mod general_commands_unit_tests {
             .expect("changing str to tasks struct failed");
         write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
 
-        let error_should_be_false = clear_deadline_tasks();
+        let error_should_be_false = clear_deadline_tasks(false).is_err();
         assert!(!error_should_be_false);
 
         // this should be the content of the file
@@ -710,6 +851,8 @@ mod general_commands_unit_tests {
 
     #[test]
     fn clearrepeating_lists_are_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -725,12 +868,14 @@ mod general_commands_unit_tests {
             .expect("changing str to tasks struct failed");
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
-        let error_should_be_true = clear_repeating_tasks();
+        let error_should_be_true = clear_repeating_tasks(false).is_err();
         assert!(error_should_be_true);
     }
 
     #[test]
     fn clearrepeating_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -768,7 +913,7 @@ mod general_commands_unit_tests {
             .expect("changing str to tasks struct failed");
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
-        let error_should_be_false = clear_repeating_tasks();
+        let error_should_be_false = clear_repeating_tasks(false).is_err();
         assert!(!error_should_be_false);
 
         // this should be the content of the file
@@ -788,48 +933,4 @@ mod general_commands_unit_tests {
         let read_repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
         assert_eq!(read_repeating_tasks, repeating_tasks);
     }
-
-    #[test]
-    fn zzzz_rename_regular_copy_to_original() {
-        // name is zzzz so it's done last
-        // now that tests are done, remove the modified original and rename copy to original
-
-        std::fs::remove_file(path_to_regular_tasks())
-            .context("failed delete modified regular_tasks.json after running tests")
-            .expect("failed to delete regular_tasks.json after regular_helpers unit tests");
-
-        std::fs::rename(regular_tasks_copy_path(), path_to_regular_tasks())
-            .context("failed to rename regular_tasks_copy to regular_tasks")
-            .expect("failed to rename regular_tasks_copy to regular_tasks after tests were done");
-    }
-
-    #[test]
-    fn zzzz_rename_deadline_copy_to_original() {
-        // name is zzzz so it's done last
-        // now that tests are done, remove the modified original and rename copy to original
-
-        std::fs::remove_file(path_to_deadline_tasks())
-            .context("failed delete modified deadline_tasks.json after running tests")
-            .expect("failed to delete deadline_tasks.json after deadline_helpers unit tests");
-
-        std::fs::rename(deadline_tasks_copy_path(), path_to_deadline_tasks())
-            .context("failed to rename deadline_tasks_copy to deadline_tasks")
-            .expect("failed to rename deadline_tasks_copy to deadline_tasks after tests were done");
-    }
-
-    #[test]
-    fn zzzz_rename_repeating_copy_to_original() {
-        // name is zzzz so it's done last
-        // now that tests are done, remove the modified original and rename copy to original
-
-        std::fs::remove_file(path_to_repeating_tasks())
-            .context("failed delete modified repeating_tasks.json after running tests")
-            .expect("failed to delete repeating_tasks.json after repeating_helpers unit tests");
-
-        std::fs::rename(repeating_tasks_copy_path(), path_to_repeating_tasks())
-            .context("failed to rename repeating_tasks_copy to repeating_tasks")
-            .expect(
-                "failed to rename repeating_tasks_copy to repeating_tasks after tests were done",
-            );
-    }
 }
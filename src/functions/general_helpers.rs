@@ -1,25 +1,55 @@
 use super::json_file_structs::*;
-use super::repeating_tasks::repeating_helpers::*;
-use chrono::{Days, Duration, Local, Months, NaiveDateTime};
+use crate::functions::config::config;
+use crate::functions::errors::ChartodoError;
+use crate::functions::recurrence::CatchUp;
+use crate::functions::search::{FoundIn, SearchMatch};
+use crate::functions::storage_helpers::{open_task_store, save_task_store};
+use crate::functions::task_ids::PositionsIn;
+use crate::functions::task_store::{PositionErrors, TaskList, TaskStore};
+use chrono::{Local, NaiveDateTime};
+use serde_json::{json, Value};
 
 // these Tasks struct come in already sorted I think
-pub fn regular_tasks_list(regular_tasks: Tasks) -> (String, String) {
+// note: each task is shown w/ its position, which is what most commands take, and its #id, which can be used instead
+// of the position and doesn't change when the list gets sorted. w/ a tag, only the tasks that have it are shown, but
+// they keep the positions they have in the whole list
+pub fn regular_tasks_list(regular_tasks: Tasks, tag: Option<&str>) -> (String, String) {
     let mut regular_todo = String::from("");
-    let mut counter: u8 = 1;
-    regular_tasks.todo.iter().for_each(|item| {
-        let task = format!("{}: {}\n", counter, item.task);
-        counter += 1;
-        regular_todo.push_str(&task);
-    });
+    regular_tasks
+        .todo
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| tag.is_none_or(|tag| item.has_tag(tag)))
+        .for_each(|(index, item)| {
+            let task = format!(
+                "{}: {} #{}{}{}\n",
+                index + 1,
+                item.task,
+                item.id,
+                format_priority(item),
+                format_tags(item)
+            );
+            regular_todo.push_str(&task);
+        });
     let regular_todo = regular_todo.trim_end();
 
     let mut regular_done = String::from("");
-    let mut counter: u8 = 1;
-    regular_tasks.done.iter().for_each(|item| {
-        let task = format!("{}: {}\n", counter, item.task);
-        counter += 1;
-        regular_done.push_str(&task);
-    });
+    regular_tasks
+        .done
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| tag.is_none_or(|tag| item.has_tag(tag)))
+        .for_each(|(index, item)| {
+            let task = format!(
+                "{}: {} #{}{}{}\n",
+                index + 1,
+                item.task,
+                item.id,
+                format_priority(item),
+                format_tags(item)
+            );
+            regular_done.push_str(&task);
+        });
     let regular_done = regular_done.trim_end();
 
     match regular_done.is_empty() {
@@ -31,36 +61,46 @@ pub fn regular_tasks_list(regular_tasks: Tasks) -> (String, String) {
     }
 }
 
-pub fn deadline_tasks_list(deadline_tasks: Tasks) -> (String, String) {
+pub fn deadline_tasks_list(deadline_tasks: Tasks, tag: Option<&str>) -> (String, String) {
     let mut deadline_todo = String::from("");
-    let mut counter: u8 = 1;
-    deadline_tasks.todo.iter().for_each(|item| {
-        let task = format!(
-            "{}: {}\n   {}: {} {}\n",
-            counter,
-            item.task,
-            check_if_due_or_not(item.date.as_ref().unwrap(), item.time.as_ref().unwrap()),
-            item.date.as_ref().unwrap(),
-            item.time.as_ref().unwrap()
-        );
-        counter += 1;
-        deadline_todo.push_str(&task);
-    });
+    deadline_tasks
+        .todo
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| tag.is_none_or(|tag| item.has_tag(tag)))
+        .for_each(|(index, item)| {
+            let task = format!(
+                "{}: {} #{}{}{}\n   {}: {}\n",
+                index + 1,
+                item.task,
+                item.id,
+                format_priority(item),
+                format_tags(item),
+                check_if_due_or_not(item.due()),
+                format_due(item)
+            );
+            deadline_todo.push_str(&task);
+        });
     let deadline_todo = deadline_todo.trim_end();
 
     let mut deadline_done = String::from("");
-    let mut counter: u8 = 1;
-    deadline_tasks.done.iter().for_each(|item| {
-        let task = format!(
-            "{}: {}\n   done: {} {}\n",
-            counter,
-            item.task,
-            item.date.as_ref().unwrap(),
-            item.time.as_ref().unwrap()
-        );
-        counter += 1;
-        deadline_done.push_str(&task);
-    });
+    deadline_tasks
+        .done
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| tag.is_none_or(|tag| item.has_tag(tag)))
+        .for_each(|(index, item)| {
+            let task = format!(
+                "{}: {} #{}{}{}\n   done: {}\n",
+                index + 1,
+                item.task,
+                item.id,
+                format_priority(item),
+                format_tags(item),
+                format_due(item)
+            );
+            deadline_done.push_str(&task);
+        });
     let deadline_done = deadline_done.trim_end();
 
     match deadline_done.is_empty() {
@@ -72,197 +112,128 @@ pub fn deadline_tasks_list(deadline_tasks: Tasks) -> (String, String) {
     }
 }
 
-// only possible to unit test if results are MISSED or if date and time are so far beyond current date and time that
-// it's impossible to get 'due' unless you spoofed your own time
-fn check_if_due_or_not(date: &String, time: &String) -> String {
-    if date < &Local::now().date_naive().to_string()
-        || date == &Local::now().date_naive().to_string() && time < &Local::now().time().to_string()
-    {
-        "MISSED".to_string()
-    } else {
-        "due".to_string()
-    }
+// e.g., 2099-01-01 00:00. regular tasks don't have one
+fn format_due(task: &Task) -> String {
+    task.due()
+        .map(|due| format!("{} {}", due.format(DATE_FORMAT), due.format(TIME_FORMAT)))
+        .unwrap_or_default()
 }
 
-pub fn repeating_tasks_list(mut repeating_tasks: Tasks) -> (String, String) {
-    // check if any repeating tasks are done first. if they are, push to todo and remove from done
-    // housekeeping
-    let now_date = &Local::now().date_naive().to_string();
-    let now_time = &Local::now().time().to_string();
-    let mut remove_these: Vec<Task> = vec![];
-
-    // pretty sure the following is an expensive action
-    let mut check_if_sorted: bool = true;
-    repeating_tasks.done.iter().for_each(|task| {
-        // double check that repeat_done = true and check if due date+time passed
-        // note: that double check may not be necessary
-        if (task.repeat_done.unwrap() && now_date > task.date.as_ref().unwrap())
-            | (task.repeat_done.unwrap()
-                && now_date == task.date.as_ref().unwrap()
-                && now_time > task.time.as_ref().unwrap())
-        {
-            check_if_sorted = false;
-            // get new original date+time, and prepare to change string to naivedatetime
-            // note: design decision time. for finished repeating tasks, should I use the original due datetime as
-            // the starting datetime for a refreshed repeating task? Or should I use Local::now as the starting
-            // datetime, aka the moment that this function is called to print the list?
-            //
-            // answer: i think i'll keep the original due datetime -> new starting datetime feature. i think this is
-            // to avoid having two different repeating-type tasks, and this is much cleaner + easier. i can
-            // just do a rp-reset command that resets the starting datetime to local now
-            // 2) And also to keep the starting + ending datetimes consistent and not actually contingent
-            // on when the list was shown/printed
-
-            // potential TODO: remove these clones? don't think it's possible
-            let new_original_date = task.date.clone().unwrap();
-            let new_original_time = task.time.clone().unwrap();
-            let add_to_this = new_original_date.clone() + " " + &new_original_time;
-
-            // change to naivedatetime. also being random w/ eprintln and exit
-            let mut change_to_date_time_and_add =
-                match NaiveDateTime::parse_from_str(add_to_this.as_str(), "%Y-%m-%d %H:%M") {
-                    Ok(datetime) => datetime,
-                    Err(_) => {
-                        eprintln!(
-                            "ERROR: While changing a repeating task that was \
-                            marked done back to todo since the due date had passed, there was an error with \
-                            parsing the set date and time to a NaiveDateTime struct. Parsing it to that \
-                            ensures that I can set a new due date and time since the old due date + time \
-                            passed and the repeating task is now not done. You should never be able to see \
-                            this"
-                        );
-                        std::process::exit(1);
-                    }
-                };
-
-            // based on the time unit, add to naivedatetime
-            match task.repeat_unit.as_ref().unwrap().as_str() {
-                "minutes" | "minute" => {
-                    change_to_date_time_and_add +=
-                        Duration::minutes(task.repeat_number.unwrap().into())
-                }
-                "hours" | "hour" => {
-                    change_to_date_time_and_add +=
-                        Duration::hours(task.repeat_number.unwrap().into())
-                }
-                "days" | "day" => {
-                    change_to_date_time_and_add = change_to_date_time_and_add
-                        .checked_add_days(Days::new(task.repeat_number.unwrap().into()))
-                        .unwrap()
-                }
-                "weeks" | "week" => {
-                    let interval: u64 = task.repeat_number.unwrap().into();
-                    change_to_date_time_and_add = change_to_date_time_and_add
-                        .checked_add_days(Days::new(interval * 7))
-                        .unwrap()
-                }
-                "months" | "month" => {
-                    change_to_date_time_and_add = change_to_date_time_and_add
-                        .checked_add_months(Months::new(task.repeat_number.unwrap()))
-                        .unwrap()
-                }
-                "years" | "year" => {
-                    change_to_date_time_and_add = change_to_date_time_and_add
-                        .checked_add_months(Months::new(task.repeat_number.unwrap() * 12))
-                        .unwrap()
-                }
-                _ => (),
-            }
+// e.g., [high]. nothing is shown for tasks w/o a priority
+fn format_priority(task: &Task) -> String {
+    task.priority
+        .map(|priority| format!(" [{}]", priority))
+        .unwrap_or_default()
+}
 
-            // get new due date+time as string
-            let new_date = format!("{}", change_to_date_time_and_add.format("%Y-%m-%d"));
-            let new_time = format!("{}", change_to_date_time_and_add.format("%H:%M"));
-
-            // create a new task (borrow checker), set new due date+time, new original date+time, and repeat_done = false
-            let new_task = Task {
-                task: task.task.clone(),
-                date: Some(new_date),
-                time: Some(new_time),
-                repeat_number: task.repeat_number,
-                repeat_unit: task.repeat_unit.clone(),
-                repeat_done: Some(false),
-                repeat_original_date: Some(new_original_date),
-                repeat_original_time: Some(new_original_time),
-            };
-            repeating_tasks.todo.push(new_task);
-            // i'm pretty sure this is an expensive action
-            remove_these.push(task.to_owned());
-        }
-    });
-    // this is an expensive action.
-    // TODO: keep track of index and then remove based on position. can also insert baesd on known position
-    // TODO: note that if I can insert and remove instead of push and retain, I have to consider whether sorting would be needed
-    // note that inserting and removing based on position can be expensive depending on the position
-    // i would also have to remove from highest to lowest position
-    remove_these.iter().for_each(|task| {
-        repeating_tasks.done.retain(|i| *i != *task);
-    });
+// e.g., +backend @errands
+fn format_tags(task: &Task) -> String {
+    task.tags.iter().map(|tag| format!(" {}", tag)).collect()
+}
 
-    // before sorting, check if anything was changed at all
-    // sort_by_key is commented out since it uses clone and I'd like to avoid that. still, sort_by may take longer
-    /* repeating_tasks
-        .todo
-        .sort_by_key(|item| (item.date.to_owned().unwrap(), item.time.to_owned().unwrap()));
-    repeating_tasks
-        .done
-        .sort_by_key(|item| (item.date.to_owned().unwrap(), item.time.to_owned().unwrap()));
-        */
-
-    if !check_if_sorted {
-        repeating_tasks.todo.sort_by(|x, y| {
-            match x.date.as_ref().unwrap().cmp(y.date.as_ref().unwrap()) {
-                std::cmp::Ordering::Equal => x.time.as_ref().unwrap().cmp(y.time.as_ref().unwrap()),
-                lesser_or_greater => lesser_or_greater,
+// e.g., 3 days, or 1 week on mon,wed,fri, catch-up: each, 12 times. only repeating tasks have one. once is what every
+// task did before there was a choice, so it isn't shown
+fn format_interval(task: &Task) -> String {
+    task.repeat()
+        .map(|repeat| {
+            let mut interval = repeat.every();
+            if repeat.catch_up != CatchUp::Once {
+                interval.push_str(&format!(", catch-up: {}", repeat.catch_up));
             }
-        });
-        repeating_tasks.done.sort_by(|x, y| {
-            match x.date.as_ref().unwrap().cmp(y.date.as_ref().unwrap()) {
-                std::cmp::Ordering::Equal => x.time.as_ref().unwrap().cmp(y.time.as_ref().unwrap()),
-                lesser_or_greater => lesser_or_greater,
+            if let Some(ends) = repeat.ends() {
+                interval.push_str(&format!(", {}", ends));
             }
-        });
+
+            interval
+        })
+        .unwrap_or_default()
+}
+
+// a repeating task whose series has ended stays done for good, see Repeat::is_finished
+fn done_or_finished(task: &Task) -> &'static str {
+    match task.repeat().is_some_and(|repeat| repeat.is_finished()) {
+        true => "finished",
+        false => "done",
+    }
+}
+
+// only possible to unit test if results are MISSED or if date and time are so far beyond current date and time that
+// it's impossible to get 'due' unless you spoofed your own time
+fn is_missed(due: Option<NaiveDateTime>) -> bool {
+    due.is_some_and(|due| due < Local::now().naive_local())
+}
+
+// MISSED and due can be changed in config.toml, see Appearance
+// note: the config was already read to open the lists, so if it couldn't be, nothing gets this far
+fn check_if_due_or_not(due: Option<NaiveDateTime>) -> String {
+    let display = config()
+        .map(|config| config.display.clone())
+        .unwrap_or_default();
+    match is_missed(due) {
+        true => display.missed_label,
+        false => display.due_label,
+    }
+}
+
+// repeating tasks that are done and whose due date+time has passed go back to todo w/ their next due date+time, caught
+// up however each one says to. this happens whenever the lists are shown, whether as the table or as json, before
+// they're opened to be shown
+pub fn refresh_repeating_tasks() -> Result<(), ChartodoError> {
+    let mut store = open_task_store()?;
+
+    // write changes to file, but only if any tasks were due again
+    if store.roll_over_repeating(Local::now().naive_local()) {
+        save_task_store(&mut store)?;
     }
 
+    Ok(())
+}
+
+pub fn repeating_tasks_list(repeating_tasks: Tasks, tag: Option<&str>) -> (String, String) {
     let mut repeating_todo = String::from("");
-    let mut counter: u8 = 1;
-    repeating_tasks.todo.iter().for_each(|item| {
-        let task = format!(
-            "{}: {}\n   interval: {} {}\n   {}: {} {}\n",
-            counter,
-            item.task,
-            item.repeat_number.unwrap(),
-            item.repeat_unit.clone().unwrap(),
-            check_if_due_or_not(item.date.as_ref().unwrap(), item.time.as_ref().unwrap()),
-            item.date.clone().unwrap(),
-            item.time.clone().unwrap()
-        );
-        counter += 1;
-        repeating_todo.push_str(&task);
-    });
+    repeating_tasks
+        .todo
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| tag.is_none_or(|tag| item.has_tag(tag)))
+        .for_each(|(index, item)| {
+            let task = format!(
+                "{}: {} #{}{}{}\n   interval: {}\n   {}: {}\n",
+                index + 1,
+                item.task,
+                item.id,
+                format_priority(item),
+                format_tags(item),
+                format_interval(item),
+                check_if_due_or_not(item.due()),
+                format_due(item)
+            );
+            repeating_todo.push_str(&task);
+        });
     let repeating_todo = repeating_todo.trim_end();
 
     let mut repeating_done = String::from("");
-    let mut counter: u8 = 1;
-    repeating_tasks.done.iter().for_each(|item| {
-        let task = format!(
-            "{}: {}\n   interval: {} {}\n   done: {} {}\n",
-            counter,
-            item.task,
-            item.repeat_number.unwrap(),
-            item.repeat_unit.clone().unwrap(),
-            item.date.clone().unwrap(),
-            item.time.clone().unwrap()
-        );
-        counter += 1;
-        repeating_done.push_str(&task);
-    });
+    repeating_tasks
+        .done
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| tag.is_none_or(|tag| item.has_tag(tag)))
+        .for_each(|(index, item)| {
+            let task = format!(
+                "{}: {} #{}{}{}\n   interval: {}\n   {}: {}\n",
+                index + 1,
+                item.task,
+                item.id,
+                format_priority(item),
+                format_tags(item),
+                format_interval(item),
+                done_or_finished(item),
+                format_due(item)
+            );
+            repeating_done.push_str(&task);
+        });
     let repeating_done = repeating_done.trim_end();
 
-    // write changes to file. wanted to do this after sorting, but for borrowing reasons, can't
-    if !check_if_sorted {
-        write_changes_to_new_repeating_tasks(repeating_tasks);
-    }
-
     match repeating_done.is_empty() {
         true => (repeating_todo.to_string(), repeating_done.to_string()),
         false => {
@@ -272,6 +243,140 @@ pub fn repeating_tasks_list(mut repeating_tasks: Tasks) -> (String, String) {
     }
 }
 
+// the json version of a kind's lists, for --format json. same tasks and positions as the table, w/ the due/MISSED
+// status worked out the same way
+pub fn tasks_json(tasks: &Tasks, tag: Option<&str>) -> Value {
+    json!({
+        "todo": task_list_json(&tasks.todo, tag, false),
+        "done": task_list_json(&tasks.done, tag, true),
+    })
+}
+
+fn task_list_json(tasks: &[Task], tag: Option<&str>, done: bool) -> Vec<Value> {
+    tasks
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| tag.is_none_or(|tag| item.has_tag(tag)))
+        .map(|(index, item)| task_json(index + 1, item, done))
+        .collect()
+}
+
+fn task_json(position: usize, task: &Task, done: bool) -> Value {
+    let mut task_json = json!({
+        "position": position,
+        "id": task.id,
+        "task": task.task,
+        "priority": task.priority,
+        "tags": task.tags,
+    });
+
+    if task.due().is_some() {
+        task_json["due"] = json!(format_due(task));
+        task_json["status"] = match done {
+            true => json!(done_or_finished(task)),
+            false => json!(match is_missed(task.due()) {
+                true => "missed",
+                false => "due",
+            }),
+        };
+    }
+    if let Some(repeat) = task.repeat() {
+        task_json["interval"] = json!(repeat.interval);
+        task_json["unit"] = json!(repeat.unit.to_string());
+        task_json["on"] = json!(repeat.on.map(|on| on.to_string()));
+        task_json["catch_up"] = json!(repeat.catch_up.to_string());
+        task_json["until"] = json!(repeat.until.map(|until| format!(
+            "{} {}",
+            until.format(DATE_FORMAT),
+            until.format(TIME_FORMAT)
+        )));
+        task_json["max_occurrences"] = json!(repeat.max_occurrences);
+        task_json["remaining"] = json!(repeat.remaining());
+        task_json["start"] = json!(format!(
+            "{} {}",
+            repeat.start.format(DATE_FORMAT),
+            repeat.start.format(TIME_FORMAT)
+        ));
+    }
+
+    task_json
+}
+
+// the json for archived tasks, see archive-list. same as the done lists, plus when each one was done
+pub fn archived_tasks_json(tasks: &[Task]) -> Vec<Value> {
+    tasks
+        .iter()
+        .enumerate()
+        .map(|(index, task)| archived_task_json(index + 1, task))
+        .collect()
+}
+
+fn archived_task_json(position: usize, task: &Task) -> Value {
+    let mut task_json = task_json(position, task, true);
+    task_json["done_at"] = json!(task.done_at.map(|done_at| format!(
+        "{} {}",
+        done_at.format(DATE_FORMAT),
+        done_at.format(TIME_FORMAT)
+    )));
+
+    task_json
+}
+
+// the json for what search found. each task is the same as in the lists or archive-list, plus which kind of list
+// it's in, and whether that's the todo, done, or archive, e.g., "list": "deadline", "in": "todo"
+pub fn search_matches_json(matches: &[SearchMatch]) -> Vec<Value> {
+    matches
+        .iter()
+        .map(|found| {
+            let (list, found_in, mut task_json) = match found.found_in {
+                FoundIn::List(list) if list == list.list().todo() => (
+                    list.list(),
+                    "todo",
+                    task_json(found.position, &found.task, false),
+                ),
+                FoundIn::List(list) => (
+                    list.list(),
+                    "done",
+                    task_json(found.position, &found.task, true),
+                ),
+                FoundIn::Archive(list) => (
+                    list,
+                    "archive",
+                    archived_task_json(found.position, &found.task),
+                ),
+            };
+            task_json["list"] = json!(list.to_string());
+            task_json["in"] = json!(found_in);
+
+            task_json
+        })
+        .collect()
+}
+
+// e.g., 1: taxes #3, due: 2099-04-15 00:00, done: 2099-04-10 08:30. archive-list and search show tasks this way
+pub fn task_line(position: usize, task: &Task) -> String {
+    let mut line = format!(
+        "{}: {}{}{} #{}",
+        position,
+        task.task,
+        format_priority(task),
+        format_tags(task),
+        task.id
+    );
+    if task.due().is_some() {
+        line.push_str(&format!(", due: {}", format_due(task)));
+    }
+    if let Some(done_at) = task.done_at {
+        line.push_str(&format!(
+            ", done: {} {}",
+            done_at.format(DATE_FORMAT),
+            done_at.format(TIME_FORMAT)
+        ));
+    }
+
+    line
+}
+
 // check if something is ranged position. several fail states:
 // 1) if there is more than one - in the range, i.e., can't be 6--10 or -6-10
 // 2) no - in item, i.e., it should be 6-10
@@ -341,50 +446,185 @@ pub fn unwrap_range_positioning(mut bound1: usize, bound2: usize) -> Vec<usize>
     unwrap_bounds
 }
 
-// cargo test general_helpers_unit_tests -- --test-threads=1
+// chartodo archive 1 3-5, and the same for deadline and repeating tasks. the done tasks are moved to archive.json
+// instead of being deleted. cleardone is the command that does the whole list, e.g., deadline-cleardone
+pub fn archive_done_tasks(
+    list: TaskList,
+    positions: Vec<String>,
+    cleardone: &str,
+) -> Result<(), ChartodoError> {
+    // open file and parse
+    let mut store = open_task_store()?;
+
+    // unwrap any ranges and check the positions
+    let positions = store.positions_from_args(
+        list.done(),
+        &positions,
+        &PositionErrors {
+            empty: &format!(
+                "The {} done list is currently empty, so you can't archive any items.",
+                list
+            ),
+            none_viable: &format!(
+                "None of the positions you provided were viable -- they were all either negative, zero, exceeded \
+                the {} done list's length, or were invalid range positioning.",
+                list
+            ),
+            whole_list: Some(&format!(
+                "You've specified archiving the entire {} done list. You should do chartodo {} --archive.",
+                list, cleardone
+            )),
+        },
+    )?;
+
+    store.archive(list, &positions)?;
+
+    // write changes to file
+    save_task_store(&mut store)?;
+
+    Ok(())
+}
+
+// edit-priority, deadline-editpriority, and repeating-editpriority only differ in which todo list they change, so
+// they all check their args and edit the task here
+pub fn edit_priority_of_todo(
+    store: &mut TaskStore,
+    list: TaskList,
+    position: &str,
+    priority: &str,
+) -> Result<(), ChartodoError> {
+    let list_name = list.todo().name();
+
+    // check the position
+    let position = store.position_from_arg(
+        list.todo(),
+        position,
+        &format!(
+            "The {} list is currently empty, so there are no todos that can be edited.",
+            list_name
+        ),
+    )?;
+
+    // none takes the priority away
+    let priority = match priority {
+        "none" => None,
+        priority => match priority.parse::<Priority>() {
+            Ok(priority) => Some(priority),
+            Err(priority_error) => {
+                return Err(ChartodoError::InvalidPriority(format!(
+                    "{}, or none.",
+                    priority_error
+                )));
+            }
+        },
+    };
+
+    // edit todo item
+    store.set_priority(list.todo(), position, priority)
+}
+
+// tag and untag both take a position followed by one or more tags. returns the position and the tags if they're all
+// fine
+fn check_position_and_tags(
+    store: &TaskStore,
+    list: PositionsIn,
+    position_and_tags: &[String],
+    command: &str,
+    what_to_do: &str,
+) -> Result<(usize, Vec<String>), ChartodoError> {
+    let list_name = list.name();
+
+    // check if we have the right number of arguments
+    if position_and_tags.len() < 2 {
+        return Err(ChartodoError::WrongNumberOfArguments(format!(
+            "You must specify the {}'s position and at least one tag.\n\tThere should be 2 or more \
+            arguments after 'chartodo {}'. You provided {} argument(s).\n\tFormat: chartodo {} ~position ~tag \
+            [...]\n\tExample: chartodo {} 4 +backend @errands",
+            list_name,
+            command,
+            position_and_tags.len(),
+            command,
+            command
+        )));
+    }
+
+    // check the position
+    let position = store.position_from_arg(
+        list,
+        &position_and_tags[0],
+        &format!(
+            "The {} list is currently empty, so there are no todos that can be {}.",
+            list_name, what_to_do
+        ),
+    )?;
+
+    Ok((position, position_and_tags[1..].to_vec()))
+}
+
+// tags the task already has are skipped
+pub fn tag_todo(
+    store: &mut TaskStore,
+    list: TaskList,
+    position_and_tags: &[String],
+    command: &str,
+) -> Result<(), ChartodoError> {
+    let (position, tags) =
+        check_position_and_tags(store, list.todo(), position_and_tags, command, "tagged")?;
+
+    // tag todo item. the tags themselves are checked by the store
+    store.tag(list.todo(), position, &tags)?;
+
+    Ok(())
+}
+
+// nothing is taken away if the task is missing one of the tags
+pub fn untag_todo(
+    store: &mut TaskStore,
+    list: TaskList,
+    position_and_tags: &[String],
+    command: &str,
+) -> Result<(), ChartodoError> {
+    let (position, tags) =
+        check_position_and_tags(store, list.todo(), position_and_tags, command, "untagged")?;
+
+    // untag todo item
+    store.untag(list.todo(), position, &tags)?;
+
+    Ok(())
+}
+
+// cargo test general_helpers_unit_tests
 #[cfg(test)]
 mod general_helpers_unit_tests {
     use super::*;
+    use crate::functions::storage_backend::JsonBackend;
+    use crate::functions::storage_helpers::{fresh_chartodo_file, test_data_dir};
+
+    fn datetime(datetime: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(datetime, "%Y-%m-%d %H:%M").unwrap()
+    }
 
     #[test]
     fn regular_tasks_list_is_correct() {
         let regular_tasks = Tasks {
             todo: vec![
                 Task {
-                    task: String::from("todo1"),
-                    date: None,
-                    time: None,
-                    repeat_number: None,
-                    repeat_unit: None,
-                    repeat_done: None,
-                    repeat_original_date: None,
-                    repeat_original_time: None,
+                    id: 1,
+                    ..Task::regular(String::from("todo1"))
                 },
                 Task {
-                    task: String::from("todo2"),
-                    date: None,
-                    time: None,
-                    repeat_number: None,
-                    repeat_unit: None,
-                    repeat_done: None,
-                    repeat_original_date: None,
-                    repeat_original_time: None,
+                    id: 12,
+                    ..Task::regular(String::from("todo2"))
                 },
             ],
             done: vec![Task {
-                task: String::from("done"),
-                date: None,
-                time: None,
-                repeat_number: None,
-                repeat_unit: None,
-                repeat_done: None,
-                repeat_original_date: None,
-                repeat_original_time: None,
+                id: 3,
+                ..Task::regular(String::from("done"))
             }],
         };
-        let correct_todo_string = String::from("1: todo1\n2: todo2");
-        let correct_done_string = String::from("DONE\n---\n1: done");
-        let (regular_todo, regular_done) = regular_tasks_list(regular_tasks);
+        let correct_todo_string = String::from("1: todo1 #1\n2: todo2 #12");
+        let correct_done_string = String::from("DONE\n---\n1: done #3");
+        let (regular_todo, regular_done) = regular_tasks_list(regular_tasks, None);
 
         assert_eq!(correct_todo_string, regular_todo);
         assert_eq!(correct_done_string, regular_done);
@@ -392,10 +632,10 @@ mod general_helpers_unit_tests {
 
     #[test]
     fn due_or_not_is_correct() {
-        let should_be_missed =
-            check_if_due_or_not(&String::from("2020-01-01"), &String::from("00:00"));
-        let should_be_due =
-            check_if_due_or_not(&String::from("2300-01-01"), &String::from("00:00"));
+        let _data_dir = test_data_dir();
+
+        let should_be_missed = check_if_due_or_not(Some(datetime("2020-01-01 00:00")));
+        let should_be_due = check_if_due_or_not(Some(datetime("2300-01-01 00:00")));
 
         assert_eq!(should_be_missed, "MISSED".to_string());
         assert_eq!(should_be_due, "due".to_string());
@@ -403,45 +643,23 @@ mod general_helpers_unit_tests {
 
     #[test]
     fn deadline_tasks_list_is_correct() {
+        let _data_dir = test_data_dir();
+
         let deadline_tasks = Tasks {
             todo: vec![
-                Task {
-                    task: String::from("todo1"),
-                    date: Some(String::from("1900-01-01")),
-                    time: Some(String::from("00:00")),
-                    repeat_number: None,
-                    repeat_unit: None,
-                    repeat_done: None,
-                    repeat_original_date: None,
-                    repeat_original_time: None,
-                },
-                Task {
-                    task: String::from("todo2"),
-                    date: Some(String::from("2300-01-01")),
-                    time: Some(String::from("23:48")),
-                    repeat_number: None,
-                    repeat_unit: None,
-                    repeat_done: None,
-                    repeat_original_date: None,
-                    repeat_original_time: None,
-                },
+                Task::deadline(String::from("todo1"), datetime("1900-01-01 00:00")),
+                Task::deadline(String::from("todo2"), datetime("2300-01-01 23:48")),
             ],
-            done: vec![Task {
-                task: String::from("done"),
-                date: Some(String::from("1930-12-25")),
-                time: Some(String::from("01:06")),
-                repeat_number: None,
-                repeat_unit: None,
-                repeat_done: None,
-                repeat_original_date: None,
-                repeat_original_time: None,
-            }],
+            done: vec![Task::deadline(
+                String::from("done"),
+                datetime("1930-12-25 01:06"),
+            )],
         };
         let correct_todo_string = String::from(
-            "1: todo1\n   MISSED: 1900-01-01 00:00\n2: todo2\n   due: 2300-01-01 23:48",
+            "1: todo1 #0\n   MISSED: 1900-01-01 00:00\n2: todo2 #0\n   due: 2300-01-01 23:48",
         );
-        let correct_done_string = String::from("DONE\n---\n1: done\n   done: 1930-12-25 01:06");
-        let (deadline_todo, deadline_done) = deadline_tasks_list(deadline_tasks);
+        let correct_done_string = String::from("DONE\n---\n1: done #0\n   done: 1930-12-25 01:06");
+        let (deadline_todo, deadline_done) = deadline_tasks_list(deadline_tasks, None);
 
         assert_eq!(correct_todo_string, deadline_todo);
         assert_eq!(correct_done_string, deadline_done);
@@ -449,46 +667,64 @@ mod general_helpers_unit_tests {
 
     #[test]
     fn repeating_tasks_list_is_correct() {
+        let _data_dir = test_data_dir();
+
         let repeating_tasks = Tasks {
             todo: vec![
-                Task {
-                    task: String::from("todo1"),
-                    date: Some(String::from("1900-01-01")),
-                    time: Some(String::from("00:00")),
-                    repeat_number: Some(1),
-                    repeat_unit: Some(String::from("year")),
-                    repeat_done: Some(false),
-                    repeat_original_date: Some(String::from("1899-01-01")),
-                    repeat_original_time: Some(String::from("00:00")),
-                },
-                Task {
-                    task: String::from("todo2"),
-                    date: Some(String::from("2300-01-01")),
-                    time: Some(String::from("23:48")),
-                    repeat_number: Some(2),
-                    repeat_unit: Some(String::from("months")),
-                    repeat_done: Some(false),
-                    repeat_original_date: Some(String::from("2299-11-01")),
-                    repeat_original_time: Some(String::from("23:48")),
-                },
+                Task::repeating(
+                    String::from("todo1"),
+                    Repeat {
+                        interval: 1,
+                        unit: RepeatUnit::Years,
+                        on: None,
+                        catch_up: CatchUp::Once,
+                        until: None,
+                        max_occurrences: None,
+                        occurrence: 1,
+                        done: false,
+                        start: datetime("1899-01-01 00:00"),
+                        due: datetime("1900-01-01 00:00"),
+                    },
+                ),
+                Task::repeating(
+                    String::from("todo2"),
+                    Repeat {
+                        interval: 2,
+                        unit: RepeatUnit::Months,
+                        on: None,
+                        catch_up: CatchUp::Once,
+                        until: None,
+                        max_occurrences: None,
+                        occurrence: 1,
+                        done: false,
+                        start: datetime("2299-11-01 23:48"),
+                        due: datetime("2300-01-01 23:48"),
+                    },
+                ),
             ],
-            done: vec![Task {
-                task: String::from("done"),
-                date: Some(String::from("2425-12-25")),
-                time: Some(String::from("01:06")),
-                repeat_number: Some(100),
-                repeat_unit: Some("minutes".to_string()),
-                repeat_done: Some(true),
-                repeat_original_date: Some("2425-12-24".to_string()),
-                repeat_original_time: Some("22:40".to_string()),
-            }],
+            done: vec![Task::repeating(
+                String::from("done"),
+                Repeat {
+                    interval: 100,
+                    unit: RepeatUnit::Minutes,
+                    on: None,
+                    catch_up: CatchUp::Once,
+                    until: None,
+                    max_occurrences: None,
+                    occurrence: 1,
+                    done: true,
+                    start: datetime("2425-12-24 22:40"),
+                    due: datetime("2425-12-25 01:06"),
+                },
+            )],
         };
         let correct_todo_string = String::from(
-            "1: todo1\n   interval: 1 year\n   MISSED: 1900-01-01 00:00\n2: todo2\n   interval: 2 months\n   due: 2300-01-01 23:48",
+            "1: todo1 #0\n   interval: 1 year\n   MISSED: 1900-01-01 00:00\n2: todo2 #0\n   interval: 2 months\n   due: 2300-01-01 23:48",
         );
-        let correct_done_string =
-            String::from("DONE\n---\n1: done\n   interval: 100 minutes\n   done: 2425-12-25 01:06");
-        let (deadline_todo, deadline_done) = repeating_tasks_list(repeating_tasks);
+        let correct_done_string = String::from(
+            "DONE\n---\n1: done #0\n   interval: 100 minutes\n   done: 2425-12-25 01:06",
+        );
+        let (deadline_todo, deadline_done) = repeating_tasks_list(repeating_tasks, None);
 
         assert_eq!(correct_todo_string, deadline_todo);
         assert_eq!(correct_done_string, deadline_done);
@@ -557,4 +793,133 @@ mod general_helpers_unit_tests {
 
         assert_eq!(unwrap_range, vec![6, 7, 8, 9, 10]);
     }
+
+    // nothing here is ever saved, so the store doesn't need a real path
+    fn store_with_regular_todo(todo: Vec<Task>) -> TaskStore {
+        let mut chartodo_file = fresh_chartodo_file();
+        chartodo_file.regular.todo = todo;
+
+        TaskStore::new(
+            Box::new(JsonBackend::new(std::path::PathBuf::new())),
+            chartodo_file,
+        )
+    }
+
+    #[test]
+    fn edit_priority_of_todo_is_correct() {
+        let mut store = store_with_regular_todo(vec![
+            Task::regular(String::from("hi")),
+            Task::regular(String::from("hello")).with_priority(Some(Priority::Low)),
+        ]);
+
+        let set_error = edit_priority_of_todo(&mut store, TaskList::Regular, "1", "h");
+        let clear_error = edit_priority_of_todo(&mut store, TaskList::Regular, "2", "none");
+
+        assert!(set_error.is_ok() && clear_error.is_ok());
+        assert_eq!(store.regular().todo[0].priority, Some(Priority::High));
+        assert_eq!(store.regular().todo[1].priority, None);
+    }
+
+    #[test]
+    fn edit_priority_of_todo_bad_args() {
+        let mut store = store_with_regular_todo(vec![Task::regular(String::from("hi"))]);
+        let bad_args = [("0", "high"), ("2", "high"), ("1", "urgent")];
+
+        for (position, priority) in bad_args {
+            assert!(
+                edit_priority_of_todo(&mut store, TaskList::Regular, position, priority).is_err()
+            );
+        }
+        assert_eq!(store.regular().todo[0].priority, None);
+        assert!(edit_priority_of_todo(
+            &mut store_with_regular_todo(vec![]),
+            TaskList::Regular,
+            "1",
+            "high"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn tag_and_untag_todo_are_correct() {
+        let mut store = store_with_regular_todo(vec![
+            Task::regular(String::from("hi")),
+            Task::regular(String::from("hello")).with_tags(&[String::from("@errands")]),
+        ]);
+
+        let tag_error = tag_todo(
+            &mut store,
+            TaskList::Regular,
+            &[
+                String::from("1"),
+                String::from("backend"),
+                String::from("+backend"),
+            ],
+            "tag",
+        );
+        let untag_error = untag_todo(
+            &mut store,
+            TaskList::Regular,
+            &[String::from("2"), String::from("errands")],
+            "untag",
+        );
+
+        assert!(tag_error.is_ok() && untag_error.is_ok());
+        assert_eq!(store.regular().todo[0].tags, vec!["+backend"]);
+        assert!(store.regular().todo[1].tags.is_empty());
+    }
+
+    #[test]
+    fn tag_and_untag_todo_bad_args() {
+        let mut store = store_with_regular_todo(vec![
+            Task::regular(String::from("hi")).with_tags(&[String::from("+backend")])
+        ]);
+        let bad_args = [
+            vec![String::from("1")],
+            vec![String::from("0"), String::from("backend")],
+            vec![String::from("2"), String::from("backend")],
+            vec![String::from("1"), String::from("+")],
+        ];
+
+        for args in bad_args {
+            assert!(tag_todo(&mut store, TaskList::Regular, &args, "tag").is_err());
+            assert!(untag_todo(&mut store, TaskList::Regular, &args, "untag").is_err());
+        }
+        // nothing is taken away if one of the tags isn't there
+        assert!(untag_todo(
+            &mut store,
+            TaskList::Regular,
+            &[
+                String::from("1"),
+                String::from("backend"),
+                String::from("frontend")
+            ],
+            "untag"
+        )
+        .is_err());
+        assert_eq!(store.regular().todo[0].tags, vec!["+backend"]);
+        assert!(tag_todo(
+            &mut store_with_regular_todo(vec![]),
+            TaskList::Regular,
+            &[String::from("1"), String::from("backend")],
+            "tag"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn list_only_shows_tagged_tasks_at_their_positions() {
+        let regular_tasks = Tasks {
+            todo: vec![
+                Task::regular(String::from("hi")),
+                Task::regular(String::from("hello")).with_tags(&[String::from("+backend")]),
+            ],
+            done: vec![Task::regular(String::from("hey"))],
+        };
+
+        let (regular_todo, regular_done) = regular_tasks_list(regular_tasks, Some("backend"));
+
+        assert_eq!(regular_todo, "2: hello #0 +backend");
+        assert_eq!(regular_done, "");
+    }
 }
@@ -0,0 +1,386 @@
+use crate::functions::{
+    json_file_structs::Task, storage_helpers::try_write_to_file, task_store::TaskList,
+};
+use anyhow::Context;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, ErrorKind, Seek, SeekFrom, Write},
+    path::Path,
+};
+
+// things that happened to tasks, kept in history.jsonl next to chartodo.json (or chartodo.sqlite3), one json object
+// per line. lines are only added, so it still has everything after the lists themselves have moved on, e.g., when
+// a repeating task rolls over or skips ahead, or a done list is cleared. the only exception is undo, which takes back
+// the lines of the command it undid, and redo, which puts them back. see undo_redo. chartodo history shows it
+//
+// a TaskStore holds on to what happened until it's saved, then adds it here, see TaskStore::save
+
+pub const HISTORY_FILE_NAME: &str = "history.jsonl";
+
+// the time something happened is kept down to the second, unlike due dates
+const HISTORY_AT_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+const HISTORY_SCHEDULED_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryEventKind {
+    // a task was marked done, whether one at a time or w/ doneall
+    Completed,
+    // an occurrence of a repeating task went by w/o it being done, and it caught up past it. see CatchUp
+    Skipped,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "HistoryRecord", into = "HistoryRecord")]
+pub struct HistoryEvent {
+    pub event: HistoryEventKind,
+    pub list: TaskList,
+    pub id: u32,
+    pub task: String,
+    // when it was due, for tasks that have a due date+time
+    pub scheduled: Option<NaiveDateTime>,
+    // when it happened
+    pub at: NaiveDateTime,
+}
+
+impl HistoryEvent {
+    // for a repeating task, scheduled is the occurrence that was done
+    pub fn completed(list: TaskList, task: &Task, at: NaiveDateTime) -> HistoryEvent {
+        HistoryEvent {
+            event: HistoryEventKind::Completed,
+            list,
+            id: task.id,
+            task: task.task.clone(),
+            scheduled: task.due(),
+            at,
+        }
+    }
+
+    // both ends are included, and either can be left open
+    pub fn happened_between(&self, from: Option<NaiveDateTime>, to: Option<NaiveDateTime>) -> bool {
+        from.is_none_or(|from| self.at >= from) && to.is_none_or(|to| self.at <= to)
+    }
+}
+
+impl fmt::Display for HistoryEventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let event = match self {
+            HistoryEventKind::Completed => "completed",
+            HistoryEventKind::Skipped => "skipped",
+        };
+
+        write!(f, "{}", event)
+    }
+}
+
+// e.g., 2099-01-10 08:30:15 completed deadline #3 taxes, due 2099-04-15 00:00
+impl fmt::Display for HistoryEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} #{} {}",
+            self.at.format(HISTORY_AT_FORMAT),
+            self.event,
+            self.list,
+            self.id,
+            self.task
+        )?;
+        if let Some(scheduled) = self.scheduled {
+            write!(f, ", due {}", scheduled.format(HISTORY_SCHEDULED_FORMAT))?;
+        }
+
+        Ok(())
+    }
+}
+
+// a HistoryEvent exactly as it's written to a line of the file
+#[derive(Debug, Deserialize, Serialize)]
+struct HistoryRecord {
+    event: String,
+    list: String,
+    id: u32,
+    task: String,
+    scheduled: Option<String>,
+    at: String,
+}
+
+impl TryFrom<HistoryRecord> for HistoryEvent {
+    type Error = String;
+
+    fn try_from(record: HistoryRecord) -> Result<Self, Self::Error> {
+        let parse = |datetime: &str, format: &str| {
+            NaiveDateTime::parse_from_str(datetime, format)
+                .map_err(|_| format!("'{}' isn't a date and time in the right format", datetime))
+        };
+
+        Ok(HistoryEvent {
+            event: match record.event.as_str() {
+                "completed" => HistoryEventKind::Completed,
+                "skipped" => HistoryEventKind::Skipped,
+                event => return Err(format!("'{}' isn't something that can happen", event)),
+            },
+            list: record.list.parse()?,
+            id: record.id,
+            task: record.task,
+            scheduled: record
+                .scheduled
+                .map(|scheduled| parse(&scheduled, HISTORY_SCHEDULED_FORMAT))
+                .transpose()?,
+            at: parse(&record.at, HISTORY_AT_FORMAT)?,
+        })
+    }
+}
+
+impl From<HistoryEvent> for HistoryRecord {
+    fn from(event: HistoryEvent) -> Self {
+        HistoryRecord {
+            event: event.event.to_string(),
+            list: event.list.to_string(),
+            id: event.id,
+            task: event.task,
+            scheduled: event
+                .scheduled
+                .map(|scheduled| scheduled.format(HISTORY_SCHEDULED_FORMAT).to_string()),
+            at: event.at.format(HISTORY_AT_FORMAT).to_string(),
+        }
+    }
+}
+
+pub fn append_history(history_path: &Path, events: &[HistoryEvent]) -> anyhow::Result<()> {
+    if events.is_empty() {
+        return Ok(());
+    }
+
+    // written in one go, so that a line is never only half there
+    let lines = to_lines(events)?;
+
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(history_path)
+        .and_then(|mut history_file| history_file.write_all(lines.as_bytes()))
+        .with_context(|| format!("couldn't add to {}", history_path.display()))
+}
+
+// puts the file back to its first len bytes, followed by events, e.g., when undo takes lines back. written the same
+// way as the lists, so that the file is never only half there
+pub fn rewind_history(
+    history_path: &Path,
+    len: u64,
+    events: &[HistoryEvent],
+) -> anyhow::Result<()> {
+    let mut contents = match std::fs::read(history_path) {
+        Ok(contents) => contents,
+        Err(read_error) if read_error.kind() == ErrorKind::NotFound => vec![],
+        Err(read_error) => {
+            return Err(read_error)
+                .with_context(|| format!("couldn't read {}", history_path.display()))
+        }
+    };
+    contents.truncate(usize::try_from(len).unwrap_or(usize::MAX));
+    contents.extend_from_slice(to_lines(events)?.as_bytes());
+
+    try_write_to_file(history_path, &contents)
+}
+
+// how long the file is, in bytes. lines are only ever added to the end, so this is all that's needed to find the
+// ones that came after, see read_history_after. no file is the same as an empty one
+pub fn history_len(history_path: &Path) -> anyhow::Result<u64> {
+    match std::fs::metadata(history_path) {
+        Ok(metadata) => Ok(metadata.len()),
+        Err(read_error) if read_error.kind() == ErrorKind::NotFound => Ok(0),
+        Err(read_error) => {
+            Err(read_error).with_context(|| format!("couldn't read {}", history_path.display()))
+        }
+    }
+}
+
+fn to_lines(events: &[HistoryEvent]) -> anyhow::Result<String> {
+    let mut lines = String::new();
+    for event in events {
+        lines.push_str(&serde_json::to_string(event)?);
+        lines.push('\n');
+    }
+
+    Ok(lines)
+}
+
+// everything in the file, oldest first. no file means nothing has happened yet
+pub fn read_history(history_path: &Path) -> anyhow::Result<Vec<HistoryEvent>> {
+    read_history_after(history_path, 0)
+}
+
+// only the events after the first len bytes, e.g., the ones a command added, so that the rest isn't read. see
+// history_len
+pub fn read_history_after(history_path: &Path, len: u64) -> anyhow::Result<Vec<HistoryEvent>> {
+    if !history_path.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut history_file = File::open(history_path)
+        .with_context(|| format!("couldn't open {}", history_path.display()))?;
+    history_file
+        .seek(SeekFrom::Start(len))
+        .with_context(|| format!("couldn't read {}", history_path.display()))?;
+    let mut events = vec![];
+    for (index, line) in BufReader::new(history_file).lines().enumerate() {
+        let line = line.with_context(|| format!("couldn't read {}", history_path.display()))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        // w/ len, the lines are counted from there
+        events.push(serde_json::from_str(&line).with_context(|| match len {
+            0 => format!(
+                "line {} of {} isn't right",
+                index + 1,
+                history_path.display()
+            ),
+            _ => format!(
+                "line {} after the first {} bytes of {} isn't right",
+                index + 1,
+                len,
+                history_path.display()
+            ),
+        })?);
+    }
+
+    Ok(events)
+}
+
+// cargo test history_unit_tests
+#[cfg(test)]
+mod history_unit_tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn datetime(datetime: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(datetime, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    fn fresh_test_dir(test_name: &str) -> PathBuf {
+        let mut test_dir = std::env::temp_dir();
+        test_dir.push(format!(
+            "chartodo_history_unit_tests_{}_{}",
+            test_name,
+            std::process::id()
+        ));
+
+        // in case a previous run didn't clean up after itself
+        if test_dir.exists() {
+            std::fs::remove_dir_all(&test_dir).unwrap();
+        }
+        std::fs::create_dir(&test_dir).unwrap();
+
+        test_dir
+    }
+
+    #[test]
+    fn history_is_appended_to() {
+        let test_dir = fresh_test_dir("appended");
+        let history_path = test_dir.join(HISTORY_FILE_NAME);
+        assert!(read_history(&history_path).unwrap().is_empty());
+
+        let skipped = |scheduled: &str| HistoryEvent {
+            event: HistoryEventKind::Skipped,
+            list: TaskList::Repeating,
+            id: 4,
+            task: String::from("gym"),
+            scheduled: Some(datetime(scheduled)),
+            at: datetime("2099-01-10 08:30:15"),
+        };
+        append_history(&history_path, &[skipped("2099-01-02 00:00:00")]).unwrap();
+        append_history(&history_path, &[]).unwrap();
+        append_history(&history_path, &[skipped("2099-01-03 00:00:00")]).unwrap();
+
+        assert_eq!(
+            read_history(&history_path).unwrap(),
+            vec![
+                skipped("2099-01-02 00:00:00"),
+                skipped("2099-01-03 00:00:00")
+            ]
+        );
+        let written = std::fs::read_to_string(&history_path).unwrap();
+        assert!(written
+            .lines()
+            .next()
+            .unwrap()
+            .contains(r#""at":"2099-01-10 08:30:15""#));
+
+        std::fs::write(&history_path, "{\"event\": \"exploded\"}\n").unwrap();
+        assert!(read_history(&history_path).is_err());
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+
+    #[test]
+    fn history_is_rewound_by_length() {
+        let test_dir = fresh_test_dir("rewound");
+        let history_path = test_dir.join(HISTORY_FILE_NAME);
+        assert_eq!(history_len(&history_path).unwrap(), 0);
+
+        let completed = |id: u32| HistoryEvent {
+            event: HistoryEventKind::Completed,
+            list: TaskList::Regular,
+            id,
+            task: String::from("hi"),
+            scheduled: None,
+            at: datetime("2099-01-10 08:30:15"),
+        };
+        append_history(&history_path, &[completed(1)]).unwrap();
+        let len = history_len(&history_path).unwrap();
+        append_history(&history_path, &[completed(2), completed(3)]).unwrap();
+
+        // only what came after len is read
+        assert_eq!(
+            read_history_after(&history_path, len).unwrap(),
+            vec![completed(2), completed(3)]
+        );
+
+        // and taken back, w/ something else after it
+        rewind_history(&history_path, len, &[completed(4)]).unwrap();
+        assert_eq!(
+            read_history(&history_path).unwrap(),
+            vec![completed(1), completed(4)]
+        );
+        rewind_history(&history_path, 0, &[]).unwrap();
+        assert_eq!(history_len(&history_path).unwrap(), 0);
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+
+    #[test]
+    fn events_are_shown_and_filtered() {
+        let completed = HistoryEvent {
+            event: HistoryEventKind::Completed,
+            list: TaskList::Deadline,
+            id: 3,
+            task: String::from("taxes"),
+            scheduled: Some(datetime("2099-04-15 00:00:00")),
+            at: datetime("2099-04-10 08:30:15"),
+        };
+        assert_eq!(
+            completed.to_string(),
+            "2099-04-10 08:30:15 completed deadline #3 taxes, due 2099-04-15 00:00"
+        );
+        assert_eq!(
+            HistoryEvent {
+                list: TaskList::Regular,
+                scheduled: None,
+                ..completed.clone()
+            }
+            .to_string(),
+            "2099-04-10 08:30:15 completed regular #3 taxes"
+        );
+
+        assert!(completed.happened_between(None, None));
+        assert!(completed.happened_between(
+            Some(datetime("2099-04-10 00:00:00")),
+            Some(datetime("2099-04-10 23:59:00"))
+        ));
+        assert!(!completed.happened_between(Some(datetime("2099-04-11 00:00:00")), None));
+        assert!(!completed.happened_between(None, Some(datetime("2099-04-09 23:59:00"))));
+    }
+}
@@ -1,4 +1,64 @@
+use crate::functions::recurrence::{
+    next_occurrence, previous_occurrence, CatchUp, RepeatOn, RepeatRules,
+};
+use anyhow::bail;
+use chrono::{Days, Duration, Months, NaiveDateTime};
 use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+// what chartodo.json holds. see migrations for what schema_version is about
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ChartodoFile {
+    pub schema_version: u32,
+    // the id the next new task gets. it only ever goes up, so an id is never reused, even after its task is removed
+    pub next_id: u32,
+    pub regular: Tasks,
+    pub deadline: Tasks,
+    pub repeating: Tasks,
+}
+
+impl ChartodoFile {
+    // new tasks are made w/ an id of 0, i.e., no id yet, and get a real one here right before they're written. that
+    // way no command has to think about ids when it adds a task
+    pub fn give_ids_to_new_tasks(&mut self) {
+        for tasks in [&mut self.regular, &mut self.deadline, &mut self.repeating] {
+            for task in tasks.todo.iter_mut().chain(tasks.done.iter_mut()) {
+                if task.id == 0 {
+                    task.id = self.next_id;
+                    self.next_id += 1;
+                }
+            }
+        }
+    }
+
+    // every command only ever puts a task in the list for its kind, so a task in the wrong list means the file was
+    // edited by hand. better to say so now than to trip over it in the middle of a command
+    pub fn check_task_kinds(&self) -> anyhow::Result<()> {
+        let lists = [
+            ("regular", &self.regular, TaskKindName::Regular),
+            ("deadline", &self.deadline, TaskKindName::Deadline),
+            ("repeating", &self.repeating, TaskKindName::Repeating),
+        ];
+
+        for (list_name, tasks, kind_name) in lists {
+            if let Some(task) = tasks
+                .todo
+                .iter()
+                .chain(tasks.done.iter())
+                .find(|task| task.kind.name() != kind_name)
+            {
+                bail!(
+                    "'{}' is in the {} list, but it isn't a {} task",
+                    task.task,
+                    list_name,
+                    list_name
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct Tasks {
@@ -6,23 +66,697 @@ pub struct Tasks {
     pub done: Vec<Task>,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+// how dates and times are written, both in the file and when the lists are shown
+pub const DATE_FORMAT: &str = "%Y-%m-%d";
+pub const TIME_FORMAT: &str = "%H:%M";
+const DATE_TIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+// a repeating task that only has an until, e.g., every minute until 2100, could have millions of occurrences left.
+// past this many, it's shown as this many or more
+pub const MAX_COUNTED_OCCURRENCES: u32 = 10_000;
+
+// the file still stores every task w/ the same flat fields it always has (see TaskRecord). serde turns them into
+// this on the way in and back on the way out, so a task that doesn't make sense is an error when the file is read
+// instead of a panic halfway through a command
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(try_from = "TaskRecord", into = "TaskRecord")]
 pub struct Task {
+    // stays the same no matter where the task moves to, unlike its position. 0 means it hasn't been given one yet
+    pub id: u32,
     pub task: String,
+    pub kind: TaskKind,
+    pub priority: Option<Priority>,
+    // e.g., +backend or @errands. always stored w/ their + or @, see parse_tag
+    pub tags: Vec<String>,
+    // when it was moved to done, so that the done lists can be kept short, see Retention. None for todos
+    pub done_at: Option<NaiveDateTime>,
+}
+
+// the id and done_at are left out on purpose. they're only bookkeeping, so two tasks that say the same thing are the
+// same task no matter what id they ended up with or when they were done
+impl PartialEq for Task {
+    fn eq(&self, other: &Self) -> bool {
+        self.task == other.task
+            && self.kind == other.kind
+            && self.priority == other.priority
+            && self.tags == other.tags
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TaskKind {
+    Regular,
+    Deadline { due: NaiveDateTime },
+    Repeating(Repeat),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TaskKindName {
+    Regular,
+    Deadline,
+    Repeating,
+}
+
+impl TaskKind {
+    fn name(&self) -> TaskKindName {
+        match self {
+            TaskKind::Regular => TaskKindName::Regular,
+            TaskKind::Deadline { .. } => TaskKindName::Deadline,
+            TaskKind::Repeating(_) => TaskKindName::Repeating,
+        }
+    }
+}
+
+impl Task {
+    pub fn regular(task: String) -> Task {
+        Task {
+            id: 0,
+            task,
+            priority: None,
+            tags: vec![],
+            done_at: None,
+            kind: TaskKind::Regular,
+        }
+    }
+
+    pub fn deadline(task: String, due: NaiveDateTime) -> Task {
+        Task {
+            id: 0,
+            task,
+            priority: None,
+            tags: vec![],
+            done_at: None,
+            kind: TaskKind::Deadline { due },
+        }
+    }
+
+    pub fn repeating(task: String, repeat: Repeat) -> Task {
+        Task {
+            id: 0,
+            task,
+            priority: None,
+            tags: vec![],
+            done_at: None,
+            kind: TaskKind::Repeating(repeat),
+        }
+    }
+
+    pub fn with_priority(mut self, priority: Option<Priority>) -> Task {
+        self.priority = priority;
+        self
+    }
+
+    pub fn with_tags(mut self, tags: &[String]) -> Task {
+        self.tags = tags.to_vec();
+        self
+    }
+
+    pub fn has_tag(&self, filter: &str) -> bool {
+        self.tags.iter().any(|tag| tag_matches(tag, filter))
+    }
+
+    // the second thing deadline and repeating tasks are sorted by, after when they're due. high comes first, and
+    // tasks w/o a priority come last
+    pub fn priority_rank(&self) -> u8 {
+        match self.priority {
+            Some(Priority::High) => 0,
+            Some(Priority::Medium) => 1,
+            Some(Priority::Low) => 2,
+            None => 3,
+        }
+    }
+
+    // regular tasks are never due
+    pub fn due(&self) -> Option<NaiveDateTime> {
+        match &self.kind {
+            TaskKind::Regular => None,
+            TaskKind::Deadline { due } => Some(*due),
+            TaskKind::Repeating(repeat) => Some(repeat.due),
+        }
+    }
+
+    pub fn repeat(&self) -> Option<&Repeat> {
+        match &self.kind {
+            TaskKind::Repeating(repeat) => Some(repeat),
+            _ => None,
+        }
+    }
+
+    pub fn repeat_mut(&mut self) -> Option<&mut Repeat> {
+        match &mut self.kind {
+            TaskKind::Repeating(repeat) => Some(repeat),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Priority {
+    High,
+    Medium,
+    Low,
+}
+
+impl FromStr for Priority {
+    type Err = String;
+
+    // 1 is the most urgent, same as high
+    fn from_str(priority: &str) -> Result<Self, Self::Err> {
+        match priority {
+            "high" | "h" | "1" => Ok(Priority::High),
+            "medium" | "m" | "2" => Ok(Priority::Medium),
+            "low" | "l" | "3" => Ok(Priority::Low),
+            _ => Err(format!(
+                "'{}' isn't a priority. It has to be high, medium, or low (or h, m, l, or 1, 2, 3)",
+                priority
+            )),
+        }
+    }
+}
+
+// a filter w/o a + or @ matches either, e.g., backend matches +backend and @backend
+pub fn tag_matches(tag: &str, filter: &str) -> bool {
+    match filter.starts_with(['+', '@']) {
+        true => tag == filter,
+        false => tag.get(1..) == Some(filter),
+    }
+}
+
+// tags are a + or @ followed by a word, e.g., +backend or @errands. a tag w/o either gets a +, so backend is the
+// same as +backend
+pub fn parse_tag(tag: &str) -> Result<String, String> {
+    let tag = match tag.starts_with(['+', '@']) {
+        true => tag.to_string(),
+        false => format!("+{}", tag),
+    };
+
+    if tag.len() == 1 || tag.chars().any(|character| character.is_whitespace()) {
+        return Err(format!(
+            "'{}' isn't a tag. It has to be + or @ followed by a word w/o spaces, e.g., +backend or @errands",
+            tag
+        ));
+    }
+
+    Ok(tag)
+}
+
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let priority = match self {
+            Priority::High => "high",
+            Priority::Medium => "medium",
+            Priority::Low => "low",
+        };
+
+        write!(f, "{}", priority)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Repeat {
+    pub interval: u32,
+    pub unit: RepeatUnit,
+    // which days it lands on, e.g., mon,wed,fri or last day. None is every interval from the start. see recurrence
+    pub on: Option<RepeatOn>,
+    // what happens once it's been done for more than one occurrence, see CatchUp
+    pub catch_up: CatchUp,
+    // when the series ends, if it does. see RepeatRules
+    pub until: Option<NaiveDateTime>,
+    pub max_occurrences: Option<u32>,
+    // which occurrence due is, starting at 1. only kept track of for max_occurrences
+    pub occurrence: u32,
+    // note: a repeating task in the done list stays there until it's due, then it goes back to todo
+    pub done: bool,
+    // to anyone reading this, the purpose of the start is so that if the user wants to set a new interval number
+    // and/or unit, there needs to be a record of when the task starts
+    pub start: NaiveDateTime,
+    pub due: NaiveDateTime,
+}
+
+impl Repeat {
+    // None if the due date would be out of chrono's range
+    pub fn starting_at(start: NaiveDateTime, interval: u32, unit: RepeatUnit) -> Option<Repeat> {
+        Repeat::starting_at_on(start, interval, unit, None)
+    }
+
+    // w/ a rule, the task is first due on the first day after start that fits it
+    pub fn starting_at_on(
+        start: NaiveDateTime,
+        interval: u32,
+        unit: RepeatUnit,
+        on: Option<RepeatOn>,
+    ) -> Option<Repeat> {
+        Some(Repeat {
+            interval,
+            unit,
+            on,
+            catch_up: CatchUp::Once,
+            until: None,
+            max_occurrences: None,
+            occurrence: 1,
+            done: false,
+            start,
+            due: next_occurrence(start, interval, unit, on)?,
+        })
+    }
+
+    // None if the start would be out of chrono's range
+    pub fn ending_at(due: NaiveDateTime, interval: u32, unit: RepeatUnit) -> Option<Repeat> {
+        Repeat::ending_at_on(due, interval, unit, None)
+    }
+
+    // w/ a rule, the task starts at the occurrence before due, so that it's due again on the days the rule says. the
+    // due date is kept as is, even if it doesn't fit the rule. the ones after it will
+    pub fn ending_at_on(
+        due: NaiveDateTime,
+        interval: u32,
+        unit: RepeatUnit,
+        on: Option<RepeatOn>,
+    ) -> Option<Repeat> {
+        Some(Repeat {
+            interval,
+            unit,
+            on,
+            catch_up: CatchUp::Once,
+            until: None,
+            max_occurrences: None,
+            occurrence: 1,
+            done: false,
+            start: previous_occurrence(due, interval, unit, on)?,
+            due,
+        })
+    }
+
+    // the same task starting over from start, w/ the same interval, unit, rule, and catch up
+    pub fn restarted_at(&self, start: NaiveDateTime) -> Option<Repeat> {
+        Some(Repeat {
+            done: false,
+            start,
+            due: next_occurrence(start, self.interval, self.unit, self.on)?,
+            ..*self
+        })
+    }
+
+    // the same task, w/ everything else it was added w/, e.g., --catch-up or --until. the rule is expected to already
+    // be the one it was made w/
+    pub fn with_rules(self, rules: RepeatRules) -> Repeat {
+        Repeat {
+            on: rules.on,
+            catch_up: rules.catch_up,
+            until: rules.until,
+            max_occurrences: rules.max_occurrences,
+            ..self
+        }
+    }
+
+    // whether due is still part of the series, i.e., not after until and not past max_occurrences
+    pub fn within_end(&self) -> bool {
+        self.until.is_none_or(|until| self.due <= until)
+            && self
+                .max_occurrences
+                .is_none_or(|max_occurrences| self.occurrence <= max_occurrences)
+    }
+
+    // the occurrence after this one. None if the series ends before it, or if it'd be out of chrono's range
+    pub fn next(&self) -> Option<Repeat> {
+        let next = Repeat {
+            occurrence: self.occurrence.saturating_add(1),
+            ..self.restarted_at(self.due)?
+        };
+
+        next.within_end().then_some(next)
+    }
+
+    // done, w/ nothing left to come back for
+    pub fn is_finished(&self) -> bool {
+        self.done && self.next().is_none()
+    }
+
+    // how many occurrences are left, counting this one if it isn't done yet. None if it repeats forever. w/ only an
+    // until, they're counted one by one, so it stops counting at MAX_COUNTED_OCCURRENCES
+    pub fn remaining(&self) -> Option<u32> {
+        if self.until.is_none() && self.max_occurrences.is_none() {
+            return None;
+        }
+
+        let mut remaining = u32::from(!self.done && self.within_end());
+        if let (None, Some(max_occurrences)) = (self.until, self.max_occurrences) {
+            return Some(remaining + max_occurrences.saturating_sub(self.occurrence));
+        }
+
+        let mut repeat = *self;
+        while remaining < MAX_COUNTED_OCCURRENCES {
+            let Some(next) = repeat.next() else {
+                break;
+            };
+            remaining += 1;
+            repeat = next;
+        }
+
+        Some(remaining)
+    }
+
+    // e.g., until 2099-02-01 23:59, 12 times. None if it repeats forever
+    pub fn ends(&self) -> Option<String> {
+        let until = self.until.map(|until| {
+            format!(
+                "until {} {}",
+                until.format(DATE_FORMAT),
+                until.format(TIME_FORMAT)
+            )
+        });
+        let times = self
+            .max_occurrences
+            .map(|max_occurrences| match max_occurrences {
+                1 => String::from("1 time"),
+                max_occurrences => format!("{} times", max_occurrences),
+            });
+
+        match (until, times) {
+            (Some(until), Some(times)) => Some(format!("{}, {}", until, times)),
+            (until, times) => until.or(times),
+        }
+    }
+
+    // e.g., 3 days, or 1 week on mon,wed,fri
+    pub fn every(&self) -> String {
+        match self.on {
+            Some(on) => format!("{} on {}", self.unit.with_interval(self.interval), on),
+            None => self.unit.with_interval(self.interval),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepeatUnit {
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+impl FromStr for RepeatUnit {
+    type Err = String;
+
+    fn from_str(unit: &str) -> Result<Self, Self::Err> {
+        match unit {
+            "minutes" | "minute" => Ok(RepeatUnit::Minutes),
+            "hours" | "hour" => Ok(RepeatUnit::Hours),
+            "days" | "day" => Ok(RepeatUnit::Days),
+            "weeks" | "week" => Ok(RepeatUnit::Weeks),
+            "months" | "month" => Ok(RepeatUnit::Months),
+            "years" | "year" => Ok(RepeatUnit::Years),
+            _ => Err(format!("'{}' isn't a time unit", unit)),
+        }
+    }
+}
+
+// this is what gets written to the file
+impl fmt::Display for RepeatUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unit = match self {
+            RepeatUnit::Minutes => "minutes",
+            RepeatUnit::Hours => "hours",
+            RepeatUnit::Days => "days",
+            RepeatUnit::Weeks => "weeks",
+            RepeatUnit::Months => "months",
+            RepeatUnit::Years => "years",
+        };
+
+        write!(f, "{}", unit)
+    }
+}
+
+impl RepeatUnit {
+    // e.g., 1 day, 3 days
+    pub fn with_interval(&self, interval: u32) -> String {
+        let plural = self.to_string();
+        match interval {
+            1 => format!("{} {}", interval, plural.trim_end_matches('s')),
+            _ => format!("{} {}", interval, plural),
+        }
+    }
+
+    pub fn add_to(&self, datetime: NaiveDateTime, interval: u32) -> Option<NaiveDateTime> {
+        match self {
+            RepeatUnit::Minutes => datetime.checked_add_signed(Duration::minutes(interval.into())),
+            RepeatUnit::Hours => datetime.checked_add_signed(Duration::hours(interval.into())),
+            RepeatUnit::Days => datetime.checked_add_days(Days::new(interval.into())),
+            RepeatUnit::Weeks => datetime.checked_add_days(Days::new(u64::from(interval) * 7)),
+            RepeatUnit::Months => datetime.checked_add_months(Months::new(interval)),
+            RepeatUnit::Years => {
+                datetime.checked_add_months(Months::new(interval.checked_mul(12)?))
+            }
+        }
+    }
+
+    pub fn subtract_from(&self, datetime: NaiveDateTime, interval: u32) -> Option<NaiveDateTime> {
+        match self {
+            RepeatUnit::Minutes => datetime.checked_sub_signed(Duration::minutes(interval.into())),
+            RepeatUnit::Hours => datetime.checked_sub_signed(Duration::hours(interval.into())),
+            RepeatUnit::Days => datetime.checked_sub_days(Days::new(interval.into())),
+            RepeatUnit::Weeks => datetime.checked_sub_days(Days::new(u64::from(interval) * 7)),
+            RepeatUnit::Months => datetime.checked_sub_months(Months::new(interval)),
+            RepeatUnit::Years => {
+                datetime.checked_sub_months(Months::new(interval.checked_mul(12)?))
+            }
+        }
+    }
+}
+
+// a task exactly as it's stored in the file. every kind of task has every field, and the ones that don't apply are
+// null
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+struct TaskRecord {
+    // tasks from before there were ids don't have one
+    #[serde(default)]
+    id: u32,
+    task: String,
     #[serde(default = "return_default_date")]
-    pub date: Option<String>,
+    date: Option<String>,
     #[serde(default = "return_default_time")]
-    pub time: Option<String>,
+    time: Option<String>,
     #[serde(default = "return_default_repeat_number")]
-    pub repeat_number: Option<u32>,
+    repeat_number: Option<u32>,
     #[serde(default = "return_default_repeat_unit")]
-    pub repeat_unit: Option<String>,
+    repeat_unit: Option<String>,
     #[serde(default = "return_default_repeat_done")]
-    pub repeat_done: Option<bool>,
+    repeat_done: Option<bool>,
     #[serde(default = "return_default_repeat_original_date")]
-    pub repeat_original_date: Option<String>,
+    repeat_original_date: Option<String>,
     #[serde(default = "return_default_repeat_original_time")]
-    pub repeat_original_time: Option<String>,
+    repeat_original_time: Option<String>,
+    // tasks from before there were repeat rules don't have one. see recurrence
+    #[serde(default)]
+    repeat_on: Option<String>,
+    // same for how they catch up. once isn't written, since that's what they all did before
+    #[serde(default)]
+    repeat_catch_up: Option<String>,
+    // and for when they end. repeat_occurrence is only written along w/ repeat_max_occurrences
+    #[serde(default)]
+    repeat_until: Option<String>,
+    #[serde(default)]
+    repeat_max_occurrences: Option<u32>,
+    #[serde(default)]
+    repeat_occurrence: Option<u32>,
+    // tasks from before there were priorities don't have one
+    #[serde(default)]
+    priority: Option<Priority>,
+    // same for tags
+    #[serde(default)]
+    tags: Vec<String>,
+    // and for when they were done. done tasks from before this are treated as done when they're first saved again
+    #[serde(default)]
+    done_at: Option<String>,
+}
+
+fn parse_date_time(task: &str, date: &str, time: &str) -> Result<NaiveDateTime, String> {
+    NaiveDateTime::parse_from_str(&format!("{} {}", date, time), DATE_TIME_FORMAT).map_err(|_| {
+        format!(
+            "'{}' has a date and time, '{} {}', that isn't in a year-month-day hour:minute format",
+            task, date, time
+        )
+    })
+}
+
+impl TryFrom<TaskRecord> for Task {
+    type Error = String;
+
+    fn try_from(record: TaskRecord) -> Result<Self, Self::Error> {
+        let has_repeat_fields = record.repeat_number.is_some()
+            || record.repeat_unit.is_some()
+            || record.repeat_done.is_some()
+            || record.repeat_original_date.is_some()
+            || record.repeat_original_time.is_some()
+            || record.repeat_on.is_some()
+            || record.repeat_catch_up.is_some()
+            || record.repeat_until.is_some()
+            || record.repeat_max_occurrences.is_some()
+            || record.repeat_occurrence.is_some();
+
+        // which fields are set is what decides the kind of task
+        let mut task = match (record.date, record.time) {
+            (None, None) if !has_repeat_fields => Ok(Task::regular(record.task)),
+            (Some(date), Some(time)) if !has_repeat_fields => {
+                let due = parse_date_time(&record.task, &date, &time)?;
+                Ok(Task::deadline(record.task, due))
+            }
+            (Some(date), Some(time)) => {
+                let (
+                    Some(interval),
+                    Some(unit),
+                    Some(done),
+                    Some(original_date),
+                    Some(original_time),
+                ) = (
+                    record.repeat_number,
+                    record.repeat_unit,
+                    record.repeat_done,
+                    record.repeat_original_date,
+                    record.repeat_original_time,
+                )
+                else {
+                    return Err(format!(
+                        "'{}' is missing some of its repeat_number, repeat_unit, repeat_done, \
+                        repeat_original_date, and repeat_original_time",
+                        record.task
+                    ));
+                };
+
+                let unit: RepeatUnit = unit
+                    .parse()
+                    .map_err(|unit_error| format!("'{}': {}", record.task, unit_error))?;
+                let on = record
+                    .repeat_on
+                    .map(|on| on.parse::<RepeatOn>())
+                    .transpose()
+                    .map_err(|on_error| format!("'{}': {}", record.task, on_error))?;
+                if let Some(on) = on.filter(|on| on.unit() != unit) {
+                    return Err(format!(
+                        "'{}' repeats on {}, which only works w/ {}, not {}",
+                        record.task,
+                        on,
+                        on.unit(),
+                        unit
+                    ));
+                }
+
+                let catch_up = record
+                    .repeat_catch_up
+                    .map(|catch_up| catch_up.parse::<CatchUp>())
+                    .transpose()
+                    .map_err(|catch_up_error| format!("'{}': {}", record.task, catch_up_error))?
+                    .unwrap_or_default();
+
+                let until = record
+                    .repeat_until
+                    .map(|until| {
+                        NaiveDateTime::parse_from_str(&until, DATE_TIME_FORMAT).map_err(|_| {
+                            format!(
+                                "'{}' repeats until '{}', which isn't in a year-month-day hour:minute format",
+                                record.task, until
+                            )
+                        })
+                    })
+                    .transpose()?;
+
+                let repeat = Repeat {
+                    interval,
+                    unit,
+                    on,
+                    catch_up,
+                    until,
+                    max_occurrences: record.repeat_max_occurrences,
+                    occurrence: record.repeat_occurrence.unwrap_or(1),
+                    done,
+                    start: parse_date_time(&record.task, &original_date, &original_time)?,
+                    due: parse_date_time(&record.task, &date, &time)?,
+                };
+                Ok(Task::repeating(record.task, repeat))
+            }
+            _ => Err(format!(
+                "'{}' has to have both a date and a time, or neither",
+                record.task
+            )),
+        }?;
+        task.id = record.id;
+        task.priority = record.priority;
+        task.tags = record.tags;
+        task.done_at = record
+            .done_at
+            .map(|done_at| {
+                NaiveDateTime::parse_from_str(&done_at, DATE_TIME_FORMAT).map_err(|_| {
+                    format!(
+                        "'{}' was done at '{}', which isn't in a year-month-day hour:minute format",
+                        task.task, done_at
+                    )
+                })
+            })
+            .transpose()?;
+
+        Ok(task)
+    }
+}
+
+impl From<Task> for TaskRecord {
+    fn from(task: Task) -> Self {
+        let format_date = |datetime: NaiveDateTime| Some(datetime.format(DATE_FORMAT).to_string());
+        let format_time = |datetime: NaiveDateTime| Some(datetime.format(TIME_FORMAT).to_string());
+
+        let mut record = TaskRecord {
+            id: task.id,
+            task: task.task,
+            date: None,
+            time: None,
+            repeat_number: None,
+            repeat_unit: None,
+            repeat_done: None,
+            repeat_original_date: None,
+            repeat_original_time: None,
+            repeat_on: None,
+            repeat_catch_up: None,
+            repeat_until: None,
+            repeat_max_occurrences: None,
+            repeat_occurrence: None,
+            priority: task.priority,
+            tags: task.tags,
+            done_at: task
+                .done_at
+                .map(|done_at| done_at.format(DATE_TIME_FORMAT).to_string()),
+        };
+
+        match task.kind {
+            TaskKind::Regular => (),
+            TaskKind::Deadline { due } => {
+                record.date = format_date(due);
+                record.time = format_time(due);
+            }
+            TaskKind::Repeating(repeat) => {
+                record.date = format_date(repeat.due);
+                record.time = format_time(repeat.due);
+                record.repeat_number = Some(repeat.interval);
+                record.repeat_unit = Some(repeat.unit.to_string());
+                record.repeat_done = Some(repeat.done);
+                record.repeat_original_date = format_date(repeat.start);
+                record.repeat_original_time = format_time(repeat.start);
+                record.repeat_on = repeat.on.map(|on| on.to_string());
+                record.repeat_catch_up = match repeat.catch_up {
+                    CatchUp::Once => None,
+                    catch_up => Some(catch_up.to_string()),
+                };
+                record.repeat_until = repeat
+                    .until
+                    .map(|until| until.format(DATE_TIME_FORMAT).to_string());
+                record.repeat_max_occurrences = repeat.max_occurrences;
+                record.repeat_occurrence = repeat.max_occurrences.map(|_| repeat.occurrence);
+            }
+        }
+
+        record
+    }
 }
 
 // the following fns return a default value if the fields aren't present in the file
@@ -54,7 +788,6 @@ fn return_default_repeat_done() -> Option<bool> {
     None
 }
 
-// to anyone reading this, the purpose of original date+time is so that if the user wants to set a new interval number and/or unit, there needs to be a record of when the task starts
 fn return_default_repeat_original_date() -> Option<String> {
     None
 }
@@ -62,3 +795,265 @@ fn return_default_repeat_original_date() -> Option<String> {
 fn return_default_repeat_original_time() -> Option<String> {
     None
 }
+
+// cargo test json_file_structs_unit_tests
+#[cfg(test)]
+mod json_file_structs_unit_tests {
+    use super::*;
+
+    fn datetime(datetime: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(datetime, DATE_TIME_FORMAT).unwrap()
+    }
+
+    #[test]
+    fn each_kind_is_read_from_the_old_json_shape() {
+        let tasks = r#"
+            {
+                "todo": [
+                    {"task": "regular"},
+                    {"task": "deadline", "date": "2099-01-01", "time": "13:26"},
+                    {
+                        "task": "repeating",
+                        "date": "2099-01-04",
+                        "time": "00:00",
+                        "repeat_number": 3,
+                        "repeat_unit": "day",
+                        "repeat_done": false,
+                        "repeat_original_date": "2099-01-01",
+                        "repeat_original_time": "00:00"
+                    }
+                ],
+                "done": []
+            }
+        "#;
+        let tasks: Tasks = serde_json::from_str(tasks).unwrap();
+
+        assert_eq!(tasks.todo[0], Task::regular("regular".to_string()));
+        assert_eq!(
+            tasks.todo[1],
+            Task::deadline("deadline".to_string(), datetime("2099-01-01 13:26"))
+        );
+        assert_eq!(
+            tasks.todo[2],
+            Task::repeating(
+                "repeating".to_string(),
+                Repeat::starting_at(datetime("2099-01-01 00:00"), 3, RepeatUnit::Days).unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn tasks_are_written_back_in_the_same_shape() {
+        let task = Task::repeating(
+            "repeating".to_string(),
+            Repeat::ending_at(datetime("2099-03-01 08:00"), 2, RepeatUnit::Months).unwrap(),
+        );
+        let written = serde_json::to_value(&task).unwrap();
+
+        assert_eq!(written["date"], "2099-03-01");
+        assert_eq!(written["time"], "08:00");
+        assert_eq!(written["repeat_unit"], "months");
+        assert_eq!(written["repeat_original_date"], "2099-01-01");
+        assert_eq!(serde_json::from_value::<Task>(written).unwrap(), task);
+    }
+
+    #[test]
+    fn malformed_tasks_are_errors() {
+        let malformed = [
+            r#"{"task": "no time", "date": "2099-01-01"}"#,
+            r#"{"task": "bad date", "date": "2099-13-01", "time": "00:00"}"#,
+            r#"{"task": "half repeat", "date": "2099-01-01", "time": "00:00", "repeat_number": 1}"#,
+            r#"{"task": "bad unit", "date": "2099-01-02", "time": "00:00", "repeat_number": 1,
+            "repeat_unit": "fortnights", "repeat_done": false, "repeat_original_date": "2099-01-01",
+            "repeat_original_time": "00:00"}"#,
+            r#"{"task": "bad rule", "date": "2099-01-02", "time": "00:00", "repeat_number": 1,
+            "repeat_unit": "days", "repeat_done": false, "repeat_original_date": "2099-01-01",
+            "repeat_original_time": "00:00", "repeat_on": "last fri"}"#,
+            r#"{"task": "rule w/o repeat", "date": "2099-01-02", "time": "00:00", "repeat_on": "mon"}"#,
+        ];
+
+        for task in malformed {
+            assert!(serde_json::from_str::<Task>(task).is_err(), "{task}");
+        }
+    }
+
+    #[test]
+    fn task_in_the_wrong_list_is_an_error() {
+        let mut chartodo_file = ChartodoFile {
+            schema_version: 3,
+            next_id: 1,
+            regular: Tasks {
+                todo: vec![],
+                done: vec![],
+            },
+            deadline: Tasks {
+                todo: vec![],
+                done: vec![],
+            },
+            repeating: Tasks {
+                todo: vec![],
+                done: vec![],
+            },
+        };
+        assert!(chartodo_file.check_task_kinds().is_ok());
+
+        chartodo_file.repeating.todo.push(Task::deadline(
+            "deadline".to_string(),
+            datetime("2099-01-01 00:00"),
+        ));
+        assert!(chartodo_file.check_task_kinds().is_err());
+    }
+
+    #[test]
+    fn huge_intervals_dont_panic() {
+        let start = datetime("2099-01-01 00:00");
+
+        assert!(Repeat::starting_at(start, u32::MAX, RepeatUnit::Years).is_none());
+        assert!(Repeat::ending_at(start, u32::MAX, RepeatUnit::Weeks).is_none());
+    }
+
+    #[test]
+    fn series_end_is_correct() {
+        // due 2099-01-02, 03, and 04 w/ 3 times, or the same by the end of the 4th
+        let daily = Repeat::starting_at(datetime("2099-01-01 00:00"), 1, RepeatUnit::Days).unwrap();
+        assert_eq!(daily.remaining(), None);
+        assert_eq!(daily.ends(), None);
+
+        for ending in [
+            RepeatRules {
+                max_occurrences: Some(3),
+                ..Default::default()
+            },
+            RepeatRules {
+                until: Some(datetime("2099-01-04 23:59")),
+                ..Default::default()
+            },
+        ] {
+            let first = daily.with_rules(ending);
+            assert_eq!(first.remaining(), Some(3));
+
+            let last = first.next().unwrap().next().unwrap();
+            assert_eq!(last.due, datetime("2099-01-04 00:00"));
+            assert_eq!(last.remaining(), Some(1));
+            assert_eq!(last.next(), None);
+            assert!(!last.is_finished());
+
+            let done = Repeat { done: true, ..last };
+            assert!(done.is_finished());
+            assert_eq!(done.remaining(), Some(0));
+        }
+
+        let both = daily.with_rules(RepeatRules {
+            until: Some(datetime("2099-06-30 23:59")),
+            max_occurrences: Some(12),
+            ..Default::default()
+        });
+        assert_eq!(both.remaining(), Some(12));
+        assert_eq!(
+            both.ends(),
+            Some(String::from("until 2099-06-30 23:59, 12 times"))
+        );
+    }
+
+    #[test]
+    fn series_end_is_read_and_written() {
+        let task = Task::repeating(
+            "installment".to_string(),
+            Repeat {
+                occurrence: 4,
+                ..Repeat::starting_at(datetime("2099-01-01 00:00"), 1, RepeatUnit::Months)
+                    .unwrap()
+                    .with_rules(RepeatRules {
+                        until: Some(datetime("2099-12-31 23:59")),
+                        max_occurrences: Some(12),
+                        ..Default::default()
+                    })
+            },
+        );
+        let written = serde_json::to_value(&task).unwrap();
+
+        assert_eq!(written["repeat_until"], "2099-12-31 23:59");
+        assert_eq!(written["repeat_max_occurrences"], 12);
+        assert_eq!(written["repeat_occurrence"], 4);
+        assert_eq!(serde_json::from_value::<Task>(written).unwrap(), task);
+
+        // a task that repeats forever doesn't have any of them
+        let forever = Task::repeating(
+            "forever".to_string(),
+            Repeat::starting_at(datetime("2099-01-01 00:00"), 1, RepeatUnit::Days).unwrap(),
+        );
+        let written = serde_json::to_value(&forever).unwrap();
+        assert!(written["repeat_until"].is_null());
+        assert!(written["repeat_occurrence"].is_null());
+    }
+
+    #[test]
+    fn priority_is_read_and_written() {
+        let task: Task = serde_json::from_str(r#"{"task": "urgent", "priority": "high"}"#).unwrap();
+        let no_priority: Task = serde_json::from_str(r#"{"task": "whenever"}"#).unwrap();
+
+        assert_eq!(task.priority, Some(Priority::High));
+        assert_eq!(no_priority.priority, None);
+        assert_eq!(serde_json::to_value(&task).unwrap()["priority"], "high");
+        assert!(serde_json::from_str::<Task>(r#"{"task": "x", "priority": "urgent"}"#).is_err());
+    }
+
+    #[test]
+    fn done_at_is_read_and_written() {
+        let task: Task =
+            serde_json::from_str(r#"{"task": "done", "done_at": "2099-01-02 08:30"}"#).unwrap();
+        let not_done: Task = serde_json::from_str(r#"{"task": "todo"}"#).unwrap();
+
+        assert_eq!(task.done_at, Some(datetime("2099-01-02 08:30")));
+        assert_eq!(not_done.done_at, None);
+        assert_eq!(
+            serde_json::to_value(&task).unwrap()["done_at"],
+            "2099-01-02 08:30"
+        );
+        assert!(serde_json::from_str::<Task>(r#"{"task": "x", "done_at": "yesterday"}"#).is_err());
+    }
+
+    #[test]
+    fn priority_from_str_is_correct() {
+        assert_eq!("high".parse::<Priority>(), Ok(Priority::High));
+        assert_eq!("m".parse::<Priority>(), Ok(Priority::Medium));
+        assert_eq!("3".parse::<Priority>(), Ok(Priority::Low));
+        assert!("4".parse::<Priority>().is_err());
+    }
+
+    #[test]
+    fn tags_are_read_and_written() {
+        let task: Task =
+            serde_json::from_str(r#"{"task": "deploy", "tags": ["+backend", "@work"]}"#).unwrap();
+        let no_tags: Task = serde_json::from_str(r#"{"task": "whenever"}"#).unwrap();
+
+        assert_eq!(task.tags, vec!["+backend", "@work"]);
+        assert!(no_tags.tags.is_empty());
+        assert_eq!(
+            serde_json::to_value(&task).unwrap()["tags"],
+            serde_json::json!(["+backend", "@work"])
+        );
+    }
+
+    #[test]
+    fn parse_tag_is_correct() {
+        assert_eq!(parse_tag("backend"), Ok("+backend".to_string()));
+        assert_eq!(parse_tag("+backend"), Ok("+backend".to_string()));
+        assert_eq!(parse_tag("@errands"), Ok("@errands".to_string()));
+        assert!(parse_tag("+").is_err());
+        assert!(parse_tag("").is_err());
+        assert!(parse_tag("two words").is_err());
+    }
+
+    #[test]
+    fn has_tag_is_correct() {
+        let task = Task::regular("deploy".to_string())
+            .with_tags(&["+backend".to_string(), "@work".to_string()]);
+
+        assert!(task.has_tag("backend"));
+        assert!(task.has_tag("+backend"));
+        assert!(task.has_tag("work"));
+        assert!(!task.has_tag("@backend"));
+        assert!(!task.has_tag("frontend"));
+    }
+}
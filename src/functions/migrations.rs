@@ -0,0 +1,456 @@
+use crate::functions::{errors::ChartodoError, json_file_structs::*, storage_helpers::*};
+use anyhow::{bail, Context};
+use serde_json::{json, Value};
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
+};
+
+// every layout chartodo has ever stored tasks in:
+// v0: general_list.txt, a plain text file w/ the todo list, a ----- line, then the done list
+// v1: regular_tasks.json, deadline_tasks.json, and repeating_tasks.json, one file per kind of task
+// v2: chartodo.json, which holds all three lists plus a schema_version
+// v3: every task has an id, and chartodo.json has the next_id to give out
+// v4: tasks can have a priority
+// v5: tasks can have tags
+// v6: repeating tasks can have a rule for which days they land on, see recurrence
+// v7: repeating tasks can say what to do about the times they were missed, see CatchUp
+// v8: repeating tasks can end, either after a date or after so many times, see RepeatRules
+// v9: done tasks remember when they were done, so that the done lists can be kept short, see Retention
+//
+// whenever the format changes, bump this and add a migration to IN_FILE_MIGRATIONS
+pub const CURRENT_SCHEMA_VERSION: u32 = 9;
+
+// v0 -> v1 -> v2 change what files there are, so they're handled by upgrade_older_layouts_if_needed. from v2 on,
+// everything is in chartodo.json, so a migration only has to take the json of one version and return the json of
+// the next. it doesn't need to touch schema_version, that gets bumped after it runs
+type Migration = fn(Value) -> anyhow::Result<Value>;
+
+// the version IN_FILE_MIGRATIONS starts upgrading from, i.e., IN_FILE_MIGRATIONS[0] upgrades v2 to v3
+const FIRST_IN_FILE_VERSION: u32 = 2;
+static IN_FILE_MIGRATIONS: &[Migration] = &[
+    give_every_task_an_id,
+    tasks_can_have_a_priority,
+    tasks_can_have_tags,
+    repeating_tasks_can_have_a_rule,
+    repeating_tasks_can_catch_up,
+    repeating_tasks_can_end,
+    done_tasks_have_a_done_at,
+];
+
+// v2 -> v3. ids are handed out list by list, todo before done, so that they start out in the same order the tasks
+// were shown in
+fn give_every_task_an_id(mut chartodo_json: Value) -> anyhow::Result<Value> {
+    let mut next_id: u32 = 1;
+    for list in ["regular", "deadline", "repeating"] {
+        for todo_or_done in ["todo", "done"] {
+            let tasks = chartodo_json
+                .get_mut(list)
+                .and_then(|tasks| tasks.get_mut(todo_or_done))
+                .and_then(|tasks| tasks.as_array_mut())
+                .with_context(|| format!("there is no {} {} list", list, todo_or_done))?;
+
+            for task in tasks.iter_mut() {
+                let task = task.as_object_mut().with_context(|| {
+                    format!(
+                        "a task in the {} {} list isn't an object",
+                        list, todo_or_done
+                    )
+                })?;
+                task.insert("id".to_string(), json!(next_id));
+                next_id += 1;
+            }
+        }
+    }
+    chartodo_json["next_id"] = json!(next_id);
+
+    Ok(chartodo_json)
+}
+
+// v3 -> v4. a task w/o a priority is read just fine, so nothing has to change. the bump is there so that an older
+// chartodo refuses the file instead of quietly dropping every priority the next time it writes
+fn tasks_can_have_a_priority(chartodo_json: Value) -> anyhow::Result<Value> {
+    Ok(chartodo_json)
+}
+
+// v4 -> v5. same deal as priorities, a task w/o tags is read just fine
+fn tasks_can_have_tags(chartodo_json: Value) -> anyhow::Result<Value> {
+    Ok(chartodo_json)
+}
+
+// v5 -> v6. same again, a repeating task w/o a rule is read just fine
+fn repeating_tasks_can_have_a_rule(chartodo_json: Value) -> anyhow::Result<Value> {
+    Ok(chartodo_json)
+}
+
+// v6 -> v7. a repeating task w/o repeat_catch_up catches up once, same as it always did
+fn repeating_tasks_can_catch_up(chartodo_json: Value) -> anyhow::Result<Value> {
+    Ok(chartodo_json)
+}
+
+// v7 -> v8. a repeating task w/o repeat_until or repeat_max_occurrences repeats forever, same as it always did
+fn repeating_tasks_can_end(chartodo_json: Value) -> anyhow::Result<Value> {
+    Ok(chartodo_json)
+}
+
+// v8 -> v9. nothing's known about when the tasks that are already done were done, so they're given a done_at the
+// next time they're saved, see TaskStore::apply_retention
+fn done_tasks_have_a_done_at(chartodo_json: Value) -> anyhow::Result<Value> {
+    Ok(chartodo_json)
+}
+
+pub fn schema_version_of(chartodo_json: &Value) -> anyhow::Result<u32> {
+    let schema_version = chartodo_json
+        .get("schema_version")
+        .context("there is no schema_version")?
+        .as_u64()
+        .context("schema_version isn't a number")?;
+
+    u32::try_from(schema_version).context("schema_version is too big")
+}
+
+// returns the file at the current version, and whether it had to be upgraded to get there. files from a newer
+// chartodo are an error, since there's no way of knowing what an older chartodo would throw away
+pub fn migrate_chartodo_file(mut chartodo_json: Value) -> anyhow::Result<(ChartodoFile, bool)> {
+    let mut schema_version = schema_version_of(&chartodo_json)?;
+    if schema_version > CURRENT_SCHEMA_VERSION {
+        bail!(
+            "schema_version {} is newer than the {} this chartodo understands",
+            schema_version,
+            CURRENT_SCHEMA_VERSION
+        );
+    }
+    if schema_version < FIRST_IN_FILE_VERSION {
+        bail!(
+            "schema_version {} can't be stored in {}",
+            schema_version,
+            CHARTODO_FILE_NAME
+        );
+    }
+
+    let migrated = schema_version < CURRENT_SCHEMA_VERSION;
+    while schema_version < CURRENT_SCHEMA_VERSION {
+        let migration = IN_FILE_MIGRATIONS[(schema_version - FIRST_IN_FILE_VERSION) as usize];
+        chartodo_json = migration(chartodo_json).with_context(|| {
+            format!(
+                "couldn't upgrade from schema_version {} to {}",
+                schema_version,
+                schema_version + 1
+            )
+        })?;
+
+        schema_version += 1;
+        chartodo_json["schema_version"] = json!(schema_version);
+    }
+
+    let chartodo_file: ChartodoFile =
+        serde_json::from_value(chartodo_json).context("the tasks don't have the right shape")?;
+    chartodo_file.check_task_kinds()?;
+
+    Ok((chartodo_file, migrated))
+}
+
+// brings a chartodo folder that was last used by an older chartodo up to v2. does nothing if chartodo.json is
+// already there, or if there's nothing to upgrade, i.e., a new install
+pub fn upgrade_older_layouts_if_needed(chartodo_dir: &Path) -> Result<(), ChartodoError> {
+    let chartodo_file_path = chartodo_dir.join(CHARTODO_FILE_NAME);
+    if chartodo_file_path.exists() {
+        return Ok(());
+    }
+
+    // v0 -> v1
+    let old_path = chartodo_dir.join("general_list.txt");
+    let regular_tasks_path = chartodo_dir.join("regular_tasks.json");
+    if old_path.exists() {
+        if regular_tasks_path.exists() {
+            // just to double check in case both the old file and the json exists. the json is newer
+            std::fs::remove_file(&old_path)
+                .with_context(|| format!("couldn't remove old file {}", old_path.display()))
+                .map_err(|remove_error| ChartodoError::Storage(format!("{:#}", remove_error)))?;
+        } else {
+            transfer_old_file_contents_to_new_json(&old_path, &regular_tasks_path);
+        }
+    }
+
+    // v1 -> v2
+    let v1_paths = [
+        regular_tasks_path,
+        chartodo_dir.join("deadline_tasks.json"),
+        chartodo_dir.join("repeating_tasks.json"),
+    ];
+    if !v1_paths.iter().any(|v1_path| v1_path.exists()) {
+        return Ok(());
+    }
+
+    // note: this is plain json rather than ChartodoFile on purpose. ChartodoFile will change w/ future versions,
+    // while this has to stay exactly what v2 looked like so that IN_FILE_MIGRATIONS can take it from there
+    let [regular, deadline, repeating] = v1_paths.each_ref().map(|v1_path| open_v1_tasks(v1_path));
+    let chartodo_json = json!({
+        "schema_version": 2,
+        "regular": regular?,
+        "deadline": deadline?,
+        "repeating": repeating?,
+    });
+    try_write_json_to_file(&chartodo_file_path, &chartodo_json)
+        .map_err(|write_error| ChartodoError::Storage(format!("{:#}", write_error)))?;
+
+    // only remove the old files once chartodo.json is safely written
+    for v1_path in v1_paths.iter().filter(|v1_path| v1_path.exists()) {
+        std::fs::remove_file(v1_path)
+            .with_context(|| format!("couldn't remove old file {}", v1_path.display()))
+            .map_err(|remove_error| ChartodoError::Storage(format!("{:#}", remove_error)))?;
+    }
+
+    Ok(())
+}
+
+fn open_v1_tasks(v1_path: &Path) -> Result<Value, ChartodoError> {
+    // a v1 file that's missing or empty just means that list had nothing in it
+    if !v1_path.exists() {
+        return Ok(json!({"todo": [], "done": []}));
+    }
+
+    match try_read_json_from_file::<Value>(v1_path) {
+        Ok(Some(v1_tasks)) => Ok(v1_tasks),
+        Ok(None) => Ok(json!({"todo": [], "done": []})),
+        Err(parse_error) => Err(broken_file_error(v1_path, parse_error)),
+    }
+}
+
+// the old file that the fn below is talking about is general_list.txt, an old format I used to use
+// code related to it will be deleted once there are enough iterations and I can be 99% sure no one's using it
+
+fn transfer_old_file_contents_to_new_json(old_path: &Path, new_json: &Path) {
+    let file = File::open(old_path)
+        .with_context(|| format!("couldn't open old file {}", old_path.display()))
+        .expect(
+            "old file not found even though it was already checked that it\
+            exists. something went very wrong",
+        );
+
+    let reader = BufReader::new(file);
+
+    // separate the lists into vecs so i can do operations on them
+    let mut file_buf: Vec<String> = vec![];
+    let mut todo_buf: Vec<String> = vec![];
+    let mut done_buf: Vec<String> = vec![];
+
+    // the reason for doing this separately is that Rust complains if I use line to check for
+    // conditions
+    for line in reader.lines() {
+        file_buf.push(line.expect("couldn't unwrap line and push to file_buf"));
+    }
+
+    // if this is 1, that means the todo list is done and the loop can push to done_buf
+    let mut todo_done_demarcation = 0;
+    // every line is brought over, no matter how many there are. a done list that's too long is trimmed the next time
+    // it's saved, see Retention
+    for line in file_buf {
+        if line == "-----" {
+            todo_done_demarcation = 1;
+        } else {
+            match todo_done_demarcation {
+                0 => todo_buf.push(line.to_string()),
+                _ => done_buf.push(line.to_string()),
+            }
+        }
+    }
+
+    // this is what a regular task looked like in v1
+    let v1_regular_task = |item: &String| {
+        json!({
+            "task": item,
+            "date": null,
+            "time": null,
+            "repeat_number": null,
+            "repeat_unit": null,
+            "repeat_done": null,
+            "repeat_original_date": null,
+            "repeat_original_time": null
+        })
+    };
+    let regular_tasks = json!({
+        "todo": todo_buf.iter().map(v1_regular_task).collect::<Vec<Value>>(),
+        "done": done_buf.iter().map(v1_regular_task).collect::<Vec<Value>>(),
+    });
+
+    // write the old contents to new json file
+    write_json_to_file(new_json, &regular_tasks);
+
+    // now that the old contents have been transferred, remove old file
+    std::fs::remove_file(old_path)
+        .with_context(|| format!("couldn't remove old file {}", old_path.display()))
+        .expect("couldn't remove old general_list.txt");
+}
+
+// cargo test migrations_unit_tests
+#[cfg(test)]
+mod migrations_unit_tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn fresh_test_dir(test_name: &str) -> PathBuf {
+        let mut test_dir = std::env::temp_dir();
+        test_dir.push(format!(
+            "chartodo_migrations_{}_{}",
+            test_name,
+            std::process::id()
+        ));
+        if test_dir.exists() {
+            std::fs::remove_dir_all(&test_dir).unwrap();
+        }
+        std::fs::create_dir(&test_dir).unwrap();
+
+        test_dir
+    }
+
+    #[test]
+    fn every_version_has_a_migration() {
+        assert_eq!(
+            FIRST_IN_FILE_VERSION + IN_FILE_MIGRATIONS.len() as u32,
+            CURRENT_SCHEMA_VERSION
+        );
+    }
+
+    #[test]
+    fn current_version_is_not_migrated() {
+        let chartodo_json = json!({
+            "schema_version": CURRENT_SCHEMA_VERSION,
+            "next_id": 1,
+            "regular": {"todo": [], "done": []},
+            "deadline": {"todo": [], "done": []},
+            "repeating": {"todo": [], "done": []},
+        });
+
+        let (chartodo_file, migrated) = migrate_chartodo_file(chartodo_json).unwrap();
+
+        assert!(!migrated);
+        assert_eq!(chartodo_file.schema_version, CURRENT_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn newer_or_missing_version_is_an_error() {
+        let newer = json!({
+            "schema_version": CURRENT_SCHEMA_VERSION + 1,
+            "next_id": 1,
+            "regular": {"todo": [], "done": []},
+            "deadline": {"todo": [], "done": []},
+            "repeating": {"todo": [], "done": []},
+        });
+        let missing = json!({
+            "regular": {"todo": [], "done": []},
+            "deadline": {"todo": [], "done": []},
+            "repeating": {"todo": [], "done": []},
+        });
+
+        assert!(migrate_chartodo_file(newer).is_err());
+        assert!(migrate_chartodo_file(missing).is_err());
+    }
+
+    #[test]
+    fn v2_tasks_are_given_ids_in_order() {
+        let v2 = json!({
+            "schema_version": 2,
+            "regular": {"todo": [{"task": "first"}], "done": [{"task": "second"}]},
+            "deadline": {"todo": [], "done": []},
+            "repeating": {"todo": [], "done": []},
+        });
+
+        let (chartodo_file, migrated) = migrate_chartodo_file(v2).unwrap();
+
+        assert!(migrated);
+        assert_eq!(chartodo_file.regular.todo[0].id, 1);
+        assert_eq!(chartodo_file.regular.done[0].id, 2);
+        assert_eq!(chartodo_file.next_id, 3);
+    }
+
+    #[test]
+    fn v1_files_are_moved_into_chartodo_file() {
+        let test_dir = fresh_test_dir("v1_to_v2");
+        std::fs::write(
+            test_dir.join("regular_tasks.json"),
+            r#"{"todo": [{"task": "from-v1"}], "done": []}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            test_dir.join("deadline_tasks.json"),
+            r#"{"todo": [], "done": [{"task": "due-v1", "date": "2099-01-01", "time": "00:00"}]}"#,
+        )
+        .unwrap();
+        // no repeating_tasks.json, so that list should start out empty
+
+        upgrade_older_layouts_if_needed(&test_dir).unwrap();
+
+        assert!(!test_dir.join("regular_tasks.json").exists());
+        assert!(!test_dir.join("deadline_tasks.json").exists());
+        let chartodo_json: Value = try_read_json_from_file(&test_dir.join(CHARTODO_FILE_NAME))
+            .unwrap()
+            .unwrap();
+        let (chartodo_file, _) = migrate_chartodo_file(chartodo_json).unwrap();
+        assert_eq!(chartodo_file.regular.todo[0].task, "from-v1");
+        assert_eq!(chartodo_file.deadline.done[0].task, "due-v1");
+        assert!(chartodo_file.repeating.todo.is_empty());
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+
+    #[test]
+    fn v0_file_is_moved_into_chartodo_file() {
+        let test_dir = fresh_test_dir("v0_to_v2");
+        std::fs::write(
+            test_dir.join("general_list.txt"),
+            "old-todo\n-----\nold-done\n",
+        )
+        .unwrap();
+
+        upgrade_older_layouts_if_needed(&test_dir).unwrap();
+
+        assert!(!test_dir.join("general_list.txt").exists());
+        assert!(!test_dir.join("regular_tasks.json").exists());
+        let chartodo_json: Value = try_read_json_from_file(&test_dir.join(CHARTODO_FILE_NAME))
+            .unwrap()
+            .unwrap();
+        let (chartodo_file, _) = migrate_chartodo_file(chartodo_json).unwrap();
+        assert_eq!(chartodo_file.regular.todo[0].task, "old-todo");
+        assert_eq!(chartodo_file.regular.done[0].task, "old-done");
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+
+    #[test]
+    fn v0_file_is_moved_over_in_full() {
+        let test_dir = fresh_test_dir("v0_in_full");
+        let todos: Vec<String> = (1..=20).map(|number| format!("todo-{}", number)).collect();
+        std::fs::write(
+            test_dir.join("general_list.txt"),
+            format!(
+                "{}\n-----\na-done-task-that-is-longer-than-thirty-characters\n",
+                todos.join("\n")
+            ),
+        )
+        .unwrap();
+
+        upgrade_older_layouts_if_needed(&test_dir).unwrap();
+
+        let chartodo_json: Value = try_read_json_from_file(&test_dir.join(CHARTODO_FILE_NAME))
+            .unwrap()
+            .unwrap();
+        let (chartodo_file, _) = migrate_chartodo_file(chartodo_json).unwrap();
+        assert_eq!(chartodo_file.regular.todo.len(), 20);
+        assert_eq!(chartodo_file.regular.done.len(), 1);
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+
+    #[test]
+    fn nothing_to_upgrade_writes_nothing() {
+        let test_dir = fresh_test_dir("nothing_to_upgrade");
+
+        upgrade_older_layouts_if_needed(&test_dir).unwrap();
+
+        assert!(!test_dir.join(CHARTODO_FILE_NAME).exists());
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+}
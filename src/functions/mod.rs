@@ -1,7 +1,25 @@
+pub mod archive;
+pub mod completions;
+pub mod config;
+pub mod date_parsing;
 pub mod deadline_tasks;
+pub mod errors;
+pub mod file_lock;
 pub mod general_commands;
+pub mod history;
 pub mod json_file_structs;
+pub mod migrations;
+pub mod output;
+pub mod recurrence;
 pub mod regular_tasks;
 pub mod repeating_tasks;
+pub mod search;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_backend;
+pub mod storage_backend;
+pub mod storage_helpers;
+pub mod task_ids;
+pub mod task_store;
+pub mod undo_redo;
 
 mod general_helpers;
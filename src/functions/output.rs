@@ -0,0 +1,105 @@
+use clap::ValueEnum;
+use std::{
+    io::Write,
+    sync::{Mutex, OnceLock},
+};
+
+// how chartodo shows what happened. text is the table, w/ what went wrong on stderr. json is for scripts and status
+// bars, see print_json_result
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+// set once by main if the user passed --format
+static OUTPUT_FORMAT: OnceLock<OutputFormat> = OnceLock::new();
+
+// w/ json, whatever the commands say is held here instead of being printed, so that it can go in the json result
+static HELD_MESSAGES: Mutex<Vec<u8>> = Mutex::new(vec![]);
+
+pub fn set_output_format(output_format: OutputFormat) {
+    // note: same as set_data_dir_override, main sets it once before any command is run
+    let _ = OUTPUT_FORMAT.set(output_format);
+}
+
+pub fn output_format() -> OutputFormat {
+    OUTPUT_FORMAT.get().copied().unwrap_or_default()
+}
+
+// what commands write their notes to, e.g., Undid: ... it's stdout, unless the output is json
+pub struct Output;
+
+pub fn writer() -> Output {
+    Output
+}
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match output_format() {
+            OutputFormat::Text => std::io::stdout().write(buf),
+            OutputFormat::Json => {
+                HELD_MESSAGES
+                    .lock()
+                    .expect("the held messages lock was poisoned")
+                    .extend_from_slice(buf);
+                Ok(buf.len())
+            }
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match output_format() {
+            OutputFormat::Text => std::io::stdout().flush(),
+            OutputFormat::Json => Ok(()),
+        }
+    }
+}
+
+// everything the commands said, one message per line, w/ the messages cleared afterward. a message that goes over
+// multiple lines, w/ the rest of its lines starting w/ a tab, is kept as one message
+pub fn take_held_messages() -> Vec<String> {
+    let held_messages = std::mem::take(
+        &mut *HELD_MESSAGES
+            .lock()
+            .expect("the held messages lock was poisoned"),
+    );
+
+    let mut messages: Vec<String> = vec![];
+    for line in String::from_utf8_lossy(&held_messages).lines() {
+        match messages.last_mut() {
+            Some(message) if line.starts_with('\t') => {
+                message.push('\n');
+                message.push_str(line);
+            }
+            _ if line.trim().is_empty() => (),
+            _ => messages.push(line.to_string()),
+        }
+    }
+
+    messages
+}
+
+// cargo test output_unit_tests
+#[cfg(test)]
+mod output_unit_tests {
+    use super::*;
+
+    #[test]
+    fn held_messages_are_split_correctly() {
+        HELD_MESSAGES
+            .lock()
+            .expect("the held messages lock was poisoned")
+            .extend_from_slice(b"Undid: add a\n\nsome note\n\tand its example\n");
+
+        assert_eq!(
+            take_held_messages(),
+            vec![
+                String::from("Undid: add a"),
+                String::from("some note\n\tand its example")
+            ]
+        );
+        assert!(take_held_messages().is_empty());
+    }
+}
@@ -0,0 +1,592 @@
+use crate::functions::json_file_structs::RepeatUnit;
+use chrono::{Datelike, Days, Months, NaiveDate, NaiveDateTime, Weekday};
+use std::{fmt, str::FromStr};
+
+// a repeating task is due every [interval] [unit] after its start. on top of that, it can have a calendar rule that
+// says which days it lands on, a bit like an RRULE:
+// - weekdays, e.g., mon,wed,fri, weekdays (mon to fri), or weekends. the unit has to be weeks, so every 1 week on
+//   mon,wed,fri is due on every one of them, and every 2 weeks on mon is every other monday
+// - the nth weekday of the month, e.g., 2nd tue or last fri. the unit has to be months
+// - the last day of the month, or the last business day (mon to fri) of the month. also months
+//
+// the task is always due at the same time of day as its start
+//
+// next_occurrence is the only place that works out when a repeating task is due next, w/ or w/o a rule. see
+// Repeat::restarted_at for how it's used. previous_occurrence goes the other way, for a task that's added by when it's
+// due instead of when it starts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepeatOn {
+    Weekdays(WeekdaySet),
+    // nth is 1 to 5, or -1 for the last one
+    NthWeekday { nth: i8, weekday: Weekday },
+    LastDay,
+    LastBusinessDay,
+}
+
+// which days of the week, one bit per day, monday first
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekdaySet(u8);
+
+impl WeekdaySet {
+    const MON_TO_FRI: WeekdaySet = WeekdaySet(0b0011111);
+    const SAT_AND_SUN: WeekdaySet = WeekdaySet(0b1100000);
+
+    pub fn contains(self, weekday: Weekday) -> bool {
+        self.0 & (1 << weekday.num_days_from_monday()) != 0
+    }
+
+    fn insert(&mut self, weekday: Weekday) {
+        self.0 |= 1 << weekday.num_days_from_monday();
+    }
+}
+
+impl RepeatOn {
+    // the unit a rule goes w/, see the top of the file
+    pub fn unit(self) -> RepeatUnit {
+        match self {
+            RepeatOn::Weekdays(_) => RepeatUnit::Weeks,
+            RepeatOn::NthWeekday { .. } | RepeatOn::LastDay | RepeatOn::LastBusinessDay => {
+                RepeatUnit::Months
+            }
+        }
+    }
+
+    // the day in the given month that a monthly rule lands on. None if there isn't one, e.g., a 5th tuesday
+    fn day_in_month(self, first_of_month: NaiveDate) -> Option<NaiveDate> {
+        let last_of_month = first_of_month
+            .checked_add_months(Months::new(1))?
+            .pred_opt()?;
+
+        match self {
+            RepeatOn::Weekdays(_) => None,
+            RepeatOn::NthWeekday { nth: -1, weekday } => {
+                let days_back = (7 + last_of_month.weekday().num_days_from_monday()
+                    - weekday.num_days_from_monday())
+                    % 7;
+                last_of_month.checked_sub_days(Days::new(days_back.into()))
+            }
+            RepeatOn::NthWeekday { nth, weekday } => {
+                let days_forward = (7 + weekday.num_days_from_monday()
+                    - first_of_month.weekday().num_days_from_monday())
+                    % 7;
+                let day = first_of_month
+                    .checked_add_days(Days::new(u64::from(days_forward) + 7 * (nth as u64 - 1)))?;
+                (day.month() == first_of_month.month()).then_some(day)
+            }
+            RepeatOn::LastDay => Some(last_of_month),
+            RepeatOn::LastBusinessDay => {
+                let days_back = match last_of_month.weekday() {
+                    Weekday::Sat => 1,
+                    Weekday::Sun => 2,
+                    _ => 0,
+                };
+                last_of_month.checked_sub_days(Days::new(days_back))
+            }
+        }
+    }
+}
+
+// when a task that repeats every [interval] [unit], w/ or w/o a rule, is due next after the given date+time. None
+// if that's past what chrono can handle
+pub fn next_occurrence(
+    after: NaiveDateTime,
+    interval: u32,
+    unit: RepeatUnit,
+    on: Option<RepeatOn>,
+) -> Option<NaiveDateTime> {
+    let time = after.time();
+
+    match on {
+        None => unit.add_to(after, interval),
+        Some(RepeatOn::Weekdays(weekdays)) => {
+            let date = after.date();
+            let monday =
+                date.checked_sub_days(Days::new(date.weekday().num_days_from_monday().into()))?;
+
+            // the rest of this week, then the week [interval] weeks later
+            let rest_of_week = (date.weekday().num_days_from_monday() + 1..7)
+                .map(|day| (monday, day))
+                .collect::<Vec<_>>();
+            let next_monday =
+                monday.checked_add_days(Days::new(u64::from(interval).checked_mul(7)?))?;
+            rest_of_week
+                .into_iter()
+                .chain((0..7).map(|day| (next_monday, day)))
+                .filter_map(|(monday, day)| monday.checked_add_days(Days::new(day.into())))
+                .find(|day| weekdays.contains(day.weekday()))
+                .map(|day| day.and_time(time))
+        }
+        Some(rule) => {
+            let mut first_of_month = after.date().with_day(1)?;
+            // a 5th weekday is in at least 4 months of every year, so this always finds one unless the interval is
+            // huge, in which case chrono runs out first
+            for _ in 0..60 {
+                if let Some(day) = rule.day_in_month(first_of_month) {
+                    if day.and_time(time) > after {
+                        return Some(day.and_time(time));
+                    }
+                }
+                first_of_month = first_of_month.checked_add_months(Months::new(interval))?;
+            }
+
+            None
+        }
+    }
+}
+
+// the other way around from next_occurrence: when a task that repeats every [interval] [unit], w/ or w/o a rule, was
+// last due before the given date+time. a task that starts there is due at the given date+time, as long as it fits
+// the rule. None if that's past what chrono can handle
+pub fn previous_occurrence(
+    before: NaiveDateTime,
+    interval: u32,
+    unit: RepeatUnit,
+    on: Option<RepeatOn>,
+) -> Option<NaiveDateTime> {
+    let time = before.time();
+
+    match on {
+        None => unit.subtract_from(before, interval),
+        Some(RepeatOn::Weekdays(weekdays)) => {
+            let date = before.date();
+            let monday =
+                date.checked_sub_days(Days::new(date.weekday().num_days_from_monday().into()))?;
+
+            // the start of this week, then the week [interval] weeks earlier, latest day first
+            let start_of_week = (0..date.weekday().num_days_from_monday())
+                .rev()
+                .map(|day| (monday, day))
+                .collect::<Vec<_>>();
+            let earlier_monday =
+                monday.checked_sub_days(Days::new(u64::from(interval).checked_mul(7)?))?;
+            start_of_week
+                .into_iter()
+                .chain((0..7).rev().map(|day| (earlier_monday, day)))
+                .filter_map(|(monday, day)| monday.checked_add_days(Days::new(day.into())))
+                .find(|day| weekdays.contains(day.weekday()))
+                .map(|day| day.and_time(time))
+        }
+        Some(rule) => {
+            let mut first_of_month = before.date().with_day(1)?;
+            // same as next_occurrence, chrono runs out before this does
+            for _ in 0..60 {
+                if let Some(day) = rule.day_in_month(first_of_month) {
+                    if day.and_time(time) < before {
+                        return Some(day.and_time(time));
+                    }
+                }
+                first_of_month = first_of_month.checked_sub_months(Months::new(interval))?;
+            }
+
+            None
+        }
+    }
+}
+
+fn nth_from_str(nth: &str) -> Option<i8> {
+    match nth {
+        "1st" | "first" => Some(1),
+        "2nd" | "second" => Some(2),
+        "3rd" | "third" => Some(3),
+        "4th" | "fourth" => Some(4),
+        "5th" | "fifth" => Some(5),
+        "last" => Some(-1),
+        _ => None,
+    }
+}
+
+impl FromStr for RepeatOn {
+    type Err = String;
+
+    // e.g., mon,wed,fri, weekdays, 2nd tue, last fri, last day, or last business day
+    fn from_str(rule: &str) -> Result<Self, Self::Err> {
+        let rule_error = || {
+            format!(
+                "'{}' isn't a rule. It has to be weekdays, weekends, a list of days like mon,wed,fri, a day of the \
+                month like 2nd tue or last fri, last day, or last business day",
+                rule
+            )
+        };
+        let lowercase = rule.trim().to_lowercase();
+
+        match lowercase.as_str() {
+            "weekdays" => return Ok(RepeatOn::Weekdays(WeekdaySet::MON_TO_FRI)),
+            "weekends" => return Ok(RepeatOn::Weekdays(WeekdaySet::SAT_AND_SUN)),
+            "last day" => return Ok(RepeatOn::LastDay),
+            "last business day" | "last weekday" => return Ok(RepeatOn::LastBusinessDay),
+            _ => (),
+        }
+
+        // a list of days can have spaces after the commas, e.g., mon, fri
+        if let Some((nth, weekday)) = lowercase
+            .split_once(' ')
+            .filter(|_| !lowercase.contains(','))
+        {
+            let nth = nth_from_str(nth).ok_or_else(rule_error)?;
+            let weekday = weekday.trim().parse().map_err(|_| rule_error())?;
+            return Ok(RepeatOn::NthWeekday { nth, weekday });
+        }
+
+        let mut weekdays = WeekdaySet(0);
+        for weekday in lowercase.split(',') {
+            weekdays.insert(weekday.trim().parse().map_err(|_| rule_error())?);
+        }
+        Ok(RepeatOn::Weekdays(weekdays))
+    }
+}
+
+// this is what gets written to the file, and it reads back the same
+impl fmt::Display for RepeatOn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let short_name = |weekday: Weekday| weekday.to_string().to_lowercase();
+
+        match self {
+            RepeatOn::Weekdays(WeekdaySet::MON_TO_FRI) => write!(f, "weekdays"),
+            RepeatOn::Weekdays(WeekdaySet::SAT_AND_SUN) => write!(f, "weekends"),
+            RepeatOn::Weekdays(weekdays) => {
+                let weekdays: Vec<String> = (0..7)
+                    .filter_map(|day| Weekday::try_from(day).ok())
+                    .filter(|weekday| weekdays.contains(*weekday))
+                    .map(short_name)
+                    .collect();
+                write!(f, "{}", weekdays.join(","))
+            }
+            RepeatOn::NthWeekday { nth, weekday } => {
+                let nth = match nth {
+                    1 => "1st",
+                    2 => "2nd",
+                    3 => "3rd",
+                    4 => "4th",
+                    5 => "5th",
+                    _ => "last",
+                };
+                write!(f, "{} {}", nth, short_name(*weekday))
+            }
+            RepeatOn::LastDay => write!(f, "last day"),
+            RepeatOn::LastBusinessDay => write!(f, "last business day"),
+        }
+    }
+}
+
+// what happens to a done repeating task once it's been due again for a while, e.g., a daily task that wasn't looked
+// at for three weeks. see TaskStore::roll_over_repeating
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CatchUp {
+    // it's due once more at the next occurrence after the last due date, even if that's passed too. this is how it's
+    // always been
+    #[default]
+    Once,
+    // it skips ahead to the first occurrence that hasn't passed yet. the ones in between are recorded as skipped
+    Next,
+    // every occurrence that's passed gets its own deadline todo, and the task itself goes on from the first one that
+    // hasn't passed
+    Each,
+}
+
+impl FromStr for CatchUp {
+    type Err = String;
+
+    fn from_str(catch_up: &str) -> Result<Self, Self::Err> {
+        match catch_up.trim().to_lowercase().as_str() {
+            "once" => Ok(CatchUp::Once),
+            "next" => Ok(CatchUp::Next),
+            "each" => Ok(CatchUp::Each),
+            _ => Err(format!(
+                "'{}' isn't a way to catch up. It has to be once, next, or each",
+                catch_up
+            )),
+        }
+    }
+}
+
+// this is what gets written to the file
+impl fmt::Display for CatchUp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let catch_up = match self {
+            CatchUp::Once => "once",
+            CatchUp::Next => "next",
+            CatchUp::Each => "each",
+        };
+
+        write!(f, "{}", catch_up)
+    }
+}
+
+// everything about how a repeating task repeats, other than its interval and unit, that can be given when it's
+// added, e.g., w/ --on, --catch-up, --until, and --times
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RepeatRules {
+    pub on: Option<RepeatOn>,
+    pub catch_up: CatchUp,
+    // the series ends after the last occurrence that's due by until, or after max_occurrences of them, whichever
+    // comes first. w/ neither, it repeats forever
+    pub until: Option<NaiveDateTime>,
+    pub max_occurrences: Option<u32>,
+}
+
+// cargo test recurrence_unit_tests
+#[cfg(test)]
+mod recurrence_unit_tests {
+    use super::*;
+
+    fn datetime(datetime: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(datetime, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    fn rule(rule: &str) -> Option<RepeatOn> {
+        Some(rule.parse().unwrap())
+    }
+
+    #[test]
+    fn rules_read_back_the_same() {
+        for text in [
+            "mon,wed,fri",
+            "weekdays",
+            "weekends",
+            "2nd tue",
+            "last fri",
+            "last day",
+            "last business day",
+        ] {
+            assert_eq!(text.parse::<RepeatOn>().unwrap().to_string(), text);
+        }
+        assert_eq!(
+            "Friday, MON".parse::<RepeatOn>().unwrap().to_string(),
+            "mon,fri"
+        );
+        assert!("6th tue".parse::<RepeatOn>().is_err());
+        assert!("mon,someday".parse::<RepeatOn>().is_err());
+        assert!("".parse::<RepeatOn>().is_err());
+    }
+
+    #[test]
+    fn no_rule_is_the_interval() {
+        assert_eq!(
+            next_occurrence(datetime("2099-01-31 09:00"), 1, RepeatUnit::Months, None),
+            Some(datetime("2099-02-28 09:00"))
+        );
+    }
+
+    #[test]
+    fn weekdays_are_correct() {
+        // 2099-01-07 is a wednesday
+        let mon_wed_fri = rule("mon,wed,fri");
+        assert_eq!(
+            next_occurrence(
+                datetime("2099-01-07 09:00"),
+                1,
+                RepeatUnit::Weeks,
+                mon_wed_fri
+            ),
+            Some(datetime("2099-01-09 09:00"))
+        );
+        assert_eq!(
+            next_occurrence(
+                datetime("2099-01-09 09:00"),
+                1,
+                RepeatUnit::Weeks,
+                mon_wed_fri
+            ),
+            Some(datetime("2099-01-12 09:00"))
+        );
+        // every other week
+        assert_eq!(
+            next_occurrence(
+                datetime("2099-01-09 09:00"),
+                2,
+                RepeatUnit::Weeks,
+                mon_wed_fri
+            ),
+            Some(datetime("2099-01-19 09:00"))
+        );
+        // friday to monday
+        assert_eq!(
+            next_occurrence(
+                datetime("2099-01-09 17:00"),
+                1,
+                RepeatUnit::Weeks,
+                rule("weekdays")
+            ),
+            Some(datetime("2099-01-12 17:00"))
+        );
+    }
+
+    #[test]
+    fn days_of_the_month_are_correct() {
+        assert_eq!(
+            next_occurrence(
+                datetime("2099-01-01 08:00"),
+                1,
+                RepeatUnit::Months,
+                rule("2nd tue")
+            ),
+            Some(datetime("2099-01-13 08:00"))
+        );
+        assert_eq!(
+            next_occurrence(
+                datetime("2099-01-13 08:00"),
+                1,
+                RepeatUnit::Months,
+                rule("2nd tue")
+            ),
+            Some(datetime("2099-02-10 08:00"))
+        );
+        assert_eq!(
+            next_occurrence(
+                datetime("2099-01-01 08:00"),
+                1,
+                RepeatUnit::Months,
+                rule("last fri")
+            ),
+            Some(datetime("2099-01-30 08:00"))
+        );
+        // there's no 5th thursday in 2099-02, but there is in 2099-04
+        assert_eq!(
+            next_occurrence(
+                datetime("2099-01-29 08:00"),
+                1,
+                RepeatUnit::Months,
+                rule("5th thu")
+            ),
+            Some(datetime("2099-04-30 08:00"))
+        );
+        assert_eq!(
+            next_occurrence(
+                datetime("2099-01-31 08:00"),
+                1,
+                RepeatUnit::Months,
+                rule("last day")
+            ),
+            Some(datetime("2099-02-28 08:00"))
+        );
+        // 2099-05-31 is a sunday
+        assert_eq!(
+            next_occurrence(
+                datetime("2099-04-30 17:00"),
+                1,
+                RepeatUnit::Months,
+                rule("last business day")
+            ),
+            Some(datetime("2099-05-29 17:00"))
+        );
+        // every 3 months
+        assert_eq!(
+            next_occurrence(
+                datetime("2099-01-31 08:00"),
+                3,
+                RepeatUnit::Months,
+                rule("last day")
+            ),
+            Some(datetime("2099-04-30 08:00"))
+        );
+    }
+
+    #[test]
+    fn previous_occurrences_are_correct() {
+        assert_eq!(
+            previous_occurrence(datetime("2099-02-28 09:00"), 1, RepeatUnit::Months, None),
+            Some(datetime("2099-01-28 09:00"))
+        );
+
+        // each one is the occurrence right before, so that next_occurrence lands back on it
+        for (due, interval, unit, on, previous) in [
+            // 2099-01-12 is a monday
+            (
+                "2099-01-12 09:00",
+                1,
+                RepeatUnit::Weeks,
+                "mon,wed,fri",
+                "2099-01-09 09:00",
+            ),
+            (
+                "2099-01-14 09:00",
+                1,
+                RepeatUnit::Weeks,
+                "mon,wed,fri",
+                "2099-01-12 09:00",
+            ),
+            (
+                "2099-01-19 09:00",
+                2,
+                RepeatUnit::Weeks,
+                "mon,wed,fri",
+                "2099-01-09 09:00",
+            ),
+            (
+                "2099-01-12 17:00",
+                1,
+                RepeatUnit::Weeks,
+                "weekdays",
+                "2099-01-09 17:00",
+            ),
+            (
+                "2099-02-10 08:00",
+                1,
+                RepeatUnit::Months,
+                "2nd tue",
+                "2099-01-13 08:00",
+            ),
+            (
+                "2099-04-30 08:00",
+                1,
+                RepeatUnit::Months,
+                "5th thu",
+                "2099-01-29 08:00",
+            ),
+            (
+                "2099-04-30 08:00",
+                3,
+                RepeatUnit::Months,
+                "last day",
+                "2099-01-31 08:00",
+            ),
+            (
+                "2099-05-29 17:00",
+                1,
+                RepeatUnit::Months,
+                "last business day",
+                "2099-04-30 17:00",
+            ),
+        ] {
+            let (due, previous) = (datetime(due), datetime(previous));
+            assert_eq!(
+                previous_occurrence(due, interval, unit, rule(on)),
+                Some(previous)
+            );
+            assert_eq!(
+                next_occurrence(previous, interval, unit, rule(on)),
+                Some(due)
+            );
+        }
+    }
+
+    #[test]
+    fn catch_up_reads_back_the_same() {
+        for text in ["once", "next", "each"] {
+            assert_eq!(text.parse::<CatchUp>().unwrap().to_string(), text);
+        }
+        assert!("all".parse::<CatchUp>().is_err());
+    }
+
+    #[test]
+    fn huge_interval_is_none() {
+        assert_eq!(
+            next_occurrence(
+                datetime("2099-01-01 00:00"),
+                u32::MAX,
+                RepeatUnit::Weeks,
+                rule("mon")
+            ),
+            None
+        );
+        assert_eq!(
+            next_occurrence(
+                datetime("2099-01-31 00:00"),
+                u32::MAX,
+                RepeatUnit::Months,
+                rule("last day")
+            ),
+            None
+        );
+    }
+}
@@ -1,367 +1,137 @@
-use super::regular_helpers::*;
-use crate::functions::general_helpers::{check_if_range_positioning, unwrap_range_positioning};
-use std::io::Write;
-
-pub fn regular_tasks_remove_done(mut done_to_remove: Vec<String>) -> bool {
-    // housekeeping
-    regular_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
+use crate::functions::errors::ChartodoError;
+use crate::functions::general_helpers::archive_done_tasks;
+use crate::functions::storage_helpers::{open_task_store, save_task_store};
+use crate::functions::task_ids::PositionsIn;
+use crate::functions::task_store::{PositionErrors, TaskList};
 
+pub fn regular_tasks_remove_done(done_to_remove: Vec<String>) -> Result<(), ChartodoError> {
     // open file and parse
-    let mut regular_tasks = open_regular_tasks_and_return_tasks_struct();
-
-    // check if the done list is empty
-    if regular_tasks.done.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The regular done list is currently empty, so you can't \
-            remove any items."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // for the record, i hate that this is a separate iteration
-    // go thru list and check if an item is ranged. if yes, unwrap it and push to original list
-    for i in (0..done_to_remove.len()).rev() {
-        let (error_or_not, bound1, bound2) = check_if_range_positioning(
-            done_to_remove.get(i).unwrap().to_string(),
-            regular_tasks.done.len(),
-        );
-
-        if !error_or_not {
-            let unwrapped_range = unwrap_range_positioning(bound1, bound2);
-            unwrapped_range
-                .iter()
-                .for_each(|number| done_to_remove.push(number.to_string()));
-            // this is not good
-        }
-    }
-
-    // filter for viable items
-    for i in (0..done_to_remove.len()).rev() {
-        if done_to_remove.get(i).unwrap().parse::<usize>().is_err()
-            || done_to_remove.get(i).unwrap().is_empty() // this will never trigger smh
-            || done_to_remove.get(i).unwrap().parse::<usize>().unwrap() == 0
-            || done_to_remove.get(i).unwrap().parse::<usize>().unwrap() > regular_tasks.done.len()
-        {
-            done_to_remove.swap_remove(i);
-        }
-    }
-
-    // check if all args were invalid
-    if done_to_remove.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: None of the positions you provided were viable \
-            -- they were all either negative, zero, exceeded the regular \
-            done list's length, or were invalid range positioning."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // sort and dedup
-    let mut done_to_remove: Vec<usize> = done_to_remove
-        .iter()
-        .map(|x| x.parse::<usize>().unwrap())
-        .collect();
-    done_to_remove.sort();
-    done_to_remove.dedup();
-
-    // check if user wants to remove all of the items
-    if done_to_remove.len() >= regular_tasks.done.len() && regular_tasks.done.len() > 5 {
-        writeln!(
-            writer,
-            "WARNING: You've specified removing the entire regular \
-            done list. You should do chartodo cleardone."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // remove each item one by one
-    done_to_remove.iter().rev().for_each(|position| {
-        regular_tasks.done.remove(position - 1);
-    });
+    let mut store = open_task_store()?;
+
+    // unwrap any ranges and check the positions
+    let done_to_remove = store.positions_from_args(
+        PositionsIn::RegularDone,
+        &done_to_remove,
+        &PositionErrors {
+            empty: "The regular done list is currently empty, so you can't \
+                remove any items.",
+            none_viable: "None of the positions you provided were viable \
+                -- they were all either negative, zero, exceeded the regular \
+                done list's length, or were invalid range positioning.",
+            whole_list: Some(
+                "You've specified removing the entire regular \
+                done list. You should do chartodo cleardone.",
+            ),
+        },
+    )?;
+
+    // remove the items
+    store.remove(PositionsIn::RegularDone, &done_to_remove)?;
 
     // write changes to file
-    write_changes_to_new_regular_tasks(regular_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn regular_tasks_not_done(mut done_to_todo: Vec<String>) -> bool {
-    // housekeeping
-    regular_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn regular_tasks_not_done(done_to_todo: Vec<String>) -> Result<(), ChartodoError> {
     // open file and parse
-    let mut regular_tasks = open_regular_tasks_and_return_tasks_struct();
-
-    // check if the done list is empty
-    if regular_tasks.done.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The regular done list is currently empty, so \
-            you can't reverse any items back to todo."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // for the record, i hate that this is a separate iteration
-    // go thru list and check if an item is ranged. if yes, unwrap it and push to original list
-    for i in (0..done_to_todo.len()).rev() {
-        let (error_or_not, bound1, bound2) = check_if_range_positioning(
-            done_to_todo.get(i).unwrap().to_string(),
-            regular_tasks.done.len(),
-        );
-
-        if !error_or_not {
-            let unwrapped_range = unwrap_range_positioning(bound1, bound2);
-            unwrapped_range
-                .iter()
-                .for_each(|number| done_to_todo.push(number.to_string()));
-            // this is not good
-        }
-    }
-
-    // filter for viable items
-    for i in (0..done_to_todo.len()).rev() {
-        if done_to_todo.get(i).unwrap().parse::<usize>().is_err()
-            || done_to_todo.get(i).unwrap().is_empty() // this will never trigger smh
-            || done_to_todo.get(i).unwrap().parse::<usize>().unwrap() == 0
-            || done_to_todo.get(i).unwrap().parse::<usize>().unwrap() > regular_tasks.done.len()
-        {
-            done_to_todo.swap_remove(i);
-        }
-    }
-
-    // check if all args were invalid
-    if done_to_todo.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: None of the positions you provided were viable \
-            -- they were all either negative, zero, exceeded the regular \
-            done list's length, or were invalid range positioning."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // sort and dedup
-    let mut done_to_todo: Vec<usize> = done_to_todo
-        .iter()
-        .map(|x| x.parse::<usize>().unwrap())
-        .collect();
-    done_to_todo.sort();
-    done_to_todo.dedup();
-
-    // check if user wants to remove all done items to todo
-    if done_to_todo.len() >= regular_tasks.done.len() && regular_tasks.done.len() > 5 {
-        writeln!(
-            writer,
-            "WARNING: you've specified reversing the entire regular done list \
-            back to todo. You should do chartodo notdoneall."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // reverse dones one by one
-    done_to_todo.iter().rev().for_each(|position| {
-        regular_tasks
-            .todo
-            .push(regular_tasks.done.get(position - 1).unwrap().clone());
-        regular_tasks.done.remove(position - 1);
-    });
+    let mut store = open_task_store()?;
+
+    // unwrap any ranges and check the positions
+    let done_to_todo = store.positions_from_args(
+        PositionsIn::RegularDone,
+        &done_to_todo,
+        &PositionErrors {
+            empty: "The regular done list is currently empty, so \
+                you can't reverse any items back to todo.",
+            none_viable: "None of the positions you provided were viable \
+                -- they were all either negative, zero, exceeded the regular \
+                done list's length, or were invalid range positioning.",
+            whole_list: Some(
+                "you've specified reversing the entire regular done list \
+                back to todo. You should do chartodo notdoneall.",
+            ),
+        },
+    )?;
+
+    // reverse dones
+    store.uncomplete(TaskList::Regular, &done_to_todo)?;
 
     // write changes to file
-    write_changes_to_new_regular_tasks(regular_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn regular_tasks_clear_done() -> bool {
-    // housekeeping
-    regular_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
+// moves done items to the archive instead of removing them. Has chaining and range positioning
+pub fn regular_tasks_archive_done(done_to_archive: Vec<String>) -> Result<(), ChartodoError> {
+    archive_done_tasks(TaskList::Regular, done_to_archive, "cleardone")
+}
 
+// w/ archive, the done items are moved to the archive instead of being deleted
+pub fn regular_tasks_clear_done(archive: bool) -> Result<(), ChartodoError> {
     // open file and parse
-    let mut regular_tasks = open_regular_tasks_and_return_tasks_struct();
+    let mut store = open_task_store()?;
+    let regular_tasks = store.regular();
 
     // check if the done list is empty
     if regular_tasks.done.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The regular done list is currently empty, so you can't \
+        return Err(ChartodoError::EmptyList(
+            "The regular done list is currently empty, so you can't \
             remove any items."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
+                .to_string(),
+        ));
     }
 
     // clear done list
-    regular_tasks.done.clear();
+    match archive {
+        true => store.archive_all(TaskList::Regular),
+        false => store.clear(PositionsIn::RegularDone),
+    }
 
     // write changes to file
-    write_changes_to_new_regular_tasks(regular_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn regular_tasks_reverse_all_dones() -> bool {
-    // housekeeping
-    regular_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn regular_tasks_reverse_all_dones() -> Result<(), ChartodoError> {
     // open file and parse
-    let mut regular_tasks = open_regular_tasks_and_return_tasks_struct();
+    let mut store = open_task_store()?;
+    let regular_tasks = store.regular();
 
     // check if the done list is empty
     if regular_tasks.done.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The regular done list is currently empty, so \
+        return Err(ChartodoError::EmptyList(
+            "The regular done list is currently empty, so \
             you can't reverse any items back to todo."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
+                .to_string(),
+        ));
     }
 
     // reverse all done items
-    regular_tasks
-        .done
-        .iter()
-        .for_each(|item| regular_tasks.todo.push(item.clone()));
-    regular_tasks.done.clear();
+    store.uncomplete_all(TaskList::Regular);
 
     // write changes to file
-    write_changes_to_new_regular_tasks(regular_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-// cargo test regular_done_unit_tests -- --test-threads=1
+// cargo test regular_done_unit_tests
 #[cfg(test)]
 mod regular_done_unit_tests {
+    use super::super::regular_helpers::*;
     use super::*;
     use crate::functions::json_file_structs::*;
+    use crate::functions::storage_helpers::test_data_dir;
     use anyhow::Context;
-    use std::path::PathBuf;
-
-    // these are taken from regular_helpers
-    fn path_to_regular_tasks() -> PathBuf {
-        // get the data dir XDG spec and return it with path to regular_tasks.json
-        let mut regular_tasks_path = dirs::data_dir()
-            .context(
-                "linux: couldn't get $HOME/.local/share/
-                    windows: couldn't get C:/Users/your_user/AppData/Local/
-                    mac: couldn't get /Users/your_user/Library/Application Support/
-
-                    those directories should exist for your OS. please double check that they do.",
-            )
-            .expect("something went wrong with fetching the user's data dirs");
-        regular_tasks_path.push("chartodo/regular_tasks.json");
-
-        regular_tasks_path
-    }
-
-    fn regular_tasks_copy_path() -> PathBuf {
-        // get the path for regular_tasks_copy.json, which will be used to hold the original contents
-        // of regular_tasks.json while it's getting modified
-        let mut regular_tasks_copy_path = dirs::data_dir()
-            .context(
-                "linux: couldn't get $HOME/.local/share/
-                    windows: couldn't get C:/Users/your_user/AppData/Local/
-                    mac: couldn't get /Users/your_user/Library/Application Support/
-
-                    those directories should exist for your OS. please double check that they do.",
-            )
-            .expect("something went wrong with fetching the user's data dirs");
-        regular_tasks_copy_path.push("chartodo/regular_tasks_copy.json");
-
-        regular_tasks_copy_path
-    }
-
-    // these have been tested in other fns, these are just included here as a sanity check
-    #[test]
-    fn regular_tasks_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/regular_tasks.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/regular_tasks.json";
-        let mac_path = "/Library/Application Support/chartodo/regular_tasks.json";
-        let mut got_regular_tasks_path: bool = false;
-        let regular_path = path_to_regular_tasks();
-        let regular_path = regular_path.to_str().unwrap();
-
-        if regular_path.contains(linux_path)
-            | regular_path.contains(windows_path)
-            | regular_path.contains(mac_path)
-        {
-            got_regular_tasks_path = true;
-        }
-
-        assert!(got_regular_tasks_path);
-    }
-
-    #[test]
-    fn regular_tasks_copy_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/regular_tasks_copy.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/regular_tasks_copy.json";
-        let mac_path = "/Library/Application Support/chartodo/regular_tasks_copy.json";
-        let mut got_regular_tasks_copy_path: bool = false;
-        let regular_tasks_copy_path = regular_tasks_copy_path();
-        let regular_tasks_copy_path = regular_tasks_copy_path.to_str().unwrap();
-
-        if regular_tasks_copy_path.contains(linux_path)
-            | regular_tasks_copy_path.contains(windows_path)
-            | regular_tasks_copy_path.contains(mac_path)
-        {
-            got_regular_tasks_copy_path = true;
-        }
-
-        assert!(got_regular_tasks_copy_path);
-    }
-
-    #[test]
-    fn aaaa_regular_tasks_clone_file() {
-        // name is aaaa so it's done first
-        // since we will be modifying the original file to run a test, the original data must be
-        // preserved first
-        std::fs::File::create(regular_tasks_copy_path())
-            .context("failed to create regular_tasks_copy.json")
-            .expect("failed to create a copy during unit test");
-
-        std::fs::copy(path_to_regular_tasks(), regular_tasks_copy_path())
-            .context("failed to copy regular_tasks.json to regular_tasks_copy.json")
-            .expect("failed to copy original file to copy file during unit test");
-    }
 
     #[test]
     fn regular_tasks_remove_done_done_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -380,13 +150,15 @@ mod regular_done_unit_tests {
 
         // check that regular done list is correctly identified as empty
         let arguments = vec![String::from("1")];
-        let error_should_be_true = regular_tasks_remove_done(arguments);
+        let error_should_be_true = regular_tasks_remove_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_remove_done_no_valid_arguments() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -416,13 +188,15 @@ mod regular_done_unit_tests {
 
         // check that there are no valid arguments
         let arguments = vec![String::from("2"), String::from("-1"), String::from("0")];
-        let error_should_be_true = regular_tasks_remove_done(arguments);
+        let error_should_be_true = regular_tasks_remove_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_remove_done_should_do_cleardone() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -509,13 +283,15 @@ mod regular_done_unit_tests {
             String::from("5"),
             String::from("6"),
         ];
-        let error_should_be_true = regular_tasks_remove_done(arguments);
+        let error_should_be_true = regular_tasks_remove_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_remove_done_should_do_cleardone_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -595,13 +371,15 @@ mod regular_done_unit_tests {
 
         // check that user should do notdoneall
         let arguments = vec![String::from("1-6")];
-        let error_should_be_true = regular_tasks_remove_done(arguments);
+        let error_should_be_true = regular_tasks_remove_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_remove_done_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -630,7 +408,7 @@ mod regular_done_unit_tests {
 
         // perform actions
         let arguments = vec![String::from("1")];
-        let error_should_be_false = regular_tasks_remove_done(arguments);
+        let error_should_be_false = regular_tasks_remove_done(arguments).is_err();
         let read_test_file = open_regular_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -653,6 +431,8 @@ mod regular_done_unit_tests {
 
     #[test]
     fn regular_tasks_remove_done_multiple_args_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -701,7 +481,7 @@ mod regular_done_unit_tests {
 
         // perform actions. 1 invalid arg + 1 left on purpose
         let arguments = vec![String::from("5"), String::from("1"), String::from("3")];
-        let error_should_be_false = regular_tasks_remove_done(arguments);
+        let error_should_be_false = regular_tasks_remove_done(arguments).is_err();
         let read_test_file = open_regular_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -735,6 +515,8 @@ mod regular_done_unit_tests {
 
     #[test]
     fn regular_tasks_remove_done_multiple_args_is_correct_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -783,7 +565,7 @@ mod regular_done_unit_tests {
 
         // perform actions. 1 invalid arg + 1 left on purpose
         let arguments = vec![String::from("5"), String::from("1-2")];
-        let error_should_be_false = regular_tasks_remove_done(arguments);
+        let error_should_be_false = regular_tasks_remove_done(arguments).is_err();
         let read_test_file = open_regular_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -817,6 +599,8 @@ mod regular_done_unit_tests {
 
     #[test]
     fn regular_tasks_not_done_done_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -835,13 +619,15 @@ mod regular_done_unit_tests {
 
         // check that regular done list is correctly identified as empty
         let arguments = vec![String::from("1")];
-        let error_should_be_true = regular_tasks_not_done(arguments);
+        let error_should_be_true = regular_tasks_not_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_not_done_no_valid_arguments() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -871,13 +657,15 @@ mod regular_done_unit_tests {
 
         // check that there are no valid arguments
         let arguments = vec![String::from("2"), String::from("-1"), String::from("0")];
-        let error_should_be_true = regular_tasks_not_done(arguments);
+        let error_should_be_true = regular_tasks_not_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_not_done_should_do_notdoneall() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -964,13 +752,15 @@ mod regular_done_unit_tests {
             String::from("5"),
             String::from("6"),
         ];
-        let error_should_be_true = regular_tasks_not_done(arguments);
+        let error_should_be_true = regular_tasks_not_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_not_done_should_do_notdoneall_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1050,13 +840,15 @@ mod regular_done_unit_tests {
 
         // check that user should do cleardone
         let arguments = vec![String::from("1-6")];
-        let error_should_be_true = regular_tasks_not_done(arguments);
+        let error_should_be_true = regular_tasks_not_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_not_done_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1085,7 +877,7 @@ mod regular_done_unit_tests {
 
         // perform actions
         let arguments = vec![String::from("1")];
-        let error_should_be_false = regular_tasks_not_done(arguments);
+        let error_should_be_false = regular_tasks_not_done(arguments).is_err();
         let read_test_file = open_regular_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -1119,6 +911,8 @@ mod regular_done_unit_tests {
 
     #[test]
     fn regular_tasks_not_done_multiple_args_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1167,7 +961,7 @@ mod regular_done_unit_tests {
 
         // perform actions. 1 invalid arg + 1 left over
         let arguments = vec![String::from("3"), String::from("-2"), String::from("2")];
-        let error_should_be_false = regular_tasks_not_done(arguments);
+        let error_should_be_false = regular_tasks_not_done(arguments).is_err();
         let read_test_file = open_regular_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -1222,6 +1016,8 @@ mod regular_done_unit_tests {
 
     #[test]
     fn regular_tasks_not_done_multiple_args_is_correct_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1270,7 +1066,7 @@ mod regular_done_unit_tests {
 
         // perform actions. 1 invalid arg + 1 left over
         let arguments = vec![String::from("-2"), String::from("2-3")];
-        let error_should_be_false = regular_tasks_not_done(arguments);
+        let error_should_be_false = regular_tasks_not_done(arguments).is_err();
         let read_test_file = open_regular_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -1325,6 +1121,8 @@ mod regular_done_unit_tests {
 
     #[test]
     fn regular_tasks_cleardone_done_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1342,13 +1140,15 @@ mod regular_done_unit_tests {
         write_changes_to_new_regular_tasks(fresh_regular_tasks);
 
         // check that regular done list is correctly identified as empty
-        let error_should_be_true = regular_tasks_clear_done();
+        let error_should_be_true = regular_tasks_clear_done(false).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_cleardone_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1386,7 +1186,7 @@ mod regular_done_unit_tests {
         write_changes_to_new_regular_tasks(fresh_regular_tasks);
 
         // perform actions
-        let error_should_be_false = regular_tasks_clear_done();
+        let error_should_be_false = regular_tasks_clear_done(false).is_err();
         let read_test_file = open_regular_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -1409,6 +1209,8 @@ mod regular_done_unit_tests {
 
     #[test]
     fn regular_tasks_notdoneall_done_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1426,13 +1228,15 @@ mod regular_done_unit_tests {
         write_changes_to_new_regular_tasks(fresh_regular_tasks);
 
         // check that regular done list is correctly identified as empty
-        let error_should_be_true = regular_tasks_reverse_all_dones();
+        let error_should_be_true = regular_tasks_reverse_all_dones().is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_notdoneall_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1470,7 +1274,7 @@ mod regular_done_unit_tests {
         write_changes_to_new_regular_tasks(fresh_regular_tasks);
 
         // perform actions
-        let error_should_be_false = regular_tasks_reverse_all_dones();
+        let error_should_be_false = regular_tasks_reverse_all_dones().is_err();
         let read_test_file = open_regular_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -1511,18 +1315,4 @@ mod regular_done_unit_tests {
         assert!(!error_should_be_false);
         assert_eq!(read_test_file, regular_tasks);
     }
-
-    #[test]
-    fn zzzz_rename_copy_to_original() {
-        // name is zzzz so it's done last
-        // now that tests are done, remove the modified original and rename copy to original
-
-        std::fs::remove_file(path_to_regular_tasks())
-            .context("failed delete modified regular_tasks.json after running tests")
-            .expect("failed to delete regular_tasks.json after regular_helpers unit tests");
-
-        std::fs::rename(regular_tasks_copy_path(), path_to_regular_tasks())
-            .context("failed to rename regular_tasks_copy to regular_tasks")
-            .expect("failed to rename regular_tasks_copy to regular_tasks after tests were done");
-    }
 }
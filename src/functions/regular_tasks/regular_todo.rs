@@ -1,497 +1,207 @@
-use super::regular_helpers::*;
-use crate::functions::general_helpers::{check_if_range_positioning, unwrap_range_positioning};
+use crate::functions::errors::ChartodoError;
+use crate::functions::general_helpers::{edit_priority_of_todo, tag_todo, untag_todo};
 use crate::functions::json_file_structs::*;
-use std::io::Write;
-
-pub fn regular_tasks_add_todo(add_todo: Vec<String>) {
-    // housekeeping
-    regular_tasks_create_dir_and_file_if_needed();
+use crate::functions::storage_helpers::{open_task_store, save_task_store};
+use crate::functions::task_ids::PositionsIn;
+use crate::functions::task_store::{PositionErrors, TaskList};
+
+pub fn regular_tasks_add_todo(
+    add_todo: Vec<String>,
+    priority: Option<Priority>,
+    tags: Vec<String>,
+) -> Result<(), ChartodoError> {
     // open file and parse
-    let mut regular_tasks = open_regular_tasks_and_return_tasks_struct();
+    let mut store = open_task_store()?;
 
     // add todos
-    let mut index: usize = 0;
-    // i can't do an iter for each loop since i can't return from inside a closure
-    while index < add_todo.len() {
-        let new_task = Task {
-            task: add_todo.get(index).unwrap().to_string(),
-            date: None,
-            time: None,
-            repeat_number: None,
-            repeat_unit: None,
-            repeat_done: None,
-            repeat_original_date: None,
-            repeat_original_time: None,
-        };
-        regular_tasks.todo.push(new_task);
-
-        index += 1;
+    for task in add_todo {
+        store.add(Task::regular(task).with_priority(priority).with_tags(&tags));
     }
 
     // write changes to file
-    write_changes_to_new_regular_tasks(regular_tasks);
+    save_task_store(&mut store)
 }
 
-pub fn regular_tasks_change_todo_to_done(mut todo_to_done: Vec<String>) -> bool {
-    // housekeeping
-    regular_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn regular_tasks_change_todo_to_done(todo_to_done: Vec<String>) -> Result<(), ChartodoError> {
     // open file and parse
-    let mut regular_tasks = open_regular_tasks_and_return_tasks_struct();
-
-    // check if todo list is empty
-    if regular_tasks.todo.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The regular todo list is currently empty so you can't change \
-            any todos to done."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // for the record, i hate that this is a separate iteration
-    // go thru list and check if an item is ranged. if yes, unwrap it and push to original list
-    for i in (0..todo_to_done.len()).rev() {
-        let (error_or_not, bound1, bound2) = check_if_range_positioning(
-            todo_to_done.get(i).unwrap().to_string(),
-            regular_tasks.todo.len(),
-        );
-
-        if !error_or_not {
-            let unwrapped_range = unwrap_range_positioning(bound1, bound2);
-            unwrapped_range
-                .iter()
-                .for_each(|number| todo_to_done.push(number.to_string()));
-            // this is not good
-        }
-    }
-
-    // filter for viable items
-    // rev cuz i want the indices to be viable after swap removing
-    for i in (0..todo_to_done.len()).rev() {
-        if todo_to_done.get(i).unwrap().parse::<usize>().is_err()
-            || todo_to_done.get(i).unwrap().is_empty() // this will never trigger smh
-            || todo_to_done.get(i).unwrap().parse::<usize>().unwrap() == 0
-            || todo_to_done.get(i).unwrap().parse::<usize>().unwrap() > regular_tasks.todo.len()
-        {
-            todo_to_done.swap_remove(i);
-        }
-    }
-
-    // check if none of the args were valid
-    if todo_to_done.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: None of the positions you provided were viable \
-            -- they were all either negative, zero, exceeded the regular \
-            todo list's length, or were invalid range positioning."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // sort and dedup. Vec<usize> is needed for sorting
-    let mut todo_to_done: Vec<usize> = todo_to_done
-        .iter()
-        .map(|x| x.parse::<usize>().unwrap())
-        .collect();
-    todo_to_done.sort();
-    todo_to_done.dedup();
-
-    // check if the user basically specified the entire list
-    if todo_to_done.len() >= regular_tasks.todo.len() && regular_tasks.todo.len() > 5 {
-        writeln!(
-            writer,
-            "WARNING: you've specified marking the entire regular todo list as \
-            done. You should do chartodo doneall."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // change todos to dones one by one. no idea if the parse slows down the process significantly
-    // rev is done so that removing by position doesn't become invalid
-    todo_to_done.iter().rev().for_each(|position| {
-        regular_tasks
-            .done
-            .push(regular_tasks.todo.get(position - 1).unwrap().to_owned());
-        regular_tasks.todo.remove(position - 1);
-    });
+    let mut store = open_task_store()?;
+
+    // unwrap any ranges and check the positions
+    let todo_to_done = store.positions_from_args(
+        PositionsIn::RegularTodo,
+        &todo_to_done,
+        &PositionErrors {
+            empty: "The regular todo list is currently empty so you can't change \
+                any todos to done.",
+            none_viable: "None of the positions you provided were viable \
+                -- they were all either negative, zero, exceeded the regular \
+                todo list's length, or were invalid range positioning.",
+            whole_list: Some(
+                "you've specified marking the entire regular todo list as \
+                done. You should do chartodo doneall.",
+            ),
+        },
+    )?;
+
+    // change todos to dones
+    store.complete(TaskList::Regular, &todo_to_done)?;
 
     // write changes to file
-    write_changes_to_new_regular_tasks(regular_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn regular_tasks_remove_todo(mut todo_to_remove: Vec<String>) -> bool {
-    // housekeeping
-    regular_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn regular_tasks_remove_todo(todo_to_remove: Vec<String>) -> Result<(), ChartodoError> {
     // open file and parse
-    let mut regular_tasks = open_regular_tasks_and_return_tasks_struct();
-
-    // check if todo list is empty
-    if regular_tasks.todo.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The regular todo list is currently empty, so you can't \
-            remove any items. Try adding to it first before removing any."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // for the record, i hate that this is a separate iteration
-    // go thru list and check if an item is ranged. if yes, unwrap it and push to original list
-    for i in (0..todo_to_remove.len()).rev() {
-        let (error_or_not, bound1, bound2) = check_if_range_positioning(
-            todo_to_remove.get(i).unwrap().to_string(),
-            regular_tasks.todo.len(),
-        );
-
-        if !error_or_not {
-            let unwrapped_range = unwrap_range_positioning(bound1, bound2);
-            unwrapped_range
-                .iter()
-                .for_each(|number| todo_to_remove.push(number.to_string()));
-            // this is not good
-        }
-    }
-
-    // filter for viable items
-    for i in (0..todo_to_remove.len()).rev() {
-        if todo_to_remove.get(i).unwrap().parse::<usize>().is_err()
-            || todo_to_remove.get(i).unwrap().is_empty() // this will never trigger smh
-            || todo_to_remove.get(i).unwrap().parse::<usize>().unwrap() == 0
-            || todo_to_remove.get(i).unwrap().parse::<usize>().unwrap() > regular_tasks.todo.len()
-        {
-            todo_to_remove.swap_remove(i);
-        }
-    }
-
-    // check if all args were invalid
-    if todo_to_remove.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: None of the positions you provided were viable \
-            -- they were all either negative, zero, exceeded the regular \
-            todo list's length, or were invalid range positioning."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // sort and dedup
-    let mut todo_to_remove: Vec<usize> = todo_to_remove
-        .iter()
-        .map(|x| x.parse::<usize>().unwrap())
-        .collect();
-    todo_to_remove.sort();
-    todo_to_remove.dedup();
-
-    // check if user wants to remove all of the items
-    if todo_to_remove.len() >= regular_tasks.todo.len() && regular_tasks.todo.len() > 5 {
-        writeln!(
-            writer,
-            "WARNING: You specified removing the entire regular todo list. You \
-            should instead do chartodo cleartodo."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // remove each item one by one
-    todo_to_remove.iter().rev().for_each(|position| {
-        regular_tasks.todo.remove(position - 1);
-    });
+    let mut store = open_task_store()?;
+
+    // unwrap any ranges and check the positions
+    let todo_to_remove = store.positions_from_args(
+        PositionsIn::RegularTodo,
+        &todo_to_remove,
+        &PositionErrors {
+            empty: "The regular todo list is currently empty, so you can't \
+                remove any items. Try adding to it first before removing any.",
+            none_viable: "None of the positions you provided were viable \
+                -- they were all either negative, zero, exceeded the regular \
+                todo list's length, or were invalid range positioning.",
+            whole_list: Some(
+                "You specified removing the entire regular todo list. You \
+                should instead do chartodo cleartodo.",
+            ),
+        },
+    )?;
+
+    // remove the items
+    store.remove(PositionsIn::RegularTodo, &todo_to_remove)?;
 
     // write changes to file
-    write_changes_to_new_regular_tasks(regular_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn regular_tasks_clear_todo() -> bool {
-    // housekeeping
-    regular_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn regular_tasks_clear_todo() -> Result<(), ChartodoError> {
     // open file and parse
-    let mut regular_tasks = open_regular_tasks_and_return_tasks_struct();
+    let mut store = open_task_store()?;
+    let regular_tasks = store.regular();
 
     // check if todo list is empty
     if regular_tasks.todo.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The regular todo list is currently empty. Try adding items \
+        return Err(ChartodoError::EmptyList(
+            "The regular todo list is currently empty. Try adding items \
             to it first before removing any."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
+                .to_string(),
+        ));
     }
 
     // clear todo list
-    regular_tasks.todo.clear();
+    store.clear(PositionsIn::RegularTodo);
 
     // write changes to file
-    write_changes_to_new_regular_tasks(regular_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn regular_tasks_change_all_todo_to_done() -> bool {
-    // housekeeping
-    regular_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn regular_tasks_change_all_todo_to_done() -> Result<(), ChartodoError> {
     // open file and parse
-    let mut regular_tasks = open_regular_tasks_and_return_tasks_struct();
+    let mut store = open_task_store()?;
+    let regular_tasks = store.regular();
 
     // check if todo list is empty
     if regular_tasks.todo.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The regular todo list is currently empty, so you can't \
+        return Err(ChartodoError::EmptyList(
+            "The regular todo list is currently empty, so you can't \
             change any todos to done."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
+                .to_string(),
+        ));
     }
 
-    // clear done list if it will overflow
-    if regular_tasks.todo.len() + regular_tasks.done.len() > 30 {
-        regular_tasks.done.clear();
-    }
-
-    // push all todos to done
-    regular_tasks
-        .todo
-        .iter()
-        .for_each(|item| regular_tasks.done.push(item.clone()));
-    regular_tasks.todo.clear();
+    // push all todos to done. if the done list gets too long, the oldest are archived when it's saved
+    store.complete_all(TaskList::Regular);
 
     // write changes to file
-    write_changes_to_new_regular_tasks(regular_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn regular_tasks_edit_todo(position_and_new: Vec<String>) -> bool {
-    // housekeeping
-    regular_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn regular_tasks_edit_todo(position: &str, task: &str) -> Result<(), ChartodoError> {
     // open file and parse
-    let mut regular_tasks = open_regular_tasks_and_return_tasks_struct();
+    let mut store = open_task_store()?;
 
-    // check if todo list is empty
-    if regular_tasks.todo.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The regular todo list is currently empty, so there are no \
-            todos that can be edited."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // the following ifs are the multitude of errors i have to check for
-
-    // check if we have the right number of arguments
-    if position_and_new.len() != 2 {
-        writeln!(
-            writer,
-            "ERROR: You must specify the regular todo's position \
-            that will be edited, and what to edit the task to.\n\tThere should be 2 \
-            arguments after 'chartodo edit'. You provided {} argument(s).\n\tFormat: \
-            chartodo edit ~position ~task\n\tExample: chartodo edit 4 new-item",
-            position_and_new.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
+    // check the position
+    let position = store.position_from_arg(
+        PositionsIn::RegularTodo,
+        position,
+        "The regular todo list is currently empty, so there are no \
+        todos that can be edited.",
+    )?;
 
-    // check if position is a valid number
-    if position_and_new.first().unwrap().parse::<usize>().is_err() {
-        writeln!(
-            writer,
-            "ERROR: To edit a regular task item, you must provide a viable \
-            position. Try something between 1 and {}",
-            regular_tasks.todo.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
+    // edit todo item
+    store.edit_task(PositionsIn::RegularTodo, position, task)?;
 
-    // positions can't be zero
-    if position_and_new.first().unwrap().parse::<usize>().unwrap() == 0 {
-        writeln!(
-            writer,
-            "ERROR: Positions can't be zero. They have to be 1 and above."
-        )
-        .expect("writeln failed");
+    // write changes to file
+    save_task_store(&mut store)?;
 
-        // error = true
-        return true;
-    }
+    Ok(())
+}
 
-    // position not in range of todo list len
-    if position_and_new.first().unwrap().parse::<usize>().unwrap() > regular_tasks.todo.len() {
-        writeln!(
-            writer,
-            "ERROR: The position you specified exceeds the regular todo list's \
-            current length. Try something between 1 and {}",
-            regular_tasks.todo.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
+pub fn regular_tasks_edit_priority(position: &str, priority: &str) -> Result<(), ChartodoError> {
+    // open file and parse
+    let mut store = open_task_store()?;
 
-    // edit todo item
-    let position: usize = position_and_new.first().unwrap().parse().unwrap();
-    // is this unwrap proper. i feel like it is, there should be no instances where it isn't accessible
-    regular_tasks.todo.get_mut(position - 1).unwrap().task =
-        position_and_new.last().unwrap().to_string();
+    // check the args and edit the task
+    edit_priority_of_todo(&mut store, TaskList::Regular, position, priority)?;
 
     // write changes to file
-    write_changes_to_new_regular_tasks(regular_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-// cargo test regular_todo_unit_tests -- --test-threads=1
-#[cfg(test)]
-mod regular_todo_unit_tests {
-    use super::*;
-    use anyhow::Context;
-    use std::path::PathBuf;
-
-    // these are taken from regular_helpers
-    fn path_to_regular_tasks() -> PathBuf {
-        // get the data dir XDG spec and return it with path to regular_tasks.json
-        let mut regular_tasks_path = dirs::data_dir()
-            .context(
-                "linux: couldn't get $HOME/.local/share/
-                    windows: couldn't get C:/Users/your_user/AppData/Local/
-                    mac: couldn't get /Users/your_user/Library/Application Support/
+pub fn regular_tasks_tag(position_and_tags: Vec<String>) -> Result<(), ChartodoError> {
+    // open file and parse
+    let mut store = open_task_store()?;
 
-                    those directories should exist for your OS. please double check that they do.",
-            )
-            .expect("something went wrong with fetching the user's data dirs");
-        regular_tasks_path.push("chartodo/regular_tasks.json");
+    // check the args and tag the task
+    tag_todo(&mut store, TaskList::Regular, &position_and_tags, "tag")?;
 
-        regular_tasks_path
-    }
+    // write changes to file
+    save_task_store(&mut store)?;
 
-    fn regular_tasks_copy_path() -> PathBuf {
-        // get the path for regular_tasks_copy.json, which will be used to hold the original contents
-        // of regular_tasks.json while it's getting modified
-        let mut regular_tasks_copy_path = dirs::data_dir()
-            .context(
-                "linux: couldn't get $HOME/.local/share/
-                    windows: couldn't get C:/Users/your_user/AppData/Local/
-                    mac: couldn't get /Users/your_user/Library/Application Support/
+    Ok(())
+}
 
-                    those directories should exist for your OS. please double check that they do.",
-            )
-            .expect("something went wrong with fetching the user's data dirs");
-        regular_tasks_copy_path.push("chartodo/regular_tasks_copy.json");
+pub fn regular_tasks_untag(position_and_tags: Vec<String>) -> Result<(), ChartodoError> {
+    // open file and parse
+    let mut store = open_task_store()?;
 
-        regular_tasks_copy_path
-    }
+    // check the args and untag the task
+    untag_todo(&mut store, TaskList::Regular, &position_and_tags, "untag")?;
 
-    // these have been tested in other fns, these are just included here as a sanity check
-    #[test]
-    fn regular_tasks_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/regular_tasks.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/regular_tasks.json";
-        let mac_path = "/Library/Application Support/chartodo/regular_tasks.json";
-        let mut got_regular_tasks_path: bool = false;
-        let regular_path = path_to_regular_tasks();
-        let regular_path = regular_path.to_str().unwrap();
-
-        if regular_path.contains(linux_path)
-            | regular_path.contains(windows_path)
-            | regular_path.contains(mac_path)
-        {
-            got_regular_tasks_path = true;
-        }
-
-        assert!(got_regular_tasks_path);
-    }
+    // write changes to file
+    save_task_store(&mut store)?;
 
-    #[test]
-    fn regular_tasks_copy_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/regular_tasks_copy.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/regular_tasks_copy.json";
-        let mac_path = "/Library/Application Support/chartodo/regular_tasks_copy.json";
-        let mut got_regular_tasks_copy_path: bool = false;
-        let regular_tasks_copy_path = regular_tasks_copy_path();
-        let regular_tasks_copy_path = regular_tasks_copy_path.to_str().unwrap();
-
-        if regular_tasks_copy_path.contains(linux_path)
-            | regular_tasks_copy_path.contains(windows_path)
-            | regular_tasks_copy_path.contains(mac_path)
-        {
-            got_regular_tasks_copy_path = true;
-        }
-
-        assert!(got_regular_tasks_copy_path);
-    }
+    Ok(())
+}
 
-    #[test]
-    fn aaaa_regular_tasks_clone_file() {
-        // name is aaaa so it's done first
-        // since we will be modifying the original file to run a test, the original data must be
-        // preserved first
-        std::fs::File::create(regular_tasks_copy_path())
-            .context("failed to create regular_tasks_copy.json")
-            .expect("failed to create a copy during unit test");
-
-        std::fs::copy(path_to_regular_tasks(), regular_tasks_copy_path())
-            .context("failed to copy regular_tasks.json to regular_tasks_copy.json")
-            .expect("failed to copy original file to copy file during unit test");
-    }
+// cargo test regular_todo_unit_tests
+#[cfg(test)]
+mod regular_todo_unit_tests {
+    use super::super::regular_helpers::*;
+    use super::*;
+    use crate::functions::storage_helpers::test_data_dir;
+    use anyhow::Context;
 
     // note that I can test for terminal printing in integration instead
 
     #[test]
     fn regular_tasks_adding_todo_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -510,7 +220,7 @@ mod regular_todo_unit_tests {
 
         // perform actions on file
         let arguments: Vec<String> = vec![String::from("this-is-the-todo-list")];
-        regular_tasks_add_todo(arguments);
+        regular_tasks_add_todo(arguments, None, vec![]).unwrap();
         let read_test_file = open_regular_tasks_and_return_tasks_struct();
 
         // this should be inside the file
@@ -543,6 +253,8 @@ mod regular_todo_unit_tests {
 
     #[test]
     fn regular_tasks_add_todo_multiple_args_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -562,7 +274,7 @@ mod regular_todo_unit_tests {
         // perform actions on file
         let arguments: Vec<String> =
             vec![String::from("this-is-the-todo-list"), String::from("hello")];
-        regular_tasks_add_todo(arguments);
+        regular_tasks_add_todo(arguments, None, vec![]).unwrap();
         let read_test_file = open_regular_tasks_and_return_tasks_struct();
 
         // this should be inside the file
@@ -605,6 +317,8 @@ mod regular_todo_unit_tests {
 
     #[test]
     fn regular_tasks_todo_to_done_regular_todo_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -634,13 +348,15 @@ mod regular_todo_unit_tests {
 
         // check that regular todo list is correctly identified as empty
         let arguments = vec![String::from("1")];
-        let error_should_be_true = regular_tasks_change_todo_to_done(arguments);
+        let error_should_be_true = regular_tasks_change_todo_to_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_todo_to_done_no_valid_arguments() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -685,13 +401,15 @@ mod regular_todo_unit_tests {
             String::from("2"),
             String::from("2-1"),
         ];
-        let error_should_be_true = regular_tasks_change_todo_to_done(arguments);
+        let error_should_be_true = regular_tasks_change_todo_to_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_todo_to_done_should_do_doneall() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -789,13 +507,15 @@ mod regular_todo_unit_tests {
             String::from("5"),
             String::from("6"),
         ];
-        let error_should_be_true = regular_tasks_change_todo_to_done(arguments);
+        let error_should_be_true = regular_tasks_change_todo_to_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_todo_to_done_should_do_doneall_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -886,13 +606,15 @@ mod regular_todo_unit_tests {
 
         // check that user should in fact do chartodo doneall
         let arguments = vec![String::from("1-6")];
-        let error_should_be_true = regular_tasks_change_todo_to_done(arguments);
+        let error_should_be_true = regular_tasks_change_todo_to_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_todo_to_done_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -932,7 +654,7 @@ mod regular_todo_unit_tests {
 
         // perform actions
         let arguments = vec![String::from("1")];
-        let error_should_be_false = regular_tasks_change_todo_to_done(arguments);
+        let error_should_be_false = regular_tasks_change_todo_to_done(arguments).is_err();
         let read_test_file = open_regular_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -977,6 +699,8 @@ mod regular_todo_unit_tests {
 
     #[test]
     fn regular_tasks_todo_to_done_multiple_args_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1041,7 +765,7 @@ mod regular_todo_unit_tests {
             String::from("2"),
             String::from("1"),
         ];
-        let error_should_be_false = regular_tasks_change_todo_to_done(arguments);
+        let error_should_be_false = regular_tasks_change_todo_to_done(arguments).is_err();
         let read_test_file = open_regular_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -1107,6 +831,8 @@ mod regular_todo_unit_tests {
 
     #[test]
     fn regular_tasks_todo_to_done_multiple_args_range_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1166,7 +892,7 @@ mod regular_todo_unit_tests {
 
         // perform actions. an invalid arg is included for testing
         let arguments = vec![String::from("1-2"), String::from("4")];
-        let error_should_be_false = regular_tasks_change_todo_to_done(arguments);
+        let error_should_be_false = regular_tasks_change_todo_to_done(arguments).is_err();
         let read_test_file = open_regular_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -1232,6 +958,8 @@ mod regular_todo_unit_tests {
 
     #[test]
     fn regular_tasks_remove_todo_todo_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1261,13 +989,15 @@ mod regular_todo_unit_tests {
 
         // check that regular todo list is correctly identified as empty
         let arguments = vec![String::from("1")];
-        let error_should_be_true = regular_tasks_remove_todo(arguments);
+        let error_should_be_true = regular_tasks_remove_todo(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_remove_todo_no_valid_arguments() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1313,13 +1043,15 @@ mod regular_todo_unit_tests {
             String::from("2"),
             String::from("2-1"),
         ];
-        let error_should_be_true = regular_tasks_remove_todo(arguments);
+        let error_should_be_true = regular_tasks_remove_todo(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_remove_todo_should_do_cleartodo() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1420,13 +1152,15 @@ mod regular_todo_unit_tests {
             String::from("5"),
             String::from("6"),
         ];
-        let error_should_be_true = regular_tasks_remove_todo(arguments);
+        let error_should_be_true = regular_tasks_remove_todo(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_remove_todo_should_do_cleartodo_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1520,13 +1254,15 @@ mod regular_todo_unit_tests {
 
         // check that user should do cleartodo
         let arguments = vec![String::from("1-6")];
-        let error_should_be_true = regular_tasks_remove_todo(arguments);
+        let error_should_be_true = regular_tasks_remove_todo(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_remove_todo_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1566,7 +1302,7 @@ mod regular_todo_unit_tests {
 
         // perform actions
         let arguments = vec![String::from("1")];
-        let error_should_be_false = regular_tasks_remove_todo(arguments);
+        let error_should_be_false = regular_tasks_remove_todo(arguments).is_err();
         let read_test_file = open_regular_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -1600,6 +1336,8 @@ mod regular_todo_unit_tests {
 
     #[test]
     fn regular_tasks_remove_todo_multiple_args_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1664,7 +1402,7 @@ mod regular_todo_unit_tests {
             String::from("-1"),
             String::from("3"),
         ];
-        let error_should_be_false = regular_tasks_remove_todo(arguments);
+        let error_should_be_false = regular_tasks_remove_todo(arguments).is_err();
         let read_test_file = open_regular_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -1709,6 +1447,8 @@ mod regular_todo_unit_tests {
 
     #[test]
     fn regular_tasks_remove_todo_multiple_args_is_correct_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1768,7 +1508,7 @@ mod regular_todo_unit_tests {
 
         // perform actions. an invalid arg + one is left in on purpose
         let arguments = vec![String::from("2-3")];
-        let error_should_be_false = regular_tasks_remove_todo(arguments);
+        let error_should_be_false = regular_tasks_remove_todo(arguments).is_err();
         let read_test_file = open_regular_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -1813,6 +1553,8 @@ mod regular_todo_unit_tests {
 
     #[test]
     fn regular_tasks_cleartodo_regular_todo_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1841,13 +1583,15 @@ mod regular_todo_unit_tests {
         write_changes_to_new_regular_tasks(fresh_regular_tasks);
 
         // check that regular todo list is correctly identified as empty
-        let error_should_be_true = regular_tasks_clear_todo();
+        let error_should_be_true = regular_tasks_clear_todo().is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_cleartoodo_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1875,7 +1619,7 @@ mod regular_todo_unit_tests {
         write_changes_to_new_regular_tasks(fresh_regular_tasks);
 
         // perform actions
-        let error_should_be_false = regular_tasks_clear_todo();
+        let error_should_be_false = regular_tasks_clear_todo().is_err();
         let read_test_file = open_regular_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -1898,6 +1642,8 @@ mod regular_todo_unit_tests {
 
     #[test]
     fn regular_tasks_doneall_todo_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1926,13 +1672,15 @@ mod regular_todo_unit_tests {
         write_changes_to_new_regular_tasks(fresh_regular_tasks);
 
         // check that regular todo list is correctly identified as empty
-        let error_should_be_true = regular_tasks_change_all_todo_to_done();
+        let error_should_be_true = regular_tasks_change_all_todo_to_done().is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_doneall_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1960,7 +1708,7 @@ mod regular_todo_unit_tests {
         write_changes_to_new_regular_tasks(fresh_regular_tasks);
 
         // perform actions
-        let error_should_be_false = regular_tasks_change_all_todo_to_done();
+        let error_should_be_false = regular_tasks_change_all_todo_to_done().is_err();
         let read_test_file = open_regular_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -1994,6 +1742,8 @@ mod regular_todo_unit_tests {
 
     #[test]
     fn regular_tasks_edit_todo_regular_todo_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2021,37 +1771,28 @@ mod regular_todo_unit_tests {
             .expect("changing str to tasks struct failed");
         write_changes_to_new_regular_tasks(fresh_regular_tasks);
 
-        let arguments = vec![String::from("1")];
-
         // check that regular todo list is correctly identified as empty
-        let error_should_be_true = regular_tasks_edit_todo(arguments);
-
-        assert!(error_should_be_true);
-    }
-
-    #[test]
-    fn regular_tasks_edit_todo_wrong_num_of_args() {
-        let arguments = vec![String::from("1")];
-
-        // check that a wrong # of args is identified
-        let error_should_be_true = regular_tasks_edit_todo(arguments);
+        let error_should_be_true = regular_tasks_edit_todo("1", "new_task").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_edit_todo_position_not_a_num() {
+        let _data_dir = test_data_dir();
+
         // the 2nd arg could be invalid, the first arg's invalid state will always be caught first
-        let arguments = vec![String::from("a"), String::from("new_task")];
 
         // check that a wrong # of args is identified
-        let error_should_be_true = regular_tasks_edit_todo(arguments);
+        let error_should_be_true = regular_tasks_edit_todo("a", "new_task").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_edit_todo_position_is_zero() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         // not needed to write anything here, but since chronologically/alphabetically the last test
         // wrote an empty todo list, running this with the same content
@@ -2095,26 +1836,26 @@ mod regular_todo_unit_tests {
             .expect("changing str to tasks struct failed");
         write_changes_to_new_regular_tasks(fresh_regular_tasks);
 
-        let arguments = vec![String::from("0"), String::from("new_task")];
-
         // catch that the position is zero
-        let error_should_be_true = regular_tasks_edit_todo(arguments);
+        let error_should_be_true = regular_tasks_edit_todo("0", "new_task").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_edit_todo_position_not_within_len() {
-        let arguments = vec![String::from("2"), String::from("new_task")];
+        let _data_dir = test_data_dir();
 
         // catch that the position is more than regular todo's len
-        let error_should_be_true = regular_tasks_edit_todo(arguments);
+        let error_should_be_true = regular_tasks_edit_todo("2", "new_task").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn regular_tasks_edit_todo_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2142,8 +1883,7 @@ mod regular_todo_unit_tests {
         write_changes_to_new_regular_tasks(fresh_regular_tasks);
 
         // perform actions
-        let arguments = vec![String::from("1"), String::from("new_task")];
-        let error_should_be_false = regular_tasks_edit_todo(arguments);
+        let error_should_be_false = regular_tasks_edit_todo("1", "new_task").is_err();
         let read_test_file = open_regular_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -2174,18 +1914,4 @@ mod regular_todo_unit_tests {
         assert!(!error_should_be_false);
         assert_eq!(read_test_file, regular_tasks);
     }
-
-    #[test]
-    fn zzzz_rename_copy_to_original() {
-        // name is zzzz so it's done last
-        // now that tests are done, remove the modified original and rename copy to original
-
-        std::fs::remove_file(path_to_regular_tasks())
-            .context("failed delete modified regular_tasks.json after running tests")
-            .expect("failed to delete regular_tasks.json after regular_helpers unit tests");
-
-        std::fs::rename(regular_tasks_copy_path(), path_to_regular_tasks())
-            .context("failed to rename regular_tasks_copy to regular_tasks")
-            .expect("failed to rename regular_tasks_copy to regular_tasks after tests were done");
-    }
 }
@@ -1,365 +1,131 @@
-use super::repeating_helpers::*;
-use crate::functions::general_helpers::{check_if_range_positioning, unwrap_range_positioning};
-use std::io::Write;
-
-pub fn repeating_tasks_not_done(mut not_done: Vec<String>) -> bool {
-    // housekeeping
-    repeating_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
+use crate::functions::errors::ChartodoError;
+use crate::functions::general_helpers::archive_done_tasks;
+use crate::functions::storage_helpers::{open_task_store, save_task_store};
+use crate::functions::task_ids::PositionsIn;
+use crate::functions::task_store::{PositionErrors, TaskList};
 
+pub fn repeating_tasks_not_done(not_done: Vec<String>) -> Result<(), ChartodoError> {
     // open file and parse
-    let mut repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
-
-    // check if the done list is empty
-    if repeating_tasks.done.is_empty() {
-        writeln!(writer, "ERROR: The repeating done list is currently empty.")
-            .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // for the record, i hate that this is a separate iteration
-    // go thru list and check if an item is ranged. if yes, unwrap it and push to original list
-    for i in (0..not_done.len()).rev() {
-        let (error_or_not, bound1, bound2) = check_if_range_positioning(
-            not_done.get(i).unwrap().to_string(),
-            repeating_tasks.done.len(),
-        );
-
-        if !error_or_not {
-            let unwrapped_range = unwrap_range_positioning(bound1, bound2);
-            unwrapped_range
-                .iter()
-                .for_each(|number| not_done.push(number.to_string()));
-            // this is not good
-        }
-    }
-
-    // filter for viable items
-    for i in (0..not_done.len()).rev() {
-        if not_done.get(i).unwrap().parse::<usize>().is_err()
-        || not_done.get(i).unwrap().is_empty() // this will never trigger smh
-        || not_done.get(i).unwrap().parse::<usize>().unwrap() == 0
-        || not_done.get(i).unwrap().parse::<usize>().unwrap() > repeating_tasks.done.len()
-        {
-            not_done.swap_remove(i);
-        }
-    }
-
-    // no valid args
-    if not_done.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: None of the positions you provided were viable \
-            -- they were all either negative, zero, or exceeded the repeating done list's \
-            length."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // sort and dedup
-    let mut not_done: Vec<usize> = not_done
-        .iter()
-        .map(|x| x.parse::<usize>().unwrap())
-        .collect();
-    not_done.sort();
-    not_done.dedup();
-
-    // check if user wants to move all done items to todo
-    if not_done.len() >= repeating_tasks.done.len() && repeating_tasks.done.len() > 5 {
-        writeln!(
-            writer,
-            "WARNING: You specified an entire done list that's \
-            relatively long. You should do repeating-notdoneall."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // before pushing to todo, change each repeat_done field in each specified done to false
-    not_done.iter().for_each(|position| {
-        repeating_tasks
-            .done
-            .get_mut(position - 1)
-            .unwrap()
-            .repeat_done = Some(false);
-    });
-
-    // reverse dones one by one
-    not_done.iter().rev().for_each(|position| {
-        repeating_tasks
-            .todo
-            .push(repeating_tasks.done.get(position - 1).unwrap().clone());
-        repeating_tasks.done.remove(position - 1);
-    });
+    let mut store = open_task_store()?;
+
+    // unwrap any ranges and check the positions
+    let not_done = store.positions_from_args(
+        PositionsIn::RepeatingDone,
+        &not_done,
+        &PositionErrors {
+            empty: "The repeating done list is currently empty.",
+            none_viable: "None of the positions you provided were viable \
+                -- they were all either negative, zero, or exceeded the repeating done list's \
+                length.",
+            whole_list: Some(
+                "You specified an entire done list that's \
+                relatively long. You should do repeating-notdoneall.",
+            ),
+        },
+    )?;
+
+    // reverse dones. each one's repeat_done field is changed to false
+    store.uncomplete(TaskList::Repeating, &not_done)?;
 
     // write changes to file
-    write_changes_to_new_repeating_tasks(repeating_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn repeating_tasks_rmdone(mut done_remove: Vec<String>) -> bool {
-    // housekeeping
-    repeating_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn repeating_tasks_rmdone(done_remove: Vec<String>) -> Result<(), ChartodoError> {
     // open file and parse
-    let mut repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
-
-    // check if the done list is empty
-    if repeating_tasks.done.is_empty() {
-        writeln!(writer, "ERROR: The repeating done list is currently empty.")
-            .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // for the record, i hate that this is a separate iteration
-    // go thru list and check if an item is ranged. if yes, unwrap it and push to original list
-    for i in (0..done_remove.len()).rev() {
-        let (error_or_not, bound1, bound2) = check_if_range_positioning(
-            done_remove.get(i).unwrap().to_string(),
-            repeating_tasks.done.len(),
-        );
-
-        if !error_or_not {
-            let unwrapped_range = unwrap_range_positioning(bound1, bound2);
-            unwrapped_range
-                .iter()
-                .for_each(|number| done_remove.push(number.to_string()));
-            // this is not good
-        }
-    }
-
-    // filter for viable items
-    for i in (0..done_remove.len()).rev() {
-        if done_remove.get(i).unwrap().parse::<usize>().is_err()
-        || done_remove.get(i).unwrap().is_empty() // this will never trigger smh
-        || done_remove.get(i).unwrap().parse::<usize>().unwrap() == 0
-        || done_remove.get(i).unwrap().parse::<usize>().unwrap() > repeating_tasks.done.len()
-        {
-            done_remove.swap_remove(i);
-        }
-    }
-
-    // no valid args
-    if done_remove.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: None of the positions you provided were viable \
-            -- they were all either negative, zero, or exceeded the repeating done list's \
-            length."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // sort and dedup
-    let mut done_remove: Vec<usize> = done_remove
-        .iter()
-        .map(|x| x.parse::<usize>().unwrap())
-        .collect();
-    done_remove.sort();
-    done_remove.dedup();
-
-    // check if user wants to remove all of the items
-    if done_remove.len() >= repeating_tasks.done.len() && repeating_tasks.done.len() > 5 {
-        writeln!(
-            writer,
-            "WARNING: You want to remove all of the finished tasks in a \
-            relatively long repeating done list. You should do repeating-cleardone."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // remove each item one by one
-    done_remove.iter().rev().for_each(|position| {
-        repeating_tasks.done.remove(position - 1);
-    });
+    let mut store = open_task_store()?;
+
+    // unwrap any ranges and check the positions
+    let done_remove = store.positions_from_args(
+        PositionsIn::RepeatingDone,
+        &done_remove,
+        &PositionErrors {
+            empty: "The repeating done list is currently empty.",
+            none_viable: "None of the positions you provided were viable \
+                -- they were all either negative, zero, or exceeded the repeating done list's \
+                length.",
+            whole_list: Some(
+                "You want to remove all of the finished tasks in a \
+                relatively long repeating done list. You should do repeating-cleardone.",
+            ),
+        },
+    )?;
+
+    // remove the items
+    store.remove(PositionsIn::RepeatingDone, &done_remove)?;
 
     // write changes to file
-    write_changes_to_new_repeating_tasks(repeating_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn repeating_tasks_not_done_all() -> bool {
-    // housekeeping
-    repeating_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn repeating_tasks_not_done_all() -> Result<(), ChartodoError> {
     // open file and parse
-    let mut repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
+    let mut store = open_task_store()?;
+    let repeating_tasks = store.repeating();
 
     // check if the done list is empty
     if repeating_tasks.done.is_empty() {
-        writeln!(writer, "ERROR: The repeating done list is currently empty.")
-            .expect("writeln failed");
-
-        // error = true
-        return true;
+        return Err(ChartodoError::EmptyList(
+            "The repeating done list is currently empty.".to_string(),
+        ));
     }
 
-    // before pushing, change each repeat_done field to false
-    repeating_tasks.done.iter_mut().for_each(|task| {
-        task.repeat_done = Some(false);
-    });
-
-    // reverse all done items
-    repeating_tasks
-        .done
-        .iter()
-        .for_each(|item| repeating_tasks.todo.push(item.clone()));
-    repeating_tasks.done.clear();
+    // reverse all done items. each one's repeat_done field is changed to false
+    store.uncomplete_all(TaskList::Repeating);
 
     // write changes to file
-    write_changes_to_new_repeating_tasks(repeating_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn repeating_tasks_clear_done() -> bool {
-    // housekeeping
-    repeating_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
+// moves done items to the archive instead of removing them. Has chaining and range positioning
+pub fn repeating_tasks_archive_done(done_to_archive: Vec<String>) -> Result<(), ChartodoError> {
+    archive_done_tasks(TaskList::Repeating, done_to_archive, "repeating-cleardone")
+}
 
+// w/ archive, the done items are moved to the archive instead of being deleted
+pub fn repeating_tasks_clear_done(archive: bool) -> Result<(), ChartodoError> {
     // open file and parse
-    let mut repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
+    let mut store = open_task_store()?;
+    let repeating_tasks = store.repeating();
 
     // check if the done list is empty
     if repeating_tasks.done.is_empty() {
-        writeln!(writer, "ERROR: The repeating done list is currently empty.")
-            .expect("writeln failed");
-
-        // error = true
-        return true;
+        return Err(ChartodoError::EmptyList(
+            "The repeating done list is currently empty.".to_string(),
+        ));
     }
 
     // clear done list
-    repeating_tasks.done.clear();
+    match archive {
+        true => store.archive_all(TaskList::Repeating),
+        false => store.clear(PositionsIn::RepeatingDone),
+    }
 
     // write changes to file
-    write_changes_to_new_repeating_tasks(repeating_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-// cargo test repeating_done_unit_tests -- --test-threads=1
+// cargo test repeating_done_unit_tests
 #[cfg(test)]
 mod repeating_done_unit_tests {
+    use super::super::repeating_helpers::*;
     use super::*;
     use crate::functions::json_file_structs::*;
+    use crate::functions::storage_helpers::test_data_dir;
     use anyhow::Context;
-    use std::path::PathBuf;
-
-    // these are taken from repeating_helpers
-    fn path_to_repeating_tasks() -> PathBuf {
-        // get the data dir XDG spec and return it with path to repeating_tasks.json
-        let mut repeating_tasks_path = dirs::data_dir()
-            .context(
-                "linux: couldn't get $HOME/.local/share/
-                    windows: couldn't get C:/Users/your_user/AppData/Local/
-                    mac: couldn't get /Users/your_user/Library/Application Support/
-
-                    those directories should exist for your OS. please double check that they do.",
-            )
-            .expect("something went wrong with fetching the user's data dirs");
-        repeating_tasks_path.push("chartodo/repeating_tasks.json");
-
-        repeating_tasks_path
-    }
-
-    fn repeating_tasks_copy_path() -> PathBuf {
-        // get the path for repeating_tasks_copy.json, which will be used to hold the original contents
-        // of repeating_tasks.json while it's getting modified
-        let mut repeating_tasks_copy_path = dirs::data_dir()
-            .context(
-                "linux: couldn't get $HOME/.local/share/
-                    windows: couldn't get C:/Users/your_user/AppData/Local/
-                    mac: couldn't get /Users/your_user/Library/Application Support/
-
-                    those directories should exist for your OS. please double check that they do.",
-            )
-            .expect("something went wrong with fetching the user's data dirs");
-        repeating_tasks_copy_path.push("chartodo/repeating_tasks_copy.json");
-
-        repeating_tasks_copy_path
-    }
-
-    // these have been tested in other fns, these are just included here as a sanity check
-    #[test]
-    fn repeating_tasks_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/repeating_tasks.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/repeating_tasks.json";
-        let mac_path = "/Library/Application Support/chartodo/repeating_tasks.json";
-        let mut got_repeating_tasks_path: bool = false;
-        let repeating_path = path_to_repeating_tasks();
-        let repeating_path = repeating_path.to_str().unwrap();
-
-        if repeating_path.contains(linux_path)
-            | repeating_path.contains(windows_path)
-            | repeating_path.contains(mac_path)
-        {
-            got_repeating_tasks_path = true;
-        }
-
-        assert!(got_repeating_tasks_path);
-    }
-
-    #[test]
-    fn repeating_tasks_copy_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/repeating_tasks_copy.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/repeating_tasks_copy.json";
-        let mac_path = "/Library/Application Support/chartodo/repeating_tasks_copy.json";
-        let mut got_repeating_tasks_copy_path: bool = false;
-        let repeating_tasks_copy_path = repeating_tasks_copy_path();
-        let repeating_tasks_copy_path = repeating_tasks_copy_path.to_str().unwrap();
-
-        if repeating_tasks_copy_path.contains(linux_path)
-            | repeating_tasks_copy_path.contains(windows_path)
-            | repeating_tasks_copy_path.contains(mac_path)
-        {
-            got_repeating_tasks_copy_path = true;
-        }
-
-        assert!(got_repeating_tasks_copy_path);
-    }
-
-    #[test]
-    fn aaaa_repeating_tasks_clone_file() {
-        // name is aaaa so it's done first
-        // since we will be modifying the original file to run a test, the original data must be
-        // preserved first
-        std::fs::File::create(repeating_tasks_copy_path())
-            .context("failed to create repeating_tasks_copy.json")
-            .expect("failed to create a copy during unit test");
-
-        std::fs::copy(path_to_repeating_tasks(), repeating_tasks_copy_path())
-            .context("failed to copy repeating_tasks.json to repeating_tasks_copy.json")
-            .expect("failed to copy original file to copy file during unit test");
-    }
 
     #[test]
     fn repeating_tasks_notdone_done_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -377,13 +143,15 @@ mod repeating_done_unit_tests {
 
         // perform actions
         let arguments = vec![String::from("don't matter")];
-        let error_should_be_true = repeating_tasks_not_done(arguments);
+        let error_should_be_true = repeating_tasks_not_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_notdone_no_valid_args() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -393,11 +161,11 @@ mod repeating_done_unit_tests {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ]
             }
@@ -412,13 +180,15 @@ mod repeating_done_unit_tests {
 
         // check that there are no valid args
         let arguments = vec![String::from("-11"), String::from("0"), String::from("2")];
-        let error_should_be_true = repeating_tasks_not_done(arguments);
+        let error_should_be_true = repeating_tasks_not_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_notdone_should_do_notdoneall() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -428,61 +198,61 @@ mod repeating_done_unit_tests {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ]
             }
@@ -505,13 +275,15 @@ mod repeating_done_unit_tests {
             String::from("6"),
             String::from("1"),
         ];
-        let error_should_be_true = repeating_tasks_not_done(arguments);
+        let error_should_be_true = repeating_tasks_not_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_notdone_should_do_notdoneall_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -521,61 +293,61 @@ mod repeating_done_unit_tests {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ]
             }
@@ -590,13 +362,15 @@ mod repeating_done_unit_tests {
 
         // check that there are no valid args
         let arguments = vec![String::from("1-6"), String::from("1")];
-        let error_should_be_true = repeating_tasks_not_done(arguments);
+        let error_should_be_true = repeating_tasks_not_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_notdone_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -625,7 +399,7 @@ mod repeating_done_unit_tests {
 
         // no valid args
         let arguments = vec![String::from("1"), String::from("2")];
-        let error_should_be_false = repeating_tasks_not_done(arguments);
+        let error_should_be_false = repeating_tasks_not_done(arguments).is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -659,6 +433,8 @@ mod repeating_done_unit_tests {
 
     #[test]
     fn repeating_tasks_notdone_multiple_args_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -723,7 +499,7 @@ mod repeating_done_unit_tests {
             String::from("4"),
             String::from("1"),
         ];
-        let error_should_be_false = repeating_tasks_not_done(arguments);
+        let error_should_be_false = repeating_tasks_not_done(arguments).is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -788,6 +564,8 @@ mod repeating_done_unit_tests {
 
     #[test]
     fn repeating_tasks_notdone_multiple_args_is_correct_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -847,7 +625,7 @@ mod repeating_done_unit_tests {
 
         // no valid args
         let arguments = vec![String::from("1-2"), String::from("4"), String::from("1")];
-        let error_should_be_false = repeating_tasks_not_done(arguments);
+        let error_should_be_false = repeating_tasks_not_done(arguments).is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -912,6 +690,8 @@ mod repeating_done_unit_tests {
 
     #[test]
     fn repeating_tasks_rmdone_done_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -929,13 +709,15 @@ mod repeating_done_unit_tests {
 
         // perform actions
         let arguments = vec![String::from("don't matter")];
-        let error_should_be_true = repeating_tasks_rmdone(arguments);
+        let error_should_be_true = repeating_tasks_rmdone(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_rmdone_no_valid_args() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -945,11 +727,11 @@ mod repeating_done_unit_tests {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ]
             }
@@ -964,13 +746,15 @@ mod repeating_done_unit_tests {
 
         // check that there are no valid args
         let arguments = vec![String::from("-11"), String::from("0"), String::from("2")];
-        let error_should_be_true = repeating_tasks_rmdone(arguments);
+        let error_should_be_true = repeating_tasks_rmdone(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_rmdone_should_do_cleardone() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -980,61 +764,61 @@ mod repeating_done_unit_tests {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ]
             }
@@ -1057,13 +841,15 @@ mod repeating_done_unit_tests {
             String::from("6"),
             String::from("1"),
         ];
-        let error_should_be_true = repeating_tasks_rmdone(arguments);
+        let error_should_be_true = repeating_tasks_rmdone(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_rmdone_should_do_cleardone_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1073,61 +859,61 @@ mod repeating_done_unit_tests {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "hi",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ]
             }
@@ -1142,13 +928,15 @@ mod repeating_done_unit_tests {
 
         // check that there are no valid args
         let arguments = vec![String::from("1-6"), String::from("1")];
-        let error_should_be_true = repeating_tasks_rmdone(arguments);
+        let error_should_be_true = repeating_tasks_rmdone(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_rmdone_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1177,7 +965,7 @@ mod repeating_done_unit_tests {
 
         // no valid args
         let arguments = vec![String::from("1"), String::from("2")];
-        let error_should_be_false = repeating_tasks_rmdone(arguments);
+        let error_should_be_false = repeating_tasks_rmdone(arguments).is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -1200,6 +988,8 @@ mod repeating_done_unit_tests {
 
     #[test]
     fn repeating_tasks_rmdone_multiple_args_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1264,7 +1054,7 @@ mod repeating_done_unit_tests {
             String::from("4"),
             String::from("1"),
         ];
-        let error_should_be_false = repeating_tasks_rmdone(arguments);
+        let error_should_be_false = repeating_tasks_rmdone(arguments).is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -1309,6 +1099,8 @@ mod repeating_done_unit_tests {
 
     #[test]
     fn repeating_tasks_rmdone_multiple_args_is_correct_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1373,7 +1165,7 @@ mod repeating_done_unit_tests {
             String::from("4"),
             String::from("1"),
         ];
-        let error_should_be_false = repeating_tasks_rmdone(arguments);
+        let error_should_be_false = repeating_tasks_rmdone(arguments).is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -1418,6 +1210,8 @@ mod repeating_done_unit_tests {
 
     #[test]
     fn repeating_tasks_notdoneall_done_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1434,13 +1228,15 @@ mod repeating_done_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let error_should_be_true = repeating_tasks_not_done_all();
+        let error_should_be_true = repeating_tasks_not_done_all().is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_notdoneall_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1499,7 +1295,7 @@ mod repeating_done_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // no valid args
-        let error_should_be_false = repeating_tasks_not_done_all();
+        let error_should_be_false = repeating_tasks_not_done_all().is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -1563,6 +1359,8 @@ mod repeating_done_unit_tests {
 
     #[test]
     fn repeating_tasks_cleardone_done_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1579,13 +1377,15 @@ mod repeating_done_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let error_should_be_true = repeating_tasks_clear_done();
+        let error_should_be_true = repeating_tasks_clear_done(false).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_cleardone_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1644,7 +1444,7 @@ mod repeating_done_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // no valid args
-        let error_should_be_false = repeating_tasks_clear_done();
+        let error_should_be_false = repeating_tasks_clear_done(false).is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -1675,20 +1475,4 @@ mod repeating_done_unit_tests {
         assert!(!error_should_be_false);
         assert_eq!(read_test_file, repeating_tasks);
     }
-
-    #[test]
-    fn zzzz_rename_copy_to_original() {
-        // name is zzzz so it's done last
-        // now that tests are done, remove the modified original and rename copy to original
-
-        std::fs::remove_file(path_to_repeating_tasks())
-            .context("failed delete modified repeating_tasks.json after running tests")
-            .expect("failed to delete repeating_tasks.json after repeating_helpers unit tests");
-
-        std::fs::rename(repeating_tasks_copy_path(), path_to_repeating_tasks())
-            .context("failed to rename repeating_tasks_copy to repeating_tasks")
-            .expect(
-                "failed to rename repeating_tasks_copy to repeating_tasks after tests were done",
-            );
-    }
 }
@@ -1,203 +1,84 @@
-use super::repeating_helpers::*;
-use crate::functions::general_helpers::{check_if_range_positioning, unwrap_range_positioning};
+use crate::functions::date_parsing::{parse_date, parse_time};
+use crate::functions::errors::{
+    check_end_fits, check_rule_fits, interval_out_of_range, ChartodoError,
+};
+use crate::functions::general_helpers::{edit_priority_of_todo, tag_todo, untag_todo};
 use crate::functions::json_file_structs::*;
-use chrono::{Days, Duration, Local, Months, NaiveDate, NaiveDateTime, NaiveTime};
-use std::io::Write;
-
-// chartodo rp-a rp_task_1 3 days rp_task_2 4 days => len % 3
-pub fn repeating_tasks_add(add: Vec<String>) -> bool {
-    // housekeeping
-    repeating_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-    // open file and parse
-    let mut repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
-
-    // check if we have the right # of args
-    // note/potential todo: i'd like to remove division here but idk what else to do lol
-    if add.len() % 3 != 0 {
-        writeln!(
-            writer,
-            "ERROR: You don't have the right amount of arguments \
+use crate::functions::recurrence::{CatchUp, RepeatOn, RepeatRules};
+use crate::functions::storage_helpers::{open_task_store, save_task_store};
+use crate::functions::task_ids::PositionsIn;
+use crate::functions::task_store::{PositionErrors, TaskList};
+use chrono::{Local, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+
+// same as the deadline add commands, main parses the words clap hands over into sets w/ the fns below, see
+// deadline_sets
+
+// task, interval, unit, date, time. the date and time are when it starts or ends, depending on the command
+pub type RepeatingDatetimeSet = (String, u32, RepeatUnit, NaiveDate, NaiveTime);
+
+fn parse_unit(unit: &str, set: usize) -> Result<RepeatUnit, ChartodoError> {
+    unit.parse::<RepeatUnit>().map_err(|_| {
+        ChartodoError::InvalidTimeUnit(format!(
+            "Your provided time unit, '{}', in \
+            argument set '{}', wasn't proper. It has to be one of the following: minutes, \
+            hours, days, weeks, months, years.",
+            unit, set
+        ))
+    })
+}
+
+// chartodo rp-a rp_task_1 3 days rp_task_2 4 days > task, interval, unit
+pub fn repeating_sets(args: &[String]) -> Result<Vec<(String, u32, RepeatUnit)>, ChartodoError> {
+    let (sets, rest) = args.as_chunks::<3>();
+    if !rest.is_empty() {
+        return Err(ChartodoError::WrongNumberOfArguments(format!(
+            "You don't have the right amount of arguments \
             when adding a repeating task.\n\tThere should be 3, 6, 9, etc. (i.e., \
             divisible by 3) arguments after 'chartodo repeating-add'. You provided {} \
             argument(s).\n\tFormat: chartodo repeating-add ~task ~interval ~time-unit \
             [...].\n\t\tOnly the following time-units are allowed: minute(s), hour(s), \
             day(s), week(s), month(s), and year(s).\n\tExample: chartodo rp-a do-a-backflip \
             2 days.\n\tAnother example: chartodo rp-a new-item 3 days another-item 4 years",
-            add.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    let mut counter: usize = 1;
-    // loop thru the args and parse for correctness
-    while counter <= add.len() / 3 {
-        // note for interviews: my first instinct was to keep accessing last elem and delete as i go
-
-        // unit: get counter * 3 - 1
-        // interval: get counter * 3 - 2
-        // task: get counter * 3 - 3
-
-        // check if the unit is proper
-        match add.get(counter * 3 - 1).unwrap().as_str() {
-            "minutes" | "minute" | "hours" | "hour" | "days" | "day" | "weeks" | "week"
-            | "months" | "month" | "years" | "year" => (),
-            _ => {
-                writeln!(
-                    writer,
-                    "ERROR: Your provided time unit, '{}', in \
-                    argument set '{}', wasn't proper. It has to be one of the following: minutes, \
-                    hours, days, weeks, months, years.",
-                    add.get(counter * 3 - 1).unwrap(),
-                    counter
-                )
-                .expect("writeln failed");
-
-                // error = true
-                return true;
-            }
-        }
-
-        // does this u32 thing even make sense? it's just a micro-optimization, not making it usize, so it makes the program slightly faster
-        // ah well. usize has a max too. u32 is fine and big enough (pause)
-        // check if the interval is proper. has to be u32
-        if add.get(counter * 3 - 2).unwrap().parse::<u32>().is_err() {
-            writeln!(
-                writer,
-                "ERROR: Your provided interval, '{}', in argument \
-                set '{}', wasn't proper. It can't be negative and can't be above 4294967295 \
-                (i.e., it has to be u32). Proper example: chartodo rp-a gym 2 days.",
-                add.get(counter * 3 - 2).unwrap(),
-                counter
-            )
-            .expect("writeln failed");
-
-            // error = true
-            return true;
-        }
-
-        // check if interval is 0
-        if add.get(counter * 3 - 2).unwrap().parse::<u32>().unwrap() == 0 {
-            writeln!(
-                writer,
-                "ERROR: You had an interval of 0 in argument set \
-                '{}'. You can't have an interval of 0, otherwise why are you even making a new \
-                repeating task?",
-                counter
-            )
-            .expect("writeln failed");
-
-            // error = true
-            return true;
-        }
-
-        // create new Task struct
-        let mut repeating_task = Task {
-            task: "".to_string(),
-            date: None,
-            time: None,
-            repeat_number: None,
-            repeat_unit: None,
-            repeat_done: Some(false),
-            repeat_original_date: None,
-            repeat_original_time: None,
-        };
-
-        // add task to struct
-        repeating_task.task = add.get(counter * 3 - 3).unwrap().to_string();
-
-        // set interval and unit
-        repeating_task.repeat_number =
-            Some(add.get(counter * 3 - 2).unwrap().parse::<u32>().unwrap());
-        repeating_task.repeat_unit = Some(add.get(counter * 3 - 1).unwrap().to_owned());
-
-        // get the date, time, repeat_original_date, and repeat_original_time
-        let (date, time, repeat_original_date, repeat_original_time) = add_to_local_now(
-            add.get(counter * 3 - 2).unwrap().parse::<u32>().unwrap(),
-            add.get(counter * 3 - 1).unwrap().to_owned(),
-        );
-
-        // set the remaining fields
-        repeating_task.date = Some(date);
-        repeating_task.time = Some(time);
-        repeating_task.repeat_original_date = Some(repeat_original_date);
-        repeating_task.repeat_original_time = Some(repeat_original_time);
-
-        // push new correct Task
-        repeating_tasks.todo.push(repeating_task);
-
-        counter += 1;
-    }
-
-    // write changes to file
-    write_changes_to_new_repeating_tasks(repeating_tasks);
-
-    // error = false
-    false
+            args.len()
+        )));
+    }
+
+    sets.iter()
+        .zip(1..)
+        .map(|([task, interval, unit], set)| {
+            let unit = parse_unit(unit, set)?;
+            // u32 is big enough for any interval anyone would want
+            let interval = match interval.parse::<u32>() {
+                Ok(0) => {
+                    return Err(ChartodoError::InvalidInterval(format!(
+                        "You had an interval of 0 in argument set \
+                        '{}'. You can't have an interval of 0, otherwise why are you even making a new \
+                        repeating task?",
+                        set
+                    )));
+                }
+                Ok(interval) => interval,
+                Err(_) => {
+                    return Err(ChartodoError::InvalidInterval(format!(
+                        "Your provided interval, '{}', in argument \
+                        set '{}', wasn't proper. It can't be negative and can't be above 4294967295 \
+                        (i.e., it has to be u32). Proper example: chartodo rp-a gym 2 days.",
+                        interval, set
+                    )));
+                }
+            };
+
+            Ok((task.clone(), interval, unit))
+        })
+        .collect()
 }
 
-fn add_to_local_now(interval: u32, unit: String) -> (String, String, String, String) {
-    // get two local nows: one for adding, the other to keep track of when task was set
-    let mut add_to_now = Local::now();
-    let return_original_now = add_to_now;
-
-    match unit.as_str() {
-        "minutes" | "minute" => add_to_now += Duration::minutes(interval.into()),
-        "hours" | "hour" => add_to_now += Duration::hours(interval.into()),
-        "days" | "day" => {
-            add_to_now = add_to_now
-                .checked_add_days(Days::new(interval.into()))
-                .unwrap()
-        }
-        "weeks" | "week" => {
-            let interval: u64 = interval.into();
-            add_to_now = add_to_now
-                .checked_add_days(Days::new(interval * 7))
-                .unwrap()
-        }
-        "months" | "month" => {
-            add_to_now = add_to_now
-                .checked_add_months(Months::new(interval))
-                .unwrap()
-        }
-        "years" | "year" => {
-            add_to_now = add_to_now
-                .checked_add_months(Months::new(interval * 12))
-                .unwrap()
-        }
-        _ => (),
-    }
-
-    let date = format!("{}", add_to_now.format("%Y-%m-%d"));
-    let time = format!("{}", add_to_now.format("%H:%M"));
-    let repeat_original_date = format!("{}", return_original_now.format("%Y-%m-%d"));
-    let repeat_original_time = format!("{}", return_original_now.format("%H:%M"));
-
-    (date, time, repeat_original_date, repeat_original_time)
-}
-
-// note to self: i have to give users the ability to edit the due date and time of repeating tasks
-// reasoning: idk i forgot. if i can't come up with/remember the reason for thinking this, this is off the table
-// reason: users might want to keep the interval + unit the same but edit the starting/ending datetime of the task
-
-pub fn repeating_tasks_add_start_datetime(start: Vec<String>) -> bool {
-    // housekeeping
-    repeating_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
-    // open file and parse
-    let mut repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
-
-    // chartodo repeating-addstart task 3 days 2022-01-01 00:00 task2 3 days 2023-01-01 00:00 => len % 5
-
-    // check if we have the right # of args
-    if start.len() % 5 != 0 {
-        writeln!(
-            writer,
-            "ERROR: You don't have the right amount of arguments \
+// chartodo rp-as task 3 days 2099-01-01 00:00 > task, interval, unit, starting date, starting time
+pub fn repeating_start_sets(args: &[String]) -> Result<Vec<RepeatingDatetimeSet>, ChartodoError> {
+    let (sets, rest) = args.as_chunks::<5>();
+    if !rest.is_empty() {
+        return Err(ChartodoError::WrongNumberOfArguments(format!(
+            "You don't have the right amount of arguments \
             when adding a repeating task with a specific starting datetime.\n\tThere \
             should be 5, 10, 15, etc. (i.e., divisible by 5) arguments after 'chartodo \
             repeating-addstart'. You provided {} argument(s).\n\tFormat: chartodo \
@@ -207,237 +88,62 @@ pub fn repeating_tasks_add_start_datetime(start: Vec<String>) -> bool {
             month(s), and year(s).\n\tExample: chartodo rp-as new-item 3 days 2099-01-01 \
             00:00.\n\tAnother example: chartodo rp-as new-item 3 days 2099-01-01 00:00 \
             another-item 4 years 23:59",
-            start.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
+            args.len()
+        )));
     }
 
-    let mut counter: usize = 1;
-    // loop thru the args and parse for correctness
-    while counter <= start.len() / 5 {
-        // chartodo repeating-addstart task 3 days 2022-01-01 00:00 task2 3 days 2023-01-01 00:00 => len % 5
-
-        // start_time: get counter * 5 - 1
-        // start_date: get counter * 5 - 2
-        // unit: get counter * 5 - 3
-        // interval: get counter * 5 - 4
-        // task: get counter * 5 - 5
-
-        // check if the starting time is proper
-        if NaiveTime::parse_from_str(start.get(counter * 5 - 1).unwrap().as_str(), "%H:%M").is_err()
-        {
-            writeln!(
-                writer,
-                "ERROR: Your provided starting time, '{}', in \
-                argument set '{}', wasn't proper. Please provide a correct starting time \
+    sets.iter()
+        .zip(1..)
+        .map(|([task, interval, unit, date, time], set)| {
+            let time = parse_time(time).ok_or_else(|| {
+                ChartodoError::InvalidTime(format!(
+                    "Your provided starting time, '{}', in \
+                    argument set '{}', wasn't proper. Please provide a correct starting time \
                 in a 24-hour format, e.g., 23:04.",
-                start.get(counter * 5 - 1).unwrap(),
-                counter
-            )
-            .expect("writeln failed");
-
-            // error = true
-            return true;
-        }
-
-        // check if starting date is proper
-        if NaiveDate::parse_from_str(start.get(counter * 5 - 2).unwrap().as_str(), "%Y-%m-%d")
-            .is_err()
-        {
-            writeln!(
-                writer,
-                "ERROR: Your provided starting date, '{}', in \
-                argument set '{}', wasn't proper. Please provide a correct starting date in a \
+                    time, set
+                ))
+            })?;
+            let date = parse_date(date).ok_or_else(|| {
+                ChartodoError::InvalidDate(format!(
+                    "Your provided starting date, '{}', in \
+                    argument set '{}', wasn't proper. Please provide a correct starting date in a \
                 year-month-day format, e.g., 2024-05-13.",
-                start.get(counter * 5 - 2).unwrap(),
-                counter
-            )
-            .expect("writeln failed");
-
-            // error = true
-            return true;
-        }
-
-        // check if unit time is proper
-        match start.get(counter * 5 - 3).unwrap().as_str() {
-            "minutes" | "minute" | "hours" | "hour" | "days" | "day" | "weeks" | "week"
-            | "months" | "month" | "years" | "year" => (),
-            _ => {
-                writeln!(
-                    writer,
-                    "ERROR: Your provided time unit, '{}', in \
-                    argument set '{}', wasn't proper. It has to be one of the following: minutes, \
-                    hours, days, weeks, months, years.",
-                    start.get(counter * 5 - 3).unwrap(),
-                    counter
-                )
-                .expect("writeln failed");
-
-                // error = true
-                return true;
-            }
-        }
-
-        // check if the interval is proper
-        if start.get(counter * 5 - 4).unwrap().parse::<u32>().is_err() {
-            writeln!(
-                writer,
-                "ERROR: Your provided interval, '{}', in argument \
-                set '{}', wasn't proper. It can't be negative and can't be above 4294967295 \
-                (i.e., it has to be u32). Proper example: chartodo rp-a gym 2 days 2020-01-01 \
-                00:00",
-                start.get(counter * 5 - 4).unwrap(),
-                counter
-            )
-            .expect("writeln failed");
-
-            // error = true
-            return true;
-        }
-
-        // check if interval is 0
-        if start.get(counter * 5 - 4).unwrap().parse::<u32>().unwrap() == 0 {
-            writeln!(
-                writer,
-                "ERROR: You provided an interval of 0 in argument \
-                set '{}'. You can't have an interval of 0, otherwise why are you even making a \
-                new repeating task?",
-                counter
-            )
-            .expect("writeln failed");
-
-            // error = true
-            return true;
-        }
-
-        // create new Task struct
-        let mut repeating_task_start = Task {
-            task: "".to_string(),
-            date: None,
-            time: None,
-            repeat_number: None,
-            repeat_unit: None,
-            repeat_done: Some(false),
-            repeat_original_date: None,
-            repeat_original_time: None,
-        };
-
-        // add task to struct
-        repeating_task_start.task = start.get(counter * 5 - 5).unwrap().to_string();
-
-        // set interval and unit
-        repeating_task_start.repeat_number =
-            Some(start.get(counter * 5 - 4).unwrap().parse::<u32>().unwrap());
-        repeating_task_start.repeat_unit = Some(start.get(counter * 5 - 3).unwrap().to_owned());
-
-        // get the date, time, repeat_original_date, and repeat_original_time
-        let (date, time, repeat_original_date, repeat_original_time) =
-            add_to_given_starting_datetime(
-                start.get(counter * 5 - 2).unwrap().to_string(),
-                start.get(counter * 5 - 1).unwrap().to_string(),
-                start.get(counter * 5 - 4).unwrap().parse::<u32>().unwrap(),
-                start.get(counter * 5 - 3).unwrap().to_owned(),
-            );
-
-        // set the remaining fields
-        repeating_task_start.date = Some(date);
-        repeating_task_start.time = Some(time);
-        repeating_task_start.repeat_original_date = Some(repeat_original_date);
-        repeating_task_start.repeat_original_time = Some(repeat_original_time);
-
-        // push new correct Task
-        repeating_tasks.todo.push(repeating_task_start);
-
-        counter += 1;
-    }
-
-    // write changes to file
-    write_changes_to_new_repeating_tasks(repeating_tasks);
-
-    // error = false
-    false
+                    date, set
+                ))
+            })?;
+            let unit = parse_unit(unit, set)?;
+            let interval = match interval.parse::<u32>() {
+                Ok(0) => {
+                    return Err(ChartodoError::InvalidInterval(format!(
+                        "You provided an interval of 0 in argument \
+                        set '{}'. You can't have an interval of 0, otherwise why are you even making a \
+                        new repeating task?",
+                        set
+                    )));
+                }
+                Ok(interval) => interval,
+                Err(_) => {
+                    return Err(ChartodoError::InvalidInterval(format!(
+                        "Your provided interval, '{}', in argument \
+                        set '{}', wasn't proper. It can't be negative and can't be above 4294967295 \
+                        (i.e., it has to be u32). Proper example: chartodo rp-a gym 2 days 2020-01-01 \
+                        00:00",
+                        interval, set
+                    )));
+                }
+            };
+
+            Ok((task.clone(), interval, unit, date.date(), time))
+        })
+        .collect()
 }
 
-fn add_to_given_starting_datetime(
-    start_date: String,
-    start_time: String,
-    interval: u32,
-    unit: String,
-) -> (String, String, String, String) {
-    // get a starting datetime to add to it
-    // note: i wish i didn't have to do naivedate + naivetime -> str -> naivedatetime
-    // note: this separate formatting + combining into string is necessary for correct parsing
-    // let start_date = format!("{}", start_date.format("%Y-%m-%d"));
-    // let start_time = format!("{}", start_time.format("%H:%M"));
-    let starting_datetime = start_date + " " + &start_time;
-    let starting_datetime = NaiveDateTime::parse_from_str(
-        starting_datetime.as_str(),
-        "%Y-%m-%d
-        %H:%M",
-    )
-    .expect(
-        "You should never be able to see this error. Somehow, when \
-        parsing a datetime from str in fn add_to_given_starting_datetime in path \
-        src/functions/repeating_tasks/repeating_todo.rs, the parsing failed. Should \
-        never happen since there were several checks that happened up to this point. If \
-        you see this, please open an issue on github.",
-    );
-    let mut add_to_start = starting_datetime;
-
-    match unit.as_str() {
-        "minutes" | "minute" => add_to_start += Duration::minutes(interval.into()),
-        "hours" | "hour" => add_to_start += Duration::hours(interval.into()),
-        "days" | "day" => {
-            add_to_start = add_to_start
-                .checked_add_days(Days::new(interval.into()))
-                .unwrap()
-        }
-        "weeks" | "week" => {
-            let interval: u64 = interval.into();
-            add_to_start = add_to_start
-                .checked_add_days(Days::new(interval * 7))
-                .unwrap()
-        }
-        "months" | "month" => {
-            add_to_start = add_to_start
-                .checked_add_months(Months::new(interval))
-                .unwrap()
-        }
-        "years" | "year" => {
-            add_to_start = add_to_start
-                .checked_add_months(Months::new(interval * 12))
-                .unwrap()
-        }
-        // this arm should never activate
-        _ => (),
-    }
-
-    let date = format!("{}", add_to_start.format("%Y-%m-%d"));
-    let time = format!("{}", add_to_start.format("%H:%M"));
-    let repeat_original_date = format!("{}", starting_datetime.format("%Y-%m-%d"));
-    let repeat_original_time = format!("{}", starting_datetime.format("%H:%M"));
-
-    (date, time, repeat_original_date, repeat_original_time)
-}
-
-pub fn repeating_tasks_add_end(add_end: Vec<String>) -> bool {
-    // housekeeping
-    repeating_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
-    // open file and parse
-    let mut repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
-
-    // chartodo repeating-addend task 3 days 2030-01-01 00:00 task2 4 months 2031-01-01 00:00 => len % 5
-
-    // check if we have the right # of args
-    if add_end.len() % 5 != 0 {
-        writeln!(
-            writer,
-            "ERROR: You don't have the right amount of arguments \
+// chartodo rp-ae task 3 days 2099-01-01 00:00 > task, interval, unit, ending date, ending time
+pub fn repeating_end_sets(args: &[String]) -> Result<Vec<RepeatingDatetimeSet>, ChartodoError> {
+    let (sets, rest) = args.as_chunks::<5>();
+    if !rest.is_empty() {
+        return Err(ChartodoError::WrongNumberOfArguments(format!(
+            "You don't have the right amount of arguments \
             when adding a repeating task with a specific ending datetime.\n\tThere \
             should be 5, 10, 15, etc. (i.e., divisible by 5) arguments after 'chartodo \
             repeating-addend'. You provided {} argument(s).\n\tFormat: chartodo \
@@ -447,1956 +153,876 @@ pub fn repeating_tasks_add_end(add_end: Vec<String>) -> bool {
             year(s).\n\tExample: chartodo rp-ae new-item 3 days 2099-01-01 00:00.\n\tAnother \
             example: chartodo rp-ae new-item 3 days 2099-01-01 00:00 another-item 4 years \
             23:59",
-            add_end.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
+            args.len()
+        )));
     }
 
-    // check how many sets of arguments there are
-    let mut counter: usize = 1;
-    // loop thru the args and parse for correctness
-    while counter <= add_end.len() / 5 {
-        // chartodo repeating-addend task 3 days 2030-01-01 00:00 task2 4 months 2031-01-01 00:00 => len % 5
-
-        // end_time: get counter * 5 - 1
-        // end_date: get counter * 5 - 2
-        // unit: get counter * 5 - 3
-        // interval: get counter * 5 - 4
-        // task: get counter * 5 - 5
-
-        // check if the starting time is proper
-        if NaiveTime::parse_from_str(add_end.get(counter * 5 - 1).unwrap().as_str(), "%H:%M")
-            .is_err()
-        {
-            writeln!(
-                writer,
-                "ERROR: Your provided ending time, '{}', in \
-                argument set '{}', wasn't proper. Please provide a correct ending time in \
+    sets.iter()
+        .zip(1..)
+        .map(|([task, interval, unit, date, time], set)| {
+            let time = parse_time(time).ok_or_else(|| {
+                ChartodoError::InvalidTime(format!(
+                    "Your provided ending time, '{}', in \
+                        argument set '{}', wasn't proper. Please provide a correct ending time in \
                 a 24-hour format, e.g., 23:04.",
-                add_end.get(counter * 5 - 1).unwrap(),
-                counter
-            )
-            .expect("writeln failed");
-
-            // error = true
-            return true;
-        }
-
-        // check if starting date is proper
-        if NaiveDate::parse_from_str(add_end.get(counter * 5 - 2).unwrap().as_str(), "%Y-%m-%d")
-            .is_err()
-        {
-            writeln!(
-                writer,
-                "ERROR: Your provided ending date, '{}', in \
-                argument set '{}', wasn't proper. Please provide a correct ending date \
+                    time, set
+                ))
+            })?;
+            let date = parse_date(date).ok_or_else(|| {
+                ChartodoError::InvalidDate(format!(
+                    "Your provided ending date, '{}', in \
+                        argument set '{}', wasn't proper. Please provide a correct ending date \
                 in a year-month-day format, e.g., 2024-05-12.",
-                add_end.get(counter * 5 - 2).unwrap(),
-                counter
-            )
-            .expect("writeln failed");
-
-            // error = true
-            return true;
-        }
-
-        // check if unit time is proper
-        match add_end.get(counter * 5 - 3).unwrap().as_str() {
-            "minutes" | "minute" | "hours" | "hour" | "days" | "day" | "weeks" | "week"
-            | "months" | "month" | "years" | "year" => (),
-            _ => {
-                writeln!(
-                    writer,
-                    "ERROR: Your provided time unit, '{}', in \
-                    argument set '{}', wasn't proper. It has to be one of the following: minutes, \
-                    hours, days, weeks, months, years.",
-                    add_end.get(counter * 5 - 3).unwrap(),
-                    counter
-                )
-                .expect("writeln failed");
-
-                // error = true
-                return true;
-            }
-        }
-
-        // check if the interval is proper
-        if add_end
-            .get(counter * 5 - 4)
-            .unwrap()
-            .parse::<u32>()
-            .is_err()
-        {
-            writeln!(
-                writer,
-                "ERROR: Your provided interval, '{}', in argument \
-                set '{}', wasn't proper. It can't be negative and can't be above 4294967295 \
-                (i.e., it has to be u32). Proper example: chartodo rp-ae gym 2 days 2000-01-01 \
-                00:00",
-                add_end.get(counter * 5 - 4).unwrap(),
-                counter
-            )
-            .expect(
-                "writeln
-                failed",
-            );
-
-            // error = true
-            return true;
-        }
-
-        // check if interval is 0
-        if add_end
-            .get(counter * 5 - 4)
-            .unwrap()
-            .parse::<u32>()
-            .unwrap()
-            == 0
-        {
-            writeln!(
-                writer,
-                "ERROR: You provided an interval of 0 in argument \
-                set {}. You can't have an interval of 0, otherwise why are you even making a new \
-                repeating task?",
-                counter
-            )
-            .expect("writeln failed");
-
-            // error = true
-            return true;
-        }
+                    date, set
+                ))
+            })?;
+            let unit = parse_unit(unit, set)?;
+            let interval = match interval.parse::<u32>() {
+                Ok(0) => {
+                    return Err(ChartodoError::InvalidInterval(format!(
+                        "You provided an interval of 0 in argument \
+                        set {}. You can't have an interval of 0, otherwise why are you even making a new \
+                        repeating task?",
+                        set
+                    )));
+                }
+                Ok(interval) => interval,
+                Err(_) => {
+                    return Err(ChartodoError::InvalidInterval(format!(
+                        "Your provided interval, '{}', in argument \
+                        set '{}', wasn't proper. It can't be negative and can't be above 4294967295 \
+                        (i.e., it has to be u32). Proper example: chartodo rp-ae gym 2 days 2000-01-01 \
+                        00:00",
+                        interval, set
+                    )));
+                }
+            };
+
+            Ok((task.clone(), interval, unit, date.date(), time))
+        })
+        .collect()
+}
 
-        // create new Task struct
-        let mut repeating_task_end = Task {
-            task: "".to_string(),
-            date: None,
-            time: None,
-            repeat_number: None,
-            repeat_unit: None,
-            repeat_done: Some(false),
-            repeat_original_date: None,
-            repeat_original_time: None,
+pub fn repeating_tasks_add(
+    add: Vec<(String, u32, RepeatUnit)>,
+    priority: Option<Priority>,
+    tags: Vec<String>,
+    rules: RepeatRules,
+) -> Result<(), ChartodoError> {
+    // open file and parse
+    let mut store = open_task_store()?;
+
+    // each one starts now
+    for (task, interval, unit) in add {
+        check_rule_fits(&task, unit, rules.on)?;
+        let Some(repeat) =
+            Repeat::starting_at_on(local_now_to_the_minute(), interval, unit, rules.on)
+        else {
+            return Err(interval_out_of_range(&task));
         };
 
-        // add task to struct
-        repeating_task_end.task = add_end.get(counter * 5 - 5).unwrap().to_string();
+        let repeat = repeat.with_rules(rules);
+        check_end_fits(&task, &repeat)?;
 
-        // set interval and unit
-        repeating_task_end.repeat_number = Some(
-            add_end
-                .get(counter * 5 - 4)
-                .unwrap()
-                .parse::<u32>()
-                .unwrap(),
+        store.add(
+            Task::repeating(task, repeat)
+                .with_priority(priority)
+                .with_tags(&tags),
         );
-        repeating_task_end.repeat_unit = Some(add_end.get(counter * 5 - 3).unwrap().to_owned());
-
-        // get the date, time, repeat_original_date, and repeat_original_time
-        let (date, time, repeat_original_date, repeat_original_time) =
-            subract_from_given_ending_datetime(
-                add_end.get(counter * 5 - 2).unwrap().to_string(),
-                add_end.get(counter * 5 - 1).unwrap().to_string(),
-                add_end
-                    .get(counter * 5 - 4)
-                    .unwrap()
-                    .parse::<u32>()
-                    .unwrap(),
-                add_end.get(counter * 5 - 3).unwrap().to_owned(),
-            );
-
-        // set the remaining fields
-        repeating_task_end.date = Some(date);
-        repeating_task_end.time = Some(time);
-        repeating_task_end.repeat_original_date = Some(repeat_original_date);
-        repeating_task_end.repeat_original_time = Some(repeat_original_time);
-
-        // push new correct Task
-        repeating_tasks.todo.push(repeating_task_end);
-
-        counter += 1;
     }
 
     // write changes to file
-    write_changes_to_new_repeating_tasks(repeating_tasks);
-
-    // error = false
-    false
+    save_task_store(&mut store)
 }
 
-fn subract_from_given_ending_datetime(
-    end_date: String,
-    end_time: String,
-    interval: u32,
-    unit: String,
-) -> (String, String, String, String) {
-    // get an ending datetime to subtract from
-    // note: i wish i didn't have to do naivedate + naivetime -> str -> naivedatetime
-    // note: this separate formatting + combining into string is necessary for correct parsing
-    // let end_date = format!("{}", end_date.format("%Y-%m-%d"));
-    // let end_time = format!("{}", end_time.format("%H:%M"));
-    let ending_datetime = end_date + " " + &end_time;
-    let ending_datetime = NaiveDateTime::parse_from_str(ending_datetime.as_str(), "%Y-%m-%d %H:%M")
-        .expect(
-            "You should never be able to see this error. Somehow, \
-            when parsing a datetime from str in fn subtract_from_given_ending_datetime in \
-            path src/functions/repeating_tasks/repeating_todo.rs, the parsing failed. Should \
-            never happen since there were several checks that happened up to this point. If \
-            you see this, please open an issue on github.",
-        );
-    let mut subtract_from_end = ending_datetime;
-
-    match unit.as_str() {
-        "minutes" | "minute" => subtract_from_end -= Duration::minutes(interval.into()),
-        "hours" | "hour" => subtract_from_end -= Duration::hours(interval.into()),
-        "days" | "day" => {
-            subtract_from_end = subtract_from_end
-                .checked_sub_days(Days::new(interval.into()))
-                .unwrap()
-        }
-        "weeks" | "week" => {
-            let interval: u64 = interval.into();
-            subtract_from_end = subtract_from_end
-                .checked_sub_days(Days::new(interval * 7))
-                .unwrap()
-        }
-        "months" | "month" => {
-            subtract_from_end = subtract_from_end
-                .checked_sub_months(Months::new(interval))
-                .unwrap()
-        }
-        "years" | "year" => {
-            subtract_from_end = subtract_from_end
-                .checked_sub_months(Months::new(interval * 12))
-                .unwrap()
-        }
-        // this arm should never activate
-        _ => (),
-    }
-
-    let date = format!("{}", ending_datetime.format("%Y-%m-%d"));
-    let time = format!("{}", ending_datetime.format("%H:%M"));
-    let repeat_original_date = format!("{}", subtract_from_end.format("%Y-%m-%d"));
-    let repeat_original_time = format!("{}", subtract_from_end.format("%H:%M"));
-
-    (date, time, repeat_original_date, repeat_original_time)
-}
-
-pub fn repeating_tasks_done(mut done: Vec<String>) -> bool {
-    // housekeeping
-    repeating_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn repeating_tasks_add_start_datetime(
+    start: Vec<RepeatingDatetimeSet>,
+    priority: Option<Priority>,
+    tags: Vec<String>,
+    rules: RepeatRules,
+) -> Result<(), ChartodoError> {
     // open file and parse
-    let mut repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
+    let mut store = open_task_store()?;
 
-    // check if todo list is empty
-    if repeating_tasks.todo.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The repeating todo list is currently empty. Try adding items to it first."
-        )
-        .expect("writeln failed");
+    for (task, interval, unit, date, time) in start {
+        check_rule_fits(&task, unit, rules.on)?;
+        let Some(repeat) = Repeat::starting_at_on(date.and_time(time), interval, unit, rules.on)
+        else {
+            return Err(interval_out_of_range(&task));
+        };
 
-        // error = true
-        return true;
-    }
+        let repeat = repeat.with_rules(rules);
+        check_end_fits(&task, &repeat)?;
 
-    // for the record, i hate that this is a separate iteration
-    // go thru list and check if an item is ranged. if yes, unwrap it and push to original list
-    for i in (0..done.len()).rev() {
-        let (error_or_not, bound1, bound2) = check_if_range_positioning(
-            done.get(i).unwrap().to_string(),
-            repeating_tasks.todo.len(),
+        store.add(
+            Task::repeating(task, repeat)
+                .with_priority(priority)
+                .with_tags(&tags),
         );
-
-        if !error_or_not {
-            let unwrapped_range = unwrap_range_positioning(bound1, bound2);
-            unwrapped_range
-                .iter()
-                .for_each(|number| done.push(number.to_string()));
-            // this is not good
-        }
-    }
-
-    // filter for viable positions
-    for i in (0..done.len()).rev() {
-        if done.get(i).unwrap().parse::<usize>().is_err()
-        || done.get(i).unwrap().is_empty() // this will never trigger smh
-        || done.get(i).unwrap().parse::<usize>().unwrap() == 0
-        || done.get(i).unwrap().parse::<usize>().unwrap() > repeating_tasks.todo.len()
-        {
-            done.swap_remove(i);
-        }
-    }
-
-    // no valid arguments
-    if done.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: None of the positions you provided were viable \
-            -- they were all either negative, zero, or exceeded the repeating todo list's \
-            length."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // sort and dedup
-    let mut done: Vec<usize> = done.iter().map(|x| x.parse::<usize>().unwrap()).collect();
-    done.sort();
-    done.dedup();
-
-    // check if the user basically specified the entire list
-    if done.len() >= repeating_tasks.todo.len() && repeating_tasks.todo.len() > 5 {
-        writeln!(
-            writer,
-            "WARNING: You've specified the entire repeating todo list that's \
-            relatively long. You should do chartodo repeating-doneall"
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
     }
 
-    // before pushing to done, change each repeat_done field in each specified todo to true
-    done.iter().for_each(|position| {
-        repeating_tasks
-            .todo
-            .get_mut(position - 1)
-            .unwrap()
-            .repeat_done = Some(true);
-    });
-
-    // change todos to dones one by one
-    done.iter().rev().for_each(|position| {
-        repeating_tasks
-            .done
-            .push(repeating_tasks.todo.get(position - 1).unwrap().to_owned());
-        repeating_tasks.todo.remove(position - 1);
-    });
-
     // write changes to file
-    write_changes_to_new_repeating_tasks(repeating_tasks);
-
-    // error = false
-    false
+    save_task_store(&mut store)
 }
 
-pub fn repeating_tasks_reset_original_datetime_to_now(mut reset: Vec<String>) -> bool {
-    // housekeeping
-    repeating_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn repeating_tasks_add_end(
+    add_end: Vec<RepeatingDatetimeSet>,
+    priority: Option<Priority>,
+    tags: Vec<String>,
+    rules: RepeatRules,
+) -> Result<(), ChartodoError> {
     // open file and parse
-    let mut repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
+    let mut store = open_task_store()?;
 
-    // check if todo list is empty
-    if repeating_tasks.todo.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The repeating todo list is currently empty. Try adding items to it first."
-        )
-        .expect("writeln failed");
+    for (task, interval, unit, date, time) in add_end {
+        check_rule_fits(&task, unit, rules.on)?;
+        let Some(repeat) = Repeat::ending_at_on(date.and_time(time), interval, unit, rules.on)
+        else {
+            return Err(interval_out_of_range(&task));
+        };
 
-        // error = true
-        return true;
-    }
+        let repeat = repeat.with_rules(rules);
+        check_end_fits(&task, &repeat)?;
 
-    // for the record, i hate that this is a separate iteration
-    // go thru list and check if an item is ranged. if yes, unwrap it and push to original list
-    for i in (0..reset.len()).rev() {
-        let (error_or_not, bound1, bound2) = check_if_range_positioning(
-            reset.get(i).unwrap().to_string(),
-            repeating_tasks.todo.len(),
+        store.add(
+            Task::repeating(task, repeat)
+                .with_priority(priority)
+                .with_tags(&tags),
         );
-
-        if !error_or_not {
-            let unwrapped_range = unwrap_range_positioning(bound1, bound2);
-            unwrapped_range
-                .iter()
-                .for_each(|number| reset.push(number.to_string()));
-            // this is not good
-        }
     }
 
-    // filter for viable positions
-    for i in (0..reset.len()).rev() {
-        if reset.get(i).unwrap().parse::<usize>().is_err()
-        || reset.get(i).unwrap().is_empty() // this will never trigger smh
-        || reset.get(i).unwrap().parse::<usize>().unwrap() == 0
-        || reset.get(i).unwrap().parse::<usize>().unwrap() > repeating_tasks.todo.len()
-        {
-            reset.swap_remove(i);
-        }
-    }
+    // write changes to file
+    save_task_store(&mut store)
+}
 
-    // no valid args
-    if reset.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: None of the positions you provided were viable \
-            -- they were all either negative, zero, or exceeded the repeating todo list's \
-            length."
-        )
-        .expect("writeln failed");
+pub fn repeating_tasks_done(done: Vec<String>) -> Result<(), ChartodoError> {
+    // open file and parse
+    let mut store = open_task_store()?;
+
+    // unwrap any ranges and check the positions
+    let done = store.positions_from_args(
+        PositionsIn::RepeatingTodo,
+        &done,
+        &PositionErrors {
+            empty: "The repeating todo list is currently empty. Try adding items to it first.",
+            none_viable: "None of the positions you provided were viable \
+                -- they were all either negative, zero, or exceeded the repeating todo list's \
+                length.",
+            whole_list: Some(
+                "You've specified the entire repeating todo list that's \
+                relatively long. You should do chartodo repeating-doneall",
+            ),
+        },
+    )?;
+
+    // change todos to dones. each one's repeat_done field is changed to true
+    store.complete(TaskList::Repeating, &done)?;
 
-        // error = true
-        return true;
-    }
+    // write changes to file
+    save_task_store(&mut store)?;
 
-    // sort and dedup
-    reset.sort();
-    reset.dedup();
-
-    // check if the user basically specified the entire list
-    if reset.len() >= repeating_tasks.todo.len() && repeating_tasks.todo.len() > 5 {
-        writeln!(
-            writer,
-            "WARNING: You've specified the entire repeating \
-            todo list that's relatively long. You should do chartodo \
-            repeating-resetall/repeating-doneresetall"
-        )
-        .expect("writeln failed");
+    Ok(())
+}
 
-        // error = true
-        return true;
-    }
+pub fn repeating_tasks_reset_original_datetime_to_now(
+    reset: Vec<String>,
+) -> Result<(), ChartodoError> {
+    // open file and parse
+    let mut store = open_task_store()?;
+
+    // unwrap any ranges and check the positions
+    let reset = store.positions_from_args(
+        PositionsIn::RepeatingTodo,
+        &reset,
+        &PositionErrors {
+            empty: "The repeating todo list is currently empty. Try adding items to it first.",
+            none_viable: "None of the positions you provided were viable \
+                -- they were all either negative, zero, or exceeded the repeating todo list's \
+                length.",
+            whole_list: Some(
+                "You've specified the entire repeating \
+                todo list that's relatively long. You should do chartodo \
+                repeating-resetall/repeating-doneresetall",
+            ),
+        },
+    )?;
 
     // reset each original datetime to local::now and make new due datetimes
-    reset.iter().for_each(|position| {
-        // create new original + due datetimes
-        let (date, time, repeat_original_date, repeat_original_time) = add_to_local_now(
-            repeating_tasks
-                .todo
-                .get(position.parse::<usize>().unwrap() - 1)
-                .unwrap()
-                .repeat_number
-                .unwrap(),
-            repeating_tasks
-                .todo
-                .get(position.parse::<usize>().unwrap() - 1)
-                .unwrap()
-                .repeat_unit
-                .as_ref()
-                .unwrap()
-                .to_string(),
-        );
-
-        // set the new datetimes
-        repeating_tasks
-            .todo
-            .get_mut(position.parse::<usize>().unwrap() - 1)
-            .unwrap()
-            .repeat_original_date = Some(repeat_original_date);
-        repeating_tasks
-            .todo
-            .get_mut(position.parse::<usize>().unwrap() - 1)
-            .unwrap()
-            .repeat_original_time = Some(repeat_original_time);
-        repeating_tasks
-            .todo
-            .get_mut(position.parse::<usize>().unwrap() - 1)
-            .unwrap()
-            .date = Some(date);
-        repeating_tasks
-            .todo
-            .get_mut(position.parse::<usize>().unwrap() - 1)
-            .unwrap()
-            .time = Some(time);
-    });
+    store.reset_repeating(&reset, local_now_to_the_minute())?;
 
     // write changes to file
-    write_changes_to_new_repeating_tasks(repeating_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn repeating_tasks_rmtodo(mut rmtodo: Vec<String>) -> bool {
-    // housekeeping
-    repeating_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn repeating_tasks_rmtodo(rmtodo: Vec<String>) -> Result<(), ChartodoError> {
     // open file and parse
-    let mut repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
-
-    // check if todo list is empty
-    if repeating_tasks.todo.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The repeating todo list is currently empty. Try adding items to it first."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // for the record, i hate that this is a separate iteration
-    // go thru list and check if an item is ranged. if yes, unwrap it and push to original list
-    for i in (0..rmtodo.len()).rev() {
-        let (error_or_not, bound1, bound2) = check_if_range_positioning(
-            rmtodo.get(i).unwrap().to_string(),
-            repeating_tasks.todo.len(),
-        );
-
-        if !error_or_not {
-            let unwrapped_range = unwrap_range_positioning(bound1, bound2);
-            unwrapped_range
-                .iter()
-                .for_each(|number| rmtodo.push(number.to_string()));
-            // this is not good
-        }
-    }
-
-    // filter for viable positions
-    for i in (0..rmtodo.len()).rev() {
-        if rmtodo.get(i).unwrap().parse::<usize>().is_err()
-        || rmtodo.get(i).unwrap().is_empty() // this will never trigger smh
-        || rmtodo.get(i).unwrap().parse::<usize>().unwrap() == 0
-        || rmtodo.get(i).unwrap().parse::<usize>().unwrap() > repeating_tasks.todo.len()
-        {
-            rmtodo.swap_remove(i);
-        }
-    }
-
-    // no valid args
-    if rmtodo.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: None of the positions you provided were viable \
-            -- they were all either negative, zero, or exceeded the repeating todo list's \
-            length."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // sort and dedup
-    let mut rmtodo: Vec<usize> = rmtodo.iter().map(|x| x.parse::<usize>().unwrap()).collect();
-    rmtodo.sort();
-    rmtodo.dedup();
-
-    // check if user wants to remove all of the items
-    if rmtodo.len() >= repeating_tasks.todo.len() && repeating_tasks.todo.len() > 5 {
-        writeln!(
-            writer,
-            "WARNING: You've specified the entire repeating todo list, one \
-            that's relatively long. You should do repeating-cleartodo"
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // remove each item one by one
-    rmtodo.iter().rev().for_each(|position| {
-        repeating_tasks.todo.remove(position - 1);
-    });
+    let mut store = open_task_store()?;
+
+    // unwrap any ranges and check the positions
+    let rmtodo = store.positions_from_args(
+        PositionsIn::RepeatingTodo,
+        &rmtodo,
+        &PositionErrors {
+            empty: "The repeating todo list is currently empty. Try adding items to it first.",
+            none_viable: "None of the positions you provided were viable \
+                -- they were all either negative, zero, or exceeded the repeating todo list's \
+                length.",
+            whole_list: Some(
+                "You've specified the entire repeating todo list, one \
+                that's relatively long. You should do repeating-cleartodo",
+            ),
+        },
+    )?;
+
+    // remove the items
+    store.remove(PositionsIn::RepeatingTodo, &rmtodo)?;
 
     // write changes to file
-    write_changes_to_new_repeating_tasks(repeating_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn repeating_tasks_doneall() -> bool {
-    // housekeeping
-    repeating_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn repeating_tasks_doneall() -> Result<(), ChartodoError> {
     // open file and parse
-    let mut repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
+    let mut store = open_task_store()?;
+    let repeating_tasks = store.repeating();
 
     // check if todo list is empty
     if repeating_tasks.todo.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The repeating todo list is currently empty, so you can't change any todos to done."
-        )
-        .expect("writeln failed");
-
-        // error = treu
-        return true;
+        return Err(ChartodoError::EmptyList(
+            "The repeating todo list is currently empty, so you can't change any todos to done."
+                .to_string(),
+        ));
     }
 
-    // before pushing, change each repeat_done field to true
-    repeating_tasks.todo.iter_mut().for_each(|task| {
-        task.repeat_done = Some(true);
-    });
-
-    // push all todos to done
-    repeating_tasks
-        .todo
-        .iter()
-        .for_each(|item| repeating_tasks.done.push(item.to_owned()));
-    repeating_tasks.todo.clear();
+    // push all todos to done. each one's repeat_done field is changed to true
+    store.complete_all(TaskList::Repeating);
 
     // write changes to file
-    write_changes_to_new_repeating_tasks(repeating_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn repeating_tasks_clear_todo() -> bool {
-    // housekeeping
-    repeating_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn repeating_tasks_clear_todo() -> Result<(), ChartodoError> {
     // open file and parse
-    let mut repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
+    let mut store = open_task_store()?;
+    let repeating_tasks = store.repeating();
 
     // check if todo list is empty
     if repeating_tasks.todo.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The repeating todo list is currently empty. Try \
+        return Err(ChartodoError::EmptyList(
+            "The repeating todo list is currently empty. Try \
             adding items to it first before removing any."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
+                .to_string(),
+        ));
     }
 
     // clear todo list
-    repeating_tasks.todo.clear();
+    store.clear(PositionsIn::RepeatingTodo);
 
     // write changes to file
-    write_changes_to_new_repeating_tasks(repeating_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn repeating_tasks_show_start(mut start: Vec<String>) -> String {
-    // housekeeping
-    repeating_tasks_create_dir_and_file_if_needed();
-
+pub fn repeating_tasks_show_start(start: Vec<String>) -> Result<String, ChartodoError> {
     // open file and parse
-    let repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
-
-    // check if todo list is empty
-    if repeating_tasks.todo.is_empty() {
-        return String::from(
-            "ERROR: The repeating todo list is currently empty. Try adding items to it first.",
-        );
-    }
-
-    // for the record, i hate that this is a separate iteration
-    // go thru list and check if an item is ranged. if yes, unwrap it and push to original list
-    for i in (0..start.len()).rev() {
-        let (error_or_not, bound1, bound2) = check_if_range_positioning(
-            start.get(i).unwrap().to_string(),
-            repeating_tasks.todo.len(),
-        );
-
-        if !error_or_not {
-            let unwrapped_range = unwrap_range_positioning(bound1, bound2);
-            unwrapped_range
-                .iter()
-                .for_each(|number| start.push(number.to_string()));
-            // this is not good
-        }
-    }
-
-    // filter for viable positions
-    for i in (0..start.len()).rev() {
-        if start.get(i).unwrap().parse::<usize>().is_err()
-        || start.get(i).unwrap().is_empty() // this will never trigger smh
-        || start.get(i).unwrap().parse::<usize>().unwrap() == 0
-        || start.get(i).unwrap().parse::<usize>().unwrap() > repeating_tasks.todo.len()
-        {
-            start.swap_remove(i);
-        }
-    }
-
-    // no valid args
-    if start.is_empty() {
-        return String::from(
-            "ERROR: None of the positions you provided were \
-            viable -- they were all either negative, zero, or exceeded the repeating todo \
-            list's length.",
-        );
-    }
-
-    // sort and dedup
-    let mut start: Vec<usize> = start.iter().map(|x| x.parse::<usize>().unwrap()).collect();
-    start.sort();
-    start.dedup();
-
-    // check if user wants to show starts for all of the items
-    if start.len() >= repeating_tasks.todo.len() && repeating_tasks.todo.len() > 5 {
-        return String::from(
-            "WARNING: You want to show the start times for an \
-            entire list that's relatively long. You should do repeating-startall.",
-        );
-    }
+    let store = open_task_store()?;
+
+    // unwrap any ranges and check the positions
+    let start = store.positions_from_args(
+        PositionsIn::RepeatingTodo,
+        &start,
+        &PositionErrors {
+            empty: "The repeating todo list is currently empty. Try adding items to it first.",
+            none_viable: "None of the positions you provided were \
+                viable -- they were all either negative, zero, or exceeded the repeating todo \
+                list's length.",
+            whole_list: Some(
+                "You want to show the start times for an \
+                entire list that's relatively long. You should do repeating-startall.",
+            ),
+        },
+    )?;
+    let repeating_tasks = store.repeating();
 
     let mut show_starts = String::from("");
     start.iter().for_each(|position| {
+        let task = repeating_tasks.todo.get(position - 1).unwrap();
         let task_and_start = format!(
-            "task: {}\n\tstart: {} {}\n",
-            repeating_tasks.todo.get(position - 1).unwrap().task,
-            repeating_tasks
-                .todo
-                .get(position - 1)
-                .unwrap()
-                .repeat_original_date
-                .as_ref()
-                .unwrap(),
-            repeating_tasks
-                .todo
-                .get(position - 1)
-                .unwrap()
-                .repeat_original_time
-                .as_ref()
-                .unwrap()
+            "task: {}\n\tstart: {}\n{}",
+            task.task,
+            format_start(task),
+            format_remaining(task)
         );
         show_starts.push_str(task_and_start.as_str());
     });
     let show_starts = show_starts.trim_end();
 
-    show_starts.to_string()
+    Ok(show_starts.to_string())
 }
 
-pub fn repeating_tasks_resetall() -> bool {
-    // housekeeping
-    repeating_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn repeating_tasks_resetall() -> Result<(), ChartodoError> {
     // open file and parse
-    let mut repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
+    let mut store = open_task_store()?;
+    let repeating_tasks = store.repeating();
 
     // check if todo list is empty
     if repeating_tasks.todo.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The repeating todo list is currently empty. Try adding items to it first."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
+        return Err(ChartodoError::EmptyList(
+            "The repeating todo list is currently empty. Try adding items to it first.".to_string(),
+        ));
     }
 
     // reset each original datetime to local::now and make new due datetimes
-    repeating_tasks.todo.iter_mut().for_each(|task| {
-        // create new original + due datetimes
-        let (date, time, repeat_original_date, repeat_original_time) = add_to_local_now(
-            task.repeat_number.unwrap(),
-            task.repeat_unit.as_ref().unwrap().to_string(),
-        );
-
-        // set the new datetimes
-        task.repeat_original_date = Some(repeat_original_date);
-        task.repeat_original_time = Some(repeat_original_time);
-        task.date = Some(date);
-        task.time = Some(time);
-    });
+    let every_position: Vec<usize> = (1..=repeating_tasks.todo.len()).collect();
+    store.reset_repeating(&every_position, local_now_to_the_minute())?;
 
     // write changes to file
-    write_changes_to_new_repeating_tasks(repeating_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn repeating_tasks_showstartall() -> String {
-    // housekeeping
-    repeating_tasks_create_dir_and_file_if_needed();
-
+pub fn repeating_tasks_showstartall() -> Result<String, ChartodoError> {
     // open file and parse
-    let repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
+    let store = open_task_store()?;
+    let repeating_tasks = store.repeating();
 
     // check if todo list is empty
     if repeating_tasks.todo.is_empty() {
-        return String::from(
-            "ERROR: The repeating todo list is currently empty. Try adding items to it first.",
-        );
+        return Err(ChartodoError::EmptyList(
+            "The repeating todo list is currently empty. Try adding items to it first.".to_string(),
+        ));
     }
 
     let mut show_starts = String::from("");
     repeating_tasks.todo.iter().for_each(|task| {
         let task_and_start = format!(
-            "task: {}\n\tstart: {} {}\n",
+            "task: {}\n\tstart: {}\n{}",
             task.task,
-            task.repeat_original_date.as_ref().unwrap(),
-            task.repeat_original_time.as_ref().unwrap()
+            format_start(task),
+            format_remaining(task)
         );
         show_starts.push_str(task_and_start.as_str());
     });
     let show_starts = show_starts.trim_end();
 
-    show_starts.to_string()
+    Ok(show_starts.to_string())
 }
 
-pub fn repeating_tasks_edit_all(edit_all: Vec<String>) -> bool {
-    // housekeeping
-    repeating_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn repeating_tasks_edit_all(
+    position: &str,
+    task: &str,
+    interval: &str,
+    unit: &str,
+    start_or_end: &str,
+    date: &str,
+    time: &str,
+) -> Result<(), ChartodoError> {
     // open file and parse
-    let mut repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
-
-    // check if todo list is empty
-    if repeating_tasks.todo.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The repeating todo list is currently empty, so there are no \
-            todos that can be edited."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
+    let mut store = open_task_store()?;
 
     // chartodo rp-ea 1 task 3 days start/end 2000-01-01 00:00. note that the user has to specify if the datetime is the start or end
 
     // the following ifs are the multitude of errors i have to check for
 
-    // check if we have the right number of arguments
-    if edit_all.len() != 7 {
-        writeln!(
-            writer,
-            "ERROR: You must specify the repeating todo's position \
-            and all the parameters that will be edited.\n\tThere should be 7 arguments \
-            after 'chartodo repeating-editall'. You provided {} argument(s).\n\tExample: \
-            chartodo repeating-editall ~position ~task ~interval ~time-unit ~start/end \
-            ~date ~time.\n\t\tDate must be in a yy-mm-dd format. Time must be in a 24-hour \
-            format.\n\t\tOnly the following time-units are allowed: minute(s), hour(s), \
-            day(s), week(s), month(s), and year(s).\n\t\tYou must specify if you're \
-            editing the ending or starting datetime by using the keywords 'start' or \
-            'end'.\n\tExample (with end): chartodo rp-ea 4 new-item 3 days end 2150-01-01 \
-            00:00.\n\tExample (with start): chartodo rp-ea 4 new-item 3 days start \
-            2150-01-01 00:00",
-            edit_all.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // check if position is a valid number
-    if edit_all.first().unwrap().parse::<usize>().is_err() {
-        writeln!(
-            writer,
-            "ERROR: The position you provided, '{}', was invalid. Try something between 1 and {}.",
-            edit_all.first().unwrap(),
-            repeating_tasks.todo.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // positions can't be zero
-    if edit_all.first().unwrap().parse::<usize>().unwrap() == 0 {
-        writeln!(
-            writer,
-            "ERROR: Positions can't be zero. They have to be 1 and above."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // position not in range of todo list len
-    if edit_all.first().unwrap().parse::<usize>().unwrap() > repeating_tasks.todo.len() {
-        writeln!(
-            writer,
-            "ERROR: The position you provided, '{}', exceeds the repeating todo \
-            list's length. Try something between 1 and {}.",
-            edit_all.first().unwrap(),
-            repeating_tasks.todo.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
+    // check the position
+    let position = store.position_from_arg(
+        PositionsIn::RepeatingTodo,
+        position,
+        "The repeating todo list is currently empty, so there are no \
+        todos that can be edited.",
+    )?;
 
     // interval isn't proper
-    if edit_all.get(2).unwrap().parse::<u32>().is_err() {
-        writeln!(
-            writer,
-            "ERROR: The interval you provided, '{}', wasn't proper. \
+    let Ok(interval) = interval.parse::<u32>() else {
+        return Err(ChartodoError::InvalidInterval(format!(
+            "The interval you provided, '{}', wasn't proper. \
             It must be in the range of 1 - 4294967295 (i.e., it has to be u32).",
-            edit_all.get(2).unwrap()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
+            interval
+        )));
+    };
 
     // check if interval is 0
-    if edit_all.get(2).unwrap().parse::<u32>().unwrap() == 0 {
-        writeln!(
-            writer,
-            "ERROR: Your interval can't be 0, otherwise why are you even setting a repeating task?"
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
+    if interval == 0 {
+        return Err(ChartodoError::InvalidInterval(
+            "Your interval can't be 0, otherwise why are you even setting a repeating task?"
+                .to_string(),
+        ));
     }
 
     // unit of time isn't proper
-    match edit_all.get(3).unwrap().as_str() {
-        "minute" | "minutes" | "hour" | "hours" | "day" | "days" | "week" | "weeks" | "month"
-        | "months" | "year" | "years" => (),
-        _ => {
-            writeln!(
-                writer,
-                "ERROR: The time unit you provided, '{}', wasn't \
+    let unit = match unit.parse::<RepeatUnit>() {
+        Ok(unit) => unit,
+        Err(_) => {
+            return Err(ChartodoError::InvalidTimeUnit(format!(
+                "The time unit you provided, '{}', wasn't \
                 proper. Proper examples: minutes, hours, days, weeks, months or years.",
-                edit_all.get(3).unwrap()
-            )
-            .expect("writeln failed");
-
-            // error = treu
-            return true;
+                unit
+            )));
         }
-    }
+    };
 
     // date isn't proper
-    if NaiveDate::parse_from_str(edit_all.get(5).unwrap().as_str(), "%Y-%m-%d").is_err() {
-        writeln!(
-            writer,
-            "ERROR: The date you provided, '{}', wasn't proper. \
-            It must be in the following format: Year-Month-Day, e.g., 2000-12-13.",
-            edit_all.get(5).unwrap()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
+    let date = match parse_date(date) {
+        Some(when) => when.date(),
+        None => {
+            return Err(ChartodoError::InvalidDate(format!(
+                "The date you provided, '{}', wasn't proper. \
+                It must be in the following format: Year-Month-Day, e.g., 2000-12-13.",
+                date
+            )));
+        }
+    };
 
     // time isn't proper
-    if NaiveTime::parse_from_str(edit_all.last().unwrap().as_str(), "%H:%M").is_err() {
-        writeln!(
-            writer,
-            "ERROR: The time you provided, '{}', wasn't \
-            proper. It must be in the following 24-hour format: H:M, e.g., 13:08.",
-            edit_all.last().unwrap()
-        )
-        .expect("writeln failed");
+    let time = match parse_time(time) {
+        Some(time) => time,
+        None => {
+            return Err(ChartodoError::InvalidTime(format!(
+                "The time you provided, '{}', wasn't \
+                proper. It must be in the following 24-hour format: H:M, e.g., 13:08.",
+                time
+            )));
+        }
+    };
 
-        // error = true
-        return true;
-    }
+    // the rule is kept, so it has to go w/ the new unit. rp-eo 1 none takes it away first
+    let on = store.repeating().todo[position - 1]
+        .repeat()
+        .and_then(|repeat| repeat.on);
+    check_rule_fits(task, unit, on)?;
 
     // check if it's start or end and do the proper operation
-    let (date, time, repeat_original_date, repeat_original_time);
-    match edit_all.get(4).unwrap().as_str() {
-        "start" => {
-            (date, time, repeat_original_date, repeat_original_time) =
-                add_to_given_starting_datetime(
-                    edit_all.get(5).unwrap().to_string(),
-                    edit_all.last().unwrap().to_string(),
-                    edit_all.get(2).unwrap().parse::<u32>().unwrap(),
-                    edit_all.get(3).unwrap().to_string(),
-                )
-        }
-        "end" => {
-            (date, time, repeat_original_date, repeat_original_time) =
-                subract_from_given_ending_datetime(
-                    edit_all.get(5).unwrap().to_string(),
-                    edit_all.last().unwrap().to_string(),
-                    edit_all.get(2).unwrap().parse::<u32>().unwrap(),
-                    edit_all.get(3).unwrap().to_string(),
-                )
-        }
+    let repeat = match start_or_end {
+        "start" => Repeat::starting_at_on(date.and_time(time), interval, unit, on),
+        "end" => Repeat::ending_at_on(date.and_time(time), interval, unit, on),
         _ => {
-            writeln!(
-                writer,
-                "ERROR: '{}' isn't correct. You must \
+            return Err(ChartodoError::InvalidStartOrEnd(format!(
+                "'{}' isn't correct. You must \
                 specify whether the given datetime is the starting or ending datetime. \
                 Please use the 'start' or 'end' keywords and nothing else, e.g., \
                 repeating-editall 1 new-repeating-task 4 weeks start 2099-12-13 13:08",
-                edit_all.get(4).unwrap()
-            )
-            .expect("writeln failed");
-
-            // error = treu
-            return true;
+                start_or_end
+            )));
         }
-    }
+    };
+    let Some(repeat) = repeat else {
+        return Err(interval_out_of_range(task));
+    };
 
     // get the task and edit
-    let position: usize = edit_all.first().unwrap().parse().unwrap();
-    repeating_tasks.todo.get_mut(position - 1).unwrap().task = edit_all.get(1).unwrap().to_string();
-    repeating_tasks.todo.get_mut(position - 1).unwrap().date = Some(date);
-    repeating_tasks.todo.get_mut(position - 1).unwrap().time = Some(time);
-    repeating_tasks
-        .todo
-        .get_mut(position - 1)
-        .unwrap()
-        .repeat_number = Some(edit_all.get(2).unwrap().parse::<u32>().unwrap());
-    repeating_tasks
-        .todo
-        .get_mut(position - 1)
-        .unwrap()
-        .repeat_unit = Some(edit_all.get(3).unwrap().to_string());
-    repeating_tasks
-        .todo
-        .get_mut(position - 1)
-        .unwrap()
-        .repeat_original_date = Some(repeat_original_date);
-    repeating_tasks
-        .todo
-        .get_mut(position - 1)
-        .unwrap()
-        .repeat_original_time = Some(repeat_original_time);
+    // note: the task itself is kept and only its parts are changed, so that it keeps its id
+    store.edit_task(PositionsIn::RepeatingTodo, position, task)?;
+    store.edit_repeating(position, |_| Some(repeat))?;
 
     // write changes to file
-    write_changes_to_new_repeating_tasks(repeating_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn repeating_tasks_edit_task(edit_task: Vec<String>) -> bool {
-    // housekeeping
-    repeating_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn repeating_tasks_edit_task(position: &str, task: &str) -> Result<(), ChartodoError> {
     // open file and parse
-    let mut repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
-
-    // check if todo list is empty
-    if repeating_tasks.todo.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The repeating todo list is currently empty, so there are no \
-            todos that can be edited."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
+    let mut store = open_task_store()?;
 
     // chartodo rp-eta 1 new-task
 
     // the following ifs are the multitude of errors i have to check for
 
-    // check if we have the right number of arguments
-    if edit_task.len() != 2 {
-        writeln!(
-            writer,
-            "ERROR: You must specify the repeating todo's \
-            position and the new task to change it to.\n\tThere should be 2 arguments after \
-            'chartodo repeating-edittask'. You provided {} argument(s).\n\tFormat: chartodo \
-            repeating-edittask ~position ~task.\n\tExample: chartodo rp-eta 4 new-item.",
-            edit_task.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // check if position is a valid number
-    if edit_task.first().unwrap().parse::<usize>().is_err() {
-        writeln!(
-            writer,
-            "ERROR: The position you provided, '{}', was invalid. Try something between 1 and {}.",
-            edit_task.first().unwrap(),
-            repeating_tasks.todo.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // positions can't be zero
-    if edit_task.first().unwrap().parse::<usize>().unwrap() == 0 {
-        writeln!(
-            writer,
-            "ERROR: Positions can't be zero. They have to be 1 and above."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // position not in range of todo list len
-    if edit_task.first().unwrap().parse::<usize>().unwrap() > repeating_tasks.todo.len() {
-        writeln!(
-            writer,
-            "ERROR: Your position, '{}', exceeds the repeating todo list's \
-            length. Try something between 1 and {}.",
-            edit_task.first().unwrap(),
-            repeating_tasks.todo.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
+    // check the position
+    let position = store.position_from_arg(
+        PositionsIn::RepeatingTodo,
+        position,
+        "The repeating todo list is currently empty, so there are no \
+        todos that can be edited.",
+    )?;
 
     // get the todo and edit task
-    let position: usize = edit_task.first().unwrap().parse().unwrap();
-    repeating_tasks.todo.get_mut(position - 1).unwrap().task =
-        edit_task.last().unwrap().to_string();
+    store.edit_task(PositionsIn::RepeatingTodo, position, task)?;
 
     // write changes to file
-    write_changes_to_new_repeating_tasks(repeating_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn repeating_tasks_edit_interval(edit_interval: Vec<String>) -> bool {
-    // housekeeping
-    repeating_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn repeating_tasks_edit_interval(position: &str, interval: &str) -> Result<(), ChartodoError> {
     // open file and parse
-    let mut repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
-
-    // check if todo list is empty
-    if repeating_tasks.todo.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The repeating todo list is currently empty, so there are no \
-            todos that can be edited."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
+    let mut store = open_task_store()?;
 
     // chartodo rp-ei 1 3
 
     // the following ifs are the multitude of errors i have to check for
 
-    // check if we have the right number of arguments
-    if edit_interval.len() != 2 {
-        writeln!(
-            writer,
-            "ERROR: You must specify the repeating todo's \
-            position and what to edit the interval to.\n\tThere should be 2 arguments after \
-            'chartodo repeating-editinterval'. You provided {} argument(s).\n\tFormat: \
-            chartodo repeating-editinterval ~position ~interval.\n\tExample: chartodo \
-            rp-ei 4 3.\n\t\t'4' would be the todo task's position and '3' would be the \
-            new interval, i.e., repeating task 4 would now have an interval of '3 days'.",
-            edit_interval.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // check if position is a valid number
-    if edit_interval.first().unwrap().parse::<usize>().is_err() {
-        writeln!(
-            writer,
-            "ERROR: The position you provided, '{}', was invalid. Try something between 1 and {}.",
-            edit_interval.first().unwrap(),
-            repeating_tasks.todo.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // positions can't be zero
-    if edit_interval.first().unwrap().parse::<usize>().unwrap() == 0 {
-        writeln!(
-            writer,
-            "ERROR: Positions can't be zero. They have to be 1 and above."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // position not in range of todo list len
-    if edit_interval.first().unwrap().parse::<usize>().unwrap() > repeating_tasks.todo.len() {
-        writeln!(
-            writer,
-            "ERROR: Your position, '{}', exceeds the repeating todo list's \
-            length. Try something between 1 and {}.",
-            edit_interval.first().unwrap(),
-            repeating_tasks.todo.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
+    // check the position
+    let position = store.position_from_arg(
+        PositionsIn::RepeatingTodo,
+        position,
+        "The repeating todo list is currently empty, so there are no \
+        todos that can be edited.",
+    )?;
 
     // interval isn't proper
-    if edit_interval.last().unwrap().parse::<u32>().is_err() {
-        writeln!(
-            writer,
-            "ERROR: The interval you provided, '{}', wasn't proper. \
+    let Ok(interval) = interval.parse::<u32>() else {
+        return Err(ChartodoError::InvalidInterval(format!(
+            "The interval you provided, '{}', wasn't proper. \
             It must be in the range of 1 - 4294967295 (i.e., it has to be u32).",
-            edit_interval.last().unwrap()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
+            interval
+        )));
+    };
 
     // check if interval is 0
-    if edit_interval.last().unwrap().parse::<u32>().unwrap() == 0 {
-        writeln!(
-            writer,
-            "ERROR: Your interval can't be 0, otherwise why are you even setting \
+    if interval == 0 {
+        return Err(ChartodoError::InvalidInterval(
+            "Your interval can't be 0, otherwise why are you even setting \
             a repeating task?"
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
+                .to_string(),
+        ));
     }
 
-    // get the updated datetimes
-    let position: usize = edit_interval.first().unwrap().parse::<usize>().unwrap() - 1;
-    let (date, time, repeat_original_date, repeat_original_time) = add_to_given_starting_datetime(
-        repeating_tasks
-            .todo
-            .get(position)
-            .unwrap()
-            .repeat_original_date
-            .as_ref()
-            .unwrap()
-            .to_string(),
-        repeating_tasks
-            .todo
-            .get(position)
-            .unwrap()
-            .repeat_original_time
-            .as_ref()
-            .unwrap()
-            .to_string(),
-        edit_interval.last().unwrap().parse::<u32>().unwrap(),
-        repeating_tasks
-            .todo
-            .get(position)
-            .unwrap()
-            .repeat_unit
-            .as_ref()
-            .unwrap()
-            .to_string(),
-    );
-
     // edit the task
-    repeating_tasks.todo.get_mut(position).unwrap().date = Some(date);
-    repeating_tasks.todo.get_mut(position).unwrap().time = Some(time);
-    repeating_tasks
-        .todo
-        .get_mut(position)
-        .unwrap()
-        .repeat_number = Some(edit_interval.last().unwrap().parse::<u32>().unwrap());
-    repeating_tasks
-        .todo
-        .get_mut(position)
-        .unwrap()
-        .repeat_original_date = Some(repeat_original_date);
-    repeating_tasks
-        .todo
-        .get_mut(position)
-        .unwrap()
-        .repeat_original_time = Some(repeat_original_time);
+    store.edit_repeating(position, |repeat| {
+        Repeat {
+            interval,
+            ..*repeat
+        }
+        .restarted_at(repeat.start)
+    })?;
 
     // write changes to file
-    write_changes_to_new_repeating_tasks(repeating_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn repeating_tasks_edit_time_unit(edit_unit: Vec<String>) -> bool {
-    // housekeeping
-    repeating_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn repeating_tasks_edit_time_unit(position: &str, unit: &str) -> Result<(), ChartodoError> {
     // open file and parse
-    let mut repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
-
-    // check if todo list is empty
-    if repeating_tasks.todo.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The repeating todo list is currently empty, so there are no \
-            todos that can be edited."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
+    let mut store = open_task_store()?;
 
     // chartodo rp-eu 1 weeks
 
     // the following ifs are the multitude of errors i have to check for
 
-    // check if we have the right number of arguments
-    if edit_unit.len() != 2 {
-        writeln!(
-            writer,
-            "ERROR: You must specify the repeating todo's \
-            position and what to edit the time unit to.\n\tThere should be 2 arguments \
-            after 'chartodo repeating-eu'. You provided {} arguments().\n\tFormat: \
-            chartodo repeating-editunit ~position ~time-unit.\n\tExample: chartodo rp-eu \
-            4 weeks.\n\t\tThat would change repeating task #4's time unit to 'weeks'.",
-            edit_unit.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // check if position is a valid number
-    if edit_unit.first().unwrap().parse::<usize>().is_err() {
-        writeln!(
-            writer,
-            "ERROR: The position you provided, '{}', was invalid. Try something \
-            between 1 and {}.",
-            edit_unit.first().unwrap(),
-            repeating_tasks.todo.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // positions can't be zero
-    if edit_unit.first().unwrap().parse::<usize>().unwrap() == 0 {
-        writeln!(
-            writer,
-            "ERROR: Positions can't be zero. They have to be 1 and above."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // position not in range of todo list len
-    if edit_unit.first().unwrap().parse::<usize>().unwrap() > repeating_tasks.todo.len() {
-        writeln!(
-            writer,
-            "ERROR: Your position, '{}', exceeds the repeating todo list's \
-            length. Try something between 1 and {}.",
-            edit_unit.first().unwrap(),
-            repeating_tasks.todo.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
+    // check the position
+    let position = store.position_from_arg(
+        PositionsIn::RepeatingTodo,
+        position,
+        "The repeating todo list is currently empty, so there are no \
+        todos that can be edited.",
+    )?;
 
     // unit of time isn't proper
-    match edit_unit.last().unwrap().as_str() {
-        "minute" | "minutes" | "hour" | "hours" | "day" | "days" | "week" | "weeks" | "month"
-        | "months" | "year" | "years" => (),
-        _ => {
-            writeln!(
-                writer,
-                "ERROR: The time unit you provided, '{}', wasn't \
+    let unit = match unit.parse::<RepeatUnit>() {
+        Ok(unit) => unit,
+        Err(_) => {
+            return Err(ChartodoError::InvalidTimeUnit(format!(
+                "The time unit you provided, '{}', wasn't \
                 proper. Proper examples: minutes, hours, days, weeks, months or years.",
-                edit_unit.last().unwrap()
-            )
-            .expect("writeln failed");
-
-            // error = true
-            return true;
+                unit
+            )));
         }
-    }
+    };
 
     // get the updated datetimes
-    let position: usize = edit_unit.first().unwrap().parse::<usize>().unwrap() - 1;
-    let (date, time, repeat_original_date, repeat_original_time) = add_to_given_starting_datetime(
-        repeating_tasks
-            .todo
-            .get(position)
-            .unwrap()
-            .repeat_original_date
-            .as_ref()
-            .unwrap()
-            .to_string(),
-        repeating_tasks
-            .todo
-            .get(position)
-            .unwrap()
-            .repeat_original_time
-            .as_ref()
-            .unwrap()
-            .to_string(),
-        repeating_tasks
-            .todo
-            .get(position)
-            .unwrap()
-            .repeat_number
-            .unwrap(),
-        edit_unit.last().unwrap().to_string(),
-    );
+
+    // the rule is kept, so it has to go w/ the new unit
+    let task = &store.repeating().todo[position - 1];
+    check_rule_fits(&task.task, unit, task.repeat().and_then(|repeat| repeat.on))?;
 
     // update the datetimes and time unit
-    repeating_tasks.todo.get_mut(position).unwrap().date = Some(date);
-    repeating_tasks.todo.get_mut(position).unwrap().time = Some(time);
-    repeating_tasks.todo.get_mut(position).unwrap().repeat_unit =
-        Some(edit_unit.last().unwrap().to_string());
-    repeating_tasks
-        .todo
-        .get_mut(position)
-        .unwrap()
-        .repeat_original_date = Some(repeat_original_date);
-    repeating_tasks
-        .todo
-        .get_mut(position)
-        .unwrap()
-        .repeat_original_time = Some(repeat_original_time);
+    store.edit_repeating(position, |repeat| {
+        Repeat { unit, ..*repeat }.restarted_at(repeat.start)
+    })?;
 
     // write changes to file
-    write_changes_to_new_repeating_tasks(repeating_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn repeating_tasks_edit_interval_unit(edit_interval_unit: Vec<String>) -> bool {
-    // housekeeping
-    repeating_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn repeating_tasks_edit_interval_unit(
+    position: &str,
+    interval: &str,
+    unit: &str,
+) -> Result<(), ChartodoError> {
     // open file and parse
-    let mut repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
-
-    // check if todo list is empty
-    if repeating_tasks.todo.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The repeating todo list is currently empty, so there are no todos that can be edited."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
+    let mut store = open_task_store()?;
 
     // chartodo rp-eiu 1 3 days
 
     // the following ifs are the multitude of errors i have to check for
 
-    // check if we have the right number of arguments
-    if edit_interval_unit.len() != 3 {
-        writeln!(
-            writer,
-            "ERROR: You must specify the repeating todo's \
-            position and what to change the interval and time unit to.\n\tThere \
-            should be 3 arguments after 'chartodo repeating-editintervalunit'. You \
-            provided {} argument(s).\n\tFormat: chartodo repeating-editintervalunit \
-            ~position ~interval ~time-unit.\n\tExample: chartodo rp-eiu 4 3 days.",
-            edit_interval_unit.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // check if position is a valid number
-    if edit_interval_unit
-        .first()
-        .unwrap()
-        .parse::<usize>()
-        .is_err()
-    {
-        writeln!(
-            writer,
-            "ERROR: The position you provided, '{}', wasn't valid. Try something \
-            between 1 and {}.",
-            edit_interval_unit.first().unwrap(),
-            repeating_tasks.todo.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // positions can't be zero
-    if edit_interval_unit
-        .first()
-        .unwrap()
-        .parse::<usize>()
-        .unwrap()
-        == 0
-    {
-        writeln!(
-            writer,
-            "ERROR: Positions can't be zero. They have to be 1 and above."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // position not in range of todo list len
-    if edit_interval_unit
-        .first()
-        .unwrap()
-        .parse::<usize>()
-        .unwrap()
-        > repeating_tasks.todo.len()
-    {
-        writeln!(
-            writer,
-            "ERROR: Your position, '{}', exceeds the repeating todo list's \
-            length. Try something between 1 and {}.",
-            edit_interval_unit.first().unwrap(),
-            repeating_tasks.todo.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
+    // check the position
+    let position = store.position_from_arg(
+        PositionsIn::RepeatingTodo,
+        position,
+        "The repeating todo list is currently empty, so there are no todos that can be edited.",
+    )?;
 
     // interval isn't proper
-    if edit_interval_unit.get(1).unwrap().parse::<u32>().is_err() {
-        writeln!(
-            writer,
-            "ERROR: The interval you provided, '{}', wasn't proper. \
+    let Ok(interval) = interval.parse::<u32>() else {
+        return Err(ChartodoError::InvalidInterval(format!(
+            "The interval you provided, '{}', wasn't proper. \
             It must be in the range of 1 - 4294967295 (i.e., it has to be u32).",
-            edit_interval_unit.get(1).unwrap()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
+            interval
+        )));
+    };
 
     // check if interval is 0
-    if edit_interval_unit.get(1).unwrap().parse::<u32>().unwrap() == 0 {
-        writeln!(
-            writer,
-            "ERROR: Your interval can't be 0, otherwise why are you even setting \
+    if interval == 0 {
+        return Err(ChartodoError::InvalidInterval(
+            "Your interval can't be 0, otherwise why are you even setting \
             a repeating task?"
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
+                .to_string(),
+        ));
     }
 
     // unit of time isn't proper
-    match edit_interval_unit.last().unwrap().as_str() {
-        "minute" | "minutes" | "hour" | "hours" | "day" | "days" | "week" | "weeks" | "month"
-        | "months" | "year" | "years" => (),
-        _ => {
-            writeln!(
-                writer,
-                "ERROR: The time unit you provided, '{}', wasn't \
+    let unit = match unit.parse::<RepeatUnit>() {
+        Ok(unit) => unit,
+        Err(_) => {
+            return Err(ChartodoError::InvalidTimeUnit(format!(
+                "The time unit you provided, '{}', wasn't \
                 proper. Proper examples: minutes, hours, days, weeks, months or years.",
-                edit_interval_unit.last().unwrap()
-            )
-            .expect("writeln failed");
-
-            // error = treu
-            return true;
+                unit
+            )));
         }
-    }
+    };
 
-    // get the updated datetimes
-    let position: usize = edit_interval_unit
-        .first()
-        .unwrap()
-        .parse::<usize>()
-        .unwrap()
-        - 1;
-    let (date, time, repeat_original_date, repeat_original_time) = add_to_given_starting_datetime(
-        repeating_tasks
-            .todo
-            .get(position)
-            .unwrap()
-            .repeat_original_date
-            .as_ref()
-            .unwrap()
-            .to_string(),
-        repeating_tasks
-            .todo
-            .get(position)
-            .unwrap()
-            .repeat_original_time
-            .as_ref()
-            .unwrap()
-            .to_string(),
-        edit_interval_unit.get(1).unwrap().parse().unwrap(),
-        edit_interval_unit.last().unwrap().to_string(),
-    );
+    // the rule is kept, so it has to go w/ the new unit
+    let task = &store.repeating().todo[position - 1];
+    check_rule_fits(&task.task, unit, task.repeat().and_then(|repeat| repeat.on))?;
 
     // get the task and edit
-    repeating_tasks.todo.get_mut(position).unwrap().date = Some(date);
-    repeating_tasks.todo.get_mut(position).unwrap().time = Some(time);
-    repeating_tasks
-        .todo
-        .get_mut(position)
-        .unwrap()
-        .repeat_number = Some(edit_interval_unit.get(1).unwrap().parse::<u32>().unwrap());
-    repeating_tasks.todo.get_mut(position).unwrap().repeat_unit =
-        Some(edit_interval_unit.last().unwrap().to_string());
-    repeating_tasks
-        .todo
-        .get_mut(position)
-        .unwrap()
-        .repeat_original_date = Some(repeat_original_date);
-    repeating_tasks
-        .todo
-        .get_mut(position)
-        .unwrap()
-        .repeat_original_time = Some(repeat_original_time);
+    store.edit_repeating(position, |repeat| {
+        Repeat {
+            interval,
+            unit,
+            ..*repeat
+        }
+        .restarted_at(repeat.start)
+    })?;
 
     // write changes to file
-    write_changes_to_new_repeating_tasks(repeating_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn repeating_tasks_edit_start(edit_start: Vec<String>) -> bool {
-    // housekeeping
-    repeating_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn repeating_tasks_edit_start(
+    position: &str,
+    date: &str,
+    time: &str,
+) -> Result<(), ChartodoError> {
     // open file and parse
-    let mut repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
-
-    // check if todo list is empty
-    if repeating_tasks.todo.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The repeating todo list is currently empty, so there are no \
-            todos that can be edited."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
+    let mut store = open_task_store()?;
 
     // chartodo rp-es 1 2100-01-01 00:00
 
     // the following ifs are the multitude of errors i have to check for
 
-    // check if we have the right number of arguments
-    if edit_start.len() != 3 {
-        writeln!(
-            writer,
-            "ERROR: You must specify the repeating todo's \
-            position and what to change the repeating task's starting datetime \
-            to.\n\tThere should be 3 arguments after 'chartodo repeating-editstart'. \
-            You provided {} argument(s).\n\tFormat: chartodo repeating-editstart \
-            ~position ~date ~time.\n\t\tDate should be in a yy-mm-dd format. Time should \
-            be in a 24-hour format.\n\tExample: chartodo rp-es 4 2100-12-24 13:08",
-            edit_start.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // check if position is a valid number
-    if edit_start.first().unwrap().parse::<usize>().is_err() {
-        writeln!(
-            writer,
-            "ERROR: The position you provided, '{}', was invalid. Try something \
-            between 1 and {}.",
-            edit_start.first().unwrap(),
-            repeating_tasks.todo.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // positions can't be zero
-    if edit_start.first().unwrap().parse::<usize>().unwrap() == 0 {
-        writeln!(
-            writer,
-            "ERROR: Positions can't be zero. They have to be 1 and above."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
-
-    // position not in range of todo list len
-    if edit_start.first().unwrap().parse::<usize>().unwrap() > repeating_tasks.todo.len() {
-        writeln!(
-            writer,
-            "ERROR: Your position, '{}', exceeds the repeating todo list's \
-            length. Try something between 1 and {}.",
-            edit_start.first().unwrap(),
-            repeating_tasks.todo.len()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
+    // check the position
+    let position = store.position_from_arg(
+        PositionsIn::RepeatingTodo,
+        position,
+        "The repeating todo list is currently empty, so there are no \
+        todos that can be edited.",
+    )?;
 
     // date isn't proper
-    if NaiveDate::parse_from_str(edit_start.get(1).unwrap().as_str(), "%Y-%m-%d").is_err() {
-        writeln!(
-            writer,
-            "ERROR: The date you provided, '{}', wasn't proper. \
-            It must be in the following format: Year-Month-Day, e.g., 2000-12-13.",
-            edit_start.get(1).unwrap()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
+    let date = match parse_date(date) {
+        Some(when) => when.date(),
+        None => {
+            return Err(ChartodoError::InvalidDate(format!(
+                "The date you provided, '{}', wasn't proper. \
+                It must be in the following format: Year-Month-Day, e.g., 2000-12-13.",
+                date
+            )));
+        }
+    };
 
     // time isn't proper
-    if NaiveTime::parse_from_str(edit_start.last().unwrap().as_str(), "%H:%M").is_err() {
-        writeln!(
-            writer,
-            "ERROR: The time you provided, '{}', wasn't \
-            proper. It must be in the following 24-hour format: H:M, e.g., 13:08.",
-            edit_start.last().unwrap()
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
+    let time = match parse_time(time) {
+        Some(time) => time,
+        None => {
+            return Err(ChartodoError::InvalidTime(format!(
+                "The time you provided, '{}', wasn't \
+                proper. It must be in the following 24-hour format: H:M, e.g., 13:08.",
+                time
+            )));
+        }
+    };
 
     // get the updated datetimes based on the given starting datetime
-    let position: usize = edit_start.first().unwrap().parse::<usize>().unwrap() - 1;
-    let (date, time, repeat_original_date, repeat_original_time) = add_to_given_starting_datetime(
-        edit_start.get(1).unwrap().to_string(),
-        edit_start.last().unwrap().to_string(),
-        repeating_tasks
-            .todo
-            .get(position)
-            .unwrap()
-            .repeat_number
-            .unwrap(),
-        repeating_tasks
-            .todo
-            .get(position)
-            .unwrap()
-            .repeat_unit
-            .as_ref()
-            .unwrap()
-            .to_string(),
-    );
 
     // edit the task
-    repeating_tasks.todo.get_mut(position).unwrap().date = Some(date);
-    repeating_tasks.todo.get_mut(position).unwrap().time = Some(time);
-    repeating_tasks
-        .todo
-        .get_mut(position)
-        .unwrap()
-        .repeat_original_date = Some(repeat_original_date);
-    repeating_tasks
-        .todo
-        .get_mut(position)
-        .unwrap()
-        .repeat_original_time = Some(repeat_original_time);
+    store.edit_repeating(position, |repeat| repeat.restarted_at(date.and_time(time)))?;
 
     // write changes to file
-    write_changes_to_new_repeating_tasks(repeating_tasks);
+    save_task_store(&mut store)?;
 
-    // error = false
-    false
+    Ok(())
 }
 
-pub fn repeating_tasks_edit_end(edit_end: Vec<String>) -> bool {
-    // housekeeping
-    repeating_tasks_create_dir_and_file_if_needed();
-    let writer = &mut std::io::stdout();
-
+pub fn repeating_tasks_edit_end(
+    position: &str,
+    date: &str,
+    time: &str,
+) -> Result<(), ChartodoError> {
     // open file and parse
-    let mut repeating_tasks = open_repeating_tasks_and_return_tasks_struct();
-
-    // check if todo list is empty
-    if repeating_tasks.todo.is_empty() {
-        writeln!(
-            writer,
-            "ERROR: The repeating todo list is currently empty, so there are no \
-            todos that can be edited."
-        )
-        .expect("writeln failed");
-
-        // error = true
-        return true;
-    }
+    let mut store = open_task_store()?;
 
     // chartodo rp-ee 1 2100-12-24 13:08
 
     // the following ifs are the multitude of errors i have to check for
 
-    // check if we have the right number of arguments
-    if edit_end.len() != 3 {
-        writeln!(
-            writer,
-            "ERROR: You must specify the repeating todo's \
-            position and what to change the repeating task's ending datetime \
-            to.\n\tThere should be 3 arguments after 'chartodo repeating-editend'. You \
-            provided {} argument(s).\n\tFormat: chartodo repeating-editend ~position \
-            ~date ~time.\n\t\tDate should be in a yy-mm-dd format. Time should be \
-            in a 24-hour format.\n\tExample: chartodo rp-ee 4 2100-12-14 13:08",
-            edit_end.len()
-        )
-        .expect("writeln failed");
+    // check the position
+    let position = store.position_from_arg(
+        PositionsIn::RepeatingTodo,
+        position,
+        "The repeating todo list is currently empty, so there are no \
+        todos that can be edited.",
+    )?;
 
-        // error = true
-        return true;
-    }
+    // date isn't proper
+    let date = match parse_date(date) {
+        Some(when) => when.date(),
+        None => {
+            return Err(ChartodoError::InvalidDate(format!(
+                "The date you provided, '{}', wasn't proper. \
+                It must be in the following format: Year-Month-Day, e.g., 2000-12-13.",
+                date
+            )));
+        }
+    };
 
-    // check if position is a valid number
-    if edit_end.first().unwrap().parse::<usize>().is_err() {
-        writeln!(
-            writer,
-            "ERROR: The position you provided, '{}', wasn't valid. Try something \
-            between 1 and {}.",
-            edit_end.first().unwrap(),
-            repeating_tasks.todo.len()
-        )
-        .expect("writeln failed");
+    // time isn't proper
+    let time = match parse_time(time) {
+        Some(time) => time,
+        None => {
+            return Err(ChartodoError::InvalidTime(format!(
+                "The time you provided, '{}', wasn't \
+                proper. It must be in the following 24-hour format: H:M, e.g., 13:08.",
+                time
+            )));
+        }
+    };
 
-        // error = true
-        return true;
-    }
+    // get the updated datetimes from the given ending datetime
 
-    // positions can't be zero
-    if edit_end.first().unwrap().parse::<usize>().unwrap() == 0 {
-        writeln!(
-            writer,
-            "ERROR: Positions can't be zero. They have to be 1 and above."
-        )
-        .expect("writeln failed");
+    // get the task and edit
+    store.edit_repeating(position, |repeat| {
+        Repeat::ending_at_on(date.and_time(time), repeat.interval, repeat.unit, repeat.on)
+    })?;
 
-        // error = treu
-        return true;
-    }
+    // write changes to file
+    save_task_store(&mut store)?;
 
-    // position not in range of todo list len
-    if edit_end.first().unwrap().parse::<usize>().unwrap() > repeating_tasks.todo.len() {
-        writeln!(
-            writer,
-            "ERROR: Your position, '{}', exceeds the repeating todo list's \
-            length. Try something between 1 and {}.",
-            edit_end.first().unwrap(),
-            repeating_tasks.todo.len()
-        )
-        .expect("writeln failed");
+    Ok(())
+}
 
-        // error = treu
-        return true;
-    }
+// chartodo rp-eo 1 mon,wed,fri. none takes the rule away
+pub fn repeating_tasks_edit_on(position: &str, rule: &str) -> Result<(), ChartodoError> {
+    // open file and parse
+    let mut store = open_task_store()?;
+
+    // check the position
+    let position = store.position_from_arg(
+        PositionsIn::RepeatingTodo,
+        position,
+        "The repeating todo list is currently empty, so there are no \
+        todos that can be edited.",
+    )?;
+
+    // a rule only has one time unit it works w/, which set_repeat_on checks
+    let on = match rule {
+        "none" => None,
+        on => match on.parse::<RepeatOn>() {
+            Ok(on) => Some(on),
+            Err(on_error) => {
+                return Err(ChartodoError::InvalidTimeUnit(format!(
+                    "{}, or none.",
+                    on_error
+                )));
+            }
+        },
+    };
+    store.set_repeat_on(position, on)?;
 
-    // date isn't proper
-    if NaiveDate::parse_from_str(edit_end.get(1).unwrap().as_str(), "%Y-%m-%d").is_err() {
-        writeln!(
-            writer,
-            "ERROR: The date you provided, '{}', wasn't proper. \
-            It must be in the following format: Year-Month-Day, e.g., 2000-12-13.",
-            edit_end.get(1).unwrap()
-        )
-        .expect("writeln failed");
+    // write changes to file
+    save_task_store(&mut store)?;
 
-        // error = treu
-        return true;
-    }
+    Ok(())
+}
 
-    // time isn't proper
-    if NaiveTime::parse_from_str(edit_end.last().unwrap().as_str(), "%H:%M").is_err() {
-        writeln!(
-            writer,
-            "ERROR: The time you provided, '{}', wasn't \
-            proper. It must be in the following 24-hour format: H:M, e.g., 13:08.",
-            edit_end.last().unwrap()
-        )
-        .expect("writeln failed");
+// chartodo rp-ec 1 each. see CatchUp for what each one does
+pub fn repeating_tasks_edit_catch_up(position: &str, catch_up: &str) -> Result<(), ChartodoError> {
+    // open file and parse
+    let mut store = open_task_store()?;
+
+    // check the position
+    let position = store.position_from_arg(
+        PositionsIn::RepeatingTodo,
+        position,
+        "The repeating todo list is currently empty, so there are no \
+        todos that can be edited.",
+    )?;
+
+    let catch_up = match catch_up.parse::<CatchUp>() {
+        Ok(catch_up) => catch_up,
+        Err(catch_up_error) => {
+            return Err(ChartodoError::InvalidTimeUnit(format!(
+                "{}.",
+                catch_up_error
+            )));
+        }
+    };
+    store.set_catch_up(position, catch_up)?;
 
-        // error = true
-        return true;
-    }
+    // write changes to file
+    save_task_store(&mut store)?;
 
-    // get the updated datetimes from the given ending datetime
-    let position: usize = edit_end.first().unwrap().parse::<usize>().unwrap() - 1;
-    let (date, time, repeat_original_date, repeat_original_time) =
-        subract_from_given_ending_datetime(
-            edit_end.get(1).unwrap().to_string(),
-            edit_end.last().unwrap().to_string(),
-            repeating_tasks
-                .todo
-                .get(position)
-                .unwrap()
-                .repeat_number
-                .unwrap(),
-            repeating_tasks
-                .todo
-                .get(position)
-                .unwrap()
-                .repeat_unit
-                .as_ref()
-                .unwrap()
-                .to_string(),
-        );
+    Ok(())
+}
 
-    // get the task and edit
-    repeating_tasks.todo.get_mut(position).unwrap().date = Some(date);
-    repeating_tasks.todo.get_mut(position).unwrap().time = Some(time);
-    repeating_tasks
-        .todo
-        .get_mut(position)
-        .unwrap()
-        .repeat_original_date = Some(repeat_original_date);
-    repeating_tasks
-        .todo
-        .get_mut(position)
-        .unwrap()
-        .repeat_original_time = Some(repeat_original_time);
+// e.g., remaining: 3, for a series that ends. nothing for one that repeats forever, so those look the same as ever
+fn format_remaining(task: &Task) -> String {
+    match task.repeat().and_then(|repeat| repeat.remaining()) {
+        Some(remaining) if remaining >= MAX_COUNTED_OCCURRENCES => {
+            format!("\tremaining: {} or more\n", MAX_COUNTED_OCCURRENCES)
+        }
+        Some(remaining) => format!("\tremaining: {}\n", remaining),
+        None => String::new(),
+    }
+}
 
-    // write changes to file
-    write_changes_to_new_repeating_tasks(repeating_tasks);
+// the start of a new repeating task is only ever shown down to the minute
+fn local_now_to_the_minute() -> NaiveDateTime {
+    let now = Local::now().naive_local();
 
-    // error = false
-    false
+    now.with_second(0)
+        .and_then(|now| now.with_nanosecond(0))
+        .unwrap_or(now)
+}
+
+// e.g., 2099-01-01 00:00
+fn format_start(task: &Task) -> String {
+    task.repeat()
+        .map(|repeat| {
+            format!(
+                "{} {}",
+                repeat.start.format(DATE_FORMAT),
+                repeat.start.format(TIME_FORMAT)
+            )
+        })
+        .unwrap_or_default()
 }
 
 // note that it's starting to dawn on me that this style of design + testing is too restrictive and not flexible enough
@@ -2405,101 +1031,62 @@ pub fn repeating_tasks_edit_end(edit_end: Vec<String>) -> bool {
 
 // note that I want it to be more flexible in the future
 
-// cargo test repeating_todo_unit_tests -- --test-threads=1
-#[cfg(test)]
-mod repeating_todo_unit_tests {
-    use super::*;
-    use anyhow::Context;
-    use std::path::PathBuf;
+pub fn repeating_tasks_edit_priority(position: &str, priority: &str) -> Result<(), ChartodoError> {
+    // open file and parse
+    let mut store = open_task_store()?;
 
-    // these are taken from repeating_helpers
-    fn path_to_repeating_tasks() -> PathBuf {
-        // get the data dir XDG spec and return it with path to repeating_tasks.json
-        let mut repeating_tasks_path = dirs::data_dir()
-            .context(
-                "linux: couldn't get $HOME/.local/share/
-                    windows: couldn't get C:/Users/your_user/AppData/Local/
-                    mac: couldn't get /Users/your_user/Library/Application Support/
+    // check the args and edit the task
+    edit_priority_of_todo(&mut store, TaskList::Repeating, position, priority)?;
 
-                    those directories should exist for your OS. please double check that they do.",
-            )
-            .expect("something went wrong with fetching the user's data dirs");
-        repeating_tasks_path.push("chartodo/repeating_tasks.json");
+    // write changes to file
+    save_task_store(&mut store)?;
 
-        repeating_tasks_path
-    }
+    Ok(())
+}
 
-    fn repeating_tasks_copy_path() -> PathBuf {
-        // get the path for repeating_tasks_copy.json, which will be used to hold the original contents
-        // of repeating_tasks.json while it's getting modified
-        let mut repeating_tasks_copy_path = dirs::data_dir()
-            .context(
-                "linux: couldn't get $HOME/.local/share/
-                    windows: couldn't get C:/Users/your_user/AppData/Local/
-                    mac: couldn't get /Users/your_user/Library/Application Support/
+pub fn repeating_tasks_tag(position_and_tags: Vec<String>) -> Result<(), ChartodoError> {
+    // open file and parse
+    let mut store = open_task_store()?;
 
-                    those directories should exist for your OS. please double check that they do.",
-            )
-            .expect("something went wrong with fetching the user's data dirs");
-        repeating_tasks_copy_path.push("chartodo/repeating_tasks_copy.json");
+    // check the args and tag the task
+    tag_todo(
+        &mut store,
+        TaskList::Repeating,
+        &position_and_tags,
+        "repeating-tag",
+    )?;
 
-        repeating_tasks_copy_path
-    }
+    // write changes to file
+    save_task_store(&mut store)?;
 
-    // these have been tested in other fns, these are just included here as a sanity check
-    #[test]
-    fn repeating_tasks_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/repeating_tasks.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/repeating_tasks.json";
-        let mac_path = "/Library/Application Support/chartodo/repeating_tasks.json";
-        let mut got_repeating_tasks_path: bool = false;
-        let repeating_path = path_to_repeating_tasks();
-        let repeating_path = repeating_path.to_str().unwrap();
-
-        if repeating_path.contains(linux_path)
-            | repeating_path.contains(windows_path)
-            | repeating_path.contains(mac_path)
-        {
-            got_repeating_tasks_path = true;
-        }
+    Ok(())
+}
+
+pub fn repeating_tasks_untag(position_and_tags: Vec<String>) -> Result<(), ChartodoError> {
+    // open file and parse
+    let mut store = open_task_store()?;
 
-        assert!(got_repeating_tasks_path);
-    }
+    // check the args and untag the task
+    untag_todo(
+        &mut store,
+        TaskList::Repeating,
+        &position_and_tags,
+        "repeating-untag",
+    )?;
 
-    #[test]
-    fn repeating_tasks_copy_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/repeating_tasks_copy.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/repeating_tasks_copy.json";
-        let mac_path = "/Library/Application Support/chartodo/repeating_tasks_copy.json";
-        let mut got_repeating_tasks_copy_path: bool = false;
-        let repeating_tasks_copy_path = repeating_tasks_copy_path();
-        let repeating_tasks_copy_path = repeating_tasks_copy_path.to_str().unwrap();
-
-        if repeating_tasks_copy_path.contains(linux_path)
-            | repeating_tasks_copy_path.contains(windows_path)
-            | repeating_tasks_copy_path.contains(mac_path)
-        {
-            got_repeating_tasks_copy_path = true;
-        }
+    // write changes to file
+    save_task_store(&mut store)?;
 
-        assert!(got_repeating_tasks_copy_path);
-    }
+    Ok(())
+}
 
-    #[test]
-    fn aaaa_repeating_tasks_clone_file() {
-        // name is aaaa so it's done first
-        // since we will be modifying the original file to run a test, the original data must be
-        // preserved first
-        std::fs::File::create(repeating_tasks_copy_path())
-            .context("failed to create repeating_tasks_copy.json")
-            .expect("failed to create a copy during unit test");
-
-        std::fs::copy(path_to_repeating_tasks(), repeating_tasks_copy_path())
-            .context("failed to copy repeating_tasks.json to repeating_tasks_copy.json")
-            .expect("failed to copy original file to copy file during unit test");
-    }
+// cargo test repeating_todo_unit_tests
+#[cfg(test)]
+mod repeating_todo_unit_tests {
+    use super::super::repeating_helpers::*;
+    use super::*;
+    use crate::functions::storage_helpers::test_data_dir;
+    use anyhow::Context;
 
     #[test]
     fn repeating_tasks_add_incorrect_num_of_args() {
@@ -2511,7 +1098,9 @@ mod repeating_todo_unit_tests {
             String::from("another"),
             String::from("2"),
         ];
-        let error_should_be_true = repeating_tasks_add(arguments);
+        let error_should_be_true = repeating_sets(&arguments)
+            .and_then(|add| repeating_tasks_add(add, None, vec![], RepeatRules::default()))
+            .is_err();
 
         assert!(error_should_be_true);
     }
@@ -2527,7 +1116,9 @@ mod repeating_todo_unit_tests {
             String::from("2"),
             String::from("seconds"),
         ];
-        let error_should_be_true = repeating_tasks_add(arguments);
+        let error_should_be_true = repeating_sets(&arguments)
+            .and_then(|add| repeating_tasks_add(add, None, vec![], RepeatRules::default()))
+            .is_err();
 
         assert!(error_should_be_true);
     }
@@ -2543,7 +1134,9 @@ mod repeating_todo_unit_tests {
             String::from("4294967296"), // one more than max of u32, 4294967295
             String::from("days"),
         ];
-        let error_should_be_true = repeating_tasks_add(arguments);
+        let error_should_be_true = repeating_sets(&arguments)
+            .and_then(|add| repeating_tasks_add(add, None, vec![], RepeatRules::default()))
+            .is_err();
 
         assert!(error_should_be_true);
     }
@@ -2559,13 +1152,17 @@ mod repeating_todo_unit_tests {
             String::from("0"),
             String::from("days"),
         ];
-        let error_should_be_true = repeating_tasks_add(arguments);
+        let error_should_be_true = repeating_sets(&arguments)
+            .and_then(|add| repeating_tasks_add(add, None, vec![], RepeatRules::default()))
+            .is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_add_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2587,7 +1184,9 @@ mod repeating_todo_unit_tests {
             String::from("1"),
             String::from("day"),
         ];
-        let error_should_be_false = repeating_tasks_add(arguments);
+        let error_should_be_false = repeating_sets(&arguments)
+            .and_then(|add| repeating_tasks_add(add, None, vec![], RepeatRules::default()))
+            .is_err();
         // impossible to test the contents since the result of the fn is dependent on the current day and time
         // i can however test the content results of rp-as and rp-ae
 
@@ -2596,6 +1195,8 @@ mod repeating_todo_unit_tests {
 
     #[test]
     fn repeating_tasks_add_multiple_args_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2620,7 +1221,9 @@ mod repeating_todo_unit_tests {
             String::from("21"),
             String::from("years"),
         ];
-        let error_should_be_false = repeating_tasks_add(arguments);
+        let error_should_be_false = repeating_sets(&arguments)
+            .and_then(|add| repeating_tasks_add(add, None, vec![], RepeatRules::default()))
+            .is_err();
         // impossible to test the contents since the result of the fn is dependent on the current day and time
         // i can however test the content results of rp-as and rp-ae
 
@@ -2643,7 +1246,11 @@ mod repeating_todo_unit_tests {
             String::from("days"),
             String::from("2022-12-24"),
         ];
-        let error_should_be_true = repeating_tasks_add_start_datetime(arguments);
+        let error_should_be_true = repeating_start_sets(&arguments)
+            .and_then(|add| {
+                repeating_tasks_add_start_datetime(add, None, vec![], RepeatRules::default())
+            })
+            .is_err();
 
         assert!(error_should_be_true);
     }
@@ -2663,7 +1270,11 @@ mod repeating_todo_unit_tests {
             String::from("2022-12-24"),
             String::from("13:26"),
         ];
-        let error_should_be_true = repeating_tasks_add_start_datetime(arguments);
+        let error_should_be_true = repeating_start_sets(&arguments)
+            .and_then(|add| {
+                repeating_tasks_add_start_datetime(add, None, vec![], RepeatRules::default())
+            })
+            .is_err();
 
         assert!(error_should_be_true);
     }
@@ -2683,7 +1294,11 @@ mod repeating_todo_unit_tests {
             String::from("2022-12-24"),
             String::from("13:26"),
         ];
-        let error_should_be_true = repeating_tasks_add_start_datetime(arguments);
+        let error_should_be_true = repeating_start_sets(&arguments)
+            .and_then(|add| {
+                repeating_tasks_add_start_datetime(add, None, vec![], RepeatRules::default())
+            })
+            .is_err();
 
         assert!(error_should_be_true);
     }
@@ -2706,7 +1321,11 @@ mod repeating_todo_unit_tests {
             String::from("2022-12-24"),
             String::from("13:26"),
         ];
-        let error_should_be_true = repeating_tasks_add_start_datetime(arguments);
+        let error_should_be_true = repeating_start_sets(&arguments)
+            .and_then(|add| {
+                repeating_tasks_add_start_datetime(add, None, vec![], RepeatRules::default())
+            })
+            .is_err();
 
         assert!(error_should_be_true);
     }
@@ -2726,7 +1345,11 @@ mod repeating_todo_unit_tests {
             String::from("2022-12-24"),
             String::from("13:26"),
         ];
-        let error_should_be_true = repeating_tasks_add_start_datetime(arguments);
+        let error_should_be_true = repeating_start_sets(&arguments)
+            .and_then(|add| {
+                repeating_tasks_add_start_datetime(add, None, vec![], RepeatRules::default())
+            })
+            .is_err();
 
         assert!(error_should_be_true);
     }
@@ -2746,13 +1369,19 @@ mod repeating_todo_unit_tests {
             String::from("2022-12-24"),
             String::from("13:26"),
         ];
-        let error_should_be_true = repeating_tasks_add_start_datetime(arguments);
+        let error_should_be_true = repeating_start_sets(&arguments)
+            .and_then(|add| {
+                repeating_tasks_add_start_datetime(add, None, vec![], RepeatRules::default())
+            })
+            .is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_add_start_datetime_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2776,7 +1405,11 @@ mod repeating_todo_unit_tests {
             String::from("2021-01-01"),
             String::from("00:00"),
         ];
-        let error_should_be_false = repeating_tasks_add_start_datetime(arguments);
+        let error_should_be_false = repeating_start_sets(&arguments)
+            .and_then(|add| {
+                repeating_tasks_add_start_datetime(add, None, vec![], RepeatRules::default())
+            })
+            .is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -2810,6 +1443,8 @@ mod repeating_todo_unit_tests {
 
     #[test]
     fn repeating_tasks_add_start_datetime_multiple_args_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2838,7 +1473,11 @@ mod repeating_todo_unit_tests {
             String::from("2099-12-05"),
             String::from("13:26"),
         ];
-        let error_should_be_false = repeating_tasks_add_start_datetime(arguments);
+        let error_should_be_false = repeating_start_sets(&arguments)
+            .and_then(|add| {
+                repeating_tasks_add_start_datetime(add, None, vec![], RepeatRules::default())
+            })
+            .is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -2881,19 +1520,16 @@ mod repeating_todo_unit_tests {
     }
 
     #[test]
-    fn add_to_given_starting_datetime_is_correct() {
-        let (date, time, repeat_original_date, repeat_original_time) =
-            add_to_given_starting_datetime(
-                "2021-01-28".to_string(),
-                "13:13".to_string(),
-                5,
-                "months".to_string(),
-            );
-
-        assert_eq!(date, "2021-06-28".to_string());
-        assert_eq!(time, "13:13".to_string());
-        assert_eq!(repeat_original_date, "2021-01-28".to_string());
-        assert_eq!(repeat_original_time, "13:13".to_string());
+    fn repeat_starting_at_given_datetime_is_correct() {
+        let start = NaiveDateTime::parse_from_str("2021-01-28 13:13", "%Y-%m-%d %H:%M").unwrap();
+        let repeat = Repeat::starting_at(start, 5, RepeatUnit::Months).unwrap();
+
+        assert_eq!(
+            repeat.due.format("%Y-%m-%d %H:%M").to_string(),
+            "2021-06-28 13:13"
+        );
+        assert_eq!(repeat.start, start);
+        assert!(!repeat.done);
     }
 
     #[test]
@@ -2910,7 +1546,9 @@ mod repeating_todo_unit_tests {
             String::from("days"),
             String::from("2022-12-24"),
         ];
-        let error_should_be_true = repeating_tasks_add_end(arguments);
+        let error_should_be_true = repeating_end_sets(&arguments)
+            .and_then(|add| repeating_tasks_add_end(add, None, vec![], RepeatRules::default()))
+            .is_err();
 
         assert!(error_should_be_true);
     }
@@ -2930,7 +1568,9 @@ mod repeating_todo_unit_tests {
             String::from("2030-12-24"),
             String::from("13:26"),
         ];
-        let error_should_be_true = repeating_tasks_add_end(arguments);
+        let error_should_be_true = repeating_end_sets(&arguments)
+            .and_then(|add| repeating_tasks_add_end(add, None, vec![], RepeatRules::default()))
+            .is_err();
 
         assert!(error_should_be_true);
     }
@@ -2950,7 +1590,9 @@ mod repeating_todo_unit_tests {
             String::from("2030-13-24"),
             String::from("13:26"),
         ];
-        let error_should_be_true = repeating_tasks_add_end(arguments);
+        let error_should_be_true = repeating_end_sets(&arguments)
+            .and_then(|add| repeating_tasks_add_end(add, None, vec![], RepeatRules::default()))
+            .is_err();
 
         assert!(error_should_be_true);
     }
@@ -2970,7 +1612,9 @@ mod repeating_todo_unit_tests {
             String::from("2030-12-24"),
             String::from("13:26"),
         ];
-        let error_should_be_true = repeating_tasks_add_end(arguments);
+        let error_should_be_true = repeating_end_sets(&arguments)
+            .and_then(|add| repeating_tasks_add_end(add, None, vec![], RepeatRules::default()))
+            .is_err();
 
         assert!(error_should_be_true);
     }
@@ -2990,7 +1634,9 @@ mod repeating_todo_unit_tests {
             String::from("2030-12-24"),
             String::from("13:26"),
         ];
-        let error_should_be_true = repeating_tasks_add_end(arguments);
+        let error_should_be_true = repeating_end_sets(&arguments)
+            .and_then(|add| repeating_tasks_add_end(add, None, vec![], RepeatRules::default()))
+            .is_err();
 
         assert!(error_should_be_true);
     }
@@ -3010,13 +1656,17 @@ mod repeating_todo_unit_tests {
             String::from("2030-12-24"),
             String::from("13:26"),
         ];
-        let error_should_be_true = repeating_tasks_add_end(arguments);
+        let error_should_be_true = repeating_end_sets(&arguments)
+            .and_then(|add| repeating_tasks_add_end(add, None, vec![], RepeatRules::default()))
+            .is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_add_end_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3040,7 +1690,9 @@ mod repeating_todo_unit_tests {
             String::from("2021-01-01"),
             String::from("00:00"),
         ];
-        let error_should_be_false = repeating_tasks_add_end(arguments);
+        let error_should_be_false = repeating_end_sets(&arguments)
+            .and_then(|add| repeating_tasks_add_end(add, None, vec![], RepeatRules::default()))
+            .is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -3074,6 +1726,8 @@ mod repeating_todo_unit_tests {
 
     #[test]
     fn repeating_tasks_add_end_multiple_args_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3102,7 +1756,9 @@ mod repeating_todo_unit_tests {
             String::from("2223-01-03"),
             String::from("13:13"),
         ];
-        let error_should_be_false = repeating_tasks_add_end(arguments);
+        let error_should_be_false = repeating_end_sets(&arguments)
+            .and_then(|add| repeating_tasks_add_end(add, None, vec![], RepeatRules::default()))
+            .is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -3145,23 +1801,38 @@ mod repeating_todo_unit_tests {
     }
 
     #[test]
-    fn subtract_from_given_ending_datetime_is_correct() {
-        let (date, time, repeat_original_date, repeat_original_time) =
-            subract_from_given_ending_datetime(
-                "2013-12-13".to_string(),
-                "00:00".to_string(),
-                100,
-                "months".to_string(),
-            );
-
-        assert_eq!(date, "2013-12-13".to_string());
-        assert_eq!(time, "00:00".to_string());
-        assert_eq!(repeat_original_date, "2005-08-13".to_string());
-        assert_eq!(repeat_original_time, "00:00".to_string());
+    fn repeat_ending_at_given_datetime_is_correct() {
+        let end = NaiveDateTime::parse_from_str("2013-12-13 00:00", "%Y-%m-%d %H:%M").unwrap();
+        let repeat = Repeat::ending_at(end, 100, RepeatUnit::Months).unwrap();
+
+        assert_eq!(repeat.due, end);
+        assert_eq!(
+            repeat.start.format("%Y-%m-%d %H:%M").to_string(),
+            "2005-08-13 00:00"
+        );
+    }
+
+    #[test]
+    fn repeating_tasks_add_interval_too_big() {
+        let _data_dir = test_data_dir();
+
+        // chrono can't count 4294967295 years into the future
+        let arguments: Vec<String> = vec![
+            String::from("this-is-the-todo-list"),
+            String::from("4294967295"),
+            String::from("years"),
+        ];
+        let error_should_be_true = repeating_sets(&arguments)
+            .and_then(|add| repeating_tasks_add(add, None, vec![], RepeatRules::default()))
+            .is_err();
+
+        assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_done_todo_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3179,13 +1850,15 @@ mod repeating_todo_unit_tests {
 
         // check that repeating todo list is correctly identified as empty
         let arguments = vec![String::from("a")];
-        let error_should_be_true = repeating_tasks_done(arguments);
+        let error_should_be_true = repeating_tasks_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_done_no_valid_args() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3219,13 +1892,15 @@ mod repeating_todo_unit_tests {
             String::from("-1"),
             String::from("2"),
         ];
-        let error_should_be_true = repeating_tasks_done(arguments);
+        let error_should_be_true = repeating_tasks_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_done_should_do_repeatingdoneall() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3312,13 +1987,15 @@ mod repeating_todo_unit_tests {
             String::from("5"),
             String::from("6"),
         ];
-        let error_should_be_true = repeating_tasks_done(arguments);
+        let error_should_be_true = repeating_tasks_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_done_should_do_repeatingdoneall_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3397,7 +2074,7 @@ mod repeating_todo_unit_tests {
 
         // no valid args
         let arguments = vec![String::from("1-6"), String::from("1")];
-        let error_should_be_true = repeating_tasks_done(arguments);
+        let error_should_be_true = repeating_tasks_done(arguments).is_err();
 
         assert!(error_should_be_true);
     }
@@ -3418,6 +2095,8 @@ mod repeating_todo_unit_tests {
 
     #[test]
     fn repeating_tasks_done_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3446,7 +2125,7 @@ mod repeating_todo_unit_tests {
 
         // no valid args
         let arguments = vec![String::from("1"), String::from("2")];
-        let error_should_be_false = repeating_tasks_done(arguments);
+        let error_should_be_false = repeating_tasks_done(arguments).is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -3486,6 +2165,8 @@ mod repeating_todo_unit_tests {
     // (before it would just sort and write automatically) is that I think it speeds it up a little bit
     #[test]
     fn repeating_tasks_done_multiple_args_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3550,7 +2231,7 @@ mod repeating_todo_unit_tests {
             String::from("4"),
             String::from("1"),
         ];
-        let error_should_be_false = repeating_tasks_done(arguments);
+        let error_should_be_false = repeating_tasks_done(arguments).is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -3615,6 +2296,8 @@ mod repeating_todo_unit_tests {
 
     #[test]
     fn repeating_tasks_done_multiple_args_is_correct_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3674,7 +2357,7 @@ mod repeating_todo_unit_tests {
 
         // no valid args
         let arguments = vec![String::from("1-2"), String::from("4"), String::from("1")];
-        let error_should_be_false = repeating_tasks_done(arguments);
+        let error_should_be_false = repeating_tasks_done(arguments).is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -3739,6 +2422,8 @@ mod repeating_todo_unit_tests {
 
     #[test]
     fn repeating_tasks_reset_datetime_todo_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3756,13 +2441,16 @@ mod repeating_todo_unit_tests {
 
         // check that repeating todo list is correctly identified as empty
         let arguments = vec![String::from("a")];
-        let error_should_be_true = repeating_tasks_reset_original_datetime_to_now(arguments);
+        let error_should_be_true =
+            repeating_tasks_reset_original_datetime_to_now(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_reset_datetime_no_valid_args() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3796,13 +2484,16 @@ mod repeating_todo_unit_tests {
             String::from("-1"),
             String::from("2"),
         ];
-        let error_should_be_true = repeating_tasks_reset_original_datetime_to_now(arguments);
+        let error_should_be_true =
+            repeating_tasks_reset_original_datetime_to_now(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_reset_datetime_should_do_repeatingresetall() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3889,13 +2580,16 @@ mod repeating_todo_unit_tests {
             String::from("5"),
             String::from("6"),
         ];
-        let error_should_be_true = repeating_tasks_reset_original_datetime_to_now(arguments);
+        let error_should_be_true =
+            repeating_tasks_reset_original_datetime_to_now(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_reset_datetime_should_do_repeatingresetall_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3974,7 +2668,8 @@ mod repeating_todo_unit_tests {
 
         // no valid args
         let arguments = vec![String::from("1-6"), String::from("1")];
-        let error_should_be_true = repeating_tasks_reset_original_datetime_to_now(arguments);
+        let error_should_be_true =
+            repeating_tasks_reset_original_datetime_to_now(arguments).is_err();
 
         assert!(error_should_be_true);
     }
@@ -3985,6 +2680,8 @@ mod repeating_todo_unit_tests {
 
     #[test]
     fn repeating_tasks_reset_datetime_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4013,13 +2710,16 @@ mod repeating_todo_unit_tests {
 
         // no valid args
         let arguments = vec![String::from("1"), String::from("2")];
-        let error_should_be_false = repeating_tasks_reset_original_datetime_to_now(arguments);
+        let error_should_be_false =
+            repeating_tasks_reset_original_datetime_to_now(arguments).is_err();
 
         assert!(!error_should_be_false);
     }
 
     #[test]
     fn repeating_tasks_reset_datetime_multiple_args_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4084,13 +2784,16 @@ mod repeating_todo_unit_tests {
             String::from("4"),
             String::from("1"),
         ];
-        let error_should_be_false = repeating_tasks_reset_original_datetime_to_now(arguments);
+        let error_should_be_false =
+            repeating_tasks_reset_original_datetime_to_now(arguments).is_err();
 
         assert!(!error_should_be_false);
     }
 
     #[test]
     fn repeating_tasks_reset_datetime_multiple_args_is_correct_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4150,13 +2853,16 @@ mod repeating_todo_unit_tests {
 
         // no valid args
         let arguments = vec![String::from("1-2"), String::from("4"), String::from("1")];
-        let error_should_be_false = repeating_tasks_reset_original_datetime_to_now(arguments);
+        let error_should_be_false =
+            repeating_tasks_reset_original_datetime_to_now(arguments).is_err();
 
         assert!(!error_should_be_false);
     }
 
     #[test]
     fn repeating_tasks_rmtodo_todo_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4174,13 +2880,15 @@ mod repeating_todo_unit_tests {
 
         // check that repeating todo list is correctly identified as empty
         let arguments = vec![String::from("a")];
-        let error_should_be_true = repeating_tasks_rmtodo(arguments);
+        let error_should_be_true = repeating_tasks_rmtodo(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_rmtodo_no_valid_args() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4214,13 +2922,15 @@ mod repeating_todo_unit_tests {
             String::from("-1"),
             String::from("2"),
         ];
-        let error_should_be_true = repeating_tasks_rmtodo(arguments);
+        let error_should_be_true = repeating_tasks_rmtodo(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_rmtodo_should_do_repeatingcleartodo() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4307,13 +3017,15 @@ mod repeating_todo_unit_tests {
             String::from("5"),
             String::from("6"),
         ];
-        let error_should_be_true = repeating_tasks_rmtodo(arguments);
+        let error_should_be_true = repeating_tasks_rmtodo(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_rmtodo_should_do_repeatingcleartodo_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4392,13 +3104,15 @@ mod repeating_todo_unit_tests {
 
         // no valid args
         let arguments = vec![String::from("1-6"), String::from("1")];
-        let error_should_be_true = repeating_tasks_rmtodo(arguments);
+        let error_should_be_true = repeating_tasks_rmtodo(arguments).is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_rmtodo_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4437,7 +3151,7 @@ mod repeating_todo_unit_tests {
 
         // no valid args
         let arguments = vec![String::from("1"), String::from("3")];
-        let error_should_be_false = repeating_tasks_rmtodo(arguments);
+        let error_should_be_false = repeating_tasks_rmtodo(arguments).is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -4471,6 +3185,8 @@ mod repeating_todo_unit_tests {
 
     #[test]
     fn repeating_tasks_rmtodo_multiple_args_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4519,7 +3235,7 @@ mod repeating_todo_unit_tests {
 
         // no valid args
         let arguments = vec![String::from("1"), String::from("3"), String::from("-1")];
-        let error_should_be_false = repeating_tasks_rmtodo(arguments);
+        let error_should_be_false = repeating_tasks_rmtodo(arguments).is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -4553,6 +3269,8 @@ mod repeating_todo_unit_tests {
 
     #[test]
     fn repeating_tasks_rmtodo_multiple_args_is_correct_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4601,7 +3319,7 @@ mod repeating_todo_unit_tests {
 
         // no valid args
         let arguments = vec![String::from("1-2"), String::from("-1")];
-        let error_should_be_false = repeating_tasks_rmtodo(arguments);
+        let error_should_be_false = repeating_tasks_rmtodo(arguments).is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -4635,6 +3353,8 @@ mod repeating_todo_unit_tests {
 
     #[test]
     fn repeating_tasks_doneall_todo_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4651,13 +3371,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // check that repeating todo list is correctly identified as empty
-        let error_should_be_true = repeating_tasks_doneall();
+        let error_should_be_true = repeating_tasks_doneall().is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_doneall_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4716,7 +3438,7 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // no valid args
-        let error_should_be_false = repeating_tasks_doneall();
+        let error_should_be_false = repeating_tasks_doneall().is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -4780,6 +3502,8 @@ mod repeating_todo_unit_tests {
 
     #[test]
     fn repeating_tasks_cleartodo_todo_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4796,13 +3520,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // check that repeating todo list is correctly identified as empty
-        let error_should_be_true = repeating_tasks_clear_todo();
+        let error_should_be_true = repeating_tasks_clear_todo().is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_cleartodo_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4850,7 +3576,7 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // no valid args
-        let error_should_be_false = repeating_tasks_clear_todo();
+        let error_should_be_false = repeating_tasks_clear_todo().is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -4873,6 +3599,8 @@ mod repeating_todo_unit_tests {
 
     #[test]
     fn repeating_tasks_showstart_todo_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4894,15 +3622,17 @@ mod repeating_todo_unit_tests {
 
         assert_eq!(
             error_msg,
-            String::from(
-                "ERROR: The repeating todo list is currently empty. Try adding \
+            Err(ChartodoError::EmptyList(String::from(
+                "The repeating todo list is currently empty. Try adding \
                 items to it first.",
-            )
+            )))
         );
     }
 
     #[test]
     fn repeating_tasks_showstart_no_valid_args() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4940,16 +3670,18 @@ mod repeating_todo_unit_tests {
 
         assert_eq!(
             error_msg,
-            String::from(
-                "ERROR: None of the positions you \
+            Err(ChartodoError::InvalidPosition(String::from(
+                "None of the positions you \
                 provided were viable -- they were all either negative, zero, or exceeded the \
                 repeating todo list's length."
-            )
+            )))
         );
     }
 
     #[test]
     fn repeating_tasks_showstart_should_do_repeatingshowstartall() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5040,16 +3772,18 @@ mod repeating_todo_unit_tests {
 
         assert_eq!(
             error_msg,
-            String::from(
-                "WARNING: You want to show \
+            Err(ChartodoError::WholeListSpecified(String::from(
+                "You want to show \
                 the start times for an entire list that's relatively long. You should do \
                 repeating-startall."
-            )
+            )))
         );
     }
 
     #[test]
     fn repeating_tasks_showstart_should_do_repeatingshowstartall_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5132,16 +3866,18 @@ mod repeating_todo_unit_tests {
 
         assert_eq!(
             error_msg,
-            String::from(
-                "WARNING: You want to show \
+            Err(ChartodoError::WholeListSpecified(String::from(
+                "You want to show \
                 the start times for an entire list that's relatively long. You should do \
                 repeating-startall."
-            )
+            )))
         );
     }
 
     #[test]
     fn repeating_tasks_showstart_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5184,12 +3920,16 @@ mod repeating_todo_unit_tests {
 
         assert_eq!(
             error_msg,
-            String::from("task: this-is-the-todo-list\n\tstart: 2020-12-31 23:57")
+            Ok(String::from(
+                "task: this-is-the-todo-list\n\tstart: 2020-12-31 23:57"
+            ))
         );
     }
 
     #[test]
     fn repeating_tasks_showstart_multiple_args_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5232,12 +3972,14 @@ mod repeating_todo_unit_tests {
 
         assert_eq!(
             error_msg,
-            String::from("task: this-is-the-todo-list\n\tstart: 2020-12-31 23:57\ntask: hi\n\tstart: 2020-12-31 23:56")
+            Ok(String::from("task: this-is-the-todo-list\n\tstart: 2020-12-31 23:57\ntask: hi\n\tstart: 2020-12-31 23:56"))
         );
     }
 
     #[test]
     fn repeating_tasks_showstart_multiple_args_is_correct_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5280,12 +4022,14 @@ mod repeating_todo_unit_tests {
 
         assert_eq!(
             error_msg,
-            String::from("task: this-is-the-todo-list\n\tstart: 2020-12-31 23:57\ntask: hi\n\tstart: 2020-12-31 23:56")
+            Ok(String::from("task: this-is-the-todo-list\n\tstart: 2020-12-31 23:57\ntask: hi\n\tstart: 2020-12-31 23:56"))
         );
     }
 
     #[test]
     fn repeating_tasks_resetall_todo_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5302,7 +4046,7 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // check that repeating todo list is correctly identified as empty
-        let error_should_be_true = repeating_tasks_resetall();
+        let error_should_be_true = repeating_tasks_resetall().is_err();
 
         assert!(error_should_be_true);
     }
@@ -5311,6 +4055,8 @@ mod repeating_todo_unit_tests {
     // the fn's result is dependent on the current date and time
     #[test]
     fn repeating_tasks_resetall_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5369,13 +4115,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // no valid args
-        let error_should_be_false = repeating_tasks_resetall();
+        let error_should_be_false = repeating_tasks_resetall().is_err();
 
         assert!(!error_should_be_false);
     }
 
     #[test]
     fn repeating_tasks_showstartall_todo_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5396,15 +4144,17 @@ mod repeating_todo_unit_tests {
 
         assert_eq!(
             error_msg,
-            String::from(
-                "ERROR: The repeating todo list is currently empty. Try adding \
+            Err(ChartodoError::EmptyList(String::from(
+                "The repeating todo list is currently empty. Try adding \
                 items to it first.",
-            )
+            )))
         );
     }
 
     #[test]
     fn repeating_tasks_showstartall_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5446,15 +4196,17 @@ mod repeating_todo_unit_tests {
 
         assert_eq!(
             error_msg,
-            String::from(
+            Ok(String::from(
                 "task: this-is-the-todo-list\n\tstart: 2020-12-31 \
                 23:57\ntask: hi\n\tstart: 2020-12-31 23:56"
-            )
+            ))
         );
     }
 
     #[test]
     fn repeating_tasks_editall_todo_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5471,53 +4223,24 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("don't matter")];
-        let error_should_be_true = repeating_tasks_edit_all(arguments);
-
-        assert!(error_should_be_true);
-    }
-
-    #[test]
-    fn repeating_tasks_editall_invalid_num_of_args() {
-        // write fresh to repeating tasks so content is known. can't be empty
-        let fresh_repeating_tasks = r#"
-            {
-                "todo": [
-                    {
-                        "task": "hello",
-                        "date": "2025-01-01",
-                        "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
-                    }
-                ],
-                "done": []
-            }
-        "#;
-        let fresh_repeating_tasks: Tasks = serde_json::from_str(fresh_repeating_tasks)
-            .context(
-                "during testing: the fresh data to put in the new \
-                repeating_tasks file wasn't correct. you should never be able to see this",
-            )
-            .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
-
-        // perform actions
-        let arguments = vec![
-            String::from("don't matter"),
-            String::from("don't matter"),
-            String::from("don't matter"),
-        ];
-        let error_should_be_true = repeating_tasks_edit_all(arguments);
+        let error_should_be_true = repeating_tasks_edit_all(
+            "1",
+            "don't matter",
+            "don't matter",
+            "don't matter",
+            "don't matter",
+            "don't matter",
+            "don't matter",
+        )
+        .is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editall_position_not_a_num() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -5526,11 +4249,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -5545,22 +4268,24 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![
-            String::from("a"),
-            String::from("hello"),
-            String::from("2099-01-01"),
-            String::from("00:00"),
-            String::from("hello"),
-            String::from("2099-01-01"),
-            String::from("00:00"),
-        ];
-        let error_should_be_true = repeating_tasks_edit_all(arguments);
+        let error_should_be_true = repeating_tasks_edit_all(
+            "a",
+            "hello",
+            "2099-01-01",
+            "00:00",
+            "hello",
+            "2099-01-01",
+            "00:00",
+        )
+        .is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editall_position_is_zero() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -5569,11 +4294,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -5588,22 +4313,24 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![
-            String::from("0"),
-            String::from("hello"),
-            String::from("2099-01-01"),
-            String::from("00:00"),
-            String::from("hello"),
-            String::from("2099-01-01"),
-            String::from("00:00"),
-        ];
-        let error_should_be_true = repeating_tasks_edit_all(arguments);
+        let error_should_be_true = repeating_tasks_edit_all(
+            "0",
+            "hello",
+            "2099-01-01",
+            "00:00",
+            "hello",
+            "2099-01-01",
+            "00:00",
+        )
+        .is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editall_position_isnt_in_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -5612,11 +4339,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -5631,16 +4358,16 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![
-            String::from("2"),
-            String::from("hello"),
-            String::from("2099-01-01"),
-            String::from("00:00"),
-            String::from("hello"),
-            String::from("2099-01-01"),
-            String::from("00:00"),
-        ];
-        let error_should_be_true = repeating_tasks_edit_all(arguments);
+        let error_should_be_true = repeating_tasks_edit_all(
+            "2",
+            "hello",
+            "2099-01-01",
+            "00:00",
+            "hello",
+            "2099-01-01",
+            "00:00",
+        )
+        .is_err();
 
         assert!(error_should_be_true);
     }
@@ -5649,6 +4376,8 @@ mod repeating_todo_unit_tests {
 
     #[test]
     fn repeating_tasks_editall_interval_isnt_u32() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -5657,11 +4386,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -5676,22 +4405,24 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![
-            String::from("1"),
-            String::from("hello"),
-            String::from("2099-01-01"),
-            String::from("00:00"),
-            String::from("hello"),
-            String::from("2099-01-01"),
-            String::from("00:00"),
-        ];
-        let error_should_be_true = repeating_tasks_edit_all(arguments);
+        let error_should_be_true = repeating_tasks_edit_all(
+            "1",
+            "hello",
+            "2099-01-01",
+            "00:00",
+            "hello",
+            "2099-01-01",
+            "00:00",
+        )
+        .is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editall_interval_is_zero() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -5700,11 +4431,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -5719,22 +4450,17 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![
-            String::from("1"),
-            String::from("hello"),
-            String::from("0"),
-            String::from("00:00"),
-            String::from("hello"),
-            String::from("2099-01-01"),
-            String::from("00:00"),
-        ];
-        let error_should_be_true = repeating_tasks_edit_all(arguments);
+        let error_should_be_true =
+            repeating_tasks_edit_all("1", "hello", "0", "00:00", "hello", "2099-01-01", "00:00")
+                .is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editall_time_unit_invalid() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -5762,22 +4488,17 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![
-            String::from("1"),
-            String::from("hello"),
-            String::from("1"),
-            String::from("00:00"),
-            String::from("hello"),
-            String::from("2099-01-01"),
-            String::from("00:00"),
-        ];
-        let error_should_be_true = repeating_tasks_edit_all(arguments);
+        let error_should_be_true =
+            repeating_tasks_edit_all("1", "hello", "1", "00:00", "hello", "2099-01-01", "00:00")
+                .is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editall_must_be_start_or_end() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -5805,22 +4526,17 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![
-            String::from("1"),
-            String::from("hello"),
-            String::from("2"),
-            String::from("years"),
-            String::from("hello"),
-            String::from("2099-01-01"),
-            String::from("00:00"),
-        ];
-        let error_should_be_true = repeating_tasks_edit_all(arguments);
+        let error_should_be_true =
+            repeating_tasks_edit_all("1", "hello", "2", "years", "hello", "2099-01-01", "00:00")
+                .is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editall_date_invalid() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -5848,22 +4564,17 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![
-            String::from("1"),
-            String::from("hello"),
-            String::from("2"),
-            String::from("years"),
-            String::from("start"),
-            String::from("2023-13-01"),
-            String::from("00:00"),
-        ];
-        let error_should_be_true = repeating_tasks_edit_all(arguments);
+        let error_should_be_true =
+            repeating_tasks_edit_all("1", "hello", "2", "years", "start", "2023-13-01", "00:00")
+                .is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editall_time_invalid() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -5891,22 +4602,17 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![
-            String::from("1"),
-            String::from("hello"),
-            String::from("2"),
-            String::from("years"),
-            String::from("start"),
-            String::from("2023-1-01"),
-            String::from("25:00"),
-        ];
-        let error_should_be_true = repeating_tasks_edit_all(arguments);
+        let error_should_be_true =
+            repeating_tasks_edit_all("1", "hello", "2", "years", "start", "2023-1-01", "25:00")
+                .is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editall_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -5934,16 +4640,9 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![
-            String::from("1"),
-            String::from("hello"),
-            String::from("2"),
-            String::from("years"),
-            String::from("start"),
-            String::from("2023-1-01"),
-            String::from("00:00"),
-        ];
-        let error_should_be_false = repeating_tasks_edit_all(arguments);
+        let error_should_be_false =
+            repeating_tasks_edit_all("1", "hello", "2", "years", "start", "2023-1-01", "00:00")
+                .is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -5977,6 +4676,8 @@ mod repeating_todo_unit_tests {
 
     #[test]
     fn repeating_tasks_edittask_todo_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5993,53 +4694,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("don't matter")];
-        let error_should_be_true = repeating_tasks_edit_task(arguments);
-
-        assert!(error_should_be_true);
-    }
-
-    #[test]
-    fn repeating_tasks_edittask_invalid_num_of_args() {
-        // write fresh to repeating tasks so content is known. can't be empty
-        let fresh_repeating_tasks = r#"
-            {
-                "todo": [
-                    {
-                        "task": "hello",
-                        "date": "2025-01-01",
-                        "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
-                    }
-                ],
-                "done": []
-            }
-        "#;
-        let fresh_repeating_tasks: Tasks = serde_json::from_str(fresh_repeating_tasks)
-            .context(
-                "during testing: the fresh data to put in the new \
-                repeating_tasks file wasn't correct. you should never be able to see this",
-            )
-            .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
-
-        // perform actions
-        let arguments = vec![
-            String::from("don't matter"),
-            String::from("don't matter"),
-            String::from("don't matter"),
-        ];
-        let error_should_be_true = repeating_tasks_edit_task(arguments);
+        let error_should_be_true = repeating_tasks_edit_task("1", "don't matter").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_edittask_position_not_a_num() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -6048,11 +4711,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -6067,14 +4730,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("a"), String::from("hello")];
-        let error_should_be_true = repeating_tasks_edit_task(arguments);
+        let error_should_be_true = repeating_tasks_edit_task("a", "hello").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_edittask_position_is_zero() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -6083,11 +4747,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -6102,14 +4766,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("0"), String::from("hello")];
-        let error_should_be_true = repeating_tasks_edit_task(arguments);
+        let error_should_be_true = repeating_tasks_edit_task("0", "hello").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_edittask_position_isnt_in_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -6118,11 +4783,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -6137,14 +4802,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("2"), String::from("hello")];
-        let error_should_be_true = repeating_tasks_edit_task(arguments);
+        let error_should_be_true = repeating_tasks_edit_task("2", "hello").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_edittask_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -6172,8 +4838,7 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("1"), String::from("hi")];
-        let error_should_be_false = repeating_tasks_edit_task(arguments);
+        let error_should_be_false = repeating_tasks_edit_task("1", "hi").is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -6207,6 +4872,8 @@ mod repeating_todo_unit_tests {
 
     #[test]
     fn repeating_tasks_editinterval_todo_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -6223,53 +4890,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("don't matter")];
-        let error_should_be_true = repeating_tasks_edit_interval(arguments);
-
-        assert!(error_should_be_true);
-    }
-
-    #[test]
-    fn repeating_tasks_editinterval_invalid_num_of_args() {
-        // write fresh to repeating tasks so content is known. can't be empty
-        let fresh_repeating_tasks = r#"
-            {
-                "todo": [
-                    {
-                        "task": "hello",
-                        "date": "2025-01-01",
-                        "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
-                    }
-                ],
-                "done": []
-            }
-        "#;
-        let fresh_repeating_tasks: Tasks = serde_json::from_str(fresh_repeating_tasks)
-            .context(
-                "during testing: the fresh data to put in the new \
-                repeating_tasks file wasn't correct. you should never be able to see this",
-            )
-            .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
-
-        // perform actions
-        let arguments = vec![
-            String::from("don't matter"),
-            String::from("don't matter"),
-            String::from("don't matter"),
-        ];
-        let error_should_be_true = repeating_tasks_edit_interval(arguments);
+        let error_should_be_true = repeating_tasks_edit_interval("1", "don't matter").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editinterval_position_not_a_num() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -6278,11 +4907,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -6297,14 +4926,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("a"), String::from("hello")];
-        let error_should_be_true = repeating_tasks_edit_interval(arguments);
+        let error_should_be_true = repeating_tasks_edit_interval("a", "hello").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editinterval_position_is_zero() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -6313,11 +4943,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -6332,14 +4962,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("0"), String::from("hello")];
-        let error_should_be_true = repeating_tasks_edit_interval(arguments);
+        let error_should_be_true = repeating_tasks_edit_interval("0", "hello").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editinterval_position_isnt_in_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -6348,11 +4979,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -6367,14 +4998,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("2"), String::from("hello")];
-        let error_should_be_true = repeating_tasks_edit_interval(arguments);
+        let error_should_be_true = repeating_tasks_edit_interval("2", "hello").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editinterval_interval_isnt_u32() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -6383,11 +5015,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -6402,14 +5034,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("1"), String::from("hello")];
-        let error_should_be_true = repeating_tasks_edit_interval(arguments);
+        let error_should_be_true = repeating_tasks_edit_interval("1", "hello").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editinterval_interval_is_zero() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -6418,11 +5051,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -6437,14 +5070,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("1"), String::from("0")];
-        let error_should_be_true = repeating_tasks_edit_interval(arguments);
+        let error_should_be_true = repeating_tasks_edit_interval("1", "0").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editinterval_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -6472,8 +5106,7 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("1"), String::from("2")];
-        let error_should_be_false = repeating_tasks_edit_interval(arguments);
+        let error_should_be_false = repeating_tasks_edit_interval("1", "2").is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -6507,6 +5140,8 @@ mod repeating_todo_unit_tests {
 
     #[test]
     fn repeating_tasks_editunit_todo_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -6523,53 +5158,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("don't matter")];
-        let error_should_be_true = repeating_tasks_edit_time_unit(arguments);
-
-        assert!(error_should_be_true);
-    }
-
-    #[test]
-    fn repeating_tasks_editunit_invalid_num_of_args() {
-        // write fresh to repeating tasks so content is known. can't be empty
-        let fresh_repeating_tasks = r#"
-            {
-                "todo": [
-                    {
-                        "task": "hello",
-                        "date": "2025-01-01",
-                        "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
-                    }
-                ],
-                "done": []
-            }
-        "#;
-        let fresh_repeating_tasks: Tasks = serde_json::from_str(fresh_repeating_tasks)
-            .context(
-                "during testing: the fresh data to put in the new \
-                repeating_tasks file wasn't correct. you should never be able to see this",
-            )
-            .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
-
-        // perform actions
-        let arguments = vec![
-            String::from("don't matter"),
-            String::from("don't matter"),
-            String::from("don't matter"),
-        ];
-        let error_should_be_true = repeating_tasks_edit_time_unit(arguments);
+        let error_should_be_true = repeating_tasks_edit_time_unit("1", "don't matter").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editunit_position_not_a_num() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -6578,11 +5175,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -6597,14 +5194,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("a"), String::from("hello")];
-        let error_should_be_true = repeating_tasks_edit_time_unit(arguments);
+        let error_should_be_true = repeating_tasks_edit_time_unit("a", "hello").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editunit_position_is_zero() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -6613,11 +5211,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -6632,14 +5230,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("0"), String::from("hello")];
-        let error_should_be_true = repeating_tasks_edit_time_unit(arguments);
+        let error_should_be_true = repeating_tasks_edit_time_unit("0", "hello").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editunit_position_isnt_in_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -6648,11 +5247,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -6667,14 +5266,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("2"), String::from("hello")];
-        let error_should_be_true = repeating_tasks_edit_time_unit(arguments);
+        let error_should_be_true = repeating_tasks_edit_time_unit("2", "hello").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editunit_time_unit_invalid() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -6702,14 +5302,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("1"), String::from("hello")];
-        let error_should_be_true = repeating_tasks_edit_time_unit(arguments);
+        let error_should_be_true = repeating_tasks_edit_time_unit("1", "hello").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editunit_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -6737,8 +5338,7 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("1"), String::from("month")];
-        let error_should_be_false = repeating_tasks_edit_time_unit(arguments);
+        let error_should_be_false = repeating_tasks_edit_time_unit("1", "month").is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -6772,6 +5372,8 @@ mod repeating_todo_unit_tests {
 
     #[test]
     fn repeating_tasks_editintervalunit_todo_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -6788,49 +5390,16 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("don't matter")];
-        let error_should_be_true = repeating_tasks_edit_interval_unit(arguments);
-
-        assert!(error_should_be_true);
-    }
-
-    #[test]
-    fn repeating_tasks_editintervalunit_invalid_num_of_args() {
-        // write fresh to repeating tasks so content is known. can't be empty
-        let fresh_repeating_tasks = r#"
-            {
-                "todo": [
-                    {
-                        "task": "hello",
-                        "date": "2025-01-01",
-                        "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
-                    }
-                ],
-                "done": []
-            }
-        "#;
-        let fresh_repeating_tasks: Tasks = serde_json::from_str(fresh_repeating_tasks)
-            .context(
-                "during testing: the fresh data to put in the new \
-                repeating_tasks file wasn't correct. you should never be able to see this",
-            )
-            .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
-
-        // perform actions
-        let arguments = vec![String::from("don't matter"), String::from("don't matter")];
-        let error_should_be_true = repeating_tasks_edit_interval_unit(arguments);
+        let error_should_be_true =
+            repeating_tasks_edit_interval_unit("1", "don't matter", "don't matter").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editintervalunit_position_not_a_num() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -6839,11 +5408,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -6858,14 +5427,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("a"), String::from("hello"), String::from("hi")];
-        let error_should_be_true = repeating_tasks_edit_interval_unit(arguments);
+        let error_should_be_true = repeating_tasks_edit_interval_unit("a", "hello", "hi").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editintervalunit_position_is_zero() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -6874,11 +5444,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -6893,14 +5463,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("0"), String::from("hello"), String::from("hi")];
-        let error_should_be_true = repeating_tasks_edit_interval_unit(arguments);
+        let error_should_be_true = repeating_tasks_edit_interval_unit("0", "hello", "hi").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editintervalunit_position_isnt_in_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -6909,11 +5480,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -6928,14 +5499,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("2"), String::from("hello"), String::from("hi")];
-        let error_should_be_true = repeating_tasks_edit_interval_unit(arguments);
+        let error_should_be_true = repeating_tasks_edit_interval_unit("2", "hello", "hi").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editintervalunit_interval_isnt_u32() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -6944,11 +5516,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -6963,14 +5535,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("1"), String::from("hello"), String::from("hi")];
-        let error_should_be_true = repeating_tasks_edit_interval_unit(arguments);
+        let error_should_be_true = repeating_tasks_edit_interval_unit("1", "hello", "hi").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editintervalunit_interval_is_zero() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -6979,11 +5552,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -6998,14 +5571,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("1"), String::from("0"), String::from("hi")];
-        let error_should_be_true = repeating_tasks_edit_interval_unit(arguments);
+        let error_should_be_true = repeating_tasks_edit_interval_unit("1", "0", "hi").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editintervalunit_time_unit_invalid() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -7033,14 +5607,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("1"), String::from("2"), String::from("hi")];
-        let error_should_be_true = repeating_tasks_edit_interval_unit(arguments);
+        let error_should_be_true = repeating_tasks_edit_interval_unit("1", "2", "hi").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editintervalunit_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -7068,8 +5643,7 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("1"), String::from("2"), String::from("months")];
-        let error_should_be_false = repeating_tasks_edit_interval_unit(arguments);
+        let error_should_be_false = repeating_tasks_edit_interval_unit("1", "2", "months").is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -7103,6 +5677,8 @@ mod repeating_todo_unit_tests {
 
     #[test]
     fn repeating_tasks_editstart_todo_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -7119,49 +5695,16 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("don't matter")];
-        let error_should_be_true = repeating_tasks_edit_start(arguments);
-
-        assert!(error_should_be_true);
-    }
-
-    #[test]
-    fn repeating_tasks_editstart_invalid_num_of_args() {
-        // write fresh to repeating tasks so content is known. can't be empty
-        let fresh_repeating_tasks = r#"
-            {
-                "todo": [
-                    {
-                        "task": "hello",
-                        "date": "2025-01-01",
-                        "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
-                    }
-                ],
-                "done": []
-            }
-        "#;
-        let fresh_repeating_tasks: Tasks = serde_json::from_str(fresh_repeating_tasks)
-            .context(
-                "during testing: the fresh data to put in the new \
-                repeating_tasks file wasn't correct. you should never be able to see this",
-            )
-            .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
-
-        // perform actions
-        let arguments = vec![String::from("don't matter"), String::from("don't matter")];
-        let error_should_be_true = repeating_tasks_edit_start(arguments);
+        let error_should_be_true =
+            repeating_tasks_edit_start("1", "don't matter", "don't matter").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editstart_position_not_a_num() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -7170,11 +5713,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -7189,14 +5732,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("a"), String::from("hello"), String::from("hi")];
-        let error_should_be_true = repeating_tasks_edit_start(arguments);
+        let error_should_be_true = repeating_tasks_edit_start("a", "hello", "hi").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editstart_position_is_zero() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -7205,11 +5749,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -7224,14 +5768,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("0"), String::from("hello"), String::from("hi")];
-        let error_should_be_true = repeating_tasks_edit_start(arguments);
+        let error_should_be_true = repeating_tasks_edit_start("0", "hello", "hi").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editstart_position_isnt_in_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -7240,11 +5785,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -7259,14 +5804,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("2"), String::from("hello"), String::from("hi")];
-        let error_should_be_true = repeating_tasks_edit_start(arguments);
+        let error_should_be_true = repeating_tasks_edit_start("2", "hello", "hi").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editstart_date_invalid() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -7294,14 +5840,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("1"), String::from("hello"), String::from("2")];
-        let error_should_be_true = repeating_tasks_edit_start(arguments);
+        let error_should_be_true = repeating_tasks_edit_start("1", "hello", "2").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editstart_time_invalid() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -7329,18 +5876,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![
-            String::from("1"),
-            String::from("2023-01-01"),
-            String::from("2"),
-        ];
-        let error_should_be_true = repeating_tasks_edit_start(arguments);
+        let error_should_be_true = repeating_tasks_edit_start("1", "2023-01-01", "2").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editstart_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -7368,12 +5912,7 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![
-            String::from("1"),
-            String::from("2023-01-01"),
-            String::from("01:01"),
-        ];
-        let error_should_be_false = repeating_tasks_edit_start(arguments);
+        let error_should_be_false = repeating_tasks_edit_start("1", "2023-01-01", "01:01").is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -7407,6 +5946,8 @@ mod repeating_todo_unit_tests {
 
     #[test]
     fn repeating_tasks_editend_todo_is_empty() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -7423,49 +5964,16 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("don't matter")];
-        let error_should_be_true = repeating_tasks_edit_end(arguments);
-
-        assert!(error_should_be_true);
-    }
-
-    #[test]
-    fn repeating_tasks_editend_invalid_num_of_args() {
-        // write fresh to repeating tasks so content is known. can't be empty
-        let fresh_repeating_tasks = r#"
-            {
-                "todo": [
-                    {
-                        "task": "hello",
-                        "date": "2025-01-01",
-                        "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
-                    }
-                ],
-                "done": []
-            }
-        "#;
-        let fresh_repeating_tasks: Tasks = serde_json::from_str(fresh_repeating_tasks)
-            .context(
-                "during testing: the fresh data to put in the new \
-                repeating_tasks file wasn't correct. you should never be able to see this",
-            )
-            .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
-
-        // perform actions
-        let arguments = vec![String::from("don't matter"), String::from("don't matter")];
-        let error_should_be_true = repeating_tasks_edit_end(arguments);
+        let error_should_be_true =
+            repeating_tasks_edit_end("1", "don't matter", "don't matter").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editend_position_not_a_num() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -7474,11 +5982,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -7493,14 +6001,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("a"), String::from("hello"), String::from("hi")];
-        let error_should_be_true = repeating_tasks_edit_end(arguments);
+        let error_should_be_true = repeating_tasks_edit_end("a", "hello", "hi").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editend_position_is_zero() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -7509,11 +6018,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -7528,14 +6037,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("0"), String::from("hello"), String::from("hi")];
-        let error_should_be_true = repeating_tasks_edit_end(arguments);
+        let error_should_be_true = repeating_tasks_edit_end("0", "hello", "hi").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editend_position_isnt_in_range() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -7544,11 +6054,11 @@ mod repeating_todo_unit_tests {
                         "task": "hello",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -7563,14 +6073,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("2"), String::from("hello"), String::from("hi")];
-        let error_should_be_true = repeating_tasks_edit_end(arguments);
+        let error_should_be_true = repeating_tasks_edit_end("2", "hello", "hi").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editend_date_invalid() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -7598,14 +6109,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![String::from("1"), String::from("hello"), String::from("2")];
-        let error_should_be_true = repeating_tasks_edit_end(arguments);
+        let error_should_be_true = repeating_tasks_edit_end("1", "hello", "2").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editend_time_invalid() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -7633,18 +6145,15 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![
-            String::from("1"),
-            String::from("2023-01-01"),
-            String::from("2"),
-        ];
-        let error_should_be_true = repeating_tasks_edit_end(arguments);
+        let error_should_be_true = repeating_tasks_edit_end("1", "2023-01-01", "2").is_err();
 
         assert!(error_should_be_true);
     }
 
     #[test]
     fn repeating_tasks_editend_is_correct() {
+        let _data_dir = test_data_dir();
+
         // write fresh to repeating tasks so content is known. can't be empty
         let fresh_repeating_tasks = r#"
             {
@@ -7672,12 +6181,7 @@ mod repeating_todo_unit_tests {
         write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
 
         // perform actions
-        let arguments = vec![
-            String::from("1"),
-            String::from("2023-01-01"),
-            String::from("01:01"),
-        ];
-        let error_should_be_false = repeating_tasks_edit_end(arguments);
+        let error_should_be_false = repeating_tasks_edit_end("1", "2023-01-01", "01:01").is_err();
         let read_test_file = open_repeating_tasks_and_return_tasks_struct();
 
         // this should be the content of the file
@@ -7708,20 +6212,4 @@ mod repeating_todo_unit_tests {
         assert!(!error_should_be_false);
         assert_eq!(read_test_file, repeating_tasks);
     }
-
-    #[test]
-    fn zzzz_rename_copy_to_original() {
-        // name is zzzz so it's done last
-        // now that tests are done, remove the modified original and rename copy to original
-
-        std::fs::remove_file(path_to_repeating_tasks())
-            .context("failed delete modified repeating_tasks.json after running tests")
-            .expect("failed to delete repeating_tasks.json after repeating_helpers unit tests");
-
-        std::fs::rename(repeating_tasks_copy_path(), path_to_repeating_tasks())
-            .context("failed to rename repeating_tasks_copy to repeating_tasks")
-            .expect(
-                "failed to rename repeating_tasks_copy to repeating_tasks after tests were done",
-            );
-    }
 }
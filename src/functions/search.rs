@@ -0,0 +1,116 @@
+use crate::functions::{
+    errors::ChartodoError, json_file_structs::Task, task_ids::PositionsIn, task_store::TaskList,
+};
+use regex::{Regex, RegexBuilder};
+use std::fmt;
+
+// chartodo search looks through the text of every task, in every list and the archive, see TaskStore::search. either
+// way it ignores case, e.g., milk finds Buy-Milk
+
+#[derive(Debug, Clone)]
+pub enum SearchPattern {
+    // anywhere in the task, kept lowercased
+    Text(String),
+    // w/ --regex, e.g., ^buy-
+    Regex(Regex),
+}
+
+impl SearchPattern {
+    pub fn text(query: &str) -> SearchPattern {
+        SearchPattern::Text(query.to_lowercase())
+    }
+
+    pub fn regex(query: &str) -> Result<SearchPattern, ChartodoError> {
+        RegexBuilder::new(query)
+            .case_insensitive(true)
+            .build()
+            .map(SearchPattern::Regex)
+            .map_err(|regex_error| {
+                ChartodoError::InvalidPattern(format!(
+                    "'{}' isn't a proper regex: {}",
+                    query,
+                    // the first lines repeat the regex and point at where it went wrong, and the last one says what
+                    // it was, e.g., error: unclosed group
+                    regex_error
+                        .to_string()
+                        .lines()
+                        .last()
+                        .unwrap_or_default()
+                        .trim_start_matches("error: ")
+                ))
+            })
+    }
+
+    pub fn matches(&self, task: &str) -> bool {
+        match self {
+            SearchPattern::Text(text) => task.to_lowercase().contains(text.as_str()),
+            SearchPattern::Regex(regex) => regex.is_match(task),
+        }
+    }
+}
+
+// where a match was found. the position is the one the commands for that list take, e.g., done or archive-restore
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FoundIn {
+    List(PositionsIn),
+    Archive(TaskList),
+}
+
+// e.g., deadline todo, or repeating archive
+impl fmt::Display for FoundIn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FoundIn::List(list) => write!(f, "{}", list.name()),
+            FoundIn::Archive(list) => write!(f, "{} archive", list),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchMatch {
+    pub found_in: FoundIn,
+    pub position: usize,
+    pub task: Task,
+}
+
+// cargo test search_unit_tests
+#[cfg(test)]
+mod search_unit_tests {
+    use super::*;
+
+    #[test]
+    fn text_ignores_case() {
+        let pattern = SearchPattern::text("MiLk");
+        assert!(pattern.matches("buy-milk"));
+        assert!(pattern.matches("MILK-the-cow"));
+        assert!(!pattern.matches("buy-eggs"));
+        // it isn't a regex
+        assert!(!SearchPattern::text("^buy").matches("buy-milk"));
+    }
+
+    #[test]
+    fn regex_ignores_case() {
+        let pattern = SearchPattern::regex("^buy-(milk|eggs)$").unwrap();
+        assert!(pattern.matches("Buy-Eggs"));
+        assert!(!pattern.matches("buy-milk-later"));
+
+        let pattern_error = SearchPattern::regex("(unclosed").unwrap_err();
+        assert_eq!(pattern_error.code(), "invalid_pattern");
+        assert_eq!(
+            pattern_error.to_string(),
+            "'(unclosed' isn't a proper regex: unclosed group"
+        );
+    }
+
+    #[test]
+    fn found_in_is_shown() {
+        assert_eq!(
+            FoundIn::List(PositionsIn::DeadlineTodo).to_string(),
+            "deadline todo"
+        );
+        assert_eq!(
+            FoundIn::Archive(TaskList::Repeating).to_string(),
+            "repeating archive"
+        );
+    }
+}
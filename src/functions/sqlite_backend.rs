@@ -0,0 +1,602 @@
+use crate::functions::{
+    json_file_structs::*,
+    migrations::CURRENT_SCHEMA_VERSION,
+    recurrence::{CatchUp, RepeatOn},
+    storage_backend::*,
+    storage_helpers::*,
+};
+use anyhow::{bail, Context};
+use chrono::NaiveDateTime;
+use rusqlite::{params, Connection, OptionalExtension, Row, Transaction};
+use std::path::{Path, PathBuf};
+
+// the lists in chartodo.sqlite3 instead of chartodo.json, for CHARTODO_STORAGE=sqlite. every save is one transaction,
+// and the due date+time of every task is indexed, see tasks_due_between
+//
+// the first time it's used in a folder that has a chartodo.json, the tasks are copied over from it. chartodo.json is
+// left where it is, so going back to json is just a matter of unsetting CHARTODO_STORAGE, minus whatever was done
+// in the meantime
+
+pub const SQLITE_FILE_NAME: &str = "chartodo.sqlite3";
+
+// the tables in chartodo.sqlite3 have their own version, kept in sqlite's user_version. it's separate from
+// schema_version, which is about what a task can hold, so a new table doesn't have to touch chartodo.json
+//
+// whenever the tables change, bump this and add the statements that get there to TABLE_MIGRATIONS
+const CURRENT_TABLES_VERSION: i64 = 5;
+
+// TABLE_MIGRATIONS[0] goes from nothing to v1, and so on
+static TABLE_MIGRATIONS: &[&str] = &[
+    // v1: the tasks, plus next_id and schema_version. a task's place in its list is kept in position, since the
+    // regular lists are in the order they were added, not by date
+    "CREATE TABLE tasks (
+        id INTEGER NOT NULL,
+        list TEXT NOT NULL,
+        done INTEGER NOT NULL,
+        position INTEGER NOT NULL,
+        task TEXT NOT NULL,
+        due TEXT,
+        repeat_interval INTEGER,
+        repeat_unit TEXT,
+        repeat_done INTEGER,
+        repeat_start TEXT,
+        priority TEXT,
+        tags TEXT NOT NULL
+    );
+    CREATE INDEX tasks_by_due ON tasks (due);
+    CREATE TABLE meta (
+        key TEXT PRIMARY KEY,
+        value INTEGER NOT NULL
+    );",
+    // v2: a repeating task's rule, e.g., mon,wed,fri. see recurrence
+    "ALTER TABLE tasks ADD COLUMN repeat_on TEXT;",
+    // v3: what a repeating task does about occurrences it missed, e.g., each. nothing means once. see CatchUp
+    "ALTER TABLE tasks ADD COLUMN repeat_catch_up TEXT;",
+    // v4: when a repeating task's series ends, and which occurrence it's on. see RepeatRules
+    "ALTER TABLE tasks ADD COLUMN repeat_until TEXT;
+    ALTER TABLE tasks ADD COLUMN repeat_max_occurrences INTEGER;
+    ALTER TABLE tasks ADD COLUMN repeat_occurrence INTEGER;",
+    // v5: when a done task was done, see Retention
+    "ALTER TABLE tasks ADD COLUMN done_at TEXT;",
+];
+
+// the due date+time and repeat start are stored like this so that sorting them as text sorts them by date
+const SQLITE_DATE_TIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SqliteBackend {
+    path: PathBuf,
+    // chartodo.json in the same folder, for the first time it's used
+    json_path: PathBuf,
+}
+
+impl SqliteBackend {
+    pub fn new(chartodo_dir: &Path) -> SqliteBackend {
+        SqliteBackend {
+            path: chartodo_dir.join(SQLITE_FILE_NAME),
+            json_path: chartodo_dir.join(CHARTODO_FILE_NAME),
+        }
+    }
+
+    fn connect(&self) -> anyhow::Result<Connection> {
+        let connection = Connection::open(&self.path)
+            .with_context(|| format!("couldn't open {}", self.path.display()))?;
+        create_tables_if_needed(&connection)
+            .with_context(|| format!("couldn't set up {}", self.path.display()))?;
+
+        Ok(connection)
+    }
+
+    fn tables_version(&self) -> anyhow::Result<i64> {
+        let connection = Connection::open(&self.path)
+            .with_context(|| format!("couldn't open {}", self.path.display()))?;
+
+        Ok(connection.query_row("PRAGMA user_version", [], |row| row.get(0))?)
+    }
+}
+
+fn create_tables_if_needed(connection: &Connection) -> anyhow::Result<()> {
+    let tables_version: i64 = connection.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+    if tables_version > CURRENT_TABLES_VERSION {
+        bail!(
+            "its tables are at version {}, while this chartodo only understands up to {}",
+            tables_version,
+            CURRENT_TABLES_VERSION
+        );
+    }
+
+    for version in tables_version..CURRENT_TABLES_VERSION {
+        let transaction = connection.unchecked_transaction()?;
+        transaction
+            .execute_batch(TABLE_MIGRATIONS[version as usize])
+            .with_context(|| format!("couldn't upgrade its tables to version {}", version + 1))?;
+        transaction.pragma_update(None, "user_version", version + 1)?;
+        transaction.commit()?;
+    }
+
+    Ok(())
+}
+
+fn list_name(list: &str) -> anyhow::Result<fn(&mut ChartodoFile) -> &mut Tasks> {
+    match list {
+        "regular" => Ok(|chartodo_file| &mut chartodo_file.regular),
+        "deadline" => Ok(|chartodo_file| &mut chartodo_file.deadline),
+        "repeating" => Ok(|chartodo_file| &mut chartodo_file.repeating),
+        _ => bail!("'{}' isn't a list", list),
+    }
+}
+
+fn parse_sqlite_date_time(task: &str, datetime: &str) -> anyhow::Result<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(datetime, SQLITE_DATE_TIME_FORMAT).with_context(|| {
+        format!(
+            "'{}' has a date and time, '{}', that isn't in a year-month-day hour:minute format",
+            task, datetime
+        )
+    })
+}
+
+// one row of the tasks table, and which list it goes in
+fn task_from_row(row: &Row) -> anyhow::Result<(String, bool, Task)> {
+    let list: String = row.get("list")?;
+    let done: bool = row.get("done")?;
+    let task: String = row.get("task")?;
+    let due: Option<String> = row.get("due")?;
+
+    let mut new_task = match (list.as_str(), due) {
+        ("regular", None) => Task::regular(task),
+        ("deadline", Some(due)) => {
+            let due = parse_sqlite_date_time(&task, &due)?;
+            Task::deadline(task, due)
+        }
+        ("repeating", Some(due)) => {
+            let (Some(interval), Some(unit), Some(repeat_done), Some(start)) = (
+                row.get::<_, Option<u32>>("repeat_interval")?,
+                row.get::<_, Option<String>>("repeat_unit")?,
+                row.get::<_, Option<bool>>("repeat_done")?,
+                row.get::<_, Option<String>>("repeat_start")?,
+            ) else {
+                bail!(
+                    "'{}' is missing some of its repeat_interval, repeat_unit, repeat_done, and repeat_start",
+                    task
+                );
+            };
+
+            let unit: RepeatUnit = unit
+                .parse()
+                .map_err(|unit_error| anyhow::anyhow!("'{}': {}", task, unit_error))?;
+            let on = match row.get::<_, Option<String>>("repeat_on")? {
+                Some(on) => Some(
+                    on.parse::<RepeatOn>()
+                        .map_err(|on_error| anyhow::anyhow!("'{}': {}", task, on_error))?,
+                ),
+                None => None,
+            };
+            let catch_up = match row.get::<_, Option<String>>("repeat_catch_up")? {
+                Some(catch_up) => catch_up
+                    .parse::<CatchUp>()
+                    .map_err(|catch_up_error| anyhow::anyhow!("'{}': {}", task, catch_up_error))?,
+                None => CatchUp::Once,
+            };
+            if let Some(on) = on.filter(|on| on.unit() != unit) {
+                bail!(
+                    "'{}' repeats on {}, which only works w/ {}, not {}",
+                    task,
+                    on,
+                    on.unit(),
+                    unit
+                );
+            }
+
+            let until = match row.get::<_, Option<String>>("repeat_until")? {
+                Some(until) => Some(parse_sqlite_date_time(&task, &until)?),
+                None => None,
+            };
+
+            let repeat = Repeat {
+                interval,
+                unit,
+                on,
+                catch_up,
+                until,
+                max_occurrences: row.get("repeat_max_occurrences")?,
+                occurrence: row.get::<_, Option<u32>>("repeat_occurrence")?.unwrap_or(1),
+                done: repeat_done,
+                start: parse_sqlite_date_time(&task, &start)?,
+                due: parse_sqlite_date_time(&task, &due)?,
+            };
+            Task::repeating(task, repeat)
+        }
+        (list, _) => bail!(
+            "'{}' is in the {} list, but it doesn't have the due date+time that goes w/ it",
+            task,
+            list
+        ),
+    };
+
+    new_task.id = row.get("id")?;
+    new_task.priority = match row.get::<_, Option<String>>("priority")? {
+        Some(priority) => Some(priority.parse().map_err(|priority_error| {
+            anyhow::anyhow!("'{}': {}", new_task.task, priority_error)
+        })?),
+        None => None,
+    };
+    new_task.tags = serde_json::from_str(&row.get::<_, String>("tags")?)
+        .with_context(|| format!("'{}' has tags that aren't a list", new_task.task))?;
+    new_task.done_at = match row.get::<_, Option<String>>("done_at")? {
+        Some(done_at) => Some(parse_sqlite_date_time(&new_task.task, &done_at)?),
+        None => None,
+    };
+
+    Ok((list, done, new_task))
+}
+
+fn insert_tasks(
+    transaction: &Transaction,
+    list: &str,
+    done: bool,
+    tasks: &[Task],
+) -> anyhow::Result<()> {
+    let mut insert = transaction.prepare_cached(
+        "INSERT INTO tasks (id, list, done, position, task, due, repeat_interval, repeat_unit, repeat_done, \
+        repeat_start, repeat_on, repeat_catch_up, repeat_until, repeat_max_occurrences, repeat_occurrence, \
+        priority, tags, done_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, \
+        ?18)",
+    )?;
+
+    for (index, task) in tasks.iter().enumerate() {
+        let format = |datetime: NaiveDateTime| datetime.format(SQLITE_DATE_TIME_FORMAT).to_string();
+        let repeat = task.repeat();
+
+        insert.execute(params![
+            task.id,
+            list,
+            done,
+            (index + 1) as i64,
+            task.task,
+            task.due().map(format),
+            repeat.map(|repeat| repeat.interval),
+            repeat.map(|repeat| repeat.unit.to_string()),
+            repeat.map(|repeat| repeat.done),
+            repeat.map(|repeat| format(repeat.start)),
+            repeat.and_then(|repeat| repeat.on).map(|on| on.to_string()),
+            repeat
+                .filter(|repeat| repeat.catch_up != CatchUp::Once)
+                .map(|repeat| repeat.catch_up.to_string()),
+            repeat.and_then(|repeat| repeat.until).map(format),
+            repeat.and_then(|repeat| repeat.max_occurrences),
+            repeat
+                .filter(|repeat| repeat.max_occurrences.is_some())
+                .map(|repeat| repeat.occurrence),
+            task.priority.map(|priority| priority.to_string()),
+            serde_json::to_string(&task.tags)?,
+            task.done_at.map(format),
+        ])?;
+    }
+
+    Ok(())
+}
+
+impl StorageBackend for SqliteBackend {
+    fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn load(&self) -> Result<Option<(ChartodoFile, bool)>, LoadError> {
+        // the first time, whatever is in chartodo.json is brought over, and has to be saved
+        if !self.path.exists() {
+            return Ok(JsonBackend::new(self.json_path.clone())
+                .load()?
+                .map(|(chartodo_file, _)| (chartodo_file, true)));
+        }
+
+        // a file from a newer chartodo isn't broken, so it's left exactly where it is
+        match self.tables_version() {
+            Ok(tables_version) if tables_version > CURRENT_TABLES_VERSION => {
+                return Err(LoadError::TooNew(format!(
+                    "{} was saved by a newer version of chartodo (its tables are at version {}, while this one \
+                    only understands up to {}). Nothing was changed. Please update chartodo.",
+                    self.path.display(),
+                    tables_version,
+                    CURRENT_TABLES_VERSION
+                )));
+            }
+            Ok(_) => (),
+            Err(open_error) => return Err(LoadError::Broken(open_error)),
+        }
+
+        let load = || -> anyhow::Result<Option<(ChartodoFile, bool)>> {
+            let connection = self.connect()?;
+
+            let meta = |key: &str| -> anyhow::Result<Option<u32>> {
+                Ok(connection
+                    .query_row("SELECT value FROM meta WHERE key = ?1", [key], |row| {
+                        row.get(0)
+                    })
+                    .optional()?)
+            };
+            // nothing's been saved yet, same as an empty chartodo.json
+            let (Some(schema_version), Some(next_id)) = (meta("schema_version")?, meta("next_id")?)
+            else {
+                return Ok(None);
+            };
+            if schema_version > CURRENT_SCHEMA_VERSION {
+                bail!(
+                    "it's at schema_version {}, while this chartodo only understands up to {}",
+                    schema_version,
+                    CURRENT_SCHEMA_VERSION
+                );
+            }
+            // the tables already hold anything an older schema_version could, so there's nothing to move around.
+            // it's saved at the current one the next time, same as a chartodo.json that was upgraded
+            let upgraded = schema_version < CURRENT_SCHEMA_VERSION;
+
+            let mut chartodo_file = ChartodoFile {
+                schema_version: CURRENT_SCHEMA_VERSION,
+                next_id,
+                regular: Tasks {
+                    todo: vec![],
+                    done: vec![],
+                },
+                deadline: Tasks {
+                    todo: vec![],
+                    done: vec![],
+                },
+                repeating: Tasks {
+                    todo: vec![],
+                    done: vec![],
+                },
+            };
+
+            let mut select = connection.prepare("SELECT * FROM tasks ORDER BY position")?;
+            let mut rows = select.query([])?;
+            while let Some(row) = rows.next()? {
+                let (list, done, task) = task_from_row(row)?;
+                let tasks = list_name(&list)?(&mut chartodo_file);
+                match done {
+                    true => tasks.done.push(task),
+                    false => tasks.todo.push(task),
+                }
+            }
+
+            Ok(Some((chartodo_file, upgraded)))
+        };
+
+        match load() {
+            Ok(chartodo_file) => Ok(chartodo_file),
+            Err(load_error) => Err(LoadError::Broken(
+                load_error.context(format!("couldn't load {}", self.path.display())),
+            )),
+        }
+    }
+
+    fn save(&self, chartodo_file: &ChartodoFile) -> anyhow::Result<()> {
+        let mut connection = self.connect()?;
+
+        // every task is written again, but it's one transaction, so it's all or nothing
+        let transaction = connection.transaction()?;
+        transaction.execute("DELETE FROM tasks", [])?;
+        for (list, tasks) in [
+            ("regular", &chartodo_file.regular),
+            ("deadline", &chartodo_file.deadline),
+            ("repeating", &chartodo_file.repeating),
+        ] {
+            insert_tasks(&transaction, list, false, &tasks.todo)?;
+            insert_tasks(&transaction, list, true, &tasks.done)?;
+        }
+        for (key, value) in [
+            ("schema_version", chartodo_file.schema_version),
+            ("next_id", chartodo_file.next_id),
+        ] {
+            transaction.execute(
+                "INSERT INTO meta (key, value) VALUES (?1, ?2) \
+                ON CONFLICT (key) DO UPDATE SET value = excluded.value",
+                params![key, value],
+            )?;
+        }
+        transaction
+            .commit()
+            .with_context(|| format!("failed to write changes to {}", self.path.display()))?;
+
+        Ok(())
+    }
+
+    // w/ the index on due, this doesn't have to read every task
+    fn tasks_due_between(
+        &self,
+        from: NaiveDateTime,
+        to: NaiveDateTime,
+    ) -> Result<Vec<Task>, LoadError> {
+        if !self.path.exists() {
+            return Ok(vec![]);
+        }
+
+        let query = || -> anyhow::Result<Vec<Task>> {
+            let connection = self.connect()?;
+            let mut select = connection.prepare(
+                "SELECT * FROM tasks WHERE due BETWEEN ?1 AND ?2 ORDER BY due, \
+                CASE priority WHEN 'high' THEN 0 WHEN 'medium' THEN 1 WHEN 'low' THEN 2 ELSE 3 END",
+            )?;
+            let mut rows = select.query(params![
+                from.format(SQLITE_DATE_TIME_FORMAT).to_string(),
+                to.format(SQLITE_DATE_TIME_FORMAT).to_string()
+            ])?;
+
+            let mut due_tasks: Vec<Task> = vec![];
+            while let Some(row) = rows.next()? {
+                due_tasks.push(task_from_row(row)?.2);
+            }
+
+            Ok(due_tasks)
+        };
+
+        query().map_err(LoadError::Broken)
+    }
+}
+
+// cargo test sqlite_backend_unit_tests --features sqlite
+#[cfg(test)]
+mod sqlite_backend_unit_tests {
+    use super::*;
+
+    fn datetime(datetime: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(datetime, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    fn fresh_test_dir(test_name: &str) -> PathBuf {
+        let mut test_dir = std::env::temp_dir();
+        test_dir.push(format!(
+            "chartodo_sqlite_backend_unit_tests_{}_{}",
+            test_name,
+            std::process::id()
+        ));
+
+        // in case a previous run didn't clean up after itself
+        if test_dir.exists() {
+            std::fs::remove_dir_all(&test_dir).unwrap();
+        }
+        std::fs::create_dir(&test_dir).unwrap();
+
+        test_dir
+    }
+
+    // one of every kind of task, w/ everything a task can have
+    fn full_chartodo_file() -> ChartodoFile {
+        let mut chartodo_file = fresh_chartodo_file();
+        chartodo_file.regular.todo.push(
+            Task::regular(String::from("tagged"))
+                .with_priority(Some(Priority::Low))
+                .with_tags(&[String::from("+backend"), String::from("@errands")]),
+        );
+        chartodo_file.repeating.done.push(Task {
+            done_at: Some(datetime("2099-01-02 08:30")),
+            ..Task::repeating(
+                String::from("done"),
+                Repeat {
+                    done: true,
+                    ..Repeat::starting_at(datetime("2099-01-01 00:00"), 2, RepeatUnit::Weeks)
+                        .unwrap()
+                },
+            )
+        });
+        chartodo_file.repeating.todo.push(Task::repeating(
+            String::from("on a rule"),
+            Repeat {
+                catch_up: CatchUp::Each,
+                until: Some(datetime("2099-12-31 23:59")),
+                max_occurrences: Some(6),
+                occurrence: 2,
+                ..Repeat::starting_at_on(
+                    datetime("2099-01-01 00:00"),
+                    1,
+                    RepeatUnit::Months,
+                    Some("last fri".parse().unwrap()),
+                )
+                .unwrap()
+            },
+        ));
+        chartodo_file.give_ids_to_new_tasks();
+
+        chartodo_file
+    }
+
+    #[test]
+    fn save_and_load_are_correct() {
+        let test_dir = fresh_test_dir("save_and_load");
+        let backend = SqliteBackend::new(&test_dir);
+
+        // nothing saved yet, and no chartodo.json to bring over
+        assert!(backend.load().unwrap().is_none());
+
+        let chartodo_file = full_chartodo_file();
+        backend.save(&chartodo_file).unwrap();
+        let (loaded_file, upgraded) = backend.load().unwrap().unwrap();
+
+        assert!(!upgraded);
+        assert_eq!(loaded_file, chartodo_file);
+        // ids aren't part of a task's PartialEq
+        assert_eq!(
+            loaded_file.regular.todo[1].id,
+            chartodo_file.regular.todo[1].id
+        );
+        assert_eq!(loaded_file.next_id, chartodo_file.next_id);
+        // and neither is done_at
+        assert_eq!(
+            loaded_file.repeating.done[0].done_at,
+            Some(datetime("2099-01-02 08:30"))
+        );
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+
+    #[test]
+    fn chartodo_json_is_brought_over() {
+        let test_dir = fresh_test_dir("brought_over");
+        let chartodo_file = full_chartodo_file();
+        JsonBackend::new(test_dir.join(CHARTODO_FILE_NAME))
+            .save(&chartodo_file)
+            .unwrap();
+
+        let backend = SqliteBackend::new(&test_dir);
+        assert_eq!(backend.load().unwrap(), Some((chartodo_file, true)));
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+
+    #[test]
+    fn tasks_due_between_uses_the_dates() {
+        let test_dir = fresh_test_dir("due_between");
+        let backend = SqliteBackend::new(&test_dir);
+        backend.save(&full_chartodo_file()).unwrap();
+
+        // the example deadline task and the done repeating task
+        let due_tasks = backend
+            .tasks_due_between(datetime("2099-01-01 00:00"), datetime("2099-01-20 00:00"))
+            .unwrap();
+        let due_tasks: Vec<&str> = due_tasks.iter().map(|task| task.task.as_str()).collect();
+        assert_eq!(due_tasks, vec!["breathe-once-before-2099", "done"]);
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+
+    #[test]
+    fn newer_tables_are_too_new() {
+        let test_dir = fresh_test_dir("too_new");
+        let backend = SqliteBackend::new(&test_dir);
+        backend.save(&full_chartodo_file()).unwrap();
+
+        Connection::open(backend.path())
+            .unwrap()
+            .pragma_update(None, "user_version", CURRENT_TABLES_VERSION + 1)
+            .unwrap();
+
+        assert!(matches!(backend.load(), Err(LoadError::TooNew(_))));
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+
+    #[test]
+    fn older_tables_are_upgraded() {
+        let test_dir = fresh_test_dir("older_tables");
+        let backend = SqliteBackend::new(&test_dir);
+
+        // what a chartodo from before repeat rules left behind
+        let connection = Connection::open(backend.path()).unwrap();
+        connection.execute_batch(TABLE_MIGRATIONS[0]).unwrap();
+        connection.pragma_update(None, "user_version", 1).unwrap();
+        connection
+            .execute_batch(
+                "INSERT INTO tasks (id, list, done, position, task, tags) VALUES (1, 'regular', 0, 1, 'old', '[]');
+                INSERT INTO meta (key, value) VALUES ('schema_version', 5), ('next_id', 2);",
+            )
+            .unwrap();
+        drop(connection);
+
+        let (chartodo_file, upgraded) = backend.load().unwrap().unwrap();
+        assert!(upgraded);
+        assert_eq!(chartodo_file.schema_version, CURRENT_SCHEMA_VERSION);
+        assert_eq!(chartodo_file.regular.todo[0].task, "old");
+        assert_eq!(backend.tables_version().unwrap(), CURRENT_TABLES_VERSION);
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+}
@@ -0,0 +1,186 @@
+use crate::functions::{json_file_structs::*, storage_helpers::*};
+use chrono::NaiveDateTime;
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+// where the lists are kept between runs. chartodo.json is the default. w/ the sqlite feature, the lists can be kept in
+// chartodo.sqlite3 instead, see sqlite_backend. which one is used is decided by CHARTODO_STORAGE:
+// CHARTODO_STORAGE=sqlite chartodo list
+//
+// every backend holds the whole ChartodoFile. the commands only ever see that, so they don't care which one it is
+pub trait StorageBackend: fmt::Debug {
+    // the file the lists are kept in
+    fn path(&self) -> &Path;
+
+    // Ok(None) means there's nothing saved yet, i.e., no file or an empty one. otherwise it's the lists at the
+    // current version, and whether they had to be upgraded to get there, in which case they should be saved
+    fn load(&self) -> Result<Option<(ChartodoFile, bool)>, LoadError>;
+
+    // all or nothing. if chartodo gets killed halfway through, the old lists are still there
+    fn save(&self, chartodo_file: &ChartodoFile) -> anyhow::Result<()>;
+
+    // every task, in any list, that's due from from up to and including to, soonest first. regular tasks are never
+    // due, so they're never in it
+    fn tasks_due_between(
+        &self,
+        from: NaiveDateTime,
+        to: NaiveDateTime,
+    ) -> Result<Vec<Task>, LoadError> {
+        let Some((chartodo_file, _)) = self.load()? else {
+            return Ok(vec![]);
+        };
+
+        let mut due_tasks: Vec<Task> = [chartodo_file.deadline, chartodo_file.repeating]
+            .into_iter()
+            .flat_map(|tasks| tasks.todo.into_iter().chain(tasks.done))
+            .filter(|task| task.due().is_some_and(|due| from <= due && due <= to))
+            .collect();
+        due_tasks.sort_by_key(|task| (task.due(), task.priority_rank()));
+
+        Ok(due_tasks)
+    }
+}
+
+pub const CHARTODO_STORAGE_ENV: &str = "CHARTODO_STORAGE";
+
+// the backend for the chartodo folder. an empty CHARTODO_STORAGE is treated as not being set, same as CHARTODO_HOME
+pub fn storage_backend_in(chartodo_dir: &Path) -> Result<Box<dyn StorageBackend>, String> {
+    let storage = std::env::var(CHARTODO_STORAGE_ENV).unwrap_or_default();
+
+    storage_backend_named(&storage, chartodo_dir)
+}
+
+pub fn storage_backend_named(
+    storage: &str,
+    chartodo_dir: &Path,
+) -> Result<Box<dyn StorageBackend>, String> {
+    match storage {
+        "" | "json" => Ok(Box::new(JsonBackend::new(
+            chartodo_dir.join(CHARTODO_FILE_NAME),
+        ))),
+        #[cfg(feature = "sqlite")]
+        "sqlite" => Ok(Box::new(
+            crate::functions::sqlite_backend::SqliteBackend::new(chartodo_dir),
+        )),
+        #[cfg(not(feature = "sqlite"))]
+        "sqlite" => Err(format!(
+            "{}=sqlite, but this chartodo was built w/o sqlite. Reinstall it w/ cargo install chartodo \
+            --features sqlite, or unset {}.",
+            CHARTODO_STORAGE_ENV, CHARTODO_STORAGE_ENV
+        )),
+        _ => Err(format!(
+            "{}='{}' isn't a kind of storage. It has to be json or sqlite.",
+            CHARTODO_STORAGE_ENV, storage
+        )),
+    }
+}
+
+// chartodo.json, written atomically. see write_json_to_file
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonBackend {
+    path: PathBuf,
+}
+
+impl JsonBackend {
+    pub fn new(path: PathBuf) -> JsonBackend {
+        JsonBackend { path }
+    }
+}
+
+impl StorageBackend for JsonBackend {
+    fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn load(&self) -> Result<Option<(ChartodoFile, bool)>, LoadError> {
+        if !self.path.exists() {
+            return Ok(None);
+        }
+
+        load_chartodo_file(&self.path)
+    }
+
+    fn save(&self, chartodo_file: &ChartodoFile) -> anyhow::Result<()> {
+        try_write_json_to_file(&self.path, chartodo_file)
+    }
+}
+
+// cargo test storage_backend_unit_tests
+#[cfg(test)]
+mod storage_backend_unit_tests {
+    use super::*;
+
+    fn datetime(datetime: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(datetime, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    fn fresh_test_dir(test_name: &str) -> PathBuf {
+        let mut test_dir = std::env::temp_dir();
+        test_dir.push(format!(
+            "chartodo_storage_backend_unit_tests_{}_{}",
+            test_name,
+            std::process::id()
+        ));
+
+        // in case a previous run didn't clean up after itself
+        if test_dir.exists() {
+            std::fs::remove_dir_all(&test_dir).unwrap();
+        }
+        std::fs::create_dir(&test_dir).unwrap();
+
+        test_dir
+    }
+
+    #[test]
+    fn json_backend_is_correct() {
+        let test_dir = fresh_test_dir("json");
+        let backend = JsonBackend::new(test_dir.join(CHARTODO_FILE_NAME));
+
+        // nothing saved yet
+        assert!(backend.load().unwrap().is_none());
+
+        let mut chartodo_file = fresh_chartodo_file();
+        chartodo_file.give_ids_to_new_tasks();
+        backend.save(&chartodo_file).unwrap();
+
+        assert_eq!(backend.load().unwrap(), Some((chartodo_file, false)));
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+
+    #[test]
+    fn tasks_due_between_is_correct() {
+        let test_dir = fresh_test_dir("due_between");
+        let backend = JsonBackend::new(test_dir.join(CHARTODO_FILE_NAME));
+
+        let mut chartodo_file = fresh_chartodo_file();
+        chartodo_file.deadline.todo = vec![
+            Task::deadline(String::from("later"), datetime("2099-02-01 00:00")),
+            Task::deadline(String::from("sooner"), datetime("2099-01-01 00:00")),
+            Task::deadline(String::from("too late"), datetime("2099-03-01 00:00")),
+        ];
+        chartodo_file.give_ids_to_new_tasks();
+        backend.save(&chartodo_file).unwrap();
+
+        let due_tasks = backend
+            .tasks_due_between(datetime("2099-01-01 00:00"), datetime("2099-02-01 00:00"))
+            .unwrap();
+        let due_tasks: Vec<&str> = due_tasks.iter().map(|task| task.task.as_str()).collect();
+        assert_eq!(due_tasks, vec!["sooner", "later"]);
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+
+    #[test]
+    fn unknown_storage_is_an_error() {
+        assert!(storage_backend_named("yaml", Path::new("chartodo")).is_err());
+        assert_eq!(
+            storage_backend_named("", Path::new("chartodo"))
+                .unwrap()
+                .path(),
+            Path::new("chartodo").join(CHARTODO_FILE_NAME)
+        );
+    }
+}
@@ -0,0 +1,592 @@
+use crate::functions::{
+    config::config, deadline_tasks::deadline_helpers::fresh_deadline_tasks, errors::ChartodoError,
+    file_lock::*, json_file_structs::*, migrations::*,
+    regular_tasks::regular_helpers::fresh_regular_tasks,
+    repeating_tasks::repeating_helpers::fresh_repeating_tasks, storage_backend::*,
+    task_store::TaskStore,
+};
+use anyhow::Context;
+use chrono::Local;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+// every task file lives inside the chartodo folder. by default that's the following:
+// linux: $HOME/.local/share/chartodo/
+// windows: C:\Users\some_user\AppData\Local\chartodo\
+// mac: /Users/some_user/Library/Application Support/chartodo/
+//
+// the folder can be moved somewhere else, in order of priority, with:
+// 1) the --data-dir flag, e.g., chartodo --data-dir ~/work-todos list
+// 2) the CHARTODO_HOME environment variable, e.g., CHARTODO_HOME=~/work-todos chartodo list
+
+pub const CHARTODO_HOME_ENV: &str = "CHARTODO_HOME";
+
+// set once by main if the user passed --data-dir
+static DATA_DIR_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+pub fn set_data_dir_override(data_dir: PathBuf) {
+    // note: this can only be set once per run. main is the only thing that sets it, and it's done before any
+    // command is run, so a second set would be a bug. ignoring it is fine
+    let _ = DATA_DIR_OVERRIDE.set(data_dir);
+}
+
+pub fn path_to_chartodo_dir() -> Result<PathBuf, ChartodoError> {
+    // unit tests never get to the real chartodo folder, see test_data_dir
+    #[cfg(test)]
+    return Ok(TEST_DATA_DIR
+        .with(|test_data_dir| test_data_dir.borrow().clone())
+        .expect("a unit test has to call test_data_dir before it touches the chartodo folder"));
+
+    #[cfg(not(test))]
+    chartodo_dir_for_this_run()
+}
+
+fn chartodo_dir_for_this_run() -> Result<PathBuf, ChartodoError> {
+    if let Some(data_dir) = DATA_DIR_OVERRIDE.get() {
+        return Ok(data_dir.clone());
+    }
+
+    // an empty CHARTODO_HOME is treated as not being set
+    if let Some(chartodo_home) = std::env::var_os(CHARTODO_HOME_ENV) {
+        if !chartodo_home.is_empty() {
+            return Ok(PathBuf::from(chartodo_home));
+        }
+    }
+
+    // get the data dir XDG spec and return it with the chartodo folder. it's only missing if the os doesn't say where
+    // home is, e.g., $HOME isn't set
+    let mut chartodo_path = dirs::data_dir().ok_or_else(|| {
+        ChartodoError::Storage(
+            "couldn't find your OS's data dir, i.e., $HOME/.local/share/ on linux, \
+            C:/Users/your_user/AppData/Local/ on windows, or /Users/your_user/Library/Application Support/ on mac. \
+            Set CHARTODO_HOME or pass --data-dir to store your tasks somewhere else. Nothing was changed."
+                .to_string(),
+        )
+    })?;
+    chartodo_path.push("chartodo");
+
+    Ok(chartodo_path)
+}
+
+#[cfg(test)]
+thread_local! {
+    static TEST_DATA_DIR: std::cell::RefCell<Option<PathBuf>> = const { std::cell::RefCell::new(None) };
+}
+
+// unit tests run in parallel, each on its own thread, so each one that touches the lists gets its own chartodo folder
+// in the temp dir, named after the test. the folder is removed again when the test is done, i.e., when the returned
+// TestDataDir is dropped
+#[cfg(test)]
+pub fn test_data_dir() -> TestDataDir {
+    let test_name = std::thread::current()
+        .name()
+        .unwrap_or("unnamed")
+        .replace("::", "-");
+    let data_dir = std::env::temp_dir().join(format!(
+        "chartodo_unit_tests_{}_{}",
+        test_name,
+        std::process::id()
+    ));
+
+    // in case a previous run didn't clean up after itself
+    if data_dir.exists() {
+        std::fs::remove_dir_all(&data_dir).expect("couldn't remove old test data dir");
+    }
+    std::fs::create_dir(&data_dir).expect("couldn't create test data dir");
+    TEST_DATA_DIR.with(|test_data_dir| *test_data_dir.borrow_mut() = Some(data_dir.clone()));
+
+    TestDataDir(data_dir)
+}
+
+#[cfg(test)]
+pub struct TestDataDir(pub PathBuf);
+
+#[cfg(test)]
+impl Drop for TestDataDir {
+    fn drop(&mut self) {
+        TEST_DATA_DIR.with(|test_data_dir| *test_data_dir.borrow_mut() = None);
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+pub fn chartodo_dir_create_if_needed() -> Result<(), ChartodoError> {
+    let chartodo_path = path_to_chartodo_dir()?;
+
+    // check if chartodo folder exists. the folders leading up to it are created too, since --data-dir and
+    // CHARTODO_HOME can point anywhere, e.g., ~/todos/work
+    if !chartodo_path.exists() {
+        std::fs::create_dir_all(&chartodo_path).map_err(|create_error| {
+            ChartodoError::Storage(format!(
+                "couldn't create the chartodo dir {}: {}",
+                chartodo_path.display(),
+                create_error
+            ))
+        })?;
+    }
+
+    Ok(())
+}
+
+// the cli holds this for the whole command, see file_lock
+pub fn lock_chartodo_dir_for_command() -> Result<ChartodoLock, ChartodoError> {
+    chartodo_dir_create_if_needed()?;
+
+    lock_chartodo_dir(&path_to_chartodo_dir()?, lock_timeout()?)
+}
+
+// writes never touch the live file directly. the contents get written to a temporary file next to it, which is
+// fsynced and then renamed over the live file. a rename within the same folder is atomic, so if chartodo gets killed
+// halfway through, the live file is either the old version or the new version, never a truncated one
+pub fn write_json_to_file<T: Serialize>(json_path: &Path, contents: &T) {
+    try_write_json_to_file(json_path, contents).expect("failed to write changes to file");
+}
+
+// same as write_json_to_file, but the error is handed back instead of panicking, e.g., for TaskStore::save
+pub fn try_write_json_to_file<T: Serialize>(json_path: &Path, contents: &T) -> anyhow::Result<()> {
+    let contents = serde_json::to_vec_pretty(contents)
+        .with_context(|| format!("failed to write changes to {}", json_path.display()))?;
+
+    try_write_to_file(json_path, &contents)
+}
+
+// the same temporary file and rename as write_json_to_file, for files that aren't json, e.g., config.toml
+pub fn try_write_to_file(path: &Path, contents: &[u8]) -> anyhow::Result<()> {
+    let mut temp_file_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_file_name.push(".tmp");
+    let temp_path = path.with_file_name(temp_file_name);
+
+    let temp_file = File::create(&temp_path)
+        .with_context(|| format!("couldn't create temporary file {}", temp_path.display()))?;
+    let mut write_to_file = BufWriter::new(temp_file);
+    write_to_file
+        .write_all(contents)
+        .with_context(|| format!("failed to write changes to {}", temp_path.display()))?;
+    write_to_file
+        .flush()
+        .with_context(|| format!("failed to flush changes to {}", temp_path.display()))?;
+
+    // make sure the contents are actually on disk before the rename makes them live
+    write_to_file
+        .get_ref()
+        .sync_all()
+        .with_context(|| format!("failed to sync {} to disk", temp_path.display()))?;
+
+    std::fs::rename(&temp_path, path).with_context(|| {
+        format!(
+            "failed to move {} to {}",
+            temp_path.display(),
+            path.display()
+        )
+    })?;
+
+    // the rename itself lives in the folder, so sync that too. windows doesn't let you open folders like this
+    #[cfg(unix)]
+    if let Some(parent) = path.parent() {
+        if let Ok(dir) = File::open(parent) {
+            let _ = dir.sync_all();
+        }
+    }
+
+    Ok(())
+}
+
+// Ok(None) means the file exists but there is nothing in it. any other unparseable content is an error, since
+// there may be tasks in there that the user wants back
+pub fn try_read_json_from_file<T: DeserializeOwned>(json_path: &Path) -> anyhow::Result<Option<T>> {
+    let contents = std::fs::read_to_string(json_path)
+        .with_context(|| format!("couldn't open {}", json_path.display()))?;
+
+    if contents.trim().is_empty() {
+        return Ok(None);
+    }
+
+    let parsed: T = serde_json::from_str(&contents)
+        .with_context(|| format!("couldn't parse {}", json_path.display()))?;
+
+    Ok(Some(parsed))
+}
+
+// e.g., chartodo.json.20990101120000.corrupt, next to the file
+fn corrupt_path_for(json_path: &Path) -> anyhow::Result<PathBuf> {
+    let file_name = json_path
+        .file_name()
+        .context("file path has no file name")?
+        .to_string_lossy()
+        .to_string();
+
+    // the timestamp is so that an older backup never gets overwritten by a newer one
+    Ok(json_path.with_file_name(format!(
+        "{}.{}.corrupt",
+        file_name,
+        Local::now().format("%Y%m%d%H%M%S")
+    )))
+}
+
+// moves an unparseable file out of the way so that it doesn't get overwritten, and returns where it went. only for
+// files that aren't worth stopping the user over, e.g., the undo history. task files are copied instead, see
+// back_up_broken_file
+pub fn move_corrupt_file_aside(json_path: &Path) -> anyhow::Result<PathBuf> {
+    let corrupt_path = corrupt_path_for(json_path)?;
+
+    std::fs::rename(json_path, &corrupt_path).with_context(|| {
+        format!(
+            "couldn't move {} to {}",
+            json_path.display(),
+            corrupt_path.display()
+        )
+    })?;
+
+    Ok(corrupt_path)
+}
+
+// copies a task file that can't be read next to it, so that the tasks in it are still around even if the file gets
+// replaced later, e.g., by chartodo init after it's moved. a copy w/ the same contents that's already there is used
+// instead of making a new one every time a command runs into the file
+pub fn back_up_broken_file(json_path: &Path) -> anyhow::Result<PathBuf> {
+    let contents = std::fs::read(json_path)
+        .with_context(|| format!("couldn't read {}", json_path.display()))?;
+
+    let earlier_copy = corrupt_path_for(json_path)?
+        .parent()
+        .and_then(|chartodo_dir| std::fs::read_dir(chartodo_dir).ok())
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|copy_path| is_corrupt_copy_of(copy_path, json_path))
+        .find(|copy_path| std::fs::read(copy_path).is_ok_and(|copy| copy == contents));
+    if let Some(earlier_copy) = earlier_copy {
+        return Ok(earlier_copy);
+    }
+
+    let corrupt_path = corrupt_path_for(json_path)?;
+    std::fs::write(&corrupt_path, &contents).with_context(|| {
+        format!(
+            "couldn't copy {} to {}",
+            json_path.display(),
+            corrupt_path.display()
+        )
+    })?;
+
+    Ok(corrupt_path)
+}
+
+// e.g., chartodo.json.20990101120000.corrupt for chartodo.json
+fn is_corrupt_copy_of(copy_path: &Path, json_path: &Path) -> bool {
+    let (Some(copy_name), Some(file_name)) = (copy_path.file_name(), json_path.file_name()) else {
+        return false;
+    };
+    let copy_name = copy_name.to_string_lossy();
+
+    copy_name.starts_with(&format!("{}.", file_name.to_string_lossy()))
+        && copy_name.ends_with(".corrupt")
+}
+
+// nothing was written, and the file is left exactly where it is, so every command stops w/ this until it's fixed.
+// it's never swapped for a new one, since the user's tasks are still in it. a copy is made first, in case it's
+// swapped for one anyway, and the message says where
+pub fn broken_file_error(json_path: &Path, parse_error: anyhow::Error) -> ChartodoError {
+    let copy = match back_up_broken_file(json_path) {
+        Ok(corrupt_path) => format!("A copy of it was saved to {}.", corrupt_path.display()),
+        Err(copy_error) => format!(
+            "It couldn't be copied somewhere safe either: {:#}.",
+            copy_error
+        ),
+    };
+
+    ChartodoError::Storage(format!(
+        "{:#}. Nothing was changed. {} Please fix {}, or move it somewhere else and run chartodo init to start over.",
+        parse_error,
+        copy,
+        json_path.display()
+    ))
+}
+
+// all three lists live in one file, chartodo.json, inside the chartodo folder (or chartodo.sqlite3, see
+// storage_backend). older versions of chartodo stored them elsewhere, which gets upgraded the first time this version
+// runs. see migrations
+pub const CHARTODO_FILE_NAME: &str = "chartodo.json";
+
+pub fn path_to_chartodo_file() -> Result<PathBuf, ChartodoError> {
+    let mut chartodo_file_path = path_to_chartodo_dir()?;
+    chartodo_file_path.push(CHARTODO_FILE_NAME);
+
+    Ok(chartodo_file_path)
+}
+
+pub fn fresh_chartodo_file() -> ChartodoFile {
+    ChartodoFile {
+        schema_version: CURRENT_SCHEMA_VERSION,
+        next_id: 1,
+        regular: fresh_regular_tasks(),
+        deadline: fresh_deadline_tasks(),
+        repeating: fresh_repeating_tasks(),
+    }
+}
+
+// what a new chartodo.json starts w/. it's empty unless the example tasks are turned on w/ defaults.starter_tasks, see
+// config
+pub fn new_chartodo_file(starter_tasks: bool) -> ChartodoFile {
+    match starter_tasks {
+        true => fresh_chartodo_file(),
+        false => {
+            let empty = || Tasks {
+                todo: vec![],
+                done: vec![],
+            };
+
+            ChartodoFile {
+                regular: empty(),
+                deadline: empty(),
+                repeating: empty(),
+                ..fresh_chartodo_file()
+            }
+        }
+    }
+}
+
+pub fn chartodo_file_create_if_needed() -> Result<(), ChartodoError> {
+    // check if chartodo folder exists. if not, create it
+    chartodo_dir_create_if_needed()?;
+    upgrade_older_layouts_if_needed(&path_to_chartodo_dir()?)?;
+
+    // create the file if it doesn't exist. w/ sqlite, this is also when chartodo.json gets brought over
+    if !storage_backend()?.path().exists() {
+        open_chartodo_file()?;
+    }
+
+    Ok(())
+}
+
+// the backend CHARTODO_STORAGE asks for, in the chartodo folder. see storage_backend
+pub fn storage_backend() -> Result<Box<dyn StorageBackend>, ChartodoError> {
+    storage_backend_in(&path_to_chartodo_dir()?).map_err(ChartodoError::Storage)
+}
+
+// what can stop the lists from being loaded
+#[derive(Debug)]
+pub enum LoadError {
+    // it was saved by a newer chartodo. it isn't broken, so it should be left exactly where it is
+    TooNew(String),
+    // it couldn't be read, parsed, or upgraded. there may be tasks in there that the user wants back
+    Broken(anyhow::Error),
+}
+
+// Ok(None) means the file exists but there is nothing in it. otherwise it's the file at the current version, and
+// whether it had to be upgraded to get there
+pub fn load_chartodo_file(
+    chartodo_file_path: &Path,
+) -> Result<Option<(ChartodoFile, bool)>, LoadError> {
+    // parsed as plain json first, since an older file won't fit ChartodoFile until it's been migrated
+    let chartodo_json: Value = match try_read_json_from_file(chartodo_file_path) {
+        Ok(Some(chartodo_json)) => chartodo_json,
+        Ok(None) => return Ok(None),
+        Err(parse_error) => return Err(LoadError::Broken(parse_error)),
+    };
+
+    // a file from a newer chartodo isn't broken, so it's left exactly where it is
+    if let Ok(schema_version) = schema_version_of(&chartodo_json) {
+        if schema_version > CURRENT_SCHEMA_VERSION {
+            return Err(LoadError::TooNew(format!(
+                "{} was saved by a newer version of chartodo (schema_version {}, while this one only \
+                understands up to {}). Nothing was changed. Please update chartodo.",
+                chartodo_file_path.display(),
+                schema_version,
+                CURRENT_SCHEMA_VERSION
+            )));
+        }
+    }
+
+    match migrate_chartodo_file(chartodo_json) {
+        Ok(migrated_file) => Ok(Some(migrated_file)),
+        Err(migration_error) => Err(LoadError::Broken(
+            migration_error.context(format!("couldn't load {}", chartodo_file_path.display())),
+        )),
+    }
+}
+
+pub fn open_chartodo_file() -> Result<ChartodoFile, ChartodoError> {
+    let backend = storage_backend()?;
+
+    match backend.load() {
+        Ok(Some((mut chartodo_file, migrated))) => {
+            // save the upgrade so that it only has to happen once
+            if migrated {
+                write_chartodo_file(&mut chartodo_file)?;
+            }
+
+            Ok(chartodo_file)
+        }
+        Ok(None) => {
+            // this is to check if somehow the file exists but there is nothing in it
+            // if there is nothing in it, write some data
+            let mut fresh_chartodo_file = new_chartodo_file(config()?.defaults.starter_tasks);
+            write_chartodo_file(&mut fresh_chartodo_file)?;
+
+            Ok(fresh_chartodo_file)
+        }
+        Err(LoadError::TooNew(message)) => Err(ChartodoError::Storage(message)),
+        Err(LoadError::Broken(parse_error)) => Err(broken_file_error(backend.path(), parse_error)),
+    }
+}
+
+// the cli's way into a TaskStore. a file that can't be read comes back as a ChartodoError, so the command stops
+// before anything is changed and the error is shown like any other
+pub fn open_task_store() -> Result<TaskStore, ChartodoError> {
+    chartodo_file_create_if_needed()?;
+
+    let config = config()?;
+
+    Ok(TaskStore::new(storage_backend()?, open_chartodo_file()?)
+        .with_retention(config.retention)
+        .with_whole_list_threshold(config.defaults.whole_list_threshold))
+}
+
+pub fn save_task_store(store: &mut TaskStore) -> Result<(), ChartodoError> {
+    store.save()
+}
+
+// takes &mut since any new tasks are given their ids on the way out
+pub fn write_chartodo_file(chartodo_file: &mut ChartodoFile) -> Result<(), ChartodoError> {
+    chartodo_file.give_ids_to_new_tasks();
+    storage_backend()?
+        .save(chartodo_file)
+        .map_err(|write_error| ChartodoError::Storage(format!("{:#}", write_error)))
+}
+
+// cargo test storage_helpers_unit_tests
+#[cfg(test)]
+mod storage_helpers_unit_tests {
+    use super::*;
+
+    #[test]
+    fn chartodo_dir_defaults_to_data_dir() {
+        // only valid if the person running the tests doesn't have CHARTODO_HOME set
+        if std::env::var_os(CHARTODO_HOME_ENV).is_some() {
+            return;
+        }
+
+        let linux_path = "/.local/share/chartodo";
+        // note: windows is supposed to have \
+        let windows_path = "/AppData/Local/chartodo";
+        let mac_path = "/Library/Application Support/chartodo";
+        let chartodo_path = chartodo_dir_for_this_run().unwrap();
+        let chartodo_path = chartodo_path.to_str().unwrap();
+
+        assert!(
+            chartodo_path.ends_with(linux_path)
+                | chartodo_path.ends_with(windows_path)
+                | chartodo_path.ends_with(mac_path)
+        );
+    }
+
+    fn fresh_test_dir(test_name: &str) -> PathBuf {
+        let mut test_dir = std::env::temp_dir();
+        test_dir.push(format!(
+            "chartodo_storage_helpers_{}_{}",
+            test_name,
+            std::process::id()
+        ));
+        if test_dir.exists() {
+            std::fs::remove_dir_all(&test_dir).unwrap();
+        }
+        std::fs::create_dir(&test_dir).unwrap();
+
+        test_dir
+    }
+
+    #[test]
+    fn write_then_read_tasks_is_correct() {
+        let test_dir = fresh_test_dir("write_then_read");
+        let tasks_path = test_dir.join("regular_tasks.json");
+        let tasks: Tasks = serde_json::from_str(
+            r#"
+            {
+                "todo": [
+                    {
+                        "task": "write-me",
+                        "date": null,
+                        "time": null,
+                        "repeat_number": null,
+                        "repeat_unit": null,
+                        "repeat_done": null,
+                        "repeat_original_date": null,
+                        "repeat_original_time": null
+                    }
+                ],
+                "done": []
+            }
+        "#,
+        )
+        .unwrap();
+
+        write_json_to_file(&tasks_path, &tasks);
+
+        assert_eq!(try_read_json_from_file(&tasks_path).unwrap(), Some(tasks));
+        // the temporary file should have been renamed over the real one
+        assert!(!test_dir.join("regular_tasks.json.tmp").exists());
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+
+    #[test]
+    fn empty_file_reads_as_none() {
+        let test_dir = fresh_test_dir("empty_file");
+        let tasks_path = test_dir.join("regular_tasks.json");
+        std::fs::write(&tasks_path, "  \n").unwrap();
+
+        assert_eq!(try_read_json_from_file::<Tasks>(&tasks_path).unwrap(), None);
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+
+    #[test]
+    fn corrupt_file_is_an_error_and_gets_moved_aside() {
+        let test_dir = fresh_test_dir("corrupt_file");
+        let tasks_path = test_dir.join("regular_tasks.json");
+        std::fs::write(&tasks_path, r#"{"todo": [{"task": "half-writ"#).unwrap();
+
+        assert!(try_read_json_from_file::<Tasks>(&tasks_path).is_err());
+
+        let corrupt_path = move_corrupt_file_aside(&tasks_path).unwrap();
+        assert!(!tasks_path.exists());
+        assert!(corrupt_path.to_str().unwrap().ends_with(".corrupt"));
+        assert_eq!(
+            std::fs::read_to_string(&corrupt_path).unwrap(),
+            r#"{"todo": [{"task": "half-writ"#
+        );
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+
+    #[test]
+    fn broken_file_gets_copied_once() {
+        let test_dir = fresh_test_dir("broken_file_copy");
+        let tasks_path = test_dir.join("chartodo.json");
+        std::fs::write(&tasks_path, r#"{"todo": [{"task": "half-writ"#).unwrap();
+
+        let corrupt_path = back_up_broken_file(&tasks_path).unwrap();
+        assert!(tasks_path.exists());
+        assert!(corrupt_path.to_str().unwrap().ends_with(".corrupt"));
+        assert_eq!(
+            std::fs::read_to_string(&corrupt_path).unwrap(),
+            r#"{"todo": [{"task": "half-writ"#
+        );
+        // the same contents again don't get another copy
+        assert_eq!(back_up_broken_file(&tasks_path).unwrap(), corrupt_path);
+
+        // and the error says where the copy is
+        let broken_error = broken_file_error(&tasks_path, anyhow::anyhow!("couldn't parse it"));
+        assert!(broken_error
+            .to_string()
+            .contains(&corrupt_path.display().to_string()));
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+}
@@ -0,0 +1,213 @@
+use crate::functions::{
+    errors::ChartodoError,
+    json_file_structs::*,
+    storage_helpers::*,
+    task_store::{TaskList, TaskStore},
+};
+
+// every command that takes positions also takes #ids, e.g., chartodo done #12 3-5. the ids are swapped for the
+// positions of their tasks before the command runs, so the commands themselves only ever deal w/ positions
+
+// the list a command's positions point into
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PositionsIn {
+    RegularTodo,
+    RegularDone,
+    DeadlineTodo,
+    DeadlineDone,
+    RepeatingTodo,
+    RepeatingDone,
+}
+
+impl PositionsIn {
+    // e.g., regular todo
+    pub fn name(self) -> &'static str {
+        match self {
+            PositionsIn::RegularTodo => "regular todo",
+            PositionsIn::RegularDone => "regular done",
+            PositionsIn::DeadlineTodo => "deadline todo",
+            PositionsIn::DeadlineDone => "deadline done",
+            PositionsIn::RepeatingTodo => "repeating todo",
+            PositionsIn::RepeatingDone => "repeating done",
+        }
+    }
+
+    pub fn list(self) -> TaskList {
+        match self {
+            PositionsIn::RegularTodo | PositionsIn::RegularDone => TaskList::Regular,
+            PositionsIn::DeadlineTodo | PositionsIn::DeadlineDone => TaskList::Deadline,
+            PositionsIn::RepeatingTodo | PositionsIn::RepeatingDone => TaskList::Repeating,
+        }
+    }
+
+    pub(crate) fn tasks(self, chartodo_file: &ChartodoFile) -> &Vec<Task> {
+        match self {
+            PositionsIn::RegularTodo => &chartodo_file.regular.todo,
+            PositionsIn::RegularDone => &chartodo_file.regular.done,
+            PositionsIn::DeadlineTodo => &chartodo_file.deadline.todo,
+            PositionsIn::DeadlineDone => &chartodo_file.deadline.done,
+            PositionsIn::RepeatingTodo => &chartodo_file.repeating.todo,
+            PositionsIn::RepeatingDone => &chartodo_file.repeating.done,
+        }
+    }
+
+    pub(crate) fn tasks_mut(self, chartodo_file: &mut ChartodoFile) -> &mut Vec<Task> {
+        match self {
+            PositionsIn::RegularTodo => &mut chartodo_file.regular.todo,
+            PositionsIn::RegularDone => &mut chartodo_file.regular.done,
+            PositionsIn::DeadlineTodo => &mut chartodo_file.deadline.todo,
+            PositionsIn::DeadlineDone => &mut chartodo_file.deadline.done,
+            PositionsIn::RepeatingTodo => &mut chartodo_file.repeating.todo,
+            PositionsIn::RepeatingDone => &mut chartodo_file.repeating.done,
+        }
+    }
+}
+
+// errors if one of the ids isn't a proper id or doesn't belong to any task in the list
+pub fn replace_ids_with_positions(
+    list: PositionsIn,
+    positions: Vec<&mut String>,
+) -> Result<(), ChartodoError> {
+    // don't bother opening the file if there are no ids
+    if !positions.iter().any(|position| position.starts_with('#')) {
+        return Ok(());
+    }
+
+    // open file and parse
+    let store = open_task_store()?;
+
+    replace_ids_with_positions_in(&store, list, positions)
+}
+
+// same as replace_ids_with_positions, for lists that are already open
+pub fn replace_ids_with_positions_in(
+    store: &TaskStore,
+    list: PositionsIn,
+    positions: Vec<&mut String>,
+) -> Result<(), ChartodoError> {
+    for position in positions {
+        let Some(id) = position.strip_prefix('#') else {
+            continue;
+        };
+
+        // id isn't proper. note that ids start at 1, same as positions
+        let id = match id.parse::<u32>() {
+            Ok(id) if id != 0 => id,
+            _ => {
+                return Err(ChartodoError::InvalidId(format!("The id you provided, '{}', wasn't proper. It has to be # followed by the number \
+                    shown next to the task, e.g., #12.",
+                    position)));
+            }
+        };
+
+        // id isn't in the list
+        let Some(found_position) = store.position_of_id(list, id) else {
+            return Err(ChartodoError::InvalidId(format!(
+                "There's no task with the id '{}' in the {} list.",
+                position,
+                list.name()
+            )));
+        };
+
+        *position = found_position.to_string();
+    }
+
+    Ok(())
+}
+
+// cargo test task_ids_unit_tests
+#[cfg(test)]
+mod task_ids_unit_tests {
+    use super::*;
+
+    // hi, hello, and hey in the regular todo list of a store in the temp dir, and their ids
+    fn store_w_known_regular_tasks(test_name: &str) -> (TaskStore, Vec<u32>) {
+        let mut test_dir = std::env::temp_dir();
+        test_dir.push(format!(
+            "chartodo_task_ids_unit_tests_{}_{}",
+            test_name,
+            std::process::id()
+        ));
+
+        // in case a previous run didn't clean up after itself. nothing is saved, so nothing is written there
+        if test_dir.exists() {
+            std::fs::remove_dir_all(&test_dir).unwrap();
+        }
+
+        let mut store = TaskStore::open(&test_dir).unwrap();
+        let ids = ["hi", "hello", "hey"]
+            .iter()
+            .map(|task| store.add_regular(task))
+            .collect();
+
+        (store, ids)
+    }
+
+    #[test]
+    fn new_tasks_get_new_ids() {
+        let (_, ids) = store_w_known_regular_tasks("new_ids");
+
+        assert!(!ids.contains(&0));
+        assert!(ids[0] < ids[1] && ids[1] < ids[2]);
+    }
+
+    #[test]
+    fn ids_are_replaced_with_positions() {
+        let (store, ids) = store_w_known_regular_tasks("replaced");
+        let mut first = format!("#{}", ids[2]);
+        let mut second = String::from("1-2");
+        let mut third = format!("#{}", ids[0]);
+
+        let error_should_be_false = replace_ids_with_positions_in(
+            &store,
+            PositionsIn::RegularTodo,
+            vec![&mut first, &mut second, &mut third],
+        )
+        .is_err();
+
+        assert!(!error_should_be_false);
+        assert_eq!(first, "3");
+        assert_eq!(second, "1-2");
+        assert_eq!(third, "1");
+    }
+
+    #[test]
+    fn id_not_in_list_is_an_error() {
+        let (store, _) = store_w_known_regular_tasks("not_in_list");
+        let mut id = String::from("#4294967295");
+
+        let error_should_be_true =
+            replace_ids_with_positions_in(&store, PositionsIn::RegularTodo, vec![&mut id]).is_err();
+
+        assert!(error_should_be_true);
+    }
+
+    #[test]
+    fn id_in_another_list_is_an_error() {
+        let (store, ids) = store_w_known_regular_tasks("another_list");
+        let mut id = format!("#{}", ids[0]);
+
+        let error_should_be_true =
+            replace_ids_with_positions_in(&store, PositionsIn::RegularDone, vec![&mut id]).is_err();
+
+        assert!(error_should_be_true);
+    }
+
+    #[test]
+    fn improper_id_is_an_error() {
+        let (store, _) = store_w_known_regular_tasks("improper");
+        let mut not_a_number = String::from("#a");
+        let mut zero = String::from("#0");
+
+        assert!(replace_ids_with_positions_in(
+            &store,
+            PositionsIn::RegularTodo,
+            vec![&mut not_a_number]
+        )
+        .is_err());
+        assert!(
+            replace_ids_with_positions_in(&store, PositionsIn::RegularTodo, vec![&mut zero])
+                .is_err()
+        );
+    }
+}
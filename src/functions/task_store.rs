@@ -0,0 +1,2025 @@
+use crate::functions::{
+    archive::*,
+    config::*,
+    errors::{check_end_fits, check_rule_fits, interval_out_of_range, ChartodoError},
+    file_lock::{lock_chartodo_dir, lock_timeout, ChartodoLock},
+    general_helpers::{check_if_range_positioning, unwrap_range_positioning},
+    history::*,
+    json_file_structs::*,
+    migrations::upgrade_older_layouts_if_needed,
+    recurrence::{CatchUp, RepeatOn, RepeatRules},
+    search::{FoundIn, SearchMatch, SearchPattern},
+    storage_backend::*,
+    storage_helpers::*,
+    task_ids::PositionsIn,
+};
+use chrono::{Days, Local, NaiveDateTime, Timelike};
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+// everything chartodo can do to the lists, w/o any of the printing. a TaskStore is loaded once, changed w/ the
+// methods below, and only written back when save is called, so something that embeds chartodo, e.g., a tui or a
+// bot, can make several changes in one go:
+//
+//     let mut store = TaskStore::open_default()?;
+//     let id = store.add_regular("buy-milk");
+//     store.complete(TaskList::Regular, &[1])?;
+//     store.save()?;
+//
+// positions start at 1, same as in the cli, and an error means nothing was changed. the cli commands only check
+// what was typed, w/ their own messages, and then call these
+//
+// a store from open or open_default holds chartodo.lock until it's dropped, same as a cli command does, so a second
+// store for the same folder, or a chartodo running at the same time, waits for it. see file_lock
+
+// the three kinds of lists. each has a todo and a done list, see PositionsIn for pointing at just one of them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskList {
+    Regular,
+    Deadline,
+    Repeating,
+}
+
+impl TaskList {
+    pub fn todo(self) -> PositionsIn {
+        match self {
+            TaskList::Regular => PositionsIn::RegularTodo,
+            TaskList::Deadline => PositionsIn::DeadlineTodo,
+            TaskList::Repeating => PositionsIn::RepeatingTodo,
+        }
+    }
+
+    pub fn done(self) -> PositionsIn {
+        match self {
+            TaskList::Regular => PositionsIn::RegularDone,
+            TaskList::Deadline => PositionsIn::DeadlineDone,
+            TaskList::Repeating => PositionsIn::RepeatingDone,
+        }
+    }
+
+    fn of(task: &Task) -> TaskList {
+        match task.kind {
+            TaskKind::Regular => TaskList::Regular,
+            TaskKind::Deadline { .. } => TaskList::Deadline,
+            TaskKind::Repeating(_) => TaskList::Repeating,
+        }
+    }
+}
+
+// e.g., chartodo archive-restore deadline 1
+impl FromStr for TaskList {
+    type Err = String;
+
+    fn from_str(list: &str) -> Result<Self, Self::Err> {
+        match list {
+            "regular" => Ok(TaskList::Regular),
+            "deadline" => Ok(TaskList::Deadline),
+            "repeating" => Ok(TaskList::Repeating),
+            list => Err(format!(
+                "'{}' isn't a list. Try regular, deadline, or repeating",
+                list
+            )),
+        }
+    }
+}
+
+// e.g., in the history, see HistoryEvent
+impl fmt::Display for TaskList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list = match self {
+            TaskList::Regular => "regular",
+            TaskList::Deadline => "deadline",
+            TaskList::Repeating => "repeating",
+        };
+
+        write!(f, "{}", list)
+    }
+}
+
+// who's holding chartodo.lock for a store's folder
+#[derive(Debug)]
+enum FolderLock {
+    // whoever made the store w/ new, e.g., the cli holds it for the whole command
+    HeldByCaller,
+    // open found no folder yet, so save takes the lock once it's created it
+    OnSave,
+    // let go of when the store is dropped
+    Held { _lock: ChartodoLock },
+}
+
+// the folder the lists are saved in, if there's one to lock, i.e., not for a store that has nowhere to save to
+fn folder_of(lists_path: &Path) -> Option<&Path> {
+    lists_path
+        .parent()
+        .filter(|chartodo_dir| !chartodo_dir.as_os_str().is_empty())
+}
+
+fn lock_folder(chartodo_dir: &Path) -> Result<FolderLock, ChartodoError> {
+    Ok(FolderLock::Held {
+        _lock: lock_chartodo_dir(chartodo_dir, lock_timeout()?)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TaskStore {
+    // where save writes to, chartodo.json unless CHARTODO_STORAGE says otherwise. see storage_backend
+    backend: Box<dyn StorageBackend>,
+    file: ChartodoFile,
+    // what happened since the lists were loaded, added to the history when they're saved. see history
+    history: Vec<HistoryEvent>,
+    // how long done tasks are kept, from config.toml. see apply_retention
+    retention: Retention,
+    // how long a list has to be before giving every position in it is refused, from config.toml. see
+    // positions_from_args
+    whole_list_threshold: usize,
+    // done tasks that were taken out of the done lists, added to the archive when the lists are saved. see archive
+    archived: Archive,
+    // everything in archive.json, once something's needed from it, w/ archived already added. see load_archive
+    archive: Option<Archive>,
+    lock: FolderLock,
+}
+
+// the history keeps when things happened down to the second, see HistoryEvent
+fn now_to_the_second() -> NaiveDateTime {
+    let now = Local::now().naive_local();
+
+    now.with_nanosecond(0).unwrap_or(now)
+}
+
+// w/ CatchUp::Each, a task that was left for a long time would bury everything else, e.g., a task every 5 minutes
+// that wasn't looked at for a week. only the latest occurrences get a todo, and the rest are recorded as skipped
+const MAX_CATCH_UP_TODOS: usize = 100;
+
+impl TaskStore {
+    // loads the lists from the given folder, upgrading them if they're from an older chartodo. a folder w/o any
+    // starts empty unless config.toml turns on the example tasks, same as the cli, and nothing is written until save
+    // is called
+    pub fn open(chartodo_dir: &Path) -> Result<TaskStore, ChartodoError> {
+        // the upgrade writes to the folder too, so the lock is taken before it
+        let lock = match chartodo_dir.exists() {
+            true => {
+                let lock = lock_folder(chartodo_dir)?;
+                upgrade_older_layouts_if_needed(chartodo_dir)?;
+                lock
+            }
+            false => FolderLock::OnSave,
+        };
+
+        TaskStore::load(
+            storage_backend_in(chartodo_dir).map_err(ChartodoError::Storage)?,
+            lock,
+        )
+    }
+
+    // same as open, but for a backend that's already been picked, e.g., a SqliteBackend regardless of
+    // CHARTODO_STORAGE
+    pub fn open_backend(backend: Box<dyn StorageBackend>) -> Result<TaskStore, ChartodoError> {
+        let lock = match folder_of(backend.path()) {
+            Some(chartodo_dir) if chartodo_dir.exists() => lock_folder(chartodo_dir)?,
+            Some(_) => FolderLock::OnSave,
+            None => FolderLock::HeldByCaller,
+        };
+
+        TaskStore::load(backend, lock)
+    }
+
+    fn load(
+        backend: Box<dyn StorageBackend>,
+        lock: FolderLock,
+    ) -> Result<TaskStore, ChartodoError> {
+        // config.toml is looked for next to the lists, same as the history. it also decides whether a folder w/o
+        // any lists starts w/ the example tasks
+        let config = read_config(&backend.path().with_file_name(CONFIG_FILE_NAME)).map_err(
+            |config_error| {
+                ChartodoError::Storage(format!("{:#}. Nothing was changed.", config_error))
+            },
+        )?;
+
+        // same as the cli, a broken file is an error and is left alone, w/ a copy of it next to it
+        let store = match backend.load() {
+            Ok(Some((chartodo_file, _))) => TaskStore::new(backend, chartodo_file),
+            Ok(None) => TaskStore::new(backend, new_chartodo_file(config.defaults.starter_tasks)),
+            Err(LoadError::TooNew(message)) => return Err(ChartodoError::Storage(message)),
+            Err(LoadError::Broken(parse_error)) => {
+                return Err(broken_file_error(backend.path(), parse_error))
+            }
+        };
+
+        Ok(TaskStore { lock, ..store }
+            .with_retention(config.retention)
+            .with_whole_list_threshold(config.defaults.whole_list_threshold))
+    }
+
+    // the folder the cli uses, i.e., CHARTODO_HOME or the default one. see storage_helpers
+    pub fn open_default() -> Result<TaskStore, ChartodoError> {
+        TaskStore::open(&path_to_chartodo_dir()?)
+    }
+
+    // a store for lists that have already been loaded. it keeps done tasks the default way unless it's given a
+    // retention, see with_retention. it doesn't take chartodo.lock, so whoever loaded the lists should be holding it
+    pub fn new(backend: Box<dyn StorageBackend>, file: ChartodoFile) -> TaskStore {
+        TaskStore {
+            backend,
+            file,
+            history: vec![],
+            retention: Retention::default(),
+            whole_list_threshold: Defaults::default().whole_list_threshold,
+            archived: Archive::default(),
+            archive: None,
+            lock: FolderLock::HeldByCaller,
+        }
+    }
+
+    pub fn with_retention(mut self, retention: Retention) -> TaskStore {
+        self.retention = retention;
+        self
+    }
+
+    pub fn with_whole_list_threshold(mut self, whole_list_threshold: usize) -> TaskStore {
+        self.whole_list_threshold = whole_list_threshold;
+        self
+    }
+
+    pub fn save(&mut self) -> Result<(), ChartodoError> {
+        // same as the cli, the chartodo folder is created along w/ the folders leading up to it
+        if let Some(chartodo_dir) = self.backend.path().parent() {
+            if !chartodo_dir.as_os_str().is_empty() && !chartodo_dir.exists() {
+                std::fs::create_dir_all(chartodo_dir).map_err(|create_error| {
+                    ChartodoError::Storage(format!(
+                        "couldn't create the chartodo dir {}: {}",
+                        chartodo_dir.display(),
+                        create_error
+                    ))
+                })?;
+            }
+        }
+        if let (FolderLock::OnSave, Some(chartodo_dir)) =
+            (&self.lock, folder_of(self.backend.path()))
+        {
+            self.lock = lock_folder(chartodo_dir)?;
+        }
+
+        self.file.give_ids_to_new_tasks();
+        self.apply_retention(Local::now().naive_local());
+        self.backend
+            .save(&self.file)
+            .map_err(|write_error| ChartodoError::Storage(format!("{:#}", write_error)))?;
+
+        // the lists come first. a history or archive that's missing something is better than lists that are
+        append_history(&self.history_path(), &self.history)
+            .map_err(|write_error| ChartodoError::Storage(format!("{:#}", write_error)))?;
+        self.history.clear();
+        // once it's been loaded, e.g., to restore something, the whole archive is written back
+        match &self.archive {
+            Some(archive) => try_write_json_to_file(&self.archive_path(), archive),
+            None => append_to_archive(&self.archive_path(), &self.archived),
+        }
+        .map_err(|write_error| ChartodoError::Storage(format!("{:#}", write_error)))?;
+        self.archived = Archive::default();
+
+        Ok(())
+    }
+
+    // the file the lists are saved to
+    pub fn path(&self) -> &Path {
+        self.backend.path()
+    }
+
+    // history.jsonl, next to the lists
+    pub fn history_path(&self) -> PathBuf {
+        self.backend.path().with_file_name(HISTORY_FILE_NAME)
+    }
+
+    // archive.json, next to the lists
+    pub fn archive_path(&self) -> PathBuf {
+        self.backend.path().with_file_name(ARCHIVE_FILE_NAME)
+    }
+
+    pub fn retention(&self) -> Retention {
+        self.retention
+    }
+
+    pub fn backend(&self) -> &dyn StorageBackend {
+        self.backend.as_ref()
+    }
+
+    pub fn file(&self) -> &ChartodoFile {
+        &self.file
+    }
+
+    pub fn list(&self, list: TaskList) -> &Tasks {
+        match list {
+            TaskList::Regular => &self.file.regular,
+            TaskList::Deadline => &self.file.deadline,
+            TaskList::Repeating => &self.file.repeating,
+        }
+    }
+
+    pub fn regular(&self) -> &Tasks {
+        self.list(TaskList::Regular)
+    }
+
+    pub fn deadline(&self) -> &Tasks {
+        self.list(TaskList::Deadline)
+    }
+
+    pub fn repeating(&self) -> &Tasks {
+        self.list(TaskList::Repeating)
+    }
+
+    pub fn tasks(&self, list: PositionsIn) -> &[Task] {
+        list.tasks(&self.file)
+    }
+
+    pub fn position_of_id(&self, list: PositionsIn, id: u32) -> Option<usize> {
+        self.tasks(list)
+            .iter()
+            .position(|task| task.id == id)
+            .map(|index| index + 1)
+    }
+
+    fn list_mut(&mut self, list: TaskList) -> &mut Tasks {
+        match list {
+            TaskList::Regular => &mut self.file.regular,
+            TaskList::Deadline => &mut self.file.deadline,
+            TaskList::Repeating => &mut self.file.repeating,
+        }
+    }
+
+    // deadline and repeating tasks are always kept in order of when they're due, then by priority. regular tasks
+    // stay in the order they were added
+    fn sort(&mut self, list: TaskList) {
+        if list == TaskList::Regular {
+            return;
+        }
+
+        let tasks = self.list_mut(list);
+        tasks
+            .todo
+            .sort_by_key(|task| (task.due(), task.priority_rank()));
+        tasks
+            .done
+            .sort_by_key(|task| (task.due(), task.priority_rank()));
+    }
+
+    // every position has to point at a task. returns them sorted, w/o any repeats
+    fn check_positions(
+        &self,
+        list: PositionsIn,
+        positions: &[usize],
+    ) -> Result<Vec<usize>, ChartodoError> {
+        check_positions_in(list.name(), self.tasks(list).len(), positions)
+    }
+
+    // the positions as they were typed in the cli, e.g., 1 3-5, w/ the ranges unwrapped, sorted, and w/o repeats.
+    // anything that doesn't point at a task is left out, and it's an error if nothing is left, or if every task in a
+    // list longer than the whole_list_threshold was given, since there's a command for that, e.g., doneall
+    pub fn positions_from_args(
+        &self,
+        list: PositionsIn,
+        positions: &[String],
+        errors: &PositionErrors,
+    ) -> Result<Vec<usize>, ChartodoError> {
+        positions_from_args_in(
+            self.tasks(list).len(),
+            positions,
+            errors,
+            self.whole_list_threshold,
+        )
+    }
+
+    // same as positions_from_args, for the archive
+    pub fn archived_positions_from_args(
+        &mut self,
+        list: TaskList,
+        positions: &[String],
+        errors: &PositionErrors,
+    ) -> Result<Vec<usize>, ChartodoError> {
+        let archived_len = self.load_archive()?.list(list).len();
+
+        positions_from_args_in(archived_len, positions, errors, self.whole_list_threshold)
+    }
+
+    // one position as it was typed in the cli, e.g., for edit. empty is what's said if the list is empty
+    pub fn position_from_arg(
+        &self,
+        list: PositionsIn,
+        position: &str,
+        empty: &str,
+    ) -> Result<usize, ChartodoError> {
+        let tasks_len = self.tasks(list).len();
+        if tasks_len == 0 {
+            return Err(ChartodoError::EmptyList(empty.to_string()));
+        }
+
+        match position.parse::<usize>() {
+            Ok(position) if position != 0 && position <= tasks_len => Ok(position),
+            _ => Err(invalid_position(position, tasks_len)),
+        }
+    }
+
+    fn task_mut(&mut self, list: PositionsIn, position: usize) -> Result<&mut Task, ChartodoError> {
+        self.check_positions(list, &[position])?;
+
+        Ok(&mut list.tasks_mut(&mut self.file)[position - 1])
+    }
+
+    // the task goes in the todo list for its kind. it gets its id right away, which is returned, so that it can be
+    // found again w/ position_of_id
+    pub fn add(&mut self, mut task: Task) -> u32 {
+        let list = TaskList::of(&task);
+
+        self.file.give_ids_to_new_tasks();
+        task.id = self.file.next_id;
+        self.file.next_id += 1;
+
+        self.list_mut(list).todo.push(task);
+        self.sort(list);
+
+        self.file.next_id - 1
+    }
+
+    pub fn add_regular(&mut self, task: &str) -> u32 {
+        self.add(Task::regular(task.to_string()))
+    }
+
+    pub fn add_deadline(&mut self, task: &str, due: NaiveDateTime) -> u32 {
+        self.add(Task::deadline(task.to_string(), due))
+    }
+
+    // the task is first due an interval after start, or w/ a rule, on the first day after start that fits it
+    pub fn add_repeating(
+        &mut self,
+        task: &str,
+        interval: u32,
+        unit: RepeatUnit,
+        rules: RepeatRules,
+        start: NaiveDateTime,
+    ) -> Result<u32, ChartodoError> {
+        if interval == 0 {
+            return Err(ChartodoError::InvalidInterval(format!(
+                "The interval for '{}' can't be 0.",
+                task
+            )));
+        }
+        check_rule_fits(task, unit, rules.on)?;
+        let Some(repeat) = Repeat::starting_at_on(start, interval, unit, rules.on) else {
+            return Err(interval_out_of_range(task));
+        };
+
+        let repeat = repeat.with_rules(rules);
+        check_end_fits(task, &repeat)?;
+
+        Ok(self.add(Task::repeating(task.to_string(), repeat)))
+    }
+
+    // moves the tasks from todo to done. a repeating task stays done until it's due again, see roll_over_repeating
+    pub fn complete(&mut self, list: TaskList, positions: &[usize]) -> Result<(), ChartodoError> {
+        let positions = self.check_positions(list.todo(), positions)?;
+
+        let now = now_to_the_second();
+        let tasks = self.list_mut(list);
+        let mut completed = vec![];
+        // rev so that removing by position doesn't move the tasks that are still to go
+        for position in positions.iter().rev() {
+            let mut task = tasks.todo.remove(position - 1);
+            if let Some(repeat) = task.repeat_mut() {
+                repeat.done = true;
+            }
+            task.done_at = Some(now);
+            completed.push(HistoryEvent::completed(list, &task, now));
+            tasks.done.push(task);
+        }
+        // in the order they were in the list
+        self.history.extend(completed.into_iter().rev());
+        self.sort(list);
+
+        Ok(())
+    }
+
+    // a done list that gets too long is trimmed when it's saved, see apply_retention
+    pub fn complete_all(&mut self, list: TaskList) {
+        let now = now_to_the_second();
+        let tasks = self.list_mut(list);
+
+        let mut completed = vec![];
+        for mut task in std::mem::take(&mut tasks.todo) {
+            if let Some(repeat) = task.repeat_mut() {
+                repeat.done = true;
+            }
+            task.done_at = Some(now);
+            completed.push(HistoryEvent::completed(list, &task, now));
+            tasks.done.push(task);
+        }
+        self.history.extend(completed);
+        self.sort(list);
+    }
+
+    // moves the tasks from done back to todo
+    pub fn uncomplete(&mut self, list: TaskList, positions: &[usize]) -> Result<(), ChartodoError> {
+        let positions = self.check_positions(list.done(), positions)?;
+
+        let tasks = self.list_mut(list);
+        for position in positions.iter().rev() {
+            let mut task = tasks.done.remove(position - 1);
+            if let Some(repeat) = task.repeat_mut() {
+                repeat.done = false;
+            }
+            task.done_at = None;
+            tasks.todo.push(task);
+        }
+        self.sort(list);
+
+        Ok(())
+    }
+
+    pub fn uncomplete_all(&mut self, list: TaskList) {
+        let tasks = self.list_mut(list);
+        for mut task in std::mem::take(&mut tasks.done) {
+            if let Some(repeat) = task.repeat_mut() {
+                repeat.done = false;
+            }
+            task.done_at = None;
+            tasks.todo.push(task);
+        }
+        self.sort(list);
+    }
+
+    pub fn remove(&mut self, list: PositionsIn, positions: &[usize]) -> Result<(), ChartodoError> {
+        let positions = self.check_positions(list, positions)?;
+
+        let tasks = list.tasks_mut(&mut self.file);
+        for position in positions.iter().rev() {
+            tasks.remove(position - 1);
+        }
+
+        Ok(())
+    }
+
+    pub fn clear(&mut self, list: PositionsIn) {
+        list.tasks_mut(&mut self.file).clear();
+    }
+
+    // clears both the todo and the done list
+    pub fn clear_both(&mut self, list: TaskList) {
+        self.clear(list.todo());
+        self.clear(list.done());
+    }
+
+    // moves done tasks to the archive instead of deleting them. see archive
+    pub fn archive(&mut self, list: TaskList, positions: &[usize]) -> Result<(), ChartodoError> {
+        let positions = self.check_positions(list.done(), positions)?;
+
+        let done = &mut self.list_mut(list).done;
+        let mut archived: Vec<Task> = positions
+            .iter()
+            .rev()
+            .map(|position| done.remove(position - 1))
+            .collect();
+        // in the order they were in the list
+        archived.reverse();
+        self.add_to_archive(list, archived);
+
+        Ok(())
+    }
+
+    pub fn archive_all(&mut self, list: TaskList) {
+        let archived = std::mem::take(&mut self.list_mut(list).done);
+        self.add_to_archive(list, archived);
+    }
+
+    // a task that was done before chartodo kept track of when counts as done when it's archived
+    fn add_to_archive(&mut self, list: TaskList, mut tasks: Vec<Task>) {
+        let now = now_to_the_second();
+        for task in tasks.iter_mut().filter(|task| task.done_at.is_none()) {
+            task.done_at = Some(now);
+        }
+
+        match &mut self.archive {
+            Some(archive) => archive.list_mut(list).extend(tasks),
+            None => self.archived.list_mut(list).extend(tasks),
+        }
+    }
+
+    // everything that's been archived, whether it's been saved yet or not. archive.json is only read the first time
+    pub fn load_archive(&mut self) -> Result<&Archive, ChartodoError> {
+        if self.archive.is_none() {
+            let mut archive = read_archive(&self.archive_path()).map_err(|read_error| {
+                ChartodoError::Storage(format!("{:#}. Nothing was changed.", read_error))
+            })?;
+            for list in [TaskList::Regular, TaskList::Deadline, TaskList::Repeating] {
+                archive.list_mut(list).append(self.archived.list_mut(list));
+            }
+            self.archive = Some(archive);
+        }
+
+        Ok(self.archive.get_or_insert_default())
+    }
+
+    // moves archived tasks back to their done list. they count as done just now, so that they aren't archived again
+    // right away, see apply_retention
+    pub fn restore(&mut self, list: TaskList, positions: &[usize]) -> Result<(), ChartodoError> {
+        let archived_len = self.load_archive()?.list(list).len();
+        let positions = check_positions_in(&format!("{} archive", list), archived_len, positions)?;
+
+        let now = now_to_the_second();
+        let archived = self.archive.get_or_insert_default().list_mut(list);
+        let mut restored: Vec<Task> = positions
+            .iter()
+            .rev()
+            .map(|position| archived.remove(position - 1))
+            .collect();
+        restored.reverse();
+
+        for task in restored.iter_mut() {
+            task.done_at = Some(now);
+        }
+        self.list_mut(list).done.extend(restored);
+        self.sort(list);
+
+        Ok(())
+    }
+
+    // every task whose text matches, w/ where it is. the lists come first, each todo before its done, then the
+    // archive, w/ the positions the commands for each one take
+    pub fn search(&mut self, pattern: &SearchPattern) -> Result<Vec<SearchMatch>, ChartodoError> {
+        let archive = self.load_archive()?.clone();
+        let in_lists = [
+            PositionsIn::RegularTodo,
+            PositionsIn::RegularDone,
+            PositionsIn::DeadlineTodo,
+            PositionsIn::DeadlineDone,
+            PositionsIn::RepeatingTodo,
+            PositionsIn::RepeatingDone,
+        ]
+        .map(|list| (FoundIn::List(list), self.tasks(list)));
+        let in_archive = [TaskList::Regular, TaskList::Deadline, TaskList::Repeating]
+            .map(|list| (FoundIn::Archive(list), archive.list(list).as_slice()));
+
+        let mut found = Vec::new();
+        for (found_in, tasks) in in_lists.into_iter().chain(in_archive) {
+            found.extend(
+                tasks
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, task)| pattern.matches(&task.task))
+                    .map(|(index, task)| SearchMatch {
+                        found_in,
+                        position: index + 1,
+                        task: task.clone(),
+                    }),
+            );
+        }
+
+        Ok(found)
+    }
+
+    pub fn edit_task(
+        &mut self,
+        list: PositionsIn,
+        position: usize,
+        task: &str,
+    ) -> Result<(), ChartodoError> {
+        self.task_mut(list, position)?.task = task.to_string();
+
+        Ok(())
+    }
+
+    pub fn edit_deadline(
+        &mut self,
+        position: usize,
+        due: NaiveDateTime,
+    ) -> Result<(), ChartodoError> {
+        self.task_mut(PositionsIn::DeadlineTodo, position)?.kind = TaskKind::Deadline { due };
+        self.sort(TaskList::Deadline);
+
+        Ok(())
+    }
+
+    // swaps the repeating part of a repeating todo for an edited one, e.g., w/ a new interval. errors if the
+    // edited dates couldn't be worked out
+    pub fn edit_repeating(
+        &mut self,
+        position: usize,
+        edit: impl FnOnce(&Repeat) -> Option<Repeat>,
+    ) -> Result<(), ChartodoError> {
+        let task = self.task_mut(PositionsIn::RepeatingTodo, position)?;
+        let Some(repeat) = task.repeat_mut() else {
+            return Ok(());
+        };
+
+        match edit(repeat) {
+            Some(edited_repeat) => *repeat = edited_repeat,
+            None => return Err(interval_out_of_range(&task.task)),
+        }
+        self.sort(TaskList::Repeating);
+
+        Ok(())
+    }
+
+    // a new rule for a repeating todo, or None to take it away. it's due next on the first day after its start that
+    // fits, same as if it had been added w/ the rule
+    pub fn set_repeat_on(
+        &mut self,
+        position: usize,
+        on: Option<RepeatOn>,
+    ) -> Result<(), ChartodoError> {
+        let task = self.task_mut(PositionsIn::RepeatingTodo, position)?;
+        if let Some(repeat) = task.repeat() {
+            check_rule_fits(&task.task, repeat.unit, on)?;
+        }
+
+        self.edit_repeating(position, |repeat| {
+            Repeat { on, ..*repeat }.restarted_at(repeat.start)
+        })
+    }
+
+    pub fn set_catch_up(
+        &mut self,
+        position: usize,
+        catch_up: CatchUp,
+    ) -> Result<(), ChartodoError> {
+        self.edit_repeating(position, |repeat| {
+            Some(Repeat {
+                catch_up,
+                ..*repeat
+            })
+        })
+    }
+
+    // the repeating todos start over from now, keeping their interval, unit, and rule
+    pub fn reset_repeating(
+        &mut self,
+        positions: &[usize],
+        now: NaiveDateTime,
+    ) -> Result<(), ChartodoError> {
+        let positions = self.check_positions(PositionsIn::RepeatingTodo, positions)?;
+
+        // worked out for every task first, so that nothing is changed if one of them can't be
+        let mut reset_repeats: Vec<(usize, Repeat)> = vec![];
+        for position in positions {
+            let task = &self.file.repeating.todo[position - 1];
+            let Some(repeat) = task.repeat() else {
+                continue;
+            };
+            match repeat.restarted_at(now) {
+                Some(reset_repeat) => reset_repeats.push((position, reset_repeat)),
+                None => return Err(interval_out_of_range(&task.task)),
+            }
+        }
+
+        for (position, reset_repeat) in reset_repeats {
+            if let Some(repeat) = self.file.repeating.todo[position - 1].repeat_mut() {
+                *repeat = reset_repeat;
+            }
+        }
+        self.sort(TaskList::Repeating);
+
+        Ok(())
+    }
+
+    // None takes the priority away
+    pub fn set_priority(
+        &mut self,
+        list: PositionsIn,
+        position: usize,
+        priority: Option<Priority>,
+    ) -> Result<(), ChartodoError> {
+        self.task_mut(list, position)?.priority = priority;
+        self.sort(list.list());
+
+        Ok(())
+    }
+
+    // tags the task already has are skipped. a tag w/o a + or @ gets a +, see parse_tag
+    pub fn tag(
+        &mut self,
+        list: PositionsIn,
+        position: usize,
+        tags: &[String],
+    ) -> Result<Vec<String>, ChartodoError> {
+        let tags = parse_tags(tags)?;
+
+        let task = self.task_mut(list, position)?;
+        for tag in tags {
+            if !task.tags.contains(&tag) {
+                task.tags.push(tag);
+            }
+        }
+
+        Ok(task.tags.clone())
+    }
+
+    // the tags are matched the same way list --tag matches them, so untagging backend takes away both +backend and
+    // @backend. if the task is missing one of them, none are taken away
+    pub fn untag(
+        &mut self,
+        list: PositionsIn,
+        position: usize,
+        tags: &[String],
+    ) -> Result<Vec<String>, ChartodoError> {
+        // checked, but matched as they were given
+        parse_tags(tags)?;
+
+        let task = self.task_mut(list, position)?;
+        if let Some(missing_tag) = tags.iter().find(|tag| !task.has_tag(tag)) {
+            return Err(ChartodoError::MissingTag(format!(
+                "The {} at position {}, '{}', doesn't have the tag '{}'.",
+                list.name(),
+                position,
+                task.task,
+                missing_tag
+            )));
+        }
+
+        for tag in tags {
+            task.tags.retain(|task_tag| !tag_matches(task_tag, tag));
+        }
+
+        Ok(task.tags.clone())
+    }
+
+    // finished repeating tasks that are due again go back to todo, caught up however each one says to. see CatchUp.
+    // returns whether any did
+    pub fn roll_over_repeating(&mut self, now: NaiveDateTime) -> bool {
+        // double check that repeat_done = true and check if due date+time passed
+        // note: that double check may not be necessary. a series that's finished stays done for good
+        let (due_again, still_done): (Vec<Task>, Vec<Task>) =
+            std::mem::take(&mut self.file.repeating.done)
+                .into_iter()
+                .partition(|task| {
+                    task.repeat().is_some_and(|repeat| {
+                        repeat.done && now > repeat.due && !repeat.is_finished()
+                    })
+                });
+        self.file.repeating.done = still_done;
+
+        if due_again.is_empty() {
+            return false;
+        }
+
+        for mut task in due_again {
+            // note: design decision time. for finished repeating tasks, should I use the original due datetime as
+            // the starting datetime for a refreshed repeating task? Or should I use Local::now as the starting
+            // datetime, aka the moment that this function is called to print the list?
+            //
+            // answer: i think i'll keep the original due datetime -> new starting datetime feature. i think this is
+            // to avoid having two different repeating-type tasks, and this is much cleaner + easier. i can
+            // just do a rp-reset command that resets the starting datetime to local now
+            // 2) And also to keep the starting + ending datetimes consistent and not actually contingent
+            // on when the list was shown/printed
+            let Some(mut next_repeat) = task.repeat().and_then(|repeat| repeat.next()) else {
+                self.file.repeating.done.push(task);
+                continue;
+            };
+
+            // every occurrence that's already gone by, oldest first. w/ once, it's due at the first one no matter
+            // what
+            let mut passed: Vec<NaiveDateTime> = vec![];
+            if next_repeat.catch_up != CatchUp::Once {
+                while now > next_repeat.due {
+                    // the last occurrence of a series is always left to be done, even if it's been missed
+                    let Some(after) = next_repeat.next() else {
+                        break;
+                    };
+                    passed.push(next_repeat.due);
+                    next_repeat = after;
+                }
+            }
+
+            let first_todo = match next_repeat.catch_up {
+                CatchUp::Each => passed.len().saturating_sub(MAX_CATCH_UP_TODOS),
+                _ => passed.len(),
+            };
+            for scheduled in &passed[..first_todo] {
+                self.history.push(HistoryEvent {
+                    event: HistoryEventKind::Skipped,
+                    list: TaskList::Repeating,
+                    id: task.id,
+                    task: task.task.clone(),
+                    scheduled: Some(*scheduled),
+                    at: now,
+                });
+            }
+            for due in &passed[first_todo..] {
+                self.file.deadline.todo.push(
+                    Task::deadline(task.task.clone(), *due)
+                        .with_priority(task.priority)
+                        .with_tags(&task.tags),
+                );
+            }
+
+            // set new due date+time, new original date+time, and repeat_done = false
+            if let Some(repeat) = task.repeat_mut() {
+                *repeat = next_repeat;
+            }
+            task.done_at = None;
+            self.file.repeating.todo.push(task);
+        }
+        self.sort(TaskList::Repeating);
+        self.sort(TaskList::Deadline);
+
+        true
+    }
+
+    // what's happened since the lists were loaded, that hasn't been saved yet
+    pub fn unsaved_history(&self) -> &[HistoryEvent] {
+        &self.history
+    }
+
+    // what's been taken out of the done lists, that hasn't been saved yet
+    pub fn unsaved_archive(&self) -> &Archive {
+        &self.archived
+    }
+
+    // takes the done tasks that are past what the retention allows out of the done lists, and archives or drops
+    // them. it's done on every save, so that a limit is never gone past for long. done tasks that don't know when
+    // they were done, i.e., from before done_at, were done before any that do, and are given now as their done_at so
+    // that they can expire too
+    pub fn apply_retention(&mut self, now: NaiveDateTime) {
+        let retention = self.retention;
+        let expired_before = match retention.max_done_days {
+            0 => None,
+            days => now.checked_sub_days(Days::new(days.into())),
+        };
+
+        for list in [TaskList::Regular, TaskList::Deadline, TaskList::Repeating] {
+            let done = &mut self.list_mut(list).done;
+
+            // a repeating task that's done but hasn't finished its series is only waiting to be due again, so
+            // it's never taken out
+            let can_go = |task: &Task| task.repeat().is_none_or(|repeat| repeat.is_finished());
+
+            // the ones that were done first are the first to go. the sort is stable, so ties stay in list order
+            let mut oldest_first: Vec<(usize, Option<NaiveDateTime>)> = done
+                .iter()
+                .enumerate()
+                .filter(|(_, task)| can_go(task))
+                .map(|(index, task)| (index, task.done_at))
+                .collect();
+            oldest_first.sort_by_key(|(_, done_at)| *done_at);
+
+            let too_many = match retention.max_done {
+                0 => 0,
+                max_done => oldest_first.len().saturating_sub(max_done),
+            };
+            let mut going: Vec<usize> = oldest_first
+                .iter()
+                .enumerate()
+                .filter(|(nth, (_, done_at))| {
+                    *nth < too_many
+                        || done_at.is_some_and(|done_at| {
+                            expired_before.is_some_and(|before| done_at < before)
+                        })
+                })
+                .map(|(_, (index, _))| *index)
+                .collect();
+            going.sort();
+
+            for task in done.iter_mut().filter(|task| task.done_at.is_none()) {
+                task.done_at = Some(now);
+            }
+            if going.is_empty() {
+                continue;
+            }
+
+            // rev so that removing by index doesn't move the tasks that are still to go
+            let mut gone: Vec<Task> = going
+                .iter()
+                .rev()
+                .map(|index| done.remove(*index))
+                .collect();
+            gone.reverse();
+
+            if retention.overflow == Overflow::Archive {
+                self.add_to_archive(list, gone);
+            }
+        }
+    }
+}
+
+// what a command says when the positions it was given can't be used, since each one words it for what it does
+pub struct PositionErrors<'a> {
+    pub empty: &'a str,
+    pub none_viable: &'a str,
+    // None for a command that's fine w/ every position being given, e.g., restore
+    pub whole_list: Option<&'a str>,
+}
+
+// same as TaskStore::positions_from_args, for any list, e.g., the archive
+fn positions_from_args_in(
+    tasks_len: usize,
+    positions: &[String],
+    errors: &PositionErrors,
+    whole_list_threshold: usize,
+) -> Result<Vec<usize>, ChartodoError> {
+    if tasks_len == 0 {
+        return Err(ChartodoError::EmptyList(errors.empty.to_string()));
+    }
+
+    let positions = viable_positions(positions, tasks_len);
+    if positions.is_empty() {
+        return Err(ChartodoError::InvalidPosition(
+            errors.none_viable.to_string(),
+        ));
+    }
+
+    if let Some(whole_list) = errors.whole_list {
+        if positions.len() >= tasks_len && tasks_len > whole_list_threshold {
+            return Err(ChartodoError::WholeListSpecified(whole_list.to_string()));
+        }
+    }
+
+    Ok(positions)
+}
+
+// the positions that were given, w/ any ranges unwrapped, sorted, and w/o repeats. anything that doesn't point at
+// something in a list of list_len, e.g., 0 or a range past the end, is left out
+fn viable_positions(positions: &[String], list_len: usize) -> Vec<usize> {
+    let mut viable = vec![];
+    for position in positions {
+        let (error_or_not, bound1, bound2) =
+            check_if_range_positioning(position.to_string(), list_len);
+
+        if !error_or_not {
+            viable.extend(unwrap_range_positioning(bound1, bound2));
+        } else if let Ok(position) = position.parse::<usize>() {
+            if position != 0 && position <= list_len {
+                viable.push(position);
+            }
+        }
+    }
+    viable.sort();
+    viable.dedup();
+
+    viable
+}
+
+fn invalid_position(position: impl fmt::Display, tasks_len: usize) -> ChartodoError {
+    ChartodoError::InvalidPosition(format!(
+        "The position you provided, '{}', was invalid. Try something between 1 and {}.",
+        position, tasks_len
+    ))
+}
+
+// same as TaskStore::check_positions, for any list, e.g., the archive
+fn check_positions_in(
+    name: &str,
+    tasks_len: usize,
+    positions: &[usize],
+) -> Result<Vec<usize>, ChartodoError> {
+    if tasks_len == 0 {
+        return Err(ChartodoError::EmptyList(format!(
+            "The {} list is currently empty.",
+            name
+        )));
+    }
+
+    if let Some(position) = positions
+        .iter()
+        .find(|position| **position == 0 || **position > tasks_len)
+    {
+        return Err(invalid_position(position, tasks_len));
+    }
+
+    let mut positions = positions.to_vec();
+    positions.sort();
+    positions.dedup();
+
+    Ok(positions)
+}
+
+fn parse_tags(tags: &[String]) -> Result<Vec<String>, ChartodoError> {
+    tags.iter()
+        .map(|tag| {
+            parse_tag(tag).map_err(|tag_error| ChartodoError::InvalidTag(format!("{}.", tag_error)))
+        })
+        .collect()
+}
+
+// cargo test task_store_unit_tests
+// unlike the other unit tests, these never touch the real chartodo.json. every test gets its own folder in the temp
+// dir, or doesn't save at all
+#[cfg(test)]
+mod task_store_unit_tests {
+    use super::*;
+    use std::{path::PathBuf, time::Duration};
+
+    fn datetime(datetime: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(datetime, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    fn fresh_test_dir(test_name: &str) -> PathBuf {
+        let mut test_dir = std::env::temp_dir();
+        test_dir.push(format!(
+            "chartodo_task_store_unit_tests_{}_{}",
+            test_name,
+            std::process::id()
+        ));
+
+        // in case a previous run didn't clean up after itself
+        if test_dir.exists() {
+            std::fs::remove_dir_all(&test_dir).unwrap();
+        }
+
+        test_dir
+    }
+
+    // the example tasks, w/ nowhere to save them
+    fn fresh_store() -> TaskStore {
+        TaskStore::new(
+            Box::new(JsonBackend::new(PathBuf::new())),
+            fresh_chartodo_file(),
+        )
+    }
+
+    fn tasks_in(store: &TaskStore, list: PositionsIn) -> Vec<&str> {
+        store
+            .tasks(list)
+            .iter()
+            .map(|task| task.task.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn open_and_save_are_correct() {
+        let test_dir = fresh_test_dir("open_and_save");
+
+        // a new folder starts empty, and nothing is written until save
+        let mut store = TaskStore::open(&test_dir).unwrap();
+        assert_eq!(store.file(), &new_chartodo_file(false));
+        assert!(!test_dir.exists());
+
+        let id = store.add_regular("hi");
+        store.save().unwrap();
+        assert_eq!(store.path(), test_dir.join(CHARTODO_FILE_NAME));
+
+        // the store holds the folder until it's dropped, so another one would wait for it
+        let saved_file = store.file().clone();
+        drop(store);
+        let reopened_store = TaskStore::open(&test_dir).unwrap();
+        assert_eq!(reopened_store.file(), &saved_file);
+        assert_eq!(
+            reopened_store.position_of_id(PositionsIn::RegularTodo, id),
+            Some(1)
+        );
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+
+    #[test]
+    fn open_holds_the_lock_until_dropped() {
+        let test_dir = fresh_test_dir("holds_the_lock");
+
+        // a folder that isn't there yet is locked once save creates it
+        let mut store = TaskStore::open(&test_dir).unwrap();
+        store.add_regular("hi");
+        store.save().unwrap();
+        let error = lock_chartodo_dir(&test_dir, Duration::ZERO).unwrap_err();
+        assert_eq!(error.code(), "locked");
+        drop(store);
+
+        // and one that is, right away
+        let store = TaskStore::open(&test_dir).unwrap();
+        assert!(lock_chartodo_dir(&test_dir, Duration::ZERO).is_err());
+        drop(store);
+        assert!(lock_chartodo_dir(&test_dir, Duration::ZERO).is_ok());
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+
+    #[test]
+    fn broken_file_is_an_error() {
+        let test_dir = fresh_test_dir("broken_file");
+        std::fs::create_dir(&test_dir).unwrap();
+        std::fs::write(test_dir.join(CHARTODO_FILE_NAME), "{ not json").unwrap();
+
+        let open_error = TaskStore::open(&test_dir).unwrap_err();
+        assert_eq!(open_error.code(), "storage");
+        assert_eq!(open_error.exit_code(), 1);
+        // and the file is left where it was
+        assert_eq!(
+            std::fs::read_to_string(test_dir.join(CHARTODO_FILE_NAME)).unwrap(),
+            "{ not json"
+        );
+        // w/ a copy of it next to it
+        assert!(open_error.to_string().contains(".corrupt"));
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+
+    #[test]
+    fn add_gives_ids_and_sorts() {
+        let mut store = fresh_store();
+
+        // the example tasks take #1 to #4
+        let later = store.add_deadline("later", datetime("2099-01-02 00:00"));
+        let sooner = store.add_deadline("sooner", datetime("2099-01-01 00:00"));
+
+        assert_eq!((later, sooner), (5, 6));
+        assert_eq!(
+            store.position_of_id(PositionsIn::DeadlineTodo, sooner),
+            Some(2)
+        );
+        assert_eq!(
+            store.position_of_id(PositionsIn::DeadlineTodo, later),
+            Some(3)
+        );
+        assert_eq!(store.position_of_id(PositionsIn::RegularTodo, later), None);
+        assert_eq!(store.file().next_id, 7);
+    }
+
+    #[test]
+    fn add_repeating_is_correct() {
+        let mut store = fresh_store();
+
+        store
+            .add_repeating(
+                "hi",
+                2,
+                RepeatUnit::Days,
+                RepeatRules::default(),
+                datetime("2099-01-01 00:00"),
+            )
+            .unwrap();
+        // the example repeating task is due after it
+        let repeat = store.repeating().todo[0].repeat().unwrap();
+        assert_eq!(repeat.due, datetime("2099-01-03 00:00"));
+
+        let zero_error = store.add_repeating(
+            "hi",
+            0,
+            RepeatUnit::Days,
+            RepeatRules::default(),
+            datetime("2099-01-01 00:00"),
+        );
+        let huge_error = store.add_repeating(
+            "hi",
+            u32::MAX,
+            RepeatUnit::Years,
+            RepeatRules::default(),
+            datetime("2099-01-01 00:00"),
+        );
+        assert!(matches!(zero_error, Err(ChartodoError::InvalidInterval(_))));
+        assert!(matches!(huge_error, Err(ChartodoError::InvalidInterval(_))));
+        assert_eq!(store.repeating().todo.len(), 2);
+    }
+
+    #[test]
+    fn complete_and_uncomplete_are_correct() {
+        let mut store = fresh_store();
+        store.add_regular("a");
+        store.add_regular("b");
+
+        store.complete(TaskList::Regular, &[3, 1, 3]).unwrap();
+        assert_eq!(tasks_in(&store, PositionsIn::RegularTodo), vec!["a"]);
+        assert_eq!(
+            tasks_in(&store, PositionsIn::RegularDone),
+            vec!["this is the done list", "b", "this is the todo list"]
+        );
+
+        store.uncomplete(TaskList::Regular, &[2]).unwrap();
+        assert_eq!(tasks_in(&store, PositionsIn::RegularTodo), vec!["a", "b"]);
+
+        // a repeating task is marked as done, and as not done when it comes back
+        store.complete(TaskList::Repeating, &[1]).unwrap();
+        assert!(store
+            .repeating()
+            .done
+            .iter()
+            .all(|task| task.repeat().unwrap().done));
+        store.uncomplete_all(TaskList::Repeating);
+        assert!(store.repeating().done.is_empty());
+        assert!(store
+            .repeating()
+            .todo
+            .iter()
+            .all(|task| !task.repeat().unwrap().done));
+    }
+
+    #[test]
+    fn bad_positions_change_nothing() {
+        let mut store = fresh_store();
+        let before = store.file().clone();
+
+        assert!(matches!(
+            store.complete(TaskList::Regular, &[1, 2]),
+            Err(ChartodoError::InvalidPosition(_))
+        ));
+        assert!(store.remove(PositionsIn::DeadlineTodo, &[0]).is_err());
+        assert!(store.edit_task(PositionsIn::RegularDone, 2, "hi").is_err());
+        assert_eq!(store.file(), &before);
+
+        store.clear(PositionsIn::RegularTodo);
+        assert!(matches!(
+            store.complete(TaskList::Regular, &[1]),
+            Err(ChartodoError::EmptyList(_))
+        ));
+    }
+
+    #[test]
+    fn positions_from_args_are_correct() {
+        let mut store = fresh_store();
+        store.clear(PositionsIn::RegularTodo);
+        let errors = PositionErrors {
+            empty: "empty",
+            none_viable: "none viable",
+            whole_list: Some("whole list"),
+        };
+        let args =
+            |args: &[&str]| -> Vec<String> { args.iter().map(|arg| arg.to_string()).collect() };
+
+        let empty = store.positions_from_args(PositionsIn::RegularTodo, &args(&["1"]), &errors);
+        assert!(matches!(empty, Err(ChartodoError::EmptyList(message)) if message == "empty"));
+
+        for task in ["a", "b", "c", "d", "e", "f"] {
+            store.add_regular(task);
+        }
+
+        // ranges are unwrapped, and anything that doesn't point at a task is left out
+        let positions = store
+            .positions_from_args(
+                PositionsIn::RegularTodo,
+                &args(&["5", "2-4", "3", "0", "-1", "a", "4-9", "7"]),
+                &errors,
+            )
+            .unwrap();
+        assert_eq!(positions, vec![2, 3, 4, 5]);
+
+        let none_viable =
+            store.positions_from_args(PositionsIn::RegularTodo, &args(&["0", "7", "3-1"]), &errors);
+        assert!(
+            matches!(none_viable, Err(ChartodoError::InvalidPosition(message)) if message == "none viable")
+        );
+
+        // the whole list is refused once it's longer than the threshold, unless the command is fine w/ it
+        let whole_list =
+            store.positions_from_args(PositionsIn::RegularTodo, &args(&["1-6"]), &errors);
+        assert!(
+            matches!(whole_list, Err(ChartodoError::WholeListSpecified(message)) if message == "whole list")
+        );
+        let store = store.with_whole_list_threshold(6);
+        assert_eq!(
+            store
+                .positions_from_args(PositionsIn::RegularTodo, &args(&["1-6"]), &errors)
+                .unwrap()
+                .len(),
+            6
+        );
+
+        assert_eq!(
+            store
+                .position_from_arg(PositionsIn::RegularTodo, "6", "empty")
+                .unwrap(),
+            6
+        );
+        for position in ["0", "7", "a"] {
+            assert!(matches!(
+                store.position_from_arg(PositionsIn::RegularTodo, position, "empty"),
+                Err(ChartodoError::InvalidPosition(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn edits_are_correct() {
+        let mut store = fresh_store();
+        let id = store.add_deadline("hi", datetime("2000-01-01 00:00"));
+
+        // the deadline list is sorted again after its due date+time is changed
+        store
+            .edit_deadline(1, datetime("2100-01-01 00:00"))
+            .unwrap();
+        let position = store.position_of_id(PositionsIn::DeadlineTodo, id).unwrap();
+        assert_eq!(position, 2);
+        store
+            .edit_task(PositionsIn::DeadlineTodo, position, "hello")
+            .unwrap();
+        assert_eq!(store.deadline().todo[1].task, "hello");
+
+        store
+            .edit_repeating(1, |repeat| {
+                Repeat::starting_at(repeat.start, 3, RepeatUnit::Weeks)
+            })
+            .unwrap();
+        assert_eq!(store.repeating().todo[0].repeat().unwrap().interval, 3);
+        assert!(store
+            .edit_repeating(1, |repeat| Repeat::starting_at(
+                repeat.start,
+                u32::MAX,
+                RepeatUnit::Years
+            ))
+            .is_err());
+        assert_eq!(store.repeating().todo[0].repeat().unwrap().interval, 3);
+    }
+
+    #[test]
+    fn reset_repeating_is_correct() {
+        let mut store = fresh_store();
+        let now = datetime("2099-06-01 12:00");
+
+        store.reset_repeating(&[1], now).unwrap();
+        let repeat = store.repeating().todo[0].repeat().unwrap();
+        assert_eq!(repeat.start, now);
+        assert_eq!(
+            repeat.due,
+            repeat.unit.add_to(now, repeat.interval).unwrap()
+        );
+    }
+
+    #[test]
+    fn priority_and_tags_are_correct() {
+        let mut store = fresh_store();
+
+        store
+            .set_priority(PositionsIn::RegularTodo, 1, Some(Priority::High))
+            .unwrap();
+        assert_eq!(store.regular().todo[0].priority, Some(Priority::High));
+
+        let tags = store
+            .tag(
+                PositionsIn::RegularTodo,
+                1,
+                &[String::from("backend"), String::from("@errands")],
+            )
+            .unwrap();
+        assert_eq!(tags, vec!["+backend", "@errands"]);
+
+        // nothing is taken away if one of the tags is missing
+        assert!(matches!(
+            store.untag(
+                PositionsIn::RegularTodo,
+                1,
+                &[String::from("backend"), String::from("frontend")]
+            ),
+            Err(ChartodoError::MissingTag(_))
+        ));
+        let tags = store
+            .untag(PositionsIn::RegularTodo, 1, &[String::from("errands")])
+            .unwrap();
+        assert_eq!(tags, vec!["+backend"]);
+        assert!(store
+            .tag(PositionsIn::RegularTodo, 1, &[String::from("+")])
+            .is_err());
+    }
+
+    #[test]
+    fn roll_over_repeating_is_correct() {
+        let mut store = fresh_store();
+        store.clear_both(TaskList::Repeating);
+        store
+            .add_repeating(
+                "hi",
+                1,
+                RepeatUnit::Days,
+                RepeatRules::default(),
+                datetime("2000-01-01 00:00"),
+            )
+            .unwrap();
+        store.complete(TaskList::Repeating, &[1]).unwrap();
+
+        // not due yet
+        assert!(!store.roll_over_repeating(datetime("2000-01-01 12:00")));
+        assert_eq!(store.repeating().done.len(), 1);
+
+        // due again, starting from when it was last due
+        assert!(store.roll_over_repeating(datetime("2000-01-03 00:00")));
+        let repeat = store.repeating().todo[0].repeat().unwrap();
+        assert!(!repeat.done);
+        assert_eq!(repeat.start, datetime("2000-01-02 00:00"));
+        assert_eq!(repeat.due, datetime("2000-01-03 00:00"));
+    }
+
+    #[test]
+    fn repeat_rules_are_kept() {
+        let mut store = fresh_store();
+        store.clear_both(TaskList::Repeating);
+        // 2000-01-03 is a monday
+        let mon_wed_fri = Some("mon,wed,fri".parse().unwrap());
+        store
+            .add_repeating(
+                "standup",
+                1,
+                RepeatUnit::Weeks,
+                RepeatRules {
+                    on: mon_wed_fri,
+                    ..Default::default()
+                },
+                datetime("2000-01-03 09:00"),
+            )
+            .unwrap();
+        assert_eq!(
+            store.repeating().todo[0].repeat().unwrap().due,
+            datetime("2000-01-05 09:00")
+        );
+
+        // the next one is friday, not a week later
+        store.complete(TaskList::Repeating, &[1]).unwrap();
+        assert!(store.roll_over_repeating(datetime("2000-01-06 00:00")));
+        let repeat = store.repeating().todo[0].repeat().unwrap();
+        assert_eq!(repeat.on, mon_wed_fri);
+        assert_eq!(repeat.due, datetime("2000-01-07 09:00"));
+
+        // a monthly rule doesn't go w/ weeks
+        let wrong_unit = store.set_repeat_on(1, Some("last day".parse().unwrap()));
+        assert!(matches!(wrong_unit, Err(ChartodoError::InvalidTimeUnit(_))));
+        assert!(store
+            .add_repeating(
+                "hi",
+                1,
+                RepeatUnit::Days,
+                RepeatRules {
+                    on: mon_wed_fri,
+                    ..Default::default()
+                },
+                datetime("2000-01-03 09:00")
+            )
+            .is_err());
+
+        store.set_repeat_on(1, None).unwrap();
+        let repeat = store.repeating().todo[0].repeat().unwrap();
+        assert_eq!(repeat.on, None);
+        assert_eq!(repeat.due, datetime("2000-01-12 09:00"));
+    }
+
+    // what's in the history besides the task being done
+    fn skipped(store: &TaskStore) -> Vec<&HistoryEvent> {
+        store
+            .unsaved_history()
+            .iter()
+            .filter(|event| event.event == HistoryEventKind::Skipped)
+            .collect()
+    }
+
+    // a daily task that was done on 2000-01-01 and not looked at again until the 5th, in the afternoon. it was due on
+    // the 2nd, 3rd, 4th, and 5th, all at midnight
+    fn left_for_a_while(catch_up: CatchUp) -> TaskStore {
+        let mut store = fresh_store();
+        store.clear_both(TaskList::Repeating);
+        store.clear_both(TaskList::Deadline);
+        store
+            .add_repeating(
+                "water plants",
+                1,
+                RepeatUnit::Days,
+                RepeatRules {
+                    catch_up,
+                    ..Default::default()
+                },
+                datetime("2000-01-01 00:00"),
+            )
+            .unwrap();
+        store.complete(TaskList::Repeating, &[1]).unwrap();
+        assert!(store.roll_over_repeating(datetime("2000-01-05 12:00")));
+
+        store
+    }
+
+    #[test]
+    fn catch_up_once_is_due_at_the_first_one() {
+        let store = left_for_a_while(CatchUp::Once);
+
+        let repeat = store.repeating().todo[0].repeat().unwrap();
+        assert_eq!(repeat.due, datetime("2000-01-03 00:00"));
+        assert!(store.deadline().todo.is_empty());
+        assert!(skipped(&store).is_empty());
+    }
+
+    #[test]
+    fn catch_up_next_skips_to_what_is_ahead() {
+        let store = left_for_a_while(CatchUp::Next);
+
+        let repeat = store.repeating().todo[0].repeat().unwrap();
+        assert_eq!(repeat.start, datetime("2000-01-05 00:00"));
+        assert_eq!(repeat.due, datetime("2000-01-06 00:00"));
+        assert!(store.deadline().todo.is_empty());
+
+        let scheduled: Vec<Option<NaiveDateTime>> = skipped(&store)
+            .iter()
+            .map(|event| event.scheduled)
+            .collect();
+        assert_eq!(
+            scheduled,
+            vec![
+                Some(datetime("2000-01-03 00:00")),
+                Some(datetime("2000-01-04 00:00")),
+                Some(datetime("2000-01-05 00:00")),
+            ]
+        );
+        assert!(skipped(&store)
+            .iter()
+            .all(|event| event.task == "water plants"));
+    }
+
+    #[test]
+    fn catch_up_each_adds_a_deadline_todo_per_miss() {
+        let store = left_for_a_while(CatchUp::Each);
+
+        let repeat = store.repeating().todo[0].repeat().unwrap();
+        assert_eq!(repeat.due, datetime("2000-01-06 00:00"));
+        assert_eq!(repeat.catch_up, CatchUp::Each);
+        let missed: Vec<Option<NaiveDateTime>> = store
+            .deadline()
+            .todo
+            .iter()
+            .map(|task| task.due())
+            .collect();
+        assert_eq!(
+            missed,
+            vec![
+                Some(datetime("2000-01-03 00:00")),
+                Some(datetime("2000-01-04 00:00")),
+                Some(datetime("2000-01-05 00:00")),
+            ]
+        );
+        assert!(skipped(&store).is_empty());
+    }
+
+    #[test]
+    fn catch_up_each_only_adds_the_latest() {
+        let mut store = fresh_store();
+        store.clear_both(TaskList::Repeating);
+        store.clear_both(TaskList::Deadline);
+        store
+            .add_repeating(
+                "stretch",
+                1,
+                RepeatUnit::Hours,
+                RepeatRules {
+                    catch_up: CatchUp::Each,
+                    ..Default::default()
+                },
+                datetime("2000-01-01 00:00"),
+            )
+            .unwrap();
+        store.complete(TaskList::Repeating, &[1]).unwrap();
+
+        // due at 02:00 through 2000-01-11 00:00, which is 239 misses
+        assert!(store.roll_over_repeating(datetime("2000-01-11 00:30")));
+        assert_eq!(store.deadline().todo.len(), MAX_CATCH_UP_TODOS);
+        assert_eq!(skipped(&store).len(), 239 - MAX_CATCH_UP_TODOS);
+        assert_eq!(
+            store.deadline().todo.last().unwrap().due(),
+            Some(datetime("2000-01-11 00:00"))
+        );
+        assert_eq!(
+            store.repeating().todo[0].repeat().unwrap().due,
+            datetime("2000-01-11 01:00")
+        );
+    }
+
+    #[test]
+    fn series_stays_done_once_it_ends() {
+        let mut store = fresh_store();
+        store.clear_both(TaskList::Repeating);
+        store
+            .add_repeating(
+                "installment",
+                1,
+                RepeatUnit::Months,
+                RepeatRules {
+                    max_occurrences: Some(2),
+                    ..Default::default()
+                },
+                datetime("2000-01-01 00:00"),
+            )
+            .unwrap();
+
+        store.complete(TaskList::Repeating, &[1]).unwrap();
+        assert!(store.roll_over_repeating(datetime("2000-02-15 00:00")));
+        let repeat = store.repeating().todo[0].repeat().unwrap();
+        assert_eq!(repeat.due, datetime("2000-03-01 00:00"));
+        assert_eq!(repeat.occurrence, 2);
+        assert_eq!(repeat.remaining(), Some(1));
+
+        // that was the last one, so it's finished
+        store.complete(TaskList::Repeating, &[1]).unwrap();
+        assert!(!store.roll_over_repeating(datetime("2001-01-01 00:00")));
+        assert!(store.repeating().todo.is_empty());
+        assert!(store.repeating().done[0].repeat().unwrap().is_finished());
+
+        // and it has to have at least one occurrence to begin w/
+        let too_late = store.add_repeating(
+            "hi",
+            1,
+            RepeatUnit::Weeks,
+            RepeatRules {
+                until: Some(datetime("2000-01-03 00:00")),
+                ..Default::default()
+            },
+            datetime("2000-01-01 00:00"),
+        );
+        assert!(matches!(too_late, Err(ChartodoError::InvalidDate(_))));
+        let zero_times = store.add_repeating(
+            "hi",
+            1,
+            RepeatUnit::Weeks,
+            RepeatRules {
+                max_occurrences: Some(0),
+                ..Default::default()
+            },
+            datetime("2000-01-01 00:00"),
+        );
+        assert!(matches!(zero_times, Err(ChartodoError::InvalidInterval(_))));
+    }
+
+    #[test]
+    fn completing_is_kept_in_the_history() {
+        // the example regular todo is #1, and #2 is already done
+        let mut store = fresh_store();
+        let id = store.add_regular("b");
+        store.complete(TaskList::Regular, &[2, 1]).unwrap();
+        store.complete_all(TaskList::Deadline);
+
+        let completed: Vec<(TaskList, u32, Option<NaiveDateTime>)> = store
+            .unsaved_history()
+            .iter()
+            .map(|event| (event.list, event.id, event.scheduled))
+            .collect();
+        assert_eq!(
+            completed,
+            vec![
+                (TaskList::Regular, 1, None),
+                (TaskList::Regular, id, None),
+                (TaskList::Deadline, 3, Some(datetime("2099-01-01 00:00"))),
+            ]
+        );
+        assert!(store
+            .unsaved_history()
+            .iter()
+            .all(|event| event.event == HistoryEventKind::Completed));
+
+        // putting them back isn't something that happened to them, as far as the history goes
+        store.uncomplete_all(TaskList::Regular);
+        assert_eq!(store.unsaved_history().len(), 3);
+    }
+
+    #[test]
+    fn history_is_saved_next_to_the_lists() {
+        let test_dir = fresh_test_dir("history");
+        let mut store = TaskStore::open(&test_dir).unwrap();
+        store.clear_both(TaskList::Repeating);
+        store
+            .add_repeating(
+                "water plants",
+                1,
+                RepeatUnit::Days,
+                RepeatRules {
+                    catch_up: CatchUp::Next,
+                    ..Default::default()
+                },
+                datetime("2000-01-01 00:00"),
+            )
+            .unwrap();
+        store.complete(TaskList::Repeating, &[1]).unwrap();
+        store.roll_over_repeating(datetime("2000-01-04 12:00"));
+        store.save().unwrap();
+
+        assert_eq!(store.history_path(), test_dir.join(HISTORY_FILE_NAME));
+        assert!(store.unsaved_history().is_empty());
+        // it was done once, then skipped twice
+        let history = read_history(&store.history_path()).unwrap();
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0].event, HistoryEventKind::Completed);
+        assert_eq!(history[0].scheduled, Some(datetime("2000-01-02 00:00")));
+        assert_eq!(history[1].event, HistoryEventKind::Skipped);
+        assert_eq!(history[1].list, TaskList::Repeating);
+        assert_eq!(history[1].scheduled, Some(datetime("2000-01-03 00:00")));
+
+        // saving again doesn't add them twice
+        store.save().unwrap();
+        assert_eq!(read_history(&store.history_path()).unwrap().len(), 3);
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+
+    fn done_at(task: &str, done_at: &str) -> Task {
+        Task {
+            done_at: Some(datetime(done_at)),
+            ..Task::regular(task.to_string())
+        }
+    }
+
+    #[test]
+    fn retention_archives_the_oldest() {
+        let mut store = fresh_store().with_retention(Retention {
+            max_done: 2,
+            ..Retention::default()
+        });
+        // the example done task doesn't have a done_at, so it was done before the rest
+        store.file.regular.done.extend([
+            done_at("b", "2099-01-02 00:00"),
+            done_at("a", "2099-01-01 00:00"),
+            done_at("c", "2099-01-03 00:00"),
+        ]);
+
+        store.apply_retention(datetime("2099-01-10 00:00"));
+        assert_eq!(tasks_in(&store, PositionsIn::RegularDone), vec!["b", "c"]);
+        assert_eq!(
+            store.unsaved_archive().regular,
+            vec![
+                Task::regular(String::from("this is the done list")),
+                Task::regular(String::from("a"))
+            ]
+        );
+        // and it's given one on the way out
+        assert_eq!(
+            store.unsaved_archive().regular[0].done_at,
+            Some(datetime("2099-01-10 00:00"))
+        );
+    }
+
+    #[test]
+    fn retention_drops_the_expired() {
+        let mut store = fresh_store().with_retention(Retention {
+            max_done: 0,
+            max_done_days: 7,
+            overflow: Overflow::Delete,
+        });
+        store.file.regular.done = vec![
+            done_at("old", "2099-01-01 00:00"),
+            done_at("new", "2099-01-05 00:00"),
+        ];
+
+        store.apply_retention(datetime("2099-01-10 00:00"));
+        assert_eq!(tasks_in(&store, PositionsIn::RegularDone), vec!["new"]);
+        assert!(store.unsaved_archive().is_empty());
+    }
+
+    #[test]
+    fn retention_keeps_repeating_tasks_that_come_back() {
+        let mut store = fresh_store().with_retention(Retention {
+            max_done: 1,
+            ..Retention::default()
+        });
+        store.clear_both(TaskList::Repeating);
+        for (task, max_occurrences) in [("forever", None), ("once", Some(1)), ("twice", Some(1))] {
+            store
+                .add_repeating(
+                    task,
+                    1,
+                    RepeatUnit::Days,
+                    RepeatRules {
+                        max_occurrences,
+                        ..Default::default()
+                    },
+                    datetime("2099-01-01 00:00"),
+                )
+                .unwrap();
+        }
+        store.complete_all(TaskList::Repeating);
+
+        // forever is only waiting to be due again. of the two finished ones, only one fits
+        store.apply_retention(datetime("2099-01-10 00:00"));
+        assert_eq!(
+            tasks_in(&store, PositionsIn::RepeatingDone),
+            vec!["forever", "twice"]
+        );
+        assert_eq!(store.unsaved_archive().repeating.len(), 1);
+        assert_eq!(store.unsaved_archive().repeating[0].task, "once");
+    }
+
+    #[test]
+    fn retention_comes_from_the_config_and_archives_on_save() {
+        let test_dir = fresh_test_dir("retention");
+        std::fs::create_dir(&test_dir).unwrap();
+        std::fs::write(
+            test_dir.join(CONFIG_FILE_NAME),
+            "[retention]\nmax_done = 1\n\n[defaults]\nstarter_tasks = true\n",
+        )
+        .unwrap();
+
+        let mut store = TaskStore::open(&test_dir).unwrap();
+        assert_eq!(store.retention().max_done, 1);
+        store.complete(TaskList::Regular, &[1]).unwrap();
+        store.save().unwrap();
+
+        assert_eq!(store.archive_path(), test_dir.join(ARCHIVE_FILE_NAME));
+        assert!(store.unsaved_archive().is_empty());
+        assert_eq!(store.regular().done.len(), 1);
+        let archive = read_archive(&store.archive_path()).unwrap();
+        assert_eq!(archive.regular.len(), 1);
+        assert!(archive.regular[0].done_at.is_some());
+
+        // saving again doesn't add it twice
+        store.save().unwrap();
+        assert_eq!(
+            read_archive(&store.archive_path()).unwrap().regular.len(),
+            1
+        );
+
+        // and a config that isn't right is an error. the store has to let go of the folder first
+        drop(store);
+        std::fs::write(test_dir.join(CONFIG_FILE_NAME), "[retention]\nmax = 1\n").unwrap();
+        let config_error = TaskStore::open(&test_dir).unwrap_err();
+        assert_eq!(config_error.code(), "storage");
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+
+    #[test]
+    fn archived_tasks_can_be_restored() {
+        let test_dir = fresh_test_dir("restore");
+        std::fs::create_dir(&test_dir).unwrap();
+        std::fs::write(
+            test_dir.join(CONFIG_FILE_NAME),
+            "[defaults]\nstarter_tasks = true\n",
+        )
+        .unwrap();
+
+        let mut store = TaskStore::open(&test_dir).unwrap();
+        store.complete_all(TaskList::Regular);
+        store.archive(TaskList::Regular, &[2]).unwrap();
+        assert_eq!(
+            tasks_in(&store, PositionsIn::RegularDone),
+            vec!["this is the done list"]
+        );
+        store.archive_all(TaskList::Regular);
+        assert!(store.regular().done.is_empty());
+        assert!(store.archive(TaskList::Regular, &[1]).is_err());
+        store.save().unwrap();
+        drop(store);
+
+        // archive.json is read once, and what was archived since goes after it
+        let mut store = TaskStore::open(&test_dir).unwrap();
+        store.complete_all(TaskList::Repeating);
+        store.archive_all(TaskList::Repeating);
+        let archive = store.load_archive().unwrap();
+        assert_eq!(
+            archive
+                .regular
+                .iter()
+                .map(|task| task.task.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["this is the todo list", "this is the done list"]
+        );
+        assert_eq!(archive.repeating.len(), 1);
+
+        assert!(store.restore(TaskList::Regular, &[3]).is_err());
+        store.restore(TaskList::Regular, &[1]).unwrap();
+        assert_eq!(
+            tasks_in(&store, PositionsIn::RegularDone),
+            vec!["this is the todo list"]
+        );
+        assert!(store.regular().done[0].done_at.is_some());
+        store.save().unwrap();
+
+        let archive = read_archive(&store.archive_path()).unwrap();
+        assert_eq!(archive.regular.len(), 1);
+        assert_eq!(archive.regular[0].task, "this is the done list");
+        assert_eq!(archive.repeating.len(), 1);
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+
+    #[test]
+    fn search_looks_everywhere() {
+        let test_dir = fresh_test_dir("search");
+        std::fs::create_dir(&test_dir).unwrap();
+        std::fs::write(
+            test_dir.join(CONFIG_FILE_NAME),
+            "[defaults]\nstarter_tasks = true\n",
+        )
+        .unwrap();
+
+        let mut store = TaskStore::open(&test_dir).unwrap();
+        store.archive(TaskList::Regular, &[1]).unwrap();
+        store.add_regular("Make-a-LIST");
+
+        let found = store.search(&SearchPattern::text("list")).unwrap();
+        assert_eq!(
+            found
+                .iter()
+                .map(|found| (found.found_in, found.position, found.task.task.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    FoundIn::List(PositionsIn::RegularTodo),
+                    1,
+                    "this is the todo list"
+                ),
+                (FoundIn::List(PositionsIn::RegularTodo), 2, "Make-a-LIST"),
+                (
+                    FoundIn::Archive(TaskList::Regular),
+                    1,
+                    "this is the done list"
+                ),
+            ]
+        );
+        assert!(store
+            .search(&SearchPattern::regex("^list").unwrap())
+            .unwrap()
+            .is_empty());
+
+        std::fs::remove_dir_all(&test_dir).unwrap();
+    }
+
+    #[test]
+    fn lists_are_parsed() {
+        assert_eq!("deadline".parse::<TaskList>(), Ok(TaskList::Deadline));
+        assert!("dl".parse::<TaskList>().is_err());
+    }
+}
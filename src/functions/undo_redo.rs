@@ -0,0 +1,278 @@
+use crate::functions::{
+    archive::{read_archive, Archive, ARCHIVE_FILE_NAME},
+    errors::ChartodoError,
+    history::{history_len, read_history_after, rewind_history, HistoryEvent, HISTORY_FILE_NAME},
+    json_file_structs::*,
+    output,
+    storage_helpers::*,
+};
+use serde::{Deserialize, Serialize};
+use std::{io::Write, path::PathBuf};
+
+// every command that changes a list saves what all three lists looked like before it ran. undo puts the lists back
+// to that, and redo reverses an undo. undo_history.json sits next to chartodo.json
+//
+// the archive is only kept in a snapshot when the command changed it, e.g., archive or cleardone --archive, so that
+// the whole archive isn't copied for every command. history.jsonl is only ever added to by a command, so all a
+// snapshot needs is how long it was, in bytes, which doesn't need the file to be read. undo takes back the lines
+// after that, and they're kept so that redo can put them back
+
+// only this many commands can be undone. the oldest snapshot is dropped once there are more
+const MAX_UNDO_HISTORY: usize = 20;
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct Snapshot {
+    pub command: String,
+    pub regular_tasks: Tasks,
+    pub deadline_tasks: Tasks,
+    pub repeating_tasks: Tasks,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive: Option<Archive>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub history: Option<HistoryCheckpoint>,
+}
+
+// what history.jsonl is put back to: its first len bytes, followed by events
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct HistoryCheckpoint {
+    pub len: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<HistoryEvent>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+pub struct UndoHistory {
+    pub undo: Vec<Snapshot>,
+    pub redo: Vec<Snapshot>,
+}
+
+fn path_to_undo_history() -> Result<PathBuf, ChartodoError> {
+    let mut undo_history_path = path_to_chartodo_dir()?;
+    undo_history_path.push("undo_history.json");
+
+    Ok(undo_history_path)
+}
+
+fn open_undo_history() -> Result<UndoHistory, ChartodoError> {
+    let undo_history_path = path_to_undo_history()?;
+    if !undo_history_path.exists() {
+        return Ok(UndoHistory::default());
+    }
+
+    // losing the undo history isn't worth stopping the user over, so a bad file is moved aside and a new
+    // history is started
+    match try_read_json_from_file(&undo_history_path) {
+        Ok(undo_history) => Ok(undo_history.unwrap_or_default()),
+        Err(parse_error) => {
+            let _ = move_corrupt_file_aside(&undo_history_path);
+            eprintln!("WARNING: {:#}. The undo history was reset.", parse_error);
+
+            Ok(UndoHistory::default())
+        }
+    }
+}
+
+fn write_changes_to_undo_history(undo_history: &UndoHistory) -> Result<(), ChartodoError> {
+    try_write_json_to_file(&path_to_undo_history()?, undo_history)
+        .map_err(|write_error| ChartodoError::Storage(format!("{:#}", write_error)))
+}
+
+fn path_to_archive() -> Result<PathBuf, ChartodoError> {
+    Ok(path_to_chartodo_dir()?.join(ARCHIVE_FILE_NAME))
+}
+
+fn path_to_history() -> Result<PathBuf, ChartodoError> {
+    Ok(path_to_chartodo_dir()?.join(HISTORY_FILE_NAME))
+}
+
+pub fn take_snapshot(command: String) -> Result<Snapshot, ChartodoError> {
+    // housekeeping
+    chartodo_file_create_if_needed()?;
+    let chartodo_file = open_chartodo_file()?;
+
+    Ok(Snapshot {
+        command,
+        regular_tasks: chartodo_file.regular,
+        deadline_tasks: chartodo_file.deadline,
+        repeating_tasks: chartodo_file.repeating,
+        // an archive that can't be read can't be put back either, and the same goes for the history
+        archive: read_archive(&path_to_archive()?).ok(),
+        history: history_len(&path_to_history()?)
+            .ok()
+            .map(|len| HistoryCheckpoint {
+                len,
+                events: vec![],
+            }),
+    })
+}
+
+fn restore_snapshot(snapshot: Snapshot) -> Result<(), ChartodoError> {
+    // all three lists are in one file, so they're put back in one write
+    let mut chartodo_file = open_chartodo_file()?;
+    chartodo_file.regular = snapshot.regular_tasks;
+    chartodo_file.deadline = snapshot.deadline_tasks;
+    chartodo_file.repeating = snapshot.repeating_tasks;
+
+    write_chartodo_file(&mut chartodo_file)?;
+
+    if let Some(archive) = snapshot.archive {
+        if let Err(write_error) = try_write_json_to_file(&path_to_archive()?, &archive) {
+            eprintln!("WARNING: {:#}. The archive wasn't put back.", write_error);
+        }
+    }
+
+    if let Some(history) = snapshot.history {
+        if let Err(write_error) = restore_history(history) {
+            eprintln!("WARNING: {:#}. The history wasn't put back.", write_error);
+        }
+    }
+
+    Ok(())
+}
+
+fn restore_history(history: HistoryCheckpoint) -> anyhow::Result<()> {
+    rewind_history(&path_to_history()?, history.len, &history.events)
+}
+
+fn push_snapshot_and_drop_oldest(snapshots: &mut Vec<Snapshot>, snapshot: Snapshot) {
+    snapshots.push(snapshot);
+    if snapshots.len() > MAX_UNDO_HISTORY {
+        snapshots.drain(..snapshots.len() - MAX_UNDO_HISTORY);
+    }
+}
+
+// called after a command ran, with the snapshot from before it ran. if nothing changed, e.g., the command errored,
+// there is nothing to undo and the history is left alone
+pub fn record_undo_snapshot(mut before_command: Snapshot) -> Result<(), ChartodoError> {
+    let after_command = take_snapshot(before_command.command.clone())?;
+    if after_command.archive == before_command.archive {
+        before_command.archive = None;
+    }
+    if after_command.history == before_command.history {
+        before_command.history = None;
+    }
+    if after_command.regular_tasks == before_command.regular_tasks
+        && after_command.deadline_tasks == before_command.deadline_tasks
+        && after_command.repeating_tasks == before_command.repeating_tasks
+        && before_command.archive.is_none()
+        && before_command.history.is_none()
+    {
+        return Ok(());
+    }
+
+    let mut undo_history = open_undo_history()?;
+    push_snapshot_and_drop_oldest(&mut undo_history.undo, before_command);
+    // a new change makes whatever was undone before impossible to redo
+    undo_history.redo.clear();
+    write_changes_to_undo_history(&undo_history)?;
+
+    Ok(())
+}
+
+// what's about to be put back over, so that it can be put back in turn. the archive and the history are only kept
+// if they're about to be put back over too. for the history, that's the lines that are about to be taken back
+fn current_snapshot(snapshot: &Snapshot) -> Result<Snapshot, ChartodoError> {
+    let mut current = take_snapshot(snapshot.command.clone())?;
+    if snapshot.archive.is_none() {
+        current.archive = None;
+    }
+    let history_path = path_to_history()?;
+    current.history = snapshot.history.as_ref().and_then(|history| {
+        let events = read_history_after(&history_path, history.len).ok()?;
+
+        Some(HistoryCheckpoint {
+            len: history.len,
+            events,
+        })
+    });
+
+    Ok(current)
+}
+
+pub fn undo() -> Result<(), ChartodoError> {
+    let writer = &mut output::writer();
+    let mut undo_history = open_undo_history()?;
+
+    let Some(snapshot) = undo_history.undo.pop() else {
+        return Err(ChartodoError::NothingToUndo(
+            "There is nothing to undo.".to_string(),
+        ));
+    };
+
+    // save what the lists look like right now so that the undo itself can be redone
+    let current = current_snapshot(&snapshot)?;
+    push_snapshot_and_drop_oldest(&mut undo_history.redo, current);
+
+    // only reported once the lists and the undo history are both back in step
+    let command = snapshot.command.clone();
+    restore_snapshot(snapshot)?;
+    write_changes_to_undo_history(&undo_history)?;
+    writeln!(writer, "Undid: {}", command).expect("writeln failed");
+
+    Ok(())
+}
+
+pub fn redo() -> Result<(), ChartodoError> {
+    let writer = &mut output::writer();
+    let mut undo_history = open_undo_history()?;
+
+    let Some(snapshot) = undo_history.redo.pop() else {
+        return Err(ChartodoError::NothingToRedo(
+            "There is nothing to redo.".to_string(),
+        ));
+    };
+
+    let current = current_snapshot(&snapshot)?;
+    push_snapshot_and_drop_oldest(&mut undo_history.undo, current);
+
+    let command = snapshot.command.clone();
+    restore_snapshot(snapshot)?;
+    write_changes_to_undo_history(&undo_history)?;
+    writeln!(writer, "Redid: {}", command).expect("writeln failed");
+
+    Ok(())
+}
+
+// cargo test undo_redo_unit_tests
+#[cfg(test)]
+mod undo_redo_unit_tests {
+    use super::*;
+
+    fn empty_snapshot(command: &str) -> Snapshot {
+        let empty: Tasks = serde_json::from_str(r#"{"todo": [], "done": []}"#).unwrap();
+
+        Snapshot {
+            command: command.to_string(),
+            regular_tasks: empty.clone(),
+            deadline_tasks: empty.clone(),
+            repeating_tasks: empty,
+            archive: None,
+            history: None,
+        }
+    }
+
+    #[test]
+    fn push_snapshot_keeps_order() {
+        let mut snapshots: Vec<Snapshot> = vec![];
+        push_snapshot_and_drop_oldest(&mut snapshots, empty_snapshot("chartodo add 1"));
+        push_snapshot_and_drop_oldest(&mut snapshots, empty_snapshot("chartodo add 2"));
+
+        assert_eq!(snapshots.len(), 2);
+        assert_eq!(snapshots.last().unwrap().command, "chartodo add 2");
+    }
+
+    #[test]
+    fn push_snapshot_drops_oldest_when_full() {
+        let mut snapshots: Vec<Snapshot> = vec![];
+        for i in 0..MAX_UNDO_HISTORY + 5 {
+            push_snapshot_and_drop_oldest(&mut snapshots, empty_snapshot(&format!("{}", i)));
+        }
+
+        assert_eq!(snapshots.len(), MAX_UNDO_HISTORY);
+        assert_eq!(snapshots.first().unwrap().command, "5");
+        assert_eq!(
+            snapshots.last().unwrap().command,
+            format!("{}", MAX_UNDO_HISTORY + 4)
+        );
+    }
+}
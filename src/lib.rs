@@ -1 +1,19 @@
 pub mod functions;
+
+// what's needed to use chartodo as a library, w/o going through the cli. see TaskStore
+pub use functions::{
+    archive::{read_archive, Archive},
+    config::{read_config, Appearance, Config, Defaults, Overflow, Retention, TableStyle},
+    errors::ChartodoError,
+    file_lock::{lock_chartodo_dir, ChartodoLock},
+    history::{read_history, HistoryEvent, HistoryEventKind},
+    json_file_structs::{Priority, Repeat, RepeatUnit, Task, TaskKind, Tasks},
+    recurrence::{next_occurrence, CatchUp, RepeatOn, RepeatRules},
+    search::{FoundIn, SearchMatch, SearchPattern},
+    storage_backend::{JsonBackend, StorageBackend},
+    task_ids::PositionsIn,
+    task_store::{TaskList, TaskStore},
+};
+
+#[cfg(feature = "sqlite")]
+pub use functions::sqlite_backend::SqliteBackend;
@@ -1,644 +1,1141 @@
-mod functions;
-
-use anyhow::{Context, Ok, Result};
-use clap::Parser;
-use functions::{
+use anyhow::Result;
+use chartodo::functions::{
+    completions::*,
+    date_parsing::{parse_from, parse_until},
     deadline_tasks::{deadline_done::*, deadline_todo::*},
+    errors::ChartodoError,
     general_commands::*,
+    json_file_structs::{parse_tag, Priority},
+    output::{self, output_format, set_output_format, OutputFormat},
+    recurrence::{CatchUp, RepeatOn, RepeatRules},
     regular_tasks::{regular_done::*, regular_todo::*},
     repeating_tasks::{repeating_done::*, repeating_todo::*},
+    storage_helpers::{lock_chartodo_dir_for_command, set_data_dir_override},
+    task_ids::*,
+    task_store::TaskList,
+    undo_redo::*,
 };
-use std::io::Write;
+use chrono::NaiveDateTime;
+use clap::{CommandFactory, Parser, Subcommand};
+use clap_complete::{engine::ArgValueCandidates, env::CompleteEnv};
+use std::{io::Write, path::PathBuf};
 
 #[derive(Parser)]
+#[command(disable_help_subcommand = true)]
 struct Cli {
     /// The action taken
-    command: String,
-    /// This has several functions:
-    /// 1. for commands that take positions, they would go here
-    /// 2. for a command like edit, both position and edit-item would be here
-    item_identifier: Option<Vec<String>>,
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Use this folder for the task files instead of the default data dir.
+    /// Takes priority over the CHARTODO_HOME environment variable
+    #[arg(long, value_name = "DIR", global = true)]
+    data_dir: Option<PathBuf>,
+    /// Show the lists and what went wrong as text, or as one json object for scripts
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text, global = true)]
+    format: OutputFormat,
 }
 
-fn main() -> Result<()> {
-    let args = Cli::parse();
-
-    // since printing the list is separate from normal commands (due to how repeating tasks are handled), and since functions
-    // will print to the terminal if an user error occurs, to avoid printing both the list and error if an error occurs,
-    // we'll flag via bool for an error from a fn (if necessary) and won't print the list if it was tripped
-    match args.command.as_str() {
-        "help" | "h" if args.item_identifier.is_none() => {
-            help();
-            Ok(())
-        }
-        "regular-help" | "r-h" if args.item_identifier.is_none() => {
-            regular_help();
-            Ok(())
-        }
-        "deadline-help" | "dl-h" if args.item_identifier.is_none() => {
-            deadline_help();
-            Ok(())
-        }
-        "repeating-help" | "rp-h" if args.item_identifier.is_none() => {
-            repeating_help();
-            Ok(())
-        }
-        "list" | "l" if args.item_identifier.is_none() => {
-            list();
-            Ok(())
-        }
-        "add" | "a" => {
-            regular_tasks_add_todo(args.item_identifier.with_context(|| {
-                format!(
-                    "Did not provide the todo item(s) \
-                    to be added. Good example: chartodo {} new-item, or chartodo {} item next-item \
-                    one-more-item. If you have questions, try chartodo help or chartodo --help",
-                    args.command, args.command
-                )
-            })?);
-            list();
-
-            Ok(())
-        }
-        "done" | "d" => {
-            let error_status =
-                regular_tasks_change_todo_to_done(args.item_identifier.with_context(|| {
-                    format!(
-                        "Did not provide the todo item(s) \
-                        to be changed to done. Good example: chartodo {} 3, or chartodo {} 3 4 5. \
-                        If you have questions, try chartodo help or chartodo --help",
-                        args.command, args.command
-                    )
-                })?);
-            if !error_status {
-                list();
-            }
-
-            Ok(())
-        }
-        "rmtodo" | "rmt" => {
-            let error_status =
-                regular_tasks_remove_todo(args.item_identifier.with_context(|| {
-                    format!(
-                        "Did not provide the todo item(s) \
-                        to be removed. Good example: chartodo {} 3, or chartodo {} 3 4 5. If you \
-                        have more questions, try chartodo help or chartodo --help",
-                        args.command, args.command
-                    )
-                })?);
-            if !error_status {
-                list();
-            }
-
-            Ok(())
-        }
-        "cleartodo" | "ct" if args.item_identifier.is_none() => {
-            let error_status = regular_tasks_clear_todo();
-            if !error_status {
-                list();
-            }
-
-            Ok(())
-        }
-        "doneall" | "da" if args.item_identifier.is_none() => {
-            let error_status = regular_tasks_change_all_todo_to_done();
-            if !error_status {
-                list();
-            }
-
-            Ok(())
-        }
-        "cleardone" | "cd" if args.item_identifier.is_none() => {
-            let error_status = regular_tasks_clear_done();
-            if !error_status {
-                list()
-            }
-
-            Ok(())
-        }
-        "clearboth" | "cb" if args.item_identifier.is_none() => {
-            let error_status = clear_regular_tasks();
-            if !error_status {
-                list();
-            }
+// note: the args are all kept as strings on purpose. the fns that use them check them and give much more helpful
+// errors than clap would, e.g., what the list's length is when a position is too big. clap still makes sure the
+// right args are there for the commands that don't have chaining
+#[derive(Subcommand)]
+enum Command {
+    /// show help
+    #[command(visible_alias = "h")]
+    Help,
+    /// show help for regular tasks
+    #[command(visible_alias = "r-h")]
+    RegularHelp,
+    /// show help for deadline tasks
+    #[command(visible_alias = "dl-h")]
+    DeadlineHelp,
+    /// show help for repeating tasks
+    #[command(visible_alias = "rp-h")]
+    RepeatingHelp,
+    /// show the todo list
+    #[command(visible_alias = "l")]
+    List {
+        /// only show the tasks w/ this tag, e.g., backend, +backend, or @errands
+        #[arg(short, long)]
+        tag: Option<String>,
+    },
+    /// clear everything (TODO, DEADLINE, REPEATING)
+    #[command(visible_alias = "ca")]
+    Clearall {
+        /// move the done tasks to the archive instead of deleting them
+        #[arg(long)]
+        archive: bool,
+    },
+    /// clear all regular todo and done tasks
+    #[command(visible_alias = "ca-r")]
+    ClearallRegular {
+        /// move the done tasks to the archive instead of deleting them
+        #[arg(long)]
+        archive: bool,
+    },
+    /// clear all deadline todo and done tasks
+    #[command(visible_alias = "ca-d")]
+    ClearallDeadline {
+        /// move the done tasks to the archive instead of deleting them
+        #[arg(long)]
+        archive: bool,
+    },
+    /// clear all repeating todo and done tasks
+    #[command(visible_alias = "ca-rp")]
+    ClearallRepeating {
+        /// move the done tasks to the archive instead of deleting them
+        #[arg(long)]
+        archive: bool,
+    },
+    /// undo the last command that changed a list. Up to 20 commands can be undone
+    #[command(visible_alias = "u")]
+    Undo,
+    /// redo the last command that was undone
+    Redo,
+    /// print a tab-completion script for your shell, e.g., chartodo completions bash
+    Completions { shell: CompletionShell },
+    /// show what's happened to tasks, e.g., when they were done, oldest first
+    History {
+        /// only what happened on or after this date, e.g., --from 2099-01-01 or --from -7d
+        #[arg(long, value_name = "DATE", value_parser = parse_from, allow_hyphen_values = true)]
+        from: Option<NaiveDateTime>,
+        /// only what happened on or before this date
+        #[arg(long, value_name = "DATE", value_parser = parse_until, allow_hyphen_values = true)]
+        to: Option<NaiveDateTime>,
+    },
+    /// find tasks in every list and the archive by their text, e.g., chartodo search milk
+    #[command(visible_alias = "s")]
+    Search {
+        query: String,
+        /// match the text against a regex instead, e.g., --regex '^buy-'. case is still ignored
+        #[arg(long)]
+        regex: bool,
+    },
+    /// start empty lists, or a copy of a template, e.g., chartodo init --template team.json
+    Init {
+        /// a chartodo.json to start from instead of empty lists
+        #[arg(long, value_name = "FILE")]
+        template: Option<PathBuf>,
+    },
+    /// show or change a setting in config.toml, e.g., chartodo config set display.table_style ascii
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+    /// show the done tasks that were archived, by kind of task
+    #[command(visible_alias = "ar-l")]
+    ArchiveList,
+    /// move archived tasks back to their done list, e.g., chartodo archive-restore deadline 1 3-4. Has chaining and
+    /// range positioning
+    #[command(visible_alias = "ar-r")]
+    ArchiveRestore {
+        /// which archive: regular, deadline, or repeating
+        list: TaskList,
+        #[arg(required = true, value_name = "POSITION")]
+        positions: Vec<String>,
+    },
+
+    /// add an item to the todo list. Has chaining
+    #[command(visible_alias = "a")]
+    Add {
+        #[arg(required = true, value_name = "TASK")]
+        tasks: Vec<String>,
+        /// give the added task(s) a priority: high, medium, or low
+        #[arg(short, long)]
+        priority: Option<Priority>,
+        /// tag the added task(s), e.g., --tag backend --tag @errands
+        #[arg(short, long = "tag", value_name = "TAG", value_parser = parse_tag)]
+        tags: Vec<String>,
+    },
+    /// change a todo item to done. Has chaining and range positioning
+    #[command(visible_alias = "d")]
+    Done {
+        #[arg(required = true, value_name = "POSITION", add = ArgValueCandidates::new(regular_todo_positions))]
+        positions: Vec<String>,
+    },
+    /// remove a todo item from the list. Has chaining and range positioning
+    #[command(visible_alias = "rmt")]
+    Rmtodo {
+        #[arg(required = true, value_name = "POSITION", add = ArgValueCandidates::new(regular_todo_positions))]
+        positions: Vec<String>,
+    },
+    /// change all todo items to done
+    #[command(visible_alias = "da")]
+    Doneall,
+    /// clear the todo list
+    #[command(visible_alias = "ct")]
+    Cleartodo,
+    /// clear both todo and done lists
+    #[command(visible_alias = "cb")]
+    Clearboth {
+        /// move the done tasks to the archive instead of deleting them
+        #[arg(long)]
+        archive: bool,
+    },
+    /// changes a todo item, with its position specified, to what you want
+    #[command(visible_alias = "e")]
+    Edit {
+        #[arg(add = ArgValueCandidates::new(regular_todo_positions))]
+        position: String,
+        task: String,
+    },
+    /// changes the priority of a todo item: high, medium, low, or none
+    #[command(visible_alias = "ep")]
+    EditPriority {
+        #[arg(add = ArgValueCandidates::new(regular_todo_positions))]
+        position: String,
+        priority: String,
+    },
+    /// add tags to a todo item, e.g., backend, +backend, or @errands
+    #[command(visible_alias = "tg")]
+    Tag {
+        #[arg(add = ArgValueCandidates::new(regular_todo_positions))]
+        position: String,
+        #[arg(required = true, value_name = "TAG")]
+        tags: Vec<String>,
+    },
+    /// take tags away from a todo item
+    #[command(visible_alias = "utg")]
+    Untag {
+        #[arg(add = ArgValueCandidates::new(regular_todo_positions))]
+        position: String,
+        #[arg(required = true, value_name = "TAG")]
+        tags: Vec<String>,
+    },
+    /// reverses a done item back to a todo item. Has chaining and range positioning
+    #[command(visible_alias = "nd")]
+    Notdone {
+        #[arg(required = true, value_name = "POSITION", add = ArgValueCandidates::new(regular_done_positions))]
+        positions: Vec<String>,
+    },
+    /// removes a done item. Has chaining and range positioning
+    #[command(visible_alias = "rmd")]
+    Rmdone {
+        #[arg(required = true, value_name = "POSITION", add = ArgValueCandidates::new(regular_done_positions))]
+        positions: Vec<String>,
+    },
+    /// reverses all done items back to todo
+    #[command(visible_alias = "nda")]
+    Notdoneall,
+    /// clears the done list
+    #[command(visible_alias = "cd")]
+    Cleardone {
+        /// move the done tasks to the archive instead of deleting them
+        #[arg(long)]
+        archive: bool,
+    },
+    /// moves a done item to the archive. Has chaining and range positioning
+    #[command(visible_alias = "ar")]
+    Archive {
+        #[arg(required = true, value_name = "POSITION", add = ArgValueCandidates::new(regular_done_positions))]
+        positions: Vec<String>,
+    },
+
+    /// adds a task with a day and time limit. Has chaining
+    #[command(visible_alias = "dl-a")]
+    DeadlineAdd {
+        // clap only has one value_parser per argument, so these are parsed into sets by main, see deadline_sets
+        #[arg(required = true, value_names = ["TASK", "DATE", "TIME"])]
+        task_date_time: Vec<String>,
+        /// give the added task(s) a priority: high, medium, or low
+        #[arg(short, long)]
+        priority: Option<Priority>,
+        /// tag the added task(s), e.g., --tag backend --tag @errands
+        #[arg(short, long = "tag", value_name = "TAG", value_parser = parse_tag)]
+        tags: Vec<String>,
+    },
+    /// adds a deadline task. only the date is specified and time defaults to 00:00. Has chaining
+    #[command(visible_alias = "dl-aod")]
+    DeadlineAddonlydate {
+        // clap only has one value_parser per argument, so these are parsed into sets by main, see deadline_sets
+        #[arg(required = true, value_names = ["TASK", "DATE"])]
+        task_date: Vec<String>,
+        /// give the added task(s) a priority: high, medium, or low
+        #[arg(short, long)]
+        priority: Option<Priority>,
+        /// tag the added task(s), e.g., --tag backend --tag @errands
+        #[arg(short, long = "tag", value_name = "TAG", value_parser = parse_tag)]
+        tags: Vec<String>,
+    },
+    /// adds a deadline task. only the time is specified and date defaults to current date. Has chaining
+    #[command(visible_alias = "dl-aot")]
+    DeadlineAddonlytime {
+        // clap only has one value_parser per argument, so these are parsed into sets by main, see deadline_sets
+        #[arg(required = true, value_names = ["TASK", "TIME"])]
+        task_time: Vec<String>,
+        /// give the added task(s) a priority: high, medium, or low
+        #[arg(short, long)]
+        priority: Option<Priority>,
+        /// tag the added task(s), e.g., --tag backend --tag @errands
+        #[arg(short, long = "tag", value_name = "TAG", value_parser = parse_tag)]
+        tags: Vec<String>,
+    },
+    /// mark one/several deadline task(s) as done. Has chaining and range positioning
+    #[command(visible_alias = "dl-d")]
+    DeadlineDone {
+        #[arg(required = true, value_name = "POSITION", add = ArgValueCandidates::new(deadline_todo_positions))]
+        positions: Vec<String>,
+    },
+    /// remove one or several todo item(s). Has chaining and range positioning
+    #[command(visible_alias = "dl-rmt")]
+    DeadlineRmtodo {
+        #[arg(required = true, value_name = "POSITION", add = ArgValueCandidates::new(deadline_todo_positions))]
+        positions: Vec<String>,
+    },
+    /// mark the entire deadline todo list as done
+    #[command(visible_alias = "dl-da")]
+    DeadlineDoneall,
+    /// clear the deadline todo list
+    #[command(visible_alias = "dl-ct")]
+    DeadlineCleartodo,
+    /// clears both of the deadline todo and done lists
+    #[command(visible_alias = "dl-cb")]
+    DeadlineClearboth {
+        /// move the done tasks to the archive instead of deleting them
+        #[arg(long)]
+        archive: bool,
+    },
+    /// edit all the parameters of a deadline todo task
+    #[command(visible_alias = "dl-ea")]
+    DeadlineEditall {
+        #[arg(add = ArgValueCandidates::new(deadline_todo_positions))]
+        position: String,
+        task: String,
+        date: String,
+        time: String,
+    },
+    /// edit the task parameter of a deadline todo task
+    #[command(visible_alias = "dl-eta")]
+    DeadlineEdittask {
+        #[arg(add = ArgValueCandidates::new(deadline_todo_positions))]
+        position: String,
+        task: String,
+    },
+    /// edit the date parameter of a deadline todo task
+    #[command(visible_alias = "dl-ed")]
+    DeadlineEditdate {
+        #[arg(add = ArgValueCandidates::new(deadline_todo_positions))]
+        position: String,
+        date: String,
+    },
+    /// edit the time parameter of a deadline todo task
+    #[command(visible_alias = "dl-eti")]
+    DeadlineEdittime {
+        #[arg(add = ArgValueCandidates::new(deadline_todo_positions))]
+        position: String,
+        time: String,
+    },
+    /// edit the date and time parameter of a deadline todo task
+    #[command(visible_alias = "dl-edt")]
+    DeadlineEditdatetime {
+        #[arg(add = ArgValueCandidates::new(deadline_todo_positions))]
+        position: String,
+        date: String,
+        time: String,
+    },
+    /// edit the priority of a deadline todo task: high, medium, low, or none
+    #[command(visible_alias = "dl-ep")]
+    DeadlineEditpriority {
+        #[arg(add = ArgValueCandidates::new(deadline_todo_positions))]
+        position: String,
+        priority: String,
+    },
+    /// add tags to a deadline todo task, e.g., backend, +backend, or @errands
+    #[command(visible_alias = "dl-tg")]
+    DeadlineTag {
+        #[arg(add = ArgValueCandidates::new(deadline_todo_positions))]
+        position: String,
+        #[arg(required = true, value_name = "TAG")]
+        tags: Vec<String>,
+    },
+    /// take tags away from a deadline todo task
+    #[command(visible_alias = "dl-utg")]
+    DeadlineUntag {
+        #[arg(add = ArgValueCandidates::new(deadline_todo_positions))]
+        position: String,
+        #[arg(required = true, value_name = "TAG")]
+        tags: Vec<String>,
+    },
+    /// reverses a deadline done item back to todo. Has chaining and range positioning
+    #[command(visible_alias = "dl-nd")]
+    DeadlineNotdone {
+        #[arg(required = true, value_name = "POSITION", add = ArgValueCandidates::new(deadline_done_positions))]
+        positions: Vec<String>,
+    },
+    /// removes a deadline done item. Has chaining and range positioning
+    #[command(visible_alias = "dl-rmd")]
+    DeadlineRmdone {
+        #[arg(required = true, value_name = "POSITION", add = ArgValueCandidates::new(deadline_done_positions))]
+        positions: Vec<String>,
+    },
+    /// reverses all deadline done items back to todo
+    #[command(visible_alias = "dl-nda")]
+    DeadlineNotdoneall,
+    /// clears the deadline done list
+    #[command(visible_alias = "dl-cd")]
+    DeadlineCleardone {
+        /// move the done tasks to the archive instead of deleting them
+        #[arg(long)]
+        archive: bool,
+    },
+    /// moves a deadline done item to the archive. Has chaining and range positioning
+    #[command(visible_alias = "dl-ar")]
+    DeadlineArchive {
+        #[arg(required = true, value_name = "POSITION", add = ArgValueCandidates::new(deadline_done_positions))]
+        positions: Vec<String>,
+    },
+
+    /// add a repeating task with a set interval. the task starts from your current date and time. Has chaining
+    #[command(visible_alias = "rp-a")]
+    RepeatingAdd {
+        // clap only has one value_parser per argument, so these are parsed into sets by main, see repeating_sets
+        #[arg(required = true, value_names = ["TASK", "INTERVAL", "UNIT"])]
+        task_interval_unit: Vec<String>,
+        /// give the added task(s) a priority: high, medium, or low
+        #[arg(short, long)]
+        priority: Option<Priority>,
+        /// tag the added task(s), e.g., --tag backend --tag @errands
+        #[arg(short, long = "tag", value_name = "TAG", value_parser = parse_tag)]
+        tags: Vec<String>,
+        /// only land on certain days, e.g., --on mon,wed,fri (w/ weeks) or --on 'last fri' (w/ months)
+        #[arg(long, value_name = "RULE")]
+        on: Option<RepeatOn>,
+        /// what to do about the times it was missed: once (the default), next, or each
+        #[arg(long, value_name = "POLICY", default_value_t)]
+        catch_up: CatchUp,
+        /// stop repeating after this date, e.g., --until 2099-06-30 or --until +6w
+        #[arg(long, value_name = "DATE", value_parser = parse_until)]
+        until: Option<NaiveDateTime>,
+        /// stop repeating after this many times, e.g., --times 12
+        #[arg(long = "times", value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+        max_occurrences: Option<u32>,
+    },
+    /// add a repeating task that starts on your specified datetime. Has chaining
+    #[command(visible_alias = "rp-as")]
+    RepeatingAddstart {
+        // clap only has one value_parser per argument, so these are parsed into sets by main, see repeating_sets
+        #[arg(required = true, value_names = ["TASK", "INTERVAL", "UNIT", "DATE", "TIME"])]
+        task_interval_unit_date_time: Vec<String>,
+        /// give the added task(s) a priority: high, medium, or low
+        #[arg(short, long)]
+        priority: Option<Priority>,
+        /// tag the added task(s), e.g., --tag backend --tag @errands
+        #[arg(short, long = "tag", value_name = "TAG", value_parser = parse_tag)]
+        tags: Vec<String>,
+        /// only land on certain days, e.g., --on mon,wed,fri (w/ weeks) or --on 'last fri' (w/ months)
+        #[arg(long, value_name = "RULE")]
+        on: Option<RepeatOn>,
+        /// what to do about the times it was missed: once (the default), next, or each
+        #[arg(long, value_name = "POLICY", default_value_t)]
+        catch_up: CatchUp,
+        /// stop repeating after this date, e.g., --until 2099-06-30 or --until +6w
+        #[arg(long, value_name = "DATE", value_parser = parse_until)]
+        until: Option<NaiveDateTime>,
+        /// stop repeating after this many times, e.g., --times 12
+        #[arg(long = "times", value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+        max_occurrences: Option<u32>,
+    },
+    /// add a repeating task that ends on your specified datetime. Has chaining
+    #[command(visible_alias = "rp-ae")]
+    RepeatingAddend {
+        // clap only has one value_parser per argument, so these are parsed into sets by main, see repeating_sets
+        #[arg(required = true, value_names = ["TASK", "INTERVAL", "UNIT", "DATE", "TIME"])]
+        task_interval_unit_date_time: Vec<String>,
+        /// give the added task(s) a priority: high, medium, or low
+        #[arg(short, long)]
+        priority: Option<Priority>,
+        /// tag the added task(s), e.g., --tag backend --tag @errands
+        #[arg(short, long = "tag", value_name = "TAG", value_parser = parse_tag)]
+        tags: Vec<String>,
+        /// only land on certain days, e.g., --on mon,wed,fri (w/ weeks) or --on 'last fri' (w/ months)
+        #[arg(long, value_name = "RULE")]
+        on: Option<RepeatOn>,
+        /// what to do about the times it was missed: once (the default), next, or each
+        #[arg(long, value_name = "POLICY", default_value_t)]
+        catch_up: CatchUp,
+        /// stop repeating after this date, e.g., --until 2099-06-30 or --until +6w
+        #[arg(long, value_name = "DATE", value_parser = parse_until)]
+        until: Option<NaiveDateTime>,
+        /// stop repeating after this many times, e.g., --times 12
+        #[arg(long = "times", value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+        max_occurrences: Option<u32>,
+    },
+    /// mark repeating todos as done. Has chaining and range positioning
+    #[command(visible_alias = "rp-d")]
+    RepeatingDone {
+        #[arg(required = true, value_name = "POSITION", add = ArgValueCandidates::new(repeating_todo_positions))]
+        positions: Vec<String>,
+    },
+    /// reset the starting datetime of a repeating task to now. Has chaining and range positioning
+    #[command(visible_aliases = ["repeating-donereset", "rp-r", "rp-dr"])]
+    RepeatingReset {
+        #[arg(required = true, value_name = "POSITION", add = ArgValueCandidates::new(repeating_todo_positions))]
+        positions: Vec<String>,
+    },
+    /// remove a repeating todo task. Has chaining and range positioning
+    #[command(visible_alias = "rp-rmt")]
+    RepeatingRmtodo {
+        #[arg(required = true, value_name = "POSITION", add = ArgValueCandidates::new(repeating_todo_positions))]
+        positions: Vec<String>,
+    },
+    /// show the starting datetime of one or more repeating tasks. Has chaining and range positioning
+    #[command(visible_alias = "rp-s")]
+    RepeatingStart {
+        #[arg(required = true, value_name = "POSITION", add = ArgValueCandidates::new(repeating_todo_positions))]
+        positions: Vec<String>,
+    },
+    /// mark all repeating tasks as done
+    #[command(visible_alias = "rp-da")]
+    RepeatingDoneall,
+    /// delete all of the repeating todo tasks
+    #[command(visible_alias = "rp-ct")]
+    RepeatingCleartodo,
+    /// clear the repeating todo and done lists
+    #[command(visible_alias = "rp-cb")]
+    RepeatingClearboth {
+        /// move the done tasks to the archive instead of deleting them
+        #[arg(long)]
+        archive: bool,
+    },
+    /// reset the starting datetime of every repeating task to now
+    #[command(visible_aliases = ["rp-ra", "repeating-doneresetall", "rp-dra"])]
+    RepeatingResetall,
+    /// show the starting datetime of all repeating tasks
+    #[command(visible_alias = "rp-sa")]
+    RepeatingStartall,
+    /// edit all the parameters of a repeating task: task, interval, time unit, and starting/ending datetime
+    #[command(visible_alias = "rp-ea")]
+    RepeatingEditall {
+        #[arg(add = ArgValueCandidates::new(repeating_todo_positions))]
+        position: String,
+        task: String,
+        interval: String,
+        unit: String,
+        /// either start or end
+        start_or_end: String,
+        date: String,
+        time: String,
+    },
+    /// edit the task parameter of a repeating task
+    #[command(visible_alias = "rp-eta")]
+    RepeatingEdittask {
+        #[arg(add = ArgValueCandidates::new(repeating_todo_positions))]
+        position: String,
+        task: String,
+    },
+    /// edit the interval of a repeating task
+    #[command(visible_alias = "rp-ei")]
+    RepeatingEditinterval {
+        #[arg(add = ArgValueCandidates::new(repeating_todo_positions))]
+        position: String,
+        interval: String,
+    },
+    // note: I don't like unit, it's too vague. but time unit is also too long
+    /// edit the time unit of a repeating task
+    #[command(visible_alias = "rp-eu")]
+    RepeatingEditunit {
+        #[arg(add = ArgValueCandidates::new(repeating_todo_positions))]
+        position: String,
+        unit: String,
+    },
+    /// edit the interval and time unit of a repeating task
+    #[command(visible_alias = "rp-eiu")]
+    RepeatingEditintervalunit {
+        #[arg(add = ArgValueCandidates::new(repeating_todo_positions))]
+        position: String,
+        interval: String,
+        unit: String,
+    },
+    /// edit the starting datetime of a repeating task
+    #[command(visible_alias = "rp-es")]
+    RepeatingEditstart {
+        #[arg(add = ArgValueCandidates::new(repeating_todo_positions))]
+        position: String,
+        date: String,
+        time: String,
+    },
+    /// edit the ending datetime of a repeating task
+    #[command(visible_alias = "rp-ee")]
+    RepeatingEditend {
+        #[arg(add = ArgValueCandidates::new(repeating_todo_positions))]
+        position: String,
+        date: String,
+        time: String,
+    },
+    /// edit which days a repeating task lands on, e.g., mon,wed,fri, weekdays, '2nd tue', 'last day', or none
+    #[command(visible_alias = "rp-eo")]
+    RepeatingEditon {
+        #[arg(add = ArgValueCandidates::new(repeating_todo_positions))]
+        position: String,
+        rule: String,
+    },
+    /// edit what a repeating task does about the times it was missed: once, next, or each
+    #[command(visible_alias = "rp-ec")]
+    RepeatingEditcatchup {
+        #[arg(add = ArgValueCandidates::new(repeating_todo_positions))]
+        position: String,
+        catch_up: String,
+    },
+    /// edit the priority of a repeating task: high, medium, low, or none
+    #[command(visible_alias = "rp-ep")]
+    RepeatingEditpriority {
+        #[arg(add = ArgValueCandidates::new(repeating_todo_positions))]
+        position: String,
+        priority: String,
+    },
+    /// add tags to a repeating task, e.g., backend, +backend, or @errands
+    #[command(visible_alias = "rp-tg")]
+    RepeatingTag {
+        #[arg(add = ArgValueCandidates::new(repeating_todo_positions))]
+        position: String,
+        #[arg(required = true, value_name = "TAG")]
+        tags: Vec<String>,
+    },
+    /// take tags away from a repeating task
+    #[command(visible_alias = "rp-utg")]
+    RepeatingUntag {
+        #[arg(add = ArgValueCandidates::new(repeating_todo_positions))]
+        position: String,
+        #[arg(required = true, value_name = "TAG")]
+        tags: Vec<String>,
+    },
+    /// reverse repeating dones back to todo. Has chaining and range positioning
+    #[command(visible_alias = "rp-nd")]
+    RepeatingNotdone {
+        #[arg(required = true, value_name = "POSITION", add = ArgValueCandidates::new(repeating_done_positions))]
+        positions: Vec<String>,
+    },
+    /// remove one/several repeating done task(s). Has chaining and range positioning
+    #[command(visible_alias = "rp-rmd")]
+    RepeatingRmdone {
+        #[arg(required = true, value_name = "POSITION", add = ArgValueCandidates::new(repeating_done_positions))]
+        positions: Vec<String>,
+    },
+    /// reverse all finished repeating tasks back to todo
+    #[command(visible_alias = "rp-nda")]
+    RepeatingNotdoneall,
+    /// delete all of the finished repeating tasks
+    #[command(visible_alias = "rp-cd")]
+    RepeatingCleardone {
+        /// move the done tasks to the archive instead of deleting them
+        #[arg(long)]
+        archive: bool,
+    },
+    /// moves a finished repeating task to the archive. Has chaining and range positioning
+    #[command(visible_alias = "rp-ar")]
+    RepeatingArchive {
+        #[arg(required = true, value_name = "POSITION", add = ArgValueCandidates::new(repeating_done_positions))]
+        positions: Vec<String>,
+    },
+
+    // anything that isn't a command above ends up here, so that chartodo can tell the user instead of clap
+    #[command(external_subcommand)]
+    Other(Vec<String>),
+}
 
-            Ok(())
-        }
-        "rmdone" | "rmd" => {
-            let error_status =
-                regular_tasks_remove_done(args.item_identifier.with_context(|| {
-                    format!(
-                        "Did not provide the done item to \
-                        be removed. Good example: chartodo {} 3, or chartodo {} 3 4 5. If you have more \
-                        questions, try chartodo help or chartodo --help",
-                        args.command, args.command
-                    )
-                })?);
-            if !error_status {
-                list();
-            }
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// show a setting, e.g., display.table_style, or every setting if none is given
+    Get { setting: Option<String> },
+    /// change a setting. The rest of config.toml is left as it is
+    Set { setting: String, value: String },
+}
 
-            Ok(())
-        }
-        "notdone" | "nd" => {
-            let error_status = regular_tasks_not_done(args.item_identifier.with_context(|| {
-                format!(
-                    "Did not provide the done item to \
-                    be reversed back to todo. Good example: chartodo {} 3, or chartodo {} 3 4 5. If \
-                    you have more questions, try chartodo help or chartodo --help",
-                    args.command, args.command
-                )
-            })?);
-            if !error_status {
-                list();
-            }
+impl Command {
+    // commands that only show things, or that don't change the lists that are already there. everything else gets a
+    // snapshot for undo
+    fn changes_tasks(&self) -> bool {
+        !matches!(
+            self,
+            Command::Help
+                | Command::RegularHelp
+                | Command::DeadlineHelp
+                | Command::RepeatingHelp
+                | Command::List { .. }
+                | Command::RepeatingStart { .. }
+                | Command::RepeatingStartall
+                | Command::Undo
+                | Command::Redo
+                | Command::Completions { .. }
+                | Command::History { .. }
+                | Command::Search { .. }
+                | Command::ArchiveList
+                | Command::Config { .. }
+                | Command::Init { .. }
+                | Command::Other(_)
+        )
+    }
 
-            Ok(())
-        }
-        "edit" | "e" => {
-            let error_status =
-                regular_tasks_edit_todo(args.item_identifier.with_context(|| {
-                    format!(
-                        "Did not provide the todo item \
-                        to be edited. Good example: chartodo {} 3 abc. If you have more questions, try \
-                        chartodo help or chartodo --help",
-                        args.command
-                    )
-                })?);
-            if !error_status {
-                list();
-            }
+    // whether the command opens anything in the chartodo folder, i.e., needs the lock
+    fn uses_tasks(&self) -> bool {
+        !matches!(
+            self,
+            Command::Help
+                | Command::RegularHelp
+                | Command::DeadlineHelp
+                | Command::RepeatingHelp
+                | Command::Completions { .. }
+                | Command::Other(_)
+        )
+    }
 
-            Ok(())
-        }
-        "notdoneall" | "nda" if args.item_identifier.is_none() => {
-            let error_status = regular_tasks_reverse_all_dones();
-            if !error_status {
-                list();
+    // the positions a command takes, and which list they're in. any #ids in them are swapped for positions before the
+    // command runs
+    fn positions_mut(&mut self) -> Option<(PositionsIn, Vec<&mut String>)> {
+        match self {
+            Command::Done { positions } | Command::Rmtodo { positions } => {
+                Some((PositionsIn::RegularTodo, positions.iter_mut().collect()))
             }
-
-            Ok(())
-        }
-        "deadline-add" | "dl-a" => {
-            let error_status = deadline_tasks_add(
-                args.item_identifier
-                    .context("didn't provide a deadline add argument")?,
-            );
-            if !error_status {
-                list();
+            Command::Edit { position, .. }
+            | Command::EditPriority { position, .. }
+            | Command::Tag { position, .. }
+            | Command::Untag { position, .. } => Some((PositionsIn::RegularTodo, vec![position])),
+            Command::Notdone { positions }
+            | Command::Rmdone { positions }
+            | Command::Archive { positions } => {
+                Some((PositionsIn::RegularDone, positions.iter_mut().collect()))
             }
-
-            Ok(())
-        }
-        "deadline-addonlydate" | "dl-aod" => {
-            let error_status = deadline_tasks_add_no_time(
-                args.item_identifier
-                    .context("didn't provide a deadline-addonlydate argument")?,
-            );
-            if !error_status {
-                list();
+            Command::DeadlineDone { positions } | Command::DeadlineRmtodo { positions } => {
+                Some((PositionsIn::DeadlineTodo, positions.iter_mut().collect()))
             }
-
-            Ok(())
-        }
-        "deadline-addonlytime" | "dl-aot" => {
-            let error_status = deadline_tasks_add_no_date(
-                args.item_identifier
-                    .context("didn't provide a deadline-addonlytime argument")?,
-            );
-            if !error_status {
-                list();
+            Command::DeadlineEditall { position, .. }
+            | Command::DeadlineEdittask { position, .. }
+            | Command::DeadlineEditdate { position, .. }
+            | Command::DeadlineEdittime { position, .. }
+            | Command::DeadlineEditdatetime { position, .. }
+            | Command::DeadlineEditpriority { position, .. }
+            | Command::DeadlineTag { position, .. }
+            | Command::DeadlineUntag { position, .. } => {
+                Some((PositionsIn::DeadlineTodo, vec![position]))
             }
-
-            Ok(())
-        }
-        "deadline-done" | "dl-d" => {
-            let error_status = deadline_tasks_done(
-                args.item_identifier
-                    .context("didn't provide a deadline-done argument")?,
-            );
-            if !error_status {
-                list();
+            Command::DeadlineNotdone { positions }
+            | Command::DeadlineRmdone { positions }
+            | Command::DeadlineArchive { positions } => {
+                Some((PositionsIn::DeadlineDone, positions.iter_mut().collect()))
             }
-
-            Ok(())
-        }
-        "deadline-rmtodo" | "dl-rmt" => {
-            let error_status = deadline_tasks_rmtodo(
-                args.item_identifier
-                    .context("didn't provide a deadline-rmtodo argument")?,
-            );
-            if !error_status {
-                list();
+            Command::RepeatingDone { positions }
+            | Command::RepeatingReset { positions }
+            | Command::RepeatingRmtodo { positions }
+            | Command::RepeatingStart { positions } => {
+                Some((PositionsIn::RepeatingTodo, positions.iter_mut().collect()))
             }
-
-            Ok(())
-        }
-        "deadline-cleartodo" | "dl-ct" if args.item_identifier.is_none() => {
-            let error_status = deadline_tasks_clear_todo();
-            if !error_status {
-                list();
+            Command::RepeatingEditall { position, .. }
+            | Command::RepeatingEdittask { position, .. }
+            | Command::RepeatingEditinterval { position, .. }
+            | Command::RepeatingEditunit { position, .. }
+            | Command::RepeatingEditintervalunit { position, .. }
+            | Command::RepeatingEditstart { position, .. }
+            | Command::RepeatingEditend { position, .. }
+            | Command::RepeatingEditon { position, .. }
+            | Command::RepeatingEditcatchup { position, .. }
+            | Command::RepeatingEditpriority { position, .. }
+            | Command::RepeatingTag { position, .. }
+            | Command::RepeatingUntag { position, .. } => {
+                Some((PositionsIn::RepeatingTodo, vec![position]))
             }
-
-            Ok(())
-        }
-        "deadline-doneall" | "dl-da" if args.item_identifier.is_none() => {
-            let error_status = deadline_tasks_done_all();
-            if !error_status {
-                list();
+            Command::RepeatingNotdone { positions }
+            | Command::RepeatingRmdone { positions }
+            | Command::RepeatingArchive { positions } => {
+                Some((PositionsIn::RepeatingDone, positions.iter_mut().collect()))
             }
-
-            Ok(())
+            _ => None,
         }
-        "deadline-editall" | "dl-ea" => {
-            let error_status = deadline_tasks_edit_all(
-                args.item_identifier
-                    .context("didn't provide arguments for deadline-editall")?,
-            );
-            if !error_status {
-                list();
-            }
+    }
+}
 
-            Ok(())
-        }
-        "deadline-edittask" | "dl-eta" => {
-            let error_status = deadline_tasks_edit_task(
-                args.item_identifier
-                    .context("didn't provide arguments for deadline-edittask")?,
-            );
-            if !error_status {
-                list();
-            }
+fn main() -> Result<()> {
+    // if the shell is asking for completions, answer and exit before anything else runs
+    CompleteEnv::with_factory(Cli::command)
+        .var(COMPLETE_ENV)
+        .complete();
 
-            Ok(())
-        }
-        "deadline-editdate" | "dl-ed" => {
-            let error_status = deadline_tasks_edit_date(
-                args.item_identifier
-                    .context("didn't provide arguments for deadline-editdate")?,
-            );
-            if !error_status {
-                list();
+    let args = Cli::parse();
+    if let Some(data_dir) = args.data_dir {
+        set_data_dir_override(data_dir);
+    }
+    set_output_format(args.format);
+
+    let Some(command) = args.command else {
+        exit_on_error(show_result(Err(no_arg_command())));
+        return Ok(());
+    };
+
+    // held until chartodo exits, so that another chartodo can't read or write the lists in the middle of this command
+    let _lock = match command.uses_tasks() {
+        true => match lock_chartodo_dir_for_command() {
+            Ok(lock) => Some(lock),
+            Err(error) => {
+                exit_on_error(show_result(Err(error)));
+                return Ok(());
             }
-
-            Ok(())
-        }
-        "deadline-edittime" | "dl-eti" => {
-            let error_status = deadline_tasks_edit_time(
-                args.item_identifier
-                    .context("didn't provide arguments for deadline-edittime")?,
-            );
-            if !error_status {
-                list();
+        },
+        false => None,
+    };
+
+    // save what every list looks like before the command runs so that it can be undone. it only ends up in the undo
+    // history if the command actually changed something. if the lists can't even be read, the command doesn't run
+    let before_command = match command.changes_tasks() {
+        true => match take_snapshot(command_line()) {
+            Ok(before_command) => Some(before_command),
+            Err(error) => {
+                exit_on_error(show_result(Err(error)));
+                return Ok(());
             }
+        },
+        false => None,
+    };
 
-            Ok(())
-        }
-        "deadline-clearboth" | "dl-cb" if args.item_identifier.is_none() => {
-            let error_status = clear_deadline_tasks();
-            if !error_status {
-                list();
-            }
+    let result = run_command(command);
 
-            Ok(())
+    // the command already went through, so this only means it can't be undone
+    if let Some(before_command) = before_command {
+        if let Err(error) = record_undo_snapshot(before_command) {
+            eprintln!("WARNING: {}. This command can't be undone.", error);
         }
-        "deadline-rmdone" | "dl-rmd" => {
-            let error_status = deadline_tasks_rmdone(
-                args.item_identifier
-                    .context("didn't provide arguments for deadline-rmdone")?,
-            );
-            if !error_status {
-                list();
-            }
+    }
 
-            Ok(())
-        }
-        "deadline-notdone" | "dl-nd" => {
-            let error_status = deadline_tasks_not_done(
-                args.item_identifier
-                    .context("didn't provide arguments for deadline-notdone")?,
-            );
-            if !error_status {
-                list();
-            }
+    exit_on_error(result);
 
-            Ok(())
-        }
-        "deadline-cleardone" | "dl-cd" if args.item_identifier.is_none() => {
-            let error_status = deadline_tasks_clear_done();
-            if !error_status {
-                list();
-            }
+    Ok(())
+}
 
-            Ok(())
-        }
-        "deadline-notdoneall" | "dl-nda" if args.item_identifier.is_none() => {
-            let error_status = deadline_tasks_notdoneall();
-            if !error_status {
-                list();
-            }
+// each class of error has its own exit code, see ChartodoError::exit_code
+fn exit_on_error(result: Result<(), ChartodoError>) {
+    if let Err(error) = result {
+        std::process::exit(error.exit_code());
+    }
+}
 
-            Ok(())
+fn run_command(mut command: Command) -> Result<(), ChartodoError> {
+    if let Some((list, positions)) = command.positions_mut() {
+        if let Err(error) = replace_ids_with_positions(list, positions) {
+            return show_result(Err(error));
         }
-        "deadline-editdatetime" | "dl-edt" => {
-            let error_status = deadline_tasks_edit_datetime(
-                args.item_identifier
-                    .context("didn't provide arguments for deadline-editdatetime")?,
-            );
-            if !error_status {
-                list();
-            }
+    }
 
-            Ok(())
+    // since printing the list is separate from normal commands (due to how repeating tasks are handled), a command
+    // only returns whether it went through, and the list (or what went wrong) is shown afterward
+    let result = match command {
+        Command::Help => {
+            help();
+            return Ok(());
         }
-        "repeating-add" | "rp-a" => {
-            let error_status = repeating_tasks_add(
-                args.item_identifier
-                    .context("didn't provide arguments for repeating-add")?,
-            );
-            if !error_status {
-                list();
-            }
-
-            Ok(())
+        Command::RegularHelp => {
+            regular_help();
+            return Ok(());
         }
-        "repeating-addstart" | "rp-as" => {
-            let error_status = repeating_tasks_add_start_datetime(
-                args.item_identifier
-                    .context("didn't provide arguments for repeating-addstart")?,
-            );
-            if !error_status {
-                list();
-            }
-
-            Ok(())
+        Command::DeadlineHelp => {
+            deadline_help();
+            return Ok(());
         }
-        "repeating-addend" | "rp-ae" => {
-            let error_status = repeating_tasks_add_end(
-                args.item_identifier
-                    .context("didn't provide arguments for repeating-addend")?,
-            );
-            if !error_status {
-                list();
-            }
-
-            Ok(())
+        Command::RepeatingHelp => {
+            repeating_help();
+            return Ok(());
         }
-        "repeating-done" | "rp-d" => {
-            let error_status = repeating_tasks_done(
-                args.item_identifier
-                    .context("didn't provide arguments for repeating-done")?,
-            );
-            if !error_status {
-                list();
-            }
-
-            Ok(())
+        Command::List { tag } => {
+            return match output_format() {
+                OutputFormat::Text => list(tag.as_deref()).or_else(|error| show_result(Err(error))),
+                OutputFormat::Json => print_json_result(Ok(()), tag.as_deref()),
+            };
         }
-        "repeating-reset" | "repeating-donereset" | "rp-r" | "rp-dr" => {
-            let error_status =
-                repeating_tasks_reset_original_datetime_to_now(args.item_identifier.context(
-                    "didn't provide arguments for \
-                    repeating-reset/repeating-donereset",
-                )?);
-            if !error_status {
-                list();
-            }
-
-            Ok(())
+        Command::Clearall { archive } => clear_all_lists(archive),
+        Command::ClearallRegular { archive } => clear_regular_tasks(archive),
+        Command::ClearallDeadline { archive } => clear_deadline_tasks(archive),
+        Command::ClearallRepeating { archive } => clear_repeating_tasks(archive),
+        Command::Undo => undo(),
+        Command::Redo => redo(),
+        Command::Completions { shell } => {
+            let result = completions(shell);
+            return result.or_else(|error| show_result(Err(error)));
         }
-        "repeating-notdone" | "rp-nd" => {
-            let error_status = repeating_tasks_not_done(
-                args.item_identifier
-                    .context("didn't provide arguments for repeating-notdone")?,
-            );
-            if !error_status {
-                list();
-            }
-
-            Ok(())
+        Command::History { from, to } => {
+            let result = show_history(from, to);
+            return result.or_else(|error| show_result(Err(error)));
         }
-        "repeating-rmtodo" | "rp-rmt" => {
-            let error_status = repeating_tasks_rmtodo(
-                args.item_identifier
-                    .context("didn't provide arguments for repeating-rmtodo")?,
-            );
-            if !error_status {
-                list();
-            }
-
-            Ok(())
+        Command::Search { query, regex } => {
+            let result = search_tasks(&query, regex);
+            return result.or_else(|error| show_result(Err(error)));
         }
-        "repeating-rmdone" | "rp-rmd" => {
-            let error_status = repeating_tasks_rmdone(
-                args.item_identifier
-                    .context("didn't provide arguments for repeating-rmdone")?,
-            );
-            if !error_status {
-                list();
-            }
-
-            Ok(())
+        Command::Init { template } => init(template.as_deref()),
+        Command::Config { action } => {
+            let result = match action {
+                ConfigAction::Get { setting } => show_setting(setting.as_deref()),
+                ConfigAction::Set { setting, value } => change_setting(&setting, &value),
+            };
+            return result.or_else(|error| show_result(Err(error)));
         }
-        "repeating-doneall" | "rp-da" if args.item_identifier.is_none() => {
-            let error_status = repeating_tasks_doneall();
-            if !error_status {
-                list();
-            }
-
-            Ok(())
+        Command::ArchiveList => {
+            let result = show_archive();
+            return result.or_else(|error| show_result(Err(error)));
         }
-        "repeating-notdoneall" | "rp-nda" if args.item_identifier.is_none() => {
-            let error_status = repeating_tasks_not_done_all();
-            if !error_status {
-                list();
-            }
-
-            Ok(())
+        Command::ArchiveRestore { list, positions } => restore_archived(list, positions),
+
+        Command::Add {
+            tasks,
+            priority,
+            tags,
+        } => regular_tasks_add_todo(tasks, priority, tags),
+        Command::Done { positions } => regular_tasks_change_todo_to_done(positions),
+        Command::Rmtodo { positions } => regular_tasks_remove_todo(positions),
+        Command::Doneall => regular_tasks_change_all_todo_to_done(),
+        Command::Cleartodo => regular_tasks_clear_todo(),
+        Command::Clearboth { archive } => clear_regular_tasks(archive),
+        Command::Edit { position, task } => regular_tasks_edit_todo(&position, &task),
+        Command::EditPriority { position, priority } => {
+            regular_tasks_edit_priority(&position, &priority)
         }
-        "repeating-cleartodo" | "rp-ct" if args.item_identifier.is_none() => {
-            let error_status = repeating_tasks_clear_todo();
-            if !error_status {
-                list();
-            }
-
-            Ok(())
+        Command::Tag { position, tags } => regular_tasks_tag([vec![position], tags].concat()),
+        Command::Untag { position, tags } => regular_tasks_untag([vec![position], tags].concat()),
+        Command::Notdone { positions } => regular_tasks_not_done(positions),
+        Command::Rmdone { positions } => regular_tasks_remove_done(positions),
+        Command::Notdoneall => regular_tasks_reverse_all_dones(),
+        Command::Cleardone { archive } => regular_tasks_clear_done(archive),
+        Command::Archive { positions } => regular_tasks_archive_done(positions),
+
+        Command::DeadlineAdd {
+            task_date_time,
+            priority,
+            tags,
+        } => deadline_sets(&task_date_time).and_then(|add| deadline_tasks_add(add, priority, tags)),
+        Command::DeadlineAddonlydate {
+            task_date,
+            priority,
+            tags,
+        } => deadline_date_sets(&task_date)
+            .and_then(|add| deadline_tasks_add_no_time(add, priority, tags)),
+        Command::DeadlineAddonlytime {
+            task_time,
+            priority,
+            tags,
+        } => deadline_time_sets(&task_time)
+            .and_then(|add| deadline_tasks_add_no_date(add, priority, tags)),
+        Command::DeadlineDone { positions } => deadline_tasks_done(positions),
+        Command::DeadlineRmtodo { positions } => deadline_tasks_rmtodo(positions),
+        Command::DeadlineDoneall => deadline_tasks_done_all(),
+        Command::DeadlineCleartodo => deadline_tasks_clear_todo(),
+        Command::DeadlineClearboth { archive } => clear_deadline_tasks(archive),
+        Command::DeadlineEditall {
+            position,
+            task,
+            date,
+            time,
+        } => deadline_tasks_edit_all(&position, &task, &date, &time),
+        Command::DeadlineEdittask { position, task } => deadline_tasks_edit_task(&position, &task),
+        Command::DeadlineEditdate { position, date } => deadline_tasks_edit_date(&position, &date),
+        Command::DeadlineEdittime { position, time } => deadline_tasks_edit_time(&position, &time),
+        Command::DeadlineEditdatetime {
+            position,
+            date,
+            time,
+        } => deadline_tasks_edit_datetime(&position, &date, &time),
+        Command::DeadlineEditpriority { position, priority } => {
+            deadline_tasks_edit_priority(&position, &priority)
         }
-        "repeating-cleardone" | "rp-cd" if args.item_identifier.is_none() => {
-            let error_status = repeating_tasks_clear_done();
-            if !error_status {
-                list();
-            }
-
-            Ok(())
+        Command::DeadlineTag { position, tags } => {
+            deadline_tasks_tag([vec![position], tags].concat())
         }
-        "repeating-clearboth" | "rp-cb" if args.item_identifier.is_none() => {
-            let error_status = clear_repeating_tasks();
-            if !error_status {
-                list();
-            }
-
-            Ok(())
+        Command::DeadlineUntag { position, tags } => {
+            deadline_tasks_untag([vec![position], tags].concat())
         }
-        "repeating-start" | "rp-s" => {
-            let show_starts = repeating_tasks_show_start(
-                args.item_identifier
-                    .context("didn't provide arguments for repeating-start")?,
-            );
-            let writer = &mut std::io::stdout();
-            writeln!(writer, "{}", show_starts).expect("writeln failed");
-            Ok(())
+        Command::DeadlineNotdone { positions } => deadline_tasks_not_done(positions),
+        Command::DeadlineRmdone { positions } => deadline_tasks_rmdone(positions),
+        Command::DeadlineNotdoneall => deadline_tasks_notdoneall(),
+        Command::DeadlineCleardone { archive } => deadline_tasks_clear_done(archive),
+        Command::DeadlineArchive { positions } => deadline_tasks_archive_done(positions),
+
+        Command::RepeatingAdd {
+            task_interval_unit,
+            priority,
+            tags,
+            on,
+            catch_up,
+            until,
+            max_occurrences,
+        } => repeating_sets(&task_interval_unit).and_then(|add| {
+            repeating_tasks_add(
+                add,
+                priority,
+                tags,
+                RepeatRules {
+                    on,
+                    catch_up,
+                    until,
+                    max_occurrences,
+                },
+            )
+        }),
+        Command::RepeatingAddstart {
+            task_interval_unit_date_time,
+            priority,
+            tags,
+            on,
+            catch_up,
+            until,
+            max_occurrences,
+        } => repeating_start_sets(&task_interval_unit_date_time).and_then(|add| {
+            repeating_tasks_add_start_datetime(
+                add,
+                priority,
+                tags,
+                RepeatRules {
+                    on,
+                    catch_up,
+                    until,
+                    max_occurrences,
+                },
+            )
+        }),
+        Command::RepeatingAddend {
+            task_interval_unit_date_time,
+            priority,
+            tags,
+            on,
+            catch_up,
+            until,
+            max_occurrences,
+        } => repeating_end_sets(&task_interval_unit_date_time).and_then(|add| {
+            repeating_tasks_add_end(
+                add,
+                priority,
+                tags,
+                RepeatRules {
+                    on,
+                    catch_up,
+                    until,
+                    max_occurrences,
+                },
+            )
+        }),
+        Command::RepeatingDone { positions } => repeating_tasks_done(positions),
+        Command::RepeatingReset { positions } => {
+            repeating_tasks_reset_original_datetime_to_now(positions)
         }
-        "repeating-resetall" | "rp-ra" | "repeating-doneresetall" | "rp-dra"
-            if args.item_identifier.is_none() =>
-        {
-            let error_status = repeating_tasks_resetall();
-            if !error_status {
-                list();
-            }
-
-            Ok(())
+        Command::RepeatingRmtodo { positions } => repeating_tasks_rmtodo(positions),
+        Command::RepeatingStart { positions } => {
+            return show_starts(repeating_tasks_show_start(positions));
         }
-        "repeating-startall" | "rp-sa" if args.item_identifier.is_none() => {
-            let show_starts = repeating_tasks_showstartall();
-            let writer = &mut std::io::stdout();
-            writeln!(writer, "{}", show_starts).expect("writeln failed");
-            Ok(())
+        Command::RepeatingDoneall => repeating_tasks_doneall(),
+        Command::RepeatingCleartodo => repeating_tasks_clear_todo(),
+        Command::RepeatingClearboth { archive } => clear_repeating_tasks(archive),
+        Command::RepeatingResetall => repeating_tasks_resetall(),
+        Command::RepeatingStartall => {
+            return show_starts(repeating_tasks_showstartall());
         }
-        "repeating-editall" | "rp-ea" => {
-            let error_status = repeating_tasks_edit_all(
-                args.item_identifier
-                    .context("didn't provide arguments for repeating-editall")?,
-            );
-            if !error_status {
-                list();
-            }
-
-            Ok(())
+        Command::RepeatingEditall {
+            position,
+            task,
+            interval,
+            unit,
+            start_or_end,
+            date,
+            time,
+        } => repeating_tasks_edit_all(
+            &position,
+            &task,
+            &interval,
+            &unit,
+            &start_or_end,
+            &date,
+            &time,
+        ),
+        Command::RepeatingEdittask { position, task } => {
+            repeating_tasks_edit_task(&position, &task)
         }
-        "repeating-edittask" | "rp-eta" => {
-            let error_status = repeating_tasks_edit_task(
-                args.item_identifier
-                    .context("didn't provide arguments for repeating-edittask")?,
-            );
-            if !error_status {
-                list();
-            }
-
-            Ok(())
+        Command::RepeatingEditinterval { position, interval } => {
+            repeating_tasks_edit_interval(&position, &interval)
         }
-        "repeating-editinterval" | "rp-ei" => {
-            let error_status = repeating_tasks_edit_interval(
-                args.item_identifier
-                    .context("didn't provide arguments for repeating-editinterval")?,
-            );
-            if !error_status {
-                list();
-            }
-
-            Ok(())
+        Command::RepeatingEditunit { position, unit } => {
+            repeating_tasks_edit_time_unit(&position, &unit)
         }
-        "repeating-editintervalunit" | "rp-eiu" => {
-            let error_status = repeating_tasks_edit_interval_unit(
-                args.item_identifier
-                    .context("didn't provide arguments for repeating-editintervalunit")?,
-            );
-            if !error_status {
-                list();
-            }
-
-            Ok(())
+        Command::RepeatingEditintervalunit {
+            position,
+            interval,
+            unit,
+        } => repeating_tasks_edit_interval_unit(&position, &interval, &unit),
+        Command::RepeatingEditstart {
+            position,
+            date,
+            time,
+        } => repeating_tasks_edit_start(&position, &date, &time),
+        Command::RepeatingEditend {
+            position,
+            date,
+            time,
+        } => repeating_tasks_edit_end(&position, &date, &time),
+        Command::RepeatingEditon { position, rule } => repeating_tasks_edit_on(&position, &rule),
+        Command::RepeatingEditcatchup { position, catch_up } => {
+            repeating_tasks_edit_catch_up(&position, &catch_up)
         }
-        // note: I don't like unit, it's too vague. but time unit is also too long
-        "repeating-editunit" | "rp-eu" => {
-            let error_status = repeating_tasks_edit_time_unit(
-                args.item_identifier
-                    .context("didn't provide arguments for repeating-editunit")?,
-            );
-            if !error_status {
-                list();
-            }
-
-            Ok(())
+        Command::RepeatingEditpriority { position, priority } => {
+            repeating_tasks_edit_priority(&position, &priority)
         }
-        "repeating-editstart" | "rp-es" => {
-            let error_status = repeating_tasks_edit_start(
-                args.item_identifier
-                    .context("didn't provide arguments for repeating-editstart")?,
-            );
-            if !error_status {
-                list();
-            }
-
-            Ok(())
+        Command::RepeatingTag { position, tags } => {
+            repeating_tasks_tag([vec![position], tags].concat())
         }
-        "repeating-editend" | "rp-ee" => {
-            let error_status = repeating_tasks_edit_end(
-                args.item_identifier
-                    .context("didn't provide arguments for repeating-editend")?,
-            );
-            if !error_status {
-                list();
-            }
-
-            Ok(())
+        Command::RepeatingUntag { position, tags } => {
+            repeating_tasks_untag([vec![position], tags].concat())
         }
-        "clearall" | "ca" if args.item_identifier.is_none() => {
-            let error_status = clear_all_lists();
-            if !error_status {
-                list();
+        Command::RepeatingNotdone { positions } => repeating_tasks_not_done(positions),
+        Command::RepeatingRmdone { positions } => repeating_tasks_rmdone(positions),
+        Command::RepeatingNotdoneall => repeating_tasks_not_done_all(),
+        Command::RepeatingCleardone { archive } => repeating_tasks_clear_done(archive),
+        Command::RepeatingArchive { positions } => repeating_tasks_archive_done(positions),
+
+        Command::Other(other) => {
+            // note: seems like it's hard for the user to reach this with an empty command
+            match other.first().map(|command| command.is_empty()) {
+                Some(true) => Err(no_arg_command()),
+                _ => Err(command_error()),
             }
-
-            Ok(())
         }
-        "clearall-regular" | "ca-r" if args.item_identifier.is_none() => {
-            let error_status = clear_regular_tasks();
-            if !error_status {
-                list();
-            }
+    };
 
-            Ok(())
-        }
-        "clearall-deadline" | "ca-d" if args.item_identifier.is_none() => {
-            let error_status = clear_deadline_tasks();
-            if !error_status {
-                list();
-            }
+    show_result(result)
+}
 
-            Ok(())
-        }
-        "clearall-repeating" | "ca-rp" if args.item_identifier.is_none() => {
-            let error_status = clear_repeating_tasks();
-            if !error_status {
-                list();
-            }
+// rp-s and rp-sa only show the starts, not the list. w/ json, the starts are already in the lists, so they're shown
+// like any other command's result
+fn show_starts(show_starts: Result<String, ChartodoError>) -> Result<(), ChartodoError> {
+    let result = show_starts.map(|show_starts| {
+        let writer = &mut output::writer();
+        writeln!(writer, "{}", show_starts).expect("writeln failed");
+    });
+
+    match (output_format(), result) {
+        (OutputFormat::Text, Ok(())) => Ok(()),
+        (_, result) => show_result(result),
+    }
+}
 
-            Ok(())
-        }
-        "" => {
-            // note: seems like it's hard for the user to reach this
-            no_arg_command();
-            Ok(())
+// what gets shown after undo/redo, e.g., Undid: chartodo rmtodo 1-20. --data-dir and --format are left out since
+// they're not part of the command itself
+fn command_line() -> String {
+    let mut command_line = vec!["chartodo".to_string()];
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--data-dir" || arg == "--format" {
+            args.next();
+            continue;
         }
-        _ => {
-            command_error();
-            Ok(())
+        if arg.starts_with("--data-dir=") || arg.starts_with("--format=") {
+            continue;
         }
+        command_line.push(arg);
     }
+
+    command_line.join(" ")
 }
 
-fn no_arg_command() {
-    let writer = &mut std::io::stdout();
-    writeln!(writer, "You must provide a command. Try chartodo help.").expect("writeln failed");
+fn no_arg_command() -> ChartodoError {
+    ChartodoError::UnknownCommand(String::from(
+        "You must provide a command. Try chartodo help.",
+    ))
 }
 
-fn command_error() {
-    let writer = &mut std::io::stdout();
-    writeln!(
-        writer,
-        "Invalid command. Please try again, or try chartodo help"
-    )
-    .expect("writeln failed");
+fn command_error() -> ChartodoError {
+    ChartodoError::UnknownCommand(String::from(
+        "Invalid command. Please try again, or try chartodo help",
+    ))
 }
 
 fn help() {
@@ -653,9 +1150,20 @@ fn help() {
             If a command says it has chaining, it means you can include multiple separate tasks or positions
             If a command says it has range positioning, it means you can include position arguments that's a range,
             e.g., 1-6, 5-10, 3-11, 1-100
-            Date format is always in year-month-day, e.g., 2099-12-25
-            Time format is always in a 24-hour format, e.g., 13:58. Note that there is no space between hour and minute
+            Anywhere a position is taken, you can use a task's #id instead, e.g., chartodo done '#12'. The id is shown next
+            to the task and never changes, even when the list gets sorted. Quote it, since some shells treat # as a comment
+            Every add command takes --priority [high/medium/low], or -p for short, which gives the added task(s) a priority
+            Every add command also takes --tag [tag], or -t for short, which tags the added task(s). It can be given more than once
+            Tags are + or @ followed by a word, e.g., +backend or @errands. A tag w/o either gets a +
+            Date format is year-month-day, e.g., 2099-12-25, or today, tomorrow, a weekday (fri, 'next monday'), or from now (+3d, 'in 2w')
+            Time format is a 24-hour format, e.g., 13:58, or noon, midnight, or eod. Note that there is no space between hour and minute
+            A date can have the time in it, e.g., 2099-12-25T08:00, eod, +2h, or 'in 30min'. That time is used if the command doesn't take one
             Only the following time units are allowed in repeating tasks: minutes, hours, days, weeks, months, years
+            Repeating add commands take --on [rule] to only land on certain days: mon,wed,fri, weekdays, or weekends w/ weeks, and '2nd tue', 'last fri', 'last day', or 'last business day' w/ months
+            Repeating add commands also take --catch-up [once/next/each] for the times a task was missed: once is due again once, next skips to the next time still ahead, each also adds a deadline todo per missed time
+            Repeating add commands also take --until [date] and --times [n] to end the series after that date or that many times. A finished series stays done, and rp-s/rp-sa show how many times are left
+            Tasks are stored in your OS's data dir. To use another folder, pass --data-dir [folder] or set CHARTODO_HOME
+            Pass --format json to any command to get the lists, or what went wrong, as one json object, e.g., chartodo list --format json
 
         A TIP BEFORE STARTING: 
             it's helpful to memorize regular todo/done commands, since most repeating/deadline commands
@@ -671,11 +1179,31 @@ fn help() {
             regular-help, r-h                       show help for regular tasks
             deadline-help, dl-h                     show help for deadline tasks
             repeating-help, rp-h                    show help for repeating tasks
-            list, l                                 show the todo list
-            clearall, ca                            clear everything (TODO, DEADLINE, REPEATING)
-            clearall-regular, ca-r                  clear all regular todo and done tasks
-            clearall-deadline, ca-d                 clear all deadline todo and done tasks
-            clearall-repeating, ca-rp               clear all repeating todo and done tasks
+            list, l                                 show the todo list. Pass --tag [tag] to only show the tasks w/ that tag
+                                                    example: chartodo list --tag backend
+            search, s                               find tasks in every list and the archive by their text, ignoring case. Pass --regex to use a regex
+                                                    format: chartodo search [text]
+                                                    example: chartodo search --regex '^buy-'
+            clearall, ca                            clear everything (TODO, DEADLINE, REPEATING). Pass --archive to archive the done items instead of deleting them
+            clearall-regular, ca-r                  clear all regular todo and done tasks. Pass --archive to archive the done items instead of deleting them
+            clearall-deadline, ca-d                 clear all deadline todo and done tasks. Pass --archive to archive the done items instead of deleting them
+            clearall-repeating, ca-rp               clear all repeating todo and done tasks. Pass --archive to archive the done items instead of deleting them
+            undo, u                                 undo the last command that changed a list. Up to 20 commands can be undone
+            redo                                    redo the last command that was undone
+            history                                 show what's happened to tasks, e.g., when each one was done, oldest first. Done lists get cleared, but this doesn't
+                                                    format: chartodo history --from [date] --to [date]
+                                                    example: chartodo history --from -7d
+            archive-list, ar-l                      show the done tasks that were archived, by kind of task
+            archive-restore, ar-r                   move archived tasks back to their done list, using the positions archive-list shows. Has chaining and range positioning
+                                                    format: chartodo archive-restore [regular/deadline/repeating] [position]
+                                                    example: chartodo ar-r deadline 1 3-4
+            init                                    start empty lists, or a copy of a template w/ --template [file]. Won't touch lists that are already there
+                                                    format: chartodo init --template [file]
+                                                    example: chartodo init --template team.json
+            config get, config set                  show or change a setting in config.toml, or show every setting w/ config get
+                                                    format: chartodo config set [setting] [value]
+                                                    example: chartodo config set display.table_style ascii
+            completions [shell]                     print a tab-completion script for bash, zsh, fish, elvish, or powershell
 
         REGULAR TODO:
             add, a                                  add an item to the todo list. To add a multi-word item, replace space with something like -. Has chaining
@@ -691,10 +1219,19 @@ fn help() {
                                                     example: chartodo rmt 4 1 5 11-15
             doneall, da                             change all todo items to done
             cleartodo, ct                           clear the todo list
-            clearboth, cb                           clear both todo and done lists
+            clearboth, cb                           clear both todo and done lists. Pass --archive to archive the done items instead of deleting them
             edit, e                                 changes a todo item, with its position specified, to what you want
                                                     format: chartoo edit [position] [new task]
                                                     example: chartodo edit 3 change-item-to-this
+            edit-priority, ep                       changes the priority of a todo item to high, medium, low, or none
+                                                    format: chartodo edit-priority [position] [priority]
+                                                    example: chartodo ep 3 high
+            tag, tg                                 add tags to a todo item
+                                                    format: chartodo tag [position] [tag] [...]
+                                                    example: chartodo tg 3 backend @errands
+            untag, utg                              take tags away from a todo item. A tag w/o + or @ takes away both, e.g., backend takes away +backend and @backend
+                                                    format: chartodo untag [position] [tag] [...]
+                                                    example: chartodo utg 3 backend
 
         REGULAR DONE:
             notdone, nd                             reverses a done item back to a todo item using numbered positions. Has chaining and range positioning
@@ -704,7 +1241,10 @@ fn help() {
                                                     format: chartodo rmdone [position]
                                                     example: chartodo rmd 4 5-8
             notdoneall, nda                         reverses all done items back to todo
-            cleardone, cd                           clears the done list
+            cleardone, cd                           clears the done list. Pass --archive to archive the done items instead of deleting them
+            archive, ar                             moves a done item to the archive using numbered positions. Has chaining and range positioning
+                                                    format: chartodo archive [position]
+                                                    example: chartodo ar 1 3-5
 
         DEADLINE TODO:
             deadline-add, dl-a                      adds a task with a day and time limit. Has chaining
@@ -726,7 +1266,7 @@ fn help() {
                                                     example: chartodo dl-rmt 1 5-9
             deadline-doneall, dl-da                 mark the entire deadline todo list as done
             deadline-cleartodo, deadline-ct         clear the deadline todo list
-            deadline-clearboth, dl-cb               clears both of the deadline todo and done lists
+            deadline-clearboth, dl-cb               clears both of the deadline todo and done lists. Pass --archive to archive the done items instead of deleting them
             deadline-editall, dl-ea                 edit all the parameters of a deadline todo task
                                                     format: chartodo deadline-editall [position] [new deadline task] [new ending date] [new ending time]
                                                     example: chartodo dl-ea 1 new-item 2150-01-01 00:00
@@ -742,6 +1282,15 @@ fn help() {
             deadline-editdatetime, dl-edt           edit the date and time parameter of a deadline todo task
                                                     format: chartodo deadline-editdatetime [position] [new ending date] [new ending time]
                                                     example: chartodo dl-edt 1 2100-01-01 13:00
+            deadline-editpriority, dl-ep            edit the priority of a deadline todo task to high, medium, low, or none. Tasks due at the same time are sorted by priority
+                                                    format: chartodo deadline-editpriority [position] [priority]
+                                                    example: chartodo dl-ep 1 low
+            deadline-tag, dl-tg                     add tags to a deadline todo task
+                                                    format: chartodo deadline-tag [position] [tag] [...]
+                                                    example: chartodo dl-tg 1 backend @errands
+            deadline-untag, dl-utg                  take tags away from a deadline todo task. A tag w/o + or @ takes away both, e.g., backend takes away +backend and @backend
+                                                    format: chartodo deadline-untag [position] [tag] [...]
+                                                    example: chartodo dl-utg 1 backend
 
         DEADLINE DONE:
             deadline-notdone, dl-nd                 reverses a deadline done item back to todo using numbered positions. Has chaining and range positioning
@@ -751,10 +1300,16 @@ fn help() {
                                                     format: chartodo deadline-rmdone [position]
                                                     example: chartodo dl-rmd 3 2 1 5-7
             deadline-notdoneall, dl-nda             reverses all deadline done items back to todo
-            deadline-cleardone, dl-cd               clears the deadline done list
+            deadline-cleardone, dl-cd               clears the deadline done list. Pass --archive to archive the done items instead of deleting them
+            deadline-archive, dl-ar                 moves a deadline done item to the archive using numbered positions. Has chaining and range positioning
+                                                    format: chartodo deadline-archive [position]
+                                                    example: chartodo dl-ar 2 4-6
 
         REPEATING TODO:
         note: Only the following time units are allowed in repeating tasks: minutes, hours, days, weeks, months, years
+        note: Repeating add commands take --on [rule] to only land on certain days: mon,wed,fri, weekdays, or weekends w/ weeks, and '2nd tue', 'last fri', 'last day', or 'last business day' w/ months
+        note: Repeating add commands also take --catch-up [once/next/each] for the times a task was missed: once is due again once, next skips to the next time still ahead, each also adds a deadline todo per missed time
+        note: Repeating add commands also take --until [date] and --times [n] to end the series after that date or that many times. A finished series stays done, and rp-s/rp-sa show how many times are left
             repeating-add, rp-a                     add a repeating task with a set interval. the task starts from your current date and time. Has chaining
                                                     format: chartodo repeating-add [repeating task] [interval] [time unit]
                                                     example: chartodo rp-a gym 2 days
@@ -762,6 +1317,7 @@ fn help() {
             repeating-addstart, rp-as               add a repeating task that starts on your specified datetime. Has chaining
                                                     format: chartodo repeating-addstart [repeating task] [interval] [time unit] [starting date] [starting time]
                                                     example: chartodo rp-as task 3 days 2099-01-01 00:00
+                                                    example: chartodo rp-as standup 1 week 2099-01-05 09:00 --on mon,wed,fri
             repeating-addend, rp-ae                 add a repeating task that ends on your specified datetime. Has chaining
                                                     format: chartodo repeating-addend [repeating task] [interval] [time unit] [ending date] [ending time]
                                                     example: chartodo rp-ae task 3 days 2099-01-01 00:00
@@ -783,7 +1339,7 @@ fn help() {
                                                     example: chartodo rp-s 1 3-5
             repeating-doneall, rp-da                mark all repeating tasks as done
             repeating-cleartodo, rp-ct              delete all of the repeating todo tasks
-            repeating-clearboth, rp-cb              clear the repeating todo and done lists
+            repeating-clearboth, rp-cb              clear the repeating todo and done lists. Pass --archive to archive the done items instead of deleting them
             repeating-resetall, repeating-doneresetall, rp-ra, rp-dra
                                                     resets the starting datetime of all repeating tasks to your current date and time
             repeating-startall, rp-sa               show the starting datetime of all repeating tasks
@@ -810,6 +1366,21 @@ fn help() {
             repeating-editend, rp-ee                edit the ending datetime of a repeating task
                                                     format: chartodo repeating-editend [position] [ending date] [ending time]
                                                     example: chartodo rp-ee 1 2100-12-24 13:08
+            repeating-editon, rp-eo                 edit which days a repeating task lands on, or none to go back to every interval. The rule has to go w/ its time unit, see --on
+                                                    format: chartodo repeating-editon [position] [rule]
+                                                    example: chartodo rp-eo 1 'last business day'
+            repeating-editcatchup, rp-ec            edit what a repeating task does about the times it was missed: once, next, or each. See --catch-up
+                                                    format: chartodo repeating-editcatchup [position] [catch-up]
+                                                    example: chartodo rp-ec 1 next
+            repeating-editpriority, rp-ep           edit the priority of a repeating task to high, medium, low, or none. Tasks due at the same time are sorted by priority
+                                                    format: chartodo repeating-editpriority [position] [priority]
+                                                    example: chartodo rp-ep 1 medium
+            repeating-tag, rp-tg                    add tags to a repeating task
+                                                    format: chartodo repeating-tag [position] [tag] [...]
+                                                    example: chartodo rp-tg 1 backend @errands
+            repeating-untag, rp-utg                 take tags away from a repeating task. A tag w/o + or @ takes away both, e.g., backend takes away +backend and @backend
+                                                    format: chartodo repeating-untag [position] [tag] [...]
+                                                    example: chartodo rp-utg 1 backend
 
         REPEATING DONE:
             repeating-notdone, rp-nd                reverse repeating dones back to todo. Has chaining and range positioning
@@ -819,7 +1390,10 @@ fn help() {
                                                     format: chartodo repeating-rmdone [position]
                                                     example: chartodo rp-rmd 1 3-5
             repeating-notdoneall, rp-nda            reverse all finished repeating tasks back to todo
-            repeating-cleardone, rp-cd              delete all of the finished repeating tasks
+            repeating-cleardone, rp-cd              delete all of the finished repeating tasks. Pass --archive to archive them instead of deleting them
+            repeating-archive, rp-ar                moves a finished repeating task to the archive. Has chaining and range positioning
+                                                    format: chartodo repeating-archive [position]
+                                                    example: chartodo rp-ar 1 3-5
         "
     )
     .expect("writeln failed");
@@ -842,10 +1416,19 @@ fn regular_help() {
                                 example: chartodo rmt 4 1 5 11-15
             doneall, da         change all todo items to done
             cleartodo, ct       clear the todo list
-            clearboth, cb       clear both todo and done lists
+            clearboth, cb       clear both todo and done lists. Pass --archive to archive the done items instead of deleting them
             edit, e             changes a todo item, with its position specified, to what you want
                                 format: chartoo edit [position] [new task]
                                 example: chartodo edit 3 change-item-to-this
+            edit-priority, ep   changes the priority of a todo item to high, medium, low, or none
+                                format: chartodo edit-priority [position] [priority]
+                                example: chartodo ep 3 high
+            tag, tg             add tags to a todo item
+                                format: chartodo tag [position] [tag] [...]
+                                example: chartodo tg 3 backend @errands
+            untag, utg          take tags away from a todo item. A tag w/o + or @ takes away both, e.g., backend takes away +backend and @backend
+                                format: chartodo untag [position] [tag] [...]
+                                example: chartodo utg 3 backend
 
         REGULAR DONE:
             notdone, nd         reverses a done item back to a todo item using numbered positions. Has chaining and range positioning
@@ -855,15 +1438,19 @@ fn regular_help() {
                                 format: chartodo rmdone [position]
                                 example: chartodo rmd 4 5-8
             notdoneall, nda     reverses all done items back to todo
-            cleardone, cd       clears the done list
+            cleardone, cd       clears the done list. Pass --archive to archive the done items instead of deleting them
+            archive, ar         moves a done item to the archive using numbered positions. Has chaining and range positioning
+                                format: chartodo archive [position]
+                                example: chartodo ar 1 3-5
          ").expect("writeln failed");
 }
 
 fn deadline_help() {
     let writer = &mut std::io::stdout();
     writeln!(writer, "
-        Date format is always in year-month-day, e.g., 2099-12-25
-        Time format is always in a 24-hour format, e.g., 13:58. Note that there is no space between hour and minute
+        Date format is year-month-day, e.g., 2099-12-25, or today, tomorrow, a weekday (fri, 'next monday'), or from now (+3d, 'in 2w')
+        Time format is a 24-hour format, e.g., 13:58, or noon, midnight, or eod. Note that there is no space between hour and minute
+        A date can have the time in it, e.g., 2099-12-25T08:00, eod, +2h, or 'in 30min'. That time is used if the command doesn't take one
 
         DEADLINE TODO:
             deadline-add, dl-a                      adds a task with a day and time limit. Has chaining
@@ -885,7 +1472,7 @@ fn deadline_help() {
                                                     example: chartodo dl-rmt 1 5-9
             deadline-doneall, dl-da                 mark the entire deadline todo list as done
             deadline-cleartodo, deadline-ct         clear the deadline todo list
-            deadline-clearboth, dl-cb               clears both of the deadline todo and done lists
+            deadline-clearboth, dl-cb               clears both of the deadline todo and done lists. Pass --archive to archive the done items instead of deleting them
             deadline-editall, dl-ea                 edit all the parameters of a deadline todo task
                                                     format: chartodo deadline-editall [position] [new deadline task] [new ending date] [new ending time]
                                                     example: chartodo dl-ea 1 new-item 2150-01-01 00:00
@@ -901,6 +1488,15 @@ fn deadline_help() {
             deadline-editdatetime, dl-edt           edit the date and time parameter of a deadline todo task
                                                     format: chartodo deadline-editdatetime [position] [new ending date] [new ending time]
                                                     example: chartodo dl-edt 1 2100-01-01 13:00
+            deadline-editpriority, dl-ep            edit the priority of a deadline todo task to high, medium, low, or none. Tasks due at the same time are sorted by priority
+                                                    format: chartodo deadline-editpriority [position] [priority]
+                                                    example: chartodo dl-ep 1 low
+            deadline-tag, dl-tg                     add tags to a deadline todo task
+                                                    format: chartodo deadline-tag [position] [tag] [...]
+                                                    example: chartodo dl-tg 1 backend @errands
+            deadline-untag, dl-utg                  take tags away from a deadline todo task. A tag w/o + or @ takes away both, e.g., backend takes away +backend and @backend
+                                                    format: chartodo deadline-untag [position] [tag] [...]
+                                                    example: chartodo dl-utg 1 backend
 
         DEADLINE DONE:
             deadline-notdone, dl-nd                 reverses a deadline done item back to todo using numbered positions. Has chaining and range positioning
@@ -910,16 +1506,23 @@ fn deadline_help() {
                                                     format: chartodo deadline-rmdone [position]
                                                     example: chartodo dl-rmd 3 2 1 5-7
             deadline-notdoneall, dl-nda             reverses all deadline done items back to todo
-            deadline-cleardone, dl-cd               clears the deadline done list
+            deadline-cleardone, dl-cd               clears the deadline done list. Pass --archive to archive the done items instead of deleting them
+            deadline-archive, dl-ar                 moves a deadline done item to the archive using numbered positions. Has chaining and range positioning
+                                                    format: chartodo deadline-archive [position]
+                                                    example: chartodo dl-ar 2 4-6
     ").expect("writeln failed");
 }
 
 fn repeating_help() {
     let writer = &mut std::io::stdout();
     writeln!(writer, "
-        Date format is always in year-month-day, e.g., 2099-12-25
-        Time format is always in a 24-hour format, e.g., 13:58. Note that there is no space between hour and minute
+        Date format is year-month-day, e.g., 2099-12-25, or today, tomorrow, a weekday (fri, 'next monday'), or from now (+3d, 'in 2w')
+        Time format is a 24-hour format, e.g., 13:58, or noon, midnight, or eod. Note that there is no space between hour and minute
+        A date can have the time in it, e.g., 2099-12-25T08:00, eod, +2h, or 'in 30min'. That time is used if the command doesn't take one
         Only the following time units are allowed in repeating tasks: minutes, hours, days, weeks, months, years
+        Repeating add commands take --on [rule] to only land on certain days: mon,wed,fri, weekdays, or weekends w/ weeks, and '2nd tue', 'last fri', 'last day', or 'last business day' w/ months
+        Repeating add commands also take --catch-up [once/next/each] for the times a task was missed: once is due again once, next skips to the next time still ahead, each also adds a deadline todo per missed time
+        Repeating add commands also take --until [date] and --times [n] to end the series after that date or that many times. A finished series stays done, and rp-s/rp-sa show how many times are left
 
         REPEATING TODO:
             repeating-add, rp-a                     add a repeating task with a set interval. the task starts from your current date and time. Has chaining
@@ -929,6 +1532,7 @@ fn repeating_help() {
             repeating-addstart, rp-as               add a repeating task that starts on your specified datetime. Has chaining
                                                     format: chartodo repeating-addstart [repeating task] [interval] [time unit] [starting date] [starting time]
                                                     example: chartodo rp-as task 3 days 2099-01-01 00:00
+                                                    example: chartodo rp-as standup 1 week 2099-01-05 09:00 --on mon,wed,fri
             repeating-addend, rp-ae                 add a repeating task that ends on your specified datetime. Has chaining
                                                     format: chartodo repeating-addend [repeating task] [interval] [time unit] [ending date] [ending time]
                                                     example: chartodo rp-ae task 3 days 2099-01-01 00:00
@@ -950,7 +1554,7 @@ fn repeating_help() {
                                                     example: chartodo rp-s 1 3-5
             repeating-doneall, rp-da                mark all repeating tasks as done
             repeating-cleartodo, rp-ct              delete all of the repeating todo tasks
-            repeating-clearboth, rp-cb              clear the repeating todo and done lists
+            repeating-clearboth, rp-cb              clear the repeating todo and done lists. Pass --archive to archive the done items instead of deleting them
             repeating-resetall, repeating-doneresetall, rp-ra, rp-dra
                                                     resets the starting datetime of all repeating tasks to your current date and time
             repeating-startall, rp-sa               show the starting datetime of all repeating tasks
@@ -977,6 +1581,21 @@ fn repeating_help() {
             repeating-editend, rp-ee                edit the ending datetime of a repeating task
                                                     format: chartodo repeating-editend [position] [ending date] [ending time]
                                                     example: chartodo rp-ee 1 2100-12-24 13:08
+            repeating-editon, rp-eo                 edit which days a repeating task lands on, or none to go back to every interval. The rule has to go w/ its time unit, see --on
+                                                    format: chartodo repeating-editon [position] [rule]
+                                                    example: chartodo rp-eo 1 'last business day'
+            repeating-editcatchup, rp-ec            edit what a repeating task does about the times it was missed: once, next, or each. See --catch-up
+                                                    format: chartodo repeating-editcatchup [position] [catch-up]
+                                                    example: chartodo rp-ec 1 next
+            repeating-editpriority, rp-ep           edit the priority of a repeating task to high, medium, low, or none. Tasks due at the same time are sorted by priority
+                                                    format: chartodo repeating-editpriority [position] [priority]
+                                                    example: chartodo rp-ep 1 medium
+            repeating-tag, rp-tg                    add tags to a repeating task
+                                                    format: chartodo repeating-tag [position] [tag] [...]
+                                                    example: chartodo rp-tg 1 backend @errands
+            repeating-untag, rp-utg                 take tags away from a repeating task. A tag w/o + or @ takes away both, e.g., backend takes away +backend and @backend
+                                                    format: chartodo repeating-untag [position] [tag] [...]
+                                                    example: chartodo rp-utg 1 backend
 
         REPEATING DONE:
             repeating-notdone, rp-nd                reverse repeating dones back to todo. Has chaining and range positioning
@@ -986,7 +1605,10 @@ fn repeating_help() {
                                                     format: chartodo repeating-rmdone [position]
                                                     example: chartodo rp-rmd 1 3-5
             repeating-notdoneall, rp-nda            reverse all finished repeating tasks back to todo
-            repeating-cleardone, rp-cd              delete all of the finished repeating tasks
+            repeating-cleardone, rp-cd              delete all of the finished repeating tasks. Pass --archive to archive them instead of deleting them
+            repeating-archive, rp-ar                moves a finished repeating task to the archive. Has chaining and range positioning
+                                                    format: chartodo repeating-archive [position]
+                                                    example: chartodo rp-ar 1 3-5
     ").expect("writeln failed");
 }
 
@@ -0,0 +1,229 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use serde_json::Value;
+
+mod common;
+use common::*;
+
+// cargo test --test archive_tests
+// every test points chartodo at its own folder in the temp dir, so these never touch the real chartodo files
+
+#[test]
+fn archive_and_restore() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("archive_and_restore");
+
+    chartodo(&data_dir, &["archive-list"])
+        .assert()
+        .success()
+        .stdout("Nothing has been archived yet.\n");
+
+    chartodo(&data_dir, &["d", "1"]).assert().success();
+    chartodo(&data_dir, &["ar", "2"]).assert().success();
+    chartodo(&data_dir, &["dl-d", "1"]).assert().success();
+    chartodo(&data_dir, &["dl-ar", "#3"]).assert().success();
+
+    let chartodo_json = read_json(data_dir.join("chartodo.json"));
+    assert_eq!(
+        tasks_in(&chartodo_json["regular"]["done"]),
+        vec!["this is the done list"]
+    );
+    assert!(tasks_in(&chartodo_json["deadline"]["done"]).is_empty());
+
+    chartodo(&data_dir, &["archive-list"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::starts_with("REGULAR\n1: this is the todo list #1, done: ")
+                .and(predicate::str::contains(
+                    "DEADLINE\n1: breathe-once-before-2099 #3, due: 2099-01-01 00:00, done: ",
+                ))
+                .and(predicate::str::contains("REPEATING").not()),
+        );
+
+    chartodo(&data_dir, &["ar-r", "regular", "1"])
+        .assert()
+        .success();
+    let chartodo_json = read_json(data_dir.join("chartodo.json"));
+    assert_eq!(
+        tasks_in(&chartodo_json["regular"]["done"]),
+        vec!["this is the done list", "this is the todo list"]
+    );
+
+    let archive = read_json(data_dir.join("archive.json"));
+    assert!(tasks_in(&archive["regular"]).is_empty());
+    assert_eq!(
+        tasks_in(&archive["deadline"]),
+        vec!["breathe-once-before-2099"]
+    );
+
+    std::fs::remove_dir_all(&data_dir)?;
+
+    Ok(())
+}
+
+#[test]
+fn archive_list_as_json() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("json");
+    chartodo(&data_dir, &["ar", "1"]).assert().success();
+
+    let output = chartodo(&data_dir, &["ar-l", "--format", "json"])
+        .output()
+        .expect("couldn't run chartodo");
+    let result: Value = serde_json::from_slice(&output.stdout)?;
+
+    assert_eq!(result["ok"], true);
+    assert_eq!(
+        tasks_in(&result["archive"]["regular"]),
+        vec!["this is the done list"]
+    );
+    assert_eq!(result["archive"]["regular"][0]["position"], 1);
+    assert!(result["archive"]["regular"][0]["done_at"].is_string());
+    assert!(tasks_in(&result["archive"]["repeating"]).is_empty());
+
+    std::fs::remove_dir_all(&data_dir)?;
+
+    Ok(())
+}
+
+#[test]
+fn cleardone_and_clearboth_can_archive() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("clear");
+
+    chartodo(&data_dir, &["rp-da"]).assert().success();
+    chartodo(&data_dir, &["rp-cd", "--archive"])
+        .assert()
+        .success();
+    chartodo(&data_dir, &["cb", "--archive"]).assert().success();
+    // w/o --archive, they're gone
+    chartodo(&data_dir, &["dl-d", "1"]).assert().success();
+    chartodo(&data_dir, &["dl-cb"]).assert().success();
+
+    let chartodo_json = read_json(data_dir.join("chartodo.json"));
+    assert!(tasks_in(&chartodo_json["regular"]["todo"]).is_empty());
+    assert!(tasks_in(&chartodo_json["regular"]["done"]).is_empty());
+    assert!(tasks_in(&chartodo_json["repeating"]["done"]).is_empty());
+
+    // the regular todo is deleted, not archived
+    let archive = read_json(data_dir.join("archive.json"));
+    assert_eq!(tasks_in(&archive["regular"]), vec!["this is the done list"]);
+    assert!(tasks_in(&archive["deadline"]).is_empty());
+    assert_eq!(
+        tasks_in(&archive["repeating"]),
+        vec!["the-turn-of-the-century"]
+    );
+
+    std::fs::remove_dir_all(&data_dir)?;
+
+    Ok(())
+}
+
+#[test]
+fn clearall_can_archive() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("clearall");
+
+    chartodo(&data_dir, &["dl-d", "1"]).assert().success();
+    chartodo(&data_dir, &["ca-d", "--archive"])
+        .assert()
+        .success();
+    chartodo(&data_dir, &["rp-da"]).assert().success();
+    chartodo(&data_dir, &["ca", "--archive"]).assert().success();
+
+    let chartodo_json = read_json(data_dir.join("chartodo.json"));
+    for list in ["regular", "deadline", "repeating"] {
+        assert!(tasks_in(&chartodo_json[list]["todo"]).is_empty());
+        assert!(tasks_in(&chartodo_json[list]["done"]).is_empty());
+    }
+
+    // only the done tasks are archived
+    let archive = read_json(data_dir.join("archive.json"));
+    assert_eq!(tasks_in(&archive["regular"]), vec!["this is the done list"]);
+    assert_eq!(
+        tasks_in(&archive["deadline"]),
+        vec!["breathe-once-before-2099"]
+    );
+    assert_eq!(
+        tasks_in(&archive["repeating"]),
+        vec!["the-turn-of-the-century"]
+    );
+
+    std::fs::remove_dir_all(&data_dir)?;
+
+    Ok(())
+}
+
+#[test]
+fn undo_puts_the_archive_back() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("undo");
+
+    chartodo(&data_dir, &["cd", "--archive"]).assert().success();
+    chartodo(&data_dir, &["undo"]).assert().success();
+
+    let chartodo_json = read_json(data_dir.join("chartodo.json"));
+    assert_eq!(
+        tasks_in(&chartodo_json["regular"]["done"]),
+        vec!["this is the done list"]
+    );
+    assert!(tasks_in(&read_json(data_dir.join("archive.json"))["regular"]).is_empty());
+
+    chartodo(&data_dir, &["redo"]).assert().success();
+    assert_eq!(
+        tasks_in(&read_json(data_dir.join("archive.json"))["regular"]),
+        vec!["this is the done list"]
+    );
+
+    std::fs::remove_dir_all(&data_dir)?;
+
+    Ok(())
+}
+
+#[test]
+fn archive_errors() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("errors");
+
+    chartodo(&data_dir, &["dl-ar", "1"])
+        .assert()
+        .failure()
+        .code(5)
+        .stderr(predicate::str::contains(
+            "The deadline done list is currently empty, so you can't archive any items.",
+        ));
+    chartodo(&data_dir, &["ar", "0", "5"])
+        .assert()
+        .failure()
+        .code(3)
+        .stderr(predicate::str::contains(
+            "None of the positions you provided were viable",
+        ));
+    chartodo(&data_dir, &["ar-r", "regular", "1"])
+        .assert()
+        .failure()
+        .code(5)
+        .stderr(predicate::str::contains(
+            "The regular archive is currently empty, so you can't restore any items.",
+        ));
+    chartodo(&data_dir, &["ar-r", "dl", "1"])
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "'dl' isn't a list. Try regular, deadline, or repeating",
+        ));
+
+    // more than 5 done tasks should be archived w/ cleardone --archive
+    let tasks: Vec<String> = (1..=6).map(|number| format!("task-{}", number)).collect();
+    let tasks: Vec<&str> = tasks.iter().map(String::as_str).collect();
+    chartodo(&data_dir, &[&["add"], tasks.as_slice()].concat())
+        .assert()
+        .success();
+    chartodo(&data_dir, &["da"]).assert().success();
+    chartodo(&data_dir, &["ar", "1-8"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "You should do chartodo cleardone --archive.",
+        ));
+
+    std::fs::remove_dir_all(&data_dir)?;
+
+    Ok(())
+}
@@ -0,0 +1,154 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+mod common;
+use common::*;
+
+// cargo test --test catch_up_tests
+// every test points chartodo at its own folder in the temp dir, so these never touch the real chartodo files. the
+// lists are read back w/ --format json, see json_output_tests
+//
+// a task that's missed times has to start in the past, so these start in 2000 and are marked done right away. the
+// next list then rolls them over
+
+fn lists(data_dir: &PathBuf) -> Value {
+    let output = chartodo(data_dir, &["list", "--format", "json"])
+        .output()
+        .expect("couldn't run chartodo");
+    let result: Value =
+        serde_json::from_slice(&output.stdout).expect("chartodo didn't print a json object");
+
+    result["lists"].clone()
+}
+
+// the skipped lines in history.jsonl. the task being done is in there too, see history_tests
+fn skipped_lines(data_dir: &Path) -> Vec<Value> {
+    std::fs::read_to_string(data_dir.join("history.jsonl"))
+        .unwrap_or_default()
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(line).expect("a history line isn't json"))
+        .filter(|event| event["event"] == "skipped")
+        .collect()
+}
+
+// the example repeating task is due in 2100, so one that started in 2000 is first
+fn add_and_finish(data_dir: &PathBuf, catch_up: &str) {
+    chartodo(
+        data_dir,
+        &[
+            "rp-as",
+            "water-plants",
+            "1",
+            "day",
+            "2000-01-01",
+            "00:00",
+            "--catch-up",
+            catch_up,
+        ],
+    )
+    .assert()
+    .success();
+    chartodo(data_dir, &["rp-d", "1"]).assert().success();
+}
+
+#[test]
+fn once_is_what_it_always_did() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("once");
+    add_and_finish(&data_dir, "once");
+
+    let lists = lists(&data_dir);
+    let water = &lists["repeating"]["todo"][0];
+    assert_eq!(water["task"], "water-plants");
+    assert_eq!(water["due"], "2000-01-03 00:00");
+    assert_eq!(water["catch_up"], "once");
+    assert_eq!(lists["deadline"]["todo"].as_array().unwrap().len(), 1);
+    assert!(skipped_lines(&data_dir).is_empty());
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn next_skips_ahead_and_keeps_the_skips() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("next");
+    add_and_finish(&data_dir, "next");
+
+    let lists = lists(&data_dir);
+    let water = lists["repeating"]["todo"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|task| task["task"] == "water-plants")
+        .unwrap();
+    assert_eq!(water["catch_up"], "next");
+    assert_eq!(water["status"], "due");
+    assert_eq!(lists["deadline"]["todo"].as_array().unwrap().len(), 1);
+
+    let history = skipped_lines(&data_dir);
+    assert_eq!(history[0]["event"], "skipped");
+    assert_eq!(history[0]["list"], "repeating");
+    assert_eq!(history[0]["task"], "water-plants");
+    assert_eq!(history[0]["scheduled"], "2000-01-03 00:00");
+    assert_eq!(history[1]["scheduled"], "2000-01-04 00:00");
+
+    // it's already caught up, so listing again adds nothing
+    let skipped = history.len();
+    chartodo(&data_dir, &["list"]).assert().success();
+    assert_eq!(skipped_lines(&data_dir).len(), skipped);
+
+    chartodo(&data_dir, &["list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("interval: 1 day, catch-up: next"));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn each_adds_deadline_todos() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("each");
+    add_and_finish(&data_dir, "each");
+
+    // only the latest 100 get a todo, the rest are in the history
+    let lists = lists(&data_dir);
+    let missed: Vec<&Value> = lists["deadline"]["todo"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|task| task["task"] == "water-plants")
+        .collect();
+    assert_eq!(missed.len(), 100);
+    assert!(missed.iter().all(|task| task["status"] == "missed"));
+    assert_eq!(skipped_lines(&data_dir)[0]["scheduled"], "2000-01-03 00:00");
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn editcatchup_changes_the_policy() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("editcatchup");
+
+    chartodo(&data_dir, &["rp-ec", "1", "each"])
+        .assert()
+        .success();
+    assert_eq!(lists(&data_dir)["repeating"]["todo"][0]["catch_up"], "each");
+
+    chartodo(&data_dir, &["rp-ec", "1", "sometimes"])
+        .assert()
+        .code(4)
+        .stderr(predicate::str::contains("once, next, or each"));
+    chartodo(
+        &data_dir,
+        &["rp-a", "gym", "2", "days", "--catch-up", "sometimes"],
+    )
+    .assert()
+    .failure();
+    assert_eq!(lists(&data_dir)["repeating"]["todo"][0]["catch_up"], "each");
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
@@ -0,0 +1,96 @@
+// helpers shared by the integration tests. every test points chartodo at its own folder in the temp dir, named after
+// the test file and the test, so these never touch the real chartodo files. not every test file uses all of them
+#![allow(dead_code)]
+
+use assert_cmd::prelude::*;
+use chartodo::functions::{
+    json_file_structs::Tasks, storage_backend::storage_backend_in,
+    storage_helpers::new_chartodo_file, task_store::TaskList,
+};
+use serde_json::Value;
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+// these turn on the example tasks, which new lists don't have unless they're turned on
+pub const STARTER_TASKS: &str = "[defaults]\nstarter_tasks = true\n";
+
+// a data dir that doesn't exist yet, e.g., for init to create
+pub fn missing_data_dir(test_name: &str) -> PathBuf {
+    // e.g., archive_tests::common, since each test file gets its own copy of this module
+    let test_file = module_path!().split("::").next().unwrap_or("chartodo");
+    let mut data_dir = std::env::temp_dir();
+    data_dir.push(format!(
+        "chartodo_{}_{}_{}",
+        test_file,
+        test_name,
+        std::process::id()
+    ));
+
+    // in case a previous run didn't clean up after itself
+    if data_dir.exists() {
+        std::fs::remove_dir_all(&data_dir).expect("couldn't remove old test data dir");
+    }
+
+    data_dir
+}
+
+// a data dir whose lists start from the example tasks
+pub fn fresh_data_dir(test_name: &str) -> PathBuf {
+    let data_dir = missing_data_dir(test_name);
+    std::fs::create_dir(&data_dir).expect("couldn't create test data dir");
+    std::fs::write(data_dir.join("config.toml"), STARTER_TASKS)
+        .expect("couldn't write test config");
+
+    data_dir
+}
+
+pub fn chartodo(data_dir: &PathBuf, args: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("chartodo").expect("couldn't find chartodo binary");
+    cmd.arg("--data-dir").arg(data_dir).args(args);
+
+    cmd
+}
+
+pub fn read_json(path: PathBuf) -> Value {
+    serde_json::from_str(&std::fs::read_to_string(path).expect("couldn't read the file"))
+        .expect("the file isn't json")
+}
+
+pub fn tasks_in(list: &Value) -> Vec<&str> {
+    list.as_array()
+        .unwrap()
+        .iter()
+        .map(|task| task["task"].as_str().unwrap())
+        .collect()
+}
+
+// puts tasks in one list of the data dir, e.g., so that a test knows what's in it before running a command. the other
+// lists are left as they were, or start w/ the example tasks, same as fresh_data_dir
+pub fn write_tasks(data_dir: &Path, list: TaskList, mut tasks: Tasks) {
+    let backend = storage_backend_in(data_dir).expect("couldn't get the storage backend");
+    let mut chartodo_file = match backend.load().expect("couldn't load the lists") {
+        Some((chartodo_file, _)) => chartodo_file,
+        None => new_chartodo_file(true),
+    };
+    // the deadline and repeating lists are kept sorted by when they're due, same as chartodo writes them
+    if list != TaskList::Regular {
+        tasks
+            .todo
+            .sort_by_key(|task| (task.due(), task.priority_rank()));
+        tasks
+            .done
+            .sort_by_key(|task| (task.due(), task.priority_rank()));
+    }
+    match list {
+        TaskList::Regular => chartodo_file.regular = tasks,
+        TaskList::Deadline => chartodo_file.deadline = tasks,
+        TaskList::Repeating => chartodo_file.repeating = tasks,
+    }
+
+    chartodo_file.give_ids_to_new_tasks();
+    backend
+        .save(&chartodo_file)
+        .expect("couldn't write the lists");
+}
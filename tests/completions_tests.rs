@@ -0,0 +1,99 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::process::Command;
+
+mod common;
+use common::*;
+
+// cargo test --test completions_tests
+// these point chartodo at their own folder in the temp dir, so the real chartodo files are never touched
+
+#[test]
+fn completions_prints_script_for_each_shell() -> Result<(), Box<dyn std::error::Error>> {
+    for shell in ["bash", "zsh", "fish", "elvish", "powershell"] {
+        let mut cmd = Command::cargo_bin("chartodo")?;
+        cmd.arg("completions").arg(shell);
+        cmd.assert().success().stdout(
+            predicate::str::contains(format!("COMPLETE=\"{}\"", shell))
+                .or(predicate::str::contains(format!("COMPLETE={}", shell)))
+                .or(predicate::str::contains(format!(
+                    "COMPLETE = \"{}\"",
+                    shell
+                ))),
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn completions_unknown_shell() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.arg("completions").arg("notashell");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value 'notashell'"));
+
+    Ok(())
+}
+
+#[test]
+fn commands_complete_with_aliases() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.env("COMPLETE", "fish")
+        .arg("--")
+        .arg("chartodo")
+        .arg("rp-ei");
+    cmd.assert().success().stdout(predicate::str::contains(
+        "rp-eiu\tedit the interval and time unit",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn positions_complete_with_task_text() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("positions");
+
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.arg("--data-dir")
+        .arg(&data_dir)
+        .arg("add")
+        .arg("buy-milk")
+        .arg("walk-dog");
+    cmd.assert().success();
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.arg("--data-dir")
+        .arg(&data_dir)
+        .arg("deadline-add")
+        .arg("pay-rent")
+        .arg("2099-02-01")
+        .arg("09:00");
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.env("CHARTODO_HOME", &data_dir)
+        .env("COMPLETE", "fish")
+        .arg("--")
+        .arg("chartodo")
+        .arg("done")
+        .arg("");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("2\tbuy-milk"))
+        .stdout(predicate::str::contains("3\twalk-dog"));
+
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.env("CHARTODO_HOME", &data_dir)
+        .env("COMPLETE", "fish")
+        .arg("--")
+        .arg("chartodo")
+        .arg("dl-eta")
+        .arg("");
+    cmd.assert().success().stdout(predicate::str::contains(
+        "2\tpay-rent (due: 2099-02-01 09:00)",
+    ));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
@@ -0,0 +1,159 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use serde_json::Value;
+
+mod common;
+use common::*;
+
+// cargo test --test config_tests
+// every test points chartodo at its own folder in the temp dir, so these never touch the real chartodo files
+
+#[test]
+fn config_get_and_set() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("get_and_set");
+
+    chartodo(&data_dir, &["config", "get"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("[defaults]\ndeadline_time = \"00:00\"")
+                .and(predicate::str::contains("table_style = \"rounded\"")),
+        );
+    chartodo(
+        &data_dir,
+        &["config", "set", "defaults.whole_list_threshold", "10"],
+    )
+    .assert()
+    .success()
+    .stdout("defaults.whole_list_threshold = 10\n");
+    chartodo(
+        &data_dir,
+        &["config", "get", "defaults.whole_list_threshold"],
+    )
+    .assert()
+    .success()
+    .stdout("defaults.whole_list_threshold = 10\n");
+    assert_eq!(
+        std::fs::read_to_string(data_dir.join("config.toml"))?,
+        "[defaults]\nstarter_tasks = true\nwhole_list_threshold = 10\n"
+    );
+
+    chartodo(
+        &data_dir,
+        &["config", "set", "display.table_style", "fancy"],
+    )
+    .assert()
+    .failure()
+    .code(4)
+    .stderr(predicate::str::contains(
+        "'fancy' isn't a proper value for display.table_style",
+    ));
+    chartodo(&data_dir, &["config", "get", "display.colour"])
+        .assert()
+        .failure()
+        .code(4)
+        .stderr(predicate::str::contains(
+            "'display.colour' isn't a setting.",
+        ));
+
+    let output = chartodo(
+        &data_dir,
+        &["config", "get", "display.missed_label", "--format", "json"],
+    )
+    .output()
+    .expect("couldn't run chartodo");
+    let result: Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(result["ok"], true);
+    assert_eq!(result["config"]["display.missed_label"], "MISSED");
+
+    std::fs::remove_dir_all(&data_dir)?;
+
+    Ok(())
+}
+
+#[test]
+fn no_starter_tasks() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("no_starter_tasks");
+    std::fs::write(
+        data_dir.join("config.toml"),
+        "[defaults]\nstarter_tasks = false\n",
+    )?;
+
+    chartodo(&data_dir, &["add", "first"]).assert().success();
+
+    let chartodo_json = read_json(data_dir.join("chartodo.json"));
+    assert_eq!(chartodo_json["regular"]["todo"][0]["task"], "first");
+    assert_eq!(chartodo_json["regular"]["todo"][0]["id"], 1);
+    for list in ["regular", "deadline", "repeating"] {
+        assert!(chartodo_json[list]["done"].as_array().unwrap().is_empty());
+    }
+    assert!(chartodo_json["deadline"]["todo"]
+        .as_array()
+        .unwrap()
+        .is_empty());
+
+    std::fs::remove_dir_all(&data_dir)?;
+
+    Ok(())
+}
+
+#[test]
+fn defaults_change_what_commands_do() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("defaults");
+    std::fs::write(
+        data_dir.join("config.toml"),
+        "[defaults]\nstarter_tasks = true\ndeadline_time = \"17:00\"\nwhole_list_threshold = 1\n",
+    )?;
+
+    chartodo(&data_dir, &["dl-aod", "taxes", "2099-04-15"])
+        .assert()
+        .success();
+    let chartodo_json = read_json(data_dir.join("chartodo.json"));
+    assert_eq!(chartodo_json["deadline"]["todo"][1]["task"], "taxes");
+    assert_eq!(chartodo_json["deadline"]["todo"][1]["time"], "17:00");
+
+    // 2 is more than the threshold, so giving every position is refused
+    chartodo(&data_dir, &["dl-d", "1", "2"])
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("WARNING"));
+
+    std::fs::remove_dir_all(&data_dir)?;
+
+    Ok(())
+}
+
+#[test]
+fn display_settings() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("display");
+    std::fs::write(
+        data_dir.join("config.toml"),
+        format!(
+            "{}[display]\ntable_style = \"ascii\"\nmissed_label = \"LATE\"\ndue_label = \"by\"\n",
+            STARTER_TASKS
+        ),
+    )?;
+
+    chartodo(&data_dir, &["dl-a", "old", "2000-01-01", "00:00"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("| CHARTODO")
+                .and(predicate::str::contains("LATE: 2000-01-01 00:00"))
+                .and(predicate::str::contains("by: 2099-01-01 00:00"))
+                .and(predicate::str::contains("╭").not()),
+        );
+
+    // json stays the same, so that scripts don't have to know the labels
+    let output = chartodo(&data_dir, &["list", "--format", "json"])
+        .output()
+        .expect("couldn't run chartodo");
+    let result: Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(result["lists"]["deadline"]["todo"][0]["status"], "missed");
+    assert_eq!(result["lists"]["deadline"]["todo"][1]["status"], "due");
+
+    std::fs::remove_dir_all(&data_dir)?;
+
+    Ok(())
+}
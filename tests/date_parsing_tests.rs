@@ -0,0 +1,121 @@
+use assert_cmd::prelude::*;
+use chrono::{Duration, Local};
+use predicates::prelude::*;
+use serde_json::Value;
+use std::path::PathBuf;
+
+mod common;
+use common::*;
+
+// cargo test --test date_parsing_tests
+// every test points chartodo at its own folder in the temp dir, so these never touch the real chartodo files. the
+// lists are read back w/ --format json, see json_output_tests
+
+// the task w/ this name in the list, as json
+fn task_in(data_dir: &PathBuf, list: &str, task: &str) -> Value {
+    let output = chartodo(data_dir, &["list", "--format", "json"])
+        .output()
+        .expect("couldn't run chartodo");
+    let result: Value =
+        serde_json::from_slice(&output.stdout).expect("chartodo didn't print a json object");
+
+    result["lists"][list]["todo"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|json_task| json_task["task"] == task)
+        .cloned()
+        .unwrap_or_else(|| panic!("'{}' isn't in the {} list", task, list))
+}
+
+#[test]
+fn deadline_add_takes_natural_dates_and_times() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("deadline_add");
+    let tomorrow = (Local::now().date_naive() + Duration::days(1)).format("%Y-%m-%d");
+
+    chartodo(&data_dir, &["dl-a", "lunch", "tomorrow", "noon"])
+        .assert()
+        .success();
+    chartodo(&data_dir, &["dl-aod", "meeting", "2099-06-01T08:30"])
+        .assert()
+        .success();
+    chartodo(&data_dir, &["dl-aod", "report", "eod"])
+        .assert()
+        .success();
+
+    assert_eq!(
+        task_in(&data_dir, "deadline", "lunch")["due"],
+        format!("{} 12:00", tomorrow)
+    );
+    assert_eq!(
+        task_in(&data_dir, "deadline", "meeting")["due"],
+        "2099-06-01 08:30"
+    );
+    assert_eq!(
+        task_in(&data_dir, "deadline", "report")["due"],
+        format!("{} 23:59", Local::now().date_naive().format("%Y-%m-%d"))
+    );
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn deadline_edit_takes_natural_dates() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("deadline_edit");
+    let in_three_days = (Local::now().date_naive() + Duration::days(3)).format("%Y-%m-%d");
+
+    // the example deadline task is #3, due 2099-01-01 00:00
+    chartodo(&data_dir, &["dl-eti", "#3", "09:15"])
+        .assert()
+        .success();
+    chartodo(&data_dir, &["dl-ed", "#3", "+3d"])
+        .assert()
+        .success();
+
+    assert_eq!(
+        task_in(&data_dir, "deadline", "breathe-once-before-2099")["due"],
+        format!("{} 09:15", in_three_days)
+    );
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn repeating_add_start_takes_natural_dates() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("repeating_add_start");
+
+    chartodo(
+        &data_dir,
+        &["rp-as", "gym", "1", "week", "in 2 weeks", "07:00"],
+    )
+    .assert()
+    .success();
+
+    let in_two_weeks = (Local::now().date_naive() + Duration::days(14)).format("%Y-%m-%d");
+    let in_three_weeks = (Local::now().date_naive() + Duration::days(21)).format("%Y-%m-%d");
+    let gym = task_in(&data_dir, "repeating", "gym");
+    assert_eq!(gym["start"], format!("{} 07:00", in_two_weeks));
+    assert_eq!(gym["due"], format!("{} 07:00", in_three_weeks));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn improper_natural_date_is_an_error() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("improper");
+
+    chartodo(&data_dir, &["dl-a", "x", "next someday", "00:00"])
+        .assert()
+        .code(4)
+        .stderr(predicate::str::contains("'next someday', was invalid"));
+    chartodo(&data_dir, &["dl-a", "x", "fri", "teatime"])
+        .assert()
+        .code(4)
+        .stderr(predicate::str::contains("'teatime', was invalid"));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
@@ -1,132 +1,52 @@
 use anyhow::Context;
 use assert_cmd::prelude::*;
-use chartodo::functions::{deadline_tasks::deadline_helpers::*, json_file_structs::*};
+use chartodo::functions::{json_file_structs::*, task_store::TaskList};
 use predicates::prelude::*;
-use std::{path::PathBuf, process::Command};
 
-// cargo test --test deadline_done_tests -- --test-threads=1
+mod common;
+use common::*;
 
-fn path_to_deadline_tasks() -> PathBuf {
-    // get the data dir XDG spec and return it with path to deadline_tasks.json
-    let mut deadline_tasks_path = dirs::data_dir()
-        .context(
-            "linux: couldn't get $HOME/.local/share/
-                windows: couldn't get C:/Users/your_user/AppData/Local/
-                mac: couldn't get /Users/your_user/Library/Application Support/
-
-                those directories should exist for your OS. please double check that they do.",
-        )
-        .expect("something went wrong with fetching the user's data dirs");
-    deadline_tasks_path.push("chartodo/deadline_tasks.json");
-
-    deadline_tasks_path
-}
-
-fn deadline_tasks_copy_path() -> PathBuf {
-    // get the path for deadline_tasks_copy.json, which will be used to hold the original contents
-    // of deadline_tasks.json while it's getting modified
-    let mut deadline_tasks_copy_path = dirs::data_dir()
-        .context(
-            "linux: couldn't get $HOME/.local/share/
-                windows: couldn't get C:/Users/your_user/AppData/Local/
-                mac: couldn't get /Users/your_user/Library/Application Support/
-
-                those directories should exist for your OS. please double check that they do.",
-        )
-        .expect("something went wrong with fetching the user's data dirs");
-    deadline_tasks_copy_path.push("chartodo/deadline_tasks_copy.json");
-
-    deadline_tasks_copy_path
-}
-
-mod aaa_do_this_first {
-    use super::*;
-
-    // these have been tested in other fns, these are just included here as a sanity check
-    #[test]
-    fn deadline_tasks_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/deadline_tasks.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/deadline_tasks.json";
-        let mac_path = "/Library/Application Support/chartodo/deadline_tasks.json";
-        let mut got_deadline_tasks_path: bool = false;
-        let deadline_path = path_to_deadline_tasks();
-        let deadline_path = deadline_path.to_str().unwrap();
-
-        if deadline_path.contains(linux_path)
-            | deadline_path.contains(windows_path)
-            | deadline_path.contains(mac_path)
-        {
-            got_deadline_tasks_path = true;
-        }
-
-        assert!(got_deadline_tasks_path);
-    }
-
-    #[test]
-    fn deadline_tasks_copy_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/deadline_tasks_copy.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/deadline_tasks_copy.json";
-        let mac_path = "/Library/Application Support/chartodo/deadline_tasks_copy.json";
-        let mut got_deadline_tasks_copy_path: bool = false;
-        let deadline_tasks_copy_path = deadline_tasks_copy_path();
-        let deadline_tasks_copy_path = deadline_tasks_copy_path.to_str().unwrap();
-
-        if deadline_tasks_copy_path.contains(linux_path)
-            | deadline_tasks_copy_path.contains(windows_path)
-            | deadline_tasks_copy_path.contains(mac_path)
-        {
-            got_deadline_tasks_copy_path = true;
-        }
-
-        assert!(got_deadline_tasks_copy_path);
-    }
-
-    #[test]
-    fn aaaa_deadline_tasks_clone_file() {
-        // name is aaaa so it's done first
-        // since we will be modifying the original file to run a test, the original data must be
-        // preserved first
-        std::fs::File::create(deadline_tasks_copy_path())
-            .context("failed to create deadline_tasks_copy.json")
-            .expect("failed to create a copy during unit test");
-
-        std::fs::copy(path_to_deadline_tasks(), deadline_tasks_copy_path())
-            .context("failed to copy deadline_tasks.json to deadline_tasks_copy.json")
-            .expect("failed to copy original file to copy file during unit test");
-    }
-}
+// cargo test --test deadline_done_tests
 
 mod deadline_done_rmdone {
     use super::*;
 
     #[test]
     fn rmdone_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-rmdone");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for deadline-rmdone",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-rmd");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for deadline-rmdone",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_empty_done");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -140,21 +60,25 @@ mod deadline_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-rmdone").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline done list is currently empty, so you can't \
             remove any items.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_abrev_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_abrev_empty_done");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -168,21 +92,25 @@ mod deadline_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-rmd").arg("1").arg("1").arg("2");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline done list is currently empty, so you can't \
             remove any items.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_no_valid_args");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -207,26 +135,30 @@ mod deadline_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-rmdone")
             .arg("0")
             .arg("a")
             .arg("2")
             .arg("");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, or exceeded the deadline done list's \
             length.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_abrev_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_abrev_no_valid_args");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -251,22 +183,26 @@ mod deadline_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-rmd").arg("0").arg("a").arg("2").arg("");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, or exceeded the deadline done list's \
             length.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_should_do_deadline_cleartodo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_should_do_deadline_cleartodo");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -341,10 +277,10 @@ mod deadline_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-rmdone")
             .arg("1")
             .arg("1")
@@ -353,16 +289,20 @@ mod deadline_done_rmdone {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You might as well do deadline-cleardone since you want to \
             remove all of the items.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_should_do_deadline_cleartodo_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_should_do_deadline_cleartodo_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -437,21 +377,25 @@ mod deadline_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-rmdone").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You might as well do deadline-cleardone since you want to \
             remove all of the items.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_abrev_should_do_deadline_cleartodo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_abrev_should_do_deadline_cleartodo");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -526,10 +470,10 @@ mod deadline_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-rmd")
             .arg("1")
             .arg("1")
@@ -538,16 +482,20 @@ mod deadline_done_rmdone {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You might as well do deadline-cleardone since you want to \
             remove all of the items.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_abrev_should_do_deadline_cleartodo_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_abrev_should_do_deadline_cleartodo_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -622,21 +570,25 @@ mod deadline_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-rmd").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You might as well do deadline-cleardone since you want to \
             remove all of the items.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -671,10 +623,10 @@ mod deadline_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-rmdone").arg("1").arg("1");
         assert!(cmd
             .assert()
@@ -682,11 +634,15 @@ mod deadline_done_rmdone {
             .try_stdout(predicate::str::contains("1: deadline-task-1"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_abrev_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -721,10 +677,10 @@ mod deadline_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-rmd").arg("1").arg("1");
         assert!(cmd
             .assert()
@@ -732,11 +688,15 @@ mod deadline_done_rmdone {
             .try_stdout(predicate::str::contains("1: deadline-task-1"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_multiple_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -801,10 +761,10 @@ mod deadline_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-rmdone")
             .arg("1")
             .arg("1")
@@ -815,11 +775,15 @@ mod deadline_done_rmdone {
             .stdout(predicate::str::contains("1: deadline-task-4"))
             .stdout(predicate::str::contains("2: deadline-task-2"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_multiple_is_correct_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_multiple_is_correct_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -884,21 +848,25 @@ mod deadline_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-rmdone").arg("3-5").arg("5");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: deadline-task-5"))
             .stdout(predicate::str::contains("2: deadline-task-4"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_abrev_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_abrev_multiple_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -963,21 +931,25 @@ mod deadline_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-rmd").arg("1").arg("1").arg("3").arg("5");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: deadline-task-4"))
             .stdout(predicate::str::contains("2: deadline-task-2"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_abrev_multiple_is_correct_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_abrev_multiple_is_correct_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1042,16 +1014,18 @@ mod deadline_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-rmd").arg("3-5");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: deadline-task-5"))
             .stdout(predicate::str::contains("2: deadline-task-4"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -1061,30 +1035,40 @@ mod deadline_done_notdone {
 
     #[test]
     fn notdone_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-notdone");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for deadline-notdone",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-nd");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for deadline-notdone",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_empty_done");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1098,20 +1082,24 @@ mod deadline_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-notdone").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline done list is currently empty.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_abrev_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_abrev_empty_done");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1125,20 +1113,24 @@ mod deadline_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-nd").arg("1").arg("1").arg("2");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline done list is currently empty.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_no_valid_args");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1163,26 +1155,30 @@ mod deadline_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-notdone")
             .arg("0")
             .arg("a")
             .arg("2")
             .arg("");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, or exceeded the deadline done list's \
             length.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_abrev_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_abrev_no_valid_args");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1207,22 +1203,26 @@ mod deadline_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-nd").arg("0").arg("a").arg("2").arg("");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, or exceeded the deadline done list's \
             length.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_should_do_deadline_notdoneall() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_should_do_deadline_notdoneall");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1297,10 +1297,10 @@ mod deadline_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-notdone")
             .arg("1")
             .arg("1")
@@ -1309,16 +1309,20 @@ mod deadline_done_notdone {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You might as well do deadline-notdoneall since you want to \
             reverse all deadline done items.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_should_do_deadline_notdoneall_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_should_do_deadline_notdoneall_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1393,21 +1397,25 @@ mod deadline_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-notdone").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You might as well do deadline-notdoneall since you want to \
             reverse all deadline done items.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_abrev_should_do_deadline_notdoneall() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_abrev_should_do_deadline_notdoneall");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1482,10 +1490,10 @@ mod deadline_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-nd")
             .arg("1")
             .arg("1")
@@ -1494,17 +1502,21 @@ mod deadline_done_notdone {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You might as well do deadline-notdoneall since you want to \
             reverse all deadline done items.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_abrev_should_do_deadline_notdoneall_range() -> Result<(), Box<dyn std::error::Error>>
     {
+        let data_dir = fresh_data_dir("notdone_abrev_should_do_deadline_notdoneall_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1579,21 +1591,25 @@ mod deadline_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-nd").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You might as well do deadline-notdoneall since you want to \
             reverse all deadline done items.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1628,21 +1644,25 @@ mod deadline_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-notdone").arg("1").arg("1");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: deadline-task-1"))
             .stdout(predicate::str::contains("1: deadline-task-2"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_abrev_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1677,21 +1697,25 @@ mod deadline_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-nd").arg("1").arg("1");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: deadline-task-1"))
             .stdout(predicate::str::contains("1: deadline-task-2"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_multiple_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1756,10 +1780,10 @@ mod deadline_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-notdone")
             .arg("1")
             .arg("1")
@@ -1773,11 +1797,15 @@ mod deadline_done_notdone {
             .stdout(predicate::str::contains("2: deadline-task-3"))
             .stdout(predicate::str::contains("3: deadline-task-1"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_multiple_is_correct_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_multiple_is_correct_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1842,10 +1870,10 @@ mod deadline_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-notdone").arg("3-5");
         cmd.assert()
             .success()
@@ -1855,11 +1883,15 @@ mod deadline_done_notdone {
             .stdout(predicate::str::contains("1: deadline-task-5"))
             .stdout(predicate::str::contains("2: deadline-task-4"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_abrev_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_abrev_multiple_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1924,10 +1956,10 @@ mod deadline_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-nd").arg("1").arg("1").arg("3").arg("5");
         cmd.assert()
             .success()
@@ -1937,11 +1969,15 @@ mod deadline_done_notdone {
             .stdout(predicate::str::contains("2: deadline-task-3"))
             .stdout(predicate::str::contains("3: deadline-task-1"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_abrev_multiple_is_correct_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_abrev_multiple_is_correct_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2006,10 +2042,10 @@ mod deadline_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-nd").arg("3-5");
         cmd.assert()
             .success()
@@ -2019,6 +2055,8 @@ mod deadline_done_notdone {
             .stdout(predicate::str::contains("1: deadline-task-5"))
             .stdout(predicate::str::contains("2: deadline-task-4"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -2028,30 +2066,40 @@ mod deadline_done_cleardone {
 
     #[test]
     fn cleardone_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleardone_no_args_allowed");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-cleardone").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn cleardone_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleardone_abrev_no_args_allowed");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-cd").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn cleardone_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleardone_empty_done");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2065,20 +2113,24 @@ mod deadline_done_cleardone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-cleardone");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline done list is currently empty.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn cleardone_abrev_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleardone_abrev_empty_done");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2092,20 +2144,24 @@ mod deadline_done_cleardone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-cd");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline done list is currently empty.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn cleardone_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleardone_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2130,10 +2186,10 @@ mod deadline_done_cleardone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-cleardone");
         assert!(cmd
             .assert()
@@ -2141,11 +2197,15 @@ mod deadline_done_cleardone {
             .try_stdout(predicate::str::contains("1: deadline-task-hello"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn cleardone_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleardone_abrev_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2170,10 +2230,10 @@ mod deadline_done_cleardone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-cd");
         assert!(cmd
             .assert()
@@ -2181,6 +2241,8 @@ mod deadline_done_cleardone {
             .try_stdout(predicate::str::contains("1: deadline-task-hello"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -2190,30 +2252,40 @@ mod deadline_done_notdoneall {
 
     #[test]
     fn notdoneall_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdoneall_no_args_allowed");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-notdoneall").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn notdoneall_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdoneall_abrev_no_args_allowed");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-nda").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn notdoneall_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdoneall_empty_done");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2227,20 +2299,24 @@ mod deadline_done_notdoneall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-notdoneall");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline done list is currently empty.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdoneall_abrev_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdoneall_abrev_empty_done");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2254,20 +2330,24 @@ mod deadline_done_notdoneall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-nda");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline done list is currently empty.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdoneall_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdoneall_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2312,23 +2392,27 @@ mod deadline_done_notdoneall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-rmd").arg("1");
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-notdoneall");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: deadline-task-3"))
             .stdout(predicate::str::contains("2: deadline-task-2"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdoneall_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdoneall_abrev_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2373,36 +2457,20 @@ mod deadline_done_notdoneall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-rmd").arg("1");
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-nda");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: deadline-task-3"))
             .stdout(predicate::str::contains("2: deadline-task-2"));
 
-        Ok(())
-    }
-}
-
-mod zzz_do_this_last {
-    use super::*;
+        std::fs::remove_dir_all(&data_dir)?;
 
-    #[test]
-    fn zzzz_rename_deadline_copy_to_original() {
-        // name is zzzz so it's done last
-        // now that tests are done, remove the modified original and rename copy to original
-
-        std::fs::remove_file(path_to_deadline_tasks())
-            .context("failed delete modified deadline_tasks.json after running tests")
-            .expect("failed to delete deadline_tasks.json after deadline_helpers unit tests");
-
-        std::fs::rename(deadline_tasks_copy_path(), path_to_deadline_tasks())
-            .context("failed to rename deadline_tasks_copy to deadline_tasks")
-            .expect("failed to rename deadline_tasks_copy to deadline_tasks after tests were done");
+        Ok(())
     }
 }
@@ -1,136 +1,56 @@
 use anyhow::Context;
 use assert_cmd::prelude::*;
-use chartodo::functions::{deadline_tasks::deadline_helpers::*, json_file_structs::*};
+use chartodo::functions::{json_file_structs::*, task_store::TaskList};
 use predicates::prelude::*;
-use std::{path::PathBuf, process::Command};
 
-// cargo test --test deadline_todo_tests -- --test-threads=1
+mod common;
+use common::*;
 
-fn path_to_deadline_tasks() -> PathBuf {
-    // get the data dir XDG spec and return it with path to deadline_tasks.json
-    let mut deadline_tasks_path = dirs::data_dir()
-        .context(
-            "linux: couldn't get $HOME/.local/share/
-                windows: couldn't get C:/Users/your_user/AppData/Local/
-                mac: couldn't get /Users/your_user/Library/Application Support/
-
-                those directories should exist for your OS. please double check that they do.",
-        )
-        .expect("something went wrong with fetching the user's data dirs");
-    deadline_tasks_path.push("chartodo/deadline_tasks.json");
-
-    deadline_tasks_path
-}
-
-fn deadline_tasks_copy_path() -> PathBuf {
-    // get the path for deadline_tasks_copy.json, which will be used to hold the original contents
-    // of deadline_tasks.json while it's getting modified
-    let mut deadline_tasks_copy_path = dirs::data_dir()
-        .context(
-            "linux: couldn't get $HOME/.local/share/
-                windows: couldn't get C:/Users/your_user/AppData/Local/
-                mac: couldn't get /Users/your_user/Library/Application Support/
-
-                those directories should exist for your OS. please double check that they do.",
-        )
-        .expect("something went wrong with fetching the user's data dirs");
-    deadline_tasks_copy_path.push("chartodo/deadline_tasks_copy.json");
-
-    deadline_tasks_copy_path
-}
-
-mod aaa_do_this_first {
-    use super::*;
-
-    // these have been tested in other fns, these are just included here as a sanity check
-    #[test]
-    fn deadline_tasks_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/deadline_tasks.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/deadline_tasks.json";
-        let mac_path = "/Library/Application Support/chartodo/deadline_tasks.json";
-        let mut got_deadline_tasks_path: bool = false;
-        let deadline_path = path_to_deadline_tasks();
-        let deadline_path = deadline_path.to_str().unwrap();
-
-        if deadline_path.contains(linux_path)
-            | deadline_path.contains(windows_path)
-            | deadline_path.contains(mac_path)
-        {
-            got_deadline_tasks_path = true;
-        }
-
-        assert!(got_deadline_tasks_path);
-    }
-
-    #[test]
-    fn deadline_tasks_copy_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/deadline_tasks_copy.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/deadline_tasks_copy.json";
-        let mac_path = "/Library/Application Support/chartodo/deadline_tasks_copy.json";
-        let mut got_deadline_tasks_copy_path: bool = false;
-        let deadline_tasks_copy_path = deadline_tasks_copy_path();
-        let deadline_tasks_copy_path = deadline_tasks_copy_path.to_str().unwrap();
-
-        if deadline_tasks_copy_path.contains(linux_path)
-            | deadline_tasks_copy_path.contains(windows_path)
-            | deadline_tasks_copy_path.contains(mac_path)
-        {
-            got_deadline_tasks_copy_path = true;
-        }
-
-        assert!(got_deadline_tasks_copy_path);
-    }
-
-    #[test]
-    fn aaaa_deadline_tasks_clone_file() {
-        // name is aaaa so it's done first
-        // since we will be modifying the original file to run a test, the original data must be
-        // preserved first
-        std::fs::File::create(deadline_tasks_copy_path())
-            .context("failed to create deadline_tasks_copy.json")
-            .expect("failed to create a copy during unit test");
-
-        std::fs::copy(path_to_deadline_tasks(), deadline_tasks_copy_path())
-            .context("failed to copy deadline_tasks.json to deadline_tasks_copy.json")
-            .expect("failed to copy original file to copy file during unit test");
-    }
-}
+// cargo test --test deadline_todo_tests
 
 mod deadline_todo_add {
     use super::*;
 
     #[test]
     fn adding_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("adding_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-add");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide a deadline add argument",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn adding_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("adding_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-a");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide a deadline add argument",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn adding_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("adding_wrong_num_of_args");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-add").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: You don't \
             have the right amount of arguments when adding a deadline task.\n\tThere should \
             be 3, 6, 9, etc. (i.e., divisible by 3) arguments after 'chartodo deadline-add'. \
@@ -140,15 +60,19 @@ mod deadline_todo_add {
             chartodo dl-a new-item 2099-01-01 00:00 another-item 2199-01-01 23:59",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn adding_abrev_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("adding_abrev_wrong_num_of_args");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-a").arg("1").arg("2").arg("3").arg("4");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: You don't \
             have the right amount of arguments when adding a deadline task.\n\tThere should \
             be 3, 6, 9, etc. (i.e., divisible by 3) arguments after 'chartodo deadline-add'. \
@@ -158,30 +82,38 @@ mod deadline_todo_add {
             chartodo dl-a new-item 2099-01-01 00:00 another-item 2199-01-01 23:59",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn adding_incorrect_time() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("adding_incorrect_time");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-add")
             .arg("deadline-task")
             .arg("2020-01-01")
             .arg("25:28");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your \
             specified time for a new deadline task in argument set 1, '25:28', was invalid. \
             Please provide a correct time in a 24-hour format, e.g. 20:05.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn adding_abrev_incorrect_time() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("adding_abrev_incorrect_time");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-a")
             .arg("deadline-task")
             .arg("2020-01-01")
@@ -189,37 +121,45 @@ mod deadline_todo_add {
             .arg("deadline-task-2")
             .arg("2020-01-01")
             .arg("25:28");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your \
             specified time for a new deadline task in argument set 2, '25:28', was invalid. \
             Please provide a correct time in a 24-hour format, e.g. 20:05.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn adding_incorrect_date() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("adding_incorrect_date");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-add")
             .arg("deadline-task")
             .arg("2020-13-01")
             .arg("13:28");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your \
             specified date for a new deadline task in argument set 1, '2020-13-01', \
             was invalid. Please provide a correct time in a year-month-day format, e.g. \
             2099-12-12.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn adding_abrev_incorrect_date() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("adding_abrev_incorrect_date");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-a")
             .arg("deadline-task")
             .arg("2020-12-01")
@@ -227,18 +167,22 @@ mod deadline_todo_add {
             .arg("deadline-task-2")
             .arg("2020-13-01")
             .arg("13:28");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your \
             specified date for a new deadline task in argument set 2, '2020-13-01', \
             was invalid. Please provide a correct time in a year-month-day format, e.g. \
             2099-12-12.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn adding_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("adding_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -252,10 +196,10 @@ mod deadline_todo_add {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-add")
             .arg("deadline-task")
             .arg("2020-01-01")
@@ -265,11 +209,15 @@ mod deadline_todo_add {
             .stdout(predicate::str::contains("1: deadline-task"))
             .stdout(predicate::str::contains("MISSED: 2020-01-01 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn adding_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("adding_abrev_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -283,10 +231,10 @@ mod deadline_todo_add {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-a")
             .arg("deadline-task")
             .arg("2099-12-13")
@@ -296,11 +244,15 @@ mod deadline_todo_add {
             .stdout(predicate::str::contains("1: deadline-task"))
             .stdout(predicate::str::contains("due: 2099-12-13 14:37"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn adding_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("adding_multiple_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -314,10 +266,10 @@ mod deadline_todo_add {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-add")
             .arg("deadline-task")
             .arg("2020-01-01")
@@ -332,11 +284,15 @@ mod deadline_todo_add {
             .stdout(predicate::str::contains("2: deadline-task-2"))
             .stdout(predicate::str::contains("due: 2099-12-13 12:01"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn adding_abrev_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("adding_abrev_multiple_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -350,10 +306,10 @@ mod deadline_todo_add {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-a")
             .arg("deadline-task")
             .arg("2099-12-13")
@@ -368,6 +324,8 @@ mod deadline_todo_add {
             .stdout(predicate::str::contains("2: deadline-task"))
             .stdout(predicate::str::contains("due: 2099-12-13 14:37"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -377,34 +335,44 @@ mod deadline_todo_add_no_time {
 
     #[test]
     fn add_no_time_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_no_time_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-addonlydate");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide a deadline-addonlydate argument",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_no_time_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_no_time_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-aod");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide a deadline-addonlydate argument",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_no_time_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_no_time_wrong_num_of_args");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-addonlydate").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: You \
             don't have the right amount of arguments when adding a deadline task w/ \
             no time.\n\tThere should be 2, 4, 6, etc. (i.e., divisible by 2) arguments \
@@ -415,15 +383,19 @@ mod deadline_todo_add_no_time {
             2199-01-01",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_no_time_abrev_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_no_time_abrev_wrong_num_of_args");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-aod").arg("1").arg("2").arg("3");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: You \
             don't have the right amount of arguments when adding a deadline task w/ \
             no time.\n\tThere should be 2, 4, 6, etc. (i.e., divisible by 2) arguments \
@@ -434,45 +406,57 @@ mod deadline_todo_add_no_time {
             2199-01-01",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_no_time_incorrect_date() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_no_time_incorrect_date");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-addonlydate")
             .arg("deadline-task")
             .arg("2020-13-01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your \
             specified date in argument set 1, '2020-13-01', was invalid. Please provide a \
             correct time in a year-month-day format, e.g. 2099-12-12.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_no_time_abrev_incorrect_date() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_no_time_abrev_incorrect_date");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-aod")
             .arg("deadline-task")
             .arg("2020-12-01")
             .arg("deadline-task-2")
             .arg("2020-13-01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your \
             specified date in argument set 2, '2020-13-01', was invalid. Please provide a \
             correct time in a year-month-day format, e.g. 2099-12-12.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_no_time_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_no_time_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -486,10 +470,10 @@ mod deadline_todo_add_no_time {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-addonlydate")
             .arg("deadline-task")
             .arg("2020-01-01");
@@ -498,11 +482,15 @@ mod deadline_todo_add_no_time {
             .stdout(predicate::str::contains("1: deadline-task"))
             .stdout(predicate::str::contains("MISSED: 2020-01-01 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_no_time_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_no_time_abrev_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -516,21 +504,25 @@ mod deadline_todo_add_no_time {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-aod").arg("deadline-task").arg("2099-12-13");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: deadline-task"))
             .stdout(predicate::str::contains("due: 2099-12-13 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_no_time_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_no_time_multiple_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -544,10 +536,10 @@ mod deadline_todo_add_no_time {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-addonlydate")
             .arg("deadline-task")
             .arg("2020-01-01")
@@ -560,11 +552,15 @@ mod deadline_todo_add_no_time {
             .stdout(predicate::str::contains("2: deadline-task-2"))
             .stdout(predicate::str::contains("due: 2099-12-13 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_no_time_abrev_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_no_time_abrev_multiple_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -578,10 +574,10 @@ mod deadline_todo_add_no_time {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-aod")
             .arg("deadline-task")
             .arg("2099-12-13")
@@ -594,6 +590,8 @@ mod deadline_todo_add_no_time {
             .stdout(predicate::str::contains("2: deadline-task"))
             .stdout(predicate::str::contains("due: 2099-12-13 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -603,34 +601,44 @@ mod deadline_todo_add_no_date {
 
     #[test]
     fn add_no_date_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_no_date_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-addonlytime");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide a deadline-addonlytime argument",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_no_date_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_no_date_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-aot");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide a deadline-addonlytime argument",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_no_date_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_no_date_wrong_num_of_args");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-addonlytime").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: You \
             don't have the right amount of arguments when adding a deadline task w/ no \
             time.\n\tThere should be 2, 4, 6, etc. (i.e., divisible by 2) arguments after \
@@ -640,15 +648,19 @@ mod deadline_todo_add_no_date {
             00:00\n\tAnother example: chartodo dl-aot new-item 23:59 another-item 23:59",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_no_date_abrev_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_no_date_abrev_wrong_num_of_args");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-aot").arg("1").arg("2").arg("3");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: You \
             don't have the right amount of arguments when adding a deadline task w/ no \
             time.\n\tThere should be 2, 4, 6, etc. (i.e., divisible by 2) arguments after \
@@ -658,46 +670,58 @@ mod deadline_todo_add_no_date {
             00:00\n\tAnother example: chartodo dl-aot new-item 23:59 another-item 23:59",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_no_date_incorrect_time() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_no_date_incorrect_time");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-addonlytime")
             .arg("deadline-task")
             .arg("25:28");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your \
             specified time for a new deadline task in argument set 1, '25:28', was invalid. \
             Please provide a correct time in a 24-hour format, e.g. 20:05.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_no_date_abrev_incorrect_time() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_no_date_abrev_incorrect_time");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-aot")
             .arg("deadline-task")
             .arg("13:28")
             .arg("deadline-task-2")
             .arg("25:28");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your \
             specified time for a new deadline task in argument set 2, '25:28', was invalid. \
             Please provide a correct time in a 24-hour format, e.g. 20:05.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     // i think it's possible to check the date and some other elements
     #[test]
     fn add_no_date_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_no_date_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -711,10 +735,10 @@ mod deadline_todo_add_no_date {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-addonlytime")
             .arg("deadline-task")
             .arg("00:00");
@@ -723,11 +747,15 @@ mod deadline_todo_add_no_date {
             .stdout(predicate::str::contains("1: deadline-task"))
             .stdout(predicate::str::contains("00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_no_date_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_no_date_abrev_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -741,21 +769,25 @@ mod deadline_todo_add_no_date {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-aot").arg("deadline-task").arg("14:37");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: deadline-task"))
             .stdout(predicate::str::contains("14:37"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_no_date_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_no_date_multiple_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -769,10 +801,10 @@ mod deadline_todo_add_no_date {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-addonlytime")
             .arg("deadline-task")
             .arg("00:00")
@@ -785,11 +817,15 @@ mod deadline_todo_add_no_date {
             .stdout(predicate::str::contains("2: deadline-task-2"))
             .stdout(predicate::str::contains("12:01"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_no_date_abrev_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_no_date_abrev_multiple_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -803,10 +839,10 @@ mod deadline_todo_add_no_date {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-aot")
             .arg("deadline-task")
             .arg("14:37")
@@ -819,6 +855,8 @@ mod deadline_todo_add_no_date {
             .stdout(predicate::str::contains("2: deadline-task"))
             .stdout(predicate::str::contains("14:37"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -828,30 +866,40 @@ mod deadline_todo_done {
 
     #[test]
     fn done_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-done");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide a deadline-done argument",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-d");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide a deadline-done argument",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_empty_todo");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -865,21 +913,25 @@ mod deadline_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-done").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline todo list is currently empty. Try adding items \
             to it first.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_abrev_empty_todo");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -893,21 +945,25 @@ mod deadline_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-d").arg("1").arg("1").arg("2");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline todo list is currently empty. Try adding items \
             to it first.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_no_valid_args");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -932,22 +988,26 @@ mod deadline_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-done").arg("0").arg("a").arg("2").arg("");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, or exceeded the deadline todo list's \
             length.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_abrev_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_abrev_no_valid_args");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -972,22 +1032,26 @@ mod deadline_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-d").arg("0").arg("a").arg("2").arg("");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, or exceeded the deadline todo list's \
             length.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_should_do_deadline_doneall() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_should_do_deadline_doneall");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1062,10 +1126,10 @@ mod deadline_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-done")
             .arg("1")
             .arg("1")
@@ -1074,16 +1138,20 @@ mod deadline_todo_done {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified the entire list. Might as well do \
             chartodo deadline-doneall",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_should_do_deadline_doneall_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_should_do_deadline_doneall_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1158,21 +1226,25 @@ mod deadline_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-done").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified the entire list. Might as well do \
             chartodo deadline-doneall",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_abrev_should_do_deadline_doneall() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_abrev_should_do_deadline_doneall");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1247,10 +1319,10 @@ mod deadline_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-d")
             .arg("1")
             .arg("1")
@@ -1259,16 +1331,20 @@ mod deadline_todo_done {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified the entire list. Might as well do \
             chartodo deadline-doneall",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_abrev_should_do_deadline_doneall_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_abrev_should_do_deadline_doneall_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1343,21 +1419,25 @@ mod deadline_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-d").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified the entire list. Might as well do \
             chartodo deadline-doneall",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1392,21 +1472,25 @@ mod deadline_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-done").arg("1").arg("1");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: deadline-task"))
             .stdout(predicate::str::contains("1: deadline-task-2"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_abrev_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1441,21 +1525,25 @@ mod deadline_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-d").arg("1").arg("1");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: deadline-task"))
             .stdout(predicate::str::contains("1: deadline-task-2"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_multiple_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1520,10 +1608,10 @@ mod deadline_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-done").arg("1").arg("1").arg("3").arg("5");
         cmd.assert()
             .success()
@@ -1533,11 +1621,15 @@ mod deadline_todo_done {
             .stdout(predicate::str::contains("1: deadline-task-4"))
             .stdout(predicate::str::contains("2: deadline-task-2"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_multiple_is_correct_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_multiple_is_correct_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1602,10 +1694,10 @@ mod deadline_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-done").arg("1-3");
         cmd.assert()
             .success()
@@ -1615,11 +1707,15 @@ mod deadline_todo_done {
             .stdout(predicate::str::contains("2: deadline-task-4"))
             .stdout(predicate::str::contains("3: deadline-task-3"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_abrev_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_abrev_multiple_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1684,10 +1780,10 @@ mod deadline_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-d").arg("1").arg("1").arg("3").arg("5");
         cmd.assert()
             .success()
@@ -1697,11 +1793,15 @@ mod deadline_todo_done {
             .stdout(predicate::str::contains("1: deadline-task-4"))
             .stdout(predicate::str::contains("2: deadline-task-2"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_abrev_multiple_is_correct_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_abrev_multiple_is_correct_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1766,10 +1866,10 @@ mod deadline_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-d").arg("1").arg("1-3");
         cmd.assert()
             .success()
@@ -1779,6 +1879,8 @@ mod deadline_todo_done {
             .stdout(predicate::str::contains("2: deadline-task-4"))
             .stdout(predicate::str::contains("3: deadline-task-3"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -1788,30 +1890,40 @@ mod deadline_todo_rmtodo {
 
     #[test]
     fn rmtodo_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-rmtodo");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide a deadline-rmtodo argument",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-rmt");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide a deadline-rmtodo argument",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_empty_todo");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1825,21 +1937,25 @@ mod deadline_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-rmtodo").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline todo list is currently empty. Try adding items \
             to it first.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_abrev_empty_todo");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1853,21 +1969,25 @@ mod deadline_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-rmt").arg("1").arg("1").arg("2");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline todo list is currently empty. Try adding items \
             to it first.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_no_valid_args");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1892,26 +2012,30 @@ mod deadline_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-rmtodo")
             .arg("0")
             .arg("a")
             .arg("2")
             .arg("");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, or exceeded the deadline todo list's \
             length.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_abrev_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_abrev_no_valid_args");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1936,22 +2060,26 @@ mod deadline_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-rmt").arg("0").arg("a").arg("2").arg("");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, or exceeded the deadline todo list's \
             length.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_should_do_deadline_cleartodo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_should_do_deadline_cleartodo");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2026,10 +2154,10 @@ mod deadline_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-rmtodo")
             .arg("1")
             .arg("1")
@@ -2038,16 +2166,20 @@ mod deadline_todo_rmtodo {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You might as well do deadline-cleartodo since you want to \
             remove all of the items.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_should_do_deadline_cleartodo_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_should_do_deadline_cleartodo_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2122,21 +2254,25 @@ mod deadline_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-rmtodo").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You might as well do deadline-cleartodo since you want to \
             remove all of the items.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_abrev_should_do_deadline_cleartodo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_abrev_should_do_deadline_cleartodo");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2211,10 +2347,10 @@ mod deadline_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-rmt")
             .arg("1")
             .arg("1")
@@ -2223,16 +2359,20 @@ mod deadline_todo_rmtodo {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You might as well do deadline-cleartodo since you want to \
             remove all of the items.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_abrev_should_do_deadline_cleartodo_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_abrev_should_do_deadline_cleartodo_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2307,21 +2447,25 @@ mod deadline_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-rmt").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You might as well do deadline-cleartodo since you want to \
             remove all of the items.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2356,10 +2500,10 @@ mod deadline_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-rmtodo").arg("1").arg("1");
         assert!(cmd
             .assert()
@@ -2367,11 +2511,15 @@ mod deadline_todo_rmtodo {
             .try_stdout(predicate::str::contains("1: deadline-task-hello"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_abrev_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2406,10 +2554,10 @@ mod deadline_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-rmt").arg("1").arg("1");
         assert!(cmd
             .assert()
@@ -2417,11 +2565,15 @@ mod deadline_todo_rmtodo {
             .try_stdout(predicate::str::contains("1: deadline-task-hello"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_multiple_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2486,10 +2638,10 @@ mod deadline_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-rmtodo")
             .arg("1")
             .arg("1")
@@ -2500,11 +2652,15 @@ mod deadline_todo_rmtodo {
             .stdout(predicate::str::contains("1: deadline-task-4"))
             .stdout(predicate::str::contains("2: deadline-task-2"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_multiple_is_correct_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_multiple_is_correct_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2569,21 +2725,25 @@ mod deadline_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-rmtodo").arg("1-3");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: deadline-task-2"))
             .stdout(predicate::str::contains("2: deadline-task"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_abrev_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_abrev_multiple_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2648,21 +2808,25 @@ mod deadline_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-rmt").arg("1").arg("1").arg("3").arg("5");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: deadline-task-4"))
             .stdout(predicate::str::contains("2: deadline-task-2"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_abrev_multiple_is_correct_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_abrev_multiple_is_correct_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2727,16 +2891,18 @@ mod deadline_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-rmt").arg("1-3");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: deadline-task-2"))
             .stdout(predicate::str::contains("2: deadline-task"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -2746,30 +2912,40 @@ mod deadline_todo_cleartodo {
 
     #[test]
     fn cleartodo_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleartodo_no_args_allowed");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-cleartodo").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn cleartodo_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleartodo_abrev_no_args_allowed");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-ct").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn cleartodo_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleartodo_empty_todo");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2783,21 +2959,25 @@ mod deadline_todo_cleartodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-cleartodo");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline todo list is currently empty. Try adding items \
             to it first before removing any.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn cleartodo_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleartodo_abrev_empty_todo");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2811,21 +2991,25 @@ mod deadline_todo_cleartodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-ct");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline todo list is currently empty. Try adding items \
             to it first before removing any.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn cleartodo_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleartodo_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2850,10 +3034,10 @@ mod deadline_todo_cleartodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-cleartodo");
         assert!(cmd
             .assert()
@@ -2861,11 +3045,15 @@ mod deadline_todo_cleartodo {
             .try_stdout(predicate::str::contains("1: deadline-task-hello"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn cleartodo_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleartodo_abrev_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2890,10 +3078,10 @@ mod deadline_todo_cleartodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-ct");
         assert!(cmd
             .assert()
@@ -2901,6 +3089,8 @@ mod deadline_todo_cleartodo {
             .try_stdout(predicate::str::contains("1: deadline-task-hello"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -2910,30 +3100,40 @@ mod deadline_todo_doneall {
 
     #[test]
     fn doneall_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("doneall_no_args_allowed");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-doneall").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn doneall_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("doneall_abrev_no_args_allowed");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-da").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn doneall_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("doneall_empty_todo");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2947,21 +3147,25 @@ mod deadline_todo_doneall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-doneall");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline todo list is currently empty, so you can't \
             change any todos to done.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn doneall_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("doneall_abrev_empty_todo");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -2975,21 +3179,25 @@ mod deadline_todo_doneall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-da");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline todo list is currently empty, so you can't \
             change any todos to done.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn doneall_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("doneall_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -3024,21 +3232,25 @@ mod deadline_todo_doneall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-doneall");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: deadline-task-2"))
             .stdout(predicate::str::contains("2: deadline-task-1"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn doneall_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("doneall_abrev_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -3073,16 +3285,18 @@ mod deadline_todo_doneall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-da");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: deadline-task-2"))
             .stdout(predicate::str::contains("2: deadline-task-1"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -3092,30 +3306,40 @@ mod deadline_todo_edit_all {
 
     #[test]
     fn editall_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-editall");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for deadline-editall",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-ea");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for deadline-editall",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_empty_todo");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -3129,21 +3353,29 @@ mod deadline_todo_edit_all {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("deadline-editall").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("deadline-editall")
+            .arg("1")
+            .arg("new-task")
+            .arg("2099-01-01")
+            .arg("00:00");
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_abrev_empty_todo");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -3157,21 +3389,29 @@ mod deadline_todo_edit_all {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("dl-ea").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("dl-ea")
+            .arg("1")
+            .arg("new-task")
+            .arg("2099-01-01")
+            .arg("00:00");
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_wrong_num_of_args");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -3196,25 +3436,24 @@ mod deadline_todo_edit_all {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-editall").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: You \
-            must specify the deadline todo's position and all the parameters that will be \
-            edited.\n\tThere should be 4 arguments after 'chartodo deadline-editall'. You \
-            provided 1 argument(s).\n\tFormat: chartodo deadline-editall ~position ~task \
-            ~date ~time\n\t\tDate must be in a yy-mm-dd format. Time must be in a 24-hour \
-            format.\n\tExample: chartodo dl-ea 4 new-item 2150-01-01 00:00",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_abrev_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_abrev_wrong_num_of_args");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -3239,25 +3478,24 @@ mod deadline_todo_edit_all {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-ea").arg("1").arg("2").arg("3");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: You \
-            must specify the deadline todo's position and all the parameters that will be \
-            edited.\n\tThere should be 4 arguments after 'chartodo deadline-editall'. You \
-            provided 3 argument(s).\n\tFormat: chartodo deadline-editall ~position ~task \
-            ~date ~time\n\t\tDate must be in a yy-mm-dd format. Time must be in a 24-hour \
-            format.\n\tExample: chartodo dl-ea 4 new-item 2150-01-01 00:00",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_position_not_usize");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -3282,24 +3520,28 @@ mod deadline_todo_edit_all {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-editall")
             .arg("a")
             .arg("edited-task")
             .arg("2099-12-13")
             .arg("24:37");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: 'a' isn't a valid position. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, 'a', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_abrev_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_abrev_position_not_usize");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -3324,24 +3566,28 @@ mod deadline_todo_edit_all {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-ea")
             .arg("a")
             .arg("edited-task")
             .arg("2099-12-13")
             .arg("24:37");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: 'a' isn't a valid position. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, 'a', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_position_is_zero");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -3366,24 +3612,28 @@ mod deadline_todo_edit_all {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-editall")
             .arg("0")
             .arg("edited-task")
             .arg("2099-12-13")
             .arg("24:37");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_abrev_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_abrev_position_is_zero");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -3408,24 +3658,28 @@ mod deadline_todo_edit_all {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-ea")
             .arg("0")
             .arg("edited-task")
             .arg("2099-12-13")
             .arg("24:37");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_position_not_in_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -3450,25 +3704,28 @@ mod deadline_todo_edit_all {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-editall")
             .arg("2")
             .arg("edited-task")
             .arg("2099-12-13")
             .arg("24:37");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Your position, '2', exceed's the todo list's length. Try \
-            something between 1 and 1",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_abrev_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_abrev_position_not_in_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -3493,25 +3750,28 @@ mod deadline_todo_edit_all {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-ea")
             .arg("2")
             .arg("edited-task")
             .arg("2099-12-13")
             .arg("24:37");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Your position, '2', exceed's the todo list's length. Try \
-            something between 1 and 1",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_date_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_date_is_wrong");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -3536,25 +3796,29 @@ mod deadline_todo_edit_all {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-editall")
             .arg("1")
             .arg("edited-task")
             .arg("2099-13-13")
             .arg("24:37");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The date provided, '2099-13-13', isn't proper. It must be in \
             a yy-mm-dd format, e.g., 2001-12-13",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_abrev_date_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_abrev_date_is_wrong");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -3579,25 +3843,29 @@ mod deadline_todo_edit_all {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-ea")
             .arg("1")
             .arg("edited-task")
             .arg("2099-13-13")
             .arg("24:37");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The date provided, '2099-13-13', isn't proper. It must be in \
             a yy-mm-dd format, e.g., 2001-12-13",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_time_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_time_is_wrong");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -3622,25 +3890,29 @@ mod deadline_todo_edit_all {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-editall")
             .arg("1")
             .arg("edited-task")
             .arg("2099-12-13")
             .arg("24:37");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The time provided, '24:37', isn't proper. It must be in a \
             24-hour format, e.g., 23:08",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_abrev_time_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_abrev_time_is_wrong");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -3665,25 +3937,29 @@ mod deadline_todo_edit_all {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-ea")
             .arg("1")
             .arg("edited-task")
             .arg("2099-12-13")
             .arg("24:37");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The time provided, '24:37', isn't proper. It must be in a \
             24-hour format, e.g., 23:08",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -3708,10 +3984,10 @@ mod deadline_todo_edit_all {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-editall")
             .arg("1")
             .arg("edited-task")
@@ -3722,11 +3998,15 @@ mod deadline_todo_edit_all {
             .stdout(predicate::str::contains("1: edited-task"))
             .stdout(predicate::str::contains("due: 2099-12-13 14:37"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_abrev_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -3751,10 +4031,10 @@ mod deadline_todo_edit_all {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-ea")
             .arg("1")
             .arg("edited-task")
@@ -3765,6 +4045,8 @@ mod deadline_todo_edit_all {
             .stdout(predicate::str::contains("1: edited-task"))
             .stdout(predicate::str::contains("due: 2099-12-13 14:37"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -3774,30 +4056,40 @@ mod deadline_todo_edit_task {
 
     #[test]
     fn edittask_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-edittask");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for deadline-edittask",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-eta");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for deadline-edittask",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_empty_todo");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -3811,21 +4103,25 @@ mod deadline_todo_edit_task {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("deadline-edittask").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("deadline-edittask").arg("1").arg("new-task");
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_abrev_empty_todo");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -3839,21 +4135,25 @@ mod deadline_todo_edit_task {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("dl-eta").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("dl-eta").arg("1").arg("new-task");
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_wrong_num_of_args");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -3878,24 +4178,24 @@ mod deadline_todo_edit_task {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-edittask").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: You must \
-            specify the deadline todo's position that will be edited and what to edit the \
-            task to.\n\tThere should be 2 arguments after 'chartodo deadline-edittask'. \
-            You provided 1 argument(s).\n\tFormat: chartodo deadline-edittask ~position \
-            ~task.\n\tExample: chartodo dl-eta 4 new-item",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_abrev_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_abrev_wrong_num_of_args");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -3920,24 +4220,24 @@ mod deadline_todo_edit_task {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-eta").arg("1").arg("2").arg("3");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: You must \
-            specify the deadline todo's position that will be edited and what to edit the \
-            task to.\n\tThere should be 2 arguments after 'chartodo deadline-edittask'. \
-            You provided 3 argument(s).\n\tFormat: chartodo deadline-edittask ~position \
-            ~task.\n\tExample: chartodo dl-eta 4 new-item",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn edittask_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_position_not_usize");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -3962,20 +4262,24 @@ mod deadline_todo_edit_task {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-edittask").arg("a").arg("edited-task");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: 'a' isn't a valid position. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, 'a', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_abrev_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_abrev_position_not_usize");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4000,20 +4304,24 @@ mod deadline_todo_edit_task {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-eta").arg("a").arg("edited-task");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: 'a' isn't a valid position. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, 'a', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_position_is_zero");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4038,20 +4346,24 @@ mod deadline_todo_edit_task {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-edittask").arg("0").arg("edited-task");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_abrev_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_abrev_position_is_zero");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4076,20 +4388,24 @@ mod deadline_todo_edit_task {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-eta").arg("0").arg("edited-task");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_position_not_in_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4114,21 +4430,24 @@ mod deadline_todo_edit_task {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-edittask").arg("2").arg("edited-task");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Your position, '2', exceed's the todo list's length. Try \
-            something between 1 and 1",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_abrev_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_abrev_position_not_in_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4153,21 +4472,24 @@ mod deadline_todo_edit_task {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-eta").arg("2").arg("edited-task");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Your position, '2', exceed's the todo list's length. Try \
-            something between 1 and 1",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4192,20 +4514,24 @@ mod deadline_todo_edit_task {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-edittask").arg("1").arg("edited-task");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: edited-task"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_abrev_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4230,15 +4556,17 @@ mod deadline_todo_edit_task {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-eta").arg("1").arg("edited-task");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: edited-task"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -4248,30 +4576,40 @@ mod deadline_todo_edit_date {
 
     #[test]
     fn editdate_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdate_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-editdate");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for deadline-editdate",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdate_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdate_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-ed");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for deadline-editdate",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdate_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdate_empty_todo");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4285,21 +4623,25 @@ mod deadline_todo_edit_date {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("deadline-editdate").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("deadline-editdate").arg("1").arg("2099-01-01");
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdate_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdate_abrev_empty_todo");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4313,21 +4655,25 @@ mod deadline_todo_edit_date {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("dl-ed").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("dl-ed").arg("1").arg("2099-01-01");
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdate_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdate_wrong_num_of_args");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4352,25 +4698,24 @@ mod deadline_todo_edit_date {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-editdate").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: You must \
-            specify the deadline todo's position that will be edited and what to edit the \
-            date to.\n\tThere should be two arguments after 'chartodo deadline-editdate'. \
-            You provided 1 argument(s).\n\tFormat: chartodo deadline-editdate ~position \
-            ~date.\n\t\tDate must be in a yy-mm-dd format.\n\tExample: chartodo dl-ed \
-            4 2150-01-01",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdate_abrev_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdate_abrev_wrong_num_of_args");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4395,25 +4740,24 @@ mod deadline_todo_edit_date {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-ed").arg("1").arg("2").arg("3");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: You must \
-            specify the deadline todo's position that will be edited and what to edit the \
-            date to.\n\tThere should be two arguments after 'chartodo deadline-editdate'. \
-            You provided 3 argument(s).\n\tFormat: chartodo deadline-editdate ~position \
-            ~date.\n\t\tDate must be in a yy-mm-dd format.\n\tExample: chartodo dl-ed \
-            4 2150-01-01",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn editdate_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdate_position_not_usize");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4438,20 +4782,24 @@ mod deadline_todo_edit_date {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-editdate").arg("a").arg("edited-task");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: 'a' isn't a valid position. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, 'a', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdate_abrev_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdate_abrev_position_not_usize");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4476,20 +4824,24 @@ mod deadline_todo_edit_date {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-ed").arg("a").arg("edited-task");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: 'a' isn't a valid position. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, 'a', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdate_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdate_position_is_zero");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4514,20 +4866,24 @@ mod deadline_todo_edit_date {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-editdate").arg("0").arg("edited-task");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdate_abrev_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdate_abrev_position_is_zero");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4552,20 +4908,24 @@ mod deadline_todo_edit_date {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-ed").arg("0").arg("edited-task");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdate_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdate_position_not_in_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4590,21 +4950,24 @@ mod deadline_todo_edit_date {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-editdate").arg("2").arg("edited-task");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Your position, '2', exceeds the todo list's length. Try \
-            something between 1 and 1",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdate_abrev_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdate_abrev_position_not_in_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4629,21 +4992,24 @@ mod deadline_todo_edit_date {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-ed").arg("2").arg("edited-task");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Your position, '2', exceeds the todo list's length. Try \
-            something between 1 and 1",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdate_date_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdate_date_is_wrong");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4668,21 +5034,25 @@ mod deadline_todo_edit_date {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-editdate").arg("1").arg("2099-13-13");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The date provided, '2099-13-13', isn't proper. It must be in \
             a yy-mm-dd format, e.g., 2021-12-24.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdate_abrev_date_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdate_abrev_date_is_wrong");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4707,21 +5077,25 @@ mod deadline_todo_edit_date {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-ed").arg("1").arg("2099-13-13");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The date provided, '2099-13-13', isn't proper. It must be in \
             a yy-mm-dd format, e.g., 2021-12-24.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdate_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdate_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4746,20 +5120,24 @@ mod deadline_todo_edit_date {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-editdate").arg("1").arg("2099-12-13");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("due: 2099-12-13"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdate_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdate_abrev_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4784,15 +5162,17 @@ mod deadline_todo_edit_date {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-ed").arg("1").arg("2099-12-13");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("due: 2099-12-13"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -4802,30 +5182,40 @@ mod deadline_todo_edit_time {
 
     #[test]
     fn edittime_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittime_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-edittime");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for deadline-edittime",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittime_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittime_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-eti");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for deadline-edittime",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittime_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittime_empty_todo");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4839,21 +5229,25 @@ mod deadline_todo_edit_time {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("deadline-edittime").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("deadline-edittime").arg("1").arg("00:00");
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittime_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittime_abrev_empty_todo");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4867,21 +5261,25 @@ mod deadline_todo_edit_time {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("dl-eti").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("dl-eti").arg("1").arg("00:00");
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittime_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittime_wrong_num_of_args");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4906,25 +5304,24 @@ mod deadline_todo_edit_time {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-edittime").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: You must \
-            specify the deadline todo's position that will be edited and what to edit the \
-            time to.\n\tThere should be 2 arguments after 'chartodo deadline-edittime'. \
-            You provided 1 argument(s).\n\tFormat: chartodo deadline-edittime ~position \
-            ~time.\n\t\tTime must be in a 24-hour format.\n\tExample: chartodo dl-eti \
-            4 23:59",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittime_abrev_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittime_abrev_wrong_num_of_args");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4949,25 +5346,24 @@ mod deadline_todo_edit_time {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-eti").arg("1").arg("2").arg("3");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: You must \
-            specify the deadline todo's position that will be edited and what to edit the \
-            time to.\n\tThere should be 2 arguments after 'chartodo deadline-edittime'. \
-            You provided 3 argument(s).\n\tFormat: chartodo deadline-edittime ~position \
-            ~time.\n\t\tTime must be in a 24-hour format.\n\tExample: chartodo dl-eti \
-            4 23:59",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn edittime_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittime_position_not_usize");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -4992,20 +5388,24 @@ mod deadline_todo_edit_time {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-edittime").arg("a").arg("edited-task");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: 'a' isn't a valid position. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, 'a', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittime_abrev_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittime_abrev_position_not_usize");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5030,20 +5430,24 @@ mod deadline_todo_edit_time {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-eti").arg("a").arg("edited-task");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: 'a' isn't a valid position. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, 'a', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittime_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittime_position_is_zero");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5068,20 +5472,24 @@ mod deadline_todo_edit_time {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-edittime").arg("0").arg("edited-task");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittime_abrev_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittime_abrev_position_is_zero");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5106,20 +5514,24 @@ mod deadline_todo_edit_time {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-eti").arg("0").arg("edited-task");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittime_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittime_position_not_in_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5144,21 +5556,24 @@ mod deadline_todo_edit_time {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-edittime").arg("2").arg("edited-task");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Your position, '2', exceeds the todo list's length. Try \
-            something between 1 and 1",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittime_abrev_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittime_abrev_position_not_in_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5183,21 +5598,24 @@ mod deadline_todo_edit_time {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-eti").arg("2").arg("edited-task");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Your position, '2', exceeds the todo list's length. Try \
-            something between 1 and 1",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittime_time_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittime_time_is_wrong");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5222,21 +5640,25 @@ mod deadline_todo_edit_time {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-edittime").arg("1").arg("24:37");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The time provided, '24:37', isn't proper. It must be in a \
             24-hour format, e.g., 23:08",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittime_abrev_time_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittime_abrev_time_is_wrong");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5261,21 +5683,25 @@ mod deadline_todo_edit_time {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-eti").arg("1").arg("24:37");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The time provided, '24:37', isn't proper. It must be in a \
             24-hour format, e.g., 23:08",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittime_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittime_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5300,20 +5726,24 @@ mod deadline_todo_edit_time {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-edittime").arg("1").arg("14:37");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("MISSED: 2021-01-01 14:37"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittime_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittime_abrev_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5338,15 +5768,17 @@ mod deadline_todo_edit_time {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-eti").arg("1").arg("14:37");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("MISSED: 2021-01-01 14:37"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -5356,30 +5788,40 @@ mod deadline_todo_edit_datetime {
 
     #[test]
     fn editdatetime_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdatetime_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-editdatetime");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for deadline-editdatetime",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdatetime_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdatetime_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-edt");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for deadline-editdatetime",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdatetime_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdatetime_empty_todo");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5393,21 +5835,28 @@ mod deadline_todo_edit_datetime {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("deadline-editdatetime").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("deadline-editdatetime")
+            .arg("1")
+            .arg("2099-01-01")
+            .arg("00:00");
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdatetime_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdatetime_abrev_empty_todo");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5421,21 +5870,25 @@ mod deadline_todo_edit_datetime {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("dl-edt").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("dl-edt").arg("1").arg("2099-01-01").arg("00:00");
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdatetime_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdatetime_wrong_num_of_args");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5460,25 +5913,24 @@ mod deadline_todo_edit_datetime {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-editdatetime").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: You must \
-            specify the deadline todo's position and what to edit the datetime to.\n\tThere \
-            should be 3 arguments after 'chartodo deadline-editdatetime'. You provided \
-            1 argument(s).\n\tFormat: chartodo deadline-editdatetime ~position ~date \
-            ~time.\n\t\tDate should be in a yy-mm-dd format. Time should be in a 24-hour \
-            format.\n\tExample: chartodo dl-edt 4 2150-01-01 00:00",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdatetime_abrev_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdatetime_abrev_wrong_num_of_args");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5503,25 +5955,24 @@ mod deadline_todo_edit_datetime {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-edt").arg("1").arg("2").arg("3").arg("4");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: You must \
-            specify the deadline todo's position and what to edit the datetime to.\n\tThere \
-            should be 3 arguments after 'chartodo deadline-editdatetime'. You provided \
-            4 argument(s).\n\tFormat: chartodo deadline-editdatetime ~position ~date \
-            ~time.\n\t\tDate should be in a yy-mm-dd format. Time should be in a 24-hour \
-            format.\n\tExample: chartodo dl-edt 4 2150-01-01 00:00",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn editdatetime_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdatetime_position_not_usize");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5546,23 +5997,27 @@ mod deadline_todo_edit_datetime {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-editdatetime")
             .arg("a")
             .arg("2099-12-13")
             .arg("24:37");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: 'a' isn't a valid position. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, 'a', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdatetime_abrev_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdatetime_abrev_position_not_usize");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5587,20 +6042,24 @@ mod deadline_todo_edit_datetime {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-edt").arg("a").arg("2099-12-13").arg("24:37");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: 'a' isn't a valid position. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, 'a', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdatetime_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdatetime_position_is_zero");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5625,23 +6084,27 @@ mod deadline_todo_edit_datetime {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-editdatetime")
             .arg("0")
             .arg("2099-12-13")
             .arg("24:37");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdatetime_abrev_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdatetime_abrev_position_is_zero");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5666,20 +6129,24 @@ mod deadline_todo_edit_datetime {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-edt").arg("0").arg("2099-12-13").arg("24:37");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdatetime_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdatetime_position_not_in_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5704,24 +6171,27 @@ mod deadline_todo_edit_datetime {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-editdatetime")
             .arg("2")
             .arg("2099-12-13")
             .arg("24:37");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Your position, '2', exceeds the todo list's length. Try \
-            something between 1 and 1",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdatetime_abrev_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdatetime_abrev_position_not_in_range");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5746,21 +6216,24 @@ mod deadline_todo_edit_datetime {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-edt").arg("2").arg("2099-12-13").arg("24:37");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Your position, '2', exceeds the todo list's length. Try \
-            something between 1 and 1",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdatetime_date_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdatetime_date_is_wrong");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5785,24 +6258,28 @@ mod deadline_todo_edit_datetime {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-editdatetime")
             .arg("1")
             .arg("2099-13-13")
             .arg("24:37");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: '2099-13-13' isn't a proper date in a yy-mm-dd format, e.g., \
             2100-12-24.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdatetime_abrev_date_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdatetime_abrev_date_is_wrong");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5827,21 +6304,25 @@ mod deadline_todo_edit_datetime {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-edt").arg("1").arg("2099-13-13").arg("24:37");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: '2099-13-13' isn't a proper date in a yy-mm-dd format, e.g., \
             2100-12-24.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdatetime_time_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdatetime_time_is_wrong");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5866,24 +6347,28 @@ mod deadline_todo_edit_datetime {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-editdatetime")
             .arg("1")
             .arg("2099-12-13")
             .arg("24:37");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: '24:37' isn't a proper time in a 24-hour format, e.g., \
             13:28",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdatetime_abrev_time_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdatetime_abrev_time_is_wrong");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5908,20 +6393,24 @@ mod deadline_todo_edit_datetime {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-edt").arg("1").arg("2099-12-13").arg("24:37");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: '24:37' isn't a proper time in a 24-hour format, e.g., 13:28",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdatetime_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdatetime_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5946,10 +6435,10 @@ mod deadline_todo_edit_datetime {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-editdatetime")
             .arg("1")
             .arg("2099-12-13")
@@ -5958,11 +6447,15 @@ mod deadline_todo_edit_datetime {
             .success()
             .stdout(predicate::str::contains("due: 2099-12-13 14:37"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editdatetime_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editdatetime_abrev_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -5987,33 +6480,17 @@ mod deadline_todo_edit_datetime {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-edt").arg("1").arg("2099-12-13").arg("14:37");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("due: 2099-12-13 14:37"));
 
-        Ok(())
-    }
-}
-
-mod zzz_do_this_last {
-    use super::*;
+        std::fs::remove_dir_all(&data_dir)?;
 
-    #[test]
-    fn zzzz_rename_deadline_copy_to_original() {
-        // name is zzzz so it's done last
-        // now that tests are done, remove the modified original and rename copy to original
-
-        std::fs::remove_file(path_to_deadline_tasks())
-            .context("failed delete modified deadline_tasks.json after running tests")
-            .expect("failed to delete deadline_tasks.json after deadline_helpers unit tests");
-
-        std::fs::rename(deadline_tasks_copy_path(), path_to_deadline_tasks())
-            .context("failed to rename deadline_tasks_copy to deadline_tasks")
-            .expect("failed to rename deadline_tasks_copy to deadline_tasks after tests were done");
+        Ok(())
     }
 }
@@ -0,0 +1,74 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+mod common;
+use common::*;
+
+// cargo test --test exit_codes_tests
+// every test points chartodo at its own folder in the temp dir. a fresh folder starts w/ the example tasks, which
+// get the ids #1 to #4, so the first task a test adds is always #5
+
+#[test]
+fn each_class_of_error_has_its_own_code() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("classes");
+
+    let cases = [
+        (vec!["not-a-command"], 2),
+        (vec!["edit-priority", "1"], 2),
+        (vec!["done", "9"], 3),
+        (vec!["done", "#3"], 3),
+        (vec!["dl-a", "x", "2099-13-01", "00:00"], 4),
+        (vec!["dl-a", "x", "2099-01-01", "25:00"], 4),
+        (vec!["rp-a", "x", "1", "fortnight"], 4),
+        (vec!["edit-priority", "1", "urgent"], 4),
+        (vec!["rmdone", "1"], 0),
+        (vec!["notdone", "1"], 5),
+        (vec!["redo"], 5),
+    ];
+    for (args, code) in cases {
+        chartodo(&data_dir, &args).assert().code(code);
+    }
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn errors_go_to_stderr() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("stderr");
+
+    chartodo(&data_dir, &["done", "9"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::starts_with(
+            "ERROR: None of the positions you provided were viable",
+        ));
+    chartodo(&data_dir, &["add", "a", "b", "c", "d", "e"])
+        .assert()
+        .success()
+        .stderr(predicate::str::is_empty());
+    chartodo(&data_dir, &["done", "1-6"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::starts_with("WARNING: "));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn an_error_changes_nothing() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("nothing");
+
+    chartodo(&data_dir, &["edit", "9", "hi"]).assert().code(3);
+    chartodo(&data_dir, &["list"])
+        .assert()
+        .success()
+        .stderr(predicate::str::is_empty())
+        .stdout(predicate::str::contains("1: this is the todo list"));
+    chartodo(&data_dir, &["undo"]).assert().code(5);
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
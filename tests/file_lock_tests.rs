@@ -0,0 +1,116 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::{fs::File, process::Command};
+
+mod common;
+use common::*;
+
+// cargo test --test file_lock_tests
+// every test points chartodo at its own folder in the temp dir, so these never touch the real chartodo files
+
+#[test]
+fn concurrent_adds_keep_every_task() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = missing_data_dir("concurrent_adds");
+    std::fs::create_dir(&data_dir)?;
+
+    let mut chartodos = vec![];
+    for number in 1..=8 {
+        let mut cmd = Command::cargo_bin("chartodo")?;
+        cmd.arg("--data-dir")
+            .arg(&data_dir)
+            .arg("add")
+            .arg(format!("concurrent-item-{}", number))
+            .stdout(std::process::Stdio::null());
+        chartodos.push(cmd.spawn()?);
+    }
+    for mut chartodo in chartodos {
+        assert!(chartodo.wait()?.success());
+    }
+
+    let chartodo_json = std::fs::read_to_string(data_dir.join("chartodo.json"))?;
+    for number in 1..=8 {
+        assert!(chartodo_json.contains(&format!("\"concurrent-item-{}\"", number)));
+    }
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn held_lock_times_out_and_changes_nothing() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = missing_data_dir("held_lock");
+    std::fs::create_dir(&data_dir)?;
+
+    // pretend another chartodo is in the middle of a command
+    let lock_file = File::create(data_dir.join("chartodo.lock"))?;
+    lock_file.lock()?;
+
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.env("CHARTODO_LOCK_TIMEOUT", "0")
+        .arg("--data-dir")
+        .arg(&data_dir)
+        .arg("add")
+        .arg("blocked-item");
+    cmd.assert()
+        .code(6)
+        .stderr(predicate::str::contains(
+            "ERROR: Another chartodo has been using",
+        ))
+        .stderr(predicate::str::contains("CHARTODO_LOCK_TIMEOUT"));
+
+    assert!(!data_dir.join("chartodo.json").exists());
+
+    // once it's let go, chartodo goes through
+    lock_file.unlock()?;
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.arg("--data-dir")
+        .arg(&data_dir)
+        .arg("add")
+        .arg("unblocked-item");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("unblocked-item"));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn help_doesnt_need_the_lock() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = missing_data_dir("help");
+    std::fs::create_dir(&data_dir)?;
+
+    let lock_file = File::create(data_dir.join("chartodo.lock"))?;
+    lock_file.lock()?;
+
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.env("CHARTODO_LOCK_TIMEOUT", "0")
+        .arg("--data-dir")
+        .arg(&data_dir)
+        .arg("help");
+    cmd.assert().success();
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn timeout_that_isnt_a_number_is_an_invalid_setting() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = missing_data_dir("bad_timeout");
+    std::fs::create_dir(&data_dir)?;
+
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.env("CHARTODO_LOCK_TIMEOUT", "soon")
+        .arg("--data-dir")
+        .arg(&data_dir)
+        .arg("add")
+        .arg("too-soon-item");
+    cmd.assert().code(4).stderr(predicate::str::contains(
+        "ERROR: CHARTODO_LOCK_TIMEOUT='soon' isn't a number of seconds",
+    ));
+
+    assert!(!data_dir.join("chartodo.json").exists());
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
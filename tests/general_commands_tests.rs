@@ -1,309 +1,50 @@
 use anyhow::Context;
 use assert_cmd::prelude::*;
-use chartodo::functions::{
-    deadline_tasks::deadline_helpers::*, json_file_structs::*, regular_tasks::regular_helpers::*,
-    repeating_tasks::repeating_helpers::*,
-};
+use chartodo::functions::{json_file_structs::*, task_store::TaskList};
 use predicates::prelude::*;
-use std::{path::PathBuf, process::Command};
 
-// cargo test --test general_commands_tests -- --test-threads=1
+mod common;
+use common::*;
 
-fn path_to_regular_tasks() -> PathBuf {
-    // get the data dir XDG spec and return it with path to regular_tasks.json
-    let mut regular_tasks_path = dirs::data_dir()
-        .context(
-            "linux: couldn't get $HOME/.local/share/
-                windows: couldn't get C:/Users/your_user/AppData/Local/
-                mac: couldn't get /Users/your_user/Library/Application Support/
-
-                those directories should exist for your OS. please double check that they do.",
-        )
-        .expect("something went wrong with fetching the user's data dirs");
-    regular_tasks_path.push("chartodo/regular_tasks.json");
-
-    regular_tasks_path
-}
-
-fn regular_tasks_copy_path() -> PathBuf {
-    // get the path for regular_tasks_copy.json, which will be used to hold the original contents
-    // of regular_tasks.json while it's getting modified
-    let mut regular_tasks_copy_path = dirs::data_dir()
-        .context(
-            "linux: couldn't get $HOME/.local/share/
-                windows: couldn't get C:/Users/your_user/AppData/Local/
-                mac: couldn't get /Users/your_user/Library/Application Support/
-
-                those directories should exist for your OS. please double check that they do.",
-        )
-        .expect("something went wrong with fetching the user's data dirs");
-    regular_tasks_copy_path.push("chartodo/regular_tasks_copy.json");
-
-    regular_tasks_copy_path
-}
-
-fn path_to_deadline_tasks() -> PathBuf {
-    // get the data dir XDG spec and return it with path to deadline_tasks.json
-    let mut deadline_tasks_path = dirs::data_dir()
-        .context(
-            "linux: couldn't get $HOME/.local/share/
-                windows: couldn't get C:/Users/your_user/AppData/Local/
-                mac: couldn't get /Users/your_user/Library/Application Support/
-
-                those directories should exist for your OS. please double check that they do.",
-        )
-        .expect("something went wrong with fetching the user's data dirs");
-    deadline_tasks_path.push("chartodo/deadline_tasks.json");
-
-    deadline_tasks_path
-}
-
-fn deadline_tasks_copy_path() -> PathBuf {
-    // get the path for deadline_tasks_copy.json, which will be used to hold the original contents
-    // of deadline_tasks.json while it's getting modified
-    let mut deadline_tasks_copy_path = dirs::data_dir()
-        .context(
-            "linux: couldn't get $HOME/.local/share/
-                windows: couldn't get C:/Users/your_user/AppData/Local/
-                mac: couldn't get /Users/your_user/Library/Application Support/
-
-                those directories should exist for your OS. please double check that they do.",
-        )
-        .expect("something went wrong with fetching the user's data dirs");
-    deadline_tasks_copy_path.push("chartodo/deadline_tasks_copy.json");
-
-    deadline_tasks_copy_path
-}
-
-fn path_to_repeating_tasks() -> PathBuf {
-    // get the data dir XDG spec and return it with path to regular_tasks.json
-    let mut repeating_tasks_path = dirs::data_dir()
-        .context(
-            "linux: couldn't get $HOME/.local/share/
-                windows: couldn't get C:/Users/your_user/AppData/Local/
-                mac: couldn't get /Users/your_user/Library/Application Support/
-
-                those directories should exist for your OS. please double check that they do.",
-        )
-        .expect("something went wrong with fetching the user's data dirs");
-    repeating_tasks_path.push("chartodo/repeating_tasks.json");
-
-    repeating_tasks_path
-}
-
-fn repeating_tasks_copy_path() -> PathBuf {
-    // get the path for repeating_tasks_copy.json, which will be used to hold the original contents
-    // of repeating_tasks.json while it's getting modified
-    let mut repeating_tasks_copy_path = dirs::data_dir()
-        .context(
-            "linux: couldn't get $HOME/.local/share/
-                windows: couldn't get C:/Users/your_user/AppData/Local/
-                mac: couldn't get /Users/your_user/Library/Application Support/
-
-                those directories should exist for your OS. please double check that they do.",
-        )
-        .expect("something went wrong with fetching the user's data dirs");
-    repeating_tasks_copy_path.push("chartodo/repeating_tasks_copy.json");
-
-    repeating_tasks_copy_path
-}
-
-mod aaa_do_this_first {
-    use super::*;
-
-    // these have been tested in other fns, these are just included here as a sanity check
-    #[test]
-    fn regular_tasks_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/regular_tasks.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/regular_tasks.json";
-        let mac_path = "/Library/Application Support/chartodo/regular_tasks.json";
-        let mut got_regular_tasks_path: bool = false;
-        let regular_path = path_to_regular_tasks();
-        let regular_path = regular_path.to_str().unwrap();
-
-        if regular_path.contains(linux_path)
-            | regular_path.contains(windows_path)
-            | regular_path.contains(mac_path)
-        {
-            got_regular_tasks_path = true;
-        }
-
-        assert!(got_regular_tasks_path);
-    }
-
-    #[test]
-    fn regular_tasks_copy_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/regular_tasks_copy.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/regular_tasks_copy.json";
-        let mac_path = "/Library/Application Support/chartodo/regular_tasks_copy.json";
-        let mut got_regular_tasks_copy_path: bool = false;
-        let regular_tasks_copy_path = regular_tasks_copy_path();
-        let regular_tasks_copy_path = regular_tasks_copy_path.to_str().unwrap();
-
-        if regular_tasks_copy_path.contains(linux_path)
-            | regular_tasks_copy_path.contains(windows_path)
-            | regular_tasks_copy_path.contains(mac_path)
-        {
-            got_regular_tasks_copy_path = true;
-        }
-
-        assert!(got_regular_tasks_copy_path);
-    }
-
-    #[test]
-    fn aaaa_regular_tasks_clone_file() {
-        // name is aaaa so it's done first
-        // since we will be modifying the original file to run a test, the original data must be
-        // preserved first
-        std::fs::File::create(regular_tasks_copy_path())
-            .context("failed to create regular_tasks_copy.json")
-            .expect("failed to create a copy during unit test");
-
-        std::fs::copy(path_to_regular_tasks(), regular_tasks_copy_path())
-            .context("failed to copy regular_tasks.json to regular_tasks_copy.json")
-            .expect("failed to copy original file to copy file during unit test");
-    }
-
-    // these have been tested in other fns, these are just included here as a sanity check
-    #[test]
-    fn deadline_tasks_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/deadline_tasks.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/deadline_tasks.json";
-        let mac_path = "/Library/Application Support/chartodo/deadline_tasks.json";
-        let mut got_deadline_tasks_path: bool = false;
-        let deadline_path = path_to_deadline_tasks();
-        let deadline_path = deadline_path.to_str().unwrap();
-
-        if deadline_path.contains(linux_path)
-            | deadline_path.contains(windows_path)
-            | deadline_path.contains(mac_path)
-        {
-            got_deadline_tasks_path = true;
-        }
-
-        assert!(got_deadline_tasks_path);
-    }
-
-    #[test]
-    fn deadline_tasks_copy_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/deadline_tasks_copy.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/deadline_tasks_copy.json";
-        let mac_path = "/Library/Application Support/chartodo/deadline_tasks_copy.json";
-        let mut got_deadline_tasks_copy_path: bool = false;
-        let deadline_tasks_copy_path = deadline_tasks_copy_path();
-        let deadline_tasks_copy_path = deadline_tasks_copy_path.to_str().unwrap();
-
-        if deadline_tasks_copy_path.contains(linux_path)
-            | deadline_tasks_copy_path.contains(windows_path)
-            | deadline_tasks_copy_path.contains(mac_path)
-        {
-            got_deadline_tasks_copy_path = true;
-        }
-
-        assert!(got_deadline_tasks_copy_path);
-    }
-
-    #[test]
-    fn aaaa_deadline_tasks_clone_file() {
-        // name is aaaa so it's done first
-        // since we will be modifying the original file to run a test, the original data must be
-        // preserved first
-        std::fs::File::create(deadline_tasks_copy_path())
-            .context("failed to create deadline_tasks_copy.json")
-            .expect("failed to create a copy during unit test");
-
-        std::fs::copy(path_to_deadline_tasks(), deadline_tasks_copy_path())
-            .context("failed to copy deadline_tasks.json to deadline_tasks_copy.json")
-            .expect("failed to copy original file to copy file during unit test");
-    }
-
-    // these have been tested in other fns, these are just included here as a sanity check
-    #[test]
-    fn repeating_tasks_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/repeating_tasks.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/repeating_tasks.json";
-        let mac_path = "/Library/Application Support/chartodo/repeating_tasks.json";
-        let mut got_repeating_tasks_path: bool = false;
-        let repeating_path = path_to_repeating_tasks();
-        let repeating_path = repeating_path.to_str().unwrap();
-
-        if repeating_path.contains(linux_path)
-            | repeating_path.contains(windows_path)
-            | repeating_path.contains(mac_path)
-        {
-            got_repeating_tasks_path = true;
-        }
-
-        assert!(got_repeating_tasks_path);
-    }
-
-    #[test]
-    fn repeating_tasks_copy_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/repeating_tasks_copy.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/repeating_tasks_copy.json";
-        let mac_path = "/Library/Application Support/chartodo/repeating_tasks_copy.json";
-        let mut got_repeating_tasks_copy_path: bool = false;
-        let repeating_tasks_copy_path = repeating_tasks_copy_path();
-        let repeating_tasks_copy_path = repeating_tasks_copy_path.to_str().unwrap();
-
-        if repeating_tasks_copy_path.contains(linux_path)
-            | repeating_tasks_copy_path.contains(windows_path)
-            | repeating_tasks_copy_path.contains(mac_path)
-        {
-            got_repeating_tasks_copy_path = true;
-        }
-
-        assert!(got_repeating_tasks_copy_path);
-    }
-
-    #[test]
-    fn aaaa_repeating_tasks_clone_file() {
-        // name is aaaa so it's done first
-        // since we will be modifying the original file to run a test, the original data must be
-        // preserved first
-        std::fs::File::create(repeating_tasks_copy_path())
-            .context("failed to create repeating_tasks_copy.json")
-            .expect("failed to create a copy during unit test");
-
-        std::fs::copy(path_to_repeating_tasks(), repeating_tasks_copy_path())
-            .context("failed to copy repeating_tasks.json to repeating_tasks_copy.json")
-            .expect("failed to copy original file to copy file during unit test");
-    }
-}
+// cargo test --test general_commands_tests
 
 mod general_commands_list {
     use super::*;
 
     #[test]
     fn list_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("list_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("list").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn list_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("list_abrev_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("l").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn list_prints_correctly() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("list_prints_correctly");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -328,7 +69,7 @@ mod general_commands_list {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -353,7 +94,7 @@ mod general_commands_list {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -378,10 +119,10 @@ mod general_commands_list {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("list");
         cmd.assert()
             .success()
@@ -392,11 +133,15 @@ mod general_commands_list {
             .stdout(predicate::str::contains("DEADLINE"))
             .stdout(predicate::str::contains("REPEATING"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn list_abrev_prints_correctly() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("list_abrev_prints_correctly");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -421,7 +166,7 @@ mod general_commands_list {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -446,7 +191,7 @@ mod general_commands_list {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -471,10 +216,10 @@ mod general_commands_list {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("l");
         cmd.assert()
             .success()
@@ -485,6 +230,8 @@ mod general_commands_list {
             .stdout(predicate::str::contains("DEADLINE"))
             .stdout(predicate::str::contains("REPEATING"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -494,28 +241,38 @@ mod general_commands_clearall {
 
     #[test]
     fn clearall_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("clearall_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("clearall").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn clearall_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("clearall_abrev_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("ca").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn clearall_all_lists_empty() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("clearall_all_lists_empty");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -529,7 +286,7 @@ mod general_commands_clearall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -543,7 +300,7 @@ mod general_commands_clearall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -557,20 +314,24 @@ mod general_commands_clearall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("clearall");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: All of the lists are currently empty.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn clearall_abrev_all_lists_empty() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("clearall_abrev_all_lists_empty");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -584,7 +345,7 @@ mod general_commands_clearall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -598,7 +359,7 @@ mod general_commands_clearall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -612,20 +373,24 @@ mod general_commands_clearall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("ca");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: All of the lists are currently empty.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn clearall_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("clearall_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -661,7 +426,7 @@ mod general_commands_clearall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -697,7 +462,7 @@ mod general_commands_clearall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -733,10 +498,10 @@ mod general_commands_clearall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("clearall");
         assert!(cmd
             .assert()
@@ -745,35 +510,39 @@ mod general_commands_clearall {
             .is_err());
         assert!(cmd
             .assert()
-            .success()
+            .failure()
             .try_stdout(predicate::str::contains("1: regular-done"))
             .is_err());
         assert!(cmd
             .assert()
-            .success()
+            .failure()
             .try_stdout(predicate::str::contains("1: deadline"))
             .is_err());
         assert!(cmd
             .assert()
-            .success()
+            .failure()
             .try_stdout(predicate::str::contains("1: deadline-done"))
             .is_err());
         assert!(cmd
             .assert()
-            .success()
+            .failure()
             .try_stdout(predicate::str::contains("1: repeating"))
             .is_err());
         assert!(cmd
             .assert()
-            .success()
+            .failure()
             .try_stdout(predicate::str::contains("1: repeating-done"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn clearall_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("clearall_abrev_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -809,7 +578,7 @@ mod general_commands_clearall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -845,7 +614,7 @@ mod general_commands_clearall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -881,10 +650,10 @@ mod general_commands_clearall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("ca");
         assert!(cmd
             .assert()
@@ -893,30 +662,32 @@ mod general_commands_clearall {
             .is_err());
         assert!(cmd
             .assert()
-            .success()
+            .failure()
             .try_stdout(predicate::str::contains("1: regular-done"))
             .is_err());
         assert!(cmd
             .assert()
-            .success()
+            .failure()
             .try_stdout(predicate::str::contains("1: deadline"))
             .is_err());
         assert!(cmd
             .assert()
-            .success()
+            .failure()
             .try_stdout(predicate::str::contains("1: deadline-done"))
             .is_err());
         assert!(cmd
             .assert()
-            .success()
+            .failure()
             .try_stdout(predicate::str::contains("1: repeating"))
             .is_err());
         assert!(cmd
             .assert()
-            .success()
+            .failure()
             .try_stdout(predicate::str::contains("1: repeating-done"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -926,28 +697,38 @@ mod general_commands_clearregular {
 
     #[test]
     fn clearregular_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("clearregular_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("clearall-regular").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn clearregular_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("clearregular_abrev_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("ca-r").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn clearregular_all_lists_empty() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("clearregular_all_lists_empty");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -961,20 +742,24 @@ mod general_commands_clearregular {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("clearall-regular");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The regular task lists are currently empty.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn clearregular_abrev_all_lists_empty() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("clearregular_abrev_all_lists_empty");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -988,20 +773,24 @@ mod general_commands_clearregular {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("ca-r");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The regular task lists are currently empty.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn clearregular_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("clearregular_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1037,10 +826,10 @@ mod general_commands_clearregular {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("clearall-regular");
         assert!(cmd
             .assert()
@@ -1049,15 +838,19 @@ mod general_commands_clearregular {
             .is_err());
         assert!(cmd
             .assert()
-            .success()
+            .failure()
             .try_stdout(predicate::str::contains("1: regular-done"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn clearregular_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("clearregular_abrev_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1093,10 +886,10 @@ mod general_commands_clearregular {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("ca-r");
         assert!(cmd
             .assert()
@@ -1105,10 +898,12 @@ mod general_commands_clearregular {
             .is_err());
         assert!(cmd
             .assert()
-            .success()
+            .failure()
             .try_stdout(predicate::str::contains("1: regular-done"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -1118,28 +913,38 @@ mod general_commands_cleardeadline {
 
     #[test]
     fn cleardeadline_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("cleardeadline_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("clearall-deadline").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn cleardeadline_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("cleardeadline_abrev_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("ca-d").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn cleardeadline_all_lists_empty() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleardeadline_all_lists_empty");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1153,20 +958,24 @@ mod general_commands_cleardeadline {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("clearall-deadline");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline task lists are currently empty.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn cleardeadline_abrev_all_lists_empty() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleardeadline_abrev_all_lists_empty");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1180,20 +989,24 @@ mod general_commands_cleardeadline {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("ca-d");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The deadline task lists are currently empty.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn cleardeadline_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleardeadline_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1229,10 +1042,10 @@ mod general_commands_cleardeadline {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("clearall-deadline");
         assert!(cmd
             .assert()
@@ -1241,15 +1054,19 @@ mod general_commands_cleardeadline {
             .is_err());
         assert!(cmd
             .assert()
-            .success()
+            .failure()
             .try_stdout(predicate::str::contains("1: deadline-done"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn cleardeadline_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleardeadline_abrev_is_correct");
+
         // write fresh to deadline tasks so content is known
         let fresh_deadline_tasks = r#"
             {
@@ -1285,11 +1102,11 @@ mod general_commands_cleardeadline {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_deadline_tasks(fresh_deadline_tasks);
+        write_tasks(&data_dir, TaskList::Deadline, fresh_deadline_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("cr-d");
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("ca-d");
         assert!(cmd
             .assert()
             .success()
@@ -1297,10 +1114,12 @@ mod general_commands_cleardeadline {
             .is_err());
         assert!(cmd
             .assert()
-            .success()
+            .failure()
             .try_stdout(predicate::str::contains("1: deadline-done"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -1310,28 +1129,38 @@ mod general_commands_clearrepeating {
 
     #[test]
     fn clearrepeating_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("clearrepeating_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("clearall-repeating").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn clearrepeating_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("clearrepeating_abrev_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("ca-rp").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn clearrepeating_all_lists_empty() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("clearrepeating_all_lists_empty");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1345,20 +1174,24 @@ mod general_commands_clearrepeating {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("clearall-repeating");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating task lists are currently empty.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn clearrepeating_abrev_all_lists_empty() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("clearrepeating_abrev_all_lists_empty");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1372,20 +1205,24 @@ mod general_commands_clearrepeating {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("ca-rp");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating task lists are currently empty.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn clearrepeating_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("clearrepeating_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1421,10 +1258,10 @@ mod general_commands_clearrepeating {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("clearall-repeating");
         assert!(cmd
             .assert()
@@ -1433,15 +1270,19 @@ mod general_commands_clearrepeating {
             .is_err());
         assert!(cmd
             .assert()
-            .success()
+            .failure()
             .try_stdout(predicate::str::contains("1: repeating-done"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn clearrepeating_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("clearrepeating_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1477,10 +1318,10 @@ mod general_commands_clearrepeating {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("ca-rp");
         assert!(cmd
             .assert()
@@ -1489,10 +1330,12 @@ mod general_commands_clearrepeating {
             .is_err());
         assert!(cmd
             .assert()
-            .success()
+            .failure()
             .try_stdout(predicate::str::contains("1: repeating-done"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -1502,30 +1345,40 @@ mod main_help {
 
     #[test]
     fn help_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("help_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("help").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn help_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("help_abrev_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("h").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn help_prints_correctly() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("help_prints_correctly");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("help");
         cmd.assert()
             .success()
@@ -1537,9 +1390,20 @@ mod main_help {
             If a command says it has chaining, it means you can include multiple separate tasks or positions
             If a command says it has range positioning, it means you can include position arguments that's a range,
             e.g., 1-6, 5-10, 3-11, 1-100
-            Date format is always in year-month-day, e.g., 2099-12-25
-            Time format is always in a 24-hour format, e.g., 13:58. Note that there is no space between hour and minute
+            Anywhere a position is taken, you can use a task's #id instead, e.g., chartodo done '#12'. The id is shown next
+            to the task and never changes, even when the list gets sorted. Quote it, since some shells treat # as a comment
+            Every add command takes --priority [high/medium/low], or -p for short, which gives the added task(s) a priority
+            Every add command also takes --tag [tag], or -t for short, which tags the added task(s). It can be given more than once
+            Tags are + or @ followed by a word, e.g., +backend or @errands. A tag w/o either gets a +
+            Date format is year-month-day, e.g., 2099-12-25, or today, tomorrow, a weekday (fri, 'next monday'), or from now (+3d, 'in 2w')
+            Time format is a 24-hour format, e.g., 13:58, or noon, midnight, or eod. Note that there is no space between hour and minute
+            A date can have the time in it, e.g., 2099-12-25T08:00, eod, +2h, or 'in 30min'. That time is used if the command doesn't take one
             Only the following time units are allowed in repeating tasks: minutes, hours, days, weeks, months, years
+            Repeating add commands take --on [rule] to only land on certain days: mon,wed,fri, weekdays, or weekends w/ weeks, and '2nd tue', 'last fri', 'last day', or 'last business day' w/ months
+            Repeating add commands also take --catch-up [once/next/each] for the times a task was missed: once is due again once, next skips to the next time still ahead, each also adds a deadline todo per missed time
+            Repeating add commands also take --until [date] and --times [n] to end the series after that date or that many times. A finished series stays done, and rp-s/rp-sa show how many times are left
+            Tasks are stored in your OS's data dir. To use another folder, pass --data-dir [folder] or set CHARTODO_HOME
+            Pass --format json to any command to get the lists, or what went wrong, as one json object, e.g., chartodo list --format json
 
         A TIP BEFORE STARTING: 
             it's helpful to memorize regular todo/done commands, since most repeating/deadline commands
@@ -1555,11 +1419,31 @@ mod main_help {
             regular-help, r-h                       show help for regular tasks
             deadline-help, dl-h                     show help for deadline tasks
             repeating-help, rp-h                    show help for repeating tasks
-            list, l                                 show the todo list
-            clearall, ca                            clear everything (TODO, DEADLINE, REPEATING)
-            clearall-regular, ca-r                  clear all regular todo and done tasks
-            clearall-deadline, ca-d                 clear all deadline todo and done tasks
-            clearall-repeating, ca-rp               clear all repeating todo and done tasks
+            list, l                                 show the todo list. Pass --tag [tag] to only show the tasks w/ that tag
+                                                    example: chartodo list --tag backend
+            search, s                               find tasks in every list and the archive by their text, ignoring case. Pass --regex to use a regex
+                                                    format: chartodo search [text]
+                                                    example: chartodo search --regex '^buy-'
+            clearall, ca                            clear everything (TODO, DEADLINE, REPEATING). Pass --archive to archive the done items instead of deleting them
+            clearall-regular, ca-r                  clear all regular todo and done tasks. Pass --archive to archive the done items instead of deleting them
+            clearall-deadline, ca-d                 clear all deadline todo and done tasks. Pass --archive to archive the done items instead of deleting them
+            clearall-repeating, ca-rp               clear all repeating todo and done tasks. Pass --archive to archive the done items instead of deleting them
+            undo, u                                 undo the last command that changed a list. Up to 20 commands can be undone
+            redo                                    redo the last command that was undone
+            history                                 show what's happened to tasks, e.g., when each one was done, oldest first. Done lists get cleared, but this doesn't
+                                                    format: chartodo history --from [date] --to [date]
+                                                    example: chartodo history --from -7d
+            archive-list, ar-l                      show the done tasks that were archived, by kind of task
+            archive-restore, ar-r                   move archived tasks back to their done list, using the positions archive-list shows. Has chaining and range positioning
+                                                    format: chartodo archive-restore [regular/deadline/repeating] [position]
+                                                    example: chartodo ar-r deadline 1 3-4
+            init                                    start empty lists, or a copy of a template w/ --template [file]. Won't touch lists that are already there
+                                                    format: chartodo init --template [file]
+                                                    example: chartodo init --template team.json
+            config get, config set                  show or change a setting in config.toml, or show every setting w/ config get
+                                                    format: chartodo config set [setting] [value]
+                                                    example: chartodo config set display.table_style ascii
+            completions [shell]                     print a tab-completion script for bash, zsh, fish, elvish, or powershell
 
         REGULAR TODO:
             add, a                                  add an item to the todo list. To add a multi-word item, replace space with something like -. Has chaining
@@ -1575,10 +1459,19 @@ mod main_help {
                                                     example: chartodo rmt 4 1 5 11-15
             doneall, da                             change all todo items to done
             cleartodo, ct                           clear the todo list
-            clearboth, cb                           clear both todo and done lists
+            clearboth, cb                           clear both todo and done lists. Pass --archive to archive the done items instead of deleting them
             edit, e                                 changes a todo item, with its position specified, to what you want
                                                     format: chartoo edit [position] [new task]
                                                     example: chartodo edit 3 change-item-to-this
+            edit-priority, ep                       changes the priority of a todo item to high, medium, low, or none
+                                                    format: chartodo edit-priority [position] [priority]
+                                                    example: chartodo ep 3 high
+            tag, tg                                 add tags to a todo item
+                                                    format: chartodo tag [position] [tag] [...]
+                                                    example: chartodo tg 3 backend @errands
+            untag, utg                              take tags away from a todo item. A tag w/o + or @ takes away both, e.g., backend takes away +backend and @backend
+                                                    format: chartodo untag [position] [tag] [...]
+                                                    example: chartodo utg 3 backend
 
         REGULAR DONE:
             notdone, nd                             reverses a done item back to a todo item using numbered positions. Has chaining and range positioning
@@ -1588,7 +1481,10 @@ mod main_help {
                                                     format: chartodo rmdone [position]
                                                     example: chartodo rmd 4 5-8
             notdoneall, nda                         reverses all done items back to todo
-            cleardone, cd                           clears the done list
+            cleardone, cd                           clears the done list. Pass --archive to archive the done items instead of deleting them
+            archive, ar                             moves a done item to the archive using numbered positions. Has chaining and range positioning
+                                                    format: chartodo archive [position]
+                                                    example: chartodo ar 1 3-5
 
         DEADLINE TODO:
             deadline-add, dl-a                      adds a task with a day and time limit. Has chaining
@@ -1610,7 +1506,7 @@ mod main_help {
                                                     example: chartodo dl-rmt 1 5-9
             deadline-doneall, dl-da                 mark the entire deadline todo list as done
             deadline-cleartodo, deadline-ct         clear the deadline todo list
-            deadline-clearboth, dl-cb               clears both of the deadline todo and done lists
+            deadline-clearboth, dl-cb               clears both of the deadline todo and done lists. Pass --archive to archive the done items instead of deleting them
             deadline-editall, dl-ea                 edit all the parameters of a deadline todo task
                                                     format: chartodo deadline-editall [position] [new deadline task] [new ending date] [new ending time]
                                                     example: chartodo dl-ea 1 new-item 2150-01-01 00:00
@@ -1626,6 +1522,15 @@ mod main_help {
             deadline-editdatetime, dl-edt           edit the date and time parameter of a deadline todo task
                                                     format: chartodo deadline-editdatetime [position] [new ending date] [new ending time]
                                                     example: chartodo dl-edt 1 2100-01-01 13:00
+            deadline-editpriority, dl-ep            edit the priority of a deadline todo task to high, medium, low, or none. Tasks due at the same time are sorted by priority
+                                                    format: chartodo deadline-editpriority [position] [priority]
+                                                    example: chartodo dl-ep 1 low
+            deadline-tag, dl-tg                     add tags to a deadline todo task
+                                                    format: chartodo deadline-tag [position] [tag] [...]
+                                                    example: chartodo dl-tg 1 backend @errands
+            deadline-untag, dl-utg                  take tags away from a deadline todo task. A tag w/o + or @ takes away both, e.g., backend takes away +backend and @backend
+                                                    format: chartodo deadline-untag [position] [tag] [...]
+                                                    example: chartodo dl-utg 1 backend
 
         DEADLINE DONE:
             deadline-notdone, dl-nd                 reverses a deadline done item back to todo using numbered positions. Has chaining and range positioning
@@ -1635,10 +1540,16 @@ mod main_help {
                                                     format: chartodo deadline-rmdone [position]
                                                     example: chartodo dl-rmd 3 2 1 5-7
             deadline-notdoneall, dl-nda             reverses all deadline done items back to todo
-            deadline-cleardone, dl-cd               clears the deadline done list
+            deadline-cleardone, dl-cd               clears the deadline done list. Pass --archive to archive the done items instead of deleting them
+            deadline-archive, dl-ar                 moves a deadline done item to the archive using numbered positions. Has chaining and range positioning
+                                                    format: chartodo deadline-archive [position]
+                                                    example: chartodo dl-ar 2 4-6
 
         REPEATING TODO:
         note: Only the following time units are allowed in repeating tasks: minutes, hours, days, weeks, months, years
+        note: Repeating add commands take --on [rule] to only land on certain days: mon,wed,fri, weekdays, or weekends w/ weeks, and '2nd tue', 'last fri', 'last day', or 'last business day' w/ months
+        note: Repeating add commands also take --catch-up [once/next/each] for the times a task was missed: once is due again once, next skips to the next time still ahead, each also adds a deadline todo per missed time
+        note: Repeating add commands also take --until [date] and --times [n] to end the series after that date or that many times. A finished series stays done, and rp-s/rp-sa show how many times are left
             repeating-add, rp-a                     add a repeating task with a set interval. the task starts from your current date and time. Has chaining
                                                     format: chartodo repeating-add [repeating task] [interval] [time unit]
                                                     example: chartodo rp-a gym 2 days
@@ -1646,6 +1557,7 @@ mod main_help {
             repeating-addstart, rp-as               add a repeating task that starts on your specified datetime. Has chaining
                                                     format: chartodo repeating-addstart [repeating task] [interval] [time unit] [starting date] [starting time]
                                                     example: chartodo rp-as task 3 days 2099-01-01 00:00
+                                                    example: chartodo rp-as standup 1 week 2099-01-05 09:00 --on mon,wed,fri
             repeating-addend, rp-ae                 add a repeating task that ends on your specified datetime. Has chaining
                                                     format: chartodo repeating-addend [repeating task] [interval] [time unit] [ending date] [ending time]
                                                     example: chartodo rp-ae task 3 days 2099-01-01 00:00
@@ -1667,7 +1579,7 @@ mod main_help {
                                                     example: chartodo rp-s 1 3-5
             repeating-doneall, rp-da                mark all repeating tasks as done
             repeating-cleartodo, rp-ct              delete all of the repeating todo tasks
-            repeating-clearboth, rp-cb              clear the repeating todo and done lists
+            repeating-clearboth, rp-cb              clear the repeating todo and done lists. Pass --archive to archive the done items instead of deleting them
             repeating-resetall, repeating-doneresetall, rp-ra, rp-dra
                                                     resets the starting datetime of all repeating tasks to your current date and time
             repeating-startall, rp-sa               show the starting datetime of all repeating tasks
@@ -1694,6 +1606,21 @@ mod main_help {
             repeating-editend, rp-ee                edit the ending datetime of a repeating task
                                                     format: chartodo repeating-editend [position] [ending date] [ending time]
                                                     example: chartodo rp-ee 1 2100-12-24 13:08
+            repeating-editon, rp-eo                 edit which days a repeating task lands on, or none to go back to every interval. The rule has to go w/ its time unit, see --on
+                                                    format: chartodo repeating-editon [position] [rule]
+                                                    example: chartodo rp-eo 1 'last business day'
+            repeating-editcatchup, rp-ec            edit what a repeating task does about the times it was missed: once, next, or each. See --catch-up
+                                                    format: chartodo repeating-editcatchup [position] [catch-up]
+                                                    example: chartodo rp-ec 1 next
+            repeating-editpriority, rp-ep           edit the priority of a repeating task to high, medium, low, or none. Tasks due at the same time are sorted by priority
+                                                    format: chartodo repeating-editpriority [position] [priority]
+                                                    example: chartodo rp-ep 1 medium
+            repeating-tag, rp-tg                    add tags to a repeating task
+                                                    format: chartodo repeating-tag [position] [tag] [...]
+                                                    example: chartodo rp-tg 1 backend @errands
+            repeating-untag, rp-utg                 take tags away from a repeating task. A tag w/o + or @ takes away both, e.g., backend takes away +backend and @backend
+                                                    format: chartodo repeating-untag [position] [tag] [...]
+                                                    example: chartodo rp-utg 1 backend
 
         REPEATING DONE:
             repeating-notdone, rp-nd                reverse repeating dones back to todo. Has chaining and range positioning
@@ -1703,16 +1630,23 @@ mod main_help {
                                                     format: chartodo repeating-rmdone [position]
                                                     example: chartodo rp-rmd 1 3-5
             repeating-notdoneall, rp-nda            reverse all finished repeating tasks back to todo
-            repeating-cleardone, rp-cd              delete all of the finished repeating tasks
+            repeating-cleardone, rp-cd              delete all of the finished repeating tasks. Pass --archive to archive them instead of deleting them
+            repeating-archive, rp-ar                moves a finished repeating task to the archive. Has chaining and range positioning
+                                                    format: chartodo repeating-archive [position]
+                                                    example: chartodo rp-ar 1 3-5
 "));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn help_abrev_prints_correctly() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("help_abrev_prints_correctly");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("h");
         cmd.assert()
             .success()
@@ -1724,9 +1658,20 @@ mod main_help {
             If a command says it has chaining, it means you can include multiple separate tasks or positions
             If a command says it has range positioning, it means you can include position arguments that's a range,
             e.g., 1-6, 5-10, 3-11, 1-100
-            Date format is always in year-month-day, e.g., 2099-12-25
-            Time format is always in a 24-hour format, e.g., 13:58. Note that there is no space between hour and minute
+            Anywhere a position is taken, you can use a task's #id instead, e.g., chartodo done '#12'. The id is shown next
+            to the task and never changes, even when the list gets sorted. Quote it, since some shells treat # as a comment
+            Every add command takes --priority [high/medium/low], or -p for short, which gives the added task(s) a priority
+            Every add command also takes --tag [tag], or -t for short, which tags the added task(s). It can be given more than once
+            Tags are + or @ followed by a word, e.g., +backend or @errands. A tag w/o either gets a +
+            Date format is year-month-day, e.g., 2099-12-25, or today, tomorrow, a weekday (fri, 'next monday'), or from now (+3d, 'in 2w')
+            Time format is a 24-hour format, e.g., 13:58, or noon, midnight, or eod. Note that there is no space between hour and minute
+            A date can have the time in it, e.g., 2099-12-25T08:00, eod, +2h, or 'in 30min'. That time is used if the command doesn't take one
             Only the following time units are allowed in repeating tasks: minutes, hours, days, weeks, months, years
+            Repeating add commands take --on [rule] to only land on certain days: mon,wed,fri, weekdays, or weekends w/ weeks, and '2nd tue', 'last fri', 'last day', or 'last business day' w/ months
+            Repeating add commands also take --catch-up [once/next/each] for the times a task was missed: once is due again once, next skips to the next time still ahead, each also adds a deadline todo per missed time
+            Repeating add commands also take --until [date] and --times [n] to end the series after that date or that many times. A finished series stays done, and rp-s/rp-sa show how many times are left
+            Tasks are stored in your OS's data dir. To use another folder, pass --data-dir [folder] or set CHARTODO_HOME
+            Pass --format json to any command to get the lists, or what went wrong, as one json object, e.g., chartodo list --format json
 
         A TIP BEFORE STARTING: 
             it's helpful to memorize regular todo/done commands, since most repeating/deadline commands
@@ -1742,11 +1687,31 @@ mod main_help {
             regular-help, r-h                       show help for regular tasks
             deadline-help, dl-h                     show help for deadline tasks
             repeating-help, rp-h                    show help for repeating tasks
-            list, l                                 show the todo list
-            clearall, ca                            clear everything (TODO, DEADLINE, REPEATING)
-            clearall-regular, ca-r                  clear all regular todo and done tasks
-            clearall-deadline, ca-d                 clear all deadline todo and done tasks
-            clearall-repeating, ca-rp               clear all repeating todo and done tasks
+            list, l                                 show the todo list. Pass --tag [tag] to only show the tasks w/ that tag
+                                                    example: chartodo list --tag backend
+            search, s                               find tasks in every list and the archive by their text, ignoring case. Pass --regex to use a regex
+                                                    format: chartodo search [text]
+                                                    example: chartodo search --regex '^buy-'
+            clearall, ca                            clear everything (TODO, DEADLINE, REPEATING). Pass --archive to archive the done items instead of deleting them
+            clearall-regular, ca-r                  clear all regular todo and done tasks. Pass --archive to archive the done items instead of deleting them
+            clearall-deadline, ca-d                 clear all deadline todo and done tasks. Pass --archive to archive the done items instead of deleting them
+            clearall-repeating, ca-rp               clear all repeating todo and done tasks. Pass --archive to archive the done items instead of deleting them
+            undo, u                                 undo the last command that changed a list. Up to 20 commands can be undone
+            redo                                    redo the last command that was undone
+            history                                 show what's happened to tasks, e.g., when each one was done, oldest first. Done lists get cleared, but this doesn't
+                                                    format: chartodo history --from [date] --to [date]
+                                                    example: chartodo history --from -7d
+            archive-list, ar-l                      show the done tasks that were archived, by kind of task
+            archive-restore, ar-r                   move archived tasks back to their done list, using the positions archive-list shows. Has chaining and range positioning
+                                                    format: chartodo archive-restore [regular/deadline/repeating] [position]
+                                                    example: chartodo ar-r deadline 1 3-4
+            init                                    start empty lists, or a copy of a template w/ --template [file]. Won't touch lists that are already there
+                                                    format: chartodo init --template [file]
+                                                    example: chartodo init --template team.json
+            config get, config set                  show or change a setting in config.toml, or show every setting w/ config get
+                                                    format: chartodo config set [setting] [value]
+                                                    example: chartodo config set display.table_style ascii
+            completions [shell]                     print a tab-completion script for bash, zsh, fish, elvish, or powershell
 
         REGULAR TODO:
             add, a                                  add an item to the todo list. To add a multi-word item, replace space with something like -. Has chaining
@@ -1762,10 +1727,19 @@ mod main_help {
                                                     example: chartodo rmt 4 1 5 11-15
             doneall, da                             change all todo items to done
             cleartodo, ct                           clear the todo list
-            clearboth, cb                           clear both todo and done lists
+            clearboth, cb                           clear both todo and done lists. Pass --archive to archive the done items instead of deleting them
             edit, e                                 changes a todo item, with its position specified, to what you want
                                                     format: chartoo edit [position] [new task]
                                                     example: chartodo edit 3 change-item-to-this
+            edit-priority, ep                       changes the priority of a todo item to high, medium, low, or none
+                                                    format: chartodo edit-priority [position] [priority]
+                                                    example: chartodo ep 3 high
+            tag, tg                                 add tags to a todo item
+                                                    format: chartodo tag [position] [tag] [...]
+                                                    example: chartodo tg 3 backend @errands
+            untag, utg                              take tags away from a todo item. A tag w/o + or @ takes away both, e.g., backend takes away +backend and @backend
+                                                    format: chartodo untag [position] [tag] [...]
+                                                    example: chartodo utg 3 backend
 
         REGULAR DONE:
             notdone, nd                             reverses a done item back to a todo item using numbered positions. Has chaining and range positioning
@@ -1775,7 +1749,10 @@ mod main_help {
                                                     format: chartodo rmdone [position]
                                                     example: chartodo rmd 4 5-8
             notdoneall, nda                         reverses all done items back to todo
-            cleardone, cd                           clears the done list
+            cleardone, cd                           clears the done list. Pass --archive to archive the done items instead of deleting them
+            archive, ar                             moves a done item to the archive using numbered positions. Has chaining and range positioning
+                                                    format: chartodo archive [position]
+                                                    example: chartodo ar 1 3-5
 
         DEADLINE TODO:
             deadline-add, dl-a                      adds a task with a day and time limit. Has chaining
@@ -1797,7 +1774,7 @@ mod main_help {
                                                     example: chartodo dl-rmt 1 5-9
             deadline-doneall, dl-da                 mark the entire deadline todo list as done
             deadline-cleartodo, deadline-ct         clear the deadline todo list
-            deadline-clearboth, dl-cb               clears both of the deadline todo and done lists
+            deadline-clearboth, dl-cb               clears both of the deadline todo and done lists. Pass --archive to archive the done items instead of deleting them
             deadline-editall, dl-ea                 edit all the parameters of a deadline todo task
                                                     format: chartodo deadline-editall [position] [new deadline task] [new ending date] [new ending time]
                                                     example: chartodo dl-ea 1 new-item 2150-01-01 00:00
@@ -1813,6 +1790,15 @@ mod main_help {
             deadline-editdatetime, dl-edt           edit the date and time parameter of a deadline todo task
                                                     format: chartodo deadline-editdatetime [position] [new ending date] [new ending time]
                                                     example: chartodo dl-edt 1 2100-01-01 13:00
+            deadline-editpriority, dl-ep            edit the priority of a deadline todo task to high, medium, low, or none. Tasks due at the same time are sorted by priority
+                                                    format: chartodo deadline-editpriority [position] [priority]
+                                                    example: chartodo dl-ep 1 low
+            deadline-tag, dl-tg                     add tags to a deadline todo task
+                                                    format: chartodo deadline-tag [position] [tag] [...]
+                                                    example: chartodo dl-tg 1 backend @errands
+            deadline-untag, dl-utg                  take tags away from a deadline todo task. A tag w/o + or @ takes away both, e.g., backend takes away +backend and @backend
+                                                    format: chartodo deadline-untag [position] [tag] [...]
+                                                    example: chartodo dl-utg 1 backend
 
         DEADLINE DONE:
             deadline-notdone, dl-nd                 reverses a deadline done item back to todo using numbered positions. Has chaining and range positioning
@@ -1822,10 +1808,16 @@ mod main_help {
                                                     format: chartodo deadline-rmdone [position]
                                                     example: chartodo dl-rmd 3 2 1 5-7
             deadline-notdoneall, dl-nda             reverses all deadline done items back to todo
-            deadline-cleardone, dl-cd               clears the deadline done list
+            deadline-cleardone, dl-cd               clears the deadline done list. Pass --archive to archive the done items instead of deleting them
+            deadline-archive, dl-ar                 moves a deadline done item to the archive using numbered positions. Has chaining and range positioning
+                                                    format: chartodo deadline-archive [position]
+                                                    example: chartodo dl-ar 2 4-6
 
         REPEATING TODO:
         note: Only the following time units are allowed in repeating tasks: minutes, hours, days, weeks, months, years
+        note: Repeating add commands take --on [rule] to only land on certain days: mon,wed,fri, weekdays, or weekends w/ weeks, and '2nd tue', 'last fri', 'last day', or 'last business day' w/ months
+        note: Repeating add commands also take --catch-up [once/next/each] for the times a task was missed: once is due again once, next skips to the next time still ahead, each also adds a deadline todo per missed time
+        note: Repeating add commands also take --until [date] and --times [n] to end the series after that date or that many times. A finished series stays done, and rp-s/rp-sa show how many times are left
             repeating-add, rp-a                     add a repeating task with a set interval. the task starts from your current date and time. Has chaining
                                                     format: chartodo repeating-add [repeating task] [interval] [time unit]
                                                     example: chartodo rp-a gym 2 days
@@ -1833,6 +1825,7 @@ mod main_help {
             repeating-addstart, rp-as               add a repeating task that starts on your specified datetime. Has chaining
                                                     format: chartodo repeating-addstart [repeating task] [interval] [time unit] [starting date] [starting time]
                                                     example: chartodo rp-as task 3 days 2099-01-01 00:00
+                                                    example: chartodo rp-as standup 1 week 2099-01-05 09:00 --on mon,wed,fri
             repeating-addend, rp-ae                 add a repeating task that ends on your specified datetime. Has chaining
                                                     format: chartodo repeating-addend [repeating task] [interval] [time unit] [ending date] [ending time]
                                                     example: chartodo rp-ae task 3 days 2099-01-01 00:00
@@ -1854,7 +1847,7 @@ mod main_help {
                                                     example: chartodo rp-s 1 3-5
             repeating-doneall, rp-da                mark all repeating tasks as done
             repeating-cleartodo, rp-ct              delete all of the repeating todo tasks
-            repeating-clearboth, rp-cb              clear the repeating todo and done lists
+            repeating-clearboth, rp-cb              clear the repeating todo and done lists. Pass --archive to archive the done items instead of deleting them
             repeating-resetall, repeating-doneresetall, rp-ra, rp-dra
                                                     resets the starting datetime of all repeating tasks to your current date and time
             repeating-startall, rp-sa               show the starting datetime of all repeating tasks
@@ -1881,6 +1874,21 @@ mod main_help {
             repeating-editend, rp-ee                edit the ending datetime of a repeating task
                                                     format: chartodo repeating-editend [position] [ending date] [ending time]
                                                     example: chartodo rp-ee 1 2100-12-24 13:08
+            repeating-editon, rp-eo                 edit which days a repeating task lands on, or none to go back to every interval. The rule has to go w/ its time unit, see --on
+                                                    format: chartodo repeating-editon [position] [rule]
+                                                    example: chartodo rp-eo 1 'last business day'
+            repeating-editcatchup, rp-ec            edit what a repeating task does about the times it was missed: once, next, or each. See --catch-up
+                                                    format: chartodo repeating-editcatchup [position] [catch-up]
+                                                    example: chartodo rp-ec 1 next
+            repeating-editpriority, rp-ep           edit the priority of a repeating task to high, medium, low, or none. Tasks due at the same time are sorted by priority
+                                                    format: chartodo repeating-editpriority [position] [priority]
+                                                    example: chartodo rp-ep 1 medium
+            repeating-tag, rp-tg                    add tags to a repeating task
+                                                    format: chartodo repeating-tag [position] [tag] [...]
+                                                    example: chartodo rp-tg 1 backend @errands
+            repeating-untag, rp-utg                 take tags away from a repeating task. A tag w/o + or @ takes away both, e.g., backend takes away +backend and @backend
+                                                    format: chartodo repeating-untag [position] [tag] [...]
+                                                    example: chartodo rp-utg 1 backend
 
         REPEATING DONE:
             repeating-notdone, rp-nd                reverse repeating dones back to todo. Has chaining and range positioning
@@ -1890,9 +1898,14 @@ mod main_help {
                                                     format: chartodo repeating-rmdone [position]
                                                     example: chartodo rp-rmd 1 3-5
             repeating-notdoneall, rp-nda            reverse all finished repeating tasks back to todo
-            repeating-cleardone, rp-cd              delete all of the finished repeating tasks
+            repeating-cleardone, rp-cd              delete all of the finished repeating tasks. Pass --archive to archive them instead of deleting them
+            repeating-archive, rp-ar                moves a finished repeating task to the archive. Has chaining and range positioning
+                                                    format: chartodo repeating-archive [position]
+                                                    example: chartodo rp-ar 1 3-5
 "));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -1902,30 +1915,40 @@ mod main_regular_help {
 
     #[test]
     fn regular_help_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("regular_help_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("regular-help").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn regular_help_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("regular_help_abrev_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("r-h").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn regular_help_prints_correctly() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_help_prints_correctly");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("regular-help");
         cmd.assert()
             .success()
@@ -1944,10 +1967,19 @@ mod main_regular_help {
                                 example: chartodo rmt 4 1 5 11-15
             doneall, da         change all todo items to done
             cleartodo, ct       clear the todo list
-            clearboth, cb       clear both todo and done lists
+            clearboth, cb       clear both todo and done lists. Pass --archive to archive the done items instead of deleting them
             edit, e             changes a todo item, with its position specified, to what you want
                                 format: chartoo edit [position] [new task]
                                 example: chartodo edit 3 change-item-to-this
+            edit-priority, ep   changes the priority of a todo item to high, medium, low, or none
+                                format: chartodo edit-priority [position] [priority]
+                                example: chartodo ep 3 high
+            tag, tg             add tags to a todo item
+                                format: chartodo tag [position] [tag] [...]
+                                example: chartodo tg 3 backend @errands
+            untag, utg          take tags away from a todo item. A tag w/o + or @ takes away both, e.g., backend takes away +backend and @backend
+                                format: chartodo untag [position] [tag] [...]
+                                example: chartodo utg 3 backend
 
         REGULAR DONE:
             notdone, nd         reverses a done item back to a todo item using numbered positions. Has chaining and range positioning
@@ -1957,16 +1989,23 @@ mod main_regular_help {
                                 format: chartodo rmdone [position]
                                 example: chartodo rmd 4 5-8
             notdoneall, nda     reverses all done items back to todo
-            cleardone, cd       clears the done list
+            cleardone, cd       clears the done list. Pass --archive to archive the done items instead of deleting them
+            archive, ar         moves a done item to the archive using numbered positions. Has chaining and range positioning
+                                format: chartodo archive [position]
+                                example: chartodo ar 1 3-5
 "));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_help_abrev_prints_correctly() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_help_abrev_prints_correctly");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("r-h");
         cmd.assert()
             .success()
@@ -1985,10 +2024,19 @@ mod main_regular_help {
                                 example: chartodo rmt 4 1 5 11-15
             doneall, da         change all todo items to done
             cleartodo, ct       clear the todo list
-            clearboth, cb       clear both todo and done lists
+            clearboth, cb       clear both todo and done lists. Pass --archive to archive the done items instead of deleting them
             edit, e             changes a todo item, with its position specified, to what you want
                                 format: chartoo edit [position] [new task]
                                 example: chartodo edit 3 change-item-to-this
+            edit-priority, ep   changes the priority of a todo item to high, medium, low, or none
+                                format: chartodo edit-priority [position] [priority]
+                                example: chartodo ep 3 high
+            tag, tg             add tags to a todo item
+                                format: chartodo tag [position] [tag] [...]
+                                example: chartodo tg 3 backend @errands
+            untag, utg          take tags away from a todo item. A tag w/o + or @ takes away both, e.g., backend takes away +backend and @backend
+                                format: chartodo untag [position] [tag] [...]
+                                example: chartodo utg 3 backend
 
         REGULAR DONE:
             notdone, nd         reverses a done item back to a todo item using numbered positions. Has chaining and range positioning
@@ -1998,9 +2046,14 @@ mod main_regular_help {
                                 format: chartodo rmdone [position]
                                 example: chartodo rmd 4 5-8
             notdoneall, nda     reverses all done items back to todo
-            cleardone, cd       clears the done list
+            cleardone, cd       clears the done list. Pass --archive to archive the done items instead of deleting them
+            archive, ar         moves a done item to the archive using numbered positions. Has chaining and range positioning
+                                format: chartodo archive [position]
+                                example: chartodo ar 1 3-5
 "));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -2010,36 +2063,47 @@ mod main_deadline_help {
 
     #[test]
     fn deadline_help_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("deadline_help_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-help").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn deadline_help_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("deadline_help_abrev_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-h").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn deadline_help_prints_correctly() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("deadline_help_prints_correctly");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("deadline-help");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("
-        Date format is always in year-month-day, e.g., 2099-12-25
-        Time format is always in a 24-hour format, e.g., 13:58. Note that there is no space between hour and minute
+        Date format is year-month-day, e.g., 2099-12-25, or today, tomorrow, a weekday (fri, 'next monday'), or from now (+3d, 'in 2w')
+        Time format is a 24-hour format, e.g., 13:58, or noon, midnight, or eod. Note that there is no space between hour and minute
+        A date can have the time in it, e.g., 2099-12-25T08:00, eod, +2h, or 'in 30min'. That time is used if the command doesn't take one
 
         DEADLINE TODO:
             deadline-add, dl-a                      adds a task with a day and time limit. Has chaining
@@ -2061,7 +2125,7 @@ mod main_deadline_help {
                                                     example: chartodo dl-rmt 1 5-9
             deadline-doneall, dl-da                 mark the entire deadline todo list as done
             deadline-cleartodo, deadline-ct         clear the deadline todo list
-            deadline-clearboth, dl-cb               clears both of the deadline todo and done lists
+            deadline-clearboth, dl-cb               clears both of the deadline todo and done lists. Pass --archive to archive the done items instead of deleting them
             deadline-editall, dl-ea                 edit all the parameters of a deadline todo task
                                                     format: chartodo deadline-editall [position] [new deadline task] [new ending date] [new ending time]
                                                     example: chartodo dl-ea 1 new-item 2150-01-01 00:00
@@ -2077,6 +2141,15 @@ mod main_deadline_help {
             deadline-editdatetime, dl-edt           edit the date and time parameter of a deadline todo task
                                                     format: chartodo deadline-editdatetime [position] [new ending date] [new ending time]
                                                     example: chartodo dl-edt 1 2100-01-01 13:00
+            deadline-editpriority, dl-ep            edit the priority of a deadline todo task to high, medium, low, or none. Tasks due at the same time are sorted by priority
+                                                    format: chartodo deadline-editpriority [position] [priority]
+                                                    example: chartodo dl-ep 1 low
+            deadline-tag, dl-tg                     add tags to a deadline todo task
+                                                    format: chartodo deadline-tag [position] [tag] [...]
+                                                    example: chartodo dl-tg 1 backend @errands
+            deadline-untag, dl-utg                  take tags away from a deadline todo task. A tag w/o + or @ takes away both, e.g., backend takes away +backend and @backend
+                                                    format: chartodo deadline-untag [position] [tag] [...]
+                                                    example: chartodo dl-utg 1 backend
 
         DEADLINE DONE:
             deadline-notdone, dl-nd                 reverses a deadline done item back to todo using numbered positions. Has chaining and range positioning
@@ -2086,22 +2159,30 @@ mod main_deadline_help {
                                                     format: chartodo deadline-rmdone [position]
                                                     example: chartodo dl-rmd 3 2 1 5-7
             deadline-notdoneall, dl-nda             reverses all deadline done items back to todo
-            deadline-cleardone, dl-cd               clears the deadline done list
+            deadline-cleardone, dl-cd               clears the deadline done list. Pass --archive to archive the done items instead of deleting them
+            deadline-archive, dl-ar                 moves a deadline done item to the archive using numbered positions. Has chaining and range positioning
+                                                    format: chartodo deadline-archive [position]
+                                                    example: chartodo dl-ar 2 4-6
 "));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn deadline_help_abrev_prints_correctly() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("deadline_help_abrev_prints_correctly");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("dl-h");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("
-        Date format is always in year-month-day, e.g., 2099-12-25
-        Time format is always in a 24-hour format, e.g., 13:58. Note that there is no space between hour and minute
+        Date format is year-month-day, e.g., 2099-12-25, or today, tomorrow, a weekday (fri, 'next monday'), or from now (+3d, 'in 2w')
+        Time format is a 24-hour format, e.g., 13:58, or noon, midnight, or eod. Note that there is no space between hour and minute
+        A date can have the time in it, e.g., 2099-12-25T08:00, eod, +2h, or 'in 30min'. That time is used if the command doesn't take one
 
         DEADLINE TODO:
             deadline-add, dl-a                      adds a task with a day and time limit. Has chaining
@@ -2123,7 +2204,7 @@ mod main_deadline_help {
                                                     example: chartodo dl-rmt 1 5-9
             deadline-doneall, dl-da                 mark the entire deadline todo list as done
             deadline-cleartodo, deadline-ct         clear the deadline todo list
-            deadline-clearboth, dl-cb               clears both of the deadline todo and done lists
+            deadline-clearboth, dl-cb               clears both of the deadline todo and done lists. Pass --archive to archive the done items instead of deleting them
             deadline-editall, dl-ea                 edit all the parameters of a deadline todo task
                                                     format: chartodo deadline-editall [position] [new deadline task] [new ending date] [new ending time]
                                                     example: chartodo dl-ea 1 new-item 2150-01-01 00:00
@@ -2139,6 +2220,15 @@ mod main_deadline_help {
             deadline-editdatetime, dl-edt           edit the date and time parameter of a deadline todo task
                                                     format: chartodo deadline-editdatetime [position] [new ending date] [new ending time]
                                                     example: chartodo dl-edt 1 2100-01-01 13:00
+            deadline-editpriority, dl-ep            edit the priority of a deadline todo task to high, medium, low, or none. Tasks due at the same time are sorted by priority
+                                                    format: chartodo deadline-editpriority [position] [priority]
+                                                    example: chartodo dl-ep 1 low
+            deadline-tag, dl-tg                     add tags to a deadline todo task
+                                                    format: chartodo deadline-tag [position] [tag] [...]
+                                                    example: chartodo dl-tg 1 backend @errands
+            deadline-untag, dl-utg                  take tags away from a deadline todo task. A tag w/o + or @ takes away both, e.g., backend takes away +backend and @backend
+                                                    format: chartodo deadline-untag [position] [tag] [...]
+                                                    example: chartodo dl-utg 1 backend
 
         DEADLINE DONE:
             deadline-notdone, dl-nd                 reverses a deadline done item back to todo using numbered positions. Has chaining and range positioning
@@ -2148,9 +2238,14 @@ mod main_deadline_help {
                                                     format: chartodo deadline-rmdone [position]
                                                     example: chartodo dl-rmd 3 2 1 5-7
             deadline-notdoneall, dl-nda             reverses all deadline done items back to todo
-            deadline-cleardone, dl-cd               clears the deadline done list
+            deadline-cleardone, dl-cd               clears the deadline done list. Pass --archive to archive the done items instead of deleting them
+            deadline-archive, dl-ar                 moves a deadline done item to the archive using numbered positions. Has chaining and range positioning
+                                                    format: chartodo deadline-archive [position]
+                                                    example: chartodo dl-ar 2 4-6
 "));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -2160,37 +2255,51 @@ mod main_repeating_help {
 
     #[test]
     fn repeating_help_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("repeating_help_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-help").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn repeating_help_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("repeating_help_abrev_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-h").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn repeating_help_prints_correctly() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("repeating_help_prints_correctly");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-help");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("
-        Date format is always in year-month-day, e.g., 2099-12-25
-        Time format is always in a 24-hour format, e.g., 13:58. Note that there is no space between hour and minute
+        Date format is year-month-day, e.g., 2099-12-25, or today, tomorrow, a weekday (fri, 'next monday'), or from now (+3d, 'in 2w')
+        Time format is a 24-hour format, e.g., 13:58, or noon, midnight, or eod. Note that there is no space between hour and minute
+        A date can have the time in it, e.g., 2099-12-25T08:00, eod, +2h, or 'in 30min'. That time is used if the command doesn't take one
         Only the following time units are allowed in repeating tasks: minutes, hours, days, weeks, months, years
+        Repeating add commands take --on [rule] to only land on certain days: mon,wed,fri, weekdays, or weekends w/ weeks, and '2nd tue', 'last fri', 'last day', or 'last business day' w/ months
+        Repeating add commands also take --catch-up [once/next/each] for the times a task was missed: once is due again once, next skips to the next time still ahead, each also adds a deadline todo per missed time
+        Repeating add commands also take --until [date] and --times [n] to end the series after that date or that many times. A finished series stays done, and rp-s/rp-sa show how many times are left
 
         REPEATING TODO:
             repeating-add, rp-a                     add a repeating task with a set interval. the task starts from your current date and time. Has chaining
@@ -2200,6 +2309,7 @@ mod main_repeating_help {
             repeating-addstart, rp-as               add a repeating task that starts on your specified datetime. Has chaining
                                                     format: chartodo repeating-addstart [repeating task] [interval] [time unit] [starting date] [starting time]
                                                     example: chartodo rp-as task 3 days 2099-01-01 00:00
+                                                    example: chartodo rp-as standup 1 week 2099-01-05 09:00 --on mon,wed,fri
             repeating-addend, rp-ae                 add a repeating task that ends on your specified datetime. Has chaining
                                                     format: chartodo repeating-addend [repeating task] [interval] [time unit] [ending date] [ending time]
                                                     example: chartodo rp-ae task 3 days 2099-01-01 00:00
@@ -2221,7 +2331,7 @@ mod main_repeating_help {
                                                     example: chartodo rp-s 1 3-5
             repeating-doneall, rp-da                mark all repeating tasks as done
             repeating-cleartodo, rp-ct              delete all of the repeating todo tasks
-            repeating-clearboth, rp-cb              clear the repeating todo and done lists
+            repeating-clearboth, rp-cb              clear the repeating todo and done lists. Pass --archive to archive the done items instead of deleting them
             repeating-resetall, repeating-doneresetall, rp-ra, rp-dra
                                                     resets the starting datetime of all repeating tasks to your current date and time
             repeating-startall, rp-sa               show the starting datetime of all repeating tasks
@@ -2248,6 +2358,21 @@ mod main_repeating_help {
             repeating-editend, rp-ee                edit the ending datetime of a repeating task
                                                     format: chartodo repeating-editend [position] [ending date] [ending time]
                                                     example: chartodo rp-ee 1 2100-12-24 13:08
+            repeating-editon, rp-eo                 edit which days a repeating task lands on, or none to go back to every interval. The rule has to go w/ its time unit, see --on
+                                                    format: chartodo repeating-editon [position] [rule]
+                                                    example: chartodo rp-eo 1 'last business day'
+            repeating-editcatchup, rp-ec            edit what a repeating task does about the times it was missed: once, next, or each. See --catch-up
+                                                    format: chartodo repeating-editcatchup [position] [catch-up]
+                                                    example: chartodo rp-ec 1 next
+            repeating-editpriority, rp-ep           edit the priority of a repeating task to high, medium, low, or none. Tasks due at the same time are sorted by priority
+                                                    format: chartodo repeating-editpriority [position] [priority]
+                                                    example: chartodo rp-ep 1 medium
+            repeating-tag, rp-tg                    add tags to a repeating task
+                                                    format: chartodo repeating-tag [position] [tag] [...]
+                                                    example: chartodo rp-tg 1 backend @errands
+            repeating-untag, rp-utg                 take tags away from a repeating task. A tag w/o + or @ takes away both, e.g., backend takes away +backend and @backend
+                                                    format: chartodo repeating-untag [position] [tag] [...]
+                                                    example: chartodo rp-utg 1 backend
 
         REPEATING DONE:
             repeating-notdone, rp-nd                reverse repeating dones back to todo. Has chaining and range positioning
@@ -2257,23 +2382,34 @@ mod main_repeating_help {
                                                     format: chartodo repeating-rmdone [position]
                                                     example: chartodo rp-rmd 1 3-5
             repeating-notdoneall, rp-nda            reverse all finished repeating tasks back to todo
-            repeating-cleardone, rp-cd              delete all of the finished repeating tasks
+            repeating-cleardone, rp-cd              delete all of the finished repeating tasks. Pass --archive to archive them instead of deleting them
+            repeating-archive, rp-ar                moves a finished repeating task to the archive. Has chaining and range positioning
+                                                    format: chartodo repeating-archive [position]
+                                                    example: chartodo rp-ar 1 3-5
 "));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn repeating_help_abrev_prints_correctly() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("repeating_help_abrev_prints_correctly");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-h");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("
-        Date format is always in year-month-day, e.g., 2099-12-25
-        Time format is always in a 24-hour format, e.g., 13:58. Note that there is no space between hour and minute
+        Date format is year-month-day, e.g., 2099-12-25, or today, tomorrow, a weekday (fri, 'next monday'), or from now (+3d, 'in 2w')
+        Time format is a 24-hour format, e.g., 13:58, or noon, midnight, or eod. Note that there is no space between hour and minute
+        A date can have the time in it, e.g., 2099-12-25T08:00, eod, +2h, or 'in 30min'. That time is used if the command doesn't take one
         Only the following time units are allowed in repeating tasks: minutes, hours, days, weeks, months, years
+        Repeating add commands take --on [rule] to only land on certain days: mon,wed,fri, weekdays, or weekends w/ weeks, and '2nd tue', 'last fri', 'last day', or 'last business day' w/ months
+        Repeating add commands also take --catch-up [once/next/each] for the times a task was missed: once is due again once, next skips to the next time still ahead, each also adds a deadline todo per missed time
+        Repeating add commands also take --until [date] and --times [n] to end the series after that date or that many times. A finished series stays done, and rp-s/rp-sa show how many times are left
 
         REPEATING TODO:
             repeating-add, rp-a                     add a repeating task with a set interval. the task starts from your current date and time. Has chaining
@@ -2283,6 +2419,7 @@ mod main_repeating_help {
             repeating-addstart, rp-as               add a repeating task that starts on your specified datetime. Has chaining
                                                     format: chartodo repeating-addstart [repeating task] [interval] [time unit] [starting date] [starting time]
                                                     example: chartodo rp-as task 3 days 2099-01-01 00:00
+                                                    example: chartodo rp-as standup 1 week 2099-01-05 09:00 --on mon,wed,fri
             repeating-addend, rp-ae                 add a repeating task that ends on your specified datetime. Has chaining
                                                     format: chartodo repeating-addend [repeating task] [interval] [time unit] [ending date] [ending time]
                                                     example: chartodo rp-ae task 3 days 2099-01-01 00:00
@@ -2304,7 +2441,7 @@ mod main_repeating_help {
                                                     example: chartodo rp-s 1 3-5
             repeating-doneall, rp-da                mark all repeating tasks as done
             repeating-cleartodo, rp-ct              delete all of the repeating todo tasks
-            repeating-clearboth, rp-cb              clear the repeating todo and done lists
+            repeating-clearboth, rp-cb              clear the repeating todo and done lists. Pass --archive to archive the done items instead of deleting them
             repeating-resetall, repeating-doneresetall, rp-ra, rp-dra
                                                     resets the starting datetime of all repeating tasks to your current date and time
             repeating-startall, rp-sa               show the starting datetime of all repeating tasks
@@ -2331,6 +2468,21 @@ mod main_repeating_help {
             repeating-editend, rp-ee                edit the ending datetime of a repeating task
                                                     format: chartodo repeating-editend [position] [ending date] [ending time]
                                                     example: chartodo rp-ee 1 2100-12-24 13:08
+            repeating-editon, rp-eo                 edit which days a repeating task lands on, or none to go back to every interval. The rule has to go w/ its time unit, see --on
+                                                    format: chartodo repeating-editon [position] [rule]
+                                                    example: chartodo rp-eo 1 'last business day'
+            repeating-editcatchup, rp-ec            edit what a repeating task does about the times it was missed: once, next, or each. See --catch-up
+                                                    format: chartodo repeating-editcatchup [position] [catch-up]
+                                                    example: chartodo rp-ec 1 next
+            repeating-editpriority, rp-ep           edit the priority of a repeating task to high, medium, low, or none. Tasks due at the same time are sorted by priority
+                                                    format: chartodo repeating-editpriority [position] [priority]
+                                                    example: chartodo rp-ep 1 medium
+            repeating-tag, rp-tg                    add tags to a repeating task
+                                                    format: chartodo repeating-tag [position] [tag] [...]
+                                                    example: chartodo rp-tg 1 backend @errands
+            repeating-untag, rp-utg                 take tags away from a repeating task. A tag w/o + or @ takes away both, e.g., backend takes away +backend and @backend
+                                                    format: chartodo repeating-untag [position] [tag] [...]
+                                                    example: chartodo rp-utg 1 backend
 
         REPEATING DONE:
             repeating-notdone, rp-nd                reverse repeating dones back to todo. Has chaining and range positioning
@@ -2340,9 +2492,14 @@ mod main_repeating_help {
                                                     format: chartodo repeating-rmdone [position]
                                                     example: chartodo rp-rmd 1 3-5
             repeating-notdoneall, rp-nda            reverse all finished repeating tasks back to todo
-            repeating-cleardone, rp-cd              delete all of the finished repeating tasks
+            repeating-cleardone, rp-cd              delete all of the finished repeating tasks. Pass --archive to archive them instead of deleting them
+            repeating-archive, rp-ar                moves a finished repeating task to the archive. Has chaining and range positioning
+                                                    format: chartodo repeating-archive [position]
+                                                    example: chartodo rp-ar 1 3-5
 "));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -2352,11 +2509,18 @@ mod main_empty {
 
     #[test]
     fn main_no_args_given() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("main_no_args_given");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "You must provide a command. Try chartodo help.",
-        ));
+        cmd.assert()
+            .failure()
+            .code(2)
+            .stderr(predicate::str::contains(
+                "You must provide a command. Try chartodo help.",
+            ));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
@@ -2367,60 +2531,19 @@ mod main_wrongcommand {
 
     #[test]
     fn main_wrong_command() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("ahahahahahahaha");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
-
-        Ok(())
-    }
-}
-
-mod zzz_do_this_last {
-    use super::*;
-
-    #[test]
-    fn zzzz_rename_regular_copy_to_original() {
-        // name is zzzz so it's done last
-        // now that tests are done, remove the modified original and rename copy to original
-
-        std::fs::remove_file(path_to_regular_tasks())
-            .context("failed delete modified regular_tasks.json after running tests")
-            .expect("failed to delete regular_tasks.json after regular_helpers unit tests");
+        let data_dir = fresh_data_dir("main_wrong_command");
 
-        std::fs::rename(regular_tasks_copy_path(), path_to_regular_tasks())
-            .context("failed to rename regular_tasks_copy to regular_tasks")
-            .expect("failed to rename regular_tasks_copy to regular_tasks after tests were done");
-    }
-
-    #[test]
-    fn zzzz_rename_deadline_copy_to_original() {
-        // name is zzzz so it's done last
-        // now that tests are done, remove the modified original and rename copy to original
-
-        std::fs::remove_file(path_to_deadline_tasks())
-            .context("failed delete modified deadline_tasks.json after running tests")
-            .expect("failed to delete deadline_tasks.json after deadline_helpers unit tests");
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("ahahahahahahaha");
+        cmd.assert()
+            .failure()
+            .code(2)
+            .stderr(predicate::str::contains(
+                "Invalid command. Please try again, or try chartodo help",
+            ));
 
-        std::fs::rename(deadline_tasks_copy_path(), path_to_deadline_tasks())
-            .context("failed to rename deadline_tasks_copy to deadline_tasks")
-            .expect("failed to rename deadline_tasks_copy to deadline_tasks after tests were done");
-    }
+        std::fs::remove_dir_all(&data_dir)?;
 
-    #[test]
-    fn zzzz_rename_repeating_copy_to_original() {
-        // name is zzzz so it's done last
-        // now that tests are done, remove the modified original and rename copy to original
-
-        std::fs::remove_file(path_to_repeating_tasks())
-            .context("failed delete modified repeating_tasks.json after running tests")
-            .expect("failed to delete repeating_tasks.json after repeating_helpers unit tests");
-
-        std::fs::rename(repeating_tasks_copy_path(), path_to_repeating_tasks())
-            .context("failed to rename repeating_tasks_copy to repeating_tasks")
-            .expect(
-                "failed to rename repeating_tasks_copy to repeating_tasks after tests were done",
-            );
+        Ok(())
     }
 }
@@ -0,0 +1,121 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use serde_json::Value;
+use std::path::PathBuf;
+
+mod common;
+use common::*;
+
+// cargo test --test history_tests
+// every test points chartodo at its own folder in the temp dir, so these never touch the real chartodo files
+
+fn history_json(data_dir: &PathBuf, args: &[&str]) -> Vec<Value> {
+    let output = chartodo(data_dir, &[&["history", "--format", "json"], args].concat())
+        .output()
+        .expect("couldn't run chartodo");
+    assert!(output.status.success());
+    let result: Value =
+        serde_json::from_slice(&output.stdout).expect("chartodo didn't print a json object");
+    assert_eq!(result["ok"], true);
+
+    result["history"].as_array().unwrap().clone()
+}
+
+#[test]
+fn every_kind_of_task_is_kept() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("every_kind");
+
+    // the example tasks: regular #1, deadline #3, and repeating #4
+    chartodo(&data_dir, &["done", "1"]).assert().success();
+    chartodo(&data_dir, &["dl-d", "1"]).assert().success();
+    chartodo(&data_dir, &["rp-da"]).assert().success();
+
+    let history = history_json(&data_dir, &[]);
+    let completed: Vec<(&str, &str, u64)> = history
+        .iter()
+        .map(|event| {
+            (
+                event["event"].as_str().unwrap(),
+                event["list"].as_str().unwrap(),
+                event["id"].as_u64().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        completed,
+        vec![
+            ("completed", "regular", 1),
+            ("completed", "deadline", 3),
+            ("completed", "repeating", 4)
+        ]
+    );
+    assert_eq!(history[0]["scheduled"], Value::Null);
+    assert_eq!(history[1]["task"], "breathe-once-before-2099");
+    assert_eq!(history[1]["scheduled"], "2099-01-01 00:00");
+
+    // clearing the done lists doesn't take anything out of it
+    chartodo(&data_dir, &["ca"]).assert().success();
+    assert_eq!(history_json(&data_dir, &[]).len(), 3);
+
+    chartodo(&data_dir, &["history"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "completed deadline #3 breathe-once-before-2099, due 2099-01-01 00:00",
+        ));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn history_takes_a_date_range() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("range");
+    chartodo(&data_dir, &["done", "1"]).assert().success();
+
+    assert_eq!(history_json(&data_dir, &["--from", "today"]).len(), 1);
+    assert_eq!(
+        history_json(&data_dir, &["--from", "-7d", "--to", "today"]).len(),
+        1
+    );
+    assert!(history_json(&data_dir, &["--from", "tomorrow"]).is_empty());
+    assert!(history_json(&data_dir, &["--to", "yesterday"]).is_empty());
+
+    chartodo(&data_dir, &["history", "--from", "tomorrow"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Nothing has happened"));
+    chartodo(&data_dir, &["history", "--from", "someday"])
+        .assert()
+        .failure();
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn undo_takes_back_the_history_and_redo_puts_it_back() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("undo");
+    chartodo(&data_dir, &["done", "1"]).assert().success();
+    chartodo(&data_dir, &["dl-d", "1"]).assert().success();
+    chartodo(&data_dir, &["history"]).assert().success();
+    let history = history_json(&data_dir, &[]);
+    assert_eq!(history.len(), 2);
+
+    // history doesn't change the lists, so the undo is for dl-d
+    chartodo(&data_dir, &["undo"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Undid: chartodo dl-d 1"));
+    assert_eq!(history_json(&data_dir, &[]), history[..1]);
+
+    chartodo(&data_dir, &["redo"]).assert().success();
+    assert_eq!(history_json(&data_dir, &[]), history);
+
+    chartodo(&data_dir, &["undo"]).assert().success();
+    chartodo(&data_dir, &["undo"]).assert().success();
+    assert!(history_json(&data_dir, &[]).is_empty());
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
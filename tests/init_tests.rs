@@ -0,0 +1,131 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+mod common;
+use common::*;
+
+// cargo test --test init_tests
+// every test points chartodo at its own folder in the temp dir, so these never touch the real chartodo files
+
+#[test]
+fn new_lists_are_empty() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = missing_data_dir("empty");
+
+    chartodo(&data_dir, &["init"]).assert().success();
+    let chartodo_json = read_json(data_dir.join("chartodo.json"));
+    for kind in ["regular", "deadline", "repeating"] {
+        assert!(tasks_in(&chartodo_json[kind]["todo"]).is_empty());
+        assert!(tasks_in(&chartodo_json[kind]["done"]).is_empty());
+    }
+
+    // same for lists that are made on the first command
+    std::fs::remove_dir_all(&data_dir)?;
+    chartodo(&data_dir, &["list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("this is the todo list").not());
+    let chartodo_json = read_json(data_dir.join("chartodo.json"));
+    assert!(tasks_in(&chartodo_json["regular"]["todo"]).is_empty());
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn init_twice_is_an_error() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = missing_data_dir("twice");
+
+    chartodo(&data_dir, &["init"]).assert().success();
+    chartodo(&data_dir, &["add", "keep-me"]).assert().success();
+    let before = std::fs::read_to_string(data_dir.join("chartodo.json"))?;
+
+    chartodo(&data_dir, &["init"])
+        .assert()
+        .failure()
+        .code(5)
+        .stderr(predicate::str::contains("There are already tasks in"));
+    assert_eq!(
+        std::fs::read_to_string(data_dir.join("chartodo.json"))?,
+        before
+    );
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn init_copies_a_template() -> Result<(), Box<dyn std::error::Error>> {
+    let template_dir = missing_data_dir("template_source");
+    chartodo(&template_dir, &["add", "standup", "review"])
+        .assert()
+        .success();
+    chartodo(&template_dir, &["rp-a", "water-plants", "3", "days"])
+        .assert()
+        .success();
+
+    let data_dir = missing_data_dir("template");
+    let template = template_dir.join("chartodo.json");
+    chartodo(
+        &data_dir,
+        &["init", "--template", template.to_str().unwrap()],
+    )
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("standup").and(predicate::str::contains("water-plants")));
+
+    let chartodo_json = read_json(data_dir.join("chartodo.json"));
+    assert_eq!(
+        tasks_in(&chartodo_json["regular"]["todo"]),
+        vec!["standup", "review"]
+    );
+    assert_eq!(
+        tasks_in(&chartodo_json["repeating"]["todo"]),
+        vec!["water-plants"]
+    );
+
+    std::fs::remove_dir_all(&template_dir)?;
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn broken_template_is_an_error() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = missing_data_dir("broken_template");
+    std::fs::create_dir(&data_dir)?;
+    let template = data_dir.join("team.json");
+    std::fs::write(&template, "{ not json")?;
+
+    chartodo(
+        &data_dir,
+        &["init", "--template", template.to_str().unwrap()],
+    )
+    .assert()
+    .failure()
+    .code(1)
+    .stderr(predicate::str::contains("couldn't use the template"));
+    assert!(!data_dir.join("chartodo.json").exists());
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn broken_lists_are_not_replaced() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = missing_data_dir("broken_lists");
+    std::fs::create_dir(&data_dir)?;
+    std::fs::write(data_dir.join("chartodo.json"), "{ not json")?;
+
+    chartodo(&data_dir, &["init"])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains("couldn't parse"))
+        .stderr(predicate::str::contains("chartodo.json"));
+    assert_eq!(
+        std::fs::read_to_string(data_dir.join("chartodo.json"))?,
+        "{ not json"
+    );
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
@@ -0,0 +1,221 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use serde_json::Value;
+use std::{path::PathBuf, process::Command};
+
+mod common;
+use common::*;
+
+// cargo test --test json_output_tests
+// every test points chartodo at its own folder in the temp dir. a fresh folder starts w/ the example tasks, which
+// get the ids #1 to #4, so the first task a test adds is always #5
+
+// runs chartodo w/ --format json and parses what it printed, which should always be exactly one json object. an
+// error still exits w/ its own code, but nothing goes to stderr
+fn chartodo_json(data_dir: &PathBuf, args: &[&str]) -> Value {
+    // --format goes first, since anything after an unknown command is taken as part of it
+    let output = Command::cargo_bin("chartodo")
+        .expect("couldn't find chartodo binary")
+        .args(["--format", "json", "--data-dir"])
+        .arg(data_dir)
+        .args(args)
+        .output()
+        .expect("couldn't run chartodo");
+    assert!(output.stderr.is_empty());
+
+    let result: Value =
+        serde_json::from_slice(&output.stdout).expect("chartodo didn't print a json object");
+    assert_eq!(result["ok"], output.status.success());
+
+    result
+}
+
+#[test]
+fn list_shows_every_list_as_json() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("list");
+
+    let result = chartodo_json(&data_dir, &["list"]);
+
+    assert_eq!(result["ok"], true);
+    assert_eq!(
+        result["lists"]["regular"]["todo"][0]["task"],
+        "this is the todo list"
+    );
+    assert_eq!(result["lists"]["regular"]["todo"][0]["id"], 1);
+    assert_eq!(result["lists"]["regular"]["done"][0]["position"], 1);
+    assert_eq!(
+        result["lists"]["deadline"]["todo"][0]["due"],
+        "2099-01-01 00:00"
+    );
+    assert_eq!(result["lists"]["deadline"]["todo"][0]["status"], "due");
+    assert_eq!(result["lists"]["repeating"]["todo"][0]["interval"], 100);
+    assert_eq!(result["lists"]["repeating"]["todo"][0]["unit"], "years");
+    assert_eq!(
+        result["lists"]["repeating"]["todo"][0]["start"],
+        "2000-01-01 00:00"
+    );
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn commands_show_the_lists_as_json() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("commands");
+
+    let result = chartodo_json(
+        &data_dir,
+        &[
+            "dl-a",
+            "late",
+            "2000-01-01",
+            "00:00",
+            "-p",
+            "high",
+            "-t",
+            "work",
+        ],
+    );
+
+    // the missed task is sorted in front of the example one
+    let late = &result["lists"]["deadline"]["todo"][0];
+    assert_eq!(result["ok"], true);
+    assert_eq!(late["task"], "late");
+    assert_eq!(late["status"], "missed");
+    assert_eq!(late["priority"], "high");
+    assert_eq!(late["tags"], serde_json::json!(["+work"]));
+
+    let result = chartodo_json(&data_dir, &["list", "--tag", "work"]);
+    assert_eq!(result["lists"]["deadline"]["todo"][0]["task"], "late");
+    assert_eq!(result["lists"]["regular"]["todo"], serde_json::json!([]));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn errors_have_a_code() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("errors");
+
+    let cases = [
+        (vec!["done", "9"], "invalid_position"),
+        (vec!["done", "#3"], "invalid_id"),
+        (vec!["dl-a", "x", "2099-13-01", "00:00"], "invalid_date"),
+        (vec!["rp-a", "x", "1", "fortnight"], "invalid_time_unit"),
+        (vec!["redo"], "nothing_to_redo"),
+        (vec!["not-a-command"], "unknown_command"),
+    ];
+    for (args, code) in cases {
+        let result = chartodo_json(&data_dir, &args);
+
+        assert_eq!(result["ok"], false, "{:?}", args);
+        assert_eq!(result["error"]["code"], code, "{:?}", args);
+        assert!(!result["error"]["message"]
+            .as_str()
+            .unwrap()
+            .starts_with("ERROR"));
+        assert!(result.get("lists").is_none());
+    }
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn storage_errors_are_json_too() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("storage_errors");
+    std::fs::write(data_dir.join("chartodo.json"), "{ not json")?;
+
+    for args in [
+        vec!["list"],
+        vec!["add", "x"],
+        vec!["done", "1"],
+        vec!["rmtodo", "1"],
+    ] {
+        let result = chartodo_json(&data_dir, &args);
+
+        assert_eq!(result["error"]["code"], "storage", "{:?}", args);
+        assert!(result["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("chartodo.json"));
+    }
+    chartodo(&data_dir, &["--format", "json", "add", "x"])
+        .assert()
+        .code(1);
+    assert_eq!(
+        std::fs::read_to_string(data_dir.join("chartodo.json"))?,
+        "{ not json"
+    );
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn config_and_backend_errors_are_json_too() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("config_errors");
+    std::fs::write(
+        data_dir.join("config.toml"),
+        "[defaults]\nnot_a_setting = 1\n",
+    )?;
+
+    for args in [vec!["list"], vec!["add", "x"], vec!["config", "get"]] {
+        let result = chartodo_json(&data_dir, &args);
+
+        assert_eq!(result["error"]["code"], "storage", "{:?}", args);
+        assert!(result["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("config.toml"));
+    }
+
+    std::fs::write(data_dir.join("config.toml"), STARTER_TASKS)?;
+    let output = chartodo(&data_dir, &["--format", "json", "list"])
+        .env("CHARTODO_STORAGE", "floppy")
+        .assert()
+        .code(1)
+        .stderr("")
+        .get_output()
+        .stdout
+        .clone();
+    let result: Value = serde_json::from_slice(&output)?;
+    assert_eq!(result["error"]["code"], "storage");
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn notes_go_in_messages() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("messages");
+
+    chartodo(&data_dir, &["add", "oops"]).assert().success();
+    let result = chartodo_json(&data_dir, &["undo"]);
+
+    assert_eq!(result["ok"], true);
+    assert_eq!(
+        result["messages"],
+        serde_json::json!(["Undid: chartodo add oops"])
+    );
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn text_is_still_the_default() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("text");
+
+    chartodo(&data_dir, &["done", "9"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::starts_with("ERROR: "));
+    chartodo(&data_dir, &["list", "--format", "text"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("CHARTODO"));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
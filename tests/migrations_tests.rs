@@ -0,0 +1,128 @@
+use assert_cmd::prelude::*;
+use chartodo::functions::migrations::CURRENT_SCHEMA_VERSION;
+use predicates::prelude::*;
+
+mod common;
+use common::*;
+
+// cargo test --test migrations_tests
+// every test points chartodo at its own folder in the temp dir and fills it w/ files from an older chartodo
+
+#[test]
+fn three_file_layout_is_moved_into_chartodo_json() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("three_files");
+    std::fs::write(
+        data_dir.join("regular_tasks.json"),
+        r#"{"todo": [{"task": "old-regular"}], "done": []}"#,
+    )?;
+    std::fs::write(
+        data_dir.join("deadline_tasks.json"),
+        r#"{"todo": [{"task": "old-deadline", "date": "2099-01-01", "time": "00:00"}], "done": []}"#,
+    )?;
+    std::fs::write(
+        data_dir.join("repeating_tasks.json"),
+        r#"{"todo": [{"task": "old-repeating", "date": "2099-01-02", "time": "00:00", "repeat_number": 1,
+        "repeat_unit": "days", "repeat_done": false, "repeat_original_date": "2099-01-01",
+        "repeat_original_time": "00:00"}], "done": []}"#,
+    )?;
+
+    chartodo(&data_dir, &["list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("old-regular"))
+        .stdout(predicate::str::contains("old-deadline"))
+        .stdout(predicate::str::contains("old-repeating"));
+
+    assert!(!data_dir.join("regular_tasks.json").exists());
+    assert!(!data_dir.join("deadline_tasks.json").exists());
+    assert!(!data_dir.join("repeating_tasks.json").exists());
+    let chartodo_json = std::fs::read_to_string(data_dir.join("chartodo.json"))?;
+    assert!(chartodo_json.contains(&format!(r#""schema_version": {}"#, CURRENT_SCHEMA_VERSION)));
+    assert!(chartodo_json.contains(r#""next_id": 4"#));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn general_list_txt_is_moved_into_chartodo_json() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("general_list");
+    std::fs::write(
+        data_dir.join("general_list.txt"),
+        "ancient-todo\n-----\nancient-done\n",
+    )?;
+
+    chartodo(&data_dir, &["list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("ancient-todo"))
+        .stdout(predicate::str::contains("ancient-done"));
+
+    assert!(!data_dir.join("general_list.txt").exists());
+    assert!(data_dir.join("chartodo.json").exists());
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn newer_schema_version_is_left_alone() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("newer_version");
+    let from_the_future = r#"{"schema_version": 999, "something_new": []}"#;
+    std::fs::write(data_dir.join("chartodo.json"), from_the_future)?;
+
+    chartodo(&data_dir, &["add", "should-not-be-added"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("newer version of chartodo"));
+
+    // not moved aside and not overwritten
+    assert_eq!(
+        std::fs::read_to_string(data_dir.join("chartodo.json"))?,
+        from_the_future
+    );
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn missing_schema_version_is_reported() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("missing_version");
+    std::fs::write(
+        data_dir.join("chartodo.json"),
+        r#"{"regular": {"todo": [], "done": []}}"#,
+    )?;
+
+    chartodo(&data_dir, &["list"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("there is no schema_version"))
+        .stderr(predicate::str::contains("chartodo init"));
+    assert!(data_dir.join("chartodo.json").exists());
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn malformed_task_is_reported() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("malformed_task");
+    std::fs::write(
+        data_dir.join("chartodo.json"),
+        r#"{"schema_version": 2, "regular": {"todo": [], "done": []},
+        "deadline": {"todo": [{"task": "no-time", "date": "2099-01-01"}], "done": []},
+        "repeating": {"todo": [], "done": []}}"#,
+    )?;
+
+    chartodo(&data_dir, &["list"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no-time"))
+        .stderr(predicate::str::contains("chartodo init"))
+        .stderr(predicate::str::contains("panicked").not());
+    assert!(data_dir.join("chartodo.json").exists());
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
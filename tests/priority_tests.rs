@@ -0,0 +1,135 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+mod common;
+use common::*;
+
+// cargo test --test priority_tests
+// every test points chartodo at its own folder in the temp dir. a fresh folder starts w/ the example tasks, which
+// get the ids #1 to #4, so the first task a test adds is always #5
+
+#[test]
+fn priority_is_set_when_adding() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("adding");
+
+    chartodo(&data_dir, &["add", "urgent", "also-urgent", "-p", "high"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2: urgent #5 [high]"))
+        .stdout(predicate::str::contains("3: also-urgent #6 [high]"));
+    chartodo(
+        &data_dir,
+        &["rp-a", "gym", "2", "days", "--priority", "low"],
+    )
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("gym #7 [low]"));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn improper_priority_when_adding_is_an_error() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("improper_add");
+
+    chartodo(&data_dir, &["add", "x", "-p", "urgent"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("'urgent' isn't a priority"));
+
+    // clap stops it before anything is read or written
+    assert!(!data_dir.join("chartodo.json").exists());
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn deadlines_due_at_the_same_time_are_sorted_by_priority() -> Result<(), Box<dyn std::error::Error>>
+{
+    let data_dir = fresh_data_dir("sorted");
+
+    chartodo(
+        &data_dir,
+        &["dl-a", "low", "2098-01-01", "00:00", "-p", "low"],
+    )
+    .assert()
+    .success();
+    chartodo(&data_dir, &["dl-a", "none", "2098-01-01", "00:00"])
+        .assert()
+        .success();
+    chartodo(
+        &data_dir,
+        &["dl-a", "high", "2098-01-01", "00:00", "-p", "high"],
+    )
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("1: high #7 [high]"))
+    .stdout(predicate::str::contains("2: low #5 [low]"))
+    .stdout(predicate::str::contains("3: none #6"));
+
+    // the date still comes first
+    chartodo(
+        &data_dir,
+        &["dl-a", "sooner", "2097-01-01", "00:00", "-p", "low"],
+    )
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("1: sooner #8 [low]"));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn priority_can_be_edited() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("edited");
+
+    chartodo(&data_dir, &["ep", "1", "medium"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "1: this is the todo list #1 [medium]",
+        ));
+    chartodo(&data_dir, &["dl-ep", "#3", "h"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "1: breathe-once-before-2099 #3 [high]",
+        ));
+    chartodo(&data_dir, &["rp-ep", "1", "3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "1: the-turn-of-the-century #4 [low]",
+        ));
+    chartodo(&data_dir, &["ep", "1", "none"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("[medium]").not());
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn improper_priority_when_editing_is_an_error() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("improper_edit");
+
+    chartodo(&data_dir, &["dl-ep", "1", "urgent"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "ERROR: 'urgent' isn't a priority. It has to be high, medium, or low (or h, m, l, or 1, 2, 3), or none.",
+        ));
+    chartodo(&data_dir, &["rp-ep", "5", "high"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "ERROR: The position you provided, '5', was invalid. Try something between 1 and 1.",
+        ));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
@@ -1,136 +1,52 @@
 use anyhow::Context;
 use assert_cmd::prelude::*;
-use chartodo::functions::{json_file_structs::*, regular_tasks::regular_helpers::*};
+use chartodo::functions::{json_file_structs::*, task_store::TaskList};
 use predicates::prelude::*;
-use std::{path::PathBuf, process::Command};
 
-// cargo test --test regular_done_tests -- --test-threads=1
+mod common;
+use common::*;
 
-fn path_to_regular_tasks() -> PathBuf {
-    // get the data dir XDG spec and return it with path to regular_tasks.json
-    let mut regular_tasks_path = dirs::data_dir()
-        .context(
-            "linux: couldn't get $HOME/.local/share/
-                windows: couldn't get C:/Users/your_user/AppData/Local/
-                mac: couldn't get /Users/your_user/Library/Application Support/
-
-                those directories should exist for your OS. please double check that they do.",
-        )
-        .expect("something went wrong with fetching the user's data dirs");
-    regular_tasks_path.push("chartodo/regular_tasks.json");
-
-    regular_tasks_path
-}
-
-fn regular_tasks_copy_path() -> PathBuf {
-    // get the path for regular_tasks_copy.json, which will be used to hold the original contents
-    // of regular_tasks.json while it's getting modified
-    let mut regular_tasks_copy_path = dirs::data_dir()
-        .context(
-            "linux: couldn't get $HOME/.local/share/
-                windows: couldn't get C:/Users/your_user/AppData/Local/
-                mac: couldn't get /Users/your_user/Library/Application Support/
-
-                those directories should exist for your OS. please double check that they do.",
-        )
-        .expect("something went wrong with fetching the user's data dirs");
-    regular_tasks_copy_path.push("chartodo/regular_tasks_copy.json");
-
-    regular_tasks_copy_path
-}
-
-mod aaa_do_this_first {
-    use super::*;
-
-    // these have been tested in other fns, these are just included here as a sanity check
-    #[test]
-    fn regular_tasks_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/regular_tasks.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/regular_tasks.json";
-        let mac_path = "/Library/Application Support/chartodo/regular_tasks.json";
-        let mut got_regular_tasks_path: bool = false;
-        let regular_path = path_to_regular_tasks();
-        let regular_path = regular_path.to_str().unwrap();
-
-        if regular_path.contains(linux_path)
-            | regular_path.contains(windows_path)
-            | regular_path.contains(mac_path)
-        {
-            got_regular_tasks_path = true;
-        }
-
-        assert!(got_regular_tasks_path);
-    }
-
-    #[test]
-    fn regular_tasks_copy_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/regular_tasks_copy.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/regular_tasks_copy.json";
-        let mac_path = "/Library/Application Support/chartodo/regular_tasks_copy.json";
-        let mut got_regular_tasks_copy_path: bool = false;
-        let regular_tasks_copy_path = regular_tasks_copy_path();
-        let regular_tasks_copy_path = regular_tasks_copy_path.to_str().unwrap();
-
-        if regular_tasks_copy_path.contains(linux_path)
-            | regular_tasks_copy_path.contains(windows_path)
-            | regular_tasks_copy_path.contains(mac_path)
-        {
-            got_regular_tasks_copy_path = true;
-        }
-
-        assert!(got_regular_tasks_copy_path);
-    }
-
-    #[test]
-    fn aaaa_regular_tasks_clone_file() {
-        // name is aaaa so it's done first
-        // since we will be modifying the original file to run a test, the original data must be
-        // preserved first
-        std::fs::File::create(regular_tasks_copy_path())
-            .context("failed to create regular_tasks_copy.json")
-            .expect("failed to create a copy during unit test");
-
-        std::fs::copy(path_to_regular_tasks(), regular_tasks_copy_path())
-            .context("failed to copy regular_tasks.json to regular_tasks_copy.json")
-            .expect("failed to copy original file to copy file during unit test");
-    }
-}
+// cargo test --test regular_done_tests
 
 mod regular_done_rmdone {
     use super::*;
 
     #[test]
     fn regular_todo_rmdone_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmdone_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmdone");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "Did not provide the done item to \
-            be removed. Good example: chartodo rmdone 3, or chartodo rmdone 3 4 5. If you \
-            have more questions, try chartodo help or chartodo --help",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmdone_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmdone_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmd");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "Did not provide the done item to \
-            be removed. Good example: chartodo rmd 3, or chartodo rmd 3 4 5. If you have \
-            more questions, try chartodo help or chartodo --help",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmdone_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmdone_empty_done");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -144,20 +60,24 @@ mod regular_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmdone").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The regular done list is currently empty, so you can't remove any items.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmdone_abrev_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmdone_abrev_empty_done");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -171,21 +91,25 @@ mod regular_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmd").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The regular done list is currently empty, so you can't \
             remove any items.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmdone_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmdone_no_valid_args");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -210,22 +134,26 @@ mod regular_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmdone").arg("a").arg("2").arg("0");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, exceeded the regular \
             done list's length, or were invalid range positioning.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmdone_abrev_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmdone_abrev_no_valid_args");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -250,22 +178,26 @@ mod regular_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmd").arg("a").arg("2").arg("0");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, exceeded the regular \
             done list's length, or were invalid range positioning.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmdone_should_do_cleardone() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmdone_should_do_cleardone");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -340,10 +272,10 @@ mod regular_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmdone")
             .arg("1")
             .arg("2")
@@ -352,16 +284,20 @@ mod regular_done_rmdone {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified removing \
             the entire regular done list. You should do chartodo cleardone.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmdone_should_do_cleardone_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmdone_should_do_cleardone_range");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -436,21 +372,25 @@ mod regular_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmdone").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified removing \
             the entire regular done list. You should do chartodo cleardone.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmdone_abrev_should_do_cleardone() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmdone_abrev_should_do_cleardone");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -525,10 +465,10 @@ mod regular_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmd")
             .arg("1")
             .arg("2")
@@ -537,17 +477,21 @@ mod regular_done_rmdone {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified removing \
             the entire regular done list. You should do chartodo cleardone.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmdone_abrev_should_do_cleardone_range(
     ) -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmdone_abrev_should_do_cleardone_range");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -622,21 +566,25 @@ mod regular_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmd").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified removing \
             the entire regular done list. You should do chartodo cleardone.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmdone_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmdone_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -671,20 +619,24 @@ mod regular_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmdone").arg("1");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: hi"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmdone_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmdone_abrev_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -719,20 +671,24 @@ mod regular_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmd").arg("1");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: hi"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmdone_multiple_args_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmdone_multiple_args_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -777,21 +733,25 @@ mod regular_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmdone").arg("1").arg("3");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: hi"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmdone_multiple_args_is_correct_range() -> Result<(), Box<dyn std::error::Error>>
     {
+        let data_dir = fresh_data_dir("regular_todo_rmdone_multiple_args_is_correct_range");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -836,21 +796,25 @@ mod regular_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmdone").arg("1-2").arg("4");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: hello"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmdone_abrev_multiple_args_is_correct() -> Result<(), Box<dyn std::error::Error>>
     {
+        let data_dir = fresh_data_dir("regular_todo_rmdone_abrev_multiple_args_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -895,21 +859,25 @@ mod regular_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmd").arg("1").arg("3");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: hi"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmdone_abrev_multiple_args_is_correct_range(
     ) -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmdone_abrev_multiple_args_is_correct_range");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -954,15 +922,17 @@ mod regular_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmd").arg("1-2").arg("4");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: hello"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -972,34 +942,40 @@ mod regular_done_notdone {
 
     #[test]
     fn regular_todo_notdone_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_notdone_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("notdone");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "Did not provide the done item to \
-            be reversed back to todo. Good example: chartodo notdone 3, or chartodo notdone \
-            3 4 5. If you have more questions, try chartodo help or chartodo --help",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_notdone_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_notdone_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("nd");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "Did not provide the done item to \
-            be reversed back to todo. Good example: chartodo nd 3, or chartodo nd 3 4 5. If \
-            you have more questions, try chartodo help or chartodo --help",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_notdone_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_notdone_empty_done");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1013,21 +989,25 @@ mod regular_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("notdone").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The regular done list is currently empty, so you can't \
             reverse any items back to todo.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_notdone_abrev_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_notdone_abrev_empty_done");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1041,21 +1021,25 @@ mod regular_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("nd").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The regular done list is currently empty, so you can't \
             reverse any items back to todo.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_notdone_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_notdone_no_valid_args");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1080,22 +1064,26 @@ mod regular_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("notdone").arg("a").arg("2").arg("0");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, exceeded the regular \
             done list's length, or were invalid range positioning.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_notdone_abrev_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_notdone_abrev_no_valid_args");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1120,22 +1108,26 @@ mod regular_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("nd").arg("a").arg("2").arg("0");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, exceeded the regular \
             done list's length, or were invalid range positioning.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_notdone_should_do_notdoneall() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_notdone_should_do_notdoneall");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1210,10 +1202,10 @@ mod regular_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("notdone")
             .arg("1")
             .arg("2")
@@ -1222,17 +1214,21 @@ mod regular_done_notdone {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: you've specified \
             reversing the entire regular done list back to todo. You should do chartodo \
             notdoneall.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_notdone_should_do_notdoneall_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_notdone_should_do_notdoneall_range");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1307,22 +1303,26 @@ mod regular_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("notdone").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: you've specified \
             reversing the entire regular done list back to todo. You should do chartodo \
             notdoneall.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_notdone_abrev_should_do_notdoneall() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_notdone_abrev_should_do_notdoneall");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1397,10 +1397,10 @@ mod regular_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("nd")
             .arg("1")
             .arg("2")
@@ -1409,18 +1409,22 @@ mod regular_done_notdone {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: you've specified \
             reversing the entire regular done list back to todo. You should do chartodo \
             notdoneall.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_notdone_abrev_should_do_notdoneall_range(
     ) -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_notdone_abrev_should_do_notdoneall_range");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1495,22 +1499,26 @@ mod regular_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("nd").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: you've specified \
             reversing the entire regular done list back to todo. You should do chartodo \
             notdoneall.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_notdone_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_notdone_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1545,21 +1553,25 @@ mod regular_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("notdone").arg("1");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: hi"))
             .stdout(predicate::str::contains("1: this-is-the-todo-list"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_notdone_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_notdone_abrev_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1594,21 +1606,25 @@ mod regular_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("nd").arg("1");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: hi"))
             .stdout(predicate::str::contains("1: this-is-the-todo-list"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_notdone_multiple_args_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_notdone_multiple_args_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1653,10 +1669,10 @@ mod regular_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("notdone").arg("1").arg("3");
         cmd.assert()
             .success()
@@ -1664,12 +1680,16 @@ mod regular_done_notdone {
             .stdout(predicate::str::contains("1: hello"))
             .stdout(predicate::str::contains("2: this-is-the-todo-list"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_notdone_multiple_args_is_correct_range(
     ) -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_notdone_multiple_args_is_correct_range");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1714,10 +1734,10 @@ mod regular_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("notdone").arg("1-2").arg("4");
         cmd.assert()
             .success()
@@ -1725,12 +1745,16 @@ mod regular_done_notdone {
             .stdout(predicate::str::contains("1: hello"))
             .stdout(predicate::str::contains("2: this-is-the-todo-list"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_notdone_abrev_multiple_args_is_correct(
     ) -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_notdone_abrev_multiple_args_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1775,10 +1799,10 @@ mod regular_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("nd").arg("1").arg("3");
         cmd.assert()
             .success()
@@ -1786,12 +1810,16 @@ mod regular_done_notdone {
             .stdout(predicate::str::contains("1: hello"))
             .stdout(predicate::str::contains("2: this-is-the-todo-list"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_notdone_abrev_multiple_args_is_correct_range(
     ) -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_notdone_abrev_multiple_args_is_correct_range");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1836,10 +1864,10 @@ mod regular_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("nd").arg("1-2").arg("4");
         cmd.assert()
             .success()
@@ -1847,6 +1875,8 @@ mod regular_done_notdone {
             .stdout(predicate::str::contains("1: hello"))
             .stdout(predicate::str::contains("2: this-is-the-todo-list"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -1856,28 +1886,39 @@ mod regular_done_cleardone {
 
     #[test]
     fn regular_todo_cleardone_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("regular_todo_cleardone_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("cleardone").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn regular_todo_cleardone_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir =
+            fresh_data_dir("regular_done_cleardone_regular_todo_cleardone_abrev_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("cd").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn regular_todo_cleardone_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_cleardone_empty_done");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1891,21 +1932,25 @@ mod regular_done_cleardone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("cleardone");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The regular done list is currently empty, so you can't \
             remove any items.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_cleardone_abrev_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_cleardone_abrev_empty_done");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1919,21 +1964,25 @@ mod regular_done_cleardone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("cd");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The regular done list is currently empty, so you can't \
             remove any items.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_cleardone_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_cleardone_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1968,21 +2017,25 @@ mod regular_done_cleardone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("cleardone");
         assert!(cmd
             .assert()
             .try_stdout(predicate::str::contains("2: hi"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_cleardone_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_cleardone_abrev_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2017,16 +2070,18 @@ mod regular_done_cleardone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("cd");
         assert!(cmd
             .assert()
             .try_stdout(predicate::str::contains("2: hi"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -2036,28 +2091,39 @@ mod regular_done_notdoneall {
 
     #[test]
     fn regular_todo_notdoneall_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("regular_todo_notdoneall_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("notdoneall").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn regular_todo_cleardone_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir =
+            fresh_data_dir("regular_done_notdoneall_regular_todo_cleardone_abrev_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("nda").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn regular_todo_notdoneall_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_notdoneall_empty_done");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2071,21 +2137,25 @@ mod regular_done_notdoneall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("notdoneall");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The regular done list is currently empty, so \
             you can't reverse any items back to todo.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_notdoneall_abrev_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_notdoneall_abrev_empty_done");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2099,21 +2169,25 @@ mod regular_done_notdoneall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("nda");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The regular done list is currently empty, so you can't \
             reverse any items back to todo.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_notdoneall_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_notdoneall_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2148,24 +2222,28 @@ mod regular_done_notdoneall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
         // we must mess up the order so we know notdoneall was correct
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmd").arg("1");
         cmd.assert().success();
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("notdoneall");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: hi"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_notdoneall_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_notdoneall_abrev_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2200,40 +2278,21 @@ mod regular_done_notdoneall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
         // we must mess up the order so we know notdoneall was correct
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmd").arg("1");
         cmd.assert().success();
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("nda");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: hi"));
 
-        Ok(())
-    }
-}
-
-mod zzz_do_this_last {
-    use super::*;
+        std::fs::remove_dir_all(&data_dir)?;
 
-    #[test]
-    fn zzzz_rename_copy_to_original() {
-        // name is zzzz so it's done last
-        // now that tests are done, remove the modified original and rename copy to original
-
-        std::fs::remove_file(path_to_regular_tasks())
-            .context("failed delete modified regular_tasks.json after running tests")
-            .expect("failed to delete regular_tasks.json after regular_helpers unit tests");
-
-        std::fs::rename(regular_tasks_copy_path(), path_to_regular_tasks())
-            .context("failed to rename regular_tasks_copy to regular_tasks")
-            .expect(
-                "failed to rename regular_tasks_copy to regular_tasks after \
-                tests were done",
-            );
+        Ok(())
     }
 }
@@ -1,137 +1,52 @@
 use anyhow::Context;
 use assert_cmd::prelude::*;
-use chartodo::functions::{json_file_structs::*, regular_tasks::regular_helpers::*};
+use chartodo::functions::{json_file_structs::*, task_store::TaskList};
 use predicates::prelude::*;
-use std::{path::PathBuf, process::Command};
 
-// cargo test --test regular_todo_tests -- --test-threads=1
+mod common;
+use common::*;
 
-// these are taken from regular_helpers
-fn path_to_regular_tasks() -> PathBuf {
-    // get the data dir XDG spec and return it with path to regular_tasks.json
-    let mut regular_tasks_path = dirs::data_dir()
-        .context(
-            "linux: couldn't get $HOME/.local/share/
-                windows: couldn't get C:/Users/your_user/AppData/Local/
-                mac: couldn't get /Users/your_user/Library/Application Support/
-
-                those directories should exist for your OS. please double check that they do.",
-        )
-        .expect("something went wrong with fetching the user's data dirs");
-    regular_tasks_path.push("chartodo/regular_tasks.json");
-
-    regular_tasks_path
-}
-
-fn regular_tasks_copy_path() -> PathBuf {
-    // get the path for regular_tasks_copy.json, which will be used to hold the original contents
-    // of regular_tasks.json while it's getting modified
-    let mut regular_tasks_copy_path = dirs::data_dir()
-        .context(
-            "linux: couldn't get $HOME/.local/share/
-                windows: couldn't get C:/Users/your_user/AppData/Local/
-                mac: couldn't get /Users/your_user/Library/Application Support/
-
-                those directories should exist for your OS. please double check that they do.",
-        )
-        .expect("something went wrong with fetching the user's data dirs");
-    regular_tasks_copy_path.push("chartodo/regular_tasks_copy.json");
-
-    regular_tasks_copy_path
-}
-
-mod aaa_do_this_first {
-    use super::*;
-
-    // these have been tested in other fns, these are just included here as a sanity check
-    #[test]
-    fn regular_tasks_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/regular_tasks.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/regular_tasks.json";
-        let mac_path = "/Library/Application Support/chartodo/regular_tasks.json";
-        let mut got_regular_tasks_path: bool = false;
-        let regular_path = path_to_regular_tasks();
-        let regular_path = regular_path.to_str().unwrap();
-
-        if regular_path.contains(linux_path)
-            | regular_path.contains(windows_path)
-            | regular_path.contains(mac_path)
-        {
-            got_regular_tasks_path = true;
-        }
-
-        assert!(got_regular_tasks_path);
-    }
-
-    #[test]
-    fn regular_tasks_copy_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/regular_tasks_copy.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/regular_tasks_copy.json";
-        let mac_path = "/Library/Application Support/chartodo/regular_tasks_copy.json";
-        let mut got_regular_tasks_copy_path: bool = false;
-        let regular_tasks_copy_path = regular_tasks_copy_path();
-        let regular_tasks_copy_path = regular_tasks_copy_path.to_str().unwrap();
-
-        if regular_tasks_copy_path.contains(linux_path)
-            | regular_tasks_copy_path.contains(windows_path)
-            | regular_tasks_copy_path.contains(mac_path)
-        {
-            got_regular_tasks_copy_path = true;
-        }
-
-        assert!(got_regular_tasks_copy_path);
-    }
-
-    #[test]
-    fn aaaa_regular_tasks_clone_file() {
-        // name is aaaa so it's done first
-        // since we will be modifying the original file to run a test, the original data must be
-        // preserved first
-        std::fs::File::create(regular_tasks_copy_path())
-            .context("failed to create regular_tasks_copy.json")
-            .expect("failed to create a copy during unit test");
-
-        std::fs::copy(path_to_regular_tasks(), regular_tasks_copy_path())
-            .context("failed to copy regular_tasks.json to regular_tasks_copy.json")
-            .expect("failed to copy original file to copy file during unit test");
-    }
-}
+// cargo test --test regular_todo_tests
 
 mod regular_todo_add {
     use super::*;
 
     #[test]
     fn regular_todo_adding_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_adding_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("add");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "Did not provide the todo item(s) \
-            to be added. Good example: chartodo add new-item, or chartodo add item next-item \
-            one-more-item. If you have questions, try chartodo help or chartodo --help",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_adding_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_adding_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("a");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "Did not provide the todo item(s) \
-            to be added. Good example: chartodo a new-item, or chartodo a item next-item \
-            one-more-item. If you have questions, try chartodo help or chartodo --help",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_adding_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_adding_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -145,20 +60,24 @@ mod regular_todo_add {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("add").arg("regular-todo-item");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: regular-todo-item"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_adding_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_adding_abrev_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -172,20 +91,24 @@ mod regular_todo_add {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("a").arg("regular-todo-item");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: regular-todo-item"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_adding_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_adding_multiple_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -199,21 +122,25 @@ mod regular_todo_add {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("add").arg("regular-todo-item").arg("hello");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: regular-todo-item"))
             .stdout(predicate::str::contains("2: hello"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_adding_multiple_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_adding_multiple_abrev_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -227,16 +154,18 @@ mod regular_todo_add {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("a").arg("regular-todo-item").arg("hello");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: regular-todo-item"))
             .stdout(predicate::str::contains("2: hello"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -246,34 +175,40 @@ mod regular_todo_done {
 
     #[test]
     fn regular_todo_done_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_done_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("done");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "Did not provide the todo item(s) \
-            to be changed to done. Good example: chartodo done 3, or chartodo done 3 4 5. If \
-            you have questions, try chartodo help or chartodo --help",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_done_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_done_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("d");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "Did not provide the todo item(s) \
-            to be changed to done. Good example: chartodo d 3, or chartodo d 3 4 5. If you \
-            have questions, try chartodo help or chartodo --help",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_done_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_done_empty_todo");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -287,21 +222,25 @@ mod regular_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("done").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The regular todo list is \
             currently empty so you can't change any todos to done.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_done_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_done_abrev_empty_todo");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -315,21 +254,25 @@ mod regular_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("d").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The regular todo list is \
             currently empty so you can't change any todos to done.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_done_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_done_no_valid_args");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -354,22 +297,26 @@ mod regular_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("done").arg("a").arg("2").arg("0");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, exceeded the regular \
             todo list's length, or were invalid range positioning.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_done_abrev_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_done_abrev_no_valid_args");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -394,22 +341,26 @@ mod regular_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("d").arg("a").arg("2").arg("0");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, exceeded the regular \
             todo list's length, or were invalid range positioning.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_done_should_do_doneall() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_done_should_do_doneall");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -484,10 +435,10 @@ mod regular_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("done")
             .arg("1")
             .arg("2")
@@ -496,16 +447,20 @@ mod regular_todo_done {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: you've specified marking \
             the entire regular todo list as done. You should do chartodo doneall.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_done_should_do_doneall_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_done_should_do_doneall_range");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -580,21 +535,25 @@ mod regular_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("done").arg("1-6").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: you've specified marking \
             the entire regular todo list as done. You should do chartodo doneall.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_done_abrev_should_do_doneall() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_done_abrev_should_do_doneall");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -669,10 +628,10 @@ mod regular_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("d")
             .arg("1")
             .arg("2")
@@ -681,16 +640,20 @@ mod regular_todo_done {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: you've specified marking \
             the entire regular todo list as done. You should do chartodo doneall.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_done_abrev_should_do_doneall_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_done_abrev_should_do_doneall_range");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -765,21 +728,25 @@ mod regular_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("d").arg("1-6").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: you've specified marking \
             the entire regular todo list as done. You should do chartodo doneall.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_done_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_done_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -814,10 +781,10 @@ mod regular_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("done").arg("1");
         cmd.assert()
             .success()
@@ -826,11 +793,15 @@ mod regular_todo_done {
             .success()
             .stdout(predicate::str::contains("1: this-is-the-todo-list"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_done_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_done_abrev_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -865,10 +836,10 @@ mod regular_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("d").arg("1");
         cmd.assert()
             .success()
@@ -877,11 +848,15 @@ mod regular_todo_done {
             .success()
             .stdout(predicate::str::contains("1: this-is-the-todo-list"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_done_multiple_args_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_done_multiple_args_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -926,22 +901,26 @@ mod regular_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("done").arg("1").arg("3");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("2: this-is-the-todo-list"))
             .stdout(predicate::str::contains("1: hello"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_done_multiple_args_is_correct_range() -> Result<(), Box<dyn std::error::Error>>
     {
+        let data_dir = fresh_data_dir("regular_todo_done_multiple_args_is_correct_range");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -986,21 +965,25 @@ mod regular_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("done").arg("1-2");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: hello"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_done_abrev_multiple_args_is_correct() -> Result<(), Box<dyn std::error::Error>>
     {
+        let data_dir = fresh_data_dir("regular_todo_done_abrev_multiple_args_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1045,21 +1028,25 @@ mod regular_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("d").arg("1").arg("3");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: hi"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_done_abrev_multiple_args_is_correct_range(
     ) -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_done_abrev_multiple_args_is_correct_range");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1104,15 +1091,17 @@ mod regular_todo_done {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("d").arg("1-2");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: hello"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -1122,34 +1111,40 @@ mod regular_todo_rmtodo {
 
     #[test]
     fn regular_todo_rmtodo_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmtodo_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmtodo");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "Did not provide the todo item(s) \
-            to be removed. Good example: chartodo rmtodo 3, or chartodo rmtodo 3 4 5. If you \
-            have more questions, try chartodo help or chartodo --help",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmtodo_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmtodo_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmt");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "Did not provide the todo item(s) \
-            to be removed. Good example: chartodo rmt 3, or chartodo rmt 3 4 5. If you have \
-            more questions, try chartodo help or chartodo --help",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmtodo_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmtodo_empty_todo");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1163,22 +1158,26 @@ mod regular_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmtodo").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The regular todo list is \
             currently empty, so you can't remove any items. Try adding to it first before \
             removing any.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmtodo_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmtodo_abrev_empty_todo");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1192,22 +1191,26 @@ mod regular_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmt").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The regular todo list is \
             currently empty, so you can't remove any items. Try adding to it first before \
             removing any.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmtodo_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmtodo_no_valid_args");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1232,22 +1235,26 @@ mod regular_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmtodo").arg("a").arg("2").arg("0");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, exceeded the regular \
             todo list's length, or were invalid range positioning.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmtodo_abrev_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmtodo_abrev_no_valid_args");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1272,22 +1279,26 @@ mod regular_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmt").arg("a").arg("2").arg("0");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, exceeded the regular \
             todo list's length, or were invalid range positioning.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmtodo_should_do_doneall() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmtodo_should_do_doneall");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1362,10 +1373,10 @@ mod regular_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmtodo")
             .arg("1")
             .arg("2")
@@ -1374,16 +1385,20 @@ mod regular_todo_rmtodo {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You specified removing \
             the entire regular todo list. You should instead do chartodo cleartodo.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmtodo_should_do_doneall_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmtodo_should_do_doneall_range");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1458,21 +1473,25 @@ mod regular_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmtodo").arg("1-6").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You specified removing \
             the entire regular todo list. You should instead do chartodo cleartodo.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmtodo_abrev_should_do_doneall() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmtodo_abrev_should_do_doneall");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1547,10 +1566,10 @@ mod regular_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmt")
             .arg("1")
             .arg("2")
@@ -1559,17 +1578,21 @@ mod regular_todo_rmtodo {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You specified removing \
             the entire regular todo list. You should instead do chartodo cleartodo.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmtodo_abrev_should_do_doneall_range() -> Result<(), Box<dyn std::error::Error>>
     {
+        let data_dir = fresh_data_dir("regular_todo_rmtodo_abrev_should_do_doneall_range");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1644,21 +1667,25 @@ mod regular_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmt").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You specified removing \
             the entire regular todo list. You should instead do chartodo cleartodo.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmtodo_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmtodo_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1693,20 +1720,24 @@ mod regular_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmtodo").arg("1");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: hi"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmtodo_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmtodo_abrev_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1741,15 +1772,17 @@ mod regular_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmt").arg("1");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: hi"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
@@ -1759,6 +1792,8 @@ mod regular_todo_rmtodo {
     // thus, concessions with testing have to be made. idk about other alternatives
     #[test]
     fn regular_todo_rmtodo_multiple_args_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmtodo_multiple_args_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1803,21 +1838,25 @@ mod regular_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmtodo").arg("1").arg("3");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: hi"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmtodo_multiple_args_is_correct_range() -> Result<(), Box<dyn std::error::Error>>
     {
+        let data_dir = fresh_data_dir("regular_todo_rmtodo_multiple_args_is_correct_range");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1862,21 +1901,25 @@ mod regular_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmtodo").arg("1-2");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: hello"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmtodo_abrev_multiple_args_is_correct() -> Result<(), Box<dyn std::error::Error>>
     {
+        let data_dir = fresh_data_dir("regular_todo_rmtodo_abrev_multiple_args_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1921,21 +1964,25 @@ mod regular_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmt").arg("1").arg("3");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: hi"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_rmtodo_abrev_multiple_args_is_correct_range(
     ) -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_rmtodo_abrev_multiple_args_is_correct_range");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -1980,15 +2027,17 @@ mod regular_todo_rmtodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rmt").arg("1-2");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: hello"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -1998,28 +2047,38 @@ mod regular_todo_cleartodo {
 
     #[test]
     fn regular_todo_cleartodo_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("regular_todo_cleartodo_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("cleartodo").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn regular_todo_cleartodo_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("regular_todo_cleartodo_abrev_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("ct").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn regular_todo_cleartodo_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_cleartodo_empty_todo");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2033,21 +2092,25 @@ mod regular_todo_cleartodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("cleartodo");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The regular todo list is \
             currently empty. Try adding items to it first before removing any.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_cleartodo_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_cleartodo_abrev_empty_todo");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2061,21 +2124,25 @@ mod regular_todo_cleartodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("ct");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The regular todo list is \
             currently empty. Try adding items to it first before removing any.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_cleartodo_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_cleartodo_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2110,10 +2177,10 @@ mod regular_todo_cleartodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("cleartodo");
         assert!(cmd
             .assert()
@@ -2121,11 +2188,15 @@ mod regular_todo_cleartodo {
             .try_stdout(predicate::str::contains("1: this-is-the-todo-list"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_cleartodo_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_cleartodo_abrev_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2160,10 +2231,10 @@ mod regular_todo_cleartodo {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("ct");
         assert!(cmd
             .assert()
@@ -2171,6 +2242,8 @@ mod regular_todo_cleartodo {
             .try_stdout(predicate::str::contains("2: hi"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -2180,28 +2253,38 @@ mod regular_todo_doneall {
 
     #[test]
     fn regular_todo_doneall_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("regular_todo_doneall_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("doneall").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn regular_todo_doneall_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let data_dir = fresh_data_dir("regular_todo_doneall_abrev_no_args_allowed");
+
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("da").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn regular_todo_doneall_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_doneall_empty_todo");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2215,21 +2298,25 @@ mod regular_todo_doneall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("doneall");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The regular todo list is \
             currently empty, so you can't change any todos to done.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_doneall_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_doneall_abrev_empty_todo");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2243,21 +2330,25 @@ mod regular_todo_doneall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("da");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The regular todo list is \
             currently empty, so you can't change any todos to done.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_doneall_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_doneall_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2302,17 +2393,17 @@ mod regular_todo_doneall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         // we must mess up the order so we know doneall was actually successful
         cmd.arg("rmt").arg("1");
         cmd.assert().success();
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("doneall");
         cmd.assert().success();
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("add").arg("welcome");
         cmd.assert()
             .success()
@@ -2320,11 +2411,15 @@ mod regular_todo_doneall {
             .stdout(predicate::str::contains("2: hello"))
             .stdout(predicate::str::contains("1: welcome"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_doneall_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_doneall_abrev_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2369,17 +2464,17 @@ mod regular_todo_doneall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         // we must mess up the order so we know doneall was actually successful
         cmd.arg("rmt").arg("1");
         cmd.assert().success();
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("da");
         cmd.assert().success();
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("add").arg("welcome");
         cmd.assert()
             .success()
@@ -2387,6 +2482,8 @@ mod regular_todo_doneall {
             .stdout(predicate::str::contains("2: hello"))
             .stdout(predicate::str::contains("1: welcome"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -2396,6 +2493,8 @@ mod regular_todo_edit {
 
     #[test]
     fn regular_todo_edit_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_edit_nothing");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2409,22 +2508,24 @@ mod regular_todo_edit {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("edit");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "Did not provide the todo item to \
-            be edited. Good example: chartodo edit 3 abc. If you have more questions, try \
-            chartodo help or chartodo --help",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_edit_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_edit_abrev_nothing");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2438,22 +2539,24 @@ mod regular_todo_edit {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("e");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "Did not provide the todo item \
-            to be edited. Good example: chartodo e 3 abc. If you have more questions, try \
-            chartodo help or chartodo --help",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_edit_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_edit_empty_todo");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2467,21 +2570,25 @@ mod regular_todo_edit {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("edit").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("edit").arg("1").arg("new-task");
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The regular todo list is \
             currently empty, so there are no todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_edit_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_edit_abrev_empty_todo");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2495,21 +2602,25 @@ mod regular_todo_edit {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("e").arg("a");
-        cmd.assert().success().stdout(predicate::str::contains(
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("e").arg("a").arg("new-task");
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The regular todo list is \
             currently empty, so there are no todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_edit_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_edit_wrong_num_of_args");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2534,24 +2645,24 @@ mod regular_todo_edit {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("edit").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: You must specify the \
-            regular todo's position that will be edited, and what to edit the task \
-            to.\n\tThere should be 2 arguments after 'chartodo edit'. You provided 1 \
-            argument(s).\n\tFormat: chartodo edit ~position ~task\n\tExample: chartodo edit \
-            4 new-item",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_edit_abrev_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_edit_abrev_wrong_num_of_args");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2576,24 +2687,24 @@ mod regular_todo_edit {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("e").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: You must specify the \
-            regular todo's position that will be edited, and what to edit the task \
-            to.\n\tThere should be 2 arguments after 'chartodo edit'. You provided 1 \
-            argument(s).\n\tFormat: chartodo edit ~position ~task\n\tExample: chartodo edit \
-            4 new-item",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_edit_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_edit_not_usize");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2618,21 +2729,24 @@ mod regular_todo_edit {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("edit").arg("a").arg("a");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: To edit a regular task \
-            item, you must provide a viable position. Try something between 1 and 1",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, 'a', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_edit_abrev_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_edit_abrev_not_usize");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2657,21 +2771,24 @@ mod regular_todo_edit {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("e").arg("a").arg("a");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: To edit a regular task \
-            item, you must provide a viable position. Try something between 1 and 1",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, 'a', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_edit_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_edit_position_is_zero");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2696,20 +2813,24 @@ mod regular_todo_edit {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("edit").arg("0").arg("a");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_edit_abrev_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_edit_abrev_position_is_zero");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2734,20 +2855,24 @@ mod regular_todo_edit {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("e").arg("0").arg("a");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_edit_position_exceeds_len() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_edit_position_exceeds_len");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2772,21 +2897,24 @@ mod regular_todo_edit {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("edit").arg("2").arg("a");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: The position you specified exceeds the regular todo list's \
-            current length. Try something between 1 and 1",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_edit_abrev_position_exceeds_len() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_edit_abrev_position_exceeds_len");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2811,21 +2939,24 @@ mod regular_todo_edit {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("e").arg("2").arg("a");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: The position you specified exceeds the regular todo list's \
-            current length. Try something between 1 and 1",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_edit_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_edit_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2850,10 +2981,10 @@ mod regular_todo_edit {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("edit").arg("1").arg("hi");
         cmd.assert()
             .success()
@@ -2864,11 +2995,15 @@ mod regular_todo_edit {
             .try_stdout(predicate::str::contains("1: this-is-the-todo-list"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn regular_todo_edit_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("regular_todo_edit_abrev_is_correct");
+
         // write fresh to regular tasks so content is known
         let fresh_regular_tasks = r#"
             {
@@ -2893,10 +3028,10 @@ mod regular_todo_edit {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_regular_tasks(fresh_regular_tasks);
+        write_tasks(&data_dir, TaskList::Regular, fresh_regular_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("e").arg("1").arg("hi");
         cmd.assert()
             .success()
@@ -2907,24 +3042,8 @@ mod regular_todo_edit {
             .try_stdout(predicate::str::contains("1: this-is-the-todo-list"))
             .is_err());
 
-        Ok(())
-    }
-}
-
-mod zzz_do_this_last {
-    use super::*;
-
-    #[test]
-    fn zzzz_rename_copy_to_original() {
-        // name is zzzz so it's done last
-        // now that tests are done, remove the modified original and rename copy to original
-
-        std::fs::remove_file(path_to_regular_tasks())
-            .context("failed delete modified regular_tasks.json after running tests")
-            .expect("failed to delete regular_tasks.json after regular_helpers unit tests");
+        std::fs::remove_dir_all(&data_dir)?;
 
-        std::fs::rename(regular_tasks_copy_path(), path_to_regular_tasks())
-            .context("failed to rename regular_tasks_copy to regular_tasks")
-            .expect("failed to rename regular_tasks_copy to regular_tasks after tests were done");
+        Ok(())
     }
 }
@@ -0,0 +1,179 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use serde_json::Value;
+use std::path::PathBuf;
+
+mod common;
+use common::*;
+
+// cargo test --test repeat_end_tests
+// every test points chartodo at its own folder in the temp dir, so these never touch the real chartodo files. the
+// lists are read back w/ --format json, see json_output_tests
+
+fn repeating_lists(data_dir: &PathBuf) -> Value {
+    let output = chartodo(data_dir, &["list", "--format", "json"])
+        .output()
+        .expect("couldn't run chartodo");
+    let result: Value =
+        serde_json::from_slice(&output.stdout).expect("chartodo didn't print a json object");
+
+    result["lists"]["repeating"].clone()
+}
+
+#[test]
+fn times_counts_down() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("times");
+
+    // the example repeating task is due in 2100, so this one is first
+    chartodo(
+        &data_dir,
+        &[
+            "rp-as",
+            "installment",
+            "1",
+            "month",
+            "2099-01-01",
+            "00:00",
+            "--times",
+            "12",
+        ],
+    )
+    .assert()
+    .success();
+
+    let installment = &repeating_lists(&data_dir)["todo"][0];
+    assert_eq!(installment["max_occurrences"], 12);
+    assert_eq!(installment["remaining"], 12);
+    assert_eq!(installment["until"], Value::Null);
+
+    chartodo(&data_dir, &["rp-s", "1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("remaining: 12"));
+    chartodo(&data_dir, &["list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("interval: 1 month, 12 times"));
+
+    // the example task repeats forever, so it doesn't have a count
+    chartodo(&data_dir, &["rp-sa"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("remaining: 12").count(1))
+        .stdout(predicate::str::contains("remaining").count(1));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn until_counts_what_fits() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("until");
+
+    // due on 2099-01-12, 19, and 26
+    chartodo(
+        &data_dir,
+        &[
+            "rp-as",
+            "review",
+            "1",
+            "week",
+            "2099-01-05",
+            "09:00",
+            "--until",
+            "2099-01-31",
+        ],
+    )
+    .assert()
+    .success();
+
+    let review = &repeating_lists(&data_dir)["todo"][0];
+    assert_eq!(review["until"], "2099-01-31 23:59");
+    assert_eq!(review["remaining"], 3);
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn finished_series_stays_done() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("finished");
+
+    // due on 2000-01-02 and 2000-01-03, both long gone
+    chartodo(
+        &data_dir,
+        &[
+            "rp-as",
+            "water",
+            "1",
+            "day",
+            "2000-01-01",
+            "00:00",
+            "--times",
+            "2",
+        ],
+    )
+    .assert()
+    .success();
+    chartodo(&data_dir, &["rp-d", "1"]).assert().success();
+
+    let repeating = repeating_lists(&data_dir);
+    assert_eq!(repeating["todo"][0]["due"], "2000-01-03 00:00");
+    assert_eq!(repeating["todo"][0]["remaining"], 1);
+
+    chartodo(&data_dir, &["rp-d", "1"]).assert().success();
+    let repeating = repeating_lists(&data_dir);
+    let water = &repeating["done"][0];
+    assert_eq!(water["task"], "water");
+    assert_eq!(water["status"], "finished");
+    assert_eq!(water["remaining"], 0);
+    assert_eq!(repeating["todo"].as_array().unwrap().len(), 1);
+
+    chartodo(&data_dir, &["list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("finished: 2000-01-03 00:00"));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn series_has_to_happen_at_least_once() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("at_least_once");
+
+    chartodo(
+        &data_dir,
+        &[
+            "rp-as",
+            "review",
+            "1",
+            "week",
+            "2099-01-05",
+            "09:00",
+            "--until",
+            "2099-01-10",
+        ],
+    )
+    .assert()
+    .code(4)
+    .stderr(predicate::str::contains(
+        "after it ends on 2099-01-10 23:59",
+    ));
+    chartodo(&data_dir, &["rp-a", "gym", "2", "days", "--times", "0"])
+        .assert()
+        .failure();
+    chartodo(
+        &data_dir,
+        &["rp-a", "gym", "2", "days", "--until", "someday"],
+    )
+    .assert()
+    .failure();
+    assert_eq!(
+        repeating_lists(&data_dir)["todo"].as_array().unwrap().len(),
+        1
+    );
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
@@ -0,0 +1,200 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use serde_json::Value;
+use std::path::PathBuf;
+
+mod common;
+use common::*;
+
+// cargo test --test repeat_rules_tests
+// every test points chartodo at its own folder in the temp dir, so these never touch the real chartodo files. the
+// lists are read back w/ --format json, see json_output_tests
+
+// the repeating todo w/ this name, as json
+fn repeating_task(data_dir: &PathBuf, task: &str) -> Value {
+    let output = chartodo(data_dir, &["list", "--format", "json"])
+        .output()
+        .expect("couldn't run chartodo");
+    let result: Value =
+        serde_json::from_slice(&output.stdout).expect("chartodo didn't print a json object");
+
+    result["lists"]["repeating"]["todo"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|json_task| json_task["task"] == task)
+        .cloned()
+        .unwrap_or_else(|| panic!("'{}' isn't in the repeating list", task))
+}
+
+#[test]
+fn add_takes_a_rule() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = missing_data_dir("add");
+
+    // 2099-01-05 is a monday, and 2099-01-31 is a saturday
+    chartodo(
+        &data_dir,
+        &[
+            "rp-as",
+            "standup",
+            "1",
+            "week",
+            "2099-01-05",
+            "09:00",
+            "--on",
+            "mon,wed,fri",
+        ],
+    )
+    .assert()
+    .success();
+    chartodo(
+        &data_dir,
+        &[
+            "rp-as",
+            "rent",
+            "1",
+            "month",
+            "2099-01-01",
+            "08:00",
+            "--on",
+            "last business day",
+        ],
+    )
+    .assert()
+    .success();
+
+    let standup = repeating_task(&data_dir, "standup");
+    assert_eq!(standup["due"], "2099-01-07 09:00");
+    assert_eq!(standup["on"], "mon,wed,fri");
+    let rent = repeating_task(&data_dir, "rent");
+    assert_eq!(rent["due"], "2099-01-30 08:00");
+    assert_eq!(rent["on"], "last business day");
+
+    chartodo(&data_dir, &["list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("interval: 1 week on mon,wed,fri"));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn rule_has_to_go_w_the_unit() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = missing_data_dir("wrong_unit");
+
+    chartodo(&data_dir, &["rp-a", "gym", "2", "days", "--on", "mon,thu"])
+        .assert()
+        .code(4)
+        .stderr(predicate::str::contains("only works w/ weeks"));
+    chartodo(&data_dir, &["rp-a", "gym", "1", "week", "--on", "6th mon"])
+        .assert()
+        .failure();
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn editon_changes_the_rule() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = missing_data_dir("editon");
+
+    // the example repeating task is due in 2100, so this one is first
+    chartodo(
+        &data_dir,
+        &["rp-as", "review", "1", "week", "2099-01-05", "09:00"],
+    )
+    .assert()
+    .success();
+    assert_eq!(repeating_task(&data_dir, "review")["on"], Value::Null);
+
+    chartodo(&data_dir, &["rp-eo", "1", "weekdays"])
+        .assert()
+        .success();
+    let review = repeating_task(&data_dir, "review");
+    assert_eq!(review["on"], "weekdays");
+    assert_eq!(review["due"], "2099-01-06 09:00");
+
+    // a monthly rule, or a unit the rule doesn't go w/, changes nothing
+    chartodo(&data_dir, &["rp-eo", "1", "2nd tue"])
+        .assert()
+        .code(4);
+    chartodo(&data_dir, &["rp-eu", "1", "months"])
+        .assert()
+        .code(4);
+    assert_eq!(repeating_task(&data_dir, "review")["on"], "weekdays");
+
+    chartodo(&data_dir, &["rp-eo", "1", "none"])
+        .assert()
+        .success();
+    let review = repeating_task(&data_dir, "review");
+    assert_eq!(review["on"], Value::Null);
+    assert_eq!(review["due"], "2099-01-12 09:00");
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn addend_starts_at_the_occurrence_before_due() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = missing_data_dir("addend");
+
+    // 2099-01-12 is a monday, so the one before it is friday. w/ every 2 weeks, it's the friday a week before that
+    chartodo(
+        &data_dir,
+        &[
+            "rp-ae",
+            "standup",
+            "1",
+            "week",
+            "2099-01-12",
+            "09:00",
+            "--on",
+            "mon,wed,fri",
+        ],
+    )
+    .assert()
+    .success();
+    chartodo(
+        &data_dir,
+        &[
+            "rp-ae",
+            "retro",
+            "2",
+            "weeks",
+            "2099-01-12",
+            "09:00",
+            "--on",
+            "mon",
+        ],
+    )
+    .assert()
+    .success();
+    chartodo(
+        &data_dir,
+        &[
+            "rp-ae",
+            "rent",
+            "1",
+            "month",
+            "2099-05-29",
+            "17:00",
+            "--on",
+            "last business day",
+        ],
+    )
+    .assert()
+    .success();
+
+    let standup = repeating_task(&data_dir, "standup");
+    assert_eq!(standup["start"], "2099-01-09 09:00");
+    assert_eq!(standup["due"], "2099-01-12 09:00");
+    let retro = repeating_task(&data_dir, "retro");
+    assert_eq!(retro["start"], "2098-12-29 09:00");
+    let rent = repeating_task(&data_dir, "rent");
+    assert_eq!(rent["start"], "2099-04-30 17:00");
+    assert_eq!(rent["due"], "2099-05-29 17:00");
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
@@ -1,132 +1,52 @@
 use anyhow::Context;
 use assert_cmd::prelude::*;
-use chartodo::functions::{json_file_structs::*, repeating_tasks::repeating_helpers::*};
+use chartodo::functions::{json_file_structs::*, task_store::TaskList};
 use predicates::prelude::*;
-use std::{path::PathBuf, process::Command};
 
-// cargo test --test repeating_done_tests -- --test-threads=1
+mod common;
+use common::*;
 
-fn path_to_repeating_tasks() -> PathBuf {
-    // get the data dir XDG spec and return it with path to regular_tasks.json
-    let mut repeating_tasks_path = dirs::data_dir()
-        .context(
-            "linux: couldn't get $HOME/.local/share/
-                windows: couldn't get C:/Users/your_user/AppData/Local/
-                mac: couldn't get /Users/your_user/Library/Application Support/
-
-                those directories should exist for your OS. please double check that they do.",
-        )
-        .expect("something went wrong with fetching the user's data dirs");
-    repeating_tasks_path.push("chartodo/repeating_tasks.json");
-
-    repeating_tasks_path
-}
-
-fn repeating_tasks_copy_path() -> PathBuf {
-    // get the path for repeating_tasks_copy.json, which will be used to hold the original contents
-    // of repeating_tasks.json while it's getting modified
-    let mut repeating_tasks_copy_path = dirs::data_dir()
-        .context(
-            "linux: couldn't get $HOME/.local/share/
-                windows: couldn't get C:/Users/your_user/AppData/Local/
-                mac: couldn't get /Users/your_user/Library/Application Support/
-
-                those directories should exist for your OS. please double check that they do.",
-        )
-        .expect("something went wrong with fetching the user's data dirs");
-    repeating_tasks_copy_path.push("chartodo/repeating_tasks_copy.json");
-
-    repeating_tasks_copy_path
-}
-
-mod aaa_do_this_first {
-    use super::*;
-
-    // these have been tested in other fns, these are just included here as a sanity check
-    #[test]
-    fn repeating_tasks_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/repeating_tasks.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/repeating_tasks.json";
-        let mac_path = "/Library/Application Support/chartodo/repeating_tasks.json";
-        let mut got_repeating_tasks_path: bool = false;
-        let repeating_path = path_to_repeating_tasks();
-        let repeating_path = repeating_path.to_str().unwrap();
-
-        if repeating_path.contains(linux_path)
-            | repeating_path.contains(windows_path)
-            | repeating_path.contains(mac_path)
-        {
-            got_repeating_tasks_path = true;
-        }
-
-        assert!(got_repeating_tasks_path);
-    }
-
-    #[test]
-    fn repeating_tasks_copy_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/repeating_tasks_copy.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/repeating_tasks_copy.json";
-        let mac_path = "/Library/Application Support/chartodo/repeating_tasks_copy.json";
-        let mut got_repeating_tasks_copy_path: bool = false;
-        let repeating_tasks_copy_path = repeating_tasks_copy_path();
-        let repeating_tasks_copy_path = repeating_tasks_copy_path.to_str().unwrap();
-
-        if repeating_tasks_copy_path.contains(linux_path)
-            | repeating_tasks_copy_path.contains(windows_path)
-            | repeating_tasks_copy_path.contains(mac_path)
-        {
-            got_repeating_tasks_copy_path = true;
-        }
-
-        assert!(got_repeating_tasks_copy_path);
-    }
-
-    #[test]
-    fn aaaa_repeating_tasks_clone_file() {
-        // name is aaaa so it's done first
-        // since we will be modifying the original file to run a test, the original data must be
-        // preserved first
-        std::fs::File::create(repeating_tasks_copy_path())
-            .context("failed to create repeating_tasks_copy.json")
-            .expect("failed to create a copy during unit test");
-
-        std::fs::copy(path_to_repeating_tasks(), repeating_tasks_copy_path())
-            .context("failed to copy repeating_tasks.json to repeating_tasks_copy.json")
-            .expect("failed to copy original file to copy file during unit test");
-    }
-}
+// cargo test --test repeating_done_tests
 
 mod repeating_done_rmdone {
     use super::*;
 
     #[test]
     fn rmdone_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-rmdone");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-rmdone",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-rmd");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-rmdone",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_empty_done");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -140,20 +60,24 @@ mod repeating_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-rmdone").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating done list is currently empty.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_abrev_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_abrev_empty_done");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -167,20 +91,24 @@ mod repeating_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-rmd").arg("1").arg("1").arg("2");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating done list is currently empty.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_no_valid_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -190,11 +118,11 @@ mod repeating_done_rmdone {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ]
             }
@@ -205,25 +133,29 @@ mod repeating_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-rmdone")
             .arg("0")
             .arg("a")
             .arg("2")
             .arg("");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable -- they were \
             all either negative, zero, or exceeded the repeating done list's length.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_abrev_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_abrev_no_valid_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -233,11 +165,11 @@ mod repeating_done_rmdone {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ]
             }
@@ -248,21 +180,25 @@ mod repeating_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-rmd").arg("0").arg("a").arg("2").arg("");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable -- they were \
             all either negative, zero, or exceeded the repeating done list's length.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_should_do_repeating_cleartodo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_should_do_repeating_cleartodo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -272,61 +208,61 @@ mod repeating_done_rmdone {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ]
             }
@@ -337,10 +273,10 @@ mod repeating_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-rmdone")
             .arg("1")
             .arg("1")
@@ -349,16 +285,20 @@ mod repeating_done_rmdone {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You want to remove all of the finished tasks in a \
             relatively long repeating done list. You should do repeating-cleardone.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_should_do_repeating_cleartodo_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_should_do_repeating_cleartodo_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -368,61 +308,61 @@ mod repeating_done_rmdone {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ]
             }
@@ -433,21 +373,25 @@ mod repeating_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-rmdone").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You want to remove all of the finished tasks in a \
             relatively long repeating done list. You should do repeating-cleardone.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_abrev_should_do_repeating_cleartodo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_abrev_should_do_repeating_cleartodo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -457,61 +401,61 @@ mod repeating_done_rmdone {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ]
             }
@@ -522,10 +466,10 @@ mod repeating_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-rmd")
             .arg("1")
             .arg("1")
@@ -534,17 +478,21 @@ mod repeating_done_rmdone {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You want to remove all of the finished tasks in a \
             relatively long repeating done list. You should do repeating-cleardone.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_abrev_should_do_repeating_cleartodo_range() -> Result<(), Box<dyn std::error::Error>>
     {
+        let data_dir = fresh_data_dir("rmdone_abrev_should_do_repeating_cleartodo_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -554,61 +502,61 @@ mod repeating_done_rmdone {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": true,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ]
             }
@@ -619,21 +567,25 @@ mod repeating_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-rmd").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You want to remove all of the finished tasks in a \
             relatively long repeating done list. You should do repeating-cleardone.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -668,10 +620,10 @@ mod repeating_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-rmdone").arg("1").arg("1");
         assert!(cmd
             .assert()
@@ -679,11 +631,15 @@ mod repeating_done_rmdone {
             .try_stdout(predicate::str::contains("1: repeating-task-1"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -718,10 +674,10 @@ mod repeating_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-rmd").arg("1").arg("1");
         assert!(cmd
             .assert()
@@ -729,11 +685,15 @@ mod repeating_done_rmdone {
             .try_stdout(predicate::str::contains("1: repeating-task-1"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_multiple_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -798,10 +758,10 @@ mod repeating_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-rmdone")
             .arg("1")
             .arg("1")
@@ -812,11 +772,15 @@ mod repeating_done_rmdone {
             .stdout(predicate::str::contains("1: repeating-task-4"))
             .stdout(predicate::str::contains("2: repeating-task-2"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_multiple_is_correct_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_multiple_is_correct_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -881,21 +845,25 @@ mod repeating_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-rmdone").arg("1-3");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: repeating-task-2"))
             .stdout(predicate::str::contains("2: repeating-task-1"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_abrev_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_abrev_multiple_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -960,21 +928,25 @@ mod repeating_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-rmd").arg("1").arg("1").arg("3").arg("5");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: repeating-task-4"))
             .stdout(predicate::str::contains("2: repeating-task-2"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmdone_abrev_multiple_is_correct_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmdone_abrev_multiple_is_correct_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1039,16 +1011,18 @@ mod repeating_done_rmdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-rmd").arg("1-3");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: repeating-task-2"))
             .stdout(predicate::str::contains("2: repeating-task-1"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -1058,30 +1032,40 @@ mod repeating_done_notdone {
 
     #[test]
     fn notdone_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-notdone");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-notdone",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-nd");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-notdone",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_empty_done");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1095,20 +1079,24 @@ mod repeating_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-notdone").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating done list is currently empty.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_abrev_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_abrev_empty_done");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1122,20 +1110,24 @@ mod repeating_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-nd").arg("1").arg("1").arg("2");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating done list is currently empty.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_no_valid_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1160,25 +1152,29 @@ mod repeating_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-notdone")
             .arg("0")
             .arg("a")
             .arg("2")
             .arg("");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable -- they were \
             all either negative, zero, or exceeded the repeating done list's length.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_abrev_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_abrev_no_valid_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1203,21 +1199,25 @@ mod repeating_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-nd").arg("0").arg("a").arg("2").arg("");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable -- they were \
             all either negative, zero, or exceeded the repeating done list's length.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_should_do_repeating_notdoneall() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_should_do_repeating_notdoneall");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1292,10 +1292,10 @@ mod repeating_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-notdone")
             .arg("1")
             .arg("1")
@@ -1304,16 +1304,20 @@ mod repeating_done_notdone {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You specified an entire done list that's \
             relatively long. You should do repeating-notdoneall.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_should_do_repeating_notdoneall_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_should_do_repeating_notdoneall_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1388,21 +1392,25 @@ mod repeating_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-notdone").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You specified an entire done list that's \
             relatively long. You should do repeating-notdoneall.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_abrev_should_do_repeating_notdoneall() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_abrev_should_do_repeating_notdoneall");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1477,10 +1485,10 @@ mod repeating_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-nd")
             .arg("1")
             .arg("1")
@@ -1489,17 +1497,21 @@ mod repeating_done_notdone {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You specified an entire done list that's \
             relatively long. You should do repeating-notdoneall.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_abrev_should_do_repeating_notdoneall_range() -> Result<(), Box<dyn std::error::Error>>
     {
+        let data_dir = fresh_data_dir("notdone_abrev_should_do_repeating_notdoneall_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1574,21 +1586,25 @@ mod repeating_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-nd").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You specified an entire done list that's \
             relatively long. You should do repeating-notdoneall.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1623,21 +1639,25 @@ mod repeating_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-notdone").arg("1").arg("1");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: repeating-task-1"))
             .stdout(predicate::str::contains("1: repeating-task-2"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1672,21 +1692,25 @@ mod repeating_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-nd").arg("1").arg("1");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: repeating-task-1"))
             .stdout(predicate::str::contains("1: repeating-task-2"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_multiple_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1751,10 +1775,10 @@ mod repeating_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-notdone")
             .arg("1")
             .arg("1")
@@ -1768,11 +1792,15 @@ mod repeating_done_notdone {
             .stdout(predicate::str::contains("2: repeating-task-3"))
             .stdout(predicate::str::contains("3: repeating-task-1"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_multiple_is_correct_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_multiple_is_correct_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1837,10 +1865,10 @@ mod repeating_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-notdone").arg("1-3");
         cmd.assert()
             .success()
@@ -1850,11 +1878,15 @@ mod repeating_done_notdone {
             .stdout(predicate::str::contains("2: repeating-task-4"))
             .stdout(predicate::str::contains("3: repeating-task-3"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_abrev_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_abrev_multiple_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1919,10 +1951,10 @@ mod repeating_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-nd").arg("1").arg("1").arg("3").arg("5");
         cmd.assert()
             .success()
@@ -1932,11 +1964,15 @@ mod repeating_done_notdone {
             .stdout(predicate::str::contains("2: repeating-task-3"))
             .stdout(predicate::str::contains("3: repeating-task-1"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdone_abrev_multiple_is_correct_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdone_abrev_multiple_is_correct_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2001,10 +2037,10 @@ mod repeating_done_notdone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-nd").arg("1-3");
         cmd.assert()
             .success()
@@ -2014,6 +2050,8 @@ mod repeating_done_notdone {
             .stdout(predicate::str::contains("2: repeating-task-4"))
             .stdout(predicate::str::contains("3: repeating-task-3"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -2023,30 +2061,40 @@ mod repeating_done_cleardone {
 
     #[test]
     fn cleardone_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleardone_no_args_allowed");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-cleardone").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn cleardone_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleardone_abrev_no_args_allowed");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-cd").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn cleardone_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleardone_empty_done");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2060,20 +2108,24 @@ mod repeating_done_cleardone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-cleardone");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating done list is currently empty.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn cleardone_abrev_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleardone_abrev_empty_done");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2087,20 +2139,24 @@ mod repeating_done_cleardone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-cd");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating done list is currently empty.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn cleardone_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleardone_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2125,10 +2181,10 @@ mod repeating_done_cleardone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-cleardone");
         assert!(cmd
             .assert()
@@ -2136,11 +2192,15 @@ mod repeating_done_cleardone {
             .try_stdout(predicate::str::contains("1: repeating-task-1"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn cleardone_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleardone_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2165,10 +2225,10 @@ mod repeating_done_cleardone {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-cd");
         assert!(cmd
             .assert()
@@ -2176,6 +2236,8 @@ mod repeating_done_cleardone {
             .try_stdout(predicate::str::contains("1: repeating-task-1"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -2185,30 +2247,40 @@ mod repeating_done_notdoneall {
 
     #[test]
     fn notdoneall_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdoneall_no_args_allowed");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-notdoneall").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn notdoneall_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdoneall_abrev_no_args_allowed");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-nda").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn notdoneall_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdoneall_empty_done");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2222,20 +2294,24 @@ mod repeating_done_notdoneall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-notdoneall");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating done list is currently empty.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdoneall_abrev_empty_done() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdoneall_abrev_empty_done");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2249,20 +2325,24 @@ mod repeating_done_notdoneall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-nda");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating done list is currently empty.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdoneall_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdoneall_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2327,12 +2407,12 @@ mod repeating_done_notdoneall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-rmd").arg("1");
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-notdoneall");
         cmd.assert()
             .success()
@@ -2342,11 +2422,15 @@ mod repeating_done_notdoneall {
             .stdout(predicate::str::contains("4: repeating-task-2"))
             .stdout(predicate::str::contains("5: repeating-task-1"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn notdoneall_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("notdoneall_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2411,12 +2495,12 @@ mod repeating_done_notdoneall {
                 file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-rmd").arg("1");
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-nda");
         cmd.assert()
             .success()
@@ -2426,26 +2510,8 @@ mod repeating_done_notdoneall {
             .stdout(predicate::str::contains("4: repeating-task-2"))
             .stdout(predicate::str::contains("5: repeating-task-1"));
 
-        Ok(())
-    }
-}
-
-mod zzz_do_this_last {
-    use super::*;
+        std::fs::remove_dir_all(&data_dir)?;
 
-    #[test]
-    fn zzzz_rename_repeating_copy_to_original() {
-        // name is zzzz so it's done last
-        // now that tests are done, remove the modified original and rename copy to original
-
-        std::fs::remove_file(path_to_repeating_tasks())
-            .context("failed delete modified repeating_tasks.json after running tests")
-            .expect("failed to delete repeating_tasks.json after repeating_helpers unit tests");
-
-        std::fs::rename(repeating_tasks_copy_path(), path_to_repeating_tasks())
-            .context("failed to rename repeating_tasks_copy to repeating_tasks")
-            .expect(
-                "failed to rename repeating_tasks_copy to repeating_tasks after tests were done",
-            );
+        Ok(())
     }
 }
@@ -1,135 +1,55 @@
 use anyhow::Context;
 use assert_cmd::prelude::*;
-use chartodo::functions::{json_file_structs::*, repeating_tasks::repeating_helpers::*};
+use chartodo::functions::{json_file_structs::*, task_store::TaskList};
 use predicates::prelude::*;
-use std::{path::PathBuf, process::Command};
 
-// cargo test --test repeating_todo_tests -- --test-threads=1
+mod common;
+use common::*;
 
-fn path_to_repeating_tasks() -> PathBuf {
-    // get the data dir XDG spec and return it with path to regular_tasks.json
-    let mut repeating_tasks_path = dirs::data_dir()
-        .context(
-            "linux: couldn't get $HOME/.local/share/
-                windows: couldn't get C:/Users/your_user/AppData/Local/
-                mac: couldn't get /Users/your_user/Library/Application Support/
-
-                those directories should exist for your OS. please double check that they do.",
-        )
-        .expect("something went wrong with fetching the user's data dirs");
-    repeating_tasks_path.push("chartodo/repeating_tasks.json");
-
-    repeating_tasks_path
-}
-
-fn repeating_tasks_copy_path() -> PathBuf {
-    // get the path for repeating_tasks_copy.json, which will be used to hold the original contents
-    // of repeating_tasks.json while it's getting modified
-    let mut repeating_tasks_copy_path = dirs::data_dir()
-        .context(
-            "linux: couldn't get $HOME/.local/share/
-                windows: couldn't get C:/Users/your_user/AppData/Local/
-                mac: couldn't get /Users/your_user/Library/Application Support/
-
-                those directories should exist for your OS. please double check that they do.",
-        )
-        .expect("something went wrong with fetching the user's data dirs");
-    repeating_tasks_copy_path.push("chartodo/repeating_tasks_copy.json");
-
-    repeating_tasks_copy_path
-}
-
-mod aaa_do_this_first {
-    use super::*;
-
-    // these have been tested in other fns, these are just included here as a sanity check
-    #[test]
-    fn repeating_tasks_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/repeating_tasks.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/repeating_tasks.json";
-        let mac_path = "/Library/Application Support/chartodo/repeating_tasks.json";
-        let mut got_repeating_tasks_path: bool = false;
-        let repeating_path = path_to_repeating_tasks();
-        let repeating_path = repeating_path.to_str().unwrap();
-
-        if repeating_path.contains(linux_path)
-            | repeating_path.contains(windows_path)
-            | repeating_path.contains(mac_path)
-        {
-            got_repeating_tasks_path = true;
-        }
-
-        assert!(got_repeating_tasks_path);
-    }
-
-    #[test]
-    fn repeating_tasks_copy_path_is_correct() {
-        let linux_path = "/.local/share/chartodo/repeating_tasks_copy.json";
-        // note: windows is supposed to have \
-        let windows_path = "/AppData/Local/chartodo/repeating_tasks_copy.json";
-        let mac_path = "/Library/Application Support/chartodo/repeating_tasks_copy.json";
-        let mut got_repeating_tasks_copy_path: bool = false;
-        let repeating_tasks_copy_path = repeating_tasks_copy_path();
-        let repeating_tasks_copy_path = repeating_tasks_copy_path.to_str().unwrap();
-
-        if repeating_tasks_copy_path.contains(linux_path)
-            | repeating_tasks_copy_path.contains(windows_path)
-            | repeating_tasks_copy_path.contains(mac_path)
-        {
-            got_repeating_tasks_copy_path = true;
-        }
-
-        assert!(got_repeating_tasks_copy_path);
-    }
-
-    #[test]
-    fn aaaa_repeating_tasks_clone_file() {
-        // name is aaaa so it's done first
-        // since we will be modifying the original file to run a test, the original data must be
-        // preserved first
-        std::fs::File::create(repeating_tasks_copy_path())
-            .context("failed to create repeating_tasks_copy.json")
-            .expect("failed to create a copy during unit test");
-
-        std::fs::copy(path_to_repeating_tasks(), repeating_tasks_copy_path())
-            .context("failed to copy repeating_tasks.json to repeating_tasks_copy.json")
-            .expect("failed to copy original file to copy file during unit test");
-    }
-}
+// cargo test --test repeating_todo_tests
 
 mod repeating_todo_add {
     use super::*;
 
     #[test]
     fn add_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-add");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-add",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-a");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-add",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
     #[test]
     fn add_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_wrong_num_of_args");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-add").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: You don't have the right amount of arguments when adding \
             a repeating task.\n\tThere should be 3, 6, 9, etc. (i.e., divisible by 3) \
             arguments after 'chartodo repeating-add'. You provided 1 argument(s).\n\tFormat: \
@@ -139,15 +59,19 @@ mod repeating_todo_add {
             chartodo rp-a new-item 3 days another-item 4 years",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_abrev_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_abrev_wrong_num_of_args");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-a").arg("1").arg("2").arg("3").arg("4");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: You don't have the right amount of arguments when adding \
             a repeating task.\n\tThere should be 3, 6, 9, etc. (i.e., divisible by 3) \
             arguments after 'chartodo repeating-add'. You provided 4 argument(s).\n\tFormat: \
@@ -157,30 +81,38 @@ mod repeating_todo_add {
             chartodo rp-a new-item 3 days another-item 4 years",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_wrong_time_unit() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_wrong_time_unit");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-a")
             .arg("repeating-task")
             .arg("3")
             .arg("seconds");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your provided time unit, 'seconds', in argument set '1', \
             wasn't proper. It has to be one of the following: minutes, hours, days, weeks, \
             months, years.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_abrev_wrong_time_unit() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_abrev_wrong_time_unit");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-a")
             .arg("repeating-task")
             .arg("3")
@@ -188,19 +120,23 @@ mod repeating_todo_add {
             .arg("repeating-task-2")
             .arg("3")
             .arg("seconds");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your provided time unit, 'seconds', in argument set '2', \
             wasn't proper. It has to be one of the following: minutes, hours, days, weeks, \
             months, years.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_interval_not_u32() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_interval_not_u32");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-add")
             .arg("repeating-task")
             .arg("a")
@@ -208,36 +144,44 @@ mod repeating_todo_add {
             .arg("repeating-task-2")
             .arg("3")
             .arg("hours");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your provided interval, 'a', in argument set '1', wasn't \
             proper. It can't be negative and can't be above 4294967295 (i.e., it has to be \
             u32). Proper example: chartodo rp-a gym 2 days.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_abrev_interval_not_u32() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_abrev_interval_not_u32");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-a")
             .arg("repeating-task")
             .arg("a")
             .arg("minutes");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your provided interval, 'a', in argument set '1', wasn't \
             proper. It can't be negative and can't be above 4294967295 (i.e., it has to be \
             u32). Proper example: chartodo rp-a gym 2 days.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_interval_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_interval_is_zero");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-add")
             .arg("repeating-task")
             .arg("3")
@@ -245,18 +189,22 @@ mod repeating_todo_add {
             .arg("repeating-task-2")
             .arg("0")
             .arg("hours");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: You had an interval of 0 in argument set '2'. You can't have \
             an interval of 0, otherwise why are you even making a new repeating task?",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_abrev_interval_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_abrev_interval_is_zero");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-a")
             .arg("repeating-task")
             .arg("0")
@@ -264,16 +212,20 @@ mod repeating_todo_add {
             .arg("repeating-task-2")
             .arg("4")
             .arg("hours");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: You had an interval of 0 in argument set '1'. You can't have \
             an interval of 0, otherwise why are you even making a new repeating task?",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -287,10 +239,10 @@ mod repeating_todo_add {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-add")
             .arg("repeating-task")
             .arg("25")
@@ -300,11 +252,15 @@ mod repeating_todo_add {
             .stdout(predicate::str::contains("1: repeating-task"))
             .stdout(predicate::str::contains("interval: 25 minutes"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -318,10 +274,10 @@ mod repeating_todo_add {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-a")
             .arg("repeating-task")
             .arg("25")
@@ -331,11 +287,15 @@ mod repeating_todo_add {
             .stdout(predicate::str::contains("1: repeating-task"))
             .stdout(predicate::str::contains("interval: 25 minutes"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_multiple_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -349,10 +309,10 @@ mod repeating_todo_add {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-add")
             .arg("repeating-task-1")
             .arg("25")
@@ -367,11 +327,15 @@ mod repeating_todo_add {
             .stdout(predicate::str::contains("2: repeating-task-1"))
             .stdout(predicate::str::contains("interval: 25 minutes"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn add_abrev_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("add_abrev_multiple_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -385,10 +349,10 @@ mod repeating_todo_add {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-a")
             .arg("repeating-task-1")
             .arg("25")
@@ -403,6 +367,8 @@ mod repeating_todo_add {
             .stdout(predicate::str::contains("2: repeating-task-1"))
             .stdout(predicate::str::contains("interval: 25 minutes"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -412,33 +378,43 @@ mod repeating_todo_add_start {
 
     #[test]
     fn addstart_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addstart_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-addstart");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-addstart",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addstart_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addstart_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-as");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-addstart",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
     #[test]
     fn addstart_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addstart_wrong_num_of_args");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-addstart").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: You don't have the right amount of arguments when adding a \
             repeating task with a specific starting datetime.\n\tThere should be 5, 10, 15, \
             etc. (i.e., divisible by 5) arguments after 'chartodo repeating-addstart'. You \
@@ -450,15 +426,19 @@ mod repeating_todo_add_start {
             new-item 3 days 2099-01-01 00:00 another-item 4 years 23:59",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addstart_abrev_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addstart_abrev_wrong_num_of_args");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-as").arg("1").arg("2").arg("3").arg("4");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: You don't have the right amount of arguments when adding a \
             repeating task with a specific starting datetime.\n\tThere should be 5, 10, 15, \
             etc. (i.e., divisible by 5) arguments after 'chartodo repeating-addstart'. You \
@@ -470,32 +450,40 @@ mod repeating_todo_add_start {
             new-item 3 days 2099-01-01 00:00 another-item 4 years 23:59",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addstart_wrong_time() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addstart_wrong_time");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-addstart")
             .arg("repeating-task")
             .arg("3")
             .arg("days")
             .arg("2099-12-13")
             .arg("25:00");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your provided starting time, '25:00', in argument set '1', \
             wasn't proper. Please provide a correct starting time in a 24-hour format, e.g., \
             23:04.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addstart_abrev_wrong_time() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addstart_abrev_wrong_time");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-as")
             .arg("repeating-task")
             .arg("3")
@@ -507,19 +495,23 @@ mod repeating_todo_add_start {
             .arg("weeks")
             .arg("2099-12-13")
             .arg("25:00");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your provided starting time, '25:00', in argument set '2', \
             wasn't proper. Please provide a correct starting time in a 24-hour format, e.g., \
             23:04.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addstart_wrong_date() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addstart_wrong_date");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-addstart")
             .arg("repeating-task")
             .arg("3")
@@ -531,57 +523,69 @@ mod repeating_todo_add_start {
             .arg("months")
             .arg("2099-12-13")
             .arg("23:59");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your provided starting date, '2099-13-13', in argument set \
             '1', wasn't proper. Please provide a correct starting date in a year-month-day \
             format, e.g., 2024-05-13.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addstart_abrev_wrong_date() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addstart_abrev_wrong_date");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-as")
             .arg("repeating-task")
             .arg("3")
             .arg("days")
             .arg("2099-13-13")
             .arg("23:00");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your provided starting date, '2099-13-13', in argument set \
             '1', wasn't proper. Please provide a correct starting date in a year-month-day \
             format, e.g., 2024-05-13.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addstart_wrong_time_unit() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addstart_wrong_time_unit");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-addstart")
             .arg("repeating-task")
             .arg("3")
             .arg("seconds")
             .arg("2099-12-13")
             .arg("00:00");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your provided time unit, 'seconds', in argument set '1', \
             wasn't proper. It has to be one of the following: minutes, hours, days, weeks, \
             months, years.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addstart_abrev_wrong_time_unit() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addstart_abrev_wrong_time_unit");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-as")
             .arg("repeating-task")
             .arg("3")
@@ -593,38 +597,46 @@ mod repeating_todo_add_start {
             .arg("seconds")
             .arg("2098-12-24")
             .arg("23:25");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your provided time unit, 'seconds', in argument set '2', \
             wasn't proper. It has to be one of the following: minutes, hours, days, weeks, \
             months, years.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addstart_interval_not_u32() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addstart_interval_not_u32");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-addstart")
             .arg("repeating-task")
             .arg("a")
             .arg("minutes")
             .arg("2099-12-13")
             .arg("14:15");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your provided interval, 'a', in argument set '1', wasn't \
             proper. It can't be negative and can't be above 4294967295 (i.e., it has to be \
             u32). Proper example: chartodo rp-a gym 2 days 2020-01-01 00:00",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addstart_abrev_interval_not_u32() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addstart_abrev_interval_not_u32");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-as")
             .arg("repeating-task")
             .arg("50")
@@ -636,19 +648,23 @@ mod repeating_todo_add_start {
             .arg("years")
             .arg("2099-12-13")
             .arg("00:00");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your provided interval, 'a', in argument set '2', wasn't \
             proper. It can't be negative and can't be above 4294967295 (i.e., it has to be \
             u32). Proper example: chartodo rp-a gym 2 days 2020-01-01 00:00",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addstart_interval_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addstart_interval_is_zero");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-addstart")
             .arg("repeating-task")
             .arg("3")
@@ -660,18 +676,22 @@ mod repeating_todo_add_start {
             .arg("hours")
             .arg("2099-12-13")
             .arg("00:00");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: You provided an interval of 0 in argument set '2'. You can't \
             have an interval of 0, otherwise why are you even making a new repeating task?",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addstart_abrev_interval_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addstart_abrev_interval_is_zero");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-as")
             .arg("repeating-task")
             .arg("0")
@@ -683,16 +703,20 @@ mod repeating_todo_add_start {
             .arg("hours")
             .arg("2099-12-13")
             .arg("00:00");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: You provided an interval of 0 in argument set '1'. You can't \
             have an interval of 0, otherwise why are you even making a new repeating task?",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addstart_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addstart_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -706,10 +730,10 @@ mod repeating_todo_add_start {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-addstart")
             .arg("repeating-task")
             .arg("25")
@@ -722,11 +746,15 @@ mod repeating_todo_add_start {
             .stdout(predicate::str::contains("interval: 25 minutes"))
             .stdout(predicate::str::contains("due: 2099-12-13 00:25"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addstart_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addstart_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -740,10 +768,10 @@ mod repeating_todo_add_start {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-as")
             .arg("repeating-task")
             .arg("25")
@@ -756,6 +784,8 @@ mod repeating_todo_add_start {
             .stdout(predicate::str::contains("interval: 25 minutes"))
             .stdout(predicate::str::contains("due: 2099-12-13 00:25"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
@@ -764,6 +794,8 @@ mod repeating_todo_add_start {
     // they were typed in
     #[test]
     fn addstart_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addstart_multiple_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -777,10 +809,10 @@ mod repeating_todo_add_start {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-addstart")
             .arg("repeating-task-2")
             .arg("25")
@@ -801,11 +833,15 @@ mod repeating_todo_add_start {
             .stdout(predicate::str::contains("interval: 25 minutes"))
             .stdout(predicate::str::contains("due: 2099-01-01 00:26"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addstart_abrev_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addstart_abrev_multiple_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -819,10 +855,10 @@ mod repeating_todo_add_start {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-as")
             .arg("repeating-task-2")
             .arg("25")
@@ -843,6 +879,8 @@ mod repeating_todo_add_start {
             .stdout(predicate::str::contains("interval: 25 minutes"))
             .stdout(predicate::str::contains("due: 2099-01-01 00:25"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -852,33 +890,43 @@ mod repeating_todo_add_end {
 
     #[test]
     fn addend_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addend_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-addend");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-addend",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addend_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addend_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ae");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-addend",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
     #[test]
     fn addend_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addend_wrong_num_of_args");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-addend").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: You don't have the right amount of arguments when adding a \
             repeating task with a specific ending datetime.\n\tThere should be 5, 10, 15, \
             etc. (i.e., divisible by 5) arguments after 'chartodo repeating-addend'. You \
@@ -890,15 +938,19 @@ mod repeating_todo_add_end {
             days 2099-01-01 00:00 another-item 4 years 23:59",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addend_abrev_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addend_abrev_wrong_num_of_args");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ae").arg("1").arg("2").arg("3").arg("4");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: You don't have the right amount of arguments when adding a \
             repeating task with a specific ending datetime.\n\tThere should be 5, 10, 15, \
             etc. (i.e., divisible by 5) arguments after 'chartodo repeating-addend'. You \
@@ -910,32 +962,40 @@ mod repeating_todo_add_end {
             days 2099-01-01 00:00 another-item 4 years 23:59",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addend_wrong_time() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addend_wrong_time");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-addend")
             .arg("repeating-task")
             .arg("3")
             .arg("days")
             .arg("2099-12-13")
             .arg("25:00");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your provided ending time, '25:00', in argument set '1', \
             wasn't proper. Please provide a correct ending time in a 24-hour format, e.g., \
             23:04.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addend_abrev_wrong_time() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addend_abrev_wrong_time");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ae")
             .arg("repeating-task")
             .arg("3")
@@ -947,19 +1007,23 @@ mod repeating_todo_add_end {
             .arg("weeks")
             .arg("2099-12-13")
             .arg("25:00");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your provided ending time, '25:00', in argument set '2', \
             wasn't proper. Please provide a correct ending time in a 24-hour format, e.g., \
             23:04.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addend_wrong_date() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addend_wrong_date");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-addend")
             .arg("repeating-task")
             .arg("3")
@@ -971,57 +1035,69 @@ mod repeating_todo_add_end {
             .arg("months")
             .arg("2099-12-13")
             .arg("23:59");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your provided ending date, '2099-13-13', in argument set \
             '1', wasn't proper. Please provide a correct ending date in a year-month-day \
             format, e.g., 2024-05-12.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addend_abrev_wrong_date() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addend_abrev_wrong_date");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ae")
             .arg("repeating-task")
             .arg("3")
             .arg("days")
             .arg("2099-13-13")
             .arg("23:00");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your provided ending date, '2099-13-13', in argument set \
             '1', wasn't proper. Please provide a correct ending date in a year-month-day \
             format, e.g., 2024-05-12.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addend_wrong_time_unit() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addend_wrong_time_unit");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-addend")
             .arg("repeating-task")
             .arg("3")
             .arg("seconds")
             .arg("2099-12-13")
             .arg("00:00");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your provided time unit, 'seconds', in argument set '1', \
             wasn't proper. It has to be one of the following: minutes, hours, days, weeks, \
             months, years.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addend_abrev_wrong_time_unit() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addend_abrev_wrong_time_unit");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ae")
             .arg("repeating-task")
             .arg("3")
@@ -1033,38 +1109,46 @@ mod repeating_todo_add_end {
             .arg("seconds")
             .arg("2098-12-24")
             .arg("23:25");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your provided time unit, 'seconds', in argument set '2', \
             wasn't proper. It has to be one of the following: minutes, hours, days, weeks, \
             months, years.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addend_interval_not_u32() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addend_interval_not_u32");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-addend")
             .arg("repeating-task")
             .arg("a")
             .arg("minutes")
             .arg("2099-12-13")
             .arg("14:15");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your provided interval, 'a', in argument set '1', wasn't \
             proper. It can't be negative and can't be above 4294967295 (i.e., it has to be \
             u32). Proper example: chartodo rp-ae gym 2 days 2000-01-01 00:00",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addend_abrev_interval_not_u32() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addend_abrev_interval_not_u32");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ae")
             .arg("repeating-task")
             .arg("50")
@@ -1076,19 +1160,23 @@ mod repeating_todo_add_end {
             .arg("years")
             .arg("2099-12-13")
             .arg("00:00");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your provided interval, 'a', in argument set '2', wasn't \
             proper. It can't be negative and can't be above 4294967295 (i.e., it has to be \
             u32). Proper example: chartodo rp-ae gym 2 days 2000-01-01 00:00",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addend_interval_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addend_interval_is_zero");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-addend")
             .arg("repeating-task")
             .arg("3")
@@ -1100,18 +1188,22 @@ mod repeating_todo_add_end {
             .arg("hours")
             .arg("2099-12-13")
             .arg("00:00");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: You provided an interval of 0 in argument set 2. You can't \
             have an interval of 0, otherwise why are you even making a new repeating task?",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addend_abrev_interval_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addend_abrev_interval_is_zero");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ae")
             .arg("repeating-task")
             .arg("0")
@@ -1123,16 +1215,20 @@ mod repeating_todo_add_end {
             .arg("hours")
             .arg("2099-12-13")
             .arg("00:00");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: You provided an interval of 0 in argument set 1. You can't \
             have an interval of 0, otherwise why are you even making a new repeating task?",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addend_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addend_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1146,10 +1242,10 @@ mod repeating_todo_add_end {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-addend")
             .arg("repeating-task")
             .arg("25")
@@ -1162,11 +1258,15 @@ mod repeating_todo_add_end {
             .stdout(predicate::str::contains("interval: 25 minutes"))
             .stdout(predicate::str::contains("due: 2099-12-13 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addend_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addend_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1180,10 +1280,10 @@ mod repeating_todo_add_end {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ae")
             .arg("repeating-task")
             .arg("25")
@@ -1196,6 +1296,8 @@ mod repeating_todo_add_end {
             .stdout(predicate::str::contains("interval: 25 minutes"))
             .stdout(predicate::str::contains("due: 2099-12-13 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
@@ -1204,6 +1306,8 @@ mod repeating_todo_add_end {
     // they were typed in
     #[test]
     fn addend_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addend_multiple_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1217,10 +1321,10 @@ mod repeating_todo_add_end {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-addend")
             .arg("repeating-task-2")
             .arg("25")
@@ -1241,11 +1345,15 @@ mod repeating_todo_add_end {
             .stdout(predicate::str::contains("interval: 25 minutes"))
             .stdout(predicate::str::contains("due: 2099-01-01 00:01"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn addend_abrev_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("addend_abrev_multiple_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1259,10 +1367,10 @@ mod repeating_todo_add_end {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ae")
             .arg("repeating-task-2")
             .arg("25")
@@ -1283,6 +1391,8 @@ mod repeating_todo_add_end {
             .stdout(predicate::str::contains("interval: 25 minutes"))
             .stdout(predicate::str::contains("due: 2099-01-01 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -1292,30 +1402,40 @@ mod repeating_todo_done {
 
     #[test]
     fn done_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-done");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-done",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-d");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-done",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1329,21 +1449,25 @@ mod repeating_todo_done {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-done").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty. Try adding items \
             to it first.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_abrev_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1357,21 +1481,25 @@ mod repeating_todo_done {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-d").arg("1").arg("1").arg("2");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty. Try adding items \
             to it first.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_no_valid_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1380,11 +1508,11 @@ mod repeating_todo_done {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -1396,22 +1524,26 @@ mod repeating_todo_done {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-done").arg("0").arg("a").arg("2").arg("");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, or exceeded the repeating todo list's \
             length.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_abrev_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_abrev_no_valid_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1420,11 +1552,11 @@ mod repeating_todo_done {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -1436,22 +1568,26 @@ mod repeating_todo_done {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-d").arg("0").arg("a").arg("2").arg("");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, or exceeded the repeating todo list's \
             length.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_should_do_repeating_doneall() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_should_do_repeating_doneall");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1460,61 +1596,61 @@ mod repeating_todo_done {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -1526,10 +1662,10 @@ mod repeating_todo_done {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-done")
             .arg("1")
             .arg("1")
@@ -1538,16 +1674,20 @@ mod repeating_todo_done {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified the entire repeating todo list that's \
             relatively long. You should do chartodo repeating-doneall",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_should_do_repeating_doneall_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_should_do_repeating_doneall_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1556,61 +1696,61 @@ mod repeating_todo_done {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
-                    },
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
+                    },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -1622,21 +1762,25 @@ mod repeating_todo_done {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-done").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified the entire repeating todo list that's \
             relatively long. You should do chartodo repeating-doneall",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_abrev_should_do_repeating_doneall() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_abrev_should_do_repeating_doneall");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1645,61 +1789,61 @@ mod repeating_todo_done {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -1711,10 +1855,10 @@ mod repeating_todo_done {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-d")
             .arg("1")
             .arg("1")
@@ -1723,16 +1867,20 @@ mod repeating_todo_done {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified the entire repeating todo list that's \
             relatively long. You should do chartodo repeating-doneall",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_abrev_should_do_repeating_doneall_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_abrev_should_do_repeating_doneall_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1741,61 +1889,61 @@ mod repeating_todo_done {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -1807,21 +1955,25 @@ mod repeating_todo_done {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-d").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified the entire repeating todo list that's \
             relatively long. You should do chartodo repeating-doneall",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1856,10 +2008,10 @@ mod repeating_todo_done {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-done").arg("1").arg("1");
         cmd.assert()
             .success()
@@ -1870,11 +2022,15 @@ mod repeating_todo_done {
             .stdout(predicate::str::contains("interval: 4 days"))
             .stdout(predicate::str::contains("due: 2099-01-05 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1909,10 +2065,10 @@ mod repeating_todo_done {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-d").arg("1").arg("1");
         cmd.assert()
             .success()
@@ -1923,11 +2079,15 @@ mod repeating_todo_done {
             .stdout(predicate::str::contains("interval: 4 days"))
             .stdout(predicate::str::contains("due: 2099-01-05 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_multiple_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -1992,10 +2152,10 @@ mod repeating_todo_done {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-done")
             .arg("1")
             .arg("1")
@@ -2019,11 +2179,15 @@ mod repeating_todo_done {
             .stdout(predicate::str::contains("interval: 4 days"))
             .stdout(predicate::str::contains("due: 2099-01-05 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_multiple_is_correct_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_multiple_is_correct_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2088,10 +2252,10 @@ mod repeating_todo_done {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-done").arg("1-3");
         cmd.assert()
             .success()
@@ -2101,11 +2265,15 @@ mod repeating_todo_done {
             .stdout(predicate::str::contains("1: repeating-task-2"))
             .stdout(predicate::str::contains("2: repeating-task-1"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_abrev_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_abrev_multiple_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2170,10 +2338,10 @@ mod repeating_todo_done {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-d").arg("1").arg("1").arg("3").arg("5");
         cmd.assert()
             .success()
@@ -2193,11 +2361,15 @@ mod repeating_todo_done {
             .stdout(predicate::str::contains("interval: 4 days"))
             .stdout(predicate::str::contains("due: 2099-01-05 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn done_abrev_multiple_is_correct_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("done_abrev_multiple_is_correct_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2262,10 +2434,10 @@ mod repeating_todo_done {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-d").arg("1-3");
         cmd.assert()
             .success()
@@ -2275,6 +2447,8 @@ mod repeating_todo_done {
             .stdout(predicate::str::contains("1: repeating-task-2"))
             .stdout(predicate::str::contains("2: repeating-task-1"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -2284,54 +2458,72 @@ mod repeating_todo_reset {
 
     #[test]
     fn reset_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("reset_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-reset");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-reset/repeating-donereset",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn reset_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("reset_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-r");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-reset/repeating-donereset",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn donereset_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("donereset_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-donereset");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-reset/repeating-donereset",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn donereset_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("donereset_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-dr");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-reset/repeating-donereset",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn reset_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("reset_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2345,21 +2537,25 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-reset").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty. Try adding items \
             to it first.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn reset_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("reset_abrev_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2373,21 +2569,25 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-r").arg("1").arg("1").arg("2");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty. Try adding items \
             to it first.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn donereset_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("donereset_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2401,21 +2601,25 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-donereset").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty. Try adding items \
             to it first.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn donereset_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("donereset_abrev_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2429,21 +2633,25 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-dr").arg("1").arg("1").arg("2");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty. Try adding items \
             to it first.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn reset_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("reset_no_valid_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2452,11 +2660,11 @@ mod repeating_todo_reset {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -2468,26 +2676,30 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-reset")
             .arg("0")
             .arg("a")
             .arg("2")
             .arg("");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, or exceeded the repeating todo list's \
             length.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn reset_abrev_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("reset_abrev_no_valid_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2496,11 +2708,11 @@ mod repeating_todo_reset {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -2512,22 +2724,26 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-r").arg("0").arg("a").arg("2").arg("");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, or exceeded the repeating todo list's \
             length.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn donereset_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("donereset_no_valid_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2536,11 +2752,11 @@ mod repeating_todo_reset {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -2552,26 +2768,30 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-donereset")
             .arg("0")
             .arg("a")
             .arg("2")
             .arg("");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, or exceeded the repeating todo list's \
             length.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn donereset_abrev_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("donereset_abrev_no_valid_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2580,11 +2800,11 @@ mod repeating_todo_reset {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -2596,22 +2816,26 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-dr").arg("0").arg("a").arg("2").arg("");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, or exceeded the repeating todo list's \
             length.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn reset_should_do_repeating_resetall() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("reset_should_do_repeating_resetall");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2620,61 +2844,61 @@ mod repeating_todo_reset {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -2686,10 +2910,10 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-reset")
             .arg("1")
             .arg("1")
@@ -2698,17 +2922,21 @@ mod repeating_todo_reset {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified the entire repeating \
             todo list that's relatively long. You should do chartodo \
             repeating-resetall/repeating-doneresetall",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn reset_should_do_repeating_resetall_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("reset_should_do_repeating_resetall_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2717,61 +2945,61 @@ mod repeating_todo_reset {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -2783,22 +3011,26 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-reset").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified the entire repeating \
             todo list that's relatively long. You should do chartodo \
             repeating-resetall/repeating-doneresetall",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn reset_abrev_should_do_repeating_resetall() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("reset_abrev_should_do_repeating_resetall");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2807,61 +3039,61 @@ mod repeating_todo_reset {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -2873,10 +3105,10 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-r")
             .arg("1")
             .arg("1")
@@ -2885,17 +3117,21 @@ mod repeating_todo_reset {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified the entire repeating \
             todo list that's relatively long. You should do chartodo \
             repeating-resetall/repeating-doneresetall",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn reset_abrev_should_do_repeating_resetall_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("reset_abrev_should_do_repeating_resetall_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2904,61 +3140,61 @@ mod repeating_todo_reset {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -2970,22 +3206,26 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-r").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified the entire repeating \
             todo list that's relatively long. You should do chartodo \
             repeating-resetall/repeating-doneresetall",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn donereset_should_do_repeating_doneresetall() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("donereset_should_do_repeating_doneresetall");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -2994,61 +3234,61 @@ mod repeating_todo_reset {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -3060,10 +3300,10 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-donereset")
             .arg("1")
             .arg("1")
@@ -3072,18 +3312,22 @@ mod repeating_todo_reset {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified the entire repeating \
             todo list that's relatively long. You should do chartodo \
             repeating-resetall/repeating-doneresetall",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn donereset_should_do_repeating_doneresetall_range() -> Result<(), Box<dyn std::error::Error>>
     {
+        let data_dir = fresh_data_dir("donereset_should_do_repeating_doneresetall_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3092,61 +3336,61 @@ mod repeating_todo_reset {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -3158,23 +3402,27 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-donereset").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified the entire repeating \
             todo list that's relatively long. You should do chartodo \
             repeating-resetall/repeating-doneresetall",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn donereset_abrev_should_do_repeating_doneresetall() -> Result<(), Box<dyn std::error::Error>>
     {
+        let data_dir = fresh_data_dir("donereset_abrev_should_do_repeating_doneresetall");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3183,61 +3431,61 @@ mod repeating_todo_reset {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -3249,10 +3497,10 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-dr")
             .arg("1")
             .arg("1")
@@ -3261,18 +3509,22 @@ mod repeating_todo_reset {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified the entire repeating \
             todo list that's relatively long. You should do chartodo \
             repeating-resetall/repeating-doneresetall",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn donereset_abrev_should_do_repeating_doneresetall_range(
     ) -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("donereset_abrev_should_do_repeating_doneresetall_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3281,61 +3533,61 @@ mod repeating_todo_reset {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -3347,23 +3599,27 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-dr").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified the entire repeating \
             todo list that's relatively long. You should do chartodo \
             repeating-resetall/repeating-doneresetall",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     // might be impossible to check when it's due
     #[test]
     fn reset_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("reset_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3398,10 +3654,10 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-reset").arg("1").arg("1");
         cmd.assert()
             .success()
@@ -3410,11 +3666,15 @@ mod repeating_todo_reset {
             .stdout(predicate::str::contains("2: repeating-task-2"))
             .stdout(predicate::str::contains("interval: 4 days"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn reset_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("reset_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3449,10 +3709,10 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-r").arg("1").arg("1");
         cmd.assert()
             .success()
@@ -3461,11 +3721,15 @@ mod repeating_todo_reset {
             .stdout(predicate::str::contains("2: repeating-task-2"))
             .stdout(predicate::str::contains("interval: 4 days"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn donereset_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("donereset_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3500,10 +3764,10 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-donereset").arg("1").arg("1");
         cmd.assert()
             .success()
@@ -3512,11 +3776,15 @@ mod repeating_todo_reset {
             .stdout(predicate::str::contains("2: repeating-task-2"))
             .stdout(predicate::str::contains("interval: 4 days"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn donereset_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("donereset_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3551,10 +3819,10 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-dr").arg("1").arg("1");
         cmd.assert()
             .success()
@@ -3563,11 +3831,15 @@ mod repeating_todo_reset {
             .stdout(predicate::str::contains("2: repeating-task-2"))
             .stdout(predicate::str::contains("interval: 4 days"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn reset_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("reset_multiple_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3632,10 +3904,10 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-reset")
             .arg("2")
             .arg("2")
@@ -3654,11 +3926,15 @@ mod repeating_todo_reset {
             .stdout(predicate::str::contains("5: repeating-task-1"))
             .stdout(predicate::str::contains("interval: 5 days"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn reset_multiple_is_correct_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("reset_multiple_is_correct_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3723,10 +3999,10 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-reset").arg("3-5");
         cmd.assert()
             .success()
@@ -3741,11 +4017,15 @@ mod repeating_todo_reset {
             .stdout(predicate::str::contains("5: repeating-task-4"))
             .stdout(predicate::str::contains("interval: 5 days"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn reset_abrev_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("reset_abrev_multiple_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3810,10 +4090,10 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-r").arg("2").arg("2").arg("4").arg("6");
         cmd.assert()
             .success()
@@ -3828,11 +4108,15 @@ mod repeating_todo_reset {
             .stdout(predicate::str::contains("5: repeating-task-1"))
             .stdout(predicate::str::contains("interval: 5 days"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn reset_abrev_multiple_is_correct_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("reset_abrev_multiple_is_correct_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3897,10 +4181,10 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-r").arg("3-5");
         cmd.assert()
             .success()
@@ -3915,11 +4199,15 @@ mod repeating_todo_reset {
             .stdout(predicate::str::contains("5: repeating-task-4"))
             .stdout(predicate::str::contains("interval: 5 days"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn donereset_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("donereset_multiple_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -3984,10 +4272,10 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-donereset")
             .arg("2")
             .arg("2")
@@ -4006,11 +4294,15 @@ mod repeating_todo_reset {
             .stdout(predicate::str::contains("5: repeating-task-1"))
             .stdout(predicate::str::contains("interval: 5 days"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn donereset_multiple_is_correct_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("donereset_multiple_is_correct_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4075,10 +4367,10 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-donereset").arg("3-5");
         cmd.assert()
             .success()
@@ -4093,11 +4385,15 @@ mod repeating_todo_reset {
             .stdout(predicate::str::contains("5: repeating-task-4"))
             .stdout(predicate::str::contains("interval: 5 days"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn donereset_abrev_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("donereset_abrev_multiple_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4162,10 +4458,10 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-dr").arg("2").arg("2").arg("4").arg("6");
         cmd.assert()
             .success()
@@ -4180,11 +4476,15 @@ mod repeating_todo_reset {
             .stdout(predicate::str::contains("5: repeating-task-1"))
             .stdout(predicate::str::contains("interval: 5 days"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn donereset_abrev_multiple_is_correct_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("donereset_abrev_multiple_is_correct_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4249,10 +4549,10 @@ mod repeating_todo_reset {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-dr").arg("3-5");
         cmd.assert()
             .success()
@@ -4267,6 +4567,8 @@ mod repeating_todo_reset {
             .stdout(predicate::str::contains("5: repeating-task-4"))
             .stdout(predicate::str::contains("interval: 5 days"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -4276,30 +4578,40 @@ mod repeating_todo_rmtodo {
 
     #[test]
     fn rmtodo_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-rmtodo");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-rmtodo",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-rmt");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-rmtodo",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4313,21 +4625,25 @@ mod repeating_todo_rmtodo {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-rmtodo").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty. Try adding items \
             to it first.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_abrev_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4341,21 +4657,25 @@ mod repeating_todo_rmtodo {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-rmt").arg("1").arg("1").arg("2");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty. Try adding items \
             to it first.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_no_valid_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4364,11 +4684,11 @@ mod repeating_todo_rmtodo {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -4380,26 +4700,30 @@ mod repeating_todo_rmtodo {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-rmtodo")
             .arg("0")
             .arg("a")
             .arg("2")
             .arg("");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, or exceeded the repeating todo list's \
             length.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_abrev_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_abrev_no_valid_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4408,11 +4732,11 @@ mod repeating_todo_rmtodo {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -4424,22 +4748,26 @@ mod repeating_todo_rmtodo {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-rmt").arg("0").arg("a").arg("2").arg("");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, or exceeded the repeating todo list's \
             length.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_should_do_repeating_cleartodo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_should_do_repeating_cleartodo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4448,61 +4776,61 @@ mod repeating_todo_rmtodo {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -4514,10 +4842,10 @@ mod repeating_todo_rmtodo {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-rmtodo")
             .arg("1")
             .arg("1")
@@ -4526,16 +4854,20 @@ mod repeating_todo_rmtodo {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified the entire repeating todo list, one \
             that's relatively long. You should do repeating-cleartodo",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_should_do_repeating_cleartodo_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_should_do_repeating_cleartodo_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4544,61 +4876,61 @@ mod repeating_todo_rmtodo {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -4610,21 +4942,25 @@ mod repeating_todo_rmtodo {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-rmtodo").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified the entire repeating todo list, one \
             that's relatively long. You should do repeating-cleartodo",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_abrev_should_do_repeating_cleartodo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_abrev_should_do_repeating_cleartodo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4633,61 +4969,61 @@ mod repeating_todo_rmtodo {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -4699,10 +5035,10 @@ mod repeating_todo_rmtodo {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-rmt")
             .arg("1")
             .arg("1")
@@ -4711,17 +5047,21 @@ mod repeating_todo_rmtodo {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified the entire repeating todo list, one \
             that's relatively long. You should do repeating-cleartodo",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_abrev_should_do_repeating_cleartodo_range() -> Result<(), Box<dyn std::error::Error>>
     {
+        let data_dir = fresh_data_dir("rmtodo_abrev_should_do_repeating_cleartodo_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4730,61 +5070,61 @@ mod repeating_todo_rmtodo {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -4796,21 +5136,25 @@ mod repeating_todo_rmtodo {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-rmt").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You've specified the entire repeating todo list, one \
             that's relatively long. You should do repeating-cleartodo",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4845,10 +5189,10 @@ mod repeating_todo_rmtodo {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-rmtodo").arg("1").arg("1");
         cmd.assert()
             .success()
@@ -4856,11 +5200,15 @@ mod repeating_todo_rmtodo {
             .stdout(predicate::str::contains("interval: 4 days"))
             .stdout(predicate::str::contains("due: 2099-01-05 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4895,10 +5243,10 @@ mod repeating_todo_rmtodo {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-rmt").arg("1").arg("1");
         cmd.assert()
             .success()
@@ -4906,11 +5254,15 @@ mod repeating_todo_rmtodo {
             .stdout(predicate::str::contains("interval: 4 days"))
             .stdout(predicate::str::contains("due: 2099-01-05 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_multiple_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -4975,10 +5327,10 @@ mod repeating_todo_rmtodo {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-rmtodo")
             .arg("1")
             .arg("1")
@@ -4993,11 +5345,15 @@ mod repeating_todo_rmtodo {
             .stdout(predicate::str::contains("interval: 4 days"))
             .stdout(predicate::str::contains("due: 2099-01-05 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_multiple_is_correct_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_multiple_is_correct_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5062,21 +5418,25 @@ mod repeating_todo_rmtodo {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-rmtodo").arg("1-3");
         cmd.assert()
             .success()
             .stdout(predicate::str::contains("1: repeating-task-2"))
             .stdout(predicate::str::contains("2: repeating-task-1"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_abrev_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_abrev_multiple_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5141,10 +5501,10 @@ mod repeating_todo_rmtodo {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-rmt").arg("1").arg("1").arg("3").arg("5");
         cmd.assert()
             .success()
@@ -5155,11 +5515,15 @@ mod repeating_todo_rmtodo {
             .stdout(predicate::str::contains("interval: 4 days"))
             .stdout(predicate::str::contains("due: 2099-01-05 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn rmtodo_abrev_multiple_is_correct_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("rmtodo_abrev_multiple_is_correct_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5224,10 +5588,10 @@ mod repeating_todo_rmtodo {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-rmt").arg("1-3");
         cmd.assert()
             .success()
@@ -5235,6 +5599,8 @@ mod repeating_todo_rmtodo {
             .stdout(predicate::str::contains("2: repeating-task-1"))
             .stdout(predicate::str::contains("interval: 4 days"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -5244,30 +5610,40 @@ mod repeating_todo_show_start {
 
     #[test]
     fn start_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("start_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-start");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-start",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn start_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("start_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-s");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-start",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn start_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("start_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5281,21 +5657,25 @@ mod repeating_todo_show_start {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-start").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty. Try adding items \
             to it first.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn start_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("start_abrev_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5309,21 +5689,25 @@ mod repeating_todo_show_start {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-s").arg("1").arg("1").arg("2");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty. Try adding items \
             to it first.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn start_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("start_no_valid_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5332,11 +5716,11 @@ mod repeating_todo_show_start {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -5348,26 +5732,30 @@ mod repeating_todo_show_start {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-start")
             .arg("0")
             .arg("a")
             .arg("2")
             .arg("");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, or exceeded the repeating todo list's \
             length.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn start_abrev_no_valid_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("start_abrev_no_valid_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5376,11 +5764,11 @@ mod repeating_todo_show_start {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -5392,22 +5780,26 @@ mod repeating_todo_show_start {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-s").arg("0").arg("a").arg("2").arg("");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: None of the positions you provided were viable \
             -- they were all either negative, zero, or exceeded the repeating todo list's \
             length.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn start_should_do_repeating_startall() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("start_should_do_repeating_startall");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5416,61 +5808,61 @@ mod repeating_todo_show_start {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -5482,10 +5874,10 @@ mod repeating_todo_show_start {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-start")
             .arg("1")
             .arg("1")
@@ -5494,16 +5886,20 @@ mod repeating_todo_show_start {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You want to show the start times for an entire list that's \
             relatively long. You should do repeating-startall.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn start_should_do_repeating_startall_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("start_should_do_repeating_startall_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5512,61 +5908,61 @@ mod repeating_todo_show_start {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -5578,21 +5974,25 @@ mod repeating_todo_show_start {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-start").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You want to show the start times for an entire list that's \
             relatively long. You should do repeating-startall.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn start_abrev_should_do_repeating_startall() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("start_abrev_should_do_repeating_startall");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5601,61 +6001,61 @@ mod repeating_todo_show_start {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -5667,10 +6067,10 @@ mod repeating_todo_show_start {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-s")
             .arg("1")
             .arg("1")
@@ -5679,16 +6079,20 @@ mod repeating_todo_show_start {
             .arg("4")
             .arg("5")
             .arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You want to show the start times for an entire list that's \
             relatively long. You should do repeating-startall.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn start_abrev_should_do_repeating_startall_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("start_abrev_should_do_repeating_startall_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5697,61 +6101,61 @@ mod repeating_todo_show_start {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     },
                     {
                         "task": "repeating",
                         "date": "2025-01-01",
                         "time": "00:00",
-                        "repeat_number": null,
-                        "repeat_unit": null,
-                        "repeat_done": null,
-                        "repeat_original_date": null,
-                        "repeat_original_time": null
+                        "repeat_number": 1,
+                        "repeat_unit": "days",
+                        "repeat_done": false,
+                        "repeat_original_date": "2024-12-31",
+                        "repeat_original_time": "00:00"
                     }
                 ],
                 "done": []
@@ -5763,21 +6167,25 @@ mod repeating_todo_show_start {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-s").arg("1-6").arg("6");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "WARNING: You want to show the start times for an entire list that's \
             relatively long. You should do repeating-startall.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn start_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("start_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5812,20 +6220,24 @@ mod repeating_todo_show_start {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-start").arg("1").arg("1");
         cmd.assert().success().stdout(predicate::str::contains(
             "task: repeating-task\n\tstart: 2025-01-01 00:00",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn start_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("start_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5860,20 +6272,24 @@ mod repeating_todo_show_start {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-s").arg("1").arg("1");
         cmd.assert().success().stdout(predicate::str::contains(
             "task: repeating-task\n\tstart: 2025-01-01 00:00",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn start_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("start_multiple_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -5938,10 +6354,10 @@ mod repeating_todo_show_start {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-start")
             .arg("1")
             .arg("1")
@@ -5953,11 +6369,15 @@ mod repeating_todo_show_start {
             repeating-task\n\tstart: 2099-01-01 00:00",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn start_multiple_is_correct_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("start_multiple_is_correct_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -6022,10 +6442,10 @@ mod repeating_todo_show_start {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-start").arg("1-3");
         cmd.assert().success().stdout(predicate::str::contains(
             "task: repeating-task-5\n\tstart: \
@@ -6033,11 +6453,15 @@ mod repeating_todo_show_start {
             repeating-task-3\n\tstart: 2099-01-01 00:00",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn start_abrev_multiple_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("start_abrev_multiple_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -6102,10 +6526,10 @@ mod repeating_todo_show_start {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-s").arg("1").arg("1").arg("3").arg("5");
         cmd.assert().success().stdout(predicate::str::contains(
             "task: repeating-task-5\n\tstart: \
@@ -6113,11 +6537,15 @@ mod repeating_todo_show_start {
             repeating-task\n\tstart: 2099-01-01 00:00",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn start_abrev_multiple_is_correct_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("start_abrev_multiple_is_correct_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -6182,10 +6610,10 @@ mod repeating_todo_show_start {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-s").arg("1-3");
         cmd.assert().success().stdout(predicate::str::contains(
             "task: repeating-task-5\n\tstart: \
@@ -6193,6 +6621,8 @@ mod repeating_todo_show_start {
             repeating-task-3\n\tstart: 2099-01-01 00:00",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -6202,30 +6632,40 @@ mod repeating_todo_doneall {
 
     #[test]
     fn doneall_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("doneall_no_args_allowed");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-doneall").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn doneall_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("doneall_abrev_no_args_allowed");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-da").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn doneall_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("doneall_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -6239,21 +6679,25 @@ mod repeating_todo_doneall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-doneall");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty, so you can't \
             change any todos to done.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn doneall_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("doneall_abrev_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -6267,21 +6711,25 @@ mod repeating_todo_doneall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-da");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty, so you can't \
             change any todos to done.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn doneall_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("doneall_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -6346,13 +6794,13 @@ mod repeating_todo_doneall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-rmt").arg("2");
         cmd.assert().success();
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-doneall");
         cmd.assert()
             .success()
@@ -6369,11 +6817,15 @@ mod repeating_todo_doneall {
             .stdout(predicate::str::contains("interval: 5 days"))
             .stdout(predicate::str::contains("done: 2099-01-05 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn doneall_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("doneall_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -6438,13 +6890,13 @@ mod repeating_todo_doneall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-rmt").arg("2");
         cmd.assert().success();
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-da");
         cmd.assert()
             .success()
@@ -6461,6 +6913,8 @@ mod repeating_todo_doneall {
             .stdout(predicate::str::contains("interval: 5 days"))
             .stdout(predicate::str::contains("done: 2099-01-05 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -6470,30 +6924,40 @@ mod repeating_todo_cleartodo {
 
     #[test]
     fn cleartodo_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleartodo_no_args_allowed");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-cleartodo").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn cleartodo_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleartodo_abrev_no_args_allowed");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ct").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn cleartodo_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleartodo_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -6507,21 +6971,25 @@ mod repeating_todo_cleartodo {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-cleartodo");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty. Try adding items \
             to it first before removing any.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn cleartodo_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleartodo_abrev_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -6535,21 +7003,25 @@ mod repeating_todo_cleartodo {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ct");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty. Try adding items \
             to it first before removing any.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn cleartodo_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleartodo_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -6614,10 +7086,10 @@ mod repeating_todo_cleartodo {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-cleartodo");
         assert!(cmd
             .assert()
@@ -6625,11 +7097,15 @@ mod repeating_todo_cleartodo {
             .try_stdout(predicate::str::contains("1: repeating-task-3"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn cleartodo_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("cleartodo_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -6694,10 +7170,10 @@ mod repeating_todo_cleartodo {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ct");
         assert!(cmd
             .assert()
@@ -6705,6 +7181,8 @@ mod repeating_todo_cleartodo {
             .try_stdout(predicate::str::contains("1: repeating-task-3"))
             .is_err());
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -6714,54 +7192,72 @@ mod repeating_todo_resetall {
 
     #[test]
     fn resetall_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("resetall_no_args_allowed");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-resetall").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn resetall_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("resetall_abrev_no_args_allowed");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ra").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn doneresetall_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("doneresetall_no_args_allowed");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-doneresetall").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn doneresetall_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("doneresetall_abrev_no_args_allowed");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-dra").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn resetall_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("resetall_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -6775,21 +7271,25 @@ mod repeating_todo_resetall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-resetall");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty. Try adding items \
             to it first.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn resetall_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("resetall_abrev_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -6803,21 +7303,25 @@ mod repeating_todo_resetall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ra");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty. Try adding items \
             to it first.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn doneresetall_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("doneresetall_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -6831,21 +7335,25 @@ mod repeating_todo_resetall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-doneresetall");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty. Try adding items \
             to it first.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn doneresetall_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("doneresetall_abrev_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -6859,21 +7367,25 @@ mod repeating_todo_resetall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-dra");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty. Try adding items \
             to it first.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn resetall_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("resetall_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -6938,10 +7450,10 @@ mod repeating_todo_resetall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-resetall");
         cmd.assert()
             .success()
@@ -6956,11 +7468,15 @@ mod repeating_todo_resetall {
             .stdout(predicate::str::contains("5: repeating-task-1"))
             .stdout(predicate::str::contains("interval: 5 days"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn resetall_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("resetall_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -7025,10 +7541,10 @@ mod repeating_todo_resetall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ra");
         cmd.assert()
             .success()
@@ -7043,11 +7559,15 @@ mod repeating_todo_resetall {
             .stdout(predicate::str::contains("5: repeating-task-1"))
             .stdout(predicate::str::contains("interval: 5 days"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn doneresetall_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("doneresetall_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -7112,10 +7632,10 @@ mod repeating_todo_resetall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-doneresetall");
         cmd.assert()
             .success()
@@ -7130,11 +7650,15 @@ mod repeating_todo_resetall {
             .stdout(predicate::str::contains("5: repeating-task-1"))
             .stdout(predicate::str::contains("interval: 5 days"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn doneresetall_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("doneresetall_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -7199,10 +7723,10 @@ mod repeating_todo_resetall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-dra");
         cmd.assert()
             .success()
@@ -7217,6 +7741,8 @@ mod repeating_todo_resetall {
             .stdout(predicate::str::contains("5: repeating-task-1"))
             .stdout(predicate::str::contains("interval: 5 days"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -7226,30 +7752,40 @@ mod repeating_todo_showstartall {
 
     #[test]
     fn startall_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("startall_no_args_allowed");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-startall").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn startall_abrev_no_args_allowed() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("startall_abrev_no_args_allowed");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-sa").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "Invalid command. Please try again, or try chartodo help",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn startall_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("startall_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -7263,21 +7799,25 @@ mod repeating_todo_showstartall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-startall");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty. Try adding items \
             to it first.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn startall_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("startall_abrev_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -7291,21 +7831,25 @@ mod repeating_todo_showstartall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-sa");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty. Try adding items \
             to it first.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn startall_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("startall_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -7370,10 +7914,10 @@ mod repeating_todo_showstartall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-startall");
         cmd.assert().success().stdout(predicate::str::contains(
             "task: \
@@ -7383,11 +7927,15 @@ mod repeating_todo_showstartall {
             2099-01-01 00:00",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn startall_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("startall_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -7452,10 +8000,10 @@ mod repeating_todo_showstartall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-sa");
         cmd.assert().success().stdout(predicate::str::contains(
             "task: \
@@ -7465,6 +8013,8 @@ mod repeating_todo_showstartall {
             2099-01-01 00:00",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -7474,30 +8024,40 @@ mod repeating_todo_editall {
 
     #[test]
     fn editall_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editall");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-editall",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ea");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-editall",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -7511,21 +8071,32 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("repeating-editall").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("repeating-editall")
+            .arg("1")
+            .arg("new-task")
+            .arg("3")
+            .arg("days")
+            .arg("end")
+            .arg("2099-01-01")
+            .arg("00:00");
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_abrev_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -7539,21 +8110,32 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("rp-ea").arg("1").arg("1").arg("2");
-        cmd.assert().success().stdout(predicate::str::contains(
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("rp-ea")
+            .arg("1")
+            .arg("1")
+            .arg("2")
+            .arg("days")
+            .arg("end")
+            .arg("2099-01-01")
+            .arg("00:00");
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_wrong_num_of_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -7578,30 +8160,24 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editall").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: You \
-            must specify the repeating todo's position and all the parameters that will be \
-            edited.\n\tThere should be 7 arguments after 'chartodo repeating-editall'. You \
-            provided 1 argument(s).\n\tExample: chartodo repeating-editall ~position ~task \
-            ~interval ~time-unit ~start/end ~date ~time.\n\t\tDate must be in a yy-mm-dd \
-            format. Time must be in a 24-hour format.\n\t\tOnly the following time-units are \
-            allowed: minute(s), hour(s), day(s), week(s), month(s), and year(s).\n\t\tYou \
-            must specify if you're editing the ending or starting datetime by using the \
-            keywords 'start' or 'end'.\n\tExample (with end): chartodo rp-ea 4 new-item 3 \
-            days end 2150-01-01 00:00.\n\tExample (with start): chartodo rp-ea 4 new-item 3 \
-            days start 2150-01-01 00:00",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_abrev_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_abrev_wrong_num_of_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -7626,30 +8202,24 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ea").arg("1").arg("2").arg("3");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: You \
-            must specify the repeating todo's position and all the parameters that will be \
-            edited.\n\tThere should be 7 arguments after 'chartodo repeating-editall'. You \
-            provided 3 argument(s).\n\tExample: chartodo repeating-editall ~position ~task \
-            ~interval ~time-unit ~start/end ~date ~time.\n\t\tDate must be in a yy-mm-dd \
-            format. Time must be in a 24-hour format.\n\t\tOnly the following time-units are \
-            allowed: minute(s), hour(s), day(s), week(s), month(s), and year(s).\n\t\tYou \
-            must specify if you're editing the ending or starting datetime by using the \
-            keywords 'start' or 'end'.\n\tExample (with end): chartodo rp-ea 4 new-item 3 \
-            days end 2150-01-01 00:00.\n\tExample (with start): chartodo rp-ea 4 new-item 3 \
-            days start 2150-01-01 00:00",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_position_not_usize");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -7674,10 +8244,10 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editall")
             .arg("a")
             .arg("new-repeating-task")
@@ -7686,16 +8256,20 @@ mod repeating_todo_editall {
             .arg("start")
             .arg("2199-02-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The position you provided, 'a', was invalid. Try something \
             between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_abrev_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_abrev_position_not_usize");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -7720,10 +8294,10 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ea")
             .arg("a")
             .arg("new-repeating-task")
@@ -7732,16 +8306,20 @@ mod repeating_todo_editall {
             .arg("start")
             .arg("2199-02-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The position you provided, 'a', was invalid. Try something \
             between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_position_is_zero");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -7766,10 +8344,10 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editall")
             .arg("0")
             .arg("new-repeating-task")
@@ -7778,15 +8356,19 @@ mod repeating_todo_editall {
             .arg("start")
             .arg("2199-02-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_abrev_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_abrev_position_is_zero");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -7811,10 +8393,10 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ea")
             .arg("0")
             .arg("new-repeating-task")
@@ -7823,15 +8405,19 @@ mod repeating_todo_editall {
             .arg("start")
             .arg("2199-02-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_position_not_in_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -7856,10 +8442,10 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editall")
             .arg("2")
             .arg("new-repeating-task")
@@ -7868,16 +8454,19 @@ mod repeating_todo_editall {
             .arg("start")
             .arg("2199-02-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: The position you provided, '2', exceeds the repeating todo \
-            list's length. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_abrev_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_abrev_position_not_in_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -7902,10 +8491,10 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ea")
             .arg("2")
             .arg("new-repeating-task")
@@ -7914,16 +8503,19 @@ mod repeating_todo_editall {
             .arg("start")
             .arg("2199-02-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: The position you provided, '2', exceeds the repeating todo \
-            list's length. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_interval_isnt_u32() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_interval_isnt_u32");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -7948,10 +8540,10 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editall")
             .arg("1")
             .arg("new-repeating-task")
@@ -7960,16 +8552,20 @@ mod repeating_todo_editall {
             .arg("start")
             .arg("2199-02-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The interval you provided, 'b', wasn't proper. It must be in \
             the range of 1 - 4294967295 (i.e., it has to be u32).",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_abrev_interval_isnt_u32() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_abrev_interval_isnt_u32");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -7994,10 +8590,10 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ea")
             .arg("1")
             .arg("new-repeating-task")
@@ -8006,16 +8602,20 @@ mod repeating_todo_editall {
             .arg("start")
             .arg("2199-02-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The interval you provided, 'b', wasn't proper. It must be in \
             the range of 1 - 4294967295 (i.e., it has to be u32).",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_interval_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_interval_is_zero");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -8040,10 +8640,10 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editall")
             .arg("1")
             .arg("new-repeating-task")
@@ -8052,16 +8652,20 @@ mod repeating_todo_editall {
             .arg("start")
             .arg("2199-02-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your interval can't be 0, otherwise why are you even setting \
             a repeating task?",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_abrev_interval_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_abrev_interval_is_zero");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -8086,10 +8690,10 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ea")
             .arg("1")
             .arg("new-repeating-task")
@@ -8098,16 +8702,20 @@ mod repeating_todo_editall {
             .arg("start")
             .arg("2199-02-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your interval can't be 0, otherwise why are you even setting \
             a repeating task?",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_time_unit_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_time_unit_is_wrong");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -8132,10 +8740,10 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editall")
             .arg("1")
             .arg("new-repeating-task")
@@ -8144,16 +8752,20 @@ mod repeating_todo_editall {
             .arg("start")
             .arg("2199-02-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The time unit you provided, 'haha', wasn't proper. Proper \
             examples: minutes, hours, days, weeks, months or years.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_abrev_time_unit_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_abrev_time_unit_is_wrong");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -8178,10 +8790,10 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ea")
             .arg("1")
             .arg("new-repeating-task")
@@ -8190,16 +8802,20 @@ mod repeating_todo_editall {
             .arg("start")
             .arg("2199-02-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The time unit you provided, 'seconds', wasn't proper. Proper \
             examples: minutes, hours, days, weeks, months or years.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_date_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_date_is_wrong");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -8224,10 +8840,10 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editall")
             .arg("1")
             .arg("new-repeating-task")
@@ -8236,16 +8852,20 @@ mod repeating_todo_editall {
             .arg("start")
             .arg("2199-13-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The date you provided, '2199-13-02', wasn't proper. It must \
             be in the following format: Year-Month-Day, e.g., 2000-12-13.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_abrev_date_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_abrev_date_is_wrong");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -8270,10 +8890,10 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ea")
             .arg("1")
             .arg("new-repeating-task")
@@ -8282,16 +8902,20 @@ mod repeating_todo_editall {
             .arg("start")
             .arg("2199-13-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The date you provided, '2199-13-02', wasn't proper. It must \
             be in the following format: Year-Month-Day, e.g., 2000-12-13.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_time_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_time_is_wrong");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -8316,10 +8940,10 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editall")
             .arg("1")
             .arg("new-repeating-task")
@@ -8328,16 +8952,20 @@ mod repeating_todo_editall {
             .arg("start")
             .arg("2199-02-02")
             .arg("25:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The time you provided, '25:01', wasn't proper. It must be in \
             the following 24-hour format: H:M, e.g., 13:08.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_abrev_time_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_abrev_time_is_wrong");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -8362,10 +8990,10 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ea")
             .arg("1")
             .arg("new-repeating-task")
@@ -8374,16 +9002,20 @@ mod repeating_todo_editall {
             .arg("start")
             .arg("2199-02-02")
             .arg("25:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The time you provided, '25:01', wasn't proper. It must be in \
             the following 24-hour format: H:M, e.g., 13:08.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_not_start_or_end() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_not_start_or_end");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -8408,10 +9040,10 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editall")
             .arg("1")
             .arg("new-repeating-task")
@@ -8420,18 +9052,22 @@ mod repeating_todo_editall {
             .arg("haha")
             .arg("2199-02-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: 'haha' isn't correct. You must specify whether the given \
             datetime is the starting or ending datetime. Please use the 'start' or 'end' \
             keywords and nothing else, e.g., repeating-editall 1 new-repeating-task 4 weeks \
             start 2099-12-13 13:08",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_abrev_not_start_or_end() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_abrev_not_start_or_end");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -8456,10 +9092,10 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ea")
             .arg("1")
             .arg("new-repeating-task")
@@ -8468,18 +9104,22 @@ mod repeating_todo_editall {
             .arg("haha")
             .arg("2199-02-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: 'haha' isn't correct. You must specify whether the given \
             datetime is the starting or ending datetime. Please use the 'start' or 'end' \
             keywords and nothing else, e.g., repeating-editall 1 new-repeating-task 4 weeks \
             start 2099-12-13 13:08",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -8504,10 +9144,10 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editall")
             .arg("1")
             .arg("new-repeating-task")
@@ -8522,11 +9162,15 @@ mod repeating_todo_editall {
             .stdout(predicate::str::contains("interval: 4 weeks"))
             .stdout(predicate::str::contains("due: 2199-03-02 01:01"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editall_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editall_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -8551,10 +9195,10 @@ mod repeating_todo_editall {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ea")
             .arg("1")
             .arg("new-repeating-task")
@@ -8569,6 +9213,8 @@ mod repeating_todo_editall {
             .stdout(predicate::str::contains("interval: 4 weeks"))
             .stdout(predicate::str::contains("due: 2199-02-02 01:01"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -8578,30 +9224,40 @@ mod repeating_todo_edittask {
 
     #[test]
     fn edittask_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-edittask");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-edittask",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-eta");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-edittask",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -8615,21 +9271,25 @@ mod repeating_todo_edittask {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("repeating-edittask").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("repeating-edittask").arg("1").arg("new-task");
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_abrev_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -8643,21 +9303,25 @@ mod repeating_todo_edittask {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("rp-eta").arg("1").arg("1").arg("2");
-        cmd.assert().success().stdout(predicate::str::contains(
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("rp-eta").arg("1").arg("new-task");
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_wrong_num_of_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -8682,24 +9346,24 @@ mod repeating_todo_edittask {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-edittask").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: You must \
-            specify the repeating todo's position and the new task to change it to.\n\tThere \
-            should be 2 arguments after 'chartodo repeating-edittask'. You provided 1 \
-            argument(s).\n\tFormat: chartodo repeating-edittask ~position ~task.\n\tExample: \
-            chartodo rp-eta 4 new-item.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_abrev_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_abrev_wrong_num_of_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -8724,24 +9388,24 @@ mod repeating_todo_edittask {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-eta").arg("1").arg("2").arg("3");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: You must \
-            specify the repeating todo's position and the new task to change it to.\n\tThere \
-            should be 2 arguments after 'chartodo repeating-edittask'. You provided 3 \
-            argument(s).\n\tFormat: chartodo repeating-edittask ~position ~task.\n\tExample: \
-            chartodo rp-eta 4 new-item.",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn edittask_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_position_not_usize");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -8766,23 +9430,27 @@ mod repeating_todo_edittask {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-edittask")
             .arg("a")
             .arg("new-repeating-task");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The position you provided, 'a', was invalid. Try something \
             between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_abrev_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_abrev_position_not_usize");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -8807,21 +9475,25 @@ mod repeating_todo_edittask {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-eta").arg("a").arg("new-repeating-task");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The position you provided, 'a', was invalid. Try something \
             between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_position_is_zero");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -8846,22 +9518,26 @@ mod repeating_todo_edittask {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-edittask")
             .arg("0")
             .arg("new-repeating-task");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_abrev_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_abrev_position_is_zero");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -8886,20 +9562,24 @@ mod repeating_todo_edittask {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-eta").arg("0").arg("new-repeating-task");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_position_not_in_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -8924,23 +9604,26 @@ mod repeating_todo_edittask {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-edittask")
             .arg("2")
             .arg("new-repeating-task");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Your position, '2', exceeds the repeating todo list's \
-            length. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_abrev_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_abrev_position_not_in_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -8965,21 +9648,24 @@ mod repeating_todo_edittask {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-eta").arg("2").arg("new-repeating-task");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Your position, '2', exceeds the repeating todo list's \
-            length. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9004,10 +9690,10 @@ mod repeating_todo_edittask {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-edittask")
             .arg("1")
             .arg("new-repeating-task");
@@ -9017,11 +9703,15 @@ mod repeating_todo_edittask {
             .stdout(predicate::str::contains("interval: 5 days"))
             .stdout(predicate::str::contains("due: 2099-01-06 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn edittask_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("edittask_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9046,10 +9736,10 @@ mod repeating_todo_edittask {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-eta").arg("1").arg("new-repeating-task");
         cmd.assert()
             .success()
@@ -9057,6 +9747,8 @@ mod repeating_todo_edittask {
             .stdout(predicate::str::contains("interval: 5 days"))
             .stdout(predicate::str::contains("due: 2099-01-06 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -9066,30 +9758,40 @@ mod repeating_todo_editinterval {
 
     #[test]
     fn editinterval_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editinterval_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editinterval");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-editinterval",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editinterval_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editinterval_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ei");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-editinterval",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editinterval_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editinterval_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9103,21 +9805,25 @@ mod repeating_todo_editinterval {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("repeating-editinterval").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("repeating-editinterval").arg("1").arg("3");
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editinterval_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editinterval_abrev_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9131,21 +9837,25 @@ mod repeating_todo_editinterval {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("rp-ei").arg("1").arg("1").arg("2");
-        cmd.assert().success().stdout(predicate::str::contains(
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("rp-ei").arg("1").arg("3");
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editinterval_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editinterval_wrong_num_of_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9170,26 +9880,24 @@ mod repeating_todo_editinterval {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editinterval").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: You \
-            must specify the repeating todo's position and what to edit the interval \
-            to.\n\tThere should be 2 arguments after 'chartodo repeating-editinterval'. You \
-            provided 1 argument(s).\n\tFormat: chartodo repeating-editinterval ~position \
-            ~interval.\n\tExample: chartodo rp-ei 4 3.\n\t\t'4' would be the todo task's \
-            position and '3' would be the new interval, i.e., repeating task 4 would now \
-            have an interval of '3 days'.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editinterval_abrev_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editinterval_abrev_wrong_num_of_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9214,26 +9922,24 @@ mod repeating_todo_editinterval {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ei").arg("1").arg("2").arg("3");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: You \
-            must specify the repeating todo's position and what to edit the interval \
-            to.\n\tThere should be 2 arguments after 'chartodo repeating-editinterval'. You \
-            provided 3 argument(s).\n\tFormat: chartodo repeating-editinterval ~position \
-            ~interval.\n\tExample: chartodo rp-ei 4 3.\n\t\t'4' would be the todo task's \
-            position and '3' would be the new interval, i.e., repeating task 4 would now \
-            have an interval of '3 days'.",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn editinterval_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editinterval_position_not_usize");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9258,21 +9964,25 @@ mod repeating_todo_editinterval {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editinterval").arg("a").arg("11");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The position you provided, 'a', was invalid. Try something \
             between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editinterval_abrev_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editinterval_abrev_position_not_usize");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9297,21 +10007,25 @@ mod repeating_todo_editinterval {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ei").arg("a").arg("11");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The position you provided, 'a', was invalid. Try something \
             between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editinterval_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editinterval_position_is_zero");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9336,20 +10050,24 @@ mod repeating_todo_editinterval {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editinterval").arg("0").arg("11");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editinterval_abrev_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editinterval_abrev_position_is_zero");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9374,20 +10092,24 @@ mod repeating_todo_editinterval {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ei").arg("0").arg("11");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editinterval_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editinterval_position_not_in_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9412,21 +10134,24 @@ mod repeating_todo_editinterval {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editinterval").arg("2").arg("11");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Your position, '2', exceeds the repeating todo list's \
-            length. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editinterval_abrev_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editinterval_abrev_position_not_in_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9451,21 +10176,24 @@ mod repeating_todo_editinterval {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ei").arg("2").arg("11");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Your position, '2', exceeds the repeating todo list's \
-            length. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editinterval_interval_isnt_u32() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editinterval_interval_isnt_u32");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9490,21 +10218,25 @@ mod repeating_todo_editinterval {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editinterval").arg("1").arg("b");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The interval you provided, 'b', wasn't proper. It must be in \
             the range of 1 - 4294967295 (i.e., it has to be u32).",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editinterval_abrev_interval_isnt_u32() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editinterval_abrev_interval_isnt_u32");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9529,21 +10261,25 @@ mod repeating_todo_editinterval {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ei").arg("1").arg("b");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The interval you provided, 'b', wasn't proper. It must be in \
             the range of 1 - 4294967295 (i.e., it has to be u32).",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editinterval_interval_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editinterval_interval_is_zero");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9568,21 +10304,25 @@ mod repeating_todo_editinterval {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editinterval").arg("1").arg("0");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your interval can't be 0, otherwise why are you even setting \
             a repeating task?",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editinterval_abrev_interval_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editinterval_abrev_interval_is_zero");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9607,21 +10347,25 @@ mod repeating_todo_editinterval {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ei").arg("1").arg("0");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your interval can't be 0, otherwise why are you even setting \
             a repeating task?",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editinterval_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editinterval_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9646,10 +10390,10 @@ mod repeating_todo_editinterval {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editinterval").arg("1").arg("11");
         cmd.assert()
             .success()
@@ -9657,11 +10401,15 @@ mod repeating_todo_editinterval {
             .stdout(predicate::str::contains("interval: 11 days"))
             .stdout(predicate::str::contains("due: 2099-01-12 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editinterval_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editinterval_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9686,10 +10434,10 @@ mod repeating_todo_editinterval {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ei").arg("1").arg("11");
         cmd.assert()
             .success()
@@ -9697,6 +10445,8 @@ mod repeating_todo_editinterval {
             .stdout(predicate::str::contains("interval: 11 days"))
             .stdout(predicate::str::contains("due: 2099-01-12 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -9706,30 +10456,40 @@ mod repeating_todo_edittimeunit {
 
     #[test]
     fn editunit_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editunit_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editunit");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-editunit",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editunit_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editunit_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-eu");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-editunit",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editunit_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editunit_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9743,21 +10503,25 @@ mod repeating_todo_edittimeunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("repeating-editunit").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("repeating-editunit").arg("1").arg("days");
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editunit_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editunit_abrev_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9771,21 +10535,25 @@ mod repeating_todo_edittimeunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("rp-eu").arg("1").arg("1").arg("2");
-        cmd.assert().success().stdout(predicate::str::contains(
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("rp-eu").arg("1").arg("days");
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editunit_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editunit_wrong_num_of_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9810,25 +10578,24 @@ mod repeating_todo_edittimeunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editunit").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: \
-            You must specify the repeating todo's position and what to edit the time \
-            unit to.\n\tThere should be 2 arguments after 'chartodo repeating-eu'. You \
-            provided 1 arguments().\n\tFormat: chartodo repeating-editunit ~position \
-            ~time-unit.\n\tExample: chartodo rp-eu 4 weeks.\n\t\tThat would change repeating \
-            task #4's time unit to 'weeks'.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editunit_abrev_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editunit_abrev_wrong_num_of_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9853,25 +10620,24 @@ mod repeating_todo_edittimeunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-eu").arg("1").arg("2").arg("3");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: \
-            You must specify the repeating todo's position and what to edit the time \
-            unit to.\n\tThere should be 2 arguments after 'chartodo repeating-eu'. You \
-            provided 3 arguments().\n\tFormat: chartodo repeating-editunit ~position \
-            ~time-unit.\n\tExample: chartodo rp-eu 4 weeks.\n\t\tThat would change repeating \
-            task #4's time unit to 'weeks'.",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn editunit_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editunit_position_not_usize");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9896,21 +10662,25 @@ mod repeating_todo_edittimeunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editunit").arg("a").arg("weeks");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The position you provided, 'a', was invalid. Try something \
             between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editunit_abrev_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editunit_abrev_position_not_usize");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9935,21 +10705,25 @@ mod repeating_todo_edittimeunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-eu").arg("a").arg("weeks");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The position you provided, 'a', was invalid. Try something \
             between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editunit_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editunit_position_is_zero");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -9974,20 +10748,24 @@ mod repeating_todo_edittimeunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editunit").arg("0").arg("weeks");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editunit_abrev_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editunit_abrev_position_is_zero");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10012,20 +10790,24 @@ mod repeating_todo_edittimeunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-eu").arg("0").arg("weeks");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editunit_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editunit_position_not_in_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10050,21 +10832,24 @@ mod repeating_todo_edittimeunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editunit").arg("2").arg("weeks");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Your position, '2', exceeds the repeating todo list's \
-            length. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editunit_abrev_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editunit_abrev_position_not_in_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10089,21 +10874,24 @@ mod repeating_todo_edittimeunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-eu").arg("2").arg("weeks");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Your position, '2', exceeds the repeating todo list's \
-            length. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editunit_time_unit_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editunit_time_unit_is_wrong");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10128,21 +10916,25 @@ mod repeating_todo_edittimeunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editunit").arg("1").arg("haha");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The time unit you provided, 'haha', wasn't proper. Proper \
             examples: minutes, hours, days, weeks, months or years.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editunit_abrev_time_unit_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editunit_abrev_time_unit_is_wrong");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10167,21 +10959,25 @@ mod repeating_todo_edittimeunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-eu").arg("1").arg("seconds");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The time unit you provided, 'seconds', wasn't proper. Proper \
             examples: minutes, hours, days, weeks, months or years.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editunit_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editunit_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10206,10 +11002,10 @@ mod repeating_todo_edittimeunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editunit").arg("1").arg("weeks");
         // dates are weird and inconsistent. not a chrono issue, it's a me issue
         cmd.assert()
@@ -10218,11 +11014,15 @@ mod repeating_todo_edittimeunit {
             .stdout(predicate::str::contains("interval: 5 weeks"))
             .stdout(predicate::str::contains("due: 2099-02-05 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editunit_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editunit_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10247,10 +11047,10 @@ mod repeating_todo_edittimeunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-eu").arg("1").arg("weeks");
         // dates are weird and inconsistent. not a chrono issue, it's a me issue
         cmd.assert()
@@ -10259,6 +11059,8 @@ mod repeating_todo_edittimeunit {
             .stdout(predicate::str::contains("interval: 5 weeks"))
             .stdout(predicate::str::contains("due: 2099-02-05 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -10268,30 +11070,40 @@ mod repeating_todo_editintervalunit {
 
     #[test]
     fn editintervalunit_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editintervalunit_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editintervalunit");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-editintervalunit",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editintervalunit_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editintervalunit_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-eiu");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-editintervalunit",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editintervalunit_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editintervalunit_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10305,21 +11117,28 @@ mod repeating_todo_editintervalunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("repeating-editintervalunit").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("repeating-editintervalunit")
+            .arg("1")
+            .arg("3")
+            .arg("days");
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editintervalunit_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editintervalunit_abrev_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10333,21 +11152,25 @@ mod repeating_todo_editintervalunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-eiu").arg("1").arg("1").arg("2");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editintervalunit_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editintervalunit_wrong_num_of_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10372,25 +11195,24 @@ mod repeating_todo_editintervalunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editintervalunit").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: \
-            You must specify the repeating todo's position and what to change the \
-            interval and time unit to.\n\tThere should be 3 arguments after 'chartodo \
-            repeating-editintervalunit'. You provided 1 argument(s).\n\tFormat: chartodo \
-            repeating-editintervalunit ~position ~interval ~time-unit.\n\tExample: chartodo \
-            rp-eiu 4 3 days.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editintervalunit_abrev_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editintervalunit_abrev_wrong_num_of_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10415,25 +11237,24 @@ mod repeating_todo_editintervalunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-eiu").arg("1").arg("2").arg("3").arg("4");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: \
-            You must specify the repeating todo's position and what to change the \
-            interval and time unit to.\n\tThere should be 3 arguments after 'chartodo \
-            repeating-editintervalunit'. You provided 4 argument(s).\n\tFormat: chartodo \
-            repeating-editintervalunit ~position ~interval ~time-unit.\n\tExample: chartodo \
-            rp-eiu 4 3 days.",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn editintervalunit_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editintervalunit_position_not_usize");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10458,24 +11279,27 @@ mod repeating_todo_editintervalunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editintervalunit")
             .arg("a")
             .arg("4")
             .arg("weeks");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: The position you provided, 'a', wasn't valid. Try something \
-            between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, 'a', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editintervalunit_abrev_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editintervalunit_abrev_position_not_usize");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10500,21 +11324,24 @@ mod repeating_todo_editintervalunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-eiu").arg("a").arg("4").arg("weeks");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: The position you provided, 'a', wasn't valid. Try something \
-            between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, 'a', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editintervalunit_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editintervalunit_position_is_zero");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10539,23 +11366,27 @@ mod repeating_todo_editintervalunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editintervalunit")
             .arg("0")
             .arg("4")
             .arg("weeks");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editintervalunit_abrev_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editintervalunit_abrev_position_is_zero");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10580,20 +11411,24 @@ mod repeating_todo_editintervalunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-eiu").arg("0").arg("4").arg("weeks");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editintervalunit_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editintervalunit_position_not_in_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10618,24 +11453,27 @@ mod repeating_todo_editintervalunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editintervalunit")
             .arg("2")
             .arg("4")
             .arg("weeks");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Your position, '2', exceeds the repeating todo list's \
-            length. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editintervalunit_abrev_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editintervalunit_abrev_position_not_in_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10660,21 +11498,24 @@ mod repeating_todo_editintervalunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-eiu").arg("2").arg("4").arg("weeks");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Your position, '2', exceeds the repeating todo list's \
-            length. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editintervalunit_interval_isnt_u32() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editintervalunit_interval_isnt_u32");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10699,24 +11540,28 @@ mod repeating_todo_editintervalunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editintervalunit")
             .arg("1")
             .arg("b")
             .arg("weeks");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The interval you provided, 'b', wasn't proper. It must be in \
             the range of 1 - 4294967295 (i.e., it has to be u32).",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editintervalunit_abrev_interval_isnt_u32() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editintervalunit_abrev_interval_isnt_u32");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10741,21 +11586,25 @@ mod repeating_todo_editintervalunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-eiu").arg("1").arg("b").arg("weeks");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The interval you provided, 'b', wasn't proper. It must be in \
             the range of 1 - 4294967295 (i.e., it has to be u32).",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editintervalunit_interval_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editintervalunit_interval_is_zero");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10780,24 +11629,28 @@ mod repeating_todo_editintervalunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editintervalunit")
             .arg("1")
             .arg("0")
             .arg("weeks");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your interval can't be 0, otherwise why are you even setting \
             a repeating task?",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editintervalunit_abrev_interval_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editintervalunit_abrev_interval_is_zero");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10822,21 +11675,25 @@ mod repeating_todo_editintervalunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-eiu").arg("1").arg("0").arg("weeks");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: Your interval can't be 0, otherwise why are you even setting \
             a repeating task?",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editintervalunit_time_unit_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editintervalunit_time_unit_is_wrong");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10861,24 +11718,28 @@ mod repeating_todo_editintervalunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editintervalunit")
             .arg("1")
             .arg("4")
             .arg("haha");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The time unit you provided, 'haha', wasn't proper. Proper \
             examples: minutes, hours, days, weeks, months or years.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editintervalunit_abrev_time_unit_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editintervalunit_abrev_time_unit_is_wrong");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10903,21 +11764,25 @@ mod repeating_todo_editintervalunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-eiu").arg("1").arg("4").arg("seconds");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The time unit you provided, 'seconds', wasn't proper. Proper \
             examples: minutes, hours, days, weeks, months or years.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editintervalunit_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editintervalunit_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10942,10 +11807,10 @@ mod repeating_todo_editintervalunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editintervalunit")
             .arg("1")
             .arg("4")
@@ -10956,11 +11821,15 @@ mod repeating_todo_editintervalunit {
             .stdout(predicate::str::contains("interval: 4 weeks"))
             .stdout(predicate::str::contains("due: 2099-01-29 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editintervalunit_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editintervalunit_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -10985,10 +11854,10 @@ mod repeating_todo_editintervalunit {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-eiu").arg("1").arg("4").arg("weeks");
         cmd.assert()
             .success()
@@ -10996,6 +11865,8 @@ mod repeating_todo_editintervalunit {
             .stdout(predicate::str::contains("interval: 4 weeks"))
             .stdout(predicate::str::contains("due: 2099-01-29 00:00"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -11005,30 +11876,40 @@ mod repeating_todo_editstart {
 
     #[test]
     fn editstart_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editstart_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editstart");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-editstart",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editstart_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editstart_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-es");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-editstart",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editstart_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editstart_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11042,21 +11923,28 @@ mod repeating_todo_editstart {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("repeating-editstart").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("repeating-editstart")
+            .arg("1")
+            .arg("2099-01-01")
+            .arg("00:00");
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editstart_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editstart_abrev_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11070,21 +11958,25 @@ mod repeating_todo_editstart {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-es").arg("1").arg("1").arg("2");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editstart_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editstart_wrong_num_of_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11109,26 +12001,24 @@ mod repeating_todo_editstart {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editstart").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: You \
-            must specify the repeating todo's position and what to change the repeating \
-            task's starting datetime to.\n\tThere should be 3 arguments after 'chartodo \
-            repeating-editstart'. You provided 1 argument(s).\n\tFormat: chartodo \
-            repeating-editstart ~position ~date ~time.\n\t\tDate should be in a yy-mm-dd \
-            format. Time should be in a 24-hour format.\n\tExample: chartodo rp-es 4 \
-            2100-12-24 13:08",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editstart_abrev_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editstart_abrev_wrong_num_of_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11153,26 +12043,24 @@ mod repeating_todo_editstart {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-es").arg("1").arg("2").arg("3").arg("4");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: You \
-            must specify the repeating todo's position and what to change the repeating \
-            task's starting datetime to.\n\tThere should be 3 arguments after 'chartodo \
-            repeating-editstart'. You provided 4 argument(s).\n\tFormat: chartodo \
-            repeating-editstart ~position ~date ~time.\n\t\tDate should be in a yy-mm-dd \
-            format. Time should be in a 24-hour format.\n\tExample: chartodo rp-es 4 \
-            2100-12-24 13:08",
-        ));
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("unexpected argument"));
+
+        std::fs::remove_dir_all(&data_dir)?;
 
         Ok(())
     }
 
     #[test]
     fn editstart_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editstart_position_not_usize");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11197,24 +12085,28 @@ mod repeating_todo_editstart {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editstart")
             .arg("a")
             .arg("2199-02-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The position you provided, 'a', was invalid. Try something \
             between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editstart_abrev_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editstart_abrev_position_not_usize");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11239,21 +12131,25 @@ mod repeating_todo_editstart {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-es").arg("a").arg("2199-02-02").arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The position you provided, 'a', was invalid. Try something \
             between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editstart_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editstart_position_is_zero");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11278,23 +12174,27 @@ mod repeating_todo_editstart {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editstart")
             .arg("0")
             .arg("2199-02-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editstart_abrev_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editstart_abrev_position_is_zero");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11319,20 +12219,24 @@ mod repeating_todo_editstart {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-es").arg("0").arg("2199-02-02").arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editstart_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editstart_position_not_in_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11357,24 +12261,27 @@ mod repeating_todo_editstart {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editstart")
             .arg("2")
             .arg("2199-02-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Your position, '2', exceeds the repeating todo list's \
-            length. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editstart_abrev_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editstart_abrev_position_not_in_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11399,21 +12306,24 @@ mod repeating_todo_editstart {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-es").arg("2").arg("2199-02-02").arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Your position, '2', exceeds the repeating todo list's \
-            length. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editstart_date_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editstart_date_is_wrong");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11438,24 +12348,28 @@ mod repeating_todo_editstart {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editstart")
             .arg("1")
             .arg("2199-13-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The date you provided, '2199-13-02', wasn't proper. It must \
             be in the following format: Year-Month-Day, e.g., 2000-12-13.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editstart_abrev_date_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editstart_abrev_date_is_wrong");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11480,21 +12394,25 @@ mod repeating_todo_editstart {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-es").arg("1").arg("2199-13-02").arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The date you provided, '2199-13-02', wasn't proper. It must \
             be in the following format: Year-Month-Day, e.g., 2000-12-13.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editstart_time_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editstart_time_is_wrong");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11519,24 +12437,28 @@ mod repeating_todo_editstart {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editstart")
             .arg("1")
             .arg("2199-02-02")
             .arg("25:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The time you provided, '25:01', wasn't proper. It must be in \
             the following 24-hour format: H:M, e.g., 13:08.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editstart_abrev_time_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editstart_abrev_time_is_wrong");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11561,21 +12483,25 @@ mod repeating_todo_editstart {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-es").arg("1").arg("2199-02-02").arg("25:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The time you provided, '25:01', wasn't proper. It must be in \
             the following 24-hour format: H:M, e.g., 13:08.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editstart_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editstart_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11600,10 +12526,10 @@ mod repeating_todo_editstart {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editstart")
             .arg("1")
             .arg("2199-02-02")
@@ -11614,11 +12540,15 @@ mod repeating_todo_editstart {
             .stdout(predicate::str::contains("interval: 5 days"))
             .stdout(predicate::str::contains("due: 2199-02-07 01:01"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editstart_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editstart_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11643,10 +12573,10 @@ mod repeating_todo_editstart {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-es").arg("1").arg("2199-02-02").arg("01:01");
         cmd.assert()
             .success()
@@ -11654,6 +12584,8 @@ mod repeating_todo_editstart {
             .stdout(predicate::str::contains("interval: 5 days"))
             .stdout(predicate::str::contains("due: 2199-02-07 01:01"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 }
@@ -11663,30 +12595,40 @@ mod repeating_todo_editend {
 
     #[test]
     fn editend_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editend_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editend");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-editend",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editend_abrev_nothing() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editend_abrev_nothing");
+
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ee");
         cmd.assert().failure().stderr(predicate::str::contains(
-            "didn't provide arguments for repeating-editend",
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editend_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editend_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11700,21 +12642,28 @@ mod repeating_todo_editend {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
-        cmd.arg("repeating-editend").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
+        let mut cmd = chartodo(&data_dir, &[]);
+        cmd.arg("repeating-editend")
+            .arg("1")
+            .arg("2099-01-01")
+            .arg("00:00");
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editend_abrev_empty_todo() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editend_abrev_empty_todo");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11728,21 +12677,25 @@ mod repeating_todo_editend {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ee").arg("1").arg("1").arg("2");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The repeating todo list is currently empty, so there are no \
             todos that can be edited.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editend_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editend_wrong_num_of_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11767,26 +12720,24 @@ mod repeating_todo_editend {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editend").arg("1");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: \
-            You must specify the repeating todo's position and what to change the \
-            repeating task's ending datetime to.\n\tThere should be 3 arguments after \
-            'chartodo repeating-editend'. You provided 1 argument(s).\n\tFormat: chartodo \
-            repeating-editend ~position ~date ~time.\n\t\tDate should be in a yy-mm-dd \
-            format. Time should be in a 24-hour format.\n\tExample: chartodo rp-ee 4 \
-            2100-12-14 13:08",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editend_abrev_wrong_num_of_args() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editend_abrev_wrong_num_of_args");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11811,26 +12762,24 @@ mod repeating_todo_editend {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ee").arg("1").arg("2");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: \
-            You must specify the repeating todo's position and what to change the \
-            repeating task's ending datetime to.\n\tThere should be 3 arguments after \
-            'chartodo repeating-editend'. You provided 2 argument(s).\n\tFormat: chartodo \
-            repeating-editend ~position ~date ~time.\n\t\tDate should be in a yy-mm-dd \
-            format. Time should be in a 24-hour format.\n\tExample: chartodo rp-ee 4 \
-            2100-12-14 13:08",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "the following required arguments were not provided",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editend_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editend_position_not_usize");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11855,24 +12804,27 @@ mod repeating_todo_editend {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editend")
             .arg("a")
             .arg("2199-02-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: The position you provided, 'a', wasn't valid. Try something \
-            between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, 'a', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editend_abrev_position_not_usize() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editend_abrev_position_not_usize");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11897,21 +12849,24 @@ mod repeating_todo_editend {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ee").arg("a").arg("2199-02-02").arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: The position you provided, 'a', wasn't valid. Try something \
-            between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, 'a', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editend_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editend_position_is_zero");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11936,23 +12891,27 @@ mod repeating_todo_editend {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editend")
             .arg("0")
             .arg("2199-02-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editend_abrev_position_is_zero() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editend_abrev_position_is_zero");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -11977,20 +12936,24 @@ mod repeating_todo_editend {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ee").arg("0").arg("2199-02-02").arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Positions can't be zero. They have to be 1 and above.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '0', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editend_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editend_position_not_in_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -12015,24 +12978,27 @@ mod repeating_todo_editend {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editend")
             .arg("2")
             .arg("2199-02-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Your position, '2', exceeds the repeating todo list's \
-            length. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editend_abrev_position_not_in_range() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editend_abrev_position_not_in_range");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -12057,21 +13023,24 @@ mod repeating_todo_editend {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ee").arg("2").arg("2199-02-02").arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
-            "ERROR: Your position, '2', exceeds the repeating todo list's \
-            length. Try something between 1 and 1.",
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "ERROR: The position you provided, '2', was invalid. Try something between 1 and 1.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editend_date_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editend_date_is_wrong");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -12096,24 +13065,28 @@ mod repeating_todo_editend {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editend")
             .arg("1")
             .arg("2199-13-02")
             .arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The date you provided, '2199-13-02', wasn't proper. It must \
             be in the following format: Year-Month-Day, e.g., 2000-12-13.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editend_abrev_date_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editend_abrev_date_is_wrong");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -12138,21 +13111,25 @@ mod repeating_todo_editend {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ee").arg("1").arg("2199-13-02").arg("01:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The date you provided, '2199-13-02', wasn't proper. It must \
             be in the following format: Year-Month-Day, e.g., 2000-12-13.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editend_time_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editend_time_is_wrong");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -12177,24 +13154,28 @@ mod repeating_todo_editend {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editend")
             .arg("1")
             .arg("2199-02-02")
             .arg("25:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The time you provided, '25:01', wasn't proper. It must be in \
             the following 24-hour format: H:M, e.g., 13:08.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editend_abrev_time_is_wrong() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editend_abrev_time_is_wrong");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -12219,21 +13200,25 @@ mod repeating_todo_editend {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ee").arg("1").arg("2199-02-02").arg("25:01");
-        cmd.assert().success().stdout(predicate::str::contains(
+        cmd.assert().failure().stderr(predicate::str::contains(
             "ERROR: The time you provided, '25:01', wasn't proper. It must be in \
             the following 24-hour format: H:M, e.g., 13:08.",
         ));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editend_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editend_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -12258,10 +13243,10 @@ mod repeating_todo_editend {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("repeating-editend")
             .arg("1")
             .arg("2199-02-02")
@@ -12272,11 +13257,15 @@ mod repeating_todo_editend {
             .stdout(predicate::str::contains("interval: 5 days"))
             .stdout(predicate::str::contains("due: 2199-02-02 01:01"));
 
+        std::fs::remove_dir_all(&data_dir)?;
+
         Ok(())
     }
 
     #[test]
     fn editend_abrev_is_correct() -> Result<(), Box<dyn std::error::Error>> {
+        let data_dir = fresh_data_dir("editend_abrev_is_correct");
+
         // write fresh to repeating tasks so content is known
         let fresh_repeating_tasks = r#"
             {
@@ -12301,10 +13290,10 @@ mod repeating_todo_editend {
                 repeating_tasks file wasn't correct. you should never be able to see this",
             )
             .expect("changing str to tasks struct failed");
-        write_changes_to_new_repeating_tasks(fresh_repeating_tasks);
+        write_tasks(&data_dir, TaskList::Repeating, fresh_repeating_tasks);
 
         // actions
-        let mut cmd = Command::cargo_bin("chartodo")?;
+        let mut cmd = chartodo(&data_dir, &[]);
         cmd.arg("rp-ee").arg("1").arg("2199-02-02").arg("01:01");
         cmd.assert()
             .success()
@@ -12312,26 +13301,8 @@ mod repeating_todo_editend {
             .stdout(predicate::str::contains("interval: 5 days"))
             .stdout(predicate::str::contains("due: 2199-02-02 01:01"));
 
-        Ok(())
-    }
-}
-
-mod zzz_do_this_last {
-    use super::*;
-
-    #[test]
-    fn zzzz_rename_repeating_copy_to_original() {
-        // name is zzzz so it's done last
-        // now that tests are done, remove the modified original and rename copy to original
-
-        std::fs::remove_file(path_to_repeating_tasks())
-            .context("failed delete modified repeating_tasks.json after running tests")
-            .expect("failed to delete repeating_tasks.json after repeating_helpers unit tests");
+        std::fs::remove_dir_all(&data_dir)?;
 
-        std::fs::rename(repeating_tasks_copy_path(), path_to_repeating_tasks())
-            .context("failed to rename repeating_tasks_copy to repeating_tasks")
-            .expect(
-                "failed to rename repeating_tasks_copy to repeating_tasks after tests were done",
-            );
+        Ok(())
     }
 }
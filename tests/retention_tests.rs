@@ -0,0 +1,150 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use serde_json::Value;
+
+mod common;
+use common::*;
+
+// cargo test --test retention_tests
+// every test points chartodo at its own folder in the temp dir, so these never touch the real chartodo files
+
+#[test]
+fn doneall_archives_instead_of_wiping() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("doneall");
+
+    let tasks: Vec<String> = (1..=30).map(|number| format!("task-{}", number)).collect();
+    let tasks: Vec<&str> = tasks.iter().map(String::as_str).collect();
+    chartodo(&data_dir, &[&["add"], tasks.as_slice()].concat())
+        .assert()
+        .success();
+    chartodo(&data_dir, &["doneall"]).assert().success();
+
+    // 32 done tasks, w/ the two example ones being the oldest. the example done task was done before chartodo
+    // kept track, so it goes first
+    let chartodo_json = read_json(data_dir.join("chartodo.json"));
+    let done = tasks_in(&chartodo_json["regular"]["done"]);
+    assert_eq!(done.len(), 30);
+    assert_eq!(done[0], "task-1");
+    assert!(chartodo_json["regular"]["done"][0]["done_at"].is_string());
+
+    let archive = read_json(data_dir.join("archive.json"));
+    assert_eq!(
+        tasks_in(&archive["regular"]),
+        vec!["this is the done list", "this is the todo list"]
+    );
+    assert!(tasks_in(&archive["deadline"]).is_empty());
+
+    std::fs::remove_dir_all(&data_dir)?;
+
+    Ok(())
+}
+
+#[test]
+fn every_done_list_is_kept_to_the_config() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("every_list");
+    std::fs::write(
+        data_dir.join("config.toml"),
+        format!("{}[retention]\nmax_done = 1\n", STARTER_TASKS),
+    )?;
+
+    chartodo(
+        &data_dir,
+        &[
+            "dl-a",
+            "taxes",
+            "2099-04-15",
+            "00:00",
+            "rent",
+            "2099-05-01",
+            "00:00",
+        ],
+    )
+    .assert()
+    .success();
+    chartodo(&data_dir, &["dl-da"]).assert().success();
+    // a repeating task that isn't finished stays, since it's coming back
+    chartodo(&data_dir, &["rp-a", "stretch", "1", "days", "--times", "1"])
+        .assert()
+        .success();
+    chartodo(&data_dir, &["rp-da"]).assert().success();
+
+    let chartodo_json = read_json(data_dir.join("chartodo.json"));
+    assert_eq!(tasks_in(&chartodo_json["regular"]["done"]).len(), 1);
+    assert_eq!(tasks_in(&chartodo_json["deadline"]["done"]).len(), 1);
+    assert_eq!(
+        tasks_in(&chartodo_json["repeating"]["done"]),
+        vec!["stretch", "the-turn-of-the-century"]
+    );
+
+    let archive = read_json(data_dir.join("archive.json"));
+    assert_eq!(tasks_in(&archive["deadline"]).len(), 2);
+    assert_eq!(tasks_in(&archive["repeating"]), Vec::<&str>::new());
+
+    std::fs::remove_dir_all(&data_dir)?;
+
+    Ok(())
+}
+
+#[test]
+fn old_done_tasks_expire() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("expire");
+    std::fs::write(
+        data_dir.join("config.toml"),
+        format!(
+            "{}[retention]\nmax_done_days = 30\noverflow = \"delete\"\n",
+            STARTER_TASKS
+        ),
+    )?;
+    chartodo(&data_dir, &["done", "1"]).assert().success();
+
+    // as if the example done task had been done a long time ago
+    let chartodo_path = data_dir.join("chartodo.json");
+    let mut chartodo_json = read_json(chartodo_path.clone());
+    chartodo_json["regular"]["done"][0]["done_at"] = Value::from("2000-01-01 00:00");
+    std::fs::write(&chartodo_path, serde_json::to_string(&chartodo_json)?)?;
+
+    chartodo(&data_dir, &["add", "hi"]).assert().success();
+
+    let chartodo_json = read_json(chartodo_path);
+    assert_eq!(
+        tasks_in(&chartodo_json["regular"]["done"]),
+        vec!["this is the todo list"]
+    );
+    // deleted, not archived
+    assert!(!data_dir.join("archive.json").exists());
+
+    std::fs::remove_dir_all(&data_dir)?;
+
+    Ok(())
+}
+
+#[test]
+fn broken_config_changes_nothing() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("broken_config");
+    chartodo(&data_dir, &["list"]).assert().success();
+    let before = std::fs::read_to_string(data_dir.join("chartodo.json"))?;
+
+    std::fs::write(
+        data_dir.join("config.toml"),
+        "[retention]\nmax_done = \"lots\"\n",
+    )?;
+    chartodo(&data_dir, &["add", "hi"])
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(
+            predicate::str::contains("couldn't parse")
+                .and(predicate::str::contains("Nothing was changed.")),
+        );
+
+    assert_eq!(
+        std::fs::read_to_string(data_dir.join("chartodo.json"))?,
+        before
+    );
+    // and it's left where it is to be fixed
+    assert!(data_dir.join("config.toml").exists());
+
+    std::fs::remove_dir_all(&data_dir)?;
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use serde_json::Value;
+use std::{path::PathBuf, process::Command};
+
+mod common;
+use common::*;
+
+// cargo test --test search_tests
+// every test points chartodo at its own folder in the temp dir, so these never touch the real chartodo files
+
+fn stdout_of(cmd: &mut Command) -> String {
+    String::from_utf8(cmd.assert().success().get_output().stdout.clone()).unwrap()
+}
+
+// buy-eggs and buy-bread in regular todo, pay-for-milk in deadline todo, buy-milk done and then archived
+fn fill_lists(data_dir: &PathBuf) {
+    chartodo(data_dir, &["add", "buy-milk", "buy-eggs", "buy-bread"])
+        .assert()
+        .success();
+    chartodo(data_dir, &["dl-a", "Pay-For-MILK", "2099-01-01", "12:00"])
+        .assert()
+        .success();
+    chartodo(data_dir, &["done", "1"]).assert().success();
+    chartodo(data_dir, &["archive", "1"]).assert().success();
+}
+
+#[test]
+fn search_looks_in_every_list_and_the_archive() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = missing_data_dir("every_list");
+    fill_lists(&data_dir);
+
+    // done tasks also say when they were done, which is whenever the test ran
+    let found = stdout_of(&mut chartodo(&data_dir, &["search", "milk"]));
+    let found: Vec<&str> = found.lines().collect();
+    assert_eq!(found.len(), 2);
+    assert_eq!(
+        found[0],
+        "deadline todo 1: Pay-For-MILK #4, due: 2099-01-01 12:00"
+    );
+    assert!(found[1].starts_with("regular archive 1: buy-milk #1, done: "));
+
+    // the position is the one done takes
+    assert_eq!(
+        stdout_of(&mut chartodo(&data_dir, &["s", "BREAD"])),
+        "regular todo 2: buy-bread #3\n"
+    );
+    chartodo(&data_dir, &["done", "2"]).assert().success();
+    chartodo(&data_dir, &["s", "bread"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "regular done 1: buy-bread #3, done: ",
+        ));
+
+    chartodo(&data_dir, &["search", "cheese"])
+        .assert()
+        .success()
+        .stdout("Nothing matched 'cheese'.\n");
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn search_can_use_a_regex() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = missing_data_dir("regex");
+    fill_lists(&data_dir);
+
+    assert_eq!(
+        stdout_of(&mut chartodo(
+            &data_dir,
+            &["search", "--regex", "^buy-(eggs|bread)$"]
+        )),
+        "regular todo 1: buy-eggs #2\nregular todo 2: buy-bread #3\n"
+    );
+    // case is still ignored
+    assert_eq!(
+        stdout_of(&mut chartodo(&data_dir, &["search", "--regex", "^pay"])),
+        "deadline todo 1: Pay-For-MILK #4, due: 2099-01-01 12:00\n"
+    );
+    // w/o --regex, it's just text
+    chartodo(&data_dir, &["search", "^buy"])
+        .assert()
+        .success()
+        .stdout("Nothing matched '^buy'.\n");
+
+    chartodo(&data_dir, &["search", "--regex", "(buy"])
+        .assert()
+        .failure()
+        .code(4)
+        .stderr(predicate::str::contains(
+            "ERROR: '(buy' isn't a proper regex: unclosed group",
+        ));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn search_json_says_where_each_task_is() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = missing_data_dir("json");
+    fill_lists(&data_dir);
+
+    let result: Value = serde_json::from_str(&stdout_of(&mut chartodo(
+        &data_dir,
+        &["--format", "json", "search", "milk"],
+    )))?;
+    assert_eq!(result["ok"], true);
+    let matches = result["matches"].as_array().unwrap();
+    assert_eq!(matches.len(), 2);
+    assert_eq!(matches[0]["list"], "deadline");
+    assert_eq!(matches[0]["in"], "todo");
+    assert_eq!(matches[0]["position"], 1);
+    assert_eq!(matches[0]["id"], 4);
+    assert_eq!(matches[0]["status"], "due");
+    assert_eq!(matches[1]["list"], "regular");
+    assert_eq!(matches[1]["in"], "archive");
+    assert_eq!(matches[1]["task"], "buy-milk");
+    assert!(matches[1]["done_at"].is_string());
+
+    let output = chartodo(&data_dir, &["--format", "json", "search", "--regex", "["])
+        .assert()
+        .code(4)
+        .get_output()
+        .stdout
+        .clone();
+    let result: Value = serde_json::from_slice(&output)?;
+    assert_eq!(result["error"]["code"], "invalid_pattern");
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
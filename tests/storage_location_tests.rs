@@ -0,0 +1,249 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::{path::PathBuf, process::Command};
+
+mod common;
+use common::*;
+
+// cargo test --test storage_location_tests
+// unlike the other integration tests, these never touch the real chartodo files, since every test points chartodo
+// at its own folder in the temp dir
+
+#[test]
+fn data_dir_flag_creates_files_in_given_dir() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = missing_data_dir("flag_creates_files");
+
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.arg("--data-dir")
+        .arg(&data_dir)
+        .arg("add")
+        .arg("flag-item");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("flag-item"));
+
+    assert!(data_dir.join("chartodo.json").exists());
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn data_dir_flag_creates_missing_parent_dirs() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = missing_data_dir("nested");
+    let nested_dir = data_dir.join("work").join("todos");
+
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.arg("--data-dir")
+        .arg(&nested_dir)
+        .arg("add")
+        .arg("nested-item");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("nested-item"));
+
+    assert!(nested_dir.join("chartodo.json").exists());
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn data_dir_that_cant_be_created_is_an_error() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = missing_data_dir("not_a_dir");
+    std::fs::create_dir(&data_dir)?;
+    // a file where a folder should be
+    std::fs::write(data_dir.join("file"), "")?;
+
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.arg("--data-dir")
+        .arg(data_dir.join("file").join("todos"))
+        .arg("add")
+        .arg("x");
+    cmd.assert().code(1).stderr(predicate::str::starts_with(
+        "ERROR: couldn't create the chartodo dir",
+    ));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn chartodo_home_env_creates_files_in_given_dir() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = missing_data_dir("env_creates_files");
+
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.env("CHARTODO_HOME", &data_dir)
+        .arg("add")
+        .arg("env-item");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("env-item"));
+
+    assert!(data_dir.join("chartodo.json").exists());
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn data_dir_flag_takes_priority_over_env() -> Result<(), Box<dyn std::error::Error>> {
+    let flag_dir = missing_data_dir("priority_flag");
+    let env_dir = missing_data_dir("priority_env");
+
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.env("CHARTODO_HOME", &env_dir)
+        .arg("add")
+        .arg("priority-item")
+        .arg("--data-dir")
+        .arg(&flag_dir);
+    cmd.assert().success();
+
+    assert!(flag_dir.join("chartodo.json").exists());
+    assert!(!env_dir.exists());
+
+    std::fs::remove_dir_all(&flag_dir)?;
+    Ok(())
+}
+
+#[test]
+fn separate_data_dirs_keep_separate_lists() -> Result<(), Box<dyn std::error::Error>> {
+    let work_dir = missing_data_dir("separate_work");
+    let personal_dir = missing_data_dir("separate_personal");
+
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.arg("--data-dir")
+        .arg(&work_dir)
+        .arg("add")
+        .arg("work-item");
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.arg("--data-dir")
+        .arg(&personal_dir)
+        .arg("add")
+        .arg("personal-item");
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.arg("--data-dir").arg(&work_dir).arg("list");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("work-item"))
+        .stdout(predicate::str::contains("personal-item").not());
+
+    std::fs::remove_dir_all(&work_dir)?;
+    std::fs::remove_dir_all(&personal_dir)?;
+    Ok(())
+}
+
+#[test]
+fn corrupt_file_is_reported_left_alone_and_copied() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = missing_data_dir("corrupt_file");
+
+    // create the file first, then cut it off halfway like a crash mid-write would
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.arg("--data-dir")
+        .arg(&data_dir)
+        .arg("add")
+        .arg("keep-me");
+    cmd.assert().success();
+    let chartodo_file_path = data_dir.join("chartodo.json");
+    let contents = std::fs::read_to_string(&chartodo_file_path)?;
+    let truncated = &contents[..contents.len() / 2];
+    std::fs::write(&chartodo_file_path, truncated)?;
+
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.arg("--data-dir")
+        .arg(&data_dir)
+        .arg("add")
+        .arg("new-item");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("couldn't parse"))
+        .stderr(predicate::str::contains("A copy of it was saved to"))
+        .stderr(predicate::str::contains(".corrupt"))
+        .stderr(predicate::str::contains("chartodo init"));
+
+    // running into it again uses the same copy instead of making another one
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.arg("--data-dir").arg(&data_dir).arg("list");
+    cmd.assert().failure();
+
+    // the bad file must not have been replaced w/ fresh data, or moved, and there's a copy of it next to it
+    assert_eq!(std::fs::read_to_string(&chartodo_file_path)?, truncated);
+    let corrupt_files: Vec<PathBuf> = std::fs::read_dir(&data_dir)?
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.to_str().unwrap().ends_with(".corrupt"))
+        .collect();
+    assert_eq!(corrupt_files.len(), 1);
+    assert_eq!(std::fs::read_to_string(&corrupt_files[0])?, truncated);
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn unknown_storage_is_an_error() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = missing_data_dir("unknown_storage");
+
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.env("CHARTODO_STORAGE", "yaml")
+        .arg("--data-dir")
+        .arg(&data_dir)
+        .arg("add")
+        .arg("yaml-item");
+    cmd.assert().code(1).stderr(predicate::str::contains(
+        "CHARTODO_STORAGE='yaml' isn't a kind of storage",
+    ));
+
+    assert!(!data_dir.join("chartodo.json").exists());
+
+    if data_dir.exists() {
+        std::fs::remove_dir_all(&data_dir)?;
+    }
+    Ok(())
+}
+
+// cargo test --test storage_location_tests --features sqlite
+#[cfg(feature = "sqlite")]
+#[test]
+fn sqlite_storage_brings_over_chartodo_json() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = missing_data_dir("sqlite_storage");
+
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.arg("--data-dir")
+        .arg(&data_dir)
+        .arg("add")
+        .arg("json-item");
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.env("CHARTODO_STORAGE", "sqlite")
+        .arg("--data-dir")
+        .arg(&data_dir)
+        .arg("add")
+        .arg("sqlite-item");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("json-item"))
+        .stdout(predicate::str::contains("sqlite-item"));
+
+    assert!(data_dir.join("chartodo.sqlite3").exists());
+    // chartodo.json is left as it was
+    let chartodo_json = std::fs::read_to_string(data_dir.join("chartodo.json"))?;
+    assert!(chartodo_json.contains("json-item"));
+    assert!(!chartodo_json.contains("sqlite-item"));
+
+    let mut cmd = Command::cargo_bin("chartodo")?;
+    cmd.env("CHARTODO_STORAGE", "sqlite")
+        .arg("--data-dir")
+        .arg(&data_dir)
+        .arg("list");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("sqlite-item"));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
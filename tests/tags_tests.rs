@@ -0,0 +1,144 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+mod common;
+use common::*;
+
+// cargo test --test tags_tests
+// every test points chartodo at its own folder in the temp dir. a fresh folder starts w/ the example tasks, which
+// get the ids #1 to #4, so the first task a test adds is always #5
+
+#[test]
+fn tags_are_set_when_adding() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("adding");
+
+    chartodo(
+        &data_dir,
+        &["add", "deploy", "-t", "backend", "--tag", "@work"],
+    )
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("2: deploy #5 +backend @work"));
+    chartodo(
+        &data_dir,
+        &[
+            "dl-a",
+            "taxes",
+            "2099-04-15",
+            "00:00",
+            "-p",
+            "high",
+            "-t",
+            "@errands",
+        ],
+    )
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("taxes #6 [high] @errands"));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn improper_tag_when_adding_is_an_error() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("improper_add");
+
+    chartodo(&data_dir, &["add", "x", "-t", "+"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("'+' isn't a tag"));
+
+    // clap stops it before chartodo ever touches the data dir
+    assert!(!data_dir.join("chartodo.json").exists());
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn list_only_shows_tasks_with_the_tag() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("list");
+
+    chartodo(&data_dir, &["add", "deploy", "-t", "backend"])
+        .assert()
+        .success();
+    chartodo(
+        &data_dir,
+        &["rp-a", "groceries", "1", "week", "-t", "@backend"],
+    )
+    .assert()
+    .success();
+
+    // a bare tag matches both + and @, and the tasks keep their real positions
+    chartodo(&data_dir, &["list", "--tag", "backend"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2: deploy #5 +backend"))
+        .stdout(predicate::str::contains("groceries #6 @backend"))
+        .stdout(predicate::str::contains("this is the todo list").not())
+        .stdout(predicate::str::contains("the-turn-of-the-century").not());
+    chartodo(&data_dir, &["l", "-t", "+backend"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("deploy #5"))
+        .stdout(predicate::str::contains("groceries").not());
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn tag_and_untag_existing_tasks() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("tag_untag");
+
+    chartodo(&data_dir, &["tag", "1", "backend", "@work"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "1: this is the todo list #1 +backend @work",
+        ));
+    chartodo(&data_dir, &["utg", "#1", "backend"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "1: this is the todo list #1 @work",
+        ));
+    chartodo(&data_dir, &["dl-tg", "1", "+taxes"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "breathe-once-before-2099 #3 +taxes",
+        ));
+    chartodo(&data_dir, &["rp-tg", "#4", "@century"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "the-turn-of-the-century #4 @century",
+        ));
+    chartodo(&data_dir, &["rp-utg", "1", "@century"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("the-turn-of-the-century #4"))
+        .stdout(predicate::str::contains("@century").not());
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn untagging_a_missing_tag_is_an_error() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("missing");
+
+    chartodo(&data_dir, &["dl-utg", "1", "backend"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "ERROR: The deadline todo at position 1, 'breathe-once-before-2099', doesn't have the tag 'backend'.",
+        ))
+        .stdout(predicate::str::contains("CHARTODO").not());
+    chartodo(&data_dir, &["tag", "1"]).assert().failure();
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
@@ -0,0 +1,151 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+mod common;
+use common::*;
+
+// cargo test --test task_ids_tests
+// every test points chartodo at its own folder in the temp dir. a fresh folder starts w/ the example tasks, which
+// get the ids #1 to #4, so the first task a test adds is always #5
+
+#[test]
+fn ids_are_shown_in_the_list() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("shown");
+
+    chartodo(&data_dir, &["add", "first", "second"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2: first #5"))
+        .stdout(predicate::str::contains("3: second #6"));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn id_still_points_at_its_task_after_sorting() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("sorting");
+
+    // later is #5, but sooner gets sorted in front of it
+    chartodo(&data_dir, &["dl-a", "later", "2099-02-01", "00:00"])
+        .assert()
+        .success();
+    chartodo(&data_dir, &["dl-a", "sooner", "2098-01-01", "00:00"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("1: sooner #6"))
+        .stdout(predicate::str::contains("3: later #5"));
+
+    chartodo(&data_dir, &["dl-d", "#5"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("DONE"))
+        .stdout(predicate::str::contains("1: later #5"))
+        .stdout(predicate::str::contains("1: sooner #6"));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn ids_can_be_mixed_with_positions() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("mixed");
+
+    chartodo(&data_dir, &["add", "a", "b", "c"])
+        .assert()
+        .success();
+    chartodo(&data_dir, &["rmt", "#7", "1-2"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("1: c #7").not())
+        .stdout(predicate::str::contains("1: b #6"));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn id_keeps_its_task_when_it_moves_lists() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("moves");
+
+    chartodo(&data_dir, &["add", "moving"]).assert().success();
+    chartodo(&data_dir, &["done", "#5"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2: moving #5"));
+    chartodo(&data_dir, &["notdone", "#5"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2: moving #5"));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn removed_ids_are_not_reused() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("not_reused");
+
+    chartodo(&data_dir, &["add", "gone"]).assert().success();
+    chartodo(&data_dir, &["rmt", "#5"]).assert().success();
+    chartodo(&data_dir, &["add", "new"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("new #6"));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn id_not_in_list_is_an_error() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("not_in_list");
+
+    // #3 is the example deadline task, so it's not in the regular todo list
+    chartodo(&data_dir, &["done", "#3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "ERROR: There's no task with the id '#3' in the regular todo list.",
+        ))
+        .stdout(predicate::str::contains("CHARTODO").not());
+
+    chartodo(&data_dir, &["rp-eta", "#abc", "new-task"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "ERROR: The id you provided, '#abc', wasn't proper.",
+        ));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn editall_keeps_the_id() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("editall");
+
+    chartodo(&data_dir, &["dl-ea", "#3", "edited", "2099-01-01", "00:00"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("1: edited #3"));
+    chartodo(
+        &data_dir,
+        &[
+            "rp-ea",
+            "#4",
+            "edited-too",
+            "1",
+            "day",
+            "start",
+            "2099-01-01",
+            "00:00",
+        ],
+    )
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("1: edited-too #4"));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
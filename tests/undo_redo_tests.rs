@@ -0,0 +1,163 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+
+mod common;
+use common::*;
+
+// cargo test --test undo_redo_tests
+// every test points chartodo at its own folder in the temp dir, so the real chartodo files and undo history are never
+// touched
+
+#[test]
+fn undo_with_empty_history_is_an_error() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("empty_undo");
+
+    chartodo(&data_dir, &["undo"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("ERROR: There is nothing to undo."));
+    chartodo(&data_dir, &["redo"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("ERROR: There is nothing to redo."));
+
+    // nothing was ever written, so there may not be a folder to clean up
+    if data_dir.exists() {
+        std::fs::remove_dir_all(&data_dir)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn undo_restores_regular_tasks() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("undo_regular");
+
+    chartodo(&data_dir, &["add", "keep-me"]).assert().success();
+    chartodo(&data_dir, &["rmtodo", "1-2"]).assert().success();
+
+    chartodo(&data_dir, &["undo"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Undid: chartodo rmtodo 1-2"))
+        .stdout(predicate::str::contains("keep-me"));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn undo_restores_all_lists_at_once() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("undo_clearall");
+
+    chartodo(&data_dir, &["add", "regular-item"])
+        .assert()
+        .success();
+    chartodo(
+        &data_dir,
+        &["deadline-add", "deadline-item", "2099-01-01", "00:00"],
+    )
+    .assert()
+    .success();
+    chartodo(&data_dir, &["repeating-add", "repeating-item", "1", "days"])
+        .assert()
+        .success();
+    chartodo(&data_dir, &["clearall"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("regular-item").not());
+
+    chartodo(&data_dir, &["u"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Undid: chartodo clearall"))
+        .stdout(predicate::str::contains("regular-item"))
+        .stdout(predicate::str::contains("deadline-item"))
+        .stdout(predicate::str::contains("repeating-item"));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn redo_reapplies_undone_command() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("redo");
+
+    chartodo(&data_dir, &["add", "gone-again"])
+        .assert()
+        .success();
+    chartodo(&data_dir, &["undo"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2: gone-again").not());
+
+    chartodo(&data_dir, &["redo"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Redid: chartodo add gone-again"))
+        .stdout(predicate::str::contains("2: gone-again"));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn new_command_clears_redo() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("new_command_clears_redo");
+
+    chartodo(&data_dir, &["add", "first"]).assert().success();
+    chartodo(&data_dir, &["undo"]).assert().success();
+    chartodo(&data_dir, &["add", "second"]).assert().success();
+
+    chartodo(&data_dir, &["redo"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("ERROR: There is nothing to redo."));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn failed_and_read_only_commands_are_not_recorded() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("not_recorded");
+
+    chartodo(&data_dir, &["add", "only-change"])
+        .assert()
+        .success();
+    chartodo(&data_dir, &["list"]).assert().success();
+    chartodo(&data_dir, &["rmtodo", "99"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("ERROR"));
+
+    chartodo(&data_dir, &["undo"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Undid: chartodo add only-change"));
+    chartodo(&data_dir, &["undo"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("ERROR: There is nothing to undo."));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}
+
+#[test]
+fn corrupt_undo_history_is_reset() -> Result<(), Box<dyn std::error::Error>> {
+    let data_dir = fresh_data_dir("corrupt_history");
+
+    chartodo(&data_dir, &["add", "still-works"])
+        .assert()
+        .success();
+    std::fs::write(data_dir.join("undo_history.json"), r#"{"undo": [{"comm"#)?;
+
+    chartodo(&data_dir, &["undo"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("The undo history was reset"))
+        .stderr(predicate::str::contains("ERROR: There is nothing to undo."));
+
+    std::fs::remove_dir_all(&data_dir)?;
+    Ok(())
+}